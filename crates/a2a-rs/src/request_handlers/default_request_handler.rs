@@ -339,6 +339,19 @@ impl RequestHandler for DefaultRequestHandler {
             A2aServerError::TaskNotFoundError(ErrorBuilder::default().build().unwrap())
         })?;
 
+        if is_terminal_state(&task.status.state) {
+            trace!(task_id = %params.id, state = ?task.status.state, "Task already terminal, not canceling");
+            return Err(A2aServerError::TaskNotCancelableError(
+                ErrorBuilder::default()
+                    .message(format!(
+                        "Task {} is already in terminal state {:?}",
+                        task.id, task.status.state
+                    ))
+                    .build()
+                    .unwrap(),
+            ));
+        }
+
         let task_manager = TaskManager::new(
             self.task_store.clone(),
             Some(task.id.clone()),
@@ -729,3 +742,121 @@ impl Clone for DefaultRequestHandler {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    mod unit {
+        use super::super::*;
+        use crate::{
+            agent_execution::agent_executor::BoxedFuture,
+            tasks::in_memory_task_store::InMemoryTaskStoreBuilder,
+            types::{TaskStatus, TaskStatusUpdateEvent},
+        };
+        use tokio::sync::RwLock;
+
+        /// Executor whose cancel publishes a final canceled status update
+        struct CancelingExecutor;
+
+        impl AgentExecutor for CancelingExecutor {
+            fn execute<'a>(
+                &'a self,
+                _context: RequestContext,
+                _queue: EventQueue,
+            ) -> BoxedFuture<'a> {
+                Box::pin(async { Ok(()) })
+            }
+
+            fn cancel<'a>(&'a self, context: RequestContext, queue: EventQueue) -> BoxedFuture<'a> {
+                Box::pin(async move {
+                    let event = TaskStatusUpdateEvent {
+                        task_id: context.task_id().unwrap().to_string(),
+                        context_id: context.context_id().unwrap().to_string(),
+                        kind: "status-update".to_string(),
+                        status: TaskStatus {
+                            state: TaskState::Canceled,
+                            message: None,
+                            timestamp: None,
+                        },
+                        final_: true,
+                        metadata: Default::default(),
+                    };
+                    queue.enqueue_event(Event::TaskStatusUpdate(event)).await?;
+                    Ok(())
+                })
+            }
+        }
+
+        fn task(state: TaskState) -> Task {
+            Task {
+                id: "task-1".to_string(),
+                context_id: "context-1".to_string(),
+                status: TaskStatus {
+                    state,
+                    message: None,
+                    timestamp: None,
+                },
+                artifacts: vec![],
+                history: vec![],
+                metadata: Default::default(),
+                kind: "task".to_string(),
+            }
+        }
+
+        async fn handler_with_task(task: Task) -> DefaultRequestHandler {
+            let task_store = InMemoryTaskStoreBuilder::default()
+                .tasks(Arc::new(RwLock::new(HashMap::new())))
+                .build()
+                .unwrap();
+            task_store.save(&task).await.unwrap();
+            DefaultRequestHandler::new(
+                Arc::new(CancelingExecutor),
+                Arc::new(task_store),
+                None,
+                None,
+                None,
+                None,
+            )
+        }
+
+        fn cancel_params() -> TaskIdParams {
+            TaskIdParams {
+                id: "task-1".to_string(),
+                metadata: Default::default(),
+            }
+        }
+
+        #[tokio::test]
+        async fn test_cancel_running_task_emits_canceled_state() {
+            let handler = handler_with_task(task(TaskState::Working)).await;
+
+            let canceled = handler
+                .on_cancel_task(cancel_params())
+                .await
+                .unwrap()
+                .unwrap();
+            assert_eq!(canceled.status.state, TaskState::Canceled);
+
+            let stored = handler.task_store.get(&"task-1".to_string()).await.unwrap();
+            assert_eq!(stored.unwrap().status.state, TaskState::Canceled);
+
+            // Canceling again hits the terminal check
+            let again = handler.on_cancel_task(cancel_params()).await;
+            assert!(matches!(
+                again,
+                Err(A2aServerError::TaskNotCancelableError(_))
+            ));
+        }
+
+        #[tokio::test]
+        async fn test_cancel_terminal_task_is_rejected() {
+            for state in [TaskState::Completed, TaskState::Failed, TaskState::Rejected] {
+                let handler = handler_with_task(task(state)).await;
+                let result = handler.on_cancel_task(cancel_params()).await;
+                assert!(matches!(
+                    result,
+                    Err(A2aServerError::TaskNotCancelableError(_))
+                ));
+            }
+        }
+    }
+}
@@ -1,7 +1,9 @@
 use std::{ops::Deref, sync::Arc};
 
 use crate::{
-    errors::A2aServerError, request_handlers::request_handler::RequestHandler, types::AgentCard,
+    errors::A2aServerError,
+    request_handlers::request_handler::RequestHandler,
+    types::{AgentCard, Task, TaskIdParams},
 };
 use async_trait::async_trait;
 use derive_builder::Builder;
@@ -20,6 +22,22 @@ pub trait A2aServiceLike: Send + Sync {
         context: RequestContext,
     ) -> Result<Option<AgentCard>, A2aServerError>;
     fn request_handler(&self, context: RequestContext) -> Arc<dyn RequestHandler + Send + Sync>;
+
+    /// Cancels an in-flight task through the request handler, which asks the agent executor
+    /// to publish the canceled status. Tasks already in a terminal state are rejected with
+    /// `A2aServerError::TaskNotCancelableError`.
+    async fn cancel_task(
+        &self,
+        context: RequestContext,
+        task_id: &str,
+    ) -> Result<Option<Task>, A2aServerError> {
+        self.request_handler(context)
+            .on_cancel_task(TaskIdParams {
+                id: task_id.to_string(),
+                metadata: Default::default(),
+            })
+            .await
+    }
 }
 
 #[derive(Builder, Clone)]
@@ -24,6 +24,73 @@ pub type ContextId = String;
 //     GetParams(GetTaskPushNotificationConfigParams),
 // }
 
+/// Metadata key on a terminal status message that records its [`TaskReasonCode`]
+pub const TASK_REASON_CODE_METADATA_KEY: &str = "reasonCode";
+
+/// Machine-readable reason a task ended up canceled or failed, so clients can tell a
+/// user cancellation apart from a timeout or an agent error.
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum TaskReasonCode {
+    UserCanceled,
+    DeadlineExceeded,
+    AgentError,
+}
+
+impl TaskStatus {
+    /// Builds a status whose message carries `reason_code` in its metadata and the optional
+    /// human-readable `reason` as a text part. Intended for canceled/failed states.
+    pub fn with_reason(
+        state: TaskState,
+        task_id: &str,
+        context_id: &str,
+        reason_code: TaskReasonCode,
+        reason: Option<String>,
+    ) -> Self {
+        let mut metadata = serde_json::Map::new();
+        metadata.insert(
+            TASK_REASON_CODE_METADATA_KEY.to_string(),
+            serde_json::to_value(reason_code).unwrap_or_default(),
+        );
+
+        let parts = reason
+            .map(|text| {
+                vec![Part::TextPart(TextPart {
+                    text,
+                    kind: "text".to_string(),
+                    metadata: Default::default(),
+                })]
+            })
+            .unwrap_or_default();
+
+        TaskStatus {
+            state,
+            message: Some(Message {
+                context_id: Some(context_id.to_string()),
+                extensions: vec![],
+                kind: "message".to_string(),
+                message_id: uuid::Uuid::new_v4().to_string(),
+                metadata,
+                parts,
+                reference_task_ids: vec![],
+                role: MessageRole::Agent,
+                task_id: Some(task_id.to_string()),
+            }),
+            timestamp: Some(chrono::Utc::now().to_rfc3339()),
+        }
+    }
+
+    /// Returns the reason code recorded on this status' message, if any
+    pub fn reason_code(&self) -> Option<TaskReasonCode> {
+        let value = self
+            .message
+            .as_ref()?
+            .metadata
+            .get(TASK_REASON_CODE_METADATA_KEY)?;
+        serde_json::from_value(value.clone()).ok()
+    }
+}
+
 impl ToSchema for AgentCard {}
 
 impl PartialSchema for AgentCard {
@@ -157,3 +224,51 @@ impl<Data> From<Result<Data, A2aServerError>> for CustomJsonRpcPayload<Data> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    mod unit {
+        use super::super::*;
+
+        #[test]
+        fn test_task_status_with_reason_round_trips() {
+            let status = TaskStatus::with_reason(
+                TaskState::Canceled,
+                "task-1",
+                "context-1",
+                TaskReasonCode::UserCanceled,
+                Some("Canceled by user".to_string()),
+            );
+            assert_eq!(status.reason_code(), Some(TaskReasonCode::UserCanceled));
+
+            let json = serde_json::to_value(&status).unwrap();
+            assert_eq!(json["message"]["metadata"]["reasonCode"], "user_canceled");
+
+            let decoded: TaskStatus = serde_json::from_value(json).unwrap();
+            assert_eq!(decoded.reason_code(), Some(TaskReasonCode::UserCanceled));
+        }
+
+        #[test]
+        fn test_task_status_reason_code_deadline_exceeded() {
+            let status = TaskStatus::with_reason(
+                TaskState::Failed,
+                "task-1",
+                "context-1",
+                TaskReasonCode::DeadlineExceeded,
+                None,
+            );
+            assert_eq!(status.reason_code(), Some(TaskReasonCode::DeadlineExceeded));
+            assert!(status.message.unwrap().parts.is_empty());
+        }
+
+        #[test]
+        fn test_task_status_without_message_has_no_reason_code() {
+            let status = TaskStatus {
+                state: TaskState::Completed,
+                message: None,
+                timestamp: None,
+            };
+            assert_eq!(status.reason_code(), None);
+        }
+    }
+}
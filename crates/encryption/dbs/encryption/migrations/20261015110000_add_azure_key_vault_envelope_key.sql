@@ -0,0 +1,73 @@
+-- +goose Up
+-- disable the enforcement of foreign-keys constraints
+PRAGMA foreign_keys = off;
+-- create "new_envelope_encryption_key" table
+CREATE TABLE `new_envelope_encryption_key` (
+  `id` text NULL,
+  `key_type` text NOT NULL,
+  `local_file_name` text NULL,
+  `aws_arn` text NULL,
+  `aws_region` text NULL,
+  `gcp_key_name` text NULL,
+  `gcp_location` text NULL,
+  `vault_address` text NULL,
+  `vault_mount` text NULL,
+  `vault_key_name` text NULL,
+  `azure_vault_url` text NULL,
+  `azure_key_name` text NULL,
+  `azure_key_version` text NULL,
+  `created_at` datetime NOT NULL DEFAULT (CURRENT_TIMESTAMP),
+  `updated_at` datetime NOT NULL DEFAULT (CURRENT_TIMESTAMP),
+  PRIMARY KEY (`id`),
+  CHECK (key_type IN ('local', 'aws_kms', 'gcp_kms', 'vault_transit', 'azure_key_vault')),
+  CHECK (
+        (key_type = 'local' AND local_file_name IS NOT NULL AND aws_arn IS NULL AND aws_region IS NULL AND gcp_key_name IS NULL AND gcp_location IS NULL AND vault_address IS NULL AND vault_mount IS NULL AND vault_key_name IS NULL AND azure_vault_url IS NULL AND azure_key_name IS NULL AND azure_key_version IS NULL) OR
+        (key_type = 'aws_kms' AND aws_arn IS NOT NULL AND aws_region IS NOT NULL AND local_file_name IS NULL AND gcp_key_name IS NULL AND gcp_location IS NULL AND vault_address IS NULL AND vault_mount IS NULL AND vault_key_name IS NULL AND azure_vault_url IS NULL AND azure_key_name IS NULL AND azure_key_version IS NULL) OR
+        (key_type = 'gcp_kms' AND gcp_key_name IS NOT NULL AND gcp_location IS NOT NULL AND local_file_name IS NULL AND aws_arn IS NULL AND aws_region IS NULL AND vault_address IS NULL AND vault_mount IS NULL AND vault_key_name IS NULL AND azure_vault_url IS NULL AND azure_key_name IS NULL AND azure_key_version IS NULL) OR
+        (key_type = 'vault_transit' AND vault_address IS NOT NULL AND vault_mount IS NOT NULL AND vault_key_name IS NOT NULL AND local_file_name IS NULL AND aws_arn IS NULL AND aws_region IS NULL AND gcp_key_name IS NULL AND gcp_location IS NULL AND azure_vault_url IS NULL AND azure_key_name IS NULL AND azure_key_version IS NULL) OR
+        (key_type = 'azure_key_vault' AND azure_vault_url IS NOT NULL AND azure_key_name IS NOT NULL AND azure_key_version IS NOT NULL AND local_file_name IS NULL AND aws_arn IS NULL AND aws_region IS NULL AND gcp_key_name IS NULL AND gcp_location IS NULL AND vault_address IS NULL AND vault_mount IS NULL AND vault_key_name IS NULL)
+    )
+);
+-- copy rows from old table "envelope_encryption_key" to new temporary table "new_envelope_encryption_key"
+INSERT INTO `new_envelope_encryption_key` (`id`, `key_type`, `local_file_name`, `aws_arn`, `aws_region`, `gcp_key_name`, `gcp_location`, `vault_address`, `vault_mount`, `vault_key_name`, `created_at`, `updated_at`) SELECT `id`, `key_type`, `local_file_name`, `aws_arn`, `aws_region`, `gcp_key_name`, `gcp_location`, `vault_address`, `vault_mount`, `vault_key_name`, `created_at`, `updated_at` FROM `envelope_encryption_key`;
+-- drop "envelope_encryption_key" table after copying rows
+DROP TABLE `envelope_encryption_key`;
+-- rename temporary table "new_envelope_encryption_key" to "envelope_encryption_key"
+ALTER TABLE `new_envelope_encryption_key` RENAME TO `envelope_encryption_key`;
+-- enable back the enforcement of foreign-keys constraints
+PRAGMA foreign_keys = on;
+
+-- +goose Down
+-- disable the enforcement of foreign-keys constraints
+PRAGMA foreign_keys = off;
+-- reverse: create "new_envelope_encryption_key" table
+CREATE TABLE `new_envelope_encryption_key` (
+  `id` text NULL,
+  `key_type` text NOT NULL,
+  `local_file_name` text NULL,
+  `aws_arn` text NULL,
+  `aws_region` text NULL,
+  `gcp_key_name` text NULL,
+  `gcp_location` text NULL,
+  `vault_address` text NULL,
+  `vault_mount` text NULL,
+  `vault_key_name` text NULL,
+  `created_at` datetime NOT NULL DEFAULT (CURRENT_TIMESTAMP),
+  `updated_at` datetime NOT NULL DEFAULT (CURRENT_TIMESTAMP),
+  PRIMARY KEY (`id`),
+  CHECK (key_type IN ('local', 'aws_kms', 'gcp_kms', 'vault_transit')),
+  CHECK (
+        (key_type = 'local' AND local_file_name IS NOT NULL AND aws_arn IS NULL AND aws_region IS NULL AND gcp_key_name IS NULL AND gcp_location IS NULL AND vault_address IS NULL AND vault_mount IS NULL AND vault_key_name IS NULL) OR
+        (key_type = 'aws_kms' AND aws_arn IS NOT NULL AND aws_region IS NOT NULL AND local_file_name IS NULL AND gcp_key_name IS NULL AND gcp_location IS NULL AND vault_address IS NULL AND vault_mount IS NULL AND vault_key_name IS NULL) OR
+        (key_type = 'gcp_kms' AND gcp_key_name IS NOT NULL AND gcp_location IS NOT NULL AND local_file_name IS NULL AND aws_arn IS NULL AND aws_region IS NULL AND vault_address IS NULL AND vault_mount IS NULL AND vault_key_name IS NULL) OR
+        (key_type = 'vault_transit' AND vault_address IS NOT NULL AND vault_mount IS NOT NULL AND vault_key_name IS NOT NULL AND local_file_name IS NULL AND aws_arn IS NULL AND aws_region IS NULL AND gcp_key_name IS NULL AND gcp_location IS NULL)
+    )
+);
+-- reverse: copy rows, Azure Key Vault keys cannot be represented in the old table
+INSERT INTO `new_envelope_encryption_key` (`id`, `key_type`, `local_file_name`, `aws_arn`, `aws_region`, `gcp_key_name`, `gcp_location`, `vault_address`, `vault_mount`, `vault_key_name`, `created_at`, `updated_at`) SELECT `id`, `key_type`, `local_file_name`, `aws_arn`, `aws_region`, `gcp_key_name`, `gcp_location`, `vault_address`, `vault_mount`, `vault_key_name`, `created_at`, `updated_at` FROM `envelope_encryption_key` WHERE `key_type` != 'azure_key_vault';
+-- reverse: drop "envelope_encryption_key" table
+DROP TABLE `envelope_encryption_key`;
+-- reverse: rename temporary table
+ALTER TABLE `new_envelope_encryption_key` RENAME TO `envelope_encryption_key`;
+-- enable back the enforcement of foreign-keys constraints
+PRAGMA foreign_keys = on;
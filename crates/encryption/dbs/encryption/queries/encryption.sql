@@ -1,19 +1,19 @@
 -- name: create_envelope_encryption_key :exec
-INSERT INTO envelope_encryption_key (id, key_type, local_file_name, aws_arn, aws_region, created_at, updated_at)
-VALUES (?, ?, ?, ?, ?, ?, ?);
+INSERT INTO envelope_encryption_key (id, key_type, local_file_name, aws_arn, aws_region, gcp_key_name, gcp_location, vault_address, vault_mount, vault_key_name, azure_vault_url, azure_key_name, azure_key_version, created_at, updated_at)
+VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?);
 
 -- name: get_envelope_encryption_key_by_id :one
-SELECT id, key_type, local_file_name, aws_arn, aws_region, created_at, updated_at
+SELECT id, key_type, local_file_name, aws_arn, aws_region, gcp_key_name, gcp_location, vault_address, vault_mount, vault_key_name, azure_vault_url, azure_key_name, azure_key_version, created_at, updated_at
 FROM envelope_encryption_key
 WHERE id = ?;
 
 -- name: get_envelope_encryption_keys :many
-SELECT id, key_type, local_file_name, aws_arn, aws_region, created_at, updated_at
+SELECT id, key_type, local_file_name, aws_arn, aws_region, gcp_key_name, gcp_location, vault_address, vault_mount, vault_key_name, azure_vault_url, azure_key_name, azure_key_version, created_at, updated_at
 FROM envelope_encryption_key
 ORDER BY created_at DESC;
 
 -- name: get_envelope_encryption_keys_paginated :many
-SELECT id, key_type, local_file_name, aws_arn, aws_region, created_at, updated_at
+SELECT id, key_type, local_file_name, aws_arn, aws_region, gcp_key_name, gcp_location, vault_address, vault_mount, vault_key_name, azure_vault_url, azure_key_name, azure_key_version, created_at, updated_at
 FROM envelope_encryption_key 
 WHERE (created_at < sqlc.narg(cursor) OR sqlc.narg(cursor) IS NULL)
 ORDER BY created_at DESC
@@ -49,7 +49,15 @@ SELECT
     eek.key_type,
     eek.local_file_name,
     eek.aws_arn,
-    eek.aws_region
+    eek.aws_region,
+    eek.gcp_key_name,
+    eek.gcp_location,
+    eek.vault_address,
+    eek.vault_mount,
+    eek.vault_key_name,
+    eek.azure_vault_url,
+    eek.azure_key_name,
+    eek.azure_key_version
 FROM data_encryption_key dek
 JOIN envelope_encryption_key eek ON dek.envelope_encryption_key_id = eek.id
 WHERE dek.id = ?;
@@ -74,7 +82,15 @@ SELECT
     eek.key_type,
     eek.local_file_name,
     eek.aws_arn,
-    eek.aws_region
+    eek.aws_region,
+    eek.gcp_key_name,
+    eek.gcp_location,
+    eek.vault_address,
+    eek.vault_mount,
+    eek.vault_key_name,
+    eek.azure_vault_url,
+    eek.azure_key_name,
+    eek.azure_key_version
 FROM data_encryption_key dek
 JOIN envelope_encryption_key eek ON dek.envelope_encryption_key_id = eek.id;
 
@@ -1,14 +1,25 @@
 CREATE TABLE IF NOT EXISTS envelope_encryption_key (
     id TEXT PRIMARY KEY,
-    key_type TEXT NOT NULL CHECK (key_type IN ('local', 'aws_kms')),
+    key_type TEXT NOT NULL CHECK (key_type IN ('local', 'aws_kms', 'gcp_kms', 'vault_transit', 'azure_key_vault')),
     local_file_name TEXT,
     aws_arn TEXT,
     aws_region TEXT,
+    gcp_key_name TEXT,
+    gcp_location TEXT,
+    vault_address TEXT,
+    vault_mount TEXT,
+    vault_key_name TEXT,
+    azure_vault_url TEXT,
+    azure_key_name TEXT,
+    azure_key_version TEXT,
     created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
     updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
     CHECK (
-        (key_type = 'local' AND local_file_name IS NOT NULL AND aws_arn IS NULL AND aws_region IS NULL) OR
-        (key_type = 'aws_kms' AND aws_arn IS NOT NULL AND aws_region IS NOT NULL AND local_file_name IS NULL)
+        (key_type = 'local' AND local_file_name IS NOT NULL AND aws_arn IS NULL AND aws_region IS NULL AND gcp_key_name IS NULL AND gcp_location IS NULL AND vault_address IS NULL AND vault_mount IS NULL AND vault_key_name IS NULL AND azure_vault_url IS NULL AND azure_key_name IS NULL AND azure_key_version IS NULL) OR
+        (key_type = 'aws_kms' AND aws_arn IS NOT NULL AND aws_region IS NOT NULL AND local_file_name IS NULL AND gcp_key_name IS NULL AND gcp_location IS NULL AND vault_address IS NULL AND vault_mount IS NULL AND vault_key_name IS NULL AND azure_vault_url IS NULL AND azure_key_name IS NULL AND azure_key_version IS NULL) OR
+        (key_type = 'gcp_kms' AND gcp_key_name IS NOT NULL AND gcp_location IS NOT NULL AND local_file_name IS NULL AND aws_arn IS NULL AND aws_region IS NULL AND vault_address IS NULL AND vault_mount IS NULL AND vault_key_name IS NULL AND azure_vault_url IS NULL AND azure_key_name IS NULL AND azure_key_version IS NULL) OR
+        (key_type = 'vault_transit' AND vault_address IS NOT NULL AND vault_mount IS NOT NULL AND vault_key_name IS NOT NULL AND local_file_name IS NULL AND aws_arn IS NULL AND aws_region IS NULL AND gcp_key_name IS NULL AND gcp_location IS NULL AND azure_vault_url IS NULL AND azure_key_name IS NULL AND azure_key_version IS NULL) OR
+        (key_type = 'azure_key_vault' AND azure_vault_url IS NOT NULL AND azure_key_name IS NOT NULL AND azure_key_version IS NOT NULL AND local_file_name IS NULL AND aws_arn IS NULL AND aws_region IS NULL AND gcp_key_name IS NULL AND gcp_location IS NULL AND vault_address IS NULL AND vault_mount IS NULL AND vault_key_name IS NULL)
     )
 );
 
@@ -329,4 +329,207 @@ mod tests {
             }
         }
     }
+
+    /// Runs against a real Cloud KMS key with application default credentials. Enable with
+    /// `--features gcp_integration_test` and set `TEST_GCP_KMS_KEY_NAME` to the key's resource name.
+    #[cfg(feature = "gcp_integration_test")]
+    mod gcp_integration {
+        use crate::logic::crypto_services::{CryptoService, DecryptionService, EncryptionService};
+        use crate::logic::dek::DataEncryptionKey;
+        use crate::logic::envelope::{
+            EnvelopeEncryptionKey, EnvelopeEncryptionKeyContents, decrypt_dek, encrypt_dek,
+            extract_location_from_gcp_key_name,
+        };
+
+        fn gcp_kms_key_name() -> String {
+            std::env::var("TEST_GCP_KMS_KEY_NAME")
+                .expect("TEST_GCP_KMS_KEY_NAME must be set for GCP KMS integration tests")
+        }
+
+        fn gcp_kms_key(key_name: &str) -> EnvelopeEncryptionKeyContents {
+            let location = extract_location_from_gcp_key_name(key_name)
+                .expect("TEST_GCP_KMS_KEY_NAME should be a crypto key resource name");
+            EnvelopeEncryptionKeyContents::GcpKms {
+                key_name: key_name.to_string(),
+                location,
+            }
+        }
+
+        #[tokio::test]
+        async fn test_encrypt_decrypt_roundtrip_with_gcp_kms() {
+            shared::setup_test!();
+
+            let parent_key = gcp_kms_key(&gcp_kms_key_name());
+            let test_data = "This is a test DEK for envelope encryption";
+
+            let encrypted_key = encrypt_dek(&parent_key, test_data.to_string())
+                .await
+                .expect("Encryption should succeed");
+
+            // The ciphertext is stored base64 encoded, like the AWS KMS flow
+            assert!(
+                base64::Engine::decode(
+                    &base64::engine::general_purpose::STANDARD,
+                    &encrypted_key.0
+                )
+                .is_ok(),
+                "Encrypted key should be valid base64"
+            );
+            assert_ne!(encrypted_key.0, test_data);
+
+            let decrypted_key = decrypt_dek(&parent_key, &encrypted_key)
+                .await
+                .expect("Decryption should succeed");
+            assert_eq!(decrypted_key.0, test_data.as_bytes());
+        }
+
+        #[tokio::test]
+        async fn test_encryption_service_roundtrip_with_gcp_kms() {
+            shared::setup_test!();
+
+            let key_name = gcp_kms_key_name();
+            let parent_key = gcp_kms_key(&key_name);
+
+            // Cloud KMS has no data key generation, so wrap a locally generated DEK
+            let mut dek = [0u8; 32];
+            rand::RngCore::fill_bytes(&mut rand::thread_rng(), &mut dek);
+            let encrypted_data_encryption_key =
+                crate::logic::envelope::encrypt_with_gcp_kms(&key_name, &dek)
+                    .await
+                    .expect("Failed to encrypt DEK with GCP KMS");
+
+            let now = shared::primitives::WrappedChronoDateTime::now();
+            let data_encryption_key = DataEncryptionKey {
+                id: uuid::Uuid::new_v4().to_string(),
+                envelope_encryption_key_id: EnvelopeEncryptionKey::from(parent_key.clone()),
+                encrypted_data_encryption_key,
+                created_at: now,
+                updated_at: now,
+            };
+
+            let crypto_service = CryptoService::new(parent_key, data_encryption_key)
+                .await
+                .expect("Failed to create crypto service");
+            let encryption_service = EncryptionService::new(crypto_service.clone());
+            let decryption_service = DecryptionService::new(crypto_service);
+
+            let encrypted = encryption_service
+                .encrypt_data("Simple plaintext".to_string())
+                .await
+                .expect("Encryption should succeed");
+            let decrypted = decryption_service
+                .decrypt_data(encrypted)
+                .await
+                .expect("Decryption should succeed");
+            assert_eq!(decrypted, "Simple plaintext");
+        }
+    }
+
+    /// Runs against a real Vault server with the transit engine enabled. Enable with
+    /// `--features vault_integration_test` and set `VAULT_ADDR`, `VAULT_TOKEN` and
+    /// `TEST_VAULT_TRANSIT_KEY_NAME` (the mount defaults to `transit`, override with
+    /// `TEST_VAULT_TRANSIT_MOUNT`).
+    #[cfg(feature = "vault_integration_test")]
+    mod vault_integration {
+        use crate::logic::envelope::{EnvelopeEncryptionKeyContents, decrypt_dek, encrypt_dek};
+
+        fn vault_transit_key() -> EnvelopeEncryptionKeyContents {
+            EnvelopeEncryptionKeyContents::VaultTransit {
+                address: std::env::var("VAULT_ADDR")
+                    .expect("VAULT_ADDR must be set for Vault integration tests"),
+                mount: std::env::var("TEST_VAULT_TRANSIT_MOUNT")
+                    .unwrap_or_else(|_| "transit".to_string()),
+                key_name: std::env::var("TEST_VAULT_TRANSIT_KEY_NAME")
+                    .expect("TEST_VAULT_TRANSIT_KEY_NAME must be set for Vault integration tests"),
+            }
+        }
+
+        #[tokio::test]
+        async fn test_encrypt_decrypt_roundtrip_with_vault_transit() {
+            shared::setup_test!();
+
+            let parent_key = vault_transit_key();
+            let test_data = "This is a test DEK for envelope encryption";
+
+            let encrypted_key = encrypt_dek(&parent_key, test_data.to_string())
+                .await
+                .expect("Encryption should succeed");
+
+            // The stored value is the base64 encoded `vault:v1:...` ciphertext
+            let ciphertext = base64::Engine::decode(
+                &base64::engine::general_purpose::STANDARD,
+                &encrypted_key.0,
+            )
+            .expect("Encrypted key should be valid base64");
+            assert!(
+                String::from_utf8(ciphertext)
+                    .unwrap()
+                    .starts_with("vault:v")
+            );
+
+            let decrypted_key = decrypt_dek(&parent_key, &encrypted_key)
+                .await
+                .expect("Decryption should succeed");
+            assert_eq!(decrypted_key.0, test_data.as_bytes());
+        }
+    }
+
+    /// Runs against a real Azure Key Vault RSA key. Enable with
+    /// `--features azure_integration_test`, sign in through any source of the default Azure
+    /// credential chain (e.g. `az login`) and set `TEST_AZURE_KEY_VAULT_URL`,
+    /// `TEST_AZURE_KEY_VAULT_KEY_NAME` and `TEST_AZURE_KEY_VAULT_KEY_VERSION`.
+    #[cfg(feature = "azure_integration_test")]
+    mod azure_integration {
+        use crate::logic::envelope::{EnvelopeEncryptionKeyContents, decrypt_dek, encrypt_dek};
+
+        fn azure_key_vault_key() -> EnvelopeEncryptionKeyContents {
+            EnvelopeEncryptionKeyContents::AzureKeyVault {
+                vault_url: std::env::var("TEST_AZURE_KEY_VAULT_URL")
+                    .expect("TEST_AZURE_KEY_VAULT_URL must be set for Azure integration tests"),
+                key_name: std::env::var("TEST_AZURE_KEY_VAULT_KEY_NAME").expect(
+                    "TEST_AZURE_KEY_VAULT_KEY_NAME must be set for Azure integration tests",
+                ),
+                key_version: std::env::var("TEST_AZURE_KEY_VAULT_KEY_VERSION").expect(
+                    "TEST_AZURE_KEY_VAULT_KEY_VERSION must be set for Azure integration tests",
+                ),
+            }
+        }
+
+        #[tokio::test]
+        async fn test_encrypt_decrypt_roundtrip_with_azure_key_vault() {
+            shared::setup_test!();
+
+            let long_key = "A".repeat(100);
+            let test_cases = vec![
+                "Simple test key",
+                "Key with special characters: !@#$%^&*()_+-=[]{}|;:',.<>?",
+                long_key.as_str(),
+            ];
+            let parent_key = azure_key_vault_key();
+
+            for test_data in test_cases {
+                let encrypted_key = encrypt_dek(&parent_key, test_data.to_string())
+                    .await
+                    .expect("Encryption should succeed");
+                assert!(
+                    base64::Engine::decode(
+                        &base64::engine::general_purpose::STANDARD,
+                        &encrypted_key.0
+                    )
+                    .is_ok(),
+                    "Encrypted key should be valid base64"
+                );
+                assert_ne!(encrypted_key.0, test_data);
+
+                let decrypted_key = decrypt_dek(&parent_key, &encrypted_key)
+                    .await
+                    .expect("Decryption should succeed");
+                assert_eq!(
+                    decrypted_key.0,
+                    test_data.as_bytes(),
+                    "Roundtrip should preserve data for: {test_data}"
+                );
+            }
+        }
+    }
 }
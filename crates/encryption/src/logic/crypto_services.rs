@@ -2,13 +2,20 @@ use dashmap::DashMap;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use shared::error::CommonError;
+use std::collections::HashSet;
 use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
 use utoipa::ToSchema;
 
 use crate::logic::dek::{DataEncryptionKey, DecryptedDataEncryptionKey};
 use crate::logic::envelope::{
-    EnvelopeEncryptionKey, EnvelopeEncryptionKeyContents, decrypt_dek,
-    get_local_envelope_encryption_key, get_or_create_local_envelope_encryption_key,
+    DecryptionError, EnvelopeEncryptionKey, EnvelopeEncryptionKeyContents,
+    decode_nonce_prefixed_ciphertext, decrypt_dek, get_local_envelope_encryption_key,
+    get_or_create_local_envelope_encryption_key,
+};
+use crate::logic::metrics::{
+    EncryptionOperation, record_cache_hit, record_cache_miss, record_operation,
 };
 use crate::repository::DataEncryptionKeyRepositoryLike;
 
@@ -51,6 +58,33 @@ impl CryptoService {
                 &data_encryption_key.envelope_encryption_key_id
         {
             envelop_key_match = arn == &aws_kms.arn && region == &aws_kms.region;
+        } else if let EnvelopeEncryptionKeyContents::GcpKms { key_name, location } =
+            &envelope_encryption_key_contents
+            && let EnvelopeEncryptionKey::GcpKms(gcp_kms) =
+                &data_encryption_key.envelope_encryption_key_id
+        {
+            envelop_key_match = key_name == &gcp_kms.key_name && location == &gcp_kms.location;
+        } else if let EnvelopeEncryptionKeyContents::VaultTransit {
+            address,
+            mount,
+            key_name,
+        } = &envelope_encryption_key_contents
+            && let EnvelopeEncryptionKey::VaultTransit(vault) =
+                &data_encryption_key.envelope_encryption_key_id
+        {
+            envelop_key_match =
+                address == &vault.address && mount == &vault.mount && key_name == &vault.key_name;
+        } else if let EnvelopeEncryptionKeyContents::AzureKeyVault {
+            vault_url,
+            key_name,
+            key_version,
+        } = &envelope_encryption_key_contents
+            && let EnvelopeEncryptionKey::AzureKeyVault(azure) =
+                &data_encryption_key.envelope_encryption_key_id
+        {
+            envelop_key_match = vault_url == &azure.vault_url
+                && key_name == &azure.key_name
+                && key_version == &azure.key_version;
         }
 
         if !envelop_key_match {
@@ -69,6 +103,21 @@ impl CryptoService {
             cached_decrypted_dek: decrypted_dek,
         })
     }
+
+    /// Builds a service for the rotated `data_encryption_key` and pairs it with this one, so
+    /// ciphertext written with the current DEK can be moved to the new DEK
+    pub async fn rotate(
+        &self,
+        envelope_encryption_key_contents: EnvelopeEncryptionKeyContents,
+        data_encryption_key: DataEncryptionKey,
+    ) -> Result<ReencryptionService, CommonError> {
+        let rotated =
+            CryptoService::new(envelope_encryption_key_contents, data_encryption_key).await?;
+        Ok(ReencryptionService {
+            decryption_service: DecryptionService::new(self.clone()),
+            encryption_service: EncryptionService::new(rotated),
+        })
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -80,6 +129,18 @@ impl EncryptionService {
     }
 
     pub async fn encrypt_data(&self, data: String) -> Result<EncryptedString, CommonError> {
+        let started = Instant::now();
+        let result = self.encrypt_data_inner(data);
+        record_operation(
+            (&self.0.data_encryption_key.envelope_encryption_key_id).into(),
+            EncryptionOperation::EncryptData,
+            started,
+            &result,
+        );
+        result
+    }
+
+    fn encrypt_data_inner(&self, data: String) -> Result<EncryptedString, CommonError> {
         use aes_gcm::{
             Aes256Gcm, Nonce,
             aead::{Aead, KeyInit, OsRng},
@@ -129,29 +190,26 @@ impl DecryptionService {
     }
 
     pub async fn decrypt_data(&self, data: EncryptedString) -> Result<String, CommonError> {
+        let started = Instant::now();
+        let result = self.decrypt_data_inner(data);
+        record_operation(
+            (&self.0.data_encryption_key.envelope_encryption_key_id).into(),
+            EncryptionOperation::DecryptData,
+            started,
+            &result,
+        );
+        result
+    }
+
+    fn decrypt_data_inner(&self, data: EncryptedString) -> Result<String, CommonError> {
         use aes_gcm::{
             Aes256Gcm, Nonce,
             aead::{Aead, KeyInit},
         };
 
-        // Base64 decode the input
-        let encrypted_data =
-            base64::Engine::decode(&base64::engine::general_purpose::STANDARD, &data.0).map_err(
-                |e| CommonError::Unknown(anyhow::anyhow!("Failed to decode base64: {e}")),
-            )?;
-
-        // Ensure we have at least the nonce (12 bytes)
-        if encrypted_data.len() < 12 {
-            return Err(CommonError::Unknown(anyhow::anyhow!(
-                "Invalid encrypted data: too short (expected at least 12 bytes for nonce)"
-            )));
-        }
-
-        // Extract the nonce (first 12 bytes)
-        let nonce = Nonce::from_slice(&encrypted_data[..12]);
-
-        // Extract the ciphertext (remaining bytes)
-        let ciphertext = &encrypted_data[12..];
+        // Split the input into nonce (first 12 bytes) and ciphertext (remaining bytes)
+        let (nonce_bytes, ciphertext) = decode_nonce_prefixed_ciphertext(&data.0)?;
+        let nonce = Nonce::from_slice(&nonce_bytes);
 
         // Get the decrypted DEK as bytes (already Vec<u8>)
         let key_bytes = &self.0.cached_decrypted_dek.0;
@@ -167,9 +225,11 @@ impl DecryptionService {
         let cipher = Aes256Gcm::new(key);
 
         // Decrypt the ciphertext
-        let plaintext = cipher
-            .decrypt(nonce, ciphertext)
-            .map_err(|e| CommonError::Unknown(anyhow::anyhow!("Decryption failed: {e}")))?;
+        let plaintext = cipher.decrypt(nonce, ciphertext.as_ref()).map_err(|_| {
+            DecryptionError::AuthenticationFailed {
+                context: "encrypted data",
+            }
+        })?;
 
         // Convert to UTF-8 string
         let result = String::from_utf8(plaintext).map_err(|e| {
@@ -180,6 +240,25 @@ impl DecryptionService {
     }
 }
 
+/// Decrypts with the current DEK and encrypts with the rotated one. Created by
+/// [`CryptoService::rotate`].
+#[derive(Clone, Debug)]
+pub struct ReencryptionService {
+    pub decryption_service: DecryptionService,
+    pub encryption_service: EncryptionService,
+}
+
+impl ReencryptionService {
+    /// Re-encrypts ciphertext written with the current DEK under the rotated DEK
+    pub async fn reencrypt_data(
+        &self,
+        data: EncryptedString,
+    ) -> Result<EncryptedString, CommonError> {
+        let plaintext = self.decryption_service.decrypt_data(data).await?;
+        self.encryption_service.encrypt_data(plaintext).await
+    }
+}
+
 /// Get a crypto service for a given data encryption key (by ID or alias)
 pub async fn get_crypto_service<R: DataEncryptionKeyRepositoryLike>(
     envelope_encryption_key_contents: &EnvelopeEncryptionKeyContents,
@@ -228,18 +307,53 @@ pub async fn get_decryption_service(
     get_decryption_service_cached(cache, dek_id).await
 }
 
-/// Crypto cache structure for managing encryption and decryption services
+/// How long a cached crypto service is reused before its DEK is reloaded from the repository
+pub const DEFAULT_CRYPTO_CACHE_TTL: Duration = Duration::from_secs(60 * 60);
+/// Maximum number of cached encryption (and, separately, decryption) services
+pub const DEFAULT_CRYPTO_CACHE_MAX_ENTRIES: usize = 1024;
+
+#[derive(Clone)]
+struct CacheEntry<T> {
+    service: T,
+    inserted_at: Instant,
+}
+
+/// Crypto cache structure for managing encryption and decryption services.
+/// Entries expire after `ttl` and each map holds at most `max_entries` services; when full, the
+/// oldest entry is evicted.
 #[derive(Clone)]
 pub struct CryptoCache {
-    encryption_services: DashMap<String, EncryptionService>,
-    decryption_services: DashMap<String, DecryptionService>,
+    encryption_services: DashMap<String, CacheEntry<EncryptionService>>,
+    decryption_services: DashMap<String, CacheEntry<DecryptionService>>,
     repository: Arc<dyn DataEncryptionKeyRepositoryLike + Send + Sync>,
     local_envelope_encryption_key_path: std::path::PathBuf,
+    warm: Arc<AtomicBool>,
+    ttl: Duration,
+    max_entries: usize,
 }
 
 impl CryptoCache {
-    /// Create a new empty crypto cache with the given repository and local key path
+    /// Create a new empty crypto cache with the given repository and local key path, using
+    /// [`DEFAULT_CRYPTO_CACHE_TTL`] and [`DEFAULT_CRYPTO_CACHE_MAX_ENTRIES`]
     pub fn new<R>(repo: R, local_envelope_encryption_key_path: std::path::PathBuf) -> Self
+    where
+        R: DataEncryptionKeyRepositoryLike + Send + Sync + 'static,
+    {
+        Self::new_with_config(
+            repo,
+            local_envelope_encryption_key_path,
+            DEFAULT_CRYPTO_CACHE_TTL,
+            DEFAULT_CRYPTO_CACHE_MAX_ENTRIES,
+        )
+    }
+
+    /// Create a new empty crypto cache with an explicit entry TTL and size bound
+    pub fn new_with_config<R>(
+        repo: R,
+        local_envelope_encryption_key_path: std::path::PathBuf,
+        ttl: Duration,
+        max_entries: usize,
+    ) -> Self
     where
         R: DataEncryptionKeyRepositoryLike + Send + Sync + 'static,
     {
@@ -248,18 +362,39 @@ impl CryptoCache {
             decryption_services: DashMap::new(),
             repository: Arc::new(repo),
             local_envelope_encryption_key_path,
+            warm: Arc::new(AtomicBool::new(false)),
+            ttl,
+            max_entries: max_entries.max(1),
         }
     }
 
-    /// Invalidate the cache for a specific DEK ID
-    /// This removes both encryption and decryption services for the given DEK,
-    /// forcing them to be recreated on the next access
-    pub fn invalidate_cache(&self, dek_id: &str) {
-        let encryption_key = format!("encryption.{dek_id}");
-        let decryption_key = format!("decryption.{dek_id}");
+    /// Whether [`init_crypto_cache`] has loaded every DEK into this cache
+    pub fn is_warm(&self) -> bool {
+        self.warm.load(Ordering::Acquire)
+    }
+
+    /// Invalidate the cache for a DEK ID or alias.
+    /// This removes the encryption and decryption services cached under that key, plus any other
+    /// entries backed by the same DEK (e.g. aliases of a rotated DEK), forcing them to be
+    /// recreated on the next access
+    pub fn invalidate(&self, dek_id_or_alias: &str) {
+        let encryption_key = format!("encryption.{dek_id_or_alias}");
+        let decryption_key = format!("decryption.{dek_id_or_alias}");
+
+        let mut dek_ids = HashSet::from([dek_id_or_alias.to_string()]);
+        if let Some(entry) = self.encryption_services.get(&encryption_key) {
+            dek_ids.insert(entry.service.0.data_encryption_key.id.clone());
+        }
+        if let Some(entry) = self.decryption_services.get(&decryption_key) {
+            dek_ids.insert(entry.service.0.data_encryption_key.id.clone());
+        }
 
-        self.encryption_services.remove(&encryption_key);
-        self.decryption_services.remove(&decryption_key);
+        self.encryption_services.retain(|key, entry| {
+            key != &encryption_key && !dek_ids.contains(&entry.service.0.data_encryption_key.id)
+        });
+        self.decryption_services.retain(|key, entry| {
+            key != &decryption_key && !dek_ids.contains(&entry.service.0.data_encryption_key.id)
+        });
     }
 
     /// Clear the entire cache
@@ -269,6 +404,41 @@ impl CryptoCache {
         self.decryption_services.clear();
     }
 
+    fn lookup<T: Clone>(&self, map: &DashMap<String, CacheEntry<T>>, key: &str) -> Option<T> {
+        let hit = map.get(key).and_then(|entry| {
+            (entry.inserted_at.elapsed() < self.ttl).then(|| entry.service.clone())
+        });
+        if hit.is_none() {
+            map.remove_if(key, |_, entry| entry.inserted_at.elapsed() >= self.ttl);
+        }
+        hit
+    }
+
+    fn store<T>(&self, map: &DashMap<String, CacheEntry<T>>, key: String, service: T) {
+        if !map.contains_key(&key) && map.len() >= self.max_entries {
+            map.retain(|_, entry| entry.inserted_at.elapsed() < self.ttl);
+            while map.len() >= self.max_entries {
+                let oldest = map
+                    .iter()
+                    .min_by_key(|entry| entry.inserted_at)
+                    .map(|entry| entry.key().clone());
+                match oldest {
+                    Some(oldest) => {
+                        map.remove(&oldest);
+                    }
+                    None => break,
+                }
+            }
+        }
+        map.insert(
+            key,
+            CacheEntry {
+                service,
+                inserted_at: Instant::now(),
+            },
+        );
+    }
+
     /// Get an encryption service from the cache by DEK ID or alias
     pub async fn get_encryption_service(
         &self,
@@ -300,6 +470,7 @@ pub async fn init_crypto_cache(cache: &CryptoCache) -> Result<(), CommonError> {
             .list_data_encryption_keys(&PaginationRequest {
                 page_size: 100,
                 next_page_token: page_token.clone(),
+                ..Default::default()
             })
             .await?;
 
@@ -328,6 +499,24 @@ pub async fn init_crypto_cache(cache: &CryptoCache) -> Result<(), CommonError> {
                 arn: aws_kms.arn.clone(),
                 region: aws_kms.region.clone(),
             },
+            EnvelopeEncryptionKey::GcpKms(gcp_kms) => EnvelopeEncryptionKeyContents::GcpKms {
+                key_name: gcp_kms.key_name.clone(),
+                location: gcp_kms.location.clone(),
+            },
+            EnvelopeEncryptionKey::VaultTransit(vault) => {
+                EnvelopeEncryptionKeyContents::VaultTransit {
+                    address: vault.address.clone(),
+                    mount: vault.mount.clone(),
+                    key_name: vault.key_name.clone(),
+                }
+            }
+            EnvelopeEncryptionKey::AzureKeyVault(azure) => {
+                EnvelopeEncryptionKeyContents::AzureKeyVault {
+                    vault_url: azure.vault_url.clone(),
+                    key_name: azure.key_name.clone(),
+                    key_version: azure.key_version.clone(),
+                }
+            }
             EnvelopeEncryptionKey::Local(local) => {
                 // Resolve the filename relative to local_envelope_encryption_key_path
                 let key_path = cache
@@ -342,18 +531,22 @@ pub async fn init_crypto_cache(cache: &CryptoCache) -> Result<(), CommonError> {
 
         // Create and cache encryption service
         let encryption_service = EncryptionService::new(crypto_service.clone());
-        cache.encryption_services.insert(
+        cache.store(
+            &cache.encryption_services,
             format!("encryption.{}", crypto_service.data_encryption_key.id),
             encryption_service,
         );
 
         // Create and cache decryption service
         let decryption_service = DecryptionService::new(crypto_service);
-        cache
-            .decryption_services
-            .insert(format!("decryption.{}", dek.id), decryption_service);
+        cache.store(
+            &cache.decryption_services,
+            format!("decryption.{}", dek.id),
+            decryption_service,
+        );
     }
 
+    cache.warm.store(true, Ordering::Release);
     Ok(())
 }
 
@@ -364,9 +557,11 @@ pub async fn get_encryption_service_cached(
 ) -> Result<EncryptionService, CommonError> {
     // Try to get from cache using the provided key (could be ID or alias)
     let cache_key = format!("encryption.{dek_id_or_alias}");
-    if let Some(service) = cache.encryption_services.get(&cache_key) {
-        return Ok((*service).clone());
+    if let Some(service) = cache.lookup(&cache.encryption_services, &cache_key) {
+        record_cache_hit();
+        return Ok(service);
     }
+    record_cache_miss();
 
     // Not in cache, resolve the alias or ID to get the actual DEK
     let dek =
@@ -379,6 +574,22 @@ pub async fn get_encryption_service_cached(
             arn: aws_kms.arn.clone(),
             region: aws_kms.region.clone(),
         },
+        EnvelopeEncryptionKey::GcpKms(gcp_kms) => EnvelopeEncryptionKeyContents::GcpKms {
+            key_name: gcp_kms.key_name.clone(),
+            location: gcp_kms.location.clone(),
+        },
+        EnvelopeEncryptionKey::VaultTransit(vault) => EnvelopeEncryptionKeyContents::VaultTransit {
+            address: vault.address.clone(),
+            mount: vault.mount.clone(),
+            key_name: vault.key_name.clone(),
+        },
+        EnvelopeEncryptionKey::AzureKeyVault(azure) => {
+            EnvelopeEncryptionKeyContents::AzureKeyVault {
+                vault_url: azure.vault_url.clone(),
+                key_name: azure.key_name.clone(),
+                key_version: azure.key_version.clone(),
+            }
+        }
         EnvelopeEncryptionKey::Local(local) => {
             // Resolve the filename relative to local_envelope_encryption_key_path
             let key_path = cache
@@ -395,9 +606,11 @@ pub async fn get_encryption_service_cached(
     let encryption_service = EncryptionService::new(crypto_service.clone());
 
     // Cache it using the key that was requested (could be alias or ID)
-    cache
-        .encryption_services
-        .insert(cache_key, encryption_service.clone());
+    cache.store(
+        &cache.encryption_services,
+        cache_key,
+        encryption_service.clone(),
+    );
 
     Ok(encryption_service)
 }
@@ -409,9 +622,11 @@ pub async fn get_decryption_service_cached(
 ) -> Result<DecryptionService, CommonError> {
     // Try to get from cache using the provided key (could be ID or alias)
     let cache_key = format!("decryption.{dek_id_or_alias}");
-    if let Some(service) = cache.decryption_services.get(&cache_key) {
-        return Ok((*service).clone());
+    if let Some(service) = cache.lookup(&cache.decryption_services, &cache_key) {
+        record_cache_hit();
+        return Ok(service);
     }
+    record_cache_miss();
 
     // Not in cache, resolve the alias or ID to get the actual DEK
     let dek =
@@ -424,6 +639,22 @@ pub async fn get_decryption_service_cached(
             arn: aws_kms.arn.clone(),
             region: aws_kms.region.clone(),
         },
+        EnvelopeEncryptionKey::GcpKms(gcp_kms) => EnvelopeEncryptionKeyContents::GcpKms {
+            key_name: gcp_kms.key_name.clone(),
+            location: gcp_kms.location.clone(),
+        },
+        EnvelopeEncryptionKey::VaultTransit(vault) => EnvelopeEncryptionKeyContents::VaultTransit {
+            address: vault.address.clone(),
+            mount: vault.mount.clone(),
+            key_name: vault.key_name.clone(),
+        },
+        EnvelopeEncryptionKey::AzureKeyVault(azure) => {
+            EnvelopeEncryptionKeyContents::AzureKeyVault {
+                vault_url: azure.vault_url.clone(),
+                key_name: azure.key_name.clone(),
+                key_version: azure.key_version.clone(),
+            }
+        }
         EnvelopeEncryptionKey::Local(local) => {
             // Resolve the filename relative to local_envelope_encryption_key_path
             let key_path = cache
@@ -440,9 +671,11 @@ pub async fn get_decryption_service_cached(
     let decryption_service = DecryptionService::new(crypto_service);
 
     // Cache it using the key that was requested (could be alias or ID)
-    cache
-        .decryption_services
-        .insert(cache_key, decryption_service.clone());
+    cache.store(
+        &cache.decryption_services,
+        cache_key,
+        decryption_service.clone(),
+    );
 
     Ok(decryption_service)
 }
@@ -603,6 +836,70 @@ mod tests {
             assert_eq!(decrypted, "test message");
         }
 
+        async fn local_dek(
+            envelope_key_contents: &EnvelopeEncryptionKeyContents,
+            id: &str,
+        ) -> crate::logic::dek::DataEncryptionKey {
+            use rand::RngCore;
+            let mut dek_bytes = vec![0u8; 32];
+            rand::thread_rng().fill_bytes(&mut dek_bytes);
+            let dek_string = unsafe { String::from_utf8_unchecked(dek_bytes) };
+
+            crate::logic::dek::DataEncryptionKey {
+                id: id.to_string(),
+                envelope_encryption_key_id: crate::logic::envelope::EnvelopeEncryptionKey::from(
+                    envelope_key_contents.clone(),
+                ),
+                encrypted_data_encryption_key: encrypt_dek(envelope_key_contents, dek_string)
+                    .await
+                    .unwrap(),
+                created_at: WrappedChronoDateTime::now(),
+                updated_at: WrappedChronoDateTime::now(),
+            }
+        }
+
+        #[tokio::test]
+        async fn test_rotate_reencrypts_under_new_dek() {
+            shared::setup_test!();
+
+            let temp_dir = tempfile::TempDir::new().expect("Failed to create temp dir");
+            let path = temp_dir.path().join("test-key");
+            let envelope_key_contents = get_or_create_local_envelope_encryption_key(&path).unwrap();
+
+            let service_a = CryptoService::new(
+                envelope_key_contents.clone(),
+                local_dek(&envelope_key_contents, "dek-a").await,
+            )
+            .await
+            .unwrap();
+            let encrypted_a = EncryptionService::new(service_a.clone())
+                .encrypt_data("secret".to_string())
+                .await
+                .unwrap();
+
+            let dek_b = local_dek(&envelope_key_contents, "dek-b").await;
+            let reencryption = service_a
+                .rotate(envelope_key_contents.clone(), dek_b.clone())
+                .await
+                .unwrap();
+            let encrypted_b = reencryption.reencrypt_data(encrypted_a).await.unwrap();
+
+            let service_b = CryptoService::new(envelope_key_contents.clone(), dek_b)
+                .await
+                .unwrap();
+            let decrypted = DecryptionService::new(service_b)
+                .decrypt_data(encrypted_b.clone())
+                .await
+                .unwrap();
+            assert_eq!(decrypted, "secret");
+
+            // The old DEK can no longer read the re-encrypted value
+            let result = DecryptionService::new(service_a)
+                .decrypt_data(encrypted_b)
+                .await;
+            assert!(result.is_err());
+        }
+
         #[tokio::test]
         async fn test_init_crypto_cache() {
             shared::setup_test!();
@@ -692,6 +989,87 @@ mod tests {
             assert_eq!(decrypted2, "message 2");
         }
 
+        #[tokio::test]
+        async fn test_encrypt_decrypt_record_metrics() {
+            use crate::logic::metrics::{
+                EncryptionBackend, EncryptionOperation, encryption_metrics,
+            };
+
+            shared::setup_test!();
+
+            let (_db, conn) = setup_in_memory_database(vec![Repository::load_sql_migrations()])
+                .await
+                .unwrap();
+            let repo = Repository::new(conn);
+            let (tx, _rx) = broadcast::channel(100);
+
+            let temp_dir = tempfile::TempDir::new().expect("Failed to create temp dir");
+            let path = temp_dir.path().join("test-key");
+            let envelope_key_contents = get_or_create_local_envelope_encryption_key(&path).unwrap();
+            let envelope_key =
+                crate::logic::envelope::EnvelopeEncryptionKey::from(envelope_key_contents.clone());
+            let create_params = crate::repository::CreateEnvelopeEncryptionKey::from((
+                envelope_key.clone(),
+                WrappedChronoDateTime::now(),
+            ));
+            EncryptionKeyRepositoryLike::create_envelope_encryption_key(&repo, &create_params)
+                .await
+                .unwrap();
+
+            let dek = crate::logic::dek::create_data_encryption_key(
+                &tx,
+                &repo,
+                CreateDekParams {
+                    envelope_encryption_key_id: envelope_key.id(),
+                    inner: CreateDekInnerParams {
+                        id: Some("test-dek-metrics".to_string()),
+                        encrypted_dek: None,
+                    },
+                },
+                temp_dir.path(),
+                false,
+            )
+            .await
+            .unwrap();
+
+            let cache = CryptoCache::new(repo.clone(), temp_dir.path().to_path_buf());
+            let before = encryption_metrics();
+
+            // First lookup misses and unwraps the DEK, second lookup is served from the cache
+            let encryption_service = get_encryption_service(&cache, &dek.id).await.unwrap();
+            let _ = get_encryption_service(&cache, &dek.id).await.unwrap();
+            let decryption_service = get_decryption_service(&cache, &dek.id).await.unwrap();
+
+            let encrypted = encryption_service
+                .encrypt_data("metrics".to_string())
+                .await
+                .unwrap();
+            decryption_service.decrypt_data(encrypted).await.unwrap();
+
+            let after = encryption_metrics();
+            let calls = |snapshot: &crate::logic::metrics::EncryptionMetricsSnapshot, operation| {
+                snapshot
+                    .operation(EncryptionBackend::Local, operation)
+                    .calls
+            };
+
+            // Metrics are process-wide and other tests run concurrently, so compare lower bounds
+            assert!(
+                calls(&after, EncryptionOperation::EncryptData)
+                    > calls(&before, EncryptionOperation::EncryptData)
+            );
+            assert!(
+                calls(&after, EncryptionOperation::DecryptData)
+                    > calls(&before, EncryptionOperation::DecryptData)
+            );
+            assert!(
+                calls(&after, EncryptionOperation::UnwrapDek)
+                    >= calls(&before, EncryptionOperation::UnwrapDek) + 2
+            );
+            assert!(after.cache_hits > before.cache_hits);
+            assert!(after.cache_misses >= before.cache_misses + 2);
+        }
+
         #[tokio::test]
         async fn test_get_encryption_service_cached_miss() {
             shared::setup_test!();
@@ -754,6 +1132,155 @@ mod tests {
             assert!(!encrypted2.0.is_empty());
         }
 
+        async fn setup_cache_with_dek(
+            ttl: Duration,
+            max_entries: usize,
+            dek_ids: &[&str],
+        ) -> (CryptoCache, Repository, tempfile::TempDir) {
+            let (_db, conn) = setup_in_memory_database(vec![Repository::load_sql_migrations()])
+                .await
+                .unwrap();
+            let repo = Repository::new(conn);
+            let (tx, _rx) = broadcast::channel(100);
+
+            let temp_dir = tempfile::TempDir::new().expect("Failed to create temp dir");
+            let path = temp_dir.path().join("test-key");
+            let envelope_key_contents = get_or_create_local_envelope_encryption_key(&path).unwrap();
+            let envelope_key =
+                crate::logic::envelope::EnvelopeEncryptionKey::from(envelope_key_contents);
+            let create_params = crate::repository::CreateEnvelopeEncryptionKey::from((
+                envelope_key.clone(),
+                WrappedChronoDateTime::now(),
+            ));
+            EncryptionKeyRepositoryLike::create_envelope_encryption_key(&repo, &create_params)
+                .await
+                .unwrap();
+
+            for dek_id in dek_ids {
+                crate::logic::dek::create_data_encryption_key(
+                    &tx,
+                    &repo,
+                    CreateDekParams {
+                        envelope_encryption_key_id: envelope_key.id(),
+                        inner: CreateDekInnerParams {
+                            id: Some(dek_id.to_string()),
+                            encrypted_dek: None,
+                        },
+                    },
+                    temp_dir.path(),
+                    false,
+                )
+                .await
+                .unwrap();
+            }
+
+            let cache = CryptoCache::new_with_config(
+                repo.clone(),
+                temp_dir.path().to_path_buf(),
+                ttl,
+                max_entries,
+            );
+            (cache, repo, temp_dir)
+        }
+
+        fn encryption_inserted_at(cache: &CryptoCache, dek_id: &str) -> Option<Instant> {
+            cache
+                .encryption_services
+                .get(&format!("encryption.{dek_id}"))
+                .map(|entry| entry.inserted_at)
+        }
+
+        #[tokio::test]
+        async fn test_crypto_cache_entries_expire_after_ttl() {
+            shared::setup_test!();
+
+            let (cache, _repo, _temp_dir) =
+                setup_cache_with_dek(Duration::from_millis(50), 16, &["test-dek-ttl"]).await;
+
+            get_encryption_service(&cache, "test-dek-ttl")
+                .await
+                .unwrap();
+            let first = encryption_inserted_at(&cache, "test-dek-ttl").unwrap();
+
+            // Within the TTL the cached entry is reused
+            get_encryption_service(&cache, "test-dek-ttl")
+                .await
+                .unwrap();
+            assert_eq!(encryption_inserted_at(&cache, "test-dek-ttl"), Some(first));
+
+            // After the TTL the service is reloaded from the repository
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            get_encryption_service(&cache, "test-dek-ttl")
+                .await
+                .unwrap();
+            assert!(encryption_inserted_at(&cache, "test-dek-ttl").unwrap() > first);
+        }
+
+        #[tokio::test]
+        async fn test_crypto_cache_is_bounded() {
+            shared::setup_test!();
+
+            let (cache, _repo, _temp_dir) = setup_cache_with_dek(
+                Duration::from_secs(60),
+                2,
+                &["test-dek-bound-1", "test-dek-bound-2", "test-dek-bound-3"],
+            )
+            .await;
+
+            for dek_id in ["test-dek-bound-1", "test-dek-bound-2", "test-dek-bound-3"] {
+                get_encryption_service(&cache, dek_id).await.unwrap();
+            }
+
+            // The oldest entry was evicted to make room
+            assert_eq!(cache.encryption_services.len(), 2);
+            assert!(encryption_inserted_at(&cache, "test-dek-bound-1").is_none());
+            assert!(encryption_inserted_at(&cache, "test-dek-bound-3").is_some());
+        }
+
+        #[tokio::test]
+        async fn test_crypto_cache_invalidate_forces_reload() {
+            shared::setup_test!();
+
+            let (cache, repo, _temp_dir) =
+                setup_cache_with_dek(Duration::from_secs(60), 16, &["test-dek-invalidate"]).await;
+            let (tx, _rx) = broadcast::channel(100);
+            crate::logic::dek_alias::create_alias(
+                &tx,
+                &repo,
+                &cache,
+                crate::logic::dek_alias::CreateAliasParams {
+                    dek_id: "test-dek-invalidate".to_string(),
+                    inner: crate::logic::dek_alias::CreateAliasInnerParams {
+                        alias: "test-alias-invalidate".to_string(),
+                    },
+                },
+            )
+            .await
+            .unwrap();
+
+            get_encryption_service(&cache, "test-dek-invalidate")
+                .await
+                .unwrap();
+            get_encryption_service(&cache, "test-alias-invalidate")
+                .await
+                .unwrap();
+            get_decryption_service(&cache, "test-dek-invalidate")
+                .await
+                .unwrap();
+            let first = encryption_inserted_at(&cache, "test-dek-invalidate").unwrap();
+
+            // Invalidating the DEK also drops entries cached under its aliases
+            cache.invalidate("test-dek-invalidate");
+            assert!(encryption_inserted_at(&cache, "test-dek-invalidate").is_none());
+            assert!(encryption_inserted_at(&cache, "test-alias-invalidate").is_none());
+            assert!(cache.decryption_services.is_empty());
+
+            get_encryption_service(&cache, "test-dek-invalidate")
+                .await
+                .unwrap();
+            assert!(encryption_inserted_at(&cache, "test-dek-invalidate").unwrap() > first);
+        }
+
         #[tokio::test]
         async fn test_get_encryption_service_not_found() {
             shared::setup_test!();
@@ -129,6 +129,22 @@ where
             arn: aws_kms.arn.clone(),
             region: aws_kms.region.clone(),
         },
+        EnvelopeEncryptionKey::GcpKms(gcp_kms) => EnvelopeEncryptionKeyContents::GcpKms {
+            key_name: gcp_kms.key_name.clone(),
+            location: gcp_kms.location.clone(),
+        },
+        EnvelopeEncryptionKey::VaultTransit(vault) => EnvelopeEncryptionKeyContents::VaultTransit {
+            address: vault.address.clone(),
+            mount: vault.mount.clone(),
+            key_name: vault.key_name.clone(),
+        },
+        EnvelopeEncryptionKey::AzureKeyVault(azure) => {
+            EnvelopeEncryptionKeyContents::AzureKeyVault {
+                vault_url: azure.vault_url.clone(),
+                key_name: azure.key_name.clone(),
+                key_version: azure.key_version.clone(),
+            }
+        }
         EnvelopeEncryptionKey::Local(local) => {
             // Resolve the filename relative to local_envelope_encryption_key_path
             let key_path = local_envelope_encryption_key_path.join(&local.file_name);
@@ -178,6 +194,48 @@ where
                 EncryptedDataEncryptionKey(encoded)
             }
 
+            EnvelopeEncryptionKeyContents::GcpKms { key_name, .. } => {
+                // --- GCP Cloud KMS path ---
+                // Cloud KMS has no data key generation, so generate the DEK locally
+                let mut dek = [0u8; 32];
+                rand::thread_rng().fill_bytes(&mut dek);
+
+                crate::logic::envelope::encrypt_with_gcp_kms(key_name, &dek).await?
+            }
+
+            EnvelopeEncryptionKeyContents::VaultTransit {
+                address,
+                mount,
+                key_name,
+            } => {
+                // --- Vault Transit path ---
+                // Wrap a locally generated DEK, like the GCP path
+                let mut dek = [0u8; 32];
+                rand::thread_rng().fill_bytes(&mut dek);
+
+                crate::logic::envelope::encrypt_with_vault_transit(address, mount, key_name, &dek)
+                    .await?
+            }
+
+            EnvelopeEncryptionKeyContents::AzureKeyVault {
+                vault_url,
+                key_name,
+                key_version,
+            } => {
+                // --- Azure Key Vault path ---
+                // Key Vault only wraps keys, so generate the DEK locally
+                let mut dek = [0u8; 32];
+                rand::thread_rng().fill_bytes(&mut dek);
+
+                crate::logic::envelope::encrypt_with_azure_key_vault(
+                    vault_url,
+                    key_name,
+                    key_version,
+                    &dek,
+                )
+                .await?
+            }
+
             EnvelopeEncryptionKeyContents::Local {
                 file_name,
                 key_bytes,
@@ -300,6 +358,22 @@ where
             arn: aws_kms.arn.clone(),
             region: aws_kms.region.clone(),
         },
+        EnvelopeEncryptionKey::GcpKms(gcp_kms) => EnvelopeEncryptionKeyContents::GcpKms {
+            key_name: gcp_kms.key_name.clone(),
+            location: gcp_kms.location.clone(),
+        },
+        EnvelopeEncryptionKey::VaultTransit(vault) => EnvelopeEncryptionKeyContents::VaultTransit {
+            address: vault.address.clone(),
+            mount: vault.mount.clone(),
+            key_name: vault.key_name.clone(),
+        },
+        EnvelopeEncryptionKey::AzureKeyVault(azure) => {
+            EnvelopeEncryptionKeyContents::AzureKeyVault {
+                vault_url: azure.vault_url.clone(),
+                key_name: azure.key_name.clone(),
+                key_version: azure.key_version.clone(),
+            }
+        }
         EnvelopeEncryptionKey::Local(local) => {
             // Resolve the filename relative to local_envelope_encryption_key_path
             let key_path = local_envelope_encryption_key_path.join(&local.file_name);
@@ -401,6 +475,7 @@ where
             .list_data_encryption_keys(&PaginationRequest {
                 page_size: 100,
                 next_page_token: page_token.clone(),
+                ..Default::default()
             })
             .await?;
 
@@ -438,6 +513,7 @@ where
     Ok(ListDekResponse {
         items,
         next_page_token,
+        total_count: Some(all_matching_items.len() as u64),
     })
 }
 
@@ -450,6 +526,25 @@ fn matches_envelope_key_id(id1: &EnvelopeEncryptionKey, id2: &EnvelopeEncryption
         (EnvelopeEncryptionKey::Local(local1), EnvelopeEncryptionKey::Local(local2)) => {
             local1.file_name == local2.file_name
         }
+        (EnvelopeEncryptionKey::GcpKms(gcp_kms1), EnvelopeEncryptionKey::GcpKms(gcp_kms2)) => {
+            gcp_kms1.key_name == gcp_kms2.key_name && gcp_kms1.location == gcp_kms2.location
+        }
+        (
+            EnvelopeEncryptionKey::VaultTransit(vault1),
+            EnvelopeEncryptionKey::VaultTransit(vault2),
+        ) => {
+            vault1.address == vault2.address
+                && vault1.mount == vault2.mount
+                && vault1.key_name == vault2.key_name
+        }
+        (
+            EnvelopeEncryptionKey::AzureKeyVault(azure1),
+            EnvelopeEncryptionKey::AzureKeyVault(azure2),
+        ) => {
+            azure1.vault_url == azure2.vault_url
+                && azure1.key_name == azure2.key_name
+                && azure1.key_version == azure2.key_version
+        }
         _ => false,
     }
 }
@@ -764,6 +859,7 @@ mod tests {
                     inner: PaginationRequest {
                         page_size: 100,
                         next_page_token: None,
+                        ..Default::default()
                     },
                 },
             )
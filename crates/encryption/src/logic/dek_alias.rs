@@ -69,8 +69,8 @@ where
     repo.create_data_encryption_key_alias(&alias).await?;
 
     // Invalidate cache entries for both the DEK ID and the new alias
-    cache.invalidate_cache(&dek_id);
-    cache.invalidate_cache(&alias_name);
+    cache.invalidate(&dek_id);
+    cache.invalidate(&alias_name);
 
     // Publish event with full DEK data
     let _ = on_change_tx.send(EncryptionKeyEvent::DataEncryptionKeyAliasAdded {
@@ -104,8 +104,8 @@ where
     repo.delete_data_encryption_key_alias(&alias_name).await?;
 
     // Invalidate cache entries for both the alias and the DEK ID
-    cache.invalidate_cache(&alias_name);
-    cache.invalidate_cache(&dek_id);
+    cache.invalidate(&alias_name);
+    cache.invalidate(&dek_id);
 
     // Publish event to trigger cache refresh
     let _ =
@@ -153,9 +153,9 @@ where
         .await?;
 
     // Invalidate cache entries for the alias and both old and new DEK IDs
-    cache.invalidate_cache(&alias_name);
-    cache.invalidate_cache(&old_dek_id);
-    cache.invalidate_cache(&new_dek_id);
+    cache.invalidate(&alias_name);
+    cache.invalidate(&old_dek_id);
+    cache.invalidate(&new_dek_id);
 
     // Publish event with full DEK data
     let _ = on_change_tx.send(EncryptionKeyEvent::DataEncryptionKeyAliasUpdated {
@@ -13,6 +13,7 @@ use shared::{
     primitives::{PaginationRequest, WrappedChronoDateTime},
 };
 use std::path::PathBuf;
+use std::time::Instant;
 use tracing::trace;
 use utoipa::ToSchema;
 
@@ -20,6 +21,7 @@ use super::{EncryptionKeyEvent, EncryptionKeyEventSender};
 use crate::logic::dek::{
     DataEncryptionKey, DecryptedDataEncryptionKey, EncryptedDataEncryptionKey,
 };
+use crate::logic::metrics::{EncryptionOperation, record_operation};
 use crate::repository::{
     CreateEnvelopeEncryptionKey, DataEncryptionKeyRepositoryLike, EncryptionKeyRepositoryLike,
 };
@@ -35,11 +37,40 @@ pub struct EnvelopeEncryptionKeyLocal {
     pub file_name: String,
 }
 
+/// A GCP Cloud KMS key. `key_name` is the full resource name of the crypto key
+/// (`projects/PROJECT/locations/LOCATION/keyRings/KEY_RING/cryptoKeys/KEY`).
+#[derive(Serialize, Deserialize, Clone, Debug, JsonSchema, ToSchema)]
+pub struct EnvelopeEncryptionKeyGcpKms {
+    pub key_name: String,
+    pub location: String,
+}
+
+/// A HashiCorp Vault Transit key. `address` is the Vault server URL and `mount` the path the
+/// transit secrets engine is mounted at.
+#[derive(Serialize, Deserialize, Clone, Debug, JsonSchema, ToSchema)]
+pub struct EnvelopeEncryptionKeyVaultTransit {
+    pub address: String,
+    pub mount: String,
+    pub key_name: String,
+}
+
+/// An Azure Key Vault RSA key. `vault_url` is the vault's base URL, e.g.
+/// `https://my-vault.vault.azure.net`, and `key_version` pins the key version DEKs are wrapped with.
+#[derive(Serialize, Deserialize, Clone, Debug, JsonSchema, ToSchema)]
+pub struct EnvelopeEncryptionKeyAzureKeyVault {
+    pub vault_url: String,
+    pub key_name: String,
+    pub key_version: String,
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, JsonSchema, ToSchema)]
 #[serde(rename_all = "snake_case", tag = "type")]
 pub enum EnvelopeEncryptionKey {
     AwsKms(EnvelopeEncryptionKeyAwsKms),
     Local(EnvelopeEncryptionKeyLocal),
+    GcpKms(EnvelopeEncryptionKeyGcpKms),
+    VaultTransit(EnvelopeEncryptionKeyVaultTransit),
+    AzureKeyVault(EnvelopeEncryptionKeyAzureKeyVault),
 }
 
 impl EnvelopeEncryptionKey {
@@ -47,10 +78,34 @@ impl EnvelopeEncryptionKey {
         match self {
             EnvelopeEncryptionKey::AwsKms(aws_kms) => aws_kms.arn.clone(),
             EnvelopeEncryptionKey::Local(local) => local.file_name.clone(),
+            EnvelopeEncryptionKey::GcpKms(gcp_kms) => gcp_kms.key_name.clone(),
+            EnvelopeEncryptionKey::VaultTransit(vault) => {
+                vault_transit_key_id(&vault.address, &vault.mount, &vault.key_name)
+            }
+            EnvelopeEncryptionKey::AzureKeyVault(azure) => {
+                azure_key_vault_key_id(&azure.vault_url, &azure.key_name, &azure.key_version)
+            }
         }
     }
 }
 
+/// The id of a Vault Transit key: the URL of the key in the Vault API
+pub fn vault_transit_key_id(address: &str, mount: &str, key_name: &str) -> String {
+    format!(
+        "{}/v1/{}/keys/{key_name}",
+        address.trim_end_matches('/'),
+        mount.trim_matches('/')
+    )
+}
+
+/// The id of an Azure Key Vault key: the key identifier (`kid`) of the key version
+pub fn azure_key_vault_key_id(vault_url: &str, key_name: &str, key_version: &str) -> String {
+    format!(
+        "{}/keys/{key_name}/{key_version}",
+        vault_url.trim_end_matches('/')
+    )
+}
+
 #[derive(Clone, zeroize::Zeroize, zeroize::ZeroizeOnDrop)]
 pub enum EnvelopeEncryptionKeyContents {
     AwsKms {
@@ -61,6 +116,20 @@ pub enum EnvelopeEncryptionKeyContents {
         file_name: String,
         key_bytes: Vec<u8>,
     },
+    GcpKms {
+        key_name: String,
+        location: String,
+    },
+    VaultTransit {
+        address: String,
+        mount: String,
+        key_name: String,
+    },
+    AzureKeyVault {
+        vault_url: String,
+        key_name: String,
+        key_version: String,
+    },
 }
 
 impl From<EnvelopeEncryptionKeyContents> for EnvelopeEncryptionKey {
@@ -78,6 +147,30 @@ impl From<EnvelopeEncryptionKeyContents> for EnvelopeEncryptionKey {
             } => EnvelopeEncryptionKey::Local(EnvelopeEncryptionKeyLocal {
                 file_name: file_name.clone(),
             }),
+            EnvelopeEncryptionKeyContents::GcpKms { key_name, location } => {
+                EnvelopeEncryptionKey::GcpKms(EnvelopeEncryptionKeyGcpKms {
+                    key_name: key_name.clone(),
+                    location: location.clone(),
+                })
+            }
+            EnvelopeEncryptionKeyContents::VaultTransit {
+                address,
+                mount,
+                key_name,
+            } => EnvelopeEncryptionKey::VaultTransit(EnvelopeEncryptionKeyVaultTransit {
+                address: address.clone(),
+                mount: mount.clone(),
+                key_name: key_name.clone(),
+            }),
+            EnvelopeEncryptionKeyContents::AzureKeyVault {
+                vault_url,
+                key_name,
+                key_version,
+            } => EnvelopeEncryptionKey::AzureKeyVault(EnvelopeEncryptionKeyAzureKeyVault {
+                vault_url: vault_url.clone(),
+                key_name: key_name.clone(),
+                key_version: key_version.clone(),
+            }),
         }
     }
 }
@@ -118,6 +211,25 @@ pub fn extract_region_from_kms_arn(arn: &str) -> Result<String, CommonError> {
     }
 }
 
+/// Extract the GCP location from a Cloud KMS key resource name
+/// Format: projects/PROJECT/locations/LOCATION/keyRings/KEY_RING/cryptoKeys/KEY
+pub fn extract_location_from_gcp_key_name(key_name: &str) -> Result<String, CommonError> {
+    let parts: Vec<&str> = key_name.split('/').collect();
+    if parts.len() == 8
+        && parts[0] == "projects"
+        && parts[2] == "locations"
+        && parts[4] == "keyRings"
+        && parts[6] == "cryptoKeys"
+        && parts.iter().all(|part| !part.is_empty())
+    {
+        Ok(parts[3].to_string())
+    } else {
+        Err(CommonError::Unknown(anyhow::anyhow!(
+            "Invalid GCP KMS key name format: {key_name}"
+        )))
+    }
+}
+
 impl libsql::FromValue for EnvelopeEncryptionKey {
     fn from_sql(val: libsql::Value) -> libsql::Result<Self>
     where
@@ -181,10 +293,27 @@ pub async fn create_envelope_encryption_key(
     trace!(key_id = %params.id(), "Creating envelope encryption key");
     let now = WrappedChronoDateTime::now();
 
-    if let EnvelopeEncryptionKey::Local(local) = &params {
-        get_or_create_local_envelope_encryption_key(
-            &local_envelope_encryption_key_path.join(&local.file_name),
-        )?;
+    match &params {
+        EnvelopeEncryptionKey::Local(local) => {
+            get_or_create_local_envelope_encryption_key(
+                &local_envelope_encryption_key_path.join(&local.file_name),
+            )?;
+        }
+        EnvelopeEncryptionKey::GcpKms(gcp_kms) => {
+            let location = extract_location_from_gcp_key_name(&gcp_kms.key_name)?;
+            if location != gcp_kms.location {
+                return Err(CommonError::InvalidRequest {
+                    msg: format!(
+                        "GCP KMS key {} is in location {location}, not {}",
+                        gcp_kms.key_name, gcp_kms.location
+                    ),
+                    source: None,
+                });
+            }
+        }
+        EnvelopeEncryptionKey::AwsKms(_)
+        | EnvelopeEncryptionKey::VaultTransit(_)
+        | EnvelopeEncryptionKey::AzureKeyVault(_) => {}
     }
 
     // Convert EnvelopeEncryptionKey to repository params using From implementation
@@ -253,6 +382,7 @@ pub async fn delete_envelope_encryption_key(
             .list_data_encryption_keys(&PaginationRequest {
                 page_size: 100,
                 next_page_token: page_token.clone(),
+                ..Default::default()
             })
             .await?;
 
@@ -323,6 +453,22 @@ pub async fn migrate_data_encryption_key(
             arn: aws_kms.arn.clone(),
             region: aws_kms.region.clone(),
         },
+        EnvelopeEncryptionKey::GcpKms(gcp_kms) => EnvelopeEncryptionKeyContents::GcpKms {
+            key_name: gcp_kms.key_name.clone(),
+            location: gcp_kms.location.clone(),
+        },
+        EnvelopeEncryptionKey::VaultTransit(vault) => EnvelopeEncryptionKeyContents::VaultTransit {
+            address: vault.address.clone(),
+            mount: vault.mount.clone(),
+            key_name: vault.key_name.clone(),
+        },
+        EnvelopeEncryptionKey::AzureKeyVault(azure) => {
+            EnvelopeEncryptionKeyContents::AzureKeyVault {
+                vault_url: azure.vault_url.clone(),
+                key_name: azure.key_name.clone(),
+                key_version: azure.key_version.clone(),
+            }
+        }
         EnvelopeEncryptionKey::Local(local) => {
             // Load the key bytes from the file (resolve relative to .soma/envelope-encryption-keys)
             get_local_envelope_encryption_key(
@@ -392,6 +538,32 @@ pub async fn migrate_data_encryption_key(
             );
             EncryptedDataEncryptionKey(encoded)
         }
+        EnvelopeEncryptionKeyContents::GcpKms { key_name, .. } => {
+            // Use GCP Cloud KMS to encrypt
+            encrypt_with_gcp_kms(key_name, decrypted_dek.0.as_slice()).await?
+        }
+        EnvelopeEncryptionKeyContents::VaultTransit {
+            address,
+            mount,
+            key_name,
+        } => {
+            // Use the Vault Transit engine to encrypt
+            encrypt_with_vault_transit(address, mount, key_name, decrypted_dek.0.as_slice()).await?
+        }
+        EnvelopeEncryptionKeyContents::AzureKeyVault {
+            vault_url,
+            key_name,
+            key_version,
+        } => {
+            // Use Azure Key Vault to wrap the DEK
+            encrypt_with_azure_key_vault(
+                vault_url,
+                key_name,
+                key_version,
+                decrypted_dek.0.as_slice(),
+            )
+            .await?
+        }
         EnvelopeEncryptionKeyContents::Local {
             file_name: _,
             key_bytes,
@@ -509,8 +681,8 @@ pub async fn migrate_data_encryption_key(
     }
 
     // Invalidate cache for both old and new DEK IDs
-    cache.invalidate_cache(&params.data_encryption_key_id);
-    cache.invalidate_cache(&new_dek_id);
+    cache.invalidate(&params.data_encryption_key_id);
+    cache.invalidate(&new_dek_id);
 
     Ok(())
 }
@@ -547,6 +719,22 @@ where
             arn: aws_kms.arn.clone(),
             region: aws_kms.region.clone(),
         },
+        EnvelopeEncryptionKey::GcpKms(gcp_kms) => EnvelopeEncryptionKeyContents::GcpKms {
+            key_name: gcp_kms.key_name.clone(),
+            location: gcp_kms.location.clone(),
+        },
+        EnvelopeEncryptionKey::VaultTransit(vault) => EnvelopeEncryptionKeyContents::VaultTransit {
+            address: vault.address.clone(),
+            mount: vault.mount.clone(),
+            key_name: vault.key_name.clone(),
+        },
+        EnvelopeEncryptionKey::AzureKeyVault(azure) => {
+            EnvelopeEncryptionKeyContents::AzureKeyVault {
+                vault_url: azure.vault_url.clone(),
+                key_name: azure.key_name.clone(),
+                key_version: azure.key_version.clone(),
+            }
+        }
         EnvelopeEncryptionKey::Local(local) => get_or_create_local_envelope_encryption_key(
             &local_envelope_encryption_key_path.join(&local.file_name),
         )?,
@@ -600,6 +788,22 @@ where
             arn: aws_kms.arn.clone(),
             region: aws_kms.region.clone(),
         },
+        EnvelopeEncryptionKey::GcpKms(gcp_kms) => EnvelopeEncryptionKeyContents::GcpKms {
+            key_name: gcp_kms.key_name.clone(),
+            location: gcp_kms.location.clone(),
+        },
+        EnvelopeEncryptionKey::VaultTransit(vault) => EnvelopeEncryptionKeyContents::VaultTransit {
+            address: vault.address.clone(),
+            mount: vault.mount.clone(),
+            key_name: vault.key_name.clone(),
+        },
+        EnvelopeEncryptionKey::AzureKeyVault(azure) => {
+            EnvelopeEncryptionKeyContents::AzureKeyVault {
+                vault_url: azure.vault_url.clone(),
+                key_name: azure.key_name.clone(),
+                key_version: azure.key_version.clone(),
+            }
+        }
         EnvelopeEncryptionKey::Local(local) => get_or_create_local_envelope_encryption_key(
             &local_envelope_encryption_key_path.join(&local.file_name),
         )?,
@@ -656,6 +860,22 @@ where
             arn: aws_kms.arn.clone(),
             region: aws_kms.region.clone(),
         },
+        EnvelopeEncryptionKey::GcpKms(gcp_kms) => EnvelopeEncryptionKeyContents::GcpKms {
+            key_name: gcp_kms.key_name.clone(),
+            location: gcp_kms.location.clone(),
+        },
+        EnvelopeEncryptionKey::VaultTransit(vault) => EnvelopeEncryptionKeyContents::VaultTransit {
+            address: vault.address.clone(),
+            mount: vault.mount.clone(),
+            key_name: vault.key_name.clone(),
+        },
+        EnvelopeEncryptionKey::AzureKeyVault(azure) => {
+            EnvelopeEncryptionKeyContents::AzureKeyVault {
+                vault_url: azure.vault_url.clone(),
+                key_name: azure.key_name.clone(),
+                key_version: azure.key_version.clone(),
+            }
+        }
         EnvelopeEncryptionKey::Local(local) => get_or_create_local_envelope_encryption_key(
             &local_envelope_encryption_key_path.join(&local.file_name),
         )?,
@@ -677,6 +897,7 @@ where
             &PaginationRequest {
                 page_size: 100,
                 next_page_token: page_token.clone(),
+                ..Default::default()
             },
         )
         .await?;
@@ -734,6 +955,25 @@ pub fn matches_envelope_key_id(id1: &EnvelopeEncryptionKey, id2: &EnvelopeEncryp
         (EnvelopeEncryptionKey::Local(local1), EnvelopeEncryptionKey::Local(local2)) => {
             local1.file_name == local2.file_name
         }
+        (EnvelopeEncryptionKey::GcpKms(gcp_kms1), EnvelopeEncryptionKey::GcpKms(gcp_kms2)) => {
+            gcp_kms1.key_name == gcp_kms2.key_name && gcp_kms1.location == gcp_kms2.location
+        }
+        (
+            EnvelopeEncryptionKey::VaultTransit(vault1),
+            EnvelopeEncryptionKey::VaultTransit(vault2),
+        ) => {
+            vault1.address == vault2.address
+                && vault1.mount == vault2.mount
+                && vault1.key_name == vault2.key_name
+        }
+        (
+            EnvelopeEncryptionKey::AzureKeyVault(azure1),
+            EnvelopeEncryptionKey::AzureKeyVault(azure2),
+        ) => {
+            azure1.vault_url == azure2.vault_url
+                && azure1.key_name == azure2.key_name
+                && azure1.key_version == azure2.key_version
+        }
         _ => false,
     }
 }
@@ -780,6 +1020,97 @@ where
     Ok(None)
 }
 
+/// Set to `true` or `1` to load local KEK files that are readable by group or others, e.g. for
+/// development checkouts where file modes are not preserved
+pub const ALLOW_INSECURE_KEK_FILE_ENV: &str = "SOMA_ALLOW_INSECURE_KEK_FILE";
+
+/// A local KEK file whose permissions let users other than its owner read it
+#[derive(Debug, thiserror::Error)]
+#[error(
+    "local KEK file {} is readable by group or others (mode {mode:o}); restrict it with `chmod 600` or set {ALLOW_INSECURE_KEK_FILE_ENV}=true",
+    path.display()
+)]
+pub struct InsecureKeyFile {
+    pub path: PathBuf,
+    pub mode: u32,
+}
+
+impl InsecureKeyFile {
+    /// Returns the insecure key file error wrapped by a `CommonError`, if there is one.
+    pub fn from_common_error(error: &CommonError) -> Option<&InsecureKeyFile> {
+        match error {
+            CommonError::Unknown(e) => e.downcast_ref::<InsecureKeyFile>(),
+            _ => None,
+        }
+    }
+}
+
+impl From<InsecureKeyFile> for CommonError {
+    fn from(error: InsecureKeyFile) -> Self {
+        CommonError::Unknown(anyhow::Error::new(error))
+    }
+}
+
+fn insecure_kek_file_allowed() -> bool {
+    std::env::var(ALLOW_INSECURE_KEK_FILE_ENV)
+        .map(|value| matches!(value.trim().to_lowercase().as_str(), "1" | "true"))
+        .unwrap_or(false)
+}
+
+/// Guards against a local KEK file that is group or world readable. Only enforced on Unix.
+///
+/// A file owned by the current user is tightened to `0600` with a warning, since KEK files
+/// written by older releases were `0644`. Any other such file is rejected with
+/// [`InsecureKeyFile`], unless [`ALLOW_INSECURE_KEK_FILE_ENV`] is set.
+fn check_local_kek_file_permissions(file_path: &PathBuf) -> Result<(), CommonError> {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::{MetadataExt, PermissionsExt};
+
+        let metadata = std::fs::metadata(file_path).map_err(|e| {
+            CommonError::Unknown(anyhow::anyhow!(
+                "Failed to read permissions of local KEK file at {}: {}",
+                file_path.display(),
+                e
+            ))
+        })?;
+        let mode = metadata.permissions().mode() & 0o777;
+        if mode & 0o044 != 0 {
+            if metadata.uid() == nix::unistd::geteuid().as_raw() {
+                std::fs::set_permissions(file_path, std::fs::Permissions::from_mode(0o600))
+                    .map_err(|e| {
+                        CommonError::Unknown(anyhow::anyhow!(
+                            "Failed to restrict permissions of local KEK file at {}: {}",
+                            file_path.display(),
+                            e
+                        ))
+                    })?;
+                tracing::warn!(
+                    file = %file_path.display(),
+                    mode = format!("{mode:o}"),
+                    "Local KEK file was readable by group or others, restricted it to 600"
+                );
+            } else if insecure_kek_file_allowed() {
+                tracing::warn!(
+                    file = %file_path.display(),
+                    mode = format!("{mode:o}"),
+                    "Loading local KEK file readable by group or others"
+                );
+            } else {
+                return Err(InsecureKeyFile {
+                    path: file_path.clone(),
+                    mode,
+                }
+                .into());
+            }
+        }
+    }
+    #[cfg(not(unix))]
+    let _ = file_path;
+
+    Ok(())
+}
+
 /// Load a local encryption key from a file path.
 /// If the file doesn't exist, returns an error.
 /// This function will NOT create a new key if the file doesn't exist.
@@ -794,6 +1125,8 @@ pub fn get_local_envelope_encryption_key(
         )));
     }
 
+    check_local_kek_file_permissions(file_path)?;
+
     // Read the key from file
     let key_bytes = std::fs::read(file_path).map_err(|e| {
         CommonError::Unknown(anyhow::anyhow!(
@@ -837,6 +1170,8 @@ pub fn get_or_create_local_envelope_encryption_key(
 ) -> Result<EnvelopeEncryptionKeyContents, CommonError> {
     // If file exists, read and return the key
     if file_path.exists() {
+        check_local_kek_file_permissions(file_path)?;
+
         let key_bytes = std::fs::read(file_path.clone()).map_err(|e| {
             CommonError::Unknown(anyhow::anyhow!(
                 "Failed to read local KEK file at {}: {}",
@@ -886,14 +1221,21 @@ pub fn get_or_create_local_envelope_encryption_key(
         })?;
     }
 
-    // Write the key to file
-    std::fs::write(file_path, &key_bytes).map_err(|e| {
-        CommonError::Unknown(anyhow::anyhow!(
-            "Failed to write local KEK file at {}: {}",
-            file_path.display(),
-            e
-        ))
-    })?;
+    // Write the key to file, readable only by its owner
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+    options
+        .open(file_path)
+        .and_then(|mut file| std::io::Write::write_all(&mut file, &key_bytes))
+        .map_err(|e| {
+            CommonError::Unknown(anyhow::anyhow!(
+                "Failed to write local KEK file at {}: {}",
+                file_path.display(),
+                e
+            ))
+        })?;
 
     // Extract only the filename from the path (relative to .soma/envelope-encryption-keys)
     let file_name = file_path
@@ -916,6 +1258,21 @@ pub fn get_or_create_local_envelope_encryption_key(
 pub async fn encrypt_dek(
     parent_encryption_key: &EnvelopeEncryptionKeyContents,
     dek: String,
+) -> Result<EncryptedDataEncryptionKey, CommonError> {
+    let started = Instant::now();
+    let result = encrypt_dek_with_backend(parent_encryption_key, dek).await;
+    record_operation(
+        parent_encryption_key.into(),
+        EncryptionOperation::WrapDek,
+        started,
+        &result,
+    );
+    result
+}
+
+async fn encrypt_dek_with_backend(
+    parent_encryption_key: &EnvelopeEncryptionKeyContents,
+    dek: String,
 ) -> Result<EncryptedDataEncryptionKey, CommonError> {
     match parent_encryption_key {
         EnvelopeEncryptionKeyContents::AwsKms { arn, region } => {
@@ -953,6 +1310,19 @@ pub async fn encrypt_dek(
 
             Ok(EncryptedDataEncryptionKey(encrypted_key))
         }
+        EnvelopeEncryptionKeyContents::GcpKms { key_name, .. } => {
+            encrypt_with_gcp_kms(key_name, dek.as_bytes()).await
+        }
+        EnvelopeEncryptionKeyContents::VaultTransit {
+            address,
+            mount,
+            key_name,
+        } => encrypt_with_vault_transit(address, mount, key_name, dek.as_bytes()).await,
+        EnvelopeEncryptionKeyContents::AzureKeyVault {
+            vault_url,
+            key_name,
+            key_version,
+        } => encrypt_with_azure_key_vault(vault_url, key_name, key_version, dek.as_bytes()).await,
         EnvelopeEncryptionKeyContents::Local {
             file_name: _,
             key_bytes,
@@ -988,9 +1358,370 @@ pub async fn encrypt_dek(
     }
 }
 
+/// Create a GCP Cloud KMS client authenticated with application default credentials
+async fn gcp_kms_client() -> Result<google_cloud_kms::client::Client, CommonError> {
+    let config = google_cloud_kms::client::ClientConfig::default()
+        .with_auth()
+        .await
+        .map_err(|e| {
+            CommonError::Unknown(anyhow::anyhow!("Failed to load GCP credentials: {e}"))
+        })?;
+    google_cloud_kms::client::Client::new(config)
+        .await
+        .map_err(|e| CommonError::Unknown(anyhow::anyhow!("Failed to create GCP KMS client: {e}")))
+}
+
+/// Encrypt `plaintext` with a GCP Cloud KMS key, base64 encoding the ciphertext for storage
+pub(crate) async fn encrypt_with_gcp_kms(
+    key_name: &str,
+    plaintext: &[u8],
+) -> Result<EncryptedDataEncryptionKey, CommonError> {
+    let kms_client = gcp_kms_client().await?;
+
+    let response = kms_client
+        .encrypt(
+            google_cloud_kms::grpc::kms::v1::EncryptRequest {
+                name: key_name.to_string(),
+                plaintext: plaintext.to_vec(),
+                ..Default::default()
+            },
+            None,
+        )
+        .await
+        .map_err(|e| {
+            CommonError::Unknown(anyhow::anyhow!("Failed to encrypt DEK with GCP KMS: {e}"))
+        })?;
+
+    let encoded = base64::Engine::encode(
+        &base64::engine::general_purpose::STANDARD,
+        &response.ciphertext,
+    );
+    Ok(EncryptedDataEncryptionKey(encoded))
+}
+
+/// Decrypt a base64 encoded ciphertext produced by [`encrypt_with_gcp_kms`]
+async fn decrypt_with_gcp_kms(
+    key_name: &str,
+    encrypted_dek: &EncryptedDataEncryptionKey,
+) -> Result<DecryptedDataEncryptionKey, CommonError> {
+    let ciphertext =
+        base64::Engine::decode(&base64::engine::general_purpose::STANDARD, &encrypted_dek.0)
+            .map_err(|e| DecryptionError::MalformedCiphertext {
+                reason: format!("invalid base64 in encrypted DEK: {e}"),
+            })?;
+
+    let kms_client = gcp_kms_client().await?;
+
+    let response = kms_client
+        .decrypt(
+            google_cloud_kms::grpc::kms::v1::DecryptRequest {
+                name: key_name.to_string(),
+                ciphertext,
+                ..Default::default()
+            },
+            None,
+        )
+        .await
+        .map_err(|e| DecryptionError::Kms {
+            key_id: key_name.to_string(),
+            message: e.to_string(),
+        })?;
+
+    Ok(DecryptedDataEncryptionKey(response.plaintext))
+}
+
+/// Token used for Vault requests, read from `VAULT_TOKEN` like the Vault CLI
+fn vault_token() -> Result<String, CommonError> {
+    std::env::var("VAULT_TOKEN").map_err(|_| {
+        CommonError::Unknown(anyhow::anyhow!(
+            "VAULT_TOKEN must be set to use a Vault Transit envelope encryption key"
+        ))
+    })
+}
+
+/// POSTs `body` to a Vault Transit endpoint (`encrypt` or `decrypt`) and returns the `data`
+/// object of the response
+async fn call_vault_transit(
+    address: &str,
+    mount: &str,
+    operation: &str,
+    key_name: &str,
+    body: serde_json::Value,
+) -> Result<serde_json::Value, String> {
+    let token = vault_token().map_err(|e| e.to_string())?;
+    let url = format!(
+        "{}/v1/{}/{operation}/{key_name}",
+        address.trim_end_matches('/'),
+        mount.trim_matches('/')
+    );
+
+    let response = reqwest::Client::new()
+        .post(&url)
+        .header("X-Vault-Token", token)
+        .json(&body)
+        .send()
+        .await
+        .map_err(|e| format!("request to {url} failed: {e}"))?;
+    let status = response.status();
+    let mut payload: serde_json::Value = response
+        .json()
+        .await
+        .map_err(|e| format!("invalid response from {url}: {e}"))?;
+    if !status.is_success() {
+        return Err(format!("{url} returned {status}: {}", payload["errors"]));
+    }
+
+    Ok(payload["data"].take())
+}
+
+/// Encrypt `plaintext` with a Vault Transit key. Vault returns a `vault:v1:...` string, which
+/// is base64 encoded for storage like the AWS KMS ciphertext.
+pub(crate) async fn encrypt_with_vault_transit(
+    address: &str,
+    mount: &str,
+    key_name: &str,
+    plaintext: &[u8],
+) -> Result<EncryptedDataEncryptionKey, CommonError> {
+    let plaintext = base64::Engine::encode(&base64::engine::general_purpose::STANDARD, plaintext);
+    let data = call_vault_transit(
+        address,
+        mount,
+        "encrypt",
+        key_name,
+        serde_json::json!({ "plaintext": plaintext }),
+    )
+    .await
+    .map_err(|e| {
+        CommonError::Unknown(anyhow::anyhow!(
+            "Failed to encrypt DEK with Vault Transit: {e}"
+        ))
+    })?;
+
+    let ciphertext = data["ciphertext"].as_str().ok_or_else(|| {
+        CommonError::Unknown(anyhow::anyhow!("Vault Transit response has no ciphertext"))
+    })?;
+    let encoded = base64::Engine::encode(
+        &base64::engine::general_purpose::STANDARD,
+        ciphertext.as_bytes(),
+    );
+    Ok(EncryptedDataEncryptionKey(encoded))
+}
+
+/// Decrypt a ciphertext produced by [`encrypt_with_vault_transit`]
+async fn decrypt_with_vault_transit(
+    address: &str,
+    mount: &str,
+    key_name: &str,
+    encrypted_dek: &EncryptedDataEncryptionKey,
+) -> Result<DecryptedDataEncryptionKey, CommonError> {
+    let ciphertext =
+        base64::Engine::decode(&base64::engine::general_purpose::STANDARD, &encrypted_dek.0)
+            .ok()
+            .and_then(|bytes| String::from_utf8(bytes).ok())
+            .ok_or_else(|| DecryptionError::MalformedCiphertext {
+                reason: "encrypted DEK is not a base64 encoded Vault ciphertext".to_string(),
+            })?;
+
+    let data = call_vault_transit(
+        address,
+        mount,
+        "decrypt",
+        key_name,
+        serde_json::json!({ "ciphertext": ciphertext }),
+    )
+    .await
+    .map_err(|message| DecryptionError::Kms {
+        key_id: vault_transit_key_id(address, mount, key_name),
+        message,
+    })?;
+
+    let plaintext = data["plaintext"]
+        .as_str()
+        .and_then(|plaintext| {
+            base64::Engine::decode(&base64::engine::general_purpose::STANDARD, plaintext).ok()
+        })
+        .ok_or_else(|| DecryptionError::MalformedCiphertext {
+            reason: "Vault Transit response has no base64 plaintext".to_string(),
+        })?;
+    Ok(DecryptedDataEncryptionKey(plaintext))
+}
+
+/// OAuth scope for the Azure Key Vault data plane
+const AZURE_KEY_VAULT_SCOPE: &str = "https://vault.azure.net/.default";
+/// Key Vault REST API version used for wrapKey/unwrapKey
+const AZURE_KEY_VAULT_API_VERSION: &str = "7.4";
+/// Key wrapping algorithm, Azure Key Vault keys used as envelope keys must be RSA keys
+const AZURE_KEY_VAULT_WRAP_ALGORITHM: &str = "RSA-OAEP-256";
+
+/// Fetch a Key Vault access token from the default Azure credential chain (environment,
+/// workload identity, managed identity, then the Azure CLI)
+async fn azure_key_vault_token() -> Result<String, String> {
+    let credential = azure_identity::create_default_credential()
+        .map_err(|e| format!("failed to load Azure credentials: {e}"))?;
+    let token = credential
+        .get_token(&[AZURE_KEY_VAULT_SCOPE])
+        .await
+        .map_err(|e| format!("failed to get an Azure Key Vault access token: {e}"))?;
+    Ok(token.token.secret().to_string())
+}
+
+/// Runs a Key Vault key operation (`wrapkey` or `unwrapkey`) on `value` and returns the
+/// resulting bytes. Key Vault exchanges key material as unpadded base64url.
+async fn call_azure_key_vault(
+    vault_url: &str,
+    key_name: &str,
+    key_version: &str,
+    operation: &str,
+    value: &[u8],
+) -> Result<Vec<u8>, String> {
+    let token = azure_key_vault_token().await?;
+    let url = format!(
+        "{}/{operation}?api-version={AZURE_KEY_VAULT_API_VERSION}",
+        azure_key_vault_key_id(vault_url, key_name, key_version)
+    );
+    let body = serde_json::json!({
+        "alg": AZURE_KEY_VAULT_WRAP_ALGORITHM,
+        "value": base64::Engine::encode(&base64::engine::general_purpose::URL_SAFE_NO_PAD, value),
+    });
+
+    let response = reqwest::Client::new()
+        .post(&url)
+        .bearer_auth(token)
+        .json(&body)
+        .send()
+        .await
+        .map_err(|e| format!("request to {url} failed: {e}"))?;
+    let status = response.status();
+    let payload: serde_json::Value = response
+        .json()
+        .await
+        .map_err(|e| format!("invalid response from {url}: {e}"))?;
+    if !status.is_success() {
+        return Err(format!("{url} returned {status}: {}", payload["error"]));
+    }
+
+    payload["value"]
+        .as_str()
+        .and_then(|value| {
+            base64::Engine::decode(&base64::engine::general_purpose::URL_SAFE_NO_PAD, value).ok()
+        })
+        .ok_or_else(|| format!("{url} returned no base64url value"))
+}
+
+/// Wrap `plaintext` with an Azure Key Vault key, base64 encoding the wrapped key for storage
+pub(crate) async fn encrypt_with_azure_key_vault(
+    vault_url: &str,
+    key_name: &str,
+    key_version: &str,
+    plaintext: &[u8],
+) -> Result<EncryptedDataEncryptionKey, CommonError> {
+    let wrapped = call_azure_key_vault(vault_url, key_name, key_version, "wrapkey", plaintext)
+        .await
+        .map_err(|e| {
+            CommonError::Unknown(anyhow::anyhow!(
+                "Failed to encrypt DEK with Azure Key Vault: {e}"
+            ))
+        })?;
+
+    let encoded = base64::Engine::encode(&base64::engine::general_purpose::STANDARD, &wrapped);
+    Ok(EncryptedDataEncryptionKey(encoded))
+}
+
+/// Unwrap a key produced by [`encrypt_with_azure_key_vault`]
+async fn decrypt_with_azure_key_vault(
+    vault_url: &str,
+    key_name: &str,
+    key_version: &str,
+    encrypted_dek: &EncryptedDataEncryptionKey,
+) -> Result<DecryptedDataEncryptionKey, CommonError> {
+    let wrapped =
+        base64::Engine::decode(&base64::engine::general_purpose::STANDARD, &encrypted_dek.0)
+            .map_err(|e| DecryptionError::MalformedCiphertext {
+                reason: format!("invalid base64 in encrypted DEK: {e}"),
+            })?;
+
+    let plaintext = call_azure_key_vault(vault_url, key_name, key_version, "unwrapkey", &wrapped)
+        .await
+        .map_err(|message| DecryptionError::Kms {
+            key_id: azure_key_vault_key_id(vault_url, key_name, key_version),
+            message,
+        })?;
+    Ok(DecryptedDataEncryptionKey(plaintext))
+}
+
+/// Distinguishes why a decryption failed so key mismatches can be told apart from corrupted
+/// ciphertext. Variants only carry diagnostic context, never plaintext or key material.
+#[derive(Debug, thiserror::Error)]
+pub enum DecryptionError {
+    /// The ciphertext could not be parsed (bad base64, or too short to contain a nonce).
+    #[error("malformed ciphertext: {reason}")]
+    MalformedCiphertext { reason: String },
+    /// AES-GCM tag verification failed, most likely because a different key encrypted the data.
+    #[error(
+        "authentication failed while decrypting {context} (likely encrypted with a different key)"
+    )]
+    AuthenticationFailed { context: &'static str },
+    /// The KMS provider rejected or failed the decrypt call.
+    #[error("KMS decrypt failed for key {key_id}: {message}")]
+    Kms { key_id: String, message: String },
+}
+
+impl DecryptionError {
+    /// Returns the decryption error wrapped by a `CommonError`, if there is one.
+    pub fn from_common_error(error: &CommonError) -> Option<&DecryptionError> {
+        match error {
+            CommonError::Unknown(e) => e.downcast_ref::<DecryptionError>(),
+            _ => None,
+        }
+    }
+}
+
+impl From<DecryptionError> for CommonError {
+    fn from(error: DecryptionError) -> Self {
+        CommonError::Unknown(anyhow::Error::new(error))
+    }
+}
+
+/// Splits a base64 `[nonce (12 bytes) | ciphertext]` blob into its nonce and ciphertext parts.
+pub(crate) fn decode_nonce_prefixed_ciphertext(
+    encoded: &str,
+) -> Result<(Vec<u8>, Vec<u8>), DecryptionError> {
+    let mut data = base64::Engine::decode(&base64::engine::general_purpose::STANDARD, encoded)
+        .map_err(|e| DecryptionError::MalformedCiphertext {
+            reason: format!("invalid base64: {e}"),
+        })?;
+
+    if data.len() < 12 {
+        return Err(DecryptionError::MalformedCiphertext {
+            reason: format!(
+                "too short: expected at least 12 bytes for nonce, got {}",
+                data.len()
+            ),
+        });
+    }
+
+    let ciphertext = data.split_off(12);
+    Ok((data, ciphertext))
+}
+
 pub async fn decrypt_dek(
     parent_encryption_key: &EnvelopeEncryptionKeyContents,
     encrypted_dek: &EncryptedDataEncryptionKey,
+) -> Result<DecryptedDataEncryptionKey, CommonError> {
+    let started = Instant::now();
+    let result = decrypt_dek_with_backend(parent_encryption_key, encrypted_dek).await;
+    record_operation(
+        parent_encryption_key.into(),
+        EncryptionOperation::UnwrapDek,
+        started,
+        &result,
+    );
+    result
+}
+
+async fn decrypt_dek_with_backend(
+    parent_encryption_key: &EnvelopeEncryptionKeyContents,
+    encrypted_dek: &EncryptedDataEncryptionKey,
 ) -> Result<DecryptedDataEncryptionKey, CommonError> {
     match parent_encryption_key {
         EnvelopeEncryptionKeyContents::AwsKms { arn, region } => {
@@ -1007,10 +1738,8 @@ pub async fn decrypt_dek(
                 &base64::engine::general_purpose::STANDARD,
                 &encrypted_dek.0,
             )
-            .map_err(|e| {
-                CommonError::Unknown(anyhow::anyhow!(
-                    "Failed to decode base64 encrypted DEK: {e}"
-                ))
+            .map_err(|e| DecryptionError::MalformedCiphertext {
+                reason: format!("invalid base64 in encrypted DEK: {e}"),
             })?;
 
             // Decrypt the DEK using AWS KMS
@@ -1020,8 +1749,9 @@ pub async fn decrypt_dek(
                 .ciphertext_blob(aws_sdk_kms::primitives::Blob::new(ciphertext_blob))
                 .send()
                 .await
-                .map_err(|e| {
-                    CommonError::Unknown(anyhow::anyhow!("Failed to decrypt DEK with AWS KMS: {e}"))
+                .map_err(|e| DecryptionError::Kms {
+                    key_id: arn.clone(),
+                    message: aws_sdk_kms::error::DisplayErrorContext(&e).to_string(),
                 })?;
 
             // Get the decrypted plaintext as raw bytes
@@ -1034,6 +1764,19 @@ pub async fn decrypt_dek(
             // Store as raw bytes (no UTF-8 conversion needed for key material)
             Ok(DecryptedDataEncryptionKey(plaintext.as_ref().to_vec()))
         }
+        EnvelopeEncryptionKeyContents::GcpKms { key_name, .. } => {
+            decrypt_with_gcp_kms(key_name, encrypted_dek).await
+        }
+        EnvelopeEncryptionKeyContents::VaultTransit {
+            address,
+            mount,
+            key_name,
+        } => decrypt_with_vault_transit(address, mount, key_name, encrypted_dek).await,
+        EnvelopeEncryptionKeyContents::AzureKeyVault {
+            vault_url,
+            key_name,
+            key_version,
+        } => decrypt_with_azure_key_vault(vault_url, key_name, key_version, encrypted_dek).await,
         EnvelopeEncryptionKeyContents::Local {
             file_name: _,
             key_bytes,
@@ -1049,27 +1792,13 @@ pub async fn decrypt_dek(
             let key = aes_gcm::Key::<Aes256Gcm>::from_slice(key_bytes);
             let cipher = Aes256Gcm::new(key);
 
-            let encrypted_data = base64::Engine::decode(
-                &base64::engine::general_purpose::STANDARD,
-                &encrypted_dek.0,
-            )
-            .map_err(|e| {
-                CommonError::Unknown(anyhow::anyhow!(
-                    "Failed to decode base64 encrypted DEK: {e}"
-                ))
-            })?;
-
-            if encrypted_data.len() < 12 {
-                return Err(CommonError::Unknown(anyhow::anyhow!(
-                    "Invalid encrypted DEK format: missing nonce"
-                )));
-            }
-
-            let nonce = Nonce::from_slice(&encrypted_data[..12]);
-            let ciphertext = &encrypted_data[12..];
+            let (nonce_bytes, ciphertext) = decode_nonce_prefixed_ciphertext(&encrypted_dek.0)?;
+            let nonce = Nonce::from_slice(&nonce_bytes);
 
-            let plaintext = cipher.decrypt(nonce, ciphertext).map_err(|e| {
-                CommonError::Unknown(anyhow::anyhow!("Local DEK decryption failed: {e}"))
+            let plaintext = cipher.decrypt(nonce, ciphertext.as_ref()).map_err(|_| {
+                DecryptionError::AuthenticationFailed {
+                    context: "data encryption key",
+                }
             })?;
 
             Ok(DecryptedDataEncryptionKey(plaintext))
@@ -1103,6 +1832,12 @@ mod tests {
             let key_path = base_path.join(filename);
             std::fs::create_dir_all(base_path).expect("Failed to create base directory");
             std::fs::write(&key_path, kek_bytes).expect("Failed to write KEK to file");
+            #[cfg(unix)]
+            {
+                use std::os::unix::fs::PermissionsExt;
+                std::fs::set_permissions(&key_path, std::fs::Permissions::from_mode(0o600))
+                    .expect("Failed to restrict KEK file permissions");
+            }
 
             EnvelopeEncryptionKeyContents::Local {
                 file_name: filename.to_string(),
@@ -1157,6 +1892,25 @@ mod tests {
             assert!(extract_region_from_kms_arn(wrong_service).is_err());
         }
 
+        #[test]
+        fn test_extract_location_from_gcp_key_name() {
+            let key_name =
+                "projects/my-project/locations/europe-west2/keyRings/soma/cryptoKeys/kek";
+            assert_eq!(
+                extract_location_from_gcp_key_name(key_name).unwrap(),
+                "europe-west2"
+            );
+
+            // A key version is not a crypto key
+            let version = format!("{key_name}/cryptoKeyVersions/1");
+            assert!(extract_location_from_gcp_key_name(&version).is_err());
+            assert!(
+                extract_location_from_gcp_key_name("projects/p/locations//keyRings/r/cryptoKeys/k")
+                    .is_err()
+            );
+            assert!(extract_location_from_gcp_key_name("not-a-key-name").is_err());
+        }
+
         #[tokio::test]
         async fn test_matches_envelope_key_id() {
             shared::setup_test!();
@@ -1325,6 +2079,67 @@ mod tests {
             }
         }
 
+        #[cfg(unix)]
+        #[test]
+        fn test_own_local_kek_file_readable_by_others_is_restricted() {
+            use std::os::unix::fs::PermissionsExt;
+
+            let temp_dir = tempfile::TempDir::new().expect("Failed to create temp dir");
+            let path = temp_dir.path().join("test-key");
+            std::fs::write(&path, [7u8; 32]).unwrap();
+
+            // Key files written by older releases are 0644, loading them upgrades them in place
+            std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o644)).unwrap();
+            let key = get_local_envelope_encryption_key(&path).unwrap();
+            assert!(matches!(
+                key,
+                EnvelopeEncryptionKeyContents::Local { key_bytes, .. } if key_bytes == [7u8; 32]
+            ));
+            let mode = std::fs::metadata(&path).unwrap().permissions().mode() & 0o777;
+            assert_eq!(mode, 0o600);
+
+            std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o640)).unwrap();
+            get_or_create_local_envelope_encryption_key(&path).unwrap();
+            let mode = std::fs::metadata(&path).unwrap().permissions().mode() & 0o777;
+            assert_eq!(mode, 0o600);
+        }
+
+        #[cfg(unix)]
+        #[test]
+        fn test_other_users_local_kek_file_readable_by_others_is_rejected() {
+            use std::os::unix::fs::PermissionsExt;
+
+            // Handing the file to another user needs root
+            if !nix::unistd::geteuid().is_root() {
+                return;
+            }
+            let temp_dir = tempfile::TempDir::new().expect("Failed to create temp dir");
+            let path = temp_dir.path().join("test-key");
+            std::fs::write(&path, [7u8; 32]).unwrap();
+            std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o644)).unwrap();
+            std::os::unix::fs::chown(&path, Some(65534), None).unwrap();
+
+            let err = get_local_envelope_encryption_key(&path).unwrap_err();
+            let insecure = InsecureKeyFile::from_common_error(&err).expect("InsecureKeyFile");
+            assert_eq!(insecure.path, path);
+            assert_eq!(insecure.mode, 0o644);
+            let err = get_or_create_local_envelope_encryption_key(&path).unwrap_err();
+            assert!(InsecureKeyFile::from_common_error(&err).is_some());
+        }
+
+        #[cfg(unix)]
+        #[test]
+        fn test_created_local_kek_file_is_owner_only() {
+            use std::os::unix::fs::PermissionsExt;
+
+            let temp_dir = tempfile::TempDir::new().expect("Failed to create temp dir");
+            let path = temp_dir.path().join("test-key");
+
+            get_or_create_local_envelope_encryption_key(&path).unwrap();
+            let mode = std::fs::metadata(&path).unwrap().permissions().mode() & 0o777;
+            assert_eq!(mode, 0o600);
+        }
+
         #[tokio::test]
         async fn test_create_envelope_encryption_key_local() {
             shared::setup_test!();
@@ -1396,6 +2211,116 @@ mod tests {
             assert!(retrieved.is_some());
         }
 
+        #[tokio::test]
+        async fn test_create_envelope_encryption_key_gcp() {
+            shared::setup_test!();
+
+            let (_db, conn) = setup_in_memory_database(vec![Repository::load_sql_migrations()])
+                .await
+                .unwrap();
+            let repo = Repository::new(conn);
+            let (tx, _rx) = broadcast::channel(100);
+            let temp_dir_handle = tempfile::tempdir().unwrap();
+            let temp_dir = temp_dir_handle.path();
+
+            let key_name =
+                "projects/my-project/locations/europe-west2/keyRings/soma/cryptoKeys/kek";
+            let envelope_key = EnvelopeEncryptionKey::GcpKms(EnvelopeEncryptionKeyGcpKms {
+                key_name: key_name.to_string(),
+                location: "europe-west2".to_string(),
+            });
+
+            create_envelope_encryption_key(temp_dir, &tx, &repo, envelope_key.clone(), false)
+                .await
+                .unwrap();
+
+            let retrieved = repo
+                .get_envelope_encryption_key_by_id(key_name)
+                .await
+                .unwrap()
+                .unwrap();
+            assert!(matches_envelope_key_id(&retrieved, &envelope_key));
+
+            // The location must match the one in the key name
+            let mismatched = EnvelopeEncryptionKey::GcpKms(EnvelopeEncryptionKeyGcpKms {
+                key_name: key_name.to_string(),
+                location: "us-east1".to_string(),
+            });
+            let result =
+                create_envelope_encryption_key(temp_dir, &tx, &repo, mismatched, false).await;
+            assert!(matches!(result, Err(CommonError::InvalidRequest { .. })));
+        }
+
+        #[tokio::test]
+        async fn test_create_envelope_encryption_key_vault_transit() {
+            shared::setup_test!();
+
+            let (_db, conn) = setup_in_memory_database(vec![Repository::load_sql_migrations()])
+                .await
+                .unwrap();
+            let repo = Repository::new(conn);
+            let (tx, _rx) = broadcast::channel(100);
+            let temp_dir_handle = tempfile::tempdir().unwrap();
+            let temp_dir = temp_dir_handle.path();
+
+            let envelope_key =
+                EnvelopeEncryptionKey::VaultTransit(EnvelopeEncryptionKeyVaultTransit {
+                    address: "https://vault.example.com:8200/".to_string(),
+                    mount: "transit".to_string(),
+                    key_name: "soma".to_string(),
+                });
+            assert_eq!(
+                envelope_key.id(),
+                "https://vault.example.com:8200/v1/transit/keys/soma"
+            );
+
+            create_envelope_encryption_key(temp_dir, &tx, &repo, envelope_key.clone(), false)
+                .await
+                .unwrap();
+
+            let retrieved = repo
+                .get_envelope_encryption_key_by_id(&envelope_key.id())
+                .await
+                .unwrap()
+                .unwrap();
+            assert!(matches_envelope_key_id(&retrieved, &envelope_key));
+        }
+
+        #[tokio::test]
+        async fn test_create_envelope_encryption_key_azure_key_vault() {
+            shared::setup_test!();
+
+            let (_db, conn) = setup_in_memory_database(vec![Repository::load_sql_migrations()])
+                .await
+                .unwrap();
+            let repo = Repository::new(conn);
+            let (tx, _rx) = broadcast::channel(100);
+            let temp_dir_handle = tempfile::tempdir().unwrap();
+            let temp_dir = temp_dir_handle.path();
+
+            let envelope_key =
+                EnvelopeEncryptionKey::AzureKeyVault(EnvelopeEncryptionKeyAzureKeyVault {
+                    vault_url: "https://soma.vault.azure.net/".to_string(),
+                    key_name: "soma".to_string(),
+                    key_version: "0123456789abcdef".to_string(),
+                });
+            assert_eq!(
+                envelope_key.id(),
+                "https://soma.vault.azure.net/keys/soma/0123456789abcdef"
+            );
+
+            create_envelope_encryption_key(temp_dir, &tx, &repo, envelope_key.clone(), false)
+                .await
+                .unwrap();
+
+            let retrieved = repo
+                .get_envelope_encryption_key_by_id(&envelope_key.id())
+                .await
+                .unwrap()
+                .unwrap();
+            assert!(matches_envelope_key_id(&retrieved, &envelope_key));
+        }
+
         #[tokio::test]
         async fn test_delete_envelope_encryption_key() {
             shared::setup_test!();
@@ -1605,6 +2530,7 @@ mod tests {
                     inner: shared::primitives::PaginationRequest {
                         page_size: 100,
                         next_page_token: None,
+                        ..Default::default()
                     },
                 },
             )
@@ -1724,6 +2650,7 @@ mod tests {
                     inner: shared::primitives::PaginationRequest {
                         page_size: 100,
                         next_page_token: None,
+                        ..Default::default()
                     },
                 },
             )
@@ -1796,6 +2723,12 @@ mod tests {
             let key_path = base_path.join(filename);
             std::fs::create_dir_all(base_path).expect("Failed to create base directory");
             std::fs::write(&key_path, kek_bytes).expect("Failed to write KEK to file");
+            #[cfg(unix)]
+            {
+                use std::os::unix::fs::PermissionsExt;
+                std::fs::set_permissions(&key_path, std::fs::Permissions::from_mode(0o600))
+                    .expect("Failed to restrict KEK file permissions");
+            }
 
             EnvelopeEncryptionKeyContents::Local {
                 file_name: filename.to_string(),
@@ -1928,6 +2861,7 @@ mod tests {
                     inner: shared::primitives::PaginationRequest {
                         page_size: 100,
                         next_page_token: None,
+                        ..Default::default()
                     },
                 },
             )
@@ -2021,6 +2955,7 @@ mod tests {
                     inner: shared::primitives::PaginationRequest {
                         page_size: 100,
                         next_page_token: None,
+                        ..Default::default()
                     },
                 },
             )
@@ -2127,6 +3062,7 @@ mod tests {
                     inner: shared::primitives::PaginationRequest {
                         page_size: 100,
                         next_page_token: None,
+                        ..Default::default()
                     },
                 },
             )
@@ -2138,5 +3074,53 @@ mod tests {
             });
             assert!(migrated_dek.is_some());
         }
+
+        #[tokio::test]
+        async fn test_decrypt_dek_with_wrong_key_is_authentication_failure() {
+            shared::setup_test!();
+
+            let (_temp_file_a, key_a) = create_temp_local_key();
+            let (_temp_file_b, key_b) = create_temp_local_key();
+
+            let encrypted_dek = encrypt_dek(&key_a, "secret-dek".to_string()).await.unwrap();
+
+            let err = decrypt_dek(&key_b, &encrypted_dek).await.unwrap_err();
+            assert!(matches!(
+                DecryptionError::from_common_error(&err),
+                Some(DecryptionError::AuthenticationFailed { .. })
+            ));
+            assert!(!format!("{err:?}").contains("secret-dek"));
+        }
+
+        #[tokio::test]
+        async fn test_decrypt_dek_truncated_blob_is_malformed() {
+            shared::setup_test!();
+
+            let (_temp_file, key) = create_temp_local_key();
+            let truncated = EncryptedDataEncryptionKey(base64::Engine::encode(
+                &base64::engine::general_purpose::STANDARD,
+                [0u8; 5],
+            ));
+
+            let err = decrypt_dek(&key, &truncated).await.unwrap_err();
+            assert!(matches!(
+                DecryptionError::from_common_error(&err),
+                Some(DecryptionError::MalformedCiphertext { .. })
+            ));
+        }
+
+        #[tokio::test]
+        async fn test_decrypt_dek_bad_base64_is_malformed() {
+            shared::setup_test!();
+
+            let (_temp_file, key) = create_temp_local_key();
+            let bad = EncryptedDataEncryptionKey("not valid base64!!".to_string());
+
+            let err = decrypt_dek(&key, &bad).await.unwrap_err();
+            assert!(matches!(
+                DecryptionError::from_common_error(&err),
+                Some(DecryptionError::MalformedCiphertext { .. })
+            ));
+        }
     }
 }
@@ -0,0 +1,339 @@
+// In-process metrics for envelope encryption: call counts, error counts and latency
+// histograms per backend and operation, plus hit/miss counters for the crypto service cache.
+// Counters are process-global so they can be rendered by whichever server exposes metrics.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+use crate::logic::envelope::{EnvelopeEncryptionKey, EnvelopeEncryptionKeyContents};
+
+/// Upper bounds (in milliseconds) of the latency histogram buckets. Anything slower lands in
+/// the implicit `+Inf` bucket.
+pub const LATENCY_BUCKETS_MS: [u64; 8] = [1, 5, 10, 25, 50, 100, 250, 1000];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EncryptionBackend {
+    Local,
+    AwsKms,
+    GcpKms,
+    VaultTransit,
+    AzureKeyVault,
+}
+
+impl EncryptionBackend {
+    const ALL: [EncryptionBackend; 5] = [
+        EncryptionBackend::Local,
+        EncryptionBackend::AwsKms,
+        EncryptionBackend::GcpKms,
+        EncryptionBackend::VaultTransit,
+        EncryptionBackend::AzureKeyVault,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EncryptionBackend::Local => "local",
+            EncryptionBackend::AwsKms => "aws_kms",
+            EncryptionBackend::GcpKms => "gcp_kms",
+            EncryptionBackend::VaultTransit => "vault_transit",
+            EncryptionBackend::AzureKeyVault => "azure_key_vault",
+        }
+    }
+
+    fn index(&self) -> usize {
+        *self as usize
+    }
+}
+
+impl From<&EnvelopeEncryptionKeyContents> for EncryptionBackend {
+    fn from(contents: &EnvelopeEncryptionKeyContents) -> Self {
+        match contents {
+            EnvelopeEncryptionKeyContents::Local { .. } => EncryptionBackend::Local,
+            EnvelopeEncryptionKeyContents::AwsKms { .. } => EncryptionBackend::AwsKms,
+            EnvelopeEncryptionKeyContents::GcpKms { .. } => EncryptionBackend::GcpKms,
+            EnvelopeEncryptionKeyContents::VaultTransit { .. } => EncryptionBackend::VaultTransit,
+            EnvelopeEncryptionKeyContents::AzureKeyVault { .. } => EncryptionBackend::AzureKeyVault,
+        }
+    }
+}
+
+impl From<&EnvelopeEncryptionKey> for EncryptionBackend {
+    fn from(key: &EnvelopeEncryptionKey) -> Self {
+        match key {
+            EnvelopeEncryptionKey::Local(_) => EncryptionBackend::Local,
+            EnvelopeEncryptionKey::AwsKms(_) => EncryptionBackend::AwsKms,
+            EnvelopeEncryptionKey::GcpKms(_) => EncryptionBackend::GcpKms,
+            EnvelopeEncryptionKey::VaultTransit(_) => EncryptionBackend::VaultTransit,
+            EnvelopeEncryptionKey::AzureKeyVault(_) => EncryptionBackend::AzureKeyVault,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EncryptionOperation {
+    /// Encrypting a DEK with the envelope key.
+    WrapDek,
+    /// Decrypting a DEK with the envelope key.
+    UnwrapDek,
+    /// Encrypting application data with a DEK.
+    EncryptData,
+    /// Decrypting application data with a DEK.
+    DecryptData,
+}
+
+impl EncryptionOperation {
+    const ALL: [EncryptionOperation; 4] = [
+        EncryptionOperation::WrapDek,
+        EncryptionOperation::UnwrapDek,
+        EncryptionOperation::EncryptData,
+        EncryptionOperation::DecryptData,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EncryptionOperation::WrapDek => "wrap_dek",
+            EncryptionOperation::UnwrapDek => "unwrap_dek",
+            EncryptionOperation::EncryptData => "encrypt_data",
+            EncryptionOperation::DecryptData => "decrypt_data",
+        }
+    }
+
+    fn index(&self) -> usize {
+        *self as usize
+    }
+}
+
+struct OperationMetrics {
+    calls: AtomicU64,
+    errors: AtomicU64,
+    latency_micros_sum: AtomicU64,
+    latency_buckets: [AtomicU64; LATENCY_BUCKETS_MS.len() + 1],
+}
+
+impl OperationMetrics {
+    const fn new() -> Self {
+        Self {
+            calls: AtomicU64::new(0),
+            errors: AtomicU64::new(0),
+            latency_micros_sum: AtomicU64::new(0),
+            latency_buckets: [const { AtomicU64::new(0) }; LATENCY_BUCKETS_MS.len() + 1],
+        }
+    }
+
+    fn record(&self, elapsed: Duration, is_error: bool) {
+        self.calls.fetch_add(1, Ordering::Relaxed);
+        if is_error {
+            self.errors.fetch_add(1, Ordering::Relaxed);
+        }
+        self.latency_micros_sum
+            .fetch_add(elapsed.as_micros() as u64, Ordering::Relaxed);
+        let elapsed_ms = elapsed.as_millis() as u64;
+        let bucket = LATENCY_BUCKETS_MS
+            .iter()
+            .position(|upper| elapsed_ms <= *upper)
+            .unwrap_or(LATENCY_BUCKETS_MS.len());
+        self.latency_buckets[bucket].fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn snapshot(&self) -> OperationMetricsSnapshot {
+        OperationMetricsSnapshot {
+            calls: self.calls.load(Ordering::Relaxed),
+            errors: self.errors.load(Ordering::Relaxed),
+            latency_micros_sum: self.latency_micros_sum.load(Ordering::Relaxed),
+            latency_buckets: self
+                .latency_buckets
+                .iter()
+                .map(|b| b.load(Ordering::Relaxed))
+                .collect(),
+        }
+    }
+}
+
+const OPERATION_COUNT: usize = EncryptionOperation::ALL.len();
+const BACKEND_COUNT: usize = EncryptionBackend::ALL.len();
+
+static OPERATIONS: [[OperationMetrics; OPERATION_COUNT]; BACKEND_COUNT] =
+    [const { [const { OperationMetrics::new() }; OPERATION_COUNT] }; BACKEND_COUNT];
+static CACHE_HITS: AtomicU64 = AtomicU64::new(0);
+static CACHE_MISSES: AtomicU64 = AtomicU64::new(0);
+
+/// Records the outcome and latency of an encryption operation started at `started`.
+pub fn record_operation<T, E>(
+    backend: EncryptionBackend,
+    operation: EncryptionOperation,
+    started: Instant,
+    result: &Result<T, E>,
+) {
+    OPERATIONS[backend.index()][operation.index()].record(started.elapsed(), result.is_err());
+}
+
+pub(crate) fn record_cache_hit() {
+    CACHE_HITS.fetch_add(1, Ordering::Relaxed);
+}
+
+pub(crate) fn record_cache_miss() {
+    CACHE_MISSES.fetch_add(1, Ordering::Relaxed);
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OperationMetricsSnapshot {
+    pub calls: u64,
+    pub errors: u64,
+    pub latency_micros_sum: u64,
+    /// Non-cumulative counts per bucket in `LATENCY_BUCKETS_MS`, with the `+Inf` bucket last.
+    pub latency_buckets: Vec<u64>,
+}
+
+#[derive(Clone, Debug)]
+pub struct EncryptionMetricsSnapshot {
+    operations: Vec<(
+        EncryptionBackend,
+        EncryptionOperation,
+        OperationMetricsSnapshot,
+    )>,
+    pub cache_hits: u64,
+    pub cache_misses: u64,
+}
+
+impl EncryptionMetricsSnapshot {
+    pub fn operation(
+        &self,
+        backend: EncryptionBackend,
+        operation: EncryptionOperation,
+    ) -> &OperationMetricsSnapshot {
+        self.operations
+            .iter()
+            .find(|(b, o, _)| *b == backend && *o == operation)
+            .map(|(_, _, snapshot)| snapshot)
+            .expect("snapshot covers every backend and operation")
+    }
+
+    /// Renders the snapshot in the Prometheus text exposition format.
+    pub fn to_prometheus_text(&self) -> String {
+        use std::fmt::Write;
+
+        let mut out = String::new();
+        let _ = writeln!(
+            out,
+            "# TYPE soma_encryption_operations_total counter\n# TYPE soma_encryption_errors_total counter\n# TYPE soma_encryption_operation_duration_seconds histogram"
+        );
+        for (backend, operation, snapshot) in &self.operations {
+            let labels = format!(
+                "backend=\"{}\",operation=\"{}\"",
+                backend.as_str(),
+                operation.as_str()
+            );
+            let _ = writeln!(
+                out,
+                "soma_encryption_operations_total{{{labels}}} {}",
+                snapshot.calls
+            );
+            let _ = writeln!(
+                out,
+                "soma_encryption_errors_total{{{labels}}} {}",
+                snapshot.errors
+            );
+            let mut cumulative = 0;
+            for (upper_ms, count) in LATENCY_BUCKETS_MS.iter().zip(&snapshot.latency_buckets) {
+                cumulative += count;
+                let _ = writeln!(
+                    out,
+                    "soma_encryption_operation_duration_seconds_bucket{{{labels},le=\"{}\"}} {cumulative}",
+                    *upper_ms as f64 / 1000.0
+                );
+            }
+            let _ = writeln!(
+                out,
+                "soma_encryption_operation_duration_seconds_bucket{{{labels},le=\"+Inf\"}} {}",
+                snapshot.calls
+            );
+            let _ = writeln!(
+                out,
+                "soma_encryption_operation_duration_seconds_sum{{{labels}}} {}",
+                snapshot.latency_micros_sum as f64 / 1_000_000.0
+            );
+            let _ = writeln!(
+                out,
+                "soma_encryption_operation_duration_seconds_count{{{labels}}} {}",
+                snapshot.calls
+            );
+        }
+        let _ = writeln!(
+            out,
+            "# TYPE soma_encryption_dek_cache_total counter\nsoma_encryption_dek_cache_total{{result=\"hit\"}} {}\nsoma_encryption_dek_cache_total{{result=\"miss\"}} {}",
+            self.cache_hits, self.cache_misses
+        );
+        out
+    }
+}
+
+/// Returns the current value of every encryption metric.
+pub fn encryption_metrics() -> EncryptionMetricsSnapshot {
+    let operations = EncryptionBackend::ALL
+        .iter()
+        .flat_map(|backend| {
+            EncryptionOperation::ALL.iter().map(move |operation| {
+                (
+                    *backend,
+                    *operation,
+                    OPERATIONS[backend.index()][operation.index()].snapshot(),
+                )
+            })
+        })
+        .collect();
+
+    EncryptionMetricsSnapshot {
+        operations,
+        cache_hits: CACHE_HITS.load(Ordering::Relaxed),
+        cache_misses: CACHE_MISSES.load(Ordering::Relaxed),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod unit {
+        use super::super::*;
+
+        #[test]
+        fn test_record_operation_buckets_latency() {
+            shared::setup_test!();
+
+            let before = encryption_metrics();
+            let ok: Result<(), ()> = Ok(());
+            let err: Result<(), ()> = Err(());
+            record_operation(
+                EncryptionBackend::AwsKms,
+                EncryptionOperation::WrapDek,
+                Instant::now(),
+                &ok,
+            );
+            record_operation(
+                EncryptionBackend::AwsKms,
+                EncryptionOperation::WrapDek,
+                Instant::now() - Duration::from_secs(2),
+                &err,
+            );
+            let after = encryption_metrics();
+
+            let before = before.operation(EncryptionBackend::AwsKms, EncryptionOperation::WrapDek);
+            let after = after.operation(EncryptionBackend::AwsKms, EncryptionOperation::WrapDek);
+            assert!(after.calls >= before.calls + 2);
+            assert!(after.errors > before.errors);
+            // The two-second call overflows every finite bucket.
+            assert!(
+                after.latency_buckets[LATENCY_BUCKETS_MS.len()]
+                    > before.latency_buckets[LATENCY_BUCKETS_MS.len()]
+            );
+        }
+
+        #[test]
+        fn test_prometheus_text_includes_labels() {
+            shared::setup_test!();
+
+            let text = encryption_metrics().to_prometheus_text();
+            assert!(text.contains(
+                "soma_encryption_operations_total{backend=\"local\",operation=\"encrypt_data\"}"
+            ));
+            assert!(text.contains("soma_encryption_dek_cache_total{result=\"miss\"}"));
+        }
+    }
+}
@@ -5,6 +5,7 @@ pub mod crypto_services;
 pub mod dek;
 pub mod dek_alias;
 pub mod envelope;
+pub mod metrics;
 
 pub use crypto_services::*;
 pub use dek::*;
@@ -17,6 +17,9 @@ use crate::logic::envelope::EnvelopeEncryptionKey;
 pub enum EnvelopeEncryptionKeyType {
     Local,
     AwsKms,
+    GcpKms,
+    VaultTransit,
+    AzureKeyVault,
 }
 
 impl EnvelopeEncryptionKeyType {
@@ -25,6 +28,9 @@ impl EnvelopeEncryptionKeyType {
         match self {
             EnvelopeEncryptionKeyType::Local => "local",
             EnvelopeEncryptionKeyType::AwsKms => "aws_kms",
+            EnvelopeEncryptionKeyType::GcpKms => "gcp_kms",
+            EnvelopeEncryptionKeyType::VaultTransit => "vault_transit",
+            EnvelopeEncryptionKeyType::AzureKeyVault => "azure_key_vault",
         }
     }
 }
@@ -36,6 +42,9 @@ impl std::str::FromStr for EnvelopeEncryptionKeyType {
         match s {
             "local" => Ok(EnvelopeEncryptionKeyType::Local),
             "aws_kms" => Ok(EnvelopeEncryptionKeyType::AwsKms),
+            "gcp_kms" => Ok(EnvelopeEncryptionKeyType::GcpKms),
+            "vault_transit" => Ok(EnvelopeEncryptionKeyType::VaultTransit),
+            "azure_key_vault" => Ok(EnvelopeEncryptionKeyType::AzureKeyVault),
             _ => Err(CommonError::Unknown(anyhow::anyhow!(
                 "Invalid key_type: {s}"
             ))),
@@ -89,6 +98,14 @@ pub struct EnvelopeEncryptionKeyRow {
     pub local_file_name: Option<String>,
     pub aws_arn: Option<String>,
     pub aws_region: Option<String>,
+    pub gcp_key_name: Option<String>,
+    pub gcp_location: Option<String>,
+    pub vault_address: Option<String>,
+    pub vault_mount: Option<String>,
+    pub vault_key_name: Option<String>,
+    pub azure_vault_url: Option<String>,
+    pub azure_key_name: Option<String>,
+    pub azure_key_version: Option<String>,
     pub created_at: WrappedChronoDateTime,
     pub updated_at: WrappedChronoDateTime,
 }
@@ -100,6 +117,14 @@ pub struct CreateEnvelopeEncryptionKey {
     pub local_file_name: Option<String>,
     pub aws_arn: Option<String>,
     pub aws_region: Option<String>,
+    pub gcp_key_name: Option<String>,
+    pub gcp_location: Option<String>,
+    pub vault_address: Option<String>,
+    pub vault_mount: Option<String>,
+    pub vault_key_name: Option<String>,
+    pub azure_vault_url: Option<String>,
+    pub azure_key_name: Option<String>,
+    pub azure_key_version: Option<String>,
     pub created_at: WrappedChronoDateTime,
     pub updated_at: WrappedChronoDateTime,
 }
@@ -112,6 +137,14 @@ impl From<EnvelopeEncryptionKeyRow> for CreateEnvelopeEncryptionKey {
             local_file_name: key.local_file_name,
             aws_arn: key.aws_arn,
             aws_region: key.aws_region,
+            gcp_key_name: key.gcp_key_name,
+            gcp_location: key.gcp_location,
+            vault_address: key.vault_address,
+            vault_mount: key.vault_mount,
+            vault_key_name: key.vault_key_name,
+            azure_vault_url: key.azure_vault_url,
+            azure_key_name: key.azure_key_name,
+            azure_key_version: key.azure_key_version,
             created_at: key.created_at,
             updated_at: key.updated_at,
         }
@@ -120,33 +153,55 @@ impl From<EnvelopeEncryptionKeyRow> for CreateEnvelopeEncryptionKey {
 
 impl From<(EnvelopeEncryptionKey, WrappedChronoDateTime)> for CreateEnvelopeEncryptionKey {
     fn from((key, now): (EnvelopeEncryptionKey, WrappedChronoDateTime)) -> Self {
-        // Extract the actual ID (ARN for AWS KMS, location for local)
-        let (id, key_type, local_file_name, aws_arn, aws_region) = match &key {
-            EnvelopeEncryptionKey::AwsKms(aws_kms) => (
-                aws_kms.arn.clone(), // Use ARN as the ID
-                EnvelopeEncryptionKeyType::AwsKms,
-                None,
-                Some(aws_kms.arn.clone()),
-                Some(aws_kms.region.clone()),
-            ),
-            EnvelopeEncryptionKey::Local(local) => (
-                local.file_name.clone(), // Use file_name as the ID
-                EnvelopeEncryptionKeyType::Local,
-                Some(local.file_name.clone()),
-                None,
-                None,
-            ),
-        };
-
-        CreateEnvelopeEncryptionKey {
-            id,
-            key_type,
-            local_file_name,
-            aws_arn,
-            aws_region,
+        // The ID is the ARN for AWS KMS, the key resource name for GCP KMS, the key URL for
+        // Vault Transit and Azure Key Vault and the file name for local keys
+        let mut params = CreateEnvelopeEncryptionKey {
+            id: key.id(),
+            key_type: EnvelopeEncryptionKeyType::Local,
+            local_file_name: None,
+            aws_arn: None,
+            aws_region: None,
+            gcp_key_name: None,
+            gcp_location: None,
+            vault_address: None,
+            vault_mount: None,
+            vault_key_name: None,
+            azure_vault_url: None,
+            azure_key_name: None,
+            azure_key_version: None,
             created_at: now,
             updated_at: now,
+        };
+
+        match key {
+            EnvelopeEncryptionKey::AwsKms(aws_kms) => {
+                params.key_type = EnvelopeEncryptionKeyType::AwsKms;
+                params.aws_arn = Some(aws_kms.arn);
+                params.aws_region = Some(aws_kms.region);
+            }
+            EnvelopeEncryptionKey::Local(local) => {
+                params.local_file_name = Some(local.file_name);
+            }
+            EnvelopeEncryptionKey::GcpKms(gcp_kms) => {
+                params.key_type = EnvelopeEncryptionKeyType::GcpKms;
+                params.gcp_key_name = Some(gcp_kms.key_name);
+                params.gcp_location = Some(gcp_kms.location);
+            }
+            EnvelopeEncryptionKey::VaultTransit(vault) => {
+                params.key_type = EnvelopeEncryptionKeyType::VaultTransit;
+                params.vault_address = Some(vault.address);
+                params.vault_mount = Some(vault.mount);
+                params.vault_key_name = Some(vault.key_name);
+            }
+            EnvelopeEncryptionKey::AzureKeyVault(azure) => {
+                params.key_type = EnvelopeEncryptionKeyType::AzureKeyVault;
+                params.azure_vault_url = Some(azure.vault_url);
+                params.azure_key_name = Some(azure.key_name);
+                params.azure_key_version = Some(azure.key_version);
+            }
         }
+
+        params
     }
 }
 
@@ -166,6 +221,9 @@ impl From<DataEncryptionKey> for CreateDataEncryptionKey {
         let envelope_key_id = match &dek.envelope_encryption_key_id {
             EnvelopeEncryptionKey::AwsKms(aws_kms) => aws_kms.arn.clone(),
             EnvelopeEncryptionKey::Local(local) => local.file_name.clone(),
+            EnvelopeEncryptionKey::GcpKms(_)
+            | EnvelopeEncryptionKey::VaultTransit(_)
+            | EnvelopeEncryptionKey::AzureKeyVault(_) => dek.envelope_encryption_key_id.id(),
         };
         CreateDataEncryptionKey {
             id: dek.id,
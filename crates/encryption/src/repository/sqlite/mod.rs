@@ -21,6 +21,7 @@ use shared::{
     error::CommonError,
     primitives::{
         PaginatedResponse, PaginationRequest, SqlMigrationLoader, decode_pagination_token,
+        pagination_defaults,
     },
 };
 use shared_macros::load_atlas_sql_migrations;
@@ -74,6 +75,14 @@ impl EncryptionKeyRepositoryLike for Repository {
             local_file_name: &params.local_file_name,
             aws_arn: &params.aws_arn,
             aws_region: &params.aws_region,
+            gcp_key_name: &params.gcp_key_name,
+            gcp_location: &params.gcp_location,
+            vault_address: &params.vault_address,
+            vault_mount: &params.vault_mount,
+            vault_key_name: &params.vault_key_name,
+            azure_vault_url: &params.azure_vault_url,
+            azure_key_name: &params.azure_key_name,
+            azure_key_version: &params.azure_key_version,
             created_at: &params.created_at,
             updated_at: &params.updated_at,
         };
@@ -157,7 +166,7 @@ impl EncryptionKeyRepositoryLike for Repository {
 
         let sqlc_params = get_envelope_encryption_keys_paginated_params {
             cursor: &cursor_datetime,
-            page_size: &pagination.page_size,
+            page_size: &pagination.resolved_page_size(),
         };
 
         let rows = get_envelope_encryption_keys_paginated(&self.conn, sqlc_params)
@@ -180,7 +189,8 @@ impl EncryptionKeyRepositoryLike for Repository {
         let items = items?;
 
         // Check if we got more items than requested (page_size + 1)
-        let has_more = items.len() as i64 > pagination.page_size;
+        let page_size = pagination.resolved_page_size();
+        let has_more = items.len() as i64 > page_size;
 
         // If we have more items than page_size, remove the extra item
         let mut items = items;
@@ -203,6 +213,10 @@ impl EncryptionKeyRepositoryLike for Repository {
         Ok(PaginatedResponse {
             items,
             next_page_token,
+            prev_page_token: None,
+            total_count: None,
+            page_size,
+            order: pagination_defaults().order,
         })
     }
 
@@ -322,7 +336,7 @@ impl EncryptionKeyRepositoryLike for Repository {
 
         let sqlc_params = get_data_encryption_keys_params {
             cursor: &cursor_datetime,
-            page_size: &pagination.page_size,
+            page_size: &pagination.resolved_page_size(),
         };
 
         let rows = get_data_encryption_keys(&self.conn, sqlc_params)
@@ -638,6 +652,14 @@ mod tests {
                 local_file_name: None,
                 aws_arn: Some("arn:aws:kms:eu-west-2:123456789012:key/test-key".to_string()),
                 aws_region: Some("eu-west-2".to_string()),
+                gcp_key_name: None,
+                gcp_location: None,
+                vault_address: None,
+                vault_mount: None,
+                vault_key_name: None,
+                azure_vault_url: None,
+                azure_key_name: None,
+                azure_key_version: None,
                 created_at: now,
                 updated_at: now,
             };
@@ -655,6 +677,14 @@ mod tests {
                 local_file_name: Some("/path/to/key".to_string()),
                 aws_arn: None,
                 aws_region: None,
+                gcp_key_name: None,
+                gcp_location: None,
+                vault_address: None,
+                vault_mount: None,
+                vault_key_name: None,
+                azure_vault_url: None,
+                azure_key_name: None,
+                azure_key_version: None,
                 created_at: now,
                 updated_at: now,
             };
@@ -916,6 +946,7 @@ mod tests {
             let pagination = PaginationRequest {
                 page_size: 10,
                 next_page_token: None,
+                ..Default::default()
             };
             let result = repo.list_data_encryption_keys(&pagination).await.unwrap();
 
@@ -951,6 +982,7 @@ mod tests {
             let pagination = PaginationRequest {
                 page_size: 2,
                 next_page_token: None,
+                ..Default::default()
             };
             let result = repo.list_data_encryption_keys(&pagination).await.unwrap();
 
@@ -964,6 +996,7 @@ mod tests {
             let pagination = PaginationRequest {
                 page_size: 2,
                 next_page_token: result.next_page_token.clone(),
+                ..Default::default()
             };
             let result = repo.list_data_encryption_keys(&pagination).await.unwrap();
 
@@ -1130,6 +1163,7 @@ mod tests {
             let pagination = PaginationRequest {
                 page_size: 10,
                 next_page_token: None,
+                ..Default::default()
             };
             let result = repo.list_data_encryption_keys(&pagination).await.unwrap();
 
@@ -1172,6 +1206,7 @@ mod tests {
             let pagination = PaginationRequest {
                 page_size: 10,
                 next_page_token: None,
+                ..Default::default()
             };
             let result = repo.list_data_encryption_keys(&pagination).await.unwrap();
 
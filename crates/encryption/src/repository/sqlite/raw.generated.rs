@@ -18,6 +18,30 @@ use serde::{Serialize, Deserialize};
       pub aws_region: &'a Option<
           String
       >,
+      pub gcp_key_name: &'a Option<
+          String
+      >,
+      pub gcp_location: &'a Option<
+          String
+      >,
+      pub vault_address: &'a Option<
+          String
+      >,
+      pub vault_mount: &'a Option<
+          String
+      >,
+      pub vault_key_name: &'a Option<
+          String
+      >,
+      pub azure_vault_url: &'a Option<
+          String
+      >,
+      pub azure_key_name: &'a Option<
+          String
+      >,
+      pub azure_key_version: &'a Option<
+          String
+      >,
       pub created_at: &'a 
           shared::primitives::WrappedChronoDateTime
       ,
@@ -30,8 +54,8 @@ use serde::{Serialize, Deserialize};
     conn: &shared::libsql::Connection
     ,params: create_envelope_encryption_key_params<'_>
 ) -> Result<u64, libsql::Error> {
-    conn.execute(r#"INSERT INTO envelope_encryption_key (id, key_type, local_file_name, aws_arn, aws_region, created_at, updated_at)
-VALUES (?, ?, ?, ?, ?, ?, ?)"#, libsql::params![
+    conn.execute(r#"INSERT INTO envelope_encryption_key (id, key_type, local_file_name, aws_arn, aws_region, gcp_key_name, gcp_location, vault_address, vault_mount, vault_key_name, azure_vault_url, azure_key_name, azure_key_version, created_at, updated_at)
+VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"#, libsql::params![
               <String as TryInto<libsql::Value>>::try_into(params.id.clone())
                   .map_err(|e| libsql::Error::ToSqlConversionFailure(e.into()))?
             ,
@@ -61,6 +85,70 @@ VALUES (?, ?, ?, ?, ?, ?, ?)"#, libsql::params![
                 },
                 None => libsql::Value::Null,
               }
+            ,
+              match params.gcp_key_name.clone() {
+                Some(value) => {
+                  <String as TryInto<libsql::Value>>::try_into(value.clone())
+                      .map_err(|e| libsql::Error::ToSqlConversionFailure(e.into()))?
+                },
+                None => libsql::Value::Null,
+              }
+            ,
+              match params.gcp_location.clone() {
+                Some(value) => {
+                  <String as TryInto<libsql::Value>>::try_into(value.clone())
+                      .map_err(|e| libsql::Error::ToSqlConversionFailure(e.into()))?
+                },
+                None => libsql::Value::Null,
+              }
+            ,
+              match params.vault_address.clone() {
+                Some(value) => {
+                  <String as TryInto<libsql::Value>>::try_into(value.clone())
+                      .map_err(|e| libsql::Error::ToSqlConversionFailure(e.into()))?
+                },
+                None => libsql::Value::Null,
+              }
+            ,
+              match params.vault_mount.clone() {
+                Some(value) => {
+                  <String as TryInto<libsql::Value>>::try_into(value.clone())
+                      .map_err(|e| libsql::Error::ToSqlConversionFailure(e.into()))?
+                },
+                None => libsql::Value::Null,
+              }
+            ,
+              match params.vault_key_name.clone() {
+                Some(value) => {
+                  <String as TryInto<libsql::Value>>::try_into(value.clone())
+                      .map_err(|e| libsql::Error::ToSqlConversionFailure(e.into()))?
+                },
+                None => libsql::Value::Null,
+              }
+            ,
+              match params.azure_vault_url.clone() {
+                Some(value) => {
+                  <String as TryInto<libsql::Value>>::try_into(value.clone())
+                      .map_err(|e| libsql::Error::ToSqlConversionFailure(e.into()))?
+                },
+                None => libsql::Value::Null,
+              }
+            ,
+              match params.azure_key_name.clone() {
+                Some(value) => {
+                  <String as TryInto<libsql::Value>>::try_into(value.clone())
+                      .map_err(|e| libsql::Error::ToSqlConversionFailure(e.into()))?
+                },
+                None => libsql::Value::Null,
+              }
+            ,
+              match params.azure_key_version.clone() {
+                Some(value) => {
+                  <String as TryInto<libsql::Value>>::try_into(value.clone())
+                      .map_err(|e| libsql::Error::ToSqlConversionFailure(e.into()))?
+                },
+                None => libsql::Value::Null,
+              }
             ,
               <shared::primitives::WrappedChronoDateTime as TryInto<libsql::Value>>::try_into(params.created_at.clone())
                   .map_err(|e| libsql::Error::ToSqlConversionFailure(e.into()))?
@@ -84,6 +172,14 @@ VALUES (?, ?, ?, ?, ?, ?, ?)"#, libsql::params![
       pub local_file_name:Option<String> ,
       pub aws_arn:Option<String> ,
       pub aws_region:Option<String> ,
+      pub gcp_key_name:Option<String> ,
+      pub gcp_location:Option<String> ,
+      pub vault_address:Option<String> ,
+      pub vault_mount:Option<String> ,
+      pub vault_key_name:Option<String> ,
+      pub azure_vault_url:Option<String> ,
+      pub azure_key_name:Option<String> ,
+      pub azure_key_version:Option<String> ,
       pub created_at:shared::primitives::WrappedChronoDateTime,
       pub updated_at:shared::primitives::WrappedChronoDateTime,
   }
@@ -91,7 +187,7 @@ VALUES (?, ?, ?, ?, ?, ?, ?)"#, libsql::params![
       conn: &shared::libsql::Connection
       ,params: get_envelope_encryption_key_by_id_params<'_>
   ) -> Result<Option<Row_get_envelope_encryption_key_by_id>, libsql::Error> {
-      let mut stmt = conn.prepare(r#"SELECT id, key_type, local_file_name, aws_arn, aws_region, created_at, updated_at
+      let mut stmt = conn.prepare(r#"SELECT id, key_type, local_file_name, aws_arn, aws_region, gcp_key_name, gcp_location, vault_address, vault_mount, vault_key_name, azure_vault_url, azure_key_name, azure_key_version, created_at, updated_at
 FROM envelope_encryption_key
 WHERE id = ?"#).await?;
       let res = stmt.query_row(
@@ -105,8 +201,16 @@ WHERE id = ?"#).await?;
                   local_file_name: row.get(2)?,
                   aws_arn: row.get(3)?,
                   aws_region: row.get(4)?,
-                  created_at: row.get(5)?,
-                  updated_at: row.get(6)?,
+                  gcp_key_name: row.get(5)?,
+                  gcp_location: row.get(6)?,
+                  vault_address: row.get(7)?,
+                  vault_mount: row.get(8)?,
+                  vault_key_name: row.get(9)?,
+                  azure_vault_url: row.get(10)?,
+                  azure_key_name: row.get(11)?,
+                  azure_key_version: row.get(12)?,
+                  created_at: row.get(13)?,
+                  updated_at: row.get(14)?,
               })),
           Err(libsql::Error::QueryReturnedNoRows) => Ok(None),
           Err(e) => Err(e),
@@ -121,13 +225,21 @@ WHERE id = ?"#).await?;
       pub local_file_name:Option<String> ,
       pub aws_arn:Option<String> ,
       pub aws_region:Option<String> ,
+      pub gcp_key_name:Option<String> ,
+      pub gcp_location:Option<String> ,
+      pub vault_address:Option<String> ,
+      pub vault_mount:Option<String> ,
+      pub vault_key_name:Option<String> ,
+      pub azure_vault_url:Option<String> ,
+      pub azure_key_name:Option<String> ,
+      pub azure_key_version:Option<String> ,
       pub created_at:shared::primitives::WrappedChronoDateTime,
       pub updated_at:shared::primitives::WrappedChronoDateTime,
   }
   pub async fn get_envelope_encryption_keys(
       conn: &shared::libsql::Connection
   ) -> Result<Vec<Row_get_envelope_encryption_keys>, libsql::Error> {
-      let stmt = conn.prepare(r#"SELECT id, key_type, local_file_name, aws_arn, aws_region, created_at, updated_at
+      let stmt = conn.prepare(r#"SELECT id, key_type, local_file_name, aws_arn, aws_region, gcp_key_name, gcp_location, vault_address, vault_mount, vault_key_name, azure_vault_url, azure_key_name, azure_key_version, created_at, updated_at
 FROM envelope_encryption_key
 ORDER BY created_at DESC"#).await?;
       let mut rows = stmt.query(libsql::params![]).await?;
@@ -140,8 +252,16 @@ ORDER BY created_at DESC"#).await?;
               local_file_name: row.get(2)?,
               aws_arn: row.get(3)?,
               aws_region: row.get(4)?,
-              created_at: row.get(5)?,
-              updated_at: row.get(6)?,
+              gcp_key_name: row.get(5)?,
+              gcp_location: row.get(6)?,
+              vault_address: row.get(7)?,
+              vault_mount: row.get(8)?,
+              vault_key_name: row.get(9)?,
+              azure_vault_url: row.get(10)?,
+              azure_key_name: row.get(11)?,
+              azure_key_version: row.get(12)?,
+              created_at: row.get(13)?,
+              updated_at: row.get(14)?,
           });
       }
 
@@ -164,6 +284,14 @@ ORDER BY created_at DESC"#).await?;
       pub local_file_name:Option<String> ,
       pub aws_arn:Option<String> ,
       pub aws_region:Option<String> ,
+      pub gcp_key_name:Option<String> ,
+      pub gcp_location:Option<String> ,
+      pub vault_address:Option<String> ,
+      pub vault_mount:Option<String> ,
+      pub vault_key_name:Option<String> ,
+      pub azure_vault_url:Option<String> ,
+      pub azure_key_name:Option<String> ,
+      pub azure_key_version:Option<String> ,
       pub created_at:shared::primitives::WrappedChronoDateTime,
       pub updated_at:shared::primitives::WrappedChronoDateTime,
   }
@@ -171,7 +299,7 @@ ORDER BY created_at DESC"#).await?;
       conn: &shared::libsql::Connection
       ,params: get_envelope_encryption_keys_paginated_params<'_>
   ) -> Result<Vec<Row_get_envelope_encryption_keys_paginated>, libsql::Error> {
-      let stmt = conn.prepare(r#"SELECT id, key_type, local_file_name, aws_arn, aws_region, created_at, updated_at
+      let stmt = conn.prepare(r#"SELECT id, key_type, local_file_name, aws_arn, aws_region, gcp_key_name, gcp_location, vault_address, vault_mount, vault_key_name, azure_vault_url, azure_key_name, azure_key_version, created_at, updated_at
 FROM envelope_encryption_key 
 WHERE (created_at < ?1 OR ?1 IS NULL)
 ORDER BY created_at DESC
@@ -186,8 +314,16 @@ LIMIT CAST(?2 AS INTEGER) + 1"#).await?;
               local_file_name: row.get(2)?,
               aws_arn: row.get(3)?,
               aws_region: row.get(4)?,
-              created_at: row.get(5)?,
-              updated_at: row.get(6)?,
+              gcp_key_name: row.get(5)?,
+              gcp_location: row.get(6)?,
+              vault_address: row.get(7)?,
+              vault_mount: row.get(8)?,
+              vault_key_name: row.get(9)?,
+              azure_vault_url: row.get(10)?,
+              azure_key_name: row.get(11)?,
+              azure_key_version: row.get(12)?,
+              created_at: row.get(13)?,
+              updated_at: row.get(14)?,
           });
       }
 
@@ -350,6 +486,14 @@ WHERE id = ?"#).await?;
       pub local_file_name:Option<String> ,
       pub aws_arn:Option<String> ,
       pub aws_region:Option<String> ,
+      pub gcp_key_name:Option<String> ,
+      pub gcp_location:Option<String> ,
+      pub vault_address:Option<String> ,
+      pub vault_mount:Option<String> ,
+      pub vault_key_name:Option<String> ,
+      pub azure_vault_url:Option<String> ,
+      pub azure_key_name:Option<String> ,
+      pub azure_key_version:Option<String> ,
   }
   pub async fn get_data_encryption_key_by_id_with_envelope(
       conn: &shared::libsql::Connection
@@ -364,7 +508,15 @@ WHERE id = ?"#).await?;
     eek.key_type,
     eek.local_file_name,
     eek.aws_arn,
-    eek.aws_region
+    eek.aws_region,
+    eek.gcp_key_name,
+    eek.gcp_location,
+    eek.vault_address,
+    eek.vault_mount,
+    eek.vault_key_name,
+    eek.azure_vault_url,
+    eek.azure_key_name,
+    eek.azure_key_version
 FROM data_encryption_key dek
 JOIN envelope_encryption_key eek ON dek.envelope_encryption_key_id = eek.id
 WHERE dek.id = ?"#).await?;
@@ -383,6 +535,14 @@ WHERE dek.id = ?"#).await?;
                   local_file_name: row.get(6)?,
                   aws_arn: row.get(7)?,
                   aws_region: row.get(8)?,
+                  gcp_key_name: row.get(9)?,
+                  gcp_location: row.get(10)?,
+                  vault_address: row.get(11)?,
+                  vault_mount: row.get(12)?,
+                  vault_key_name: row.get(13)?,
+                  azure_vault_url: row.get(14)?,
+                  azure_key_name: row.get(15)?,
+                  azure_key_version: row.get(16)?,
               })),
           Err(libsql::Error::QueryReturnedNoRows) => Ok(None),
           Err(e) => Err(e),
@@ -457,6 +617,14 @@ LIMIT CAST(?2 AS INTEGER) + 1"#).await?;
       pub local_file_name:Option<String> ,
       pub aws_arn:Option<String> ,
       pub aws_region:Option<String> ,
+      pub gcp_key_name:Option<String> ,
+      pub gcp_location:Option<String> ,
+      pub vault_address:Option<String> ,
+      pub vault_mount:Option<String> ,
+      pub vault_key_name:Option<String> ,
+      pub azure_vault_url:Option<String> ,
+      pub azure_key_name:Option<String> ,
+      pub azure_key_version:Option<String> ,
   }
   pub async fn get_all_data_encryption_keys_with_envelope_keys(
       conn: &shared::libsql::Connection
@@ -470,7 +638,15 @@ LIMIT CAST(?2 AS INTEGER) + 1"#).await?;
     eek.key_type,
     eek.local_file_name,
     eek.aws_arn,
-    eek.aws_region
+    eek.aws_region,
+    eek.gcp_key_name,
+    eek.gcp_location,
+    eek.vault_address,
+    eek.vault_mount,
+    eek.vault_key_name,
+    eek.azure_vault_url,
+    eek.azure_key_name,
+    eek.azure_key_version
 FROM data_encryption_key dek
 JOIN envelope_encryption_key eek ON dek.envelope_encryption_key_id = eek.id"#).await?;
       let mut rows = stmt.query(libsql::params![]).await?;
@@ -487,6 +663,14 @@ JOIN envelope_encryption_key eek ON dek.envelope_encryption_key_id = eek.id"#).a
               local_file_name: row.get(6)?,
               aws_arn: row.get(7)?,
               aws_region: row.get(8)?,
+              gcp_key_name: row.get(9)?,
+              gcp_location: row.get(10)?,
+              vault_address: row.get(11)?,
+              vault_mount: row.get(12)?,
+              vault_key_name: row.get(13)?,
+              azure_vault_url: row.get(14)?,
+              azure_key_name: row.get(15)?,
+              azure_key_version: row.get(16)?,
           });
       }
 
@@ -10,9 +10,42 @@ use super::{
 // Conversion from repository EnvelopeEncryptionKey row types to logic EnvelopeEncryptionKey enum
 use crate::logic::envelope::{
     EnvelopeEncryptionKey as LogicEnvelopeEncryptionKey, EnvelopeEncryptionKeyAwsKms,
-    EnvelopeEncryptionKeyLocal,
+    EnvelopeEncryptionKeyAzureKeyVault, EnvelopeEncryptionKeyGcpKms, EnvelopeEncryptionKeyLocal,
+    EnvelopeEncryptionKeyVaultTransit,
 };
 
+fn vault_transit_key_from_row(
+    address: Option<String>,
+    mount: Option<String>,
+    key_name: Option<String>,
+) -> Result<LogicEnvelopeEncryptionKey, CommonError> {
+    let missing =
+        |field: &str| CommonError::Unknown(anyhow::anyhow!("Vault Transit key missing {field}"));
+    Ok(LogicEnvelopeEncryptionKey::VaultTransit(
+        EnvelopeEncryptionKeyVaultTransit {
+            address: address.ok_or_else(|| missing("address"))?,
+            mount: mount.ok_or_else(|| missing("mount"))?,
+            key_name: key_name.ok_or_else(|| missing("key name"))?,
+        },
+    ))
+}
+
+fn azure_key_vault_key_from_row(
+    vault_url: Option<String>,
+    key_name: Option<String>,
+    key_version: Option<String>,
+) -> Result<LogicEnvelopeEncryptionKey, CommonError> {
+    let missing =
+        |field: &str| CommonError::Unknown(anyhow::anyhow!("Azure Key Vault key missing {field}"));
+    Ok(LogicEnvelopeEncryptionKey::AzureKeyVault(
+        EnvelopeEncryptionKeyAzureKeyVault {
+            vault_url: vault_url.ok_or_else(|| missing("vault URL"))?,
+            key_name: key_name.ok_or_else(|| missing("key name"))?,
+            key_version: key_version.ok_or_else(|| missing("key version"))?,
+        },
+    ))
+}
+
 impl TryFrom<Row_get_envelope_encryption_key_by_id> for LogicEnvelopeEncryptionKey {
     type Error = CommonError;
 
@@ -37,6 +70,27 @@ impl TryFrom<Row_get_envelope_encryption_key_by_id> for LogicEnvelopeEncryptionK
                     EnvelopeEncryptionKeyLocal { file_name },
                 ))
             }
+            crate::repository::EnvelopeEncryptionKeyType::GcpKms => {
+                let key_name = row.gcp_key_name.ok_or_else(|| {
+                    CommonError::Unknown(anyhow::anyhow!("GCP KMS key missing key name"))
+                })?;
+                let location = row.gcp_location.ok_or_else(|| {
+                    CommonError::Unknown(anyhow::anyhow!("GCP KMS key missing location"))
+                })?;
+                Ok(LogicEnvelopeEncryptionKey::GcpKms(
+                    EnvelopeEncryptionKeyGcpKms { key_name, location },
+                ))
+            }
+            crate::repository::EnvelopeEncryptionKeyType::VaultTransit => {
+                vault_transit_key_from_row(row.vault_address, row.vault_mount, row.vault_key_name)
+            }
+            crate::repository::EnvelopeEncryptionKeyType::AzureKeyVault => {
+                azure_key_vault_key_from_row(
+                    row.azure_vault_url,
+                    row.azure_key_name,
+                    row.azure_key_version,
+                )
+            }
         }
     }
 }
@@ -65,6 +119,27 @@ impl TryFrom<Row_get_envelope_encryption_keys> for LogicEnvelopeEncryptionKey {
                     EnvelopeEncryptionKeyLocal { file_name },
                 ))
             }
+            crate::repository::EnvelopeEncryptionKeyType::GcpKms => {
+                let key_name = row.gcp_key_name.ok_or_else(|| {
+                    CommonError::Unknown(anyhow::anyhow!("GCP KMS key missing key name"))
+                })?;
+                let location = row.gcp_location.ok_or_else(|| {
+                    CommonError::Unknown(anyhow::anyhow!("GCP KMS key missing location"))
+                })?;
+                Ok(LogicEnvelopeEncryptionKey::GcpKms(
+                    EnvelopeEncryptionKeyGcpKms { key_name, location },
+                ))
+            }
+            crate::repository::EnvelopeEncryptionKeyType::VaultTransit => {
+                vault_transit_key_from_row(row.vault_address, row.vault_mount, row.vault_key_name)
+            }
+            crate::repository::EnvelopeEncryptionKeyType::AzureKeyVault => {
+                azure_key_vault_key_from_row(
+                    row.azure_vault_url,
+                    row.azure_key_name,
+                    row.azure_key_version,
+                )
+            }
         }
     }
 }
@@ -93,6 +168,27 @@ impl TryFrom<Row_get_envelope_encryption_keys_paginated> for LogicEnvelopeEncryp
                     EnvelopeEncryptionKeyLocal { file_name },
                 ))
             }
+            crate::repository::EnvelopeEncryptionKeyType::GcpKms => {
+                let key_name = row.gcp_key_name.ok_or_else(|| {
+                    CommonError::Unknown(anyhow::anyhow!("GCP KMS key missing key name"))
+                })?;
+                let location = row.gcp_location.ok_or_else(|| {
+                    CommonError::Unknown(anyhow::anyhow!("GCP KMS key missing location"))
+                })?;
+                Ok(LogicEnvelopeEncryptionKey::GcpKms(
+                    EnvelopeEncryptionKeyGcpKms { key_name, location },
+                ))
+            }
+            crate::repository::EnvelopeEncryptionKeyType::VaultTransit => {
+                vault_transit_key_from_row(row.vault_address, row.vault_mount, row.vault_key_name)
+            }
+            crate::repository::EnvelopeEncryptionKeyType::AzureKeyVault => {
+                azure_key_vault_key_from_row(
+                    row.azure_vault_url,
+                    row.azure_key_name,
+                    row.azure_key_version,
+                )
+            }
         }
     }
 }
@@ -123,6 +219,28 @@ impl TryFrom<Row_get_all_data_encryption_keys_with_envelope_keys> for LogicDataE
                 })?;
                 LogicEnvelopeEncryptionKey::Local(EnvelopeEncryptionKeyLocal { file_name })
             }
+            crate::repository::EnvelopeEncryptionKeyType::GcpKms => {
+                let key_name = row.gcp_key_name.ok_or_else(|| {
+                    CommonError::Unknown(anyhow::anyhow!("GCP KMS key missing key name"))
+                })?;
+                let location = row.gcp_location.ok_or_else(|| {
+                    CommonError::Unknown(anyhow::anyhow!("GCP KMS key missing location"))
+                })?;
+                LogicEnvelopeEncryptionKey::GcpKms(EnvelopeEncryptionKeyGcpKms {
+                    key_name,
+                    location,
+                })
+            }
+            crate::repository::EnvelopeEncryptionKeyType::VaultTransit => {
+                vault_transit_key_from_row(row.vault_address, row.vault_mount, row.vault_key_name)?
+            }
+            crate::repository::EnvelopeEncryptionKeyType::AzureKeyVault => {
+                azure_key_vault_key_from_row(
+                    row.azure_vault_url,
+                    row.azure_key_name,
+                    row.azure_key_version,
+                )?
+            }
         };
 
         Ok(LogicDataEncryptionKey {
@@ -156,6 +274,28 @@ impl TryFrom<Row_get_data_encryption_key_by_id_with_envelope> for LogicDataEncry
                 })?;
                 LogicEnvelopeEncryptionKey::Local(EnvelopeEncryptionKeyLocal { file_name })
             }
+            crate::repository::EnvelopeEncryptionKeyType::GcpKms => {
+                let key_name = row.gcp_key_name.ok_or_else(|| {
+                    CommonError::Unknown(anyhow::anyhow!("GCP KMS key missing key name"))
+                })?;
+                let location = row.gcp_location.ok_or_else(|| {
+                    CommonError::Unknown(anyhow::anyhow!("GCP KMS key missing location"))
+                })?;
+                LogicEnvelopeEncryptionKey::GcpKms(EnvelopeEncryptionKeyGcpKms {
+                    key_name,
+                    location,
+                })
+            }
+            crate::repository::EnvelopeEncryptionKeyType::VaultTransit => {
+                vault_transit_key_from_row(row.vault_address, row.vault_mount, row.vault_key_name)?
+            }
+            crate::repository::EnvelopeEncryptionKeyType::AzureKeyVault => {
+                azure_key_vault_key_from_row(
+                    row.azure_vault_url,
+                    row.azure_key_name,
+                    row.azure_key_version,
+                )?
+            }
         };
 
         Ok(LogicDataEncryptionKey {
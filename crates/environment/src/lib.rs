@@ -4,6 +4,7 @@
 //! - Secret storage with encryption (using DEK aliases)
 //! - Environment variable storage (plain-text)
 //! - Change event publishing for real-time updates
+//! - Audit events for secret access
 //! - HTTP API endpoints for CRUD operations
 
 pub mod logic;
@@ -15,6 +16,7 @@ pub mod service;
 pub mod test;
 
 // Re-export commonly used types
+pub use logic::audit::{AuditAction, AuditEvent, AuditSink, NoopAuditSink};
 pub use logic::secret::{Secret, SecretChangeEvt, SecretChangeTx, create_secret_change_channel};
 pub use logic::variable::{
     Variable, VariableChangeEvt, VariableChangeTx, create_variable_change_channel,
@@ -0,0 +1,107 @@
+//! Audit trail for secret access
+//! Every secret read, create, update and delete is emitted as a structured `tracing` event on the
+//! [`AUDIT_TRACING_TARGET`] target and handed to an [`AuditSink`]. Secret values are never recorded.
+
+use serde::Serialize;
+use shared::{identity::Identity, primitives::WrappedChronoDateTime};
+use tracing::info;
+
+/// `tracing` target used for audit events so they can be routed separately from other logs
+pub const AUDIT_TRACING_TARGET: &str = "soma::audit";
+
+/// Principal recorded for requests that carry no caller identity
+pub const ANONYMOUS_PRINCIPAL: &str = "anonymous";
+
+/// Operation performed on a secret
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditAction {
+    Read,
+    Created,
+    Updated,
+    Deleted,
+}
+
+impl AuditAction {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AuditAction::Read => "read",
+            AuditAction::Created => "created",
+            AuditAction::Updated => "updated",
+            AuditAction::Deleted => "deleted",
+        }
+    }
+}
+
+/// A single audited secret operation. Deliberately has no field for the secret value.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct AuditEvent {
+    pub action: AuditAction,
+    pub secret_key: String,
+    pub principal: String,
+    pub occurred_at: WrappedChronoDateTime,
+}
+
+/// Destination for audit events, e.g. a compliance log or a dedicated channel
+pub trait AuditSink: Send + Sync {
+    fn record(&self, event: &AuditEvent);
+}
+
+/// Default sink; audit events are only emitted through `tracing`
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoopAuditSink;
+
+impl AuditSink for NoopAuditSink {
+    fn record(&self, _event: &AuditEvent) {}
+}
+
+/// Forwards audit events onto a dedicated channel. Events are dropped once the receiver is gone.
+impl AuditSink for tokio::sync::mpsc::UnboundedSender<AuditEvent> {
+    fn record(&self, event: &AuditEvent) {
+        let _ = self.send(event.clone());
+    }
+}
+
+/// Principal string for the caller of a request
+pub fn principal_from_identity(identity: Option<&Identity>) -> String {
+    match identity {
+        Some(Identity::Machine(machine)) => machine.sub.clone(),
+        Some(Identity::Human(human)) => human.sub.clone(),
+        Some(Identity::MachineOnBehalfOfHuman { machine, human }) => {
+            format!("{} (on behalf of {})", machine.sub, human.sub)
+        }
+        Some(Identity::Unauthenticated) | None => ANONYMOUS_PRINCIPAL.to_string(),
+    }
+}
+
+/// Records secret operations performed on behalf of one caller
+pub struct SecretAudit<'a> {
+    sink: &'a dyn AuditSink,
+    principal: String,
+}
+
+impl<'a> SecretAudit<'a> {
+    pub fn new(sink: &'a dyn AuditSink, principal: impl Into<String>) -> Self {
+        Self {
+            sink,
+            principal: principal.into(),
+        }
+    }
+
+    pub fn record(&self, action: AuditAction, secret_key: &str) {
+        let event = AuditEvent {
+            action,
+            secret_key: secret_key.to_string(),
+            principal: self.principal.clone(),
+            occurred_at: WrappedChronoDateTime::now(),
+        };
+        info!(
+            target: AUDIT_TRACING_TARGET,
+            action = event.action.as_str(),
+            secret_key = %event.secret_key,
+            principal = %event.principal,
+            "Secret accessed"
+        );
+        self.sink.record(&event);
+    }
+}
@@ -1,5 +1,6 @@
 //! Business logic layer for environment crate
 //! Contains domain models, CRUD operations, and event publishing for secrets and variables
 
+pub mod audit;
 pub mod secret;
 pub mod variable;
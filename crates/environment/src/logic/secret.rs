@@ -10,7 +10,10 @@ use shared::{
 };
 use utoipa::ToSchema;
 
-use crate::repository::{CreateSecret, SecretRepositoryLike, UpdateSecret};
+use crate::{
+    logic::audit::{AuditAction, SecretAudit},
+    repository::{CreateSecret, SecretRepositoryLike, UpdateSecret},
+};
 
 /// Domain model for Secret - an encrypted key-value pair
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema, JsonSchema)]
@@ -79,7 +82,12 @@ pub struct ListDecryptedSecretsResponse {
 pub enum SecretChangeEvt {
     Created(Secret),
     Updated(Secret),
-    Deleted { id: String, key: String },
+    Deleted {
+        id: String,
+        key: String,
+    },
+    /// Several secrets created or updated together by [`set_secrets_batch`]
+    BatchUpdated(Vec<Secret>),
 }
 
 /// Channel type for broadcasting secret change events
@@ -98,6 +106,7 @@ pub async fn create_secret<R: SecretRepositoryLike>(
     on_change_tx: &SecretChangeTx,
     repository: &R,
     crypto_cache: &CryptoCache,
+    audit: &SecretAudit<'_>,
     request: CreateSecretRequest,
     publish_on_change_evt: bool,
 ) -> Result<CreateSecretResponse, CommonError> {
@@ -131,6 +140,7 @@ pub async fn create_secret<R: SecretRepositoryLike>(
     };
 
     repository.create_secret(&create_params).await?;
+    audit.record(AuditAction::Created, &secret.key);
 
     if publish_on_change_evt {
         on_change_tx
@@ -148,6 +158,7 @@ pub async fn update_secret<R: SecretRepositoryLike>(
     on_change_tx: &SecretChangeTx,
     repository: &R,
     crypto_cache: &CryptoCache,
+    audit: &SecretAudit<'_>,
     id: WrappedUuidV4,
     request: UpdateSecretRequest,
     publish_on_change_evt: bool,
@@ -178,6 +189,7 @@ pub async fn update_secret<R: SecretRepositoryLike>(
     };
 
     repository.update_secret(&update_params).await?;
+    audit.record(AuditAction::Updated, &existing.key);
 
     let updated_secret = Secret {
         id,
@@ -199,10 +211,93 @@ pub async fn update_secret<R: SecretRepositoryLike>(
     Ok(updated_secret)
 }
 
+/// Create or update several secrets by key. All values are encrypted first and then persisted in
+/// a single transaction, and one [`SecretChangeEvt::BatchUpdated`] is published for the batch.
+/// Existing secrets keep their DEK alias, like [`update_secret`].
+pub async fn set_secrets_batch<R: SecretRepositoryLike>(
+    on_change_tx: &SecretChangeTx,
+    repository: &R,
+    crypto_cache: &CryptoCache,
+    audit: &SecretAudit<'_>,
+    requests: Vec<CreateSecretRequest>,
+    publish_on_change_evt: bool,
+) -> Result<Vec<Secret>, CommonError> {
+    let now = WrappedChronoDateTime::now();
+    let mut creates = Vec::new();
+    let mut updates = Vec::new();
+    let mut secrets = Vec::with_capacity(requests.len());
+    let mut audit_actions = Vec::with_capacity(requests.len());
+
+    for request in requests {
+        let existing = repository.get_secret_by_key(&request.key).await?;
+        let dek_alias = existing
+            .as_ref()
+            .map(|existing| existing.dek_alias.clone())
+            .unwrap_or(request.dek_alias);
+
+        let encryption_service = crypto_cache.get_encryption_service(&dek_alias).await?;
+        let encrypted_secret = encryption_service.encrypt_data(request.raw_value).await?;
+
+        let secret = match existing {
+            Some(existing) => {
+                audit_actions.push(AuditAction::Updated);
+                updates.push(UpdateSecret {
+                    id: existing.id.clone(),
+                    encrypted_secret: encrypted_secret.0.clone(),
+                    dek_alias: dek_alias.clone(),
+                    updated_at: now,
+                });
+                Secret {
+                    encrypted_secret: encrypted_secret.0,
+                    updated_at: now,
+                    ..existing
+                }
+            }
+            None => {
+                audit_actions.push(AuditAction::Created);
+                let id = WrappedUuidV4::new();
+                creates.push(CreateSecret {
+                    id: id.clone(),
+                    key: request.key.clone(),
+                    encrypted_secret: encrypted_secret.0.clone(),
+                    dek_alias: dek_alias.clone(),
+                    created_at: now,
+                    updated_at: now,
+                });
+                Secret {
+                    id,
+                    key: request.key,
+                    encrypted_secret: encrypted_secret.0,
+                    dek_alias,
+                    created_at: now,
+                    updated_at: now,
+                }
+            }
+        };
+        secrets.push(secret);
+    }
+
+    repository.apply_secrets_batch(&creates, &updates).await?;
+    for (action, secret) in audit_actions.into_iter().zip(&secrets) {
+        audit.record(action, &secret.key);
+    }
+
+    if publish_on_change_evt && !secrets.is_empty() {
+        on_change_tx
+            .send(SecretChangeEvt::BatchUpdated(secrets.clone()))
+            .map_err(|e| {
+                CommonError::Unknown(anyhow::anyhow!("Failed to send secret change event: {e}"))
+            })?;
+    }
+
+    Ok(secrets)
+}
+
 /// Delete a secret by ID
 pub async fn delete_secret<R: SecretRepositoryLike>(
     on_change_tx: &SecretChangeTx,
     repository: &R,
+    audit: &SecretAudit<'_>,
     id: WrappedUuidV4,
     publish_on_change_evt: bool,
 ) -> Result<DeleteSecretResponse, CommonError> {
@@ -215,6 +310,7 @@ pub async fn delete_secret<R: SecretRepositoryLike>(
     })?;
 
     repository.delete_secret(&id).await?;
+    audit.record(AuditAction::Deleted, &existing.key);
 
     if publish_on_change_evt {
         on_change_tx
@@ -233,6 +329,7 @@ pub async fn delete_secret<R: SecretRepositoryLike>(
 /// Get a secret by ID
 pub async fn get_secret_by_id<R: SecretRepositoryLike>(
     repository: &R,
+    audit: &SecretAudit<'_>,
     id: WrappedUuidV4,
 ) -> Result<GetSecretResponse, CommonError> {
     let secret = repository.get_secret_by_id(&id).await?;
@@ -241,6 +338,7 @@ pub async fn get_secret_by_id<R: SecretRepositoryLike>(
         lookup_id: id.to_string(),
         source: None,
     })?;
+    audit.record(AuditAction::Read, &secret.key);
 
     Ok(secret)
 }
@@ -248,6 +346,7 @@ pub async fn get_secret_by_id<R: SecretRepositoryLike>(
 /// Get a secret by key
 pub async fn get_secret_by_key<R: SecretRepositoryLike>(
     repository: &R,
+    audit: &SecretAudit<'_>,
     key: String,
 ) -> Result<GetSecretResponse, CommonError> {
     let secret = repository.get_secret_by_key(&key).await?;
@@ -256,6 +355,7 @@ pub async fn get_secret_by_key<R: SecretRepositoryLike>(
         lookup_id: key.clone(),
         source: None,
     })?;
+    audit.record(AuditAction::Read, &secret.key);
 
     Ok(secret)
 }
@@ -277,6 +377,7 @@ pub async fn list_secrets<R: SecretRepositoryLike>(
 pub async fn list_decrypted_secrets<R: SecretRepositoryLike>(
     repository: &R,
     crypto_cache: &CryptoCache,
+    audit: &SecretAudit<'_>,
     pagination: PaginationRequest,
 ) -> Result<ListDecryptedSecretsResponse, CommonError> {
     use encryption::logic::crypto_services::EncryptedString;
@@ -294,6 +395,7 @@ pub async fn list_decrypted_secrets<R: SecretRepositoryLike>(
         let decrypted_value = decryption_service
             .decrypt_data(EncryptedString(secret.encrypted_secret.clone()))
             .await?;
+        audit.record(AuditAction::Read, &secret.key);
 
         decrypted_secrets.push(DecryptedSecret {
             id: secret.id,
@@ -323,6 +425,7 @@ pub struct ImportSecretRequest {
 /// This does NOT publish change events since it's used for initial sync
 pub async fn import_secret<R: SecretRepositoryLike>(
     repository: &R,
+    audit: &SecretAudit<'_>,
     request: ImportSecretRequest,
 ) -> Result<Secret, CommonError> {
     let now = WrappedChronoDateTime::now();
@@ -347,6 +450,7 @@ pub async fn import_secret<R: SecretRepositoryLike>(
     };
 
     repository.create_secret(&create_params).await?;
+    audit.record(AuditAction::Created, &secret.key);
 
     Ok(secret)
 }
@@ -355,6 +459,7 @@ pub async fn import_secret<R: SecretRepositoryLike>(
 mod tests {
     mod unit {
         use super::super::*;
+        use crate::logic::audit::{AuditEvent, NoopAuditSink};
         use crate::repository::Repository;
         use crate::test::encryption_service::setup_test_encryption;
         use shared::primitives::SqlMigrationLoader;
@@ -368,6 +473,10 @@ mod tests {
             Repository::new(conn)
         }
 
+        fn test_audit() -> SecretAudit<'static> {
+            SecretAudit::new(&NoopAuditSink, "test-principal")
+        }
+
         #[tokio::test]
         async fn test_create_secret() {
             let encryption_setup = setup_test_encryption("test-alias").await;
@@ -384,6 +493,7 @@ mod tests {
                 &on_change_tx,
                 &repository,
                 &encryption_setup.crypto_cache,
+                &test_audit(),
                 request.clone(),
                 true,
             )
@@ -425,6 +535,7 @@ mod tests {
                 &on_change_tx,
                 &repository,
                 &encryption_setup.crypto_cache,
+                &test_audit(),
                 create_request,
                 false,
             )
@@ -440,6 +551,7 @@ mod tests {
                 &on_change_tx,
                 &repository,
                 &encryption_setup.crypto_cache,
+                &test_audit(),
                 created.id.clone(),
                 update_request,
                 true,
@@ -462,6 +574,69 @@ mod tests {
             }
         }
 
+        #[tokio::test]
+        async fn test_set_secrets_batch_publishes_one_event() {
+            let encryption_setup = setup_test_encryption("test-alias").await;
+            let repository = setup_test_repository().await;
+            let (on_change_tx, mut on_change_rx) = tokio::sync::broadcast::channel(10);
+
+            // One existing secret that the batch updates
+            let existing = create_secret(
+                &on_change_tx,
+                &repository,
+                &encryption_setup.crypto_cache,
+                &test_audit(),
+                CreateSecretRequest {
+                    key: "secret-key-0".to_string(),
+                    raw_value: "original-value".to_string(),
+                    dek_alias: encryption_setup.dek_alias.clone(),
+                },
+                false,
+            )
+            .await
+            .unwrap();
+
+            let requests = (0..4)
+                .map(|i| CreateSecretRequest {
+                    key: format!("secret-key-{i}"),
+                    raw_value: format!("secret-value-{i}"),
+                    dek_alias: encryption_setup.dek_alias.clone(),
+                })
+                .collect();
+            let secrets = set_secrets_batch(
+                &on_change_tx,
+                &repository,
+                &encryption_setup.crypto_cache,
+                &test_audit(),
+                requests,
+                true,
+            )
+            .await
+            .unwrap();
+
+            assert_eq!(secrets.len(), 4);
+            assert_eq!(secrets[0].id, existing.id);
+            assert_ne!(secrets[0].encrypted_secret, existing.encrypted_secret);
+
+            match on_change_rx.try_recv().unwrap() {
+                SecretChangeEvt::BatchUpdated(batch) => assert_eq!(batch, secrets),
+                other => panic!("Expected BatchUpdated event, got {other:?}"),
+            }
+            assert!(on_change_rx.try_recv().is_err());
+
+            let listed = list_secrets(
+                &repository,
+                PaginationRequest {
+                    page_size: 10,
+                    next_page_token: None,
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+            assert_eq!(listed.secrets.len(), 4);
+        }
+
         #[tokio::test]
         async fn test_delete_secret() {
             let encryption_setup = setup_test_encryption("test-alias").await;
@@ -479,6 +654,7 @@ mod tests {
                 &on_change_tx,
                 &repository,
                 &encryption_setup.crypto_cache,
+                &test_audit(),
                 create_request,
                 false,
             )
@@ -486,7 +662,14 @@ mod tests {
             .unwrap();
 
             // Delete the secret
-            let result = delete_secret(&on_change_tx, &repository, created.id.clone(), true).await;
+            let result = delete_secret(
+                &on_change_tx,
+                &repository,
+                &test_audit(),
+                created.id.clone(),
+                true,
+            )
+            .await;
 
             assert!(result.is_ok());
             let response = result.unwrap();
@@ -504,7 +687,7 @@ mod tests {
             }
 
             // Verify it's deleted
-            let get_result = get_secret_by_id(&repository, created.id).await;
+            let get_result = get_secret_by_id(&repository, &test_audit(), created.id).await;
             assert!(get_result.is_err());
         }
 
@@ -525,6 +708,7 @@ mod tests {
                 &on_change_tx,
                 &repository,
                 &encryption_setup.crypto_cache,
+                &test_audit(),
                 create_request,
                 false,
             )
@@ -532,7 +716,7 @@ mod tests {
             .unwrap();
 
             // Get by id
-            let result = get_secret_by_id(&repository, created.id.clone()).await;
+            let result = get_secret_by_id(&repository, &test_audit(), created.id.clone()).await;
 
             assert!(result.is_ok());
             let secret = result.unwrap();
@@ -557,6 +741,7 @@ mod tests {
                 &on_change_tx,
                 &repository,
                 &encryption_setup.crypto_cache,
+                &test_audit(),
                 create_request,
                 false,
             )
@@ -564,7 +749,8 @@ mod tests {
             .unwrap();
 
             // Get by key
-            let result = get_secret_by_key(&repository, "my-secret-key".to_string()).await;
+            let result =
+                get_secret_by_key(&repository, &test_audit(), "my-secret-key".to_string()).await;
 
             assert!(result.is_ok());
             let secret = result.unwrap();
@@ -590,6 +776,7 @@ mod tests {
                     &on_change_tx,
                     &repository,
                     &encryption_setup.crypto_cache,
+                    &test_audit(),
                     create_request,
                     false,
                 )
@@ -603,6 +790,7 @@ mod tests {
                 PaginationRequest {
                     page_size: 10,
                     next_page_token: None,
+                    ..Default::default()
                 },
             )
             .await;
@@ -616,7 +804,7 @@ mod tests {
         async fn test_get_secret_not_found() {
             let repository = setup_test_repository().await;
 
-            let result = get_secret_by_id(&repository, WrappedUuidV4::new()).await;
+            let result = get_secret_by_id(&repository, &test_audit(), WrappedUuidV4::new()).await;
 
             assert!(result.is_err());
             match result.unwrap_err() {
@@ -625,6 +813,83 @@ mod tests {
             }
         }
 
+        #[tokio::test]
+        async fn test_secret_operations_are_audited_without_values() {
+            let encryption_setup = setup_test_encryption("test-alias").await;
+            let repository = setup_test_repository().await;
+            let (on_change_tx, _on_change_rx) = tokio::sync::broadcast::channel(10);
+            let (audit_tx, mut audit_rx) = tokio::sync::mpsc::unbounded_channel::<AuditEvent>();
+            let audit = SecretAudit::new(&audit_tx, "user-123");
+
+            let created = create_secret(
+                &on_change_tx,
+                &repository,
+                &encryption_setup.crypto_cache,
+                &audit,
+                CreateSecretRequest {
+                    key: "audited-key".to_string(),
+                    raw_value: "original-plaintext".to_string(),
+                    dek_alias: encryption_setup.dek_alias.clone(),
+                },
+                false,
+            )
+            .await
+            .unwrap();
+            get_secret_by_key(&repository, &audit, "audited-key".to_string())
+                .await
+                .unwrap();
+            update_secret(
+                &on_change_tx,
+                &repository,
+                &encryption_setup.crypto_cache,
+                &audit,
+                created.id.clone(),
+                UpdateSecretRequest {
+                    raw_value: "updated-plaintext".to_string(),
+                },
+                false,
+            )
+            .await
+            .unwrap();
+            list_decrypted_secrets(
+                &repository,
+                &encryption_setup.crypto_cache,
+                &audit,
+                PaginationRequest {
+                    page_size: 10,
+                    next_page_token: None,
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+            delete_secret(&on_change_tx, &repository, &audit, created.id, false)
+                .await
+                .unwrap();
+
+            let mut events = Vec::new();
+            while let Ok(event) = audit_rx.try_recv() {
+                events.push(event);
+            }
+            let actions: Vec<_> = events.iter().map(|event| event.action).collect();
+            assert_eq!(
+                actions,
+                vec![
+                    AuditAction::Created,
+                    AuditAction::Read,
+                    AuditAction::Updated,
+                    AuditAction::Read,
+                    AuditAction::Deleted,
+                ]
+            );
+            for event in &events {
+                assert_eq!(event.secret_key, "audited-key");
+                assert_eq!(event.principal, "user-123");
+                let recorded = serde_json::to_string(event).unwrap();
+                assert!(!recorded.contains("plaintext"), "{recorded}");
+            }
+        }
+
         #[tokio::test]
         async fn test_create_secret_no_publish() {
             let encryption_setup = setup_test_encryption("test-alias").await;
@@ -641,6 +906,7 @@ mod tests {
                 &on_change_tx,
                 &repository,
                 &encryption_setup.crypto_cache,
+                &test_audit(),
                 request,
                 false, // Don't publish
             )
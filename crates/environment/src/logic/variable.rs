@@ -466,6 +466,7 @@ mod tests {
                 PaginationRequest {
                     page_size: 10,
                     next_page_token: None,
+                    ..Default::default()
                 },
             )
             .await;
@@ -611,6 +612,7 @@ mod tests {
                 PaginationRequest {
                     page_size: 3,
                     next_page_token: None,
+                    ..Default::default()
                 },
             )
             .await;
@@ -626,6 +628,7 @@ mod tests {
                 PaginationRequest {
                     page_size: 3,
                     next_page_token: response.next_page_token,
+                    ..Default::default()
                 },
             )
             .await;
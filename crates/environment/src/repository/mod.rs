@@ -74,6 +74,13 @@ pub trait SecretRepositoryLike: Send + Sync {
         &self,
         pagination: &PaginationRequest,
     ) -> Result<PaginatedResponse<Secret>, CommonError>;
+
+    /// Create and update several secrets in a single transaction
+    async fn apply_secrets_batch(
+        &self,
+        creates: &[CreateSecret],
+        updates: &[UpdateSecret],
+    ) -> Result<(), CommonError>;
 }
 
 /// Repository trait for variable operations
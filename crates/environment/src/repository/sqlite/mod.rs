@@ -167,7 +167,7 @@ impl SecretRepositoryLike for Repository {
 
         let sqlc_params = get_secrets_params {
             cursor: &cursor_datetime,
-            page_size: &pagination.page_size,
+            page_size: &pagination.resolved_page_size(),
         };
 
         let rows = get_secrets(&self.conn, sqlc_params)
@@ -188,6 +188,73 @@ impl SecretRepositoryLike for Repository {
             |secret| vec![secret.created_at.get_inner().to_rfc3339()],
         ))
     }
+
+    async fn apply_secrets_batch(
+        &self,
+        creates: &[CreateSecret],
+        updates: &[UpdateSecret],
+    ) -> Result<(), CommonError> {
+        let tx = self
+            .conn
+            .transaction()
+            .await
+            .context("Failed to begin secrets batch transaction")
+            .map_err(|e| CommonError::Repository {
+                msg: e.to_string(),
+                source: Some(e),
+            })?;
+        let tx_conn = shared::libsql::Connection::new((*tx).clone());
+
+        let result: Result<(), anyhow::Error> = async {
+            for params in creates {
+                insert_secret(
+                    &tx_conn,
+                    insert_secret_params {
+                        id: &params.id,
+                        key: &params.key,
+                        encrypted_secret: &params.encrypted_secret,
+                        dek_alias: &params.dek_alias,
+                        created_at: &params.created_at,
+                        updated_at: &params.updated_at,
+                    },
+                )
+                .await
+                .with_context(|| format!("Failed to create secret {}", params.key))?;
+            }
+            for params in updates {
+                update_secret(
+                    &tx_conn,
+                    update_secret_params {
+                        id: &params.id,
+                        encrypted_secret: &params.encrypted_secret,
+                        dek_alias: &params.dek_alias,
+                        updated_at: &params.updated_at,
+                    },
+                )
+                .await
+                .with_context(|| format!("Failed to update secret {}", params.id))?;
+            }
+            Ok(())
+        }
+        .await;
+
+        let result = match result {
+            Ok(()) => tx
+                .commit()
+                .await
+                .context("Failed to commit secrets batch transaction"),
+            Err(e) => {
+                // Roll back explicitly so the error from the failed statement is reported
+                let _ = tx.rollback().await;
+                Err(e)
+            }
+        };
+
+        result.map_err(|e| CommonError::Repository {
+            msg: e.to_string(),
+            source: Some(e),
+        })
+    }
 }
 
 #[async_trait::async_trait]
@@ -307,7 +374,7 @@ impl VariableRepositoryLike for Repository {
 
         let sqlc_params = get_variables_params {
             cursor: &cursor_datetime,
-            page_size: &pagination.page_size,
+            page_size: &pagination.resolved_page_size(),
         };
 
         let rows = get_variables(&self.conn, sqlc_params)
@@ -1,6 +1,6 @@
 //! Secret HTTP endpoints
 
-use axum::extract::{Json, Path, Query, State};
+use axum::extract::{Extension, Json, Path, Query, State};
 use shared::adapters::openapi::API_VERSION_TAG;
 use std::sync::Arc;
 use tracing::trace;
@@ -18,6 +18,7 @@ use crate::{
 use shared::{
     adapters::openapi::JsonResponse,
     error::CommonError,
+    identity::Identity,
     primitives::{PaginationRequest, WrappedUuidV4},
 };
 
@@ -38,6 +39,11 @@ pub fn create_router() -> OpenApiRouter<Arc<EnvironmentService>> {
         .routes(routes!(route_delete_secret))
 }
 
+/// Caller identity attached to the request by the authentication layer, if any
+fn caller(identity: &Option<Extension<Identity>>) -> Option<&Identity> {
+    identity.as_ref().map(|Extension(identity)| identity)
+}
+
 #[utoipa::path(
     post,
     path = format!("{}/{}/{}/secret", PATH_PREFIX, SERVICE_ROUTE_KEY, API_VERSION_1),
@@ -61,6 +67,7 @@ pub fn create_router() -> OpenApiRouter<Arc<EnvironmentService>> {
 )]
 async fn route_create_secret(
     State(ctx): State<Arc<EnvironmentService>>,
+    identity: Option<Extension<Identity>>,
     Json(request): Json<CreateSecretRequest>,
 ) -> JsonResponse<CreateSecretResponse, CommonError> {
     trace!(key = %request.key, "Creating secret");
@@ -68,6 +75,7 @@ async fn route_create_secret(
         &ctx.secret_change_tx,
         &ctx.repository,
         &ctx.crypto_cache,
+        &ctx.secret_audit(caller(&identity)),
         request,
         true,
     )
@@ -99,10 +107,16 @@ async fn route_create_secret(
 )]
 async fn route_import_secret(
     State(ctx): State<Arc<EnvironmentService>>,
+    identity: Option<Extension<Identity>>,
     Json(request): Json<ImportSecretRequest>,
 ) -> JsonResponse<Secret, CommonError> {
     trace!(secret_key = %request.key, "Importing secret");
-    let res = import_secret(&ctx.repository, request).await;
+    let res = import_secret(
+        &ctx.repository,
+        &ctx.secret_audit(caller(&identity)),
+        request,
+    )
+    .await;
     trace!(success = res.is_ok(), "Importing secret completed");
     JsonResponse::from(res)
 }
@@ -165,13 +179,20 @@ async fn route_list_secrets(
 )]
 async fn route_list_decrypted_secrets(
     State(ctx): State<Arc<EnvironmentService>>,
+    identity: Option<Extension<Identity>>,
     Query(pagination): Query<PaginationRequest>,
 ) -> JsonResponse<ListDecryptedSecretsResponse, CommonError> {
     trace!(
         page_size = pagination.page_size,
         "Listing decrypted secrets"
     );
-    let res = list_decrypted_secrets(&ctx.repository, &ctx.crypto_cache, pagination).await;
+    let res = list_decrypted_secrets(
+        &ctx.repository,
+        &ctx.crypto_cache,
+        &ctx.secret_audit(caller(&identity)),
+        pagination,
+    )
+    .await;
     trace!(success = res.is_ok(), "Listing decrypted secrets completed");
     JsonResponse::from(res)
 }
@@ -202,10 +223,16 @@ async fn route_list_decrypted_secrets(
 )]
 async fn route_get_secret_by_id(
     State(ctx): State<Arc<EnvironmentService>>,
+    identity: Option<Extension<Identity>>,
     Path(secret_id): Path<WrappedUuidV4>,
 ) -> JsonResponse<GetSecretResponse, CommonError> {
     trace!(secret_id = %secret_id, "Getting secret by ID");
-    let res = get_secret_by_id(&ctx.repository, secret_id).await;
+    let res = get_secret_by_id(
+        &ctx.repository,
+        &ctx.secret_audit(caller(&identity)),
+        secret_id,
+    )
+    .await;
     trace!(success = res.is_ok(), "Getting secret by ID completed");
     JsonResponse::from(res)
 }
@@ -236,10 +263,11 @@ async fn route_get_secret_by_id(
 )]
 async fn route_get_secret_by_key(
     State(ctx): State<Arc<EnvironmentService>>,
+    identity: Option<Extension<Identity>>,
     Path(key): Path<String>,
 ) -> JsonResponse<GetSecretResponse, CommonError> {
     trace!(key = %key, "Getting secret by key");
-    let res = get_secret_by_key(&ctx.repository, key).await;
+    let res = get_secret_by_key(&ctx.repository, &ctx.secret_audit(caller(&identity)), key).await;
     trace!(success = res.is_ok(), "Getting secret by key completed");
     JsonResponse::from(res)
 }
@@ -271,6 +299,7 @@ async fn route_get_secret_by_key(
 )]
 async fn route_update_secret(
     State(ctx): State<Arc<EnvironmentService>>,
+    identity: Option<Extension<Identity>>,
     Path(secret_id): Path<WrappedUuidV4>,
     Json(request): Json<UpdateSecretRequest>,
 ) -> JsonResponse<UpdateSecretResponse, CommonError> {
@@ -279,6 +308,7 @@ async fn route_update_secret(
         &ctx.secret_change_tx,
         &ctx.repository,
         &ctx.crypto_cache,
+        &ctx.secret_audit(caller(&identity)),
         secret_id,
         request,
         true,
@@ -314,10 +344,18 @@ async fn route_update_secret(
 )]
 async fn route_delete_secret(
     State(ctx): State<Arc<EnvironmentService>>,
+    identity: Option<Extension<Identity>>,
     Path(secret_id): Path<WrappedUuidV4>,
 ) -> JsonResponse<DeleteSecretResponse, CommonError> {
     trace!(secret_id = %secret_id, "Deleting secret");
-    let res = delete_secret(&ctx.secret_change_tx, &ctx.repository, secret_id, true).await;
+    let res = delete_secret(
+        &ctx.secret_change_tx,
+        &ctx.repository,
+        &ctx.secret_audit(caller(&identity)),
+        secret_id,
+        true,
+    )
+    .await;
     trace!(success = res.is_ok(), "Deleting secret completed");
     JsonResponse::from(res)
 }
@@ -1,10 +1,17 @@
 //! Service layer for environment crate
 //! Provides the main service struct that holds all dependencies for environment operations
 
+use std::sync::Arc;
+
 use encryption::logic::crypto_services::CryptoCache;
+use shared::{error::CommonError, identity::Identity};
 
 use crate::{
-    logic::{secret::SecretChangeTx, variable::VariableChangeTx},
+    logic::{
+        audit::{AuditSink, NoopAuditSink, SecretAudit, principal_from_identity},
+        secret::{CreateSecretRequest, Secret, SecretChangeTx, set_secrets_batch},
+        variable::VariableChangeTx,
+    },
     repository::Repository,
 };
 
@@ -16,6 +23,9 @@ pub struct EnvironmentService {
     pub crypto_cache: CryptoCache,
     pub secret_change_tx: SecretChangeTx,
     pub variable_change_tx: VariableChangeTx,
+    /// Receives an audit event for every secret operation; a no-op unless set with
+    /// [`EnvironmentService::with_audit_sink`]
+    pub audit_sink: Arc<dyn AuditSink>,
 }
 
 /// Parameters for creating an EnvironmentService
@@ -34,6 +44,35 @@ impl EnvironmentService {
             crypto_cache: params.crypto_cache,
             secret_change_tx: params.secret_change_tx,
             variable_change_tx: params.variable_change_tx,
+            audit_sink: Arc::new(NoopAuditSink),
         }
     }
+
+    /// Send secret audit events to `audit_sink` in addition to `tracing`
+    pub fn with_audit_sink(mut self, audit_sink: Arc<dyn AuditSink>) -> Self {
+        self.audit_sink = audit_sink;
+        self
+    }
+
+    /// Audit recorder for secret operations performed by the caller `identity`
+    pub fn secret_audit(&self, identity: Option<&Identity>) -> SecretAudit<'_> {
+        SecretAudit::new(self.audit_sink.as_ref(), principal_from_identity(identity))
+    }
+
+    /// Create or update several secrets in one transaction, publishing a single change event
+    pub async fn set_secrets_batch(
+        &self,
+        identity: Option<&Identity>,
+        requests: Vec<CreateSecretRequest>,
+    ) -> Result<Vec<Secret>, CommonError> {
+        set_secrets_batch(
+            &self.secret_change_tx,
+            &self.repository,
+            &self.crypto_cache,
+            &self.secret_audit(identity),
+            requests,
+            true,
+        )
+        .await
+    }
 }
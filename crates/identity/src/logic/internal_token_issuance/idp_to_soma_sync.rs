@@ -22,6 +22,7 @@ pub async fn sync_user_groups<R: UserRepositoryLike>(
         let pagination = PaginationRequest {
             page_size: 1000,
             next_page_token,
+            ..Default::default()
         };
         let result = repository.list_user_groups(user_id, &pagination).await?;
         all_memberships.extend(result.items);
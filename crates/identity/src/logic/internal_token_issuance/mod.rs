@@ -38,6 +38,7 @@ async fn get_valid_signing_key<R: UserRepositoryLike>(
         let pagination = PaginationRequest {
             page_size: 100,
             next_page_token: next_page_token.clone(),
+            ..Default::default()
         };
 
         let keys = repository.list_jwt_signing_keys(&pagination).await?;
@@ -396,6 +397,7 @@ pub async fn refresh_access_token<R: UserRepositoryLike>(
         let pagination = PaginationRequest {
             page_size: 1000,
             next_page_token,
+            ..Default::default()
         };
         let result = repository
             .list_user_groups(&claims.sub, &pagination)
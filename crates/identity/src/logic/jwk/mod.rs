@@ -193,6 +193,10 @@ where
     Ok(PaginatedResponse {
         items,
         next_page_token: result.next_page_token,
+        prev_page_token: result.prev_page_token,
+        total_count: result.total_count,
+        page_size: result.page_size,
+        order: result.order,
     })
 }
 
@@ -234,6 +238,7 @@ where
         let pagination = PaginationRequest {
             page_size: 1000, // Large page size to get all keys
             next_page_token,
+            ..Default::default()
         };
         let result = repository.list_jwt_signing_keys(&pagination).await?;
         collected_results.extend(result.items);
@@ -279,6 +284,7 @@ where
     let pagination = PaginationRequest {
         page_size: 1,
         next_page_token: None,
+        ..Default::default()
     };
 
     let result = repository.list_jwt_signing_keys(&pagination).await?;
@@ -346,6 +352,7 @@ where
     let pagination = PaginationRequest {
         page_size: 1000, // Large page size to get all keys
         next_page_token: None,
+        ..Default::default()
     };
 
     let result = repository.list_jwt_signing_keys(&pagination).await?;
@@ -615,6 +622,7 @@ mod tests {
             let pagination = PaginationRequest {
                 page_size: 10,
                 next_page_token: None,
+                ..Default::default()
             };
 
             let auth_client = mock_admin_auth_client();
@@ -692,6 +700,7 @@ QAB
             let pagination = PaginationRequest {
                 page_size: 10,
                 next_page_token: None,
+                ..Default::default()
             };
             let initial_keys = ctx
                 .identity_repo
@@ -741,6 +750,7 @@ QAB
             let pagination = PaginationRequest {
                 page_size: 10,
                 next_page_token: None,
+                ..Default::default()
             };
             let before_keys = ctx
                 .identity_repo
@@ -800,6 +810,7 @@ QAB
             let pagination = PaginationRequest {
                 page_size: 10,
                 next_page_token: None,
+                ..Default::default()
             };
             let keys = ctx
                 .identity_repo
@@ -831,6 +842,7 @@ QAB
             let pagination = PaginationRequest {
                 page_size: 10,
                 next_page_token: None,
+                ..Default::default()
             };
             let before_keys = ctx
                 .identity_repo
@@ -876,6 +888,7 @@ QAB
             let pagination = PaginationRequest {
                 page_size: 10,
                 next_page_token: None,
+                ..Default::default()
             };
             let keys = ctx
                 .identity_repo
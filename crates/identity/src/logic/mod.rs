@@ -36,16 +36,10 @@ pub async fn decode_jwt_to_claims(
         CommonError::Unknown(anyhow::anyhow!("JWT token missing 'kid' in header"))
     })?;
 
-    // Get or fetch the external JWKS
-    if external_jwks_cache.get_key(jwks_uri, &kid).is_none() {
-        external_jwks_cache.fetch_jwks(jwks_uri).await?;
-    }
-
-    let decoding_key = external_jwks_cache.get_key(jwks_uri, &kid).ok_or_else(|| {
-        CommonError::Unknown(anyhow::anyhow!(
-            "Key '{kid}' not found in JWKS from {jwks_uri}"
-        ))
-    })?;
+    // Get the key, re-fetching the external JWKS if the kid is not cached yet
+    let decoding_key = external_jwks_cache
+        .get_key_or_refresh(jwks_uri, &kid)
+        .await?;
 
     // Build validation
     let mut validation = Validation::new(Algorithm::RS256);
@@ -87,16 +81,10 @@ pub async fn decode_jwt_to_claims_unsafe(
         CommonError::Unknown(anyhow::anyhow!("JWT token missing 'kid' in header"))
     })?;
 
-    // Get or fetch the external JWKS
-    if external_jwks_cache.get_key(jwks_uri, &kid).is_none() {
-        external_jwks_cache.fetch_jwks(jwks_uri).await?;
-    }
-
-    let decoding_key = external_jwks_cache.get_key(jwks_uri, &kid).ok_or_else(|| {
-        CommonError::Unknown(anyhow::anyhow!(
-            "Key '{kid}' not found in JWKS from {jwks_uri}"
-        ))
-    })?;
+    // Get the key, re-fetching the external JWKS if the kid is not cached yet
+    let decoding_key = external_jwks_cache
+        .get_key_or_refresh(jwks_uri, &kid)
+        .await?;
 
     // Build validation - disable all validation since we just want to extract claims
     // The token comes from a trusted source (the IdP's token endpoint over HTTPS)
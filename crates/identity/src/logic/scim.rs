@@ -500,6 +500,7 @@ async fn get_user_scim_internal(
             &PaginationRequest {
                 page_size: 100,
                 next_page_token: None,
+                ..Default::default()
             },
         )
         .await?;
@@ -535,6 +536,7 @@ pub async fn list_users_scim(
     let pagination = PaginationRequest {
         page_size: params.count,
         next_page_token: None,
+        ..Default::default()
     };
 
     let users_response = repo.list_users(&pagination, None, None).await?;
@@ -827,6 +829,7 @@ async fn get_group_scim_internal(
             &PaginationRequest {
                 page_size: 1000,
                 next_page_token: None,
+                ..Default::default()
             },
         )
         .await?;
@@ -847,6 +850,7 @@ pub async fn list_groups_scim(
     let pagination = PaginationRequest {
         page_size: params.count,
         next_page_token: None,
+        ..Default::default()
     };
 
     let groups_response = repo.list_groups(&pagination).await?;
@@ -859,6 +863,7 @@ pub async fn list_groups_scim(
                 &PaginationRequest {
                     page_size: 1000,
                     next_page_token: None,
+                    ..Default::default()
                 },
             )
             .await?;
@@ -110,6 +110,7 @@ impl StsConfigCache {
             let pagination = PaginationRequest {
                 page_size: 100,
                 next_page_token: next_page_token.clone(),
+                ..Default::default()
             };
 
             let result = self
@@ -182,5 +182,6 @@ pub async fn list_sts_configs<R: UserRepositoryLike>(
             .map(|config| config.config)
             .collect(),
         next_page_token: result.next_page_token,
+        total_count: result.total_count,
     })
 }
@@ -1,13 +1,23 @@
 use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 use jsonwebtoken::DecodingKey;
 use shared::error::CommonError;
+use tokio::sync::Mutex;
+
+/// Minimum time between two forced refreshes of the same JWKS URI
+pub const DEFAULT_MIN_REFRESH_INTERVAL: Duration = Duration::from_secs(30);
 
 /// External JWKS cache for fetching public keys from external identity providers
 #[derive(Clone)]
 pub struct ExternalJwksCache {
     /// Maps JWKS URI -> (kid -> DecodingKey)
     keys: std::sync::Arc<dashmap::DashMap<String, HashMap<String, DecodingKey>>>,
+    /// Maps JWKS URI -> time of the last refresh done by `get_key_or_refresh`. The lock also
+    /// coalesces concurrent refreshes of the same URI.
+    refreshes: Arc<dashmap::DashMap<String, Arc<Mutex<Option<Instant>>>>>,
+    min_refresh_interval: Duration,
 }
 
 impl Default for ExternalJwksCache {
@@ -18,8 +28,14 @@ impl Default for ExternalJwksCache {
 
 impl ExternalJwksCache {
     pub fn new() -> Self {
+        Self::with_min_refresh_interval(DEFAULT_MIN_REFRESH_INTERVAL)
+    }
+
+    pub fn with_min_refresh_interval(min_refresh_interval: Duration) -> Self {
         Self {
             keys: std::sync::Arc::new(dashmap::DashMap::new()),
+            refreshes: Arc::new(dashmap::DashMap::new()),
+            min_refresh_interval,
         }
     }
 
@@ -76,4 +92,134 @@ impl ExternalJwksCache {
             .get(jwks_uri)
             .and_then(|keys| keys.get(kid).cloned())
     }
+
+    /// Get a decoding key, re-fetching the JWKS once if `kid` is not cached (e.g. after the
+    /// provider rotated its keys). Concurrent callers for the same URI share one fetch, and a
+    /// URI is re-fetched at most once per `min_refresh_interval`.
+    pub async fn get_key_or_refresh(
+        &self,
+        jwks_uri: &str,
+        kid: &str,
+    ) -> Result<DecodingKey, CommonError> {
+        if let Some(key) = self.get_key(jwks_uri, kid) {
+            return Ok(key);
+        }
+
+        let refresh = self
+            .refreshes
+            .entry(jwks_uri.to_string())
+            .or_default()
+            .clone();
+        let mut last_refresh = refresh.lock().await;
+
+        // Another caller may have refreshed while we waited for the lock
+        if let Some(key) = self.get_key(jwks_uri, kid) {
+            return Ok(key);
+        }
+
+        let rate_limited = last_refresh
+            .is_some_and(|last_refresh| last_refresh.elapsed() < self.min_refresh_interval);
+        if rate_limited {
+            tracing::debug!(%jwks_uri, %kid, "Skipping JWKS refresh, refreshed too recently");
+        } else {
+            tracing::debug!(%jwks_uri, %kid, "Key not cached, refreshing JWKS");
+            self.fetch_jwks(jwks_uri).await?;
+            // Only successful fetches count, so a failed one is retried by the next lookup
+            *last_refresh = Some(Instant::now());
+        }
+
+        self.get_key(jwks_uri, kid).ok_or_else(|| {
+            CommonError::Unknown(anyhow::anyhow!(
+                "Key '{kid}' not found in JWKS from {jwks_uri}"
+            ))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod unit {
+        use super::super::*;
+        use std::sync::atomic::{AtomicU32, Ordering};
+
+        fn jwks(kids: &[&str]) -> String {
+            let keys: Vec<_> = kids
+                .iter()
+                .map(
+                    |kid| serde_json::json!({ "kid": kid, "kty": "RSA", "n": "AQAB", "e": "AQAB" }),
+                )
+                .collect();
+            serde_json::json!({ "keys": keys }).to_string()
+        }
+
+        /// Serves `bodies` in order, repeating the last one, and counts the requests
+        async fn serve_jwks(bodies: Vec<String>) -> (String, Arc<AtomicU32>) {
+            use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+            let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+            let uri = format!("http://{}/jwks", listener.local_addr().unwrap());
+            let requests = Arc::new(AtomicU32::new(0));
+            let counter = requests.clone();
+            tokio::spawn(async move {
+                loop {
+                    let (mut socket, _) = listener.accept().await.unwrap();
+                    let mut buf = [0u8; 4096];
+                    let _ = socket.read(&mut buf).await.unwrap();
+                    let index = counter.fetch_add(1, Ordering::SeqCst) as usize;
+                    let body = &bodies[index.min(bodies.len() - 1)];
+                    let response = format!(
+                        "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+                        body.len()
+                    );
+                    socket.write_all(response.as_bytes()).await.unwrap();
+                    socket.shutdown().await.unwrap();
+                }
+            });
+            (uri, requests)
+        }
+
+        #[tokio::test]
+        async fn test_rotated_key_is_found_after_one_refresh() {
+            let (uri, requests) = serve_jwks(vec![jwks(&["old"]), jwks(&["old", "new"])]).await;
+            let cache = ExternalJwksCache::with_min_refresh_interval(Duration::ZERO);
+
+            assert!(cache.get_key_or_refresh(&uri, "old").await.is_ok());
+            assert_eq!(requests.load(Ordering::SeqCst), 1);
+
+            // Concurrent lookups of the rotated key share a single refresh
+            let lookups = (0..5).map(|_| {
+                let cache = cache.clone();
+                let uri = uri.clone();
+                tokio::spawn(async move { cache.get_key_or_refresh(&uri, "new").await })
+            });
+            for lookup in lookups {
+                assert!(lookup.await.unwrap().is_ok());
+            }
+            assert_eq!(requests.load(Ordering::SeqCst), 2);
+        }
+
+        #[tokio::test]
+        async fn test_unknown_kid_refresh_is_rate_limited() {
+            let (uri, requests) = serve_jwks(vec![jwks(&["old"])]).await;
+            let cache = ExternalJwksCache::with_min_refresh_interval(Duration::from_secs(60));
+
+            assert!(cache.get_key_or_refresh(&uri, "unknown").await.is_err());
+            assert!(cache.get_key_or_refresh(&uri, "unknown").await.is_err());
+            assert_eq!(requests.load(Ordering::SeqCst), 1);
+
+            // Cached keys are still served without a fetch
+            assert!(cache.get_key_or_refresh(&uri, "old").await.is_ok());
+            assert_eq!(requests.load(Ordering::SeqCst), 1);
+        }
+
+        #[tokio::test]
+        async fn test_failed_refresh_is_not_rate_limited() {
+            let (uri, requests) = serve_jwks(vec!["not a jwks".to_string(), jwks(&["old"])]).await;
+            let cache = ExternalJwksCache::with_min_refresh_interval(Duration::from_secs(60));
+
+            assert!(cache.get_key_or_refresh(&uri, "old").await.is_err());
+            assert!(cache.get_key_or_refresh(&uri, "old").await.is_ok());
+            assert_eq!(requests.load(Ordering::SeqCst), 2);
+        }
+    }
 }
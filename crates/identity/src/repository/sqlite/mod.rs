@@ -168,7 +168,7 @@ impl UserRepositoryLike for Repository {
             cursor: &cursor_datetime,
             user_type: &user_type_owned,
             role: &role_owned,
-            page_size: &pagination.page_size,
+            page_size: &pagination.resolved_page_size(),
         };
 
         let rows = get_users(&self.conn, sqlc_params)
@@ -292,7 +292,7 @@ impl UserRepositoryLike for Repository {
         let sqlc_params = get_api_keys_params {
             cursor: &cursor_datetime,
             user_id: &user_id_owned,
-            page_size: &pagination.page_size,
+            page_size: &pagination.resolved_page_size(),
         };
 
         let rows = get_api_keys(&self.conn, sqlc_params)
@@ -421,7 +421,7 @@ impl UserRepositoryLike for Repository {
 
         let sqlc_params = get_groups_params {
             cursor: &cursor_datetime,
-            page_size: &pagination.page_size,
+            page_size: &pagination.resolved_page_size(),
         };
 
         let rows = get_groups(&self.conn, sqlc_params)
@@ -531,7 +531,7 @@ impl UserRepositoryLike for Repository {
         let sqlc_params = get_group_members_params {
             group_id: &group_id.to_string(),
             cursor: &cursor_datetime,
-            page_size: &pagination.page_size,
+            page_size: &pagination.resolved_page_size(),
         };
 
         let rows = get_group_members(&self.conn, sqlc_params)
@@ -581,7 +581,7 @@ impl UserRepositoryLike for Repository {
         let sqlc_params = get_user_groups_params {
             user_id: &user_id.to_string(),
             cursor: &cursor_datetime,
-            page_size: &pagination.page_size,
+            page_size: &pagination.resolved_page_size(),
         };
 
         let rows = get_user_groups(&self.conn, sqlc_params)
@@ -719,7 +719,7 @@ impl UserRepositoryLike for Repository {
 
         let sqlc_params = get_jwt_signing_keys_params {
             cursor: &cursor_datetime,
-            page_size: &pagination.page_size,
+            page_size: &pagination.resolved_page_size(),
         };
 
         let rows = get_jwt_signing_keys(&self.conn, sqlc_params)
@@ -849,7 +849,7 @@ impl UserRepositoryLike for Repository {
         let sqlc_params = get_sts_configurations_params {
             cursor: &cursor_datetime,
             config_type: &config_type_owned,
-            page_size: &pagination.page_size,
+            page_size: &pagination.resolved_page_size(),
         };
 
         let rows = get_sts_configurations(&self.conn, sqlc_params)
@@ -967,7 +967,7 @@ impl UserRepositoryLike for Repository {
         let sqlc_params = get_user_auth_flow_configs_params {
             cursor: &cursor_datetime,
             config_type: &config_type_owned,
-            page_size: &pagination.page_size,
+            page_size: &pagination.resolved_page_size(),
         };
 
         let rows = get_user_auth_flow_configs(&self.conn, sqlc_params)
@@ -1273,6 +1273,7 @@ mod tests {
             let pagination = PaginationRequest {
                 page_size: 10,
                 next_page_token: None,
+                ..Default::default()
             };
             let result = repo.list_users(&pagination, None, None).await.unwrap();
             assert_eq!(result.items.len(), 5);
@@ -1313,6 +1314,7 @@ mod tests {
             let pagination = PaginationRequest {
                 page_size: 2,
                 next_page_token: None,
+                ..Default::default()
             };
             let result = repo.list_users(&pagination, None, None).await.unwrap();
             assert_eq!(result.items.len(), 2);
@@ -1322,6 +1324,7 @@ mod tests {
             let pagination = PaginationRequest {
                 page_size: 2,
                 next_page_token: result.next_page_token,
+                ..Default::default()
             };
             let result = repo.list_users(&pagination, None, None).await.unwrap();
             assert_eq!(result.items.len(), 2);
@@ -1331,12 +1334,38 @@ mod tests {
             let pagination = PaginationRequest {
                 page_size: 2,
                 next_page_token: result.next_page_token,
+                ..Default::default()
             };
             let result = repo.list_users(&pagination, None, None).await.unwrap();
             assert_eq!(result.items.len(), 1);
             assert!(result.next_page_token.is_none());
         }
 
+        #[tokio::test]
+        async fn test_list_users_unset_page_size_uses_default() {
+            let repo = setup_test_db().await;
+
+            for i in 0..=shared::primitives::DEFAULT_PAGE_SIZE {
+                let user = create_test_user(
+                    &format!("user-{i}"),
+                    UserType::Machine,
+                    Some(&format!("user{i}@example.com")),
+                    Role::User,
+                );
+                repo.create_user(&user).await.unwrap();
+            }
+
+            let result = repo
+                .list_users(&PaginationRequest::default(), None, None)
+                .await
+                .unwrap();
+            assert_eq!(
+                result.items.len() as i64,
+                shared::primitives::DEFAULT_PAGE_SIZE
+            );
+            assert!(result.next_page_token.is_some());
+        }
+
         // ============================================
         // API Key tests
         // ============================================
@@ -1448,6 +1477,7 @@ mod tests {
             let pagination = PaginationRequest {
                 page_size: 10,
                 next_page_token: None,
+                ..Default::default()
             };
             let result = repo.list_api_keys(&pagination, None).await.unwrap();
             assert_eq!(result.items.len(), 5);
@@ -1511,6 +1541,7 @@ mod tests {
             let pagination = PaginationRequest {
                 page_size: 10,
                 next_page_token: None,
+                ..Default::default()
             };
             let result = repo
                 .list_api_keys(&pagination, Some("user-1"))
@@ -1553,6 +1584,7 @@ mod tests {
             let pagination = PaginationRequest {
                 page_size: 10,
                 next_page_token: None,
+                ..Default::default()
             };
             let result = repo
                 .list_api_keys(&pagination, Some("user-1"))
@@ -1626,6 +1658,7 @@ mod tests {
             let pagination = PaginationRequest {
                 page_size: 10,
                 next_page_token: None,
+                ..Default::default()
             };
             let result = repo.list_groups(&pagination).await.unwrap();
             assert_eq!(result.items.len(), 5);
@@ -1646,6 +1679,7 @@ mod tests {
             let pagination = PaginationRequest {
                 page_size: 2,
                 next_page_token: None,
+                ..Default::default()
             };
             let result = repo.list_groups(&pagination).await.unwrap();
             assert_eq!(result.items.len(), 2);
@@ -1655,6 +1689,7 @@ mod tests {
             let pagination = PaginationRequest {
                 page_size: 2,
                 next_page_token: result.next_page_token,
+                ..Default::default()
             };
             let result = repo.list_groups(&pagination).await.unwrap();
             assert_eq!(result.items.len(), 2);
@@ -1664,6 +1699,7 @@ mod tests {
             let pagination = PaginationRequest {
                 page_size: 2,
                 next_page_token: result.next_page_token,
+                ..Default::default()
             };
             let result = repo.list_groups(&pagination).await.unwrap();
             assert_eq!(result.items.len(), 1);
@@ -1771,6 +1807,7 @@ mod tests {
             let pagination = PaginationRequest {
                 page_size: 10,
                 next_page_token: None,
+                ..Default::default()
             };
             let result = repo
                 .list_group_members("group-1", &pagination)
@@ -1809,6 +1846,7 @@ mod tests {
             let pagination = PaginationRequest {
                 page_size: 2,
                 next_page_token: None,
+                ..Default::default()
             };
             let result = repo
                 .list_group_members("group-1", &pagination)
@@ -1821,6 +1859,7 @@ mod tests {
             let pagination = PaginationRequest {
                 page_size: 2,
                 next_page_token: result.next_page_token,
+                ..Default::default()
             };
             let result = repo
                 .list_group_members("group-1", &pagination)
@@ -1855,6 +1894,7 @@ mod tests {
             let pagination = PaginationRequest {
                 page_size: 10,
                 next_page_token: None,
+                ..Default::default()
             };
             let result = repo.list_user_groups("user-1", &pagination).await.unwrap();
             assert_eq!(result.items.len(), 5);
@@ -1890,6 +1930,7 @@ mod tests {
             let pagination = PaginationRequest {
                 page_size: 2,
                 next_page_token: None,
+                ..Default::default()
             };
             let result = repo.list_user_groups("user-1", &pagination).await.unwrap();
             assert_eq!(result.items.len(), 2);
@@ -1899,6 +1940,7 @@ mod tests {
             let pagination = PaginationRequest {
                 page_size: 2,
                 next_page_token: result.next_page_token,
+                ..Default::default()
             };
             let result = repo.list_user_groups("user-1", &pagination).await.unwrap();
             assert_eq!(result.items.len(), 2);
@@ -1941,6 +1983,7 @@ mod tests {
             let pagination = PaginationRequest {
                 page_size: 10,
                 next_page_token: None,
+                ..Default::default()
             };
             let result = repo
                 .list_group_members("group-1", &pagination)
@@ -2003,6 +2046,7 @@ mod tests {
             let pagination = PaginationRequest {
                 page_size: 10,
                 next_page_token: None,
+                ..Default::default()
             };
             let result = repo.list_user_groups("user-1", &pagination).await.unwrap();
             assert_eq!(result.items.len(), 0);
@@ -2041,6 +2085,7 @@ mod tests {
             let pagination = PaginationRequest {
                 page_size: 10,
                 next_page_token: None,
+                ..Default::default()
             };
             // Check users are no longer in the group
             for i in 1..=3 {
@@ -2083,6 +2128,7 @@ mod tests {
             let pagination = PaginationRequest {
                 page_size: 10,
                 next_page_token: None,
+                ..Default::default()
             };
             for i in 1..=3 {
                 let result = repo
@@ -2179,6 +2225,7 @@ mod tests {
             let pagination = PaginationRequest {
                 page_size: 10,
                 next_page_token: None,
+                ..Default::default()
             };
             let result = repo.list_jwt_signing_keys(&pagination).await.unwrap();
             assert_eq!(result.items.len(), 5);
@@ -2204,6 +2251,7 @@ mod tests {
             let pagination = PaginationRequest {
                 page_size: 2,
                 next_page_token: None,
+                ..Default::default()
             };
             let result = repo.list_jwt_signing_keys(&pagination).await.unwrap();
             assert_eq!(result.items.len(), 2);
@@ -2213,6 +2261,7 @@ mod tests {
             let pagination = PaginationRequest {
                 page_size: 2,
                 next_page_token: result.next_page_token,
+                ..Default::default()
             };
             let result = repo.list_jwt_signing_keys(&pagination).await.unwrap();
             assert_eq!(result.items.len(), 2);
@@ -2222,6 +2271,7 @@ mod tests {
             let pagination = PaginationRequest {
                 page_size: 2,
                 next_page_token: result.next_page_token,
+                ..Default::default()
             };
             let result = repo.list_jwt_signing_keys(&pagination).await.unwrap();
             assert_eq!(result.items.len(), 1);
@@ -2373,6 +2423,7 @@ mod tests {
             let pagination = PaginationRequest {
                 page_size: 10,
                 next_page_token: None,
+                ..Default::default()
             };
             let result = repo
                 .list_sts_configurations(&pagination, None)
@@ -2399,6 +2450,7 @@ mod tests {
             let pagination = PaginationRequest {
                 page_size: 10,
                 next_page_token: None,
+                ..Default::default()
             };
             let result = repo
                 .list_sts_configurations(&pagination, Some(StsTokenConfigType::JwtTemplate))
@@ -2441,6 +2493,7 @@ mod tests {
             let pagination = PaginationRequest {
                 page_size: 2,
                 next_page_token: None,
+                ..Default::default()
             };
             let result = repo
                 .list_sts_configurations(&pagination, None)
@@ -2453,6 +2506,7 @@ mod tests {
             let pagination = PaginationRequest {
                 page_size: 2,
                 next_page_token: result.next_page_token,
+                ..Default::default()
             };
             let result = repo
                 .list_sts_configurations(&pagination, None)
@@ -2465,6 +2519,7 @@ mod tests {
             let pagination = PaginationRequest {
                 page_size: 2,
                 next_page_token: result.next_page_token,
+                ..Default::default()
             };
             let result = repo
                 .list_sts_configurations(&pagination, None)
@@ -2493,6 +2548,7 @@ mod tests {
             let pagination = PaginationRequest {
                 page_size: 2,
                 next_page_token: None,
+                ..Default::default()
             };
             let result = repo
                 .list_sts_configurations(&pagination, Some(StsTokenConfigType::JwtTemplate))
@@ -2511,6 +2567,7 @@ mod tests {
             let pagination = PaginationRequest {
                 page_size: 2,
                 next_page_token: result.next_page_token,
+                ..Default::default()
             };
             let result = repo
                 .list_sts_configurations(&pagination, Some(StsTokenConfigType::JwtTemplate))
@@ -4,6 +4,7 @@
 //! - `api_key`: API key management endpoints
 //! - `auth`: Authentication endpoints (login, logout, token refresh)
 //! - `jwk`: JSON Web Key endpoints
+//! - `rate_limit`: Per-API-key and per-IP request rate limiting middleware
 //! - `scim`: SCIM 2.0 user and group provisioning endpoints
 //! - `sts_config`: STS configuration endpoints
 //! - `sts_exchange`: STS token exchange endpoints
@@ -14,6 +15,7 @@
 mod api_key;
 mod auth;
 mod jwk;
+pub mod rate_limit;
 mod scim;
 mod sts_config;
 mod sts_exchange;
@@ -472,6 +474,7 @@ async fn route_list_users(
     let pagination = PaginationRequest {
         page_size: query.page_size,
         next_page_token: query.next_page_token,
+        ..Default::default()
     };
 
     let user_type_filter = query.user_type.as_ref().and_then(|s| UserType::parse(s));
@@ -527,6 +530,7 @@ async fn route_list_user_groups(
         let pagination = PaginationRequest {
             page_size: query.page_size,
             next_page_token: query.next_page_token,
+            ..Default::default()
         };
 
         ctx.repository.list_user_groups(&user_id, &pagination).await
@@ -757,6 +761,7 @@ async fn route_list_groups(
     let pagination = PaginationRequest {
         page_size: query.page_size,
         next_page_token: query.next_page_token,
+        ..Default::default()
     };
 
     let res = ctx.repository.list_groups(&pagination).await;
@@ -940,6 +945,7 @@ async fn route_list_group_members(
         let pagination = PaginationRequest {
             page_size: query.page_size,
             next_page_token: query.next_page_token,
+            ..Default::default()
         };
 
         ctx.repository
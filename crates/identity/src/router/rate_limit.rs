@@ -0,0 +1,367 @@
+//! Per-caller request rate limiting
+//!
+//! Requests carrying a known API key are limited per key; every other request is limited per
+//! client IP. Each caller gets a token bucket holding `requests_per_minute` tokens that refills
+//! continuously, so a caller may burst up to the limit and then proceeds at the steady rate.
+//!
+//! Apply [`rate_limit`] with `axum::middleware::from_fn_with_state(limiter, rate_limit)`. Paths
+//! listed in [`RateLimitConfig::exempt_paths`] (health probes, internal SDK callbacks) are never
+//! limited.
+
+use std::net::{IpAddr, SocketAddr};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use axum::Json;
+use axum::extract::{ConnectInfo, Request, State};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use dashmap::DashMap;
+use http::{StatusCode, header::RETRY_AFTER};
+use serde_json::json;
+use tokio::time::Instant;
+use tracing::{debug, warn};
+
+use crate::logic::api_key::cache::ApiKeyCache;
+use crate::logic::api_key::hash_api_key;
+use crate::logic::auth_client::API_KEY_HEADER;
+
+/// Once this many callers are tracked, buckets that have fully refilled are dropped
+const MAX_TRACKED_CALLERS: usize = 10_000;
+
+/// Minimum time between two sweeps of fully refilled buckets
+const SWEEP_INTERVAL: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Clone)]
+pub struct RateLimitConfig {
+    /// Requests each caller may make per minute, which is also the burst size
+    pub requests_per_minute: u32,
+    /// Request paths that are never limited, matched exactly
+    pub exempt_paths: Vec<String>,
+}
+
+/// Who a request is counted against
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum RateLimitKey {
+    /// A known API key, by key id
+    ApiKey(String),
+    /// A request without a known API key, by client IP
+    Ip(IpAddr),
+    /// A request without a known API key whose client address is not available
+    Anonymous,
+}
+
+struct TokenBucket {
+    tokens: f64,
+    refilled_at: Instant,
+}
+
+#[derive(Clone)]
+pub struct RateLimiter {
+    config: RateLimitConfig,
+    api_key_cache: ApiKeyCache,
+    buckets: Arc<DashMap<RateLimitKey, TokenBucket>>,
+    last_swept: Arc<Mutex<Instant>>,
+}
+
+impl RateLimiter {
+    pub fn new(config: RateLimitConfig, api_key_cache: ApiKeyCache) -> Self {
+        Self {
+            config,
+            api_key_cache,
+            buckets: Arc::new(DashMap::new()),
+            last_swept: Arc::new(Mutex::new(Instant::now())),
+        }
+    }
+
+    /// Whether requests to `path` bypass the limiter
+    pub fn is_exempt(&self, path: &str) -> bool {
+        self.config.exempt_paths.iter().any(|exempt| exempt == path)
+    }
+
+    fn capacity(&self) -> f64 {
+        f64::from(self.config.requests_per_minute.max(1))
+    }
+
+    /// Tokens a bucket regains over `elapsed`
+    fn refill(&self, elapsed: Duration) -> f64 {
+        elapsed.as_secs_f64() * self.capacity() / 60.0
+    }
+
+    /// Takes a token from the caller's bucket, or returns how long until one is available
+    pub fn check(&self, key: RateLimitKey) -> Result<(), Duration> {
+        let capacity = self.capacity();
+        let now = Instant::now();
+
+        self.sweep(now);
+
+        let mut bucket = self.buckets.entry(key).or_insert(TokenBucket {
+            tokens: capacity,
+            refilled_at: now,
+        });
+        bucket.tokens =
+            (bucket.tokens + self.refill(now.duration_since(bucket.refilled_at))).min(capacity);
+        bucket.refilled_at = now;
+
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            Ok(())
+        } else {
+            Err(Duration::from_secs_f64(
+                (1.0 - bucket.tokens) * 60.0 / capacity,
+            ))
+        }
+    }
+
+    /// Drops buckets that have fully refilled once too many callers are tracked. The scan runs
+    /// at most once per [`SWEEP_INTERVAL`], so a full map does not cost a scan per request.
+    fn sweep(&self, now: Instant) {
+        if self.buckets.len() < MAX_TRACKED_CALLERS {
+            return;
+        }
+        // Another request is already sweeping
+        let Ok(mut last_swept) = self.last_swept.try_lock() else {
+            return;
+        };
+        if now.duration_since(*last_swept) < SWEEP_INTERVAL {
+            return;
+        }
+        *last_swept = now;
+
+        let capacity = self.capacity();
+        self.buckets.retain(|_, bucket| {
+            bucket.tokens + self.refill(now.duration_since(bucket.refilled_at)) < capacity
+        });
+        debug!(tracked = self.buckets.len(), "Swept rate limit buckets");
+    }
+
+    /// Resolves who a request is counted against. Unknown API keys are counted against the
+    /// client IP so random keys cannot be used to get a fresh bucket.
+    pub async fn key_for(&self, api_key: Option<&str>, client_ip: Option<IpAddr>) -> RateLimitKey {
+        if let Some(api_key) = api_key {
+            match self
+                .api_key_cache
+                .get_by_hashed_value(&hash_api_key(api_key))
+                .await
+            {
+                Ok(Some(cached)) => return RateLimitKey::ApiKey(cached.api_key.id),
+                Ok(None) => {}
+                Err(e) => warn!(error = ?e, "Failed to resolve API key for rate limiting"),
+            }
+        }
+
+        match client_ip {
+            Some(ip) => RateLimitKey::Ip(ip),
+            None => RateLimitKey::Anonymous,
+        }
+    }
+}
+
+/// Rejects requests over the caller's limit with 429 and a `Retry-After` header in seconds
+pub async fn rate_limit(
+    State(limiter): State<RateLimiter>,
+    request: Request,
+    next: Next,
+) -> Response {
+    if limiter.is_exempt(request.uri().path()) {
+        return next.run(request).await;
+    }
+
+    let api_key = request
+        .headers()
+        .get(API_KEY_HEADER)
+        .and_then(|v| v.to_str().ok())
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty());
+    let client_ip = request
+        .extensions()
+        .get::<ConnectInfo<SocketAddr>>()
+        .map(|ConnectInfo(addr)| addr.ip());
+
+    let key = limiter.key_for(api_key.as_deref(), client_ip).await;
+    match limiter.check(key.clone()) {
+        Ok(()) => next.run(request).await,
+        Err(retry_after) => {
+            debug!(?key, "Rate limit exceeded");
+            let retry_after_seconds = retry_after.as_secs_f64().ceil().max(1.0) as u64;
+            (
+                StatusCode::TOO_MANY_REQUESTS,
+                [(RETRY_AFTER, retry_after_seconds.to_string())],
+                Json(json!({
+                    "name": "RateLimited",
+                    "message": "too many requests, retry later",
+                })),
+            )
+                .into_response()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod unit {
+        use super::super::*;
+        use crate::logic::api_key::{HashedApiKey, HashedApiKeyWithUser};
+        use crate::repository::Repository;
+        use axum::{Router, body::Body, middleware, routing::get};
+        use shared::identity::{Role, User, UserType};
+        use shared::primitives::{SqlMigrationLoader, WrappedChronoDateTime};
+        use shared::test_utils::repository::setup_in_memory_database;
+        use tower::Service;
+
+        const API_KEY: &str = "sk--rate-limited";
+
+        async fn setup_router(requests_per_minute: u32) -> Router {
+            shared::setup_test!();
+
+            let (_db, conn) = setup_in_memory_database(vec![Repository::load_sql_migrations()])
+                .await
+                .unwrap();
+            let api_key_cache = ApiKeyCache::new(Arc::new(Repository::new(conn)));
+            let now = WrappedChronoDateTime::now();
+            api_key_cache.add(HashedApiKeyWithUser {
+                api_key: HashedApiKey {
+                    id: "key-1".to_string(),
+                    hashed_value: hash_api_key(API_KEY),
+                    description: None,
+                    user_id: "machine-1".to_string(),
+                    created_at: now,
+                    updated_at: now,
+                },
+                user: User {
+                    id: "machine-1".to_string(),
+                    user_type: UserType::Machine,
+                    email: None,
+                    role: Role::Agent,
+                    description: None,
+                    created_at: now,
+                    updated_at: now,
+                },
+            });
+
+            let limiter = RateLimiter::new(
+                RateLimitConfig {
+                    requests_per_minute,
+                    exempt_paths: vec!["/healthz".to_string()],
+                },
+                api_key_cache,
+            );
+            Router::new()
+                .route("/", get(|| async { "ok" }))
+                .route("/healthz", get(|| async { "ok" }))
+                .layer(middleware::from_fn_with_state(limiter, rate_limit))
+        }
+
+        fn request_with_key(api_key: &str) -> Request {
+            Request::get("/")
+                .header(API_KEY_HEADER, api_key)
+                .body(Body::empty())
+                .unwrap()
+        }
+
+        fn request_from(ip: [u8; 4]) -> Request {
+            let mut request = Request::get("/").body(Body::empty()).unwrap();
+            request
+                .extensions_mut()
+                .insert(ConnectInfo(SocketAddr::from((ip, 4000))));
+            request
+        }
+
+        #[tokio::test(start_paused = true)]
+        async fn test_exhausted_api_key_is_rejected_until_refill() {
+            let mut router = setup_router(2).await;
+
+            for _ in 0..2 {
+                let response = router.call(request_with_key(API_KEY)).await.unwrap();
+                assert_eq!(response.status(), StatusCode::OK);
+            }
+
+            let response = router.call(request_with_key(API_KEY)).await.unwrap();
+            assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+            assert_eq!(response.headers()[RETRY_AFTER], "30");
+
+            // Other callers have their own buckets
+            let response = router.call(request_from([10, 0, 0, 1])).await.unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+
+            tokio::time::advance(Duration::from_secs(30)).await;
+            let response = router.call(request_with_key(API_KEY)).await.unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+            let response = router.call(request_with_key(API_KEY)).await.unwrap();
+            assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+        }
+
+        #[tokio::test(start_paused = true)]
+        async fn test_unauthenticated_requests_are_limited_per_ip() {
+            let mut router = setup_router(1).await;
+
+            let response = router.call(request_from([10, 0, 0, 1])).await.unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+            let response = router.call(request_from([10, 0, 0, 1])).await.unwrap();
+            assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+            assert_eq!(response.headers()[RETRY_AFTER], "60");
+
+            let response = router.call(request_from([10, 0, 0, 2])).await.unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+
+            // An unknown API key does not escape the IP bucket
+            let mut request = request_with_key("sk--unknown");
+            request
+                .extensions_mut()
+                .insert(ConnectInfo(SocketAddr::from(([10, 0, 0, 1], 4000))));
+            let response = router.call(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+
+            tokio::time::advance(Duration::from_secs(60)).await;
+            let response = router.call(request_from([10, 0, 0, 1])).await.unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+        }
+
+        #[tokio::test(start_paused = true)]
+        async fn test_exempt_paths_are_not_limited() {
+            let mut router = setup_router(1).await;
+
+            for _ in 0..3 {
+                let mut request = Request::get("/healthz").body(Body::empty()).unwrap();
+                request
+                    .extensions_mut()
+                    .insert(ConnectInfo(SocketAddr::from(([10, 0, 0, 1], 4000))));
+                let response = router.call(request).await.unwrap();
+                assert_eq!(response.status(), StatusCode::OK);
+            }
+
+            // Exempt requests did not use up the caller's bucket
+            let response = router.call(request_from([10, 0, 0, 1])).await.unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+        }
+
+        #[tokio::test(start_paused = true)]
+        async fn test_refilled_buckets_are_swept_once_per_interval() {
+            shared::setup_test!();
+
+            let (_db, conn) = setup_in_memory_database(vec![Repository::load_sql_migrations()])
+                .await
+                .unwrap();
+            let limiter = RateLimiter::new(
+                RateLimitConfig {
+                    requests_per_minute: 60,
+                    exempt_paths: vec![],
+                },
+                ApiKeyCache::new(Arc::new(Repository::new(conn))),
+            );
+            let ip = |i: usize| RateLimitKey::Ip(IpAddr::from((i as u32).to_be_bytes()));
+
+            for i in 0..MAX_TRACKED_CALLERS {
+                limiter.check(ip(i)).unwrap();
+            }
+            // Nothing is swept before the interval has passed
+            limiter.check(ip(MAX_TRACKED_CALLERS)).unwrap();
+            assert_eq!(limiter.buckets.len(), MAX_TRACKED_CALLERS + 1);
+
+            // Every bucket has refilled by now, so only the new caller remains
+            tokio::time::advance(SWEEP_INTERVAL).await;
+            limiter.check(ip(MAX_TRACKED_CALLERS + 1)).unwrap();
+            assert_eq!(limiter.buckets.len(), 1);
+        }
+    }
+}
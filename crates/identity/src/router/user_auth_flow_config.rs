@@ -202,6 +202,7 @@ async fn route_list_user_auth_flow_configs(
         pagination: PaginationRequest {
             page_size: query.page_size.unwrap_or(10) as i64,
             next_page_token: query.next_page_token,
+            ..Default::default()
         },
         config_type: query.config_type,
     };
@@ -226,20 +226,39 @@ SELECT
         ), JSON('null')) AS TEXT
     ) AS user_credential
 FROM provider_instance pi
-WHERE (pi.created_at < sqlc.narg(cursor) OR sqlc.narg(cursor) IS NULL)
+WHERE ((pi.created_at, pi.id) < (sqlc.narg(cursor), sqlc.narg(cursor_id)) OR sqlc.narg(cursor) IS NULL)
   AND (CAST(pi.status = sqlc.narg(status) AS TEXT) OR sqlc.narg(status) IS NULL)
   AND (CAST(pi.provider_controller_type_id = sqlc.narg(provider_controller_type_id) AS TEXT) OR sqlc.narg(provider_controller_type_id) IS NULL)
-ORDER BY pi.created_at DESC
+ORDER BY pi.created_at DESC, pi.id DESC
 LIMIT CAST(sqlc.arg(page_size) AS INTEGER) + 1;
 
+-- name: count_provider_instances :one
+SELECT CAST(COUNT(*) AS INTEGER) AS total
+FROM provider_instance pi
+WHERE (CAST(pi.status = sqlc.narg(status) AS TEXT) OR sqlc.narg(status) IS NULL)
+  AND (CAST(pi.provider_controller_type_id = sqlc.narg(provider_controller_type_id) AS TEXT) OR sqlc.narg(provider_controller_type_id) IS NULL);
+
 -- name: get_function_instances :many
 SELECT function_controller_type_id, provider_controller_type_id, provider_instance_id, created_at, updated_at
 FROM function_instance
-WHERE (created_at < sqlc.narg(cursor) OR sqlc.narg(cursor) IS NULL)
+WHERE ((created_at, provider_instance_id, function_controller_type_id) < (sqlc.narg(cursor), sqlc.narg(cursor_provider_instance_id), sqlc.narg(cursor_function_controller_type_id)) OR sqlc.narg(cursor) IS NULL)
   AND (CAST(provider_instance_id = sqlc.narg(provider_instance_id) AS TEXT) OR sqlc.narg(provider_instance_id) IS NULL)
-ORDER BY created_at DESC
+ORDER BY created_at DESC, provider_instance_id DESC, function_controller_type_id DESC
+LIMIT CAST(sqlc.arg(page_size) AS INTEGER) + 1;
+
+-- name: get_function_instances_backward :many
+SELECT function_controller_type_id, provider_controller_type_id, provider_instance_id, created_at, updated_at
+FROM function_instance
+WHERE (created_at, provider_instance_id, function_controller_type_id) > (sqlc.arg(cursor), sqlc.narg(cursor_provider_instance_id), sqlc.narg(cursor_function_controller_type_id))
+  AND (CAST(provider_instance_id = sqlc.narg(provider_instance_id) AS TEXT) OR sqlc.narg(provider_instance_id) IS NULL)
+ORDER BY created_at ASC, provider_instance_id ASC, function_controller_type_id ASC
 LIMIT CAST(sqlc.arg(page_size) AS INTEGER) + 1;
 
+-- name: count_function_instances :one
+SELECT CAST(COUNT(*) AS INTEGER) AS total
+FROM function_instance
+WHERE (CAST(provider_instance_id = sqlc.narg(provider_instance_id) AS TEXT) OR sqlc.narg(provider_instance_id) IS NULL);
+
 -- name: get_provider_instances_grouped_by_function_controller_type_id :many
 SELECT
     fi.function_controller_type_id,
@@ -0,0 +1,268 @@
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, RwLock};
+
+use shared::error::CommonError;
+use shared::identity::{Identity, Role};
+use shared::restate::invoke::parse_initial_object_id;
+
+/// Header the generated bridge clients set to the Restate object key of the agent task they run
+/// in (`soma:v1:task:{task_id}`), so the calling agent can be resolved from the task.
+pub const AGENT_TASK_KEY_HEADER: &str = "x-soma-task-key";
+
+/// Upper bound on remembered task -> agent bindings. The oldest binding is evicted first and is
+/// re-established the next time a message for that task is handled.
+const MAX_TASK_BINDINGS: usize = 10_000;
+
+/// Formats the allowlist entry for a provider function
+pub fn agent_function_key(
+    provider_controller_type_id: &str,
+    function_controller_type_id: &str,
+) -> String {
+    format!("{provider_controller_type_id}.{function_controller_type_id}")
+}
+
+#[derive(Default)]
+struct AgentAllowlistsState {
+    /// Keyed by `{project_id}.{agent_id}`, entries are `{provider}.{function}` type ids
+    allowlists: HashMap<String, Vec<String>>,
+    /// Task id -> `{project_id}.{agent_id}` of the agent running the task
+    task_agents: HashMap<String, String>,
+    task_order: VecDeque<String>,
+}
+
+/// Provider functions each agent may invoke, plus the tasks each agent is currently running so
+/// bridge calls made from a task can be attributed to its agent. Cheap to clone.
+#[derive(Clone, Default)]
+pub struct AgentAllowlists {
+    state: Arc<RwLock<AgentAllowlistsState>>,
+}
+
+fn poison_error<T>(_e: T) -> CommonError {
+    CommonError::Unknown(anyhow::anyhow!("Poison error"))
+}
+
+impl AgentAllowlists {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Replaces the allowlist for a single agent. An empty list allows every function.
+    pub fn set(&self, agent_id: &str, allowed_functions: Vec<String>) -> Result<(), CommonError> {
+        let mut state = self.state.write().map_err(poison_error)?;
+        state
+            .allowlists
+            .insert(agent_id.to_string(), allowed_functions);
+        Ok(())
+    }
+
+    /// Replaces every agent allowlist, e.g. after agents are re-synced from the SDK
+    pub fn replace(&self, allowlists: HashMap<String, Vec<String>>) -> Result<(), CommonError> {
+        let mut state = self.state.write().map_err(poison_error)?;
+        state.allowlists = allowlists;
+        Ok(())
+    }
+
+    /// Records that `agent_id` is running `task_id`
+    pub fn bind_task(&self, task_id: &str, agent_id: &str) -> Result<(), CommonError> {
+        let mut state = self.state.write().map_err(poison_error)?;
+        if state
+            .task_agents
+            .insert(task_id.to_string(), agent_id.to_string())
+            .is_none()
+        {
+            state.task_order.push_back(task_id.to_string());
+        }
+        while state.task_order.len() > MAX_TASK_BINDINGS {
+            if let Some(evicted) = state.task_order.pop_front() {
+                state.task_agents.remove(&evicted);
+            }
+        }
+        Ok(())
+    }
+
+    /// Resolves the agent a bridge call is made on behalf of.
+    ///
+    /// Calls carrying a task key belong to the agent bound to that task. Agent principals must
+    /// call from a task, since nothing else identifies which agent they are. Returns `None` for
+    /// every other caller, which is not subject to an allowlist.
+    pub fn resolve_calling_agent(
+        &self,
+        identity: Option<&Identity>,
+        task_key: Option<&str>,
+    ) -> Result<Option<String>, CommonError> {
+        if let Some(task_key) = task_key {
+            let task_id =
+                parse_initial_object_id(task_key).ok_or_else(|| CommonError::InvalidRequest {
+                    msg: format!("Invalid {AGENT_TASK_KEY_HEADER} header: '{task_key}'"),
+                    source: None,
+                })?;
+            let state = self.state.read().map_err(poison_error)?;
+            return match state.task_agents.get(task_id) {
+                Some(agent_id) => Ok(Some(agent_id.clone())),
+                None => Err(CommonError::Authorization {
+                    msg: format!("Task '{task_id}' is not running on any known agent"),
+                    source: anyhow::anyhow!("Unknown agent task"),
+                }),
+            };
+        }
+
+        if matches!(identity.and_then(Identity::role), Some(Role::Agent)) {
+            return Err(CommonError::Authorization {
+                msg: format!(
+                    "Agent callers must invoke functions from a task ({AGENT_TASK_KEY_HEADER})"
+                ),
+                source: anyhow::anyhow!("Agent caller without task context"),
+            });
+        }
+
+        Ok(None)
+    }
+
+    /// Returns an authorization error unless `agent_id` has an allowlist that is empty or
+    /// includes the provider function. Agents without a registered allowlist are rejected.
+    pub fn check(
+        &self,
+        agent_id: &str,
+        provider_controller_type_id: &str,
+        function_controller_type_id: &str,
+    ) -> Result<(), CommonError> {
+        let state = self.state.read().map_err(poison_error)?;
+        let key = agent_function_key(provider_controller_type_id, function_controller_type_id);
+
+        let Some(allowed_functions) = state.allowlists.get(agent_id) else {
+            tracing::debug!(agent_id, function = %key, "Agent has no registered allowlist");
+            return Err(CommonError::Authorization {
+                msg: format!("Agent '{agent_id}' is not registered to invoke functions"),
+                source: anyhow::anyhow!("Agent has no allowlist"),
+            });
+        };
+        if allowed_functions.is_empty() || allowed_functions.contains(&key) {
+            return Ok(());
+        }
+
+        tracing::debug!(agent_id, function = %key, "Function not in agent allowlist");
+        Err(CommonError::Authorization {
+            msg: format!("Agent '{agent_id}' is not allowed to invoke function '{key}'"),
+            source: anyhow::anyhow!("Function not in agent allowlist"),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod unit {
+        use super::super::*;
+        use shared::identity::Machine;
+        use shared::restate::invoke::construct_initial_object_id;
+
+        fn agent_identity() -> Identity {
+            Identity::Machine(Machine {
+                sub: "agent-machine".to_string(),
+                role: Role::Agent,
+            })
+        }
+
+        #[test]
+        fn test_function_outside_allowlist_is_rejected() {
+            shared::setup_test!();
+
+            let allowlists = AgentAllowlists::new();
+            allowlists
+                .set(
+                    "project.restricted",
+                    vec![agent_function_key("mail", "send")],
+                )
+                .unwrap();
+
+            let result = allowlists.check("project.restricted", "mail", "delete");
+            assert!(matches!(result, Err(CommonError::Authorization { .. })));
+        }
+
+        #[test]
+        fn test_function_inside_allowlist_is_allowed() {
+            shared::setup_test!();
+
+            let allowlists = AgentAllowlists::new();
+            allowlists
+                .set("project.scoped", vec![agent_function_key("mail", "send")])
+                .unwrap();
+
+            assert!(allowlists.check("project.scoped", "mail", "send").is_ok());
+        }
+
+        #[test]
+        fn test_empty_allowlist_allows_everything() {
+            shared::setup_test!();
+
+            let allowlists = AgentAllowlists::new();
+            allowlists.set("project.open", vec![]).unwrap();
+
+            assert!(allowlists.check("project.open", "mail", "delete").is_ok());
+        }
+
+        #[test]
+        fn test_agent_without_allowlist_is_rejected() {
+            shared::setup_test!();
+
+            let allowlists = AgentAllowlists::new();
+
+            let result = allowlists.check("project.unknown", "mail", "delete");
+            assert!(matches!(result, Err(CommonError::Authorization { .. })));
+        }
+
+        #[test]
+        fn test_calling_agent_is_resolved_from_task() {
+            shared::setup_test!();
+
+            let allowlists = AgentAllowlists::new();
+            allowlists.bind_task("task-1", "project.agent").unwrap();
+            let task_key = construct_initial_object_id("task-1");
+
+            let agent = allowlists
+                .resolve_calling_agent(Some(&agent_identity()), Some(&task_key))
+                .unwrap();
+            assert_eq!(agent.as_deref(), Some("project.agent"));
+
+            let unknown_task = construct_initial_object_id("task-2");
+            let result = allowlists.resolve_calling_agent(None, Some(&unknown_task));
+            assert!(matches!(result, Err(CommonError::Authorization { .. })));
+        }
+
+        #[test]
+        fn test_agent_principal_without_task_is_rejected() {
+            shared::setup_test!();
+
+            let allowlists = AgentAllowlists::new();
+
+            let result = allowlists.resolve_calling_agent(Some(&agent_identity()), None);
+            assert!(matches!(result, Err(CommonError::Authorization { .. })));
+            assert!(
+                allowlists
+                    .resolve_calling_agent(None, None)
+                    .unwrap()
+                    .is_none()
+            );
+        }
+
+        #[test]
+        fn test_oldest_task_binding_is_evicted() {
+            shared::setup_test!();
+
+            let allowlists = AgentAllowlists::new();
+            for i in 0..=MAX_TASK_BINDINGS {
+                allowlists
+                    .bind_task(&format!("task-{i}"), "project.agent")
+                    .unwrap();
+            }
+
+            let first = construct_initial_object_id("task-0");
+            assert!(
+                allowlists
+                    .resolve_calling_agent(None, Some(&first))
+                    .is_err()
+            );
+            let last = construct_initial_object_id(&format!("task-{MAX_TASK_BINDINGS}"));
+            assert!(allowlists.resolve_calling_agent(None, Some(&last)).is_ok());
+        }
+    }
+}
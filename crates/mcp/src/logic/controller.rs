@@ -4,7 +4,7 @@ use once_cell::sync::Lazy;
 use serde::{Deserialize, Serialize};
 use shared::{
     error::CommonError,
-    primitives::{PaginatedResponse, PaginationRequest, WrappedSchema},
+    primitives::{PaginatedResponse, PaginationRequest, WrappedJsonValue, WrappedSchema},
 };
 use shared_macros::{authn, authz_role};
 use std::sync::RwLock;
@@ -73,6 +73,17 @@ pub struct FunctionControllerSerialized {
     pub parameters: WrappedSchema,
     pub output: WrappedSchema,
     pub categories: Vec<String>, // TODO: change to Vec<&'static str>
+    pub deprecated: bool,
+    pub deprecation_message: Option<String>,
+    pub examples: Vec<FunctionExample>,
+}
+
+/// Example invocation of a function, for documentation in UIs and generated clients
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, ToSchema)]
+pub struct FunctionExample {
+    pub description: Option<String>,
+    pub parameters: WrappedJsonValue,
+    pub output: Option<WrappedJsonValue>,
 }
 
 impl From<Arc<dyn FunctionControllerLike>> for FunctionControllerSerialized {
@@ -88,6 +99,9 @@ impl From<Arc<dyn FunctionControllerLike>> for FunctionControllerSerialized {
                 .into_iter()
                 .map(|c| c.to_string())
                 .collect(),
+            deprecated: function.deprecated(),
+            deprecation_message: function.deprecation_message(),
+            examples: function.examples(),
         }
     }
 }
@@ -105,6 +119,9 @@ impl From<&Arc<dyn FunctionControllerLike>> for FunctionControllerSerialized {
                 .into_iter()
                 .map(|c| c.to_string())
                 .collect(),
+            deprecated: function.deprecated(),
+            deprecation_message: function.deprecation_message(),
+            examples: function.examples(),
         }
     }
 }
@@ -320,6 +337,7 @@ mod tests {
             let pagination = PaginationRequest {
                 page_size: 10,
                 next_page_token: None,
+                ..Default::default()
             };
 
             let result = list_available_providers(auth_client, headers, pagination).await;
@@ -8,9 +8,10 @@ use shared::{
 };
 
 use crate::logic::{
-    ConfigurationSchema, Metadata, ProviderCredentialControllerLike, ResourceServerCredentialLike,
-    ResourceServerCredentialSerialized, StaticCredentialConfigurationLike,
-    StaticProviderCredentialControllerLike, UserCredentialLike, schemars_make_password,
+    ConfigurationSchema, CredentialValidator, Metadata, ProviderCredentialControllerLike,
+    ResourceServerCredentialLike, ResourceServerCredentialSerialized,
+    StaticCredentialConfigurationLike, StaticProviderCredentialControllerLike, UserCredentialLike,
+    schemars_make_password,
 };
 use ::encryption::logic::crypto_services::{DecryptionService, EncryptedString, EncryptionService};
 
@@ -53,6 +54,12 @@ impl ResourceServerCredentialLike for ApiKeyResourceServerCredential {
     fn value(&self) -> WrappedJsonValue {
         WrappedJsonValue::new(json!(self))
     }
+
+    fn validate(&self) -> Result<(), CommonError> {
+        CredentialValidator::new()
+            .require_non_empty("api_key", &self.api_key.0)
+            .finish(self.type_id())
+    }
 }
 
 // ============================================================================
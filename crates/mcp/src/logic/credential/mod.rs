@@ -11,7 +11,8 @@ use serde_json::Value;
 use shared::{
     error::CommonError,
     primitives::{
-        PaginationRequest, WrappedChronoDateTime, WrappedJsonValue, WrappedSchema, WrappedUuidV4,
+        JsonLimits, PaginationRequest, WrappedChronoDateTime, WrappedJsonValue, WrappedSchema,
+        WrappedUuidV4,
     },
 };
 use shared_macros::{authn, authz_role};
@@ -22,8 +23,8 @@ use ::encryption::logic::crypto_services::{DecryptionService, EncryptionService}
 
 use crate::{
     logic::{
-        Metadata, OnConfigChangeEvt, OnConfigChangeTx, ProviderControllerLike,
-        ProviderCredentialControllerLike,
+        DEFAULT_REFRESH_LEAD_TIME, Metadata, OnConfigChangeEvt, OnConfigChangeTx,
+        ProviderControllerLike, ProviderCredentialControllerLike,
         controller::{
             WithCredentialControllerTypeId, WithProviderControllerTypeId,
             get_credential_controller, get_provider_controller,
@@ -52,7 +53,76 @@ pub struct Credential<T> {
 }
 
 pub trait RotateableCredentialLike: Send + Sync {
-    fn next_rotation_time(&self) -> WrappedChronoDateTime;
+    fn expiry_time(&self) -> WrappedChronoDateTime;
+    /// Rotation time using [`DEFAULT_REFRESH_LEAD_TIME`]. The rotation scheduler uses
+    /// [`scheduled_rotation_time`] instead, which honours the provider's lead time.
+    fn next_rotation_time(&self) -> WrappedChronoDateTime {
+        rotation_time_before_expiry(&self.expiry_time(), DEFAULT_REFRESH_LEAD_TIME)
+    }
+}
+
+/// `lead_time` before `expiry_time`, or `expiry_time` itself if that underflows
+pub fn rotation_time_before_expiry(
+    expiry_time: &WrappedChronoDateTime,
+    lead_time: std::time::Duration,
+) -> WrappedChronoDateTime {
+    chrono::Duration::from_std(lead_time)
+        .ok()
+        .and_then(|lead_time| expiry_time.get_inner().checked_sub_signed(lead_time))
+        .map(WrappedChronoDateTime::new)
+        .unwrap_or(*expiry_time)
+}
+
+/// When the rotation scheduler should next rotate `credential`: the provider controller's
+/// [`ProviderControllerLike::refresh_lead_time`] before it expires
+pub fn scheduled_rotation_time(
+    provider_controller: &dyn ProviderControllerLike,
+    credential: &dyn RotateableCredentialLike,
+) -> WrappedChronoDateTime {
+    rotation_time_before_expiry(
+        &credential.expiry_time(),
+        provider_controller.refresh_lead_time(),
+    )
+}
+
+/// Collects field-level problems found while validating a credential so they can
+/// be reported together as a single `CommonError::InvalidRequest`.
+#[derive(Default)]
+pub struct CredentialValidator {
+    errors: Vec<String>,
+}
+
+impl CredentialValidator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn require_non_empty(&mut self, field: &str, value: &str) -> &mut Self {
+        if value.trim().is_empty() {
+            self.errors.push(format!("{field}: must not be empty"));
+        }
+        self
+    }
+
+    pub fn require_http_url(&mut self, field: &str, value: &str) -> &mut Self {
+        if value.trim().is_empty() {
+            self.errors.push(format!("{field}: must not be empty"));
+        } else if !(value.starts_with("https://") || value.starts_with("http://")) {
+            self.errors
+                .push(format!("{field}: must be an absolute http(s) URL"));
+        }
+        self
+    }
+
+    pub fn finish(&self, type_id: &str) -> Result<(), CommonError> {
+        if self.errors.is_empty() {
+            return Ok(());
+        }
+        Err(CommonError::InvalidRequest {
+            msg: format!("Invalid {type_id} credential: {}", self.errors.join("; ")),
+            source: None,
+        })
+    }
 }
 
 // Static credential configurations
@@ -75,6 +145,10 @@ pub trait ResourceServerCredentialLike: Send + Sync {
     fn as_rotateable_credential(&self) -> Option<&dyn RotateableCredentialLike> {
         None
     }
+    /// Checks that required fields are present and well formed before the credential is saved.
+    fn validate(&self) -> Result<(), CommonError> {
+        Ok(())
+    }
 }
 
 pub type ResourceServerCredential = Credential<Arc<dyn ResourceServerCredentialLike>>;
@@ -87,6 +161,10 @@ pub trait UserCredentialLike: Send + Sync {
     fn as_rotateable_credential(&self) -> Option<&dyn RotateableCredentialLike> {
         None
     }
+    /// Checks that required fields are present and well formed before the credential is saved.
+    fn validate(&self) -> Result<(), CommonError> {
+        Ok(())
+    }
 }
 
 pub type UserCredential = Credential<Arc<dyn UserCredentialLike>>;
@@ -233,6 +311,7 @@ pub type CreateResourceServerCredentialResponse = ResourceServerCredentialSerial
 /// # Errors
 /// - Returns error if provider or credential controller is not found
 /// - Returns error if configuration deserialization fails
+/// - Returns `InvalidRequest` if the credential fails validation
 /// - Returns error if database save fails
 #[authz_role(Admin, Maintainer, permission = "credential:write")]
 #[authn]
@@ -269,6 +348,12 @@ pub async fn create_resource_server_credential(
         e
     })?;
 
+    params
+        .inner
+        .inner
+        .resource_server_configuration
+        .check_limits(&JsonLimits::default())?;
+
     let (resource_server_credential, mut core_metadata) = credential_controller
         .from_serialized_resource_server_configuration(
             params.inner.inner.resource_server_configuration,
@@ -278,13 +363,21 @@ pub async fn create_resource_server_credential(
             e
         })?;
 
+    resource_server_credential.validate().map_err(|e| {
+        tracing::error!(error = %e, "Resource server credential failed validation");
+        e
+    })?;
+
     if let Some(metadata) = params.inner.inner.metadata {
         core_metadata.0.extend(metadata.0);
     }
 
-    let next_rotation_time = resource_server_credential
-        .as_rotateable_credential()
-        .map(|resource_server_credential| resource_server_credential.next_rotation_time());
+    let next_rotation_time =
+        resource_server_credential
+            .as_rotateable_credential()
+            .map(|resource_server_credential| {
+                scheduled_rotation_time(provider_controller.as_ref(), resource_server_credential)
+            });
 
     let now = WrappedChronoDateTime::now();
     let resource_server_credential_serialized = ResourceServerCredentialSerialized {
@@ -334,6 +427,11 @@ pub async fn create_user_credential(
     repo: &impl crate::repository::ProviderRepositoryLike,
     params: CreateUserCredentialParams,
 ) -> Result<CreateUserCredentialResponse, CommonError> {
+    params
+        .inner
+        .inner
+        .user_credential_configuration
+        .check_limits(&JsonLimits::default())?;
     create_user_credential_internal(repo, params).await
 }
 
@@ -353,6 +451,7 @@ pub async fn create_user_credential(
 /// # Errors
 /// - Returns error if provider or credential controller is not found
 /// - Returns error if configuration deserialization fails
+/// - Returns `InvalidRequest` if the credential fails validation
 /// - Returns error if database save fails
 ///
 /// # Safety
@@ -373,13 +472,17 @@ async fn create_user_credential_internal(
             params.inner.inner.user_credential_configuration,
         )?;
 
+    user_credential.validate()?;
+
     if let Some(metadata) = params.inner.inner.metadata {
         core_metadata.0.extend(metadata.0);
     }
 
     let next_rotation_time = user_credential
         .as_rotateable_credential()
-        .map(|user_credential| user_credential.next_rotation_time());
+        .map(|user_credential| {
+            scheduled_rotation_time(provider_controller.as_ref(), user_credential)
+        });
 
     let now = WrappedChronoDateTime::now();
     let user_credential_serialized = UserCredentialSerialized {
@@ -764,6 +867,7 @@ where
                 &PaginationRequest {
                     page_size: 1000,
                     next_page_token,
+                    ..Default::default()
                 },
                 None,
                 Some(&rotation_window_end),
@@ -913,7 +1017,7 @@ where
         }
     };
 
-    let rotated_credential = rotateable_controller
+    let mut rotated_credential = rotateable_controller
         .rotate_resource_server_credential(
             &decryption_service,
             &encryption_service,
@@ -921,6 +1025,14 @@ where
             resource_server_cred,
         )
         .await?;
+    let (rotated, _) = credential_controller
+        .from_serialized_resource_server_configuration(rotated_credential.value.clone())?;
+    if let Some(rotated) = rotated.as_rotateable_credential() {
+        rotated_credential.next_rotation_time = Some(scheduled_rotation_time(
+            provider_controller.as_ref(),
+            rotated,
+        ));
+    }
     // id: &WrappedUuidV4,
     // value: Option<&WrappedJsonValue>,
     // metadata: Option<&crate::logic::Metadata>,
@@ -980,7 +1092,7 @@ where
         }
     };
 
-    let rotated_credential = rotateable_controller
+    let mut rotated_credential = rotateable_controller
         .rotate_user_credential(
             &decryption_service,
             &encryption_service,
@@ -989,6 +1101,14 @@ where
             user_cred,
         )
         .await?;
+    let (rotated, _) = credential_controller
+        .from_serialized_user_credential_configuration(rotated_credential.value.clone())?;
+    if let Some(rotated) = rotated.as_rotateable_credential() {
+        rotated_credential.next_rotation_time = Some(scheduled_rotation_time(
+            provider_controller.as_ref(),
+            rotated,
+        ));
+    }
     // id: &WrappedUuidV4,
     // value: Option<&WrappedJsonValue>,
     // metadata: Option<&crate::logic::Metadata>,
@@ -1022,6 +1142,7 @@ mod tests {
             Oauth2AuthorizationCodeFlowStaticCredentialConfiguration,
             Oauth2AuthorizationCodeFlowUserCredential, OauthAuthFlowController,
         };
+        use ::encryption::logic::crypto_services::EncryptedString;
 
         use shared::primitives::SqlMigrationLoader;
 
@@ -1276,5 +1397,78 @@ mod tests {
             // This credential should NOT be rotated yet
             assert!(rotation_time_later.get_inner() > rotation_window_end.get_inner());
         }
+
+        struct LeadTimeProviderController {
+            refresh_lead_time: Option<std::time::Duration>,
+        }
+
+        impl ProviderControllerLike for LeadTimeProviderController {
+            fn type_id(&self) -> String {
+                "lead_time_test".to_string()
+            }
+            fn documentation(&self) -> String {
+                String::new()
+            }
+            fn name(&self) -> String {
+                "Lead time test".to_string()
+            }
+            fn categories(&self) -> Vec<String> {
+                vec![]
+            }
+            fn functions(&self) -> Vec<Arc<dyn crate::logic::FunctionControllerLike>> {
+                vec![]
+            }
+            fn credential_controllers(&self) -> Vec<Arc<dyn ProviderCredentialControllerLike>> {
+                vec![]
+            }
+            fn metadata(&self) -> Metadata {
+                Metadata::new()
+            }
+            fn refresh_lead_time(&self) -> std::time::Duration {
+                self.refresh_lead_time.unwrap_or(DEFAULT_REFRESH_LEAD_TIME)
+            }
+        }
+
+        #[test]
+        fn test_scheduled_rotation_time_uses_provider_refresh_lead_time() {
+            let expiry_time = WrappedChronoDateTime::now();
+            let credential = Oauth2AuthorizationCodeFlowUserCredential {
+                code: EncryptedString("code".to_string()),
+                access_token: EncryptedString("access".to_string()),
+                refresh_token: EncryptedString("refresh".to_string()),
+                expiry_time,
+                sub: "test-user".to_string(),
+                scopes: vec![],
+                metadata: Metadata::new(),
+            };
+
+            // The trait default is five minutes, same as the credential's own rotation time
+            let default_provider = LeadTimeProviderController {
+                refresh_lead_time: None,
+            };
+            assert_eq!(
+                default_provider.refresh_lead_time(),
+                std::time::Duration::from_secs(5 * 60)
+            );
+            let default_rotation = scheduled_rotation_time(&default_provider, &credential);
+            assert_eq!(
+                default_rotation.get_inner(),
+                expiry_time.get_inner() - chrono::Duration::minutes(5)
+            );
+            assert_eq!(
+                default_rotation.get_inner(),
+                credential.next_rotation_time().get_inner()
+            );
+
+            // A provider with short lived tokens refreshes closer to expiry
+            let short_lived_provider = LeadTimeProviderController {
+                refresh_lead_time: Some(std::time::Duration::from_secs(30)),
+            };
+            let short_lived_rotation = scheduled_rotation_time(&short_lived_provider, &credential);
+            assert_eq!(
+                short_lived_rotation.get_inner(),
+                expiry_time.get_inner() - chrono::Duration::seconds(30)
+            );
+        }
     }
 }
@@ -1,6 +1,9 @@
 use encryption::logic::crypto_services::CryptoCache;
 use serde::{Deserialize, Serialize};
-use shared::{error::CommonError, primitives::WrappedJsonValue};
+use shared::{
+    error::CommonError,
+    primitives::{JsonLimits, WrappedJsonValue},
+};
 use shared_macros::{authn, authz_role};
 use utoipa::ToSchema;
 
@@ -48,6 +51,7 @@ pub async fn encrypt_resource_server_configuration(
         &params.inner.credential_controller_type_id,
     )?;
     let resource_server_configuration = params.inner.inner.value;
+    resource_server_configuration.check_limits(&JsonLimits::default())?;
 
     let encrypted_resource_server_configuration = credential_controller
         .encrypt_resource_server_configuration(&encryption_service, resource_server_configuration)
@@ -79,6 +83,7 @@ pub async fn encrypt_user_credential_configuration(
         &params.inner.credential_controller_type_id,
     )?;
     let user_credential_configuration = params.inner.inner.value;
+    user_credential_configuration.check_limits(&JsonLimits::default())?;
 
     let encrypted_user_credential_configuration = credential_controller
         .encrypt_user_credential_configuration(&encryption_service, user_credential_configuration)
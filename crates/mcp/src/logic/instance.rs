@@ -5,8 +5,8 @@ use serde::{Deserialize, Serialize};
 use shared::{
     error::CommonError,
     primitives::{
-        PaginatedResponse, PaginationRequest, WrappedChronoDateTime, WrappedJsonValue,
-        WrappedUuidV4,
+        PaginatedResponse, PaginationOrder, PaginationRequest, WrappedChronoDateTime,
+        WrappedJsonValue, WrappedUuidV4,
     },
 };
 use shared_macros::{authn, authz_role};
@@ -23,6 +23,7 @@ use crate::{
     logic::{
         FunctionControllerLike, InvokeResult, OnConfigChangeEvt, OnConfigChangeTx,
         ProviderControllerLike,
+        agent_allowlist::AgentAllowlists,
         controller::{
             FunctionControllerSerialized, PROVIDER_REGISTRY, ProviderControllerSerialized,
             ProviderCredentialControllerSerialized, WithCredentialControllerTypeId,
@@ -300,6 +301,10 @@ pub async fn list_provider_instances_internal(
     Ok(PaginatedResponse {
         items: enriched_items?,
         next_page_token: provider_instances_with_data.next_page_token,
+        prev_page_token: provider_instances_with_data.prev_page_token,
+        total_count: provider_instances_with_data.total_count,
+        page_size: provider_instances_with_data.page_size,
+        order: provider_instances_with_data.order,
     })
 }
 
@@ -376,6 +381,7 @@ pub async fn get_function_instances_internal(
     let mut pagination = PaginationRequest {
         page_size: 1000,
         next_page_token: None,
+        ..Default::default()
     };
 
     loop {
@@ -958,8 +964,12 @@ pub async fn list_provider_instances_grouped_by_function(
     };
 
     // Get the paginated slice
-    let page_size = params.page_size as usize;
-    let end_offset = std::cmp::min(offset + page_size, function_configs.len());
+    let page_size = PaginationRequest {
+        page_size: params.page_size,
+        ..Default::default()
+    }
+    .resolved_page_size();
+    let end_offset = std::cmp::min(offset + page_size as usize, function_configs.len());
     let paginated_configs = &function_configs[offset..end_offset];
 
     // Extract function_controller_type_ids from the paginated slice
@@ -1020,6 +1030,10 @@ pub async fn list_provider_instances_grouped_by_function(
     Ok(PaginatedResponse {
         items,
         next_page_token,
+        prev_page_token: None,
+        total_count: Some(function_configs.len() as u64),
+        page_size,
+        order: PaginationOrder::NameAscending,
     })
 }
 
@@ -1154,15 +1168,27 @@ pub type InvokeFunctionParams =
     WithProviderInstanceId<WithFunctionInstanceId<InvokeFunctionParamsInner>>;
 pub type InvokeFunctionResponse = InvokeResult;
 
-/// Invoke a function on a provider instance
+/// Invoke a function on a provider instance.
+/// Calls made by an agent (see [`AgentAllowlists::resolve_calling_agent`]) must be in that
+/// agent's allowlist.
 #[authz_role(Admin, Maintainer, Agent, permission = "function:invoke")]
 #[authn]
 pub async fn invoke_function(
     repo: &crate::repository::Repository,
     encryption_service: &CryptoCache,
+    agent_allowlists: &AgentAllowlists,
+    task_key: Option<String>,
     params: InvokeFunctionParams,
 ) -> Result<InvokeFunctionResponse, CommonError> {
-    invoke_function_internal(repo, encryption_service, params).await
+    let agent_id = agent_allowlists.resolve_calling_agent(Some(&_identity), task_key.as_deref())?;
+    invoke_function_internal(
+        repo,
+        encryption_service,
+        agent_allowlists,
+        agent_id.as_deref(),
+        params,
+    )
+    .await
 }
 
 /// Internal function to invoke a function (no auth check).
@@ -1170,6 +1196,8 @@ pub async fn invoke_function(
 pub(crate) async fn invoke_function_internal(
     repo: &crate::repository::Repository,
     encryption_service: &CryptoCache,
+    agent_allowlists: &AgentAllowlists,
+    agent_id: Option<&str>,
     params: InvokeFunctionParams,
 ) -> Result<InvokeFunctionResponse, CommonError> {
     trace!(
@@ -1185,6 +1213,16 @@ pub(crate) async fn invoke_function_internal(
             "Provider instance not found"
         )))?;
 
+    if let Some(agent_id) = agent_id {
+        agent_allowlists.check(
+            agent_id,
+            &provider_instance
+                .provider_instance
+                .provider_controller_type_id,
+            &params.inner.function_controller_type_id,
+        )?;
+    }
+
     let function_instance_with_credentials = repo
         .get_function_instance_with_credentials(
             &params.inner.function_controller_type_id,
@@ -1322,6 +1360,7 @@ mod tests {
             let pagination = PaginationRequest {
                 page_size: 10,
                 next_page_token: None,
+                ..Default::default()
             };
 
             let result = list_provider_instances_internal(
@@ -1356,6 +1395,7 @@ mod tests {
             let pagination = PaginationRequest {
                 page_size: 10,
                 next_page_token: None,
+                ..Default::default()
             };
 
             let result = list_function_instances_internal(
@@ -14,9 +14,9 @@ use shared::{
 
 use crate::{
     logic::{
-        FunctionControllerLike, InvokeFunctionParams, InvokeFunctionParamsInner, InvokeResult,
-        PROVIDER_REGISTRY, ProviderControllerLike, WithFunctionInstanceId,
-        invoke_function_internal,
+        AGENT_TASK_KEY_HEADER, AgentAllowlists, FunctionControllerLike, InvokeFunctionParams,
+        InvokeFunctionParamsInner, InvokeResult, PROVIDER_REGISTRY, ProviderControllerLike,
+        WithFunctionInstanceId, invoke_function_internal,
     },
     repository::{ProviderRepositoryLike, Repository},
 };
@@ -65,9 +65,21 @@ fn extract_mcp_server_instance_id(extensions: &Extensions) -> Result<String, rmc
     ))
 }
 
+/// Extracts the agent task key header from the request context, see [`AGENT_TASK_KEY_HEADER`]
+fn extract_agent_task_key(extensions: &Extensions) -> Option<String> {
+    extensions
+        .get::<Parts>()?
+        .headers
+        .get(AGENT_TASK_KEY_HEADER)?
+        .to_str()
+        .ok()
+        .map(str::to_string)
+}
+
 pub struct McpServerService {
     pub repository: Repository,
     pub encryption_service: CryptoCache,
+    pub agent_allowlists: AgentAllowlists,
 }
 
 impl ServerHandler for McpServerService {
@@ -87,6 +99,7 @@ impl ServerHandler for McpServerService {
             let pagination = PaginationRequest {
                 page_size: 1000,
                 next_page_token: next_page_token.clone(),
+                ..Default::default()
             };
 
             let result = self
@@ -206,10 +219,18 @@ impl ServerHandler for McpServerService {
                 )
             })?;
 
+        // Tool calls made from an agent task are subject to that agent's allowlist
+        let task_key = extract_agent_task_key(&context.extensions);
+        let agent_id = self
+            .agent_allowlists
+            .resolve_calling_agent(None, task_key.as_deref())?;
+
         // Now we have the function_controller_type_id and provider_instance_id to invoke the function
         let function_instance = invoke_function_internal(
             &self.repository,
             &self.encryption_service,
+            &self.agent_allowlists,
+            agent_id.as_deref(),
             InvokeFunctionParams {
                 provider_instance_id: mcp_function.provider_instance_id.clone(),
                 inner: WithFunctionInstanceId {
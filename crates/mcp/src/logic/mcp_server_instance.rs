@@ -309,6 +309,7 @@ pub async fn list_mcp_server_instances_internal<R: ProviderRepositoryLike>(
     let pagination = PaginationRequest {
         page_size: params.page_size,
         next_page_token: params.next_page_token,
+        ..Default::default()
     };
 
     let result = repository.list_mcp_server_instances(&pagination).await?;
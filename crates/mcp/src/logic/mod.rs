@@ -1,3 +1,4 @@
+pub mod agent_allowlist;
 pub mod controller;
 pub mod credential;
 pub mod credential_encryption;
@@ -81,6 +82,7 @@ impl libsql::FromValue for Metadata {
 }
 
 // Re-export commonly used types and functions
+pub use agent_allowlist::*;
 pub use controller::*;
 pub use credential::*;
 pub use credential_encryption::*;
@@ -159,6 +161,10 @@ pub trait ProviderCredentialControllerLike: Send + Sync {
     ) -> Result<(Box<dyn UserCredentialLike>, Metadata), CommonError>;
 }
 
+/// How long before a credential expires the rotation scheduler refreshes it, unless the provider
+/// controller overrides [`ProviderControllerLike::refresh_lead_time`]
+pub const DEFAULT_REFRESH_LEAD_TIME: std::time::Duration = std::time::Duration::from_secs(5 * 60);
+
 #[async_trait]
 pub trait ProviderControllerLike: Send + Sync {
     fn type_id(&self) -> String;
@@ -168,6 +174,11 @@ pub trait ProviderControllerLike: Send + Sync {
     fn functions(&self) -> Vec<Arc<dyn FunctionControllerLike>>;
     fn credential_controllers(&self) -> Vec<Arc<dyn ProviderCredentialControllerLike>>;
     fn metadata(&self) -> Metadata;
+    /// How long before expiry the provider's rotateable credentials are refreshed. Providers
+    /// with short lived tokens can override this.
+    fn refresh_lead_time(&self) -> std::time::Duration {
+        DEFAULT_REFRESH_LEAD_TIME
+    }
 }
 
 pub trait ProviderInstanceLike {
@@ -221,6 +232,10 @@ pub trait RotateableControllerUserCredentialLike {
 #[derive(Serialize, Deserialize, Clone, Debug, ToSchema)]
 pub struct InvokeError {
     pub message: String,
+    /// Classifies the failure, e.g. `rate_limited` or `retryable`, when the function reported it
+    pub code: Option<String>,
+    /// Suggested delay before retrying a `rate_limited` or `retryable` failure
+    pub retry_after_ms: Option<u64>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, ToSchema)]
@@ -238,6 +253,17 @@ pub trait FunctionControllerLike: Send + Sync {
     fn parameters(&self) -> WrappedSchema;
     fn output(&self) -> WrappedSchema;
     fn categories(&self) -> Vec<String>;
+    /// Deprecated functions remain invokable but are flagged in listings so clients can warn.
+    fn deprecated(&self) -> bool {
+        false
+    }
+    fn deprecation_message(&self) -> Option<String> {
+        None
+    }
+    /// Example invocations shown in function listings and generated client docs
+    fn examples(&self) -> Vec<FunctionExample> {
+        vec![]
+    }
     async fn invoke(
         &self,
         crypto_service: &DecryptionService,
@@ -236,6 +236,8 @@ lorem ipsum dolor sit amet, consectetur adipiscing elit. Sed do eiusmod tempor i
                 .unwrap_or_else(|_| "Unknown error".to_string());
             return Ok(InvokeResult::Error(InvokeError {
                 message: error_text,
+                code: None,
+                retry_after_ms: None,
             }));
         }
 
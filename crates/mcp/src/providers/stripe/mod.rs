@@ -137,6 +137,8 @@ impl FunctionControllerLike for ProcessRefundFunctionController {
                 .unwrap_or_else(|_| "Unknown error".to_string());
             return Ok(InvokeResult::Error(InvokeError {
                 message: error_text,
+                code: None,
+                retry_after_ms: None,
             }));
         }
 
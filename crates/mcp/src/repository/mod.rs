@@ -113,6 +113,16 @@ impl From<ProviderInstanceSerialized> for CreateProviderInstance {
     }
 }
 
+// Repository parameter structs for partially updating provider instances.
+// Fields left as `None` are not touched.
+#[derive(Debug, Default)]
+pub struct PatchProviderInstance {
+    pub display_name: Option<String>,
+    pub status: Option<String>,
+    /// `Some(None)` clears the return address
+    pub return_on_successful_brokering: Option<Option<crate::logic::ReturnAddress>>,
+}
+
 // Repository parameter structs for function instances
 #[derive(Debug)]
 pub struct CreateFunctionInstance {
@@ -286,6 +296,13 @@ pub trait ProviderRepositoryLike {
         display_name: &str,
     ) -> Result<(), CommonError>;
 
+    /// Update only the provided columns of a provider instance, always bumping `updated_at`
+    async fn patch_provider_instance(
+        &self,
+        id: &str,
+        params: &PatchProviderInstance,
+    ) -> Result<(), CommonError>;
+
     async fn update_provider_instance_after_brokering(
         &self,
         id: &str,
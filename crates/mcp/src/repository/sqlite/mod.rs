@@ -22,15 +22,16 @@ use crate::logic::mcp_server_instance::{
 use crate::repository::{
     CreateBrokerState, CreateFunctionInstance, CreateMcpServerInstance,
     CreateMcpServerInstanceFunction, CreateProviderInstance, CreateResourceServerCredential,
-    CreateUserCredential, ProviderRepositoryLike, UpdateMcpServerInstanceFunction,
+    CreateUserCredential, PatchProviderInstance, ProviderRepositoryLike,
+    UpdateMcpServerInstanceFunction,
 };
 use anyhow::Context;
 use shared::primitives::{WrappedChronoDateTime, WrappedJsonValue};
 use shared::{
     error::CommonError,
     primitives::{
-        PaginatedResponse, PaginationRequest, SqlMigrationLoader, WrappedUuidV4,
-        decode_pagination_token,
+        PaginatedResponse, PaginationDirection, PaginationRequest, SqlMigrationLoader,
+        WrappedUuidV4, decode_pagination_token, pagination_defaults,
     },
 };
 use shared_macros::load_atlas_sql_migrations;
@@ -47,6 +48,56 @@ impl Repository {
     }
 }
 
+/// Splits a `(created_at, id)` cursor token. The id is everything after the timestamp, so ids
+/// containing the token separator survive the round trip. Tokens issued before the id was added
+/// only carry the timestamp.
+fn decode_created_at_cursor(
+    token: &str,
+) -> Result<(WrappedChronoDateTime, Option<String>), CommonError> {
+    let decoded_parts = decode_pagination_token(token).map_err(|e| CommonError::Repository {
+        msg: format!("Invalid pagination token: {e}"),
+        source: Some(e.into()),
+    })?;
+    let (created_at, id_parts) =
+        decoded_parts
+            .split_first()
+            .ok_or_else(|| CommonError::InvalidRequest {
+                msg: "Empty pagination token".to_string(),
+                source: None,
+            })?;
+    let created_at = WrappedChronoDateTime::try_from(created_at.as_str()).map_err(|e| {
+        CommonError::Repository {
+            msg: format!("Invalid datetime in pagination token: {e}"),
+            source: Some(e.into()),
+        }
+    })?;
+    let id = (!id_parts.is_empty()).then(|| id_parts.join("__"));
+    Ok((created_at, id))
+}
+
+/// Function instances have no single id; their cursor id is the JSON-encoded
+/// `[provider_instance_id, function_controller_type_id]` key
+fn function_instance_cursor_id(item: &FunctionInstanceSerialized) -> String {
+    serde_json::json!([item.provider_instance_id, item.function_controller_type_id]).to_string()
+}
+
+fn decode_function_instance_cursor_id(
+    id: Option<&str>,
+) -> Result<(Option<String>, Option<String>), CommonError> {
+    let Some(id) = id else {
+        return Ok((None, None));
+    };
+    let (provider_instance_id, function_controller_type_id): (String, String) =
+        serde_json::from_str(id).map_err(|e| CommonError::InvalidRequest {
+            msg: format!("Invalid function instance in pagination token: {e}"),
+            source: None,
+        })?;
+    Ok((
+        Some(provider_instance_id),
+        Some(function_controller_type_id),
+    ))
+}
+
 impl ProviderRepositoryLike for Repository {
     async fn create_resource_server_credential(
         &self,
@@ -188,7 +239,7 @@ impl ProviderRepositoryLike for Repository {
 
         let sqlc_params = get_user_credentials_params {
             cursor: &cursor_datetime,
-            page_size: &pagination.page_size,
+            page_size: &pagination.resolved_page_size(),
         };
 
         let rows = get_user_credentials(&self.conn, sqlc_params)
@@ -238,7 +289,7 @@ impl ProviderRepositoryLike for Repository {
 
         let sqlc_params = get_resource_server_credentials_params {
             cursor: &cursor_datetime,
-            page_size: &pagination.page_size,
+            page_size: &pagination.resolved_page_size(),
         };
 
         let rows = get_resource_server_credentials(&self.conn, sqlc_params)
@@ -335,6 +386,50 @@ impl ProviderRepositoryLike for Repository {
         Ok(())
     }
 
+    async fn patch_provider_instance(
+        &self,
+        id: &str,
+        params: &PatchProviderInstance,
+    ) -> Result<(), CommonError> {
+        let mut assignments = Vec::new();
+        let mut values: Vec<libsql::Value> = Vec::new();
+
+        if let Some(display_name) = &params.display_name {
+            assignments.push("display_name = ?");
+            values.push(display_name.clone().into());
+        }
+        if let Some(status) = &params.status {
+            assignments.push("status = ?");
+            values.push(status.clone().into());
+        }
+        if let Some(return_on_successful_brokering) = &params.return_on_successful_brokering {
+            assignments.push("return_on_successful_brokering = ?");
+            values.push(match return_on_successful_brokering {
+                Some(v) => {
+                    WrappedJsonValue::new(serde_json::to_value(v).ok().unwrap_or_default()).into()
+                }
+                None => libsql::Value::Null,
+            });
+        }
+        assignments.push("updated_at = CURRENT_TIMESTAMP");
+        values.push(id.to_string().into());
+
+        let sql = format!(
+            "UPDATE provider_instance SET {} WHERE id = ?",
+            assignments.join(", ")
+        );
+
+        self.conn
+            .execute(&sql, values)
+            .await
+            .context("Failed to patch provider instance")
+            .map_err(|e| CommonError::Repository {
+                msg: e.to_string(),
+                source: Some(e),
+            })?;
+        Ok(())
+    }
+
     async fn update_provider_instance_after_brokering(
         &self,
         id: &str,
@@ -519,31 +614,19 @@ impl ProviderRepositoryLike for Repository {
         status: Option<&str>,
         provider_controller_type_id: Option<&str>,
     ) -> Result<PaginatedResponse<ProviderInstanceSerializedWithFunctions>, CommonError> {
-        // Decode the base64 token to get the datetime cursor
-        let cursor_datetime = if let Some(token) = &pagination.next_page_token {
-            let decoded_parts =
-                decode_pagination_token(token).map_err(|e| CommonError::Repository {
-                    msg: format!("Invalid pagination token: {e}"),
-                    source: Some(e.into()),
-                })?;
-            if decoded_parts.is_empty() {
-                None
-            } else {
-                Some(
-                    shared::primitives::WrappedChronoDateTime::try_from(decoded_parts[0].as_str())
-                        .map_err(|e| CommonError::Repository {
-                            msg: format!("Invalid datetime in pagination token: {e}"),
-                            source: Some(e.into()),
-                        })?,
-                )
+        // Decode the base64 token to get the (created_at, id) cursor
+        let (cursor_datetime, cursor_id) = match &pagination.next_page_token {
+            Some(token) => {
+                let (created_at, id) = decode_created_at_cursor(token)?;
+                (Some(created_at), id)
             }
-        } else {
-            None
+            None => (None, None),
         };
 
         let sqlc_params = get_provider_instances_params {
             cursor: &cursor_datetime,
-            page_size: &pagination.page_size,
+            cursor_id: &cursor_id,
+            page_size: &pagination.resolved_page_size(),
             status: &status.map(|status| status.to_string()),
             provider_controller_type_id: &provider_controller_type_id.map(|s| s.to_string()),
         };
@@ -561,11 +644,31 @@ impl ProviderRepositoryLike for Repository {
             .map(|row| row.try_into())
             .collect::<Result<Vec<_>, _>>()?;
 
-        Ok(PaginatedResponse::from_items_with_extra(
-            items,
-            pagination,
-            |item| vec![item.provider_instance.created_at.get_inner().to_rfc3339()],
-        ))
+        let total = count_provider_instances(
+            &self.conn,
+            count_provider_instances_params {
+                status: &status.map(|status| status.to_string()),
+                provider_controller_type_id: &provider_controller_type_id.map(|s| s.to_string()),
+            },
+        )
+        .await
+        .context("Failed to count provider instances")
+        .map_err(|e| CommonError::Repository {
+            msg: e.to_string(),
+            source: Some(e),
+        })?
+        .map(|row| row.total as u64)
+        .unwrap_or_default();
+
+        Ok(
+            PaginatedResponse::from_items_with_extra(items, pagination, |item| {
+                vec![
+                    item.provider_instance.created_at.get_inner().to_rfc3339(),
+                    item.provider_instance.id.clone(),
+                ]
+            })
+            .with_total_count(total),
+        )
     }
 
     async fn list_function_instances(
@@ -573,58 +676,101 @@ impl ProviderRepositoryLike for Repository {
         pagination: &PaginationRequest,
         provider_instance_id: Option<&str>,
     ) -> Result<PaginatedResponse<FunctionInstanceSerialized>, CommonError> {
-        // Decode the base64 token to get the datetime cursor
-        let cursor_datetime = if let Some(token) = &pagination.next_page_token {
-            let decoded_parts =
-                decode_pagination_token(token).map_err(|e| CommonError::Repository {
-                    msg: format!("Invalid pagination token: {e}"),
-                    source: Some(e.into()),
-                })?;
-            if decoded_parts.is_empty() {
-                None
-            } else {
-                Some(
-                    shared::primitives::WrappedChronoDateTime::try_from(decoded_parts[0].as_str())
-                        .map_err(|e| CommonError::Repository {
-                            msg: format!("Invalid datetime in pagination token: {e}"),
-                            source: Some(e.into()),
-                        })?,
-                )
+        // Decode the base64 token to get the (created_at, provider instance, function) cursor
+        let (cursor_datetime, cursor_id) = match &pagination.next_page_token {
+            Some(token) => {
+                let (created_at, id) = decode_created_at_cursor(token)?;
+                (Some(created_at), id)
             }
-        } else {
-            None
+            None => (None, None),
         };
+        let (cursor_provider_instance_id, cursor_function_controller_type_id) =
+            decode_function_instance_cursor_id(cursor_id.as_deref())?;
+
+        let provider_instance_id = provider_instance_id.map(|id| id.to_string());
+        let items: Vec<FunctionInstanceSerialized> = match pagination.direction {
+            PaginationDirection::Forward => {
+                let sqlc_params = get_function_instances_params {
+                    cursor: &cursor_datetime,
+                    cursor_provider_instance_id: &cursor_provider_instance_id,
+                    cursor_function_controller_type_id: &cursor_function_controller_type_id,
+                    page_size: &pagination.resolved_page_size(),
+                    provider_instance_id: &provider_instance_id,
+                };
 
-        let sqlc_params = get_function_instances_params {
-            cursor: &cursor_datetime,
-            page_size: &pagination.page_size,
-            provider_instance_id: &provider_instance_id.map(|id| id.to_string()),
-        };
+                get_function_instances(&self.conn, sqlc_params)
+                    .await
+                    .context("Failed to get function instances")
+                    .map_err(|e| CommonError::Repository {
+                        msg: e.to_string(),
+                        source: Some(e),
+                    })?
+                    .into_iter()
+                    .map(|row| FunctionInstanceSerialized {
+                        function_controller_type_id: row.function_controller_type_id,
+                        provider_controller_type_id: row.provider_controller_type_id,
+                        provider_instance_id: row.provider_instance_id,
+                        created_at: row.created_at,
+                        updated_at: row.updated_at,
+                    })
+                    .collect()
+            }
+            PaginationDirection::Backward => {
+                let cursor = cursor_datetime.ok_or_else(|| CommonError::InvalidRequest {
+                    msg: "Paging backward requires a prev_page_token".to_string(),
+                    source: None,
+                })?;
+                let sqlc_params = get_function_instances_backward_params {
+                    cursor: &cursor,
+                    cursor_provider_instance_id: &cursor_provider_instance_id,
+                    cursor_function_controller_type_id: &cursor_function_controller_type_id,
+                    page_size: &pagination.resolved_page_size(),
+                    provider_instance_id: &provider_instance_id,
+                };
 
-        let rows = get_function_instances(&self.conn, sqlc_params)
-            .await
-            .context("Failed to get function instances")
-            .map_err(|e| CommonError::Repository {
-                msg: e.to_string(),
-                source: Some(e),
-            })?;
+                get_function_instances_backward(&self.conn, sqlc_params)
+                    .await
+                    .context("Failed to get function instances")
+                    .map_err(|e| CommonError::Repository {
+                        msg: e.to_string(),
+                        source: Some(e),
+                    })?
+                    .into_iter()
+                    .map(|row| FunctionInstanceSerialized {
+                        function_controller_type_id: row.function_controller_type_id,
+                        provider_controller_type_id: row.provider_controller_type_id,
+                        provider_instance_id: row.provider_instance_id,
+                        created_at: row.created_at,
+                        updated_at: row.updated_at,
+                    })
+                    .collect()
+            }
+        };
 
-        let items: Vec<FunctionInstanceSerialized> = rows
-            .into_iter()
-            .map(|row| FunctionInstanceSerialized {
-                function_controller_type_id: row.function_controller_type_id,
-                provider_controller_type_id: row.provider_controller_type_id,
-                provider_instance_id: row.provider_instance_id,
-                created_at: row.created_at,
-                updated_at: row.updated_at,
+        let total = count_function_instances(
+            &self.conn,
+            count_function_instances_params {
+                provider_instance_id: &provider_instance_id,
+            },
+        )
+        .await
+        .context("Failed to count function instances")
+        .map_err(|e| CommonError::Repository {
+            msg: e.to_string(),
+            source: Some(e),
+        })?
+        .map(|row| row.total as u64)
+        .unwrap_or_default();
+
+        Ok(
+            PaginatedResponse::from_items_with_extra_bidirectional(items, pagination, |item| {
+                vec![
+                    item.created_at.get_inner().to_rfc3339(),
+                    function_instance_cursor_id(item),
+                ]
             })
-            .collect();
-
-        Ok(PaginatedResponse::from_items_with_extra(
-            items,
-            pagination,
-            |item| vec![item.created_at.get_inner().to_rfc3339()],
-        ))
+            .with_total_count(total),
+        )
     }
 
     async fn get_provider_instances_grouped_by_function_controller_type_id(
@@ -762,7 +908,7 @@ impl ProviderRepositoryLike for Repository {
                 cursor: &cursor_datetime,
                 status: &status.map(|s| s.to_string()),
                 rotation_window_end: &rotation_window_end.copied(),
-                page_size: &pagination.page_size,
+                page_size: &pagination.resolved_page_size(),
             };
 
         let rows = get_provider_instances_with_credentials(&self.conn, params)
@@ -779,9 +925,10 @@ impl ProviderRepositoryLike for Repository {
             .collect::<Result<Vec<_>, _>>()?;
 
         // Check if we have more items than requested
-        let has_more = items.len() > pagination.page_size as usize;
+        let page_size = pagination.resolved_page_size();
+        let has_more = items.len() as i64 > page_size;
         let items = if has_more {
-            items[..pagination.page_size as usize].to_vec()
+            items[..page_size as usize].to_vec()
         } else {
             items
         };
@@ -797,6 +944,10 @@ impl ProviderRepositoryLike for Repository {
         Ok(PaginatedResponse {
             items,
             next_page_token,
+            prev_page_token: None,
+            total_count: None,
+            page_size,
+            order: pagination_defaults().order,
         })
     }
 
@@ -900,7 +1051,7 @@ impl ProviderRepositoryLike for Repository {
 
         let sqlc_params = list_mcp_server_instances_params {
             cursor: &cursor_datetime,
-            page_size: &pagination.page_size,
+            page_size: &pagination.resolved_page_size(),
         };
 
         let rows = list_mcp_server_instances(&self.conn, sqlc_params)
@@ -1073,7 +1224,7 @@ impl ProviderRepositoryLike for Repository {
         let sqlc_params = list_mcp_server_instance_functions_params {
             mcp_server_instance_id: &mcp_server_instance_id_string,
             cursor: &cursor_datetime,
-            page_size: &pagination.page_size,
+            page_size: &pagination.resolved_page_size(),
         };
 
         let rows = list_mcp_server_instance_functions(&self.conn, sqlc_params)
@@ -1089,9 +1240,10 @@ impl ProviderRepositoryLike for Repository {
             .map(|row| row.try_into())
             .collect::<Result<Vec<_>, _>>()?;
 
-        let has_more = items.len() > pagination.page_size as usize;
+        let page_size = pagination.resolved_page_size();
+        let has_more = items.len() as i64 > page_size;
         let items = if has_more {
-            items[..pagination.page_size as usize].to_vec()
+            items[..page_size as usize].to_vec()
         } else {
             items
         };
@@ -1105,6 +1257,10 @@ impl ProviderRepositoryLike for Repository {
         Ok(PaginatedResponse {
             items,
             next_page_token,
+            prev_page_token: None,
+            total_count: None,
+            page_size,
+            order: pagination_defaults().order,
         })
     }
 }
@@ -1230,14 +1386,14 @@ mod tests {
     mod unit {
         use super::super::*;
         use crate::logic::{
-            Metadata, ProviderInstanceSerialized,
+            Metadata, ProviderInstanceSerialized, ReturnAddress,
             credential::{BrokerAction, BrokerActionRedirect},
-            instance::FunctionInstanceSerialized,
+            instance::{FunctionInstanceSerialized, ReturnAddressUrl},
         };
         use crate::repository::{
             BrokerState, CreateBrokerState, CreateFunctionInstance, CreateProviderInstance,
-            CreateResourceServerCredential, CreateUserCredential, ProviderRepositoryLike,
-            ResourceServerCredentialSerialized, UserCredentialSerialized,
+            CreateResourceServerCredential, CreateUserCredential, PatchProviderInstance,
+            ProviderRepositoryLike, ResourceServerCredentialSerialized, UserCredentialSerialized,
         };
         use shared::primitives::{
             SqlMigrationLoader, WrappedChronoDateTime, WrappedJsonValue, WrappedUuidV4,
@@ -1504,6 +1660,129 @@ mod tests {
             );
         }
 
+        #[tokio::test]
+        async fn test_patch_provider_instance_preserves_untouched_fields() {
+            let (_db, conn) = setup_in_memory_database(vec![Repository::load_sql_migrations()])
+                .await
+                .unwrap();
+            let repo = Repository::new(conn);
+
+            let now = WrappedChronoDateTime::now();
+            let resource_server_cred = ResourceServerCredentialSerialized {
+                id: WrappedUuidV4::new(),
+                type_id: "resource_server_no_auth".to_string(),
+                metadata: Metadata::new(),
+                value: WrappedJsonValue::new(serde_json::json!({})),
+                created_at: now,
+                updated_at: now,
+                next_rotation_time: None,
+                dek_alias: create_test_dek_alias(),
+            };
+            repo.create_resource_server_credential(&CreateResourceServerCredential::from(
+                resource_server_cred.clone(),
+            ))
+            .await
+            .unwrap();
+
+            let provider_instance = ProviderInstanceSerialized {
+                id: uuid::Uuid::new_v4().to_string(),
+                display_name: "Original Name".to_string(),
+                resource_server_credential_id: resource_server_cred.id.clone(),
+                user_credential_id: None,
+                created_at: now,
+                updated_at: now,
+                provider_controller_type_id: "google_mail".to_string(),
+                credential_controller_type_id: "no_auth".to_string(),
+                status: "brokering_in_progress".to_string(),
+                return_on_successful_brokering: None,
+            };
+            repo.create_provider_instance(&CreateProviderInstance::from(provider_instance.clone()))
+                .await
+                .unwrap();
+            let get = |id: String| {
+                let repo = repo.clone();
+                async move {
+                    repo.get_provider_instance_by_id(&id)
+                        .await
+                        .unwrap()
+                        .unwrap()
+                        .provider_instance
+                }
+            };
+            let original_updated_at = get(provider_instance.id.clone()).await.updated_at;
+
+            // Sleep 1 second to ensure different timestamp (SQLite CURRENT_TIMESTAMP has second precision)
+            tokio::time::sleep(tokio::time::Duration::from_secs(1)).await;
+
+            // Patch only the status
+            repo.patch_provider_instance(
+                &provider_instance.id,
+                &PatchProviderInstance {
+                    status: Some("active".to_string()),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+            let patched = get(provider_instance.id.clone()).await;
+            assert_eq!(patched.status, "active");
+            assert_eq!(patched.display_name, "Original Name");
+            assert!(patched.return_on_successful_brokering.is_none());
+            assert!(patched.updated_at.get_inner() > original_updated_at.get_inner());
+
+            // Patch only the return address
+            repo.patch_provider_instance(
+                &provider_instance.id,
+                &PatchProviderInstance {
+                    return_on_successful_brokering: Some(Some(ReturnAddress::Url(
+                        ReturnAddressUrl {
+                            url: "https://example.com/done".to_string(),
+                        },
+                    ))),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+            let patched = get(provider_instance.id.clone()).await;
+            assert!(matches!(
+                patched.return_on_successful_brokering,
+                Some(ReturnAddress::Url(ReturnAddressUrl { ref url })) if url == "https://example.com/done"
+            ));
+            assert_eq!(patched.status, "active");
+            assert_eq!(patched.display_name, "Original Name");
+
+            // Patch only the display name
+            repo.patch_provider_instance(
+                &provider_instance.id,
+                &PatchProviderInstance {
+                    display_name: Some("Patched Name".to_string()),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+            let patched = get(provider_instance.id.clone()).await;
+            assert_eq!(patched.display_name, "Patched Name");
+            assert_eq!(patched.status, "active");
+            assert!(patched.return_on_successful_brokering.is_some());
+
+            // Clearing the return address leaves the other columns alone
+            repo.patch_provider_instance(
+                &provider_instance.id,
+                &PatchProviderInstance {
+                    return_on_successful_brokering: Some(None),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+            let patched = get(provider_instance.id.clone()).await;
+            assert!(patched.return_on_successful_brokering.is_none());
+            assert_eq!(patched.display_name, "Patched Name");
+            assert_eq!(patched.status, "active");
+        }
+
         #[tokio::test]
         async fn test_delete_provider_instance() {
             let (_db, conn) = setup_in_memory_database(vec![Repository::load_sql_migrations()])
@@ -2082,6 +2361,7 @@ mod tests {
             let pagination = PaginationRequest {
                 page_size: 10,
                 next_page_token: None,
+                ..Default::default()
             };
 
             let result = repo
@@ -2375,6 +2655,7 @@ mod tests {
             let pagination = PaginationRequest {
                 page_size: 10,
                 next_page_token: None,
+                ..Default::default()
             };
 
             // Test with status=None (should return all)
@@ -2383,6 +2664,7 @@ mod tests {
                 .await
                 .unwrap();
             assert_eq!(result_all.items.len(), 2);
+            assert_eq!(result_all.total_count, Some(2));
 
             // Test with status="active" (should return only active)
             let result_active = repo
@@ -2391,6 +2673,7 @@ mod tests {
                 .unwrap();
             assert_eq!(result_active.items.len(), 1);
             assert_eq!(result_active.items[0].provider_instance.status, "active");
+            assert_eq!(result_active.total_count, Some(1));
 
             // Test with status="disabled" (should return only disabled)
             let result_disabled = repo
@@ -2513,6 +2796,7 @@ mod tests {
             let pagination = PaginationRequest {
                 page_size: 10,
                 next_page_token: None,
+                ..Default::default()
             };
 
             // Test with provider_instance_id=None (should return all)
@@ -2521,6 +2805,22 @@ mod tests {
                 .await
                 .unwrap();
             assert_eq!(result_all.items.len(), 3);
+            assert_eq!(result_all.total_count, Some(3));
+
+            // The total covers every page, not just the one returned
+            let first_page = repo
+                .list_function_instances(
+                    &PaginationRequest {
+                        page_size: 1,
+                        next_page_token: None,
+                        ..Default::default()
+                    },
+                    None,
+                )
+                .await
+                .unwrap();
+            assert_eq!(first_page.items.len(), 1);
+            assert_eq!(first_page.total_count, Some(3));
 
             // Test with provider_instance_id="pi-1" (should return only pi-1 functions)
             let result_pi1 = repo
@@ -2528,6 +2828,7 @@ mod tests {
                 .await
                 .unwrap();
             assert_eq!(result_pi1.items.len(), 2);
+            assert_eq!(result_pi1.total_count, Some(2));
             assert!(
                 result_pi1
                     .items
@@ -2544,6 +2845,239 @@ mod tests {
             assert_eq!(result_pi2.items[0].provider_instance_id, "pi-2");
         }
 
+        #[tokio::test]
+        async fn test_list_function_instances_pages_forward_then_backward() {
+            shared::setup_test!();
+
+            let (_db, conn) = setup_in_memory_database(vec![Repository::load_sql_migrations()])
+                .await
+                .unwrap();
+            let repo = Repository::new(conn);
+
+            let now = WrappedChronoDateTime::now();
+            let rsc_id = WrappedUuidV4::new();
+            repo.create_resource_server_credential(&CreateResourceServerCredential {
+                id: rsc_id.clone(),
+                type_id: "test_type".to_string(),
+                metadata: Metadata::new(),
+                value: WrappedJsonValue::new(serde_json::json!({"test": "value"})),
+                created_at: now,
+                updated_at: now,
+                next_rotation_time: None,
+                dek_alias: create_test_dek_alias(),
+            })
+            .await
+            .unwrap();
+            repo.create_provider_instance(&CreateProviderInstance {
+                id: "pi-1".to_string(),
+                display_name: "Provider 1".to_string(),
+                resource_server_credential_id: rsc_id,
+                user_credential_id: None,
+                created_at: now,
+                updated_at: now,
+                provider_controller_type_id: "test_provider".to_string(),
+                credential_controller_type_id: "test_credential".to_string(),
+                status: "active".to_string(),
+                return_on_successful_brokering: None,
+            })
+            .await
+            .unwrap();
+
+            // fn-0 is the newest, so it leads the first page
+            for i in 0..6 {
+                let created_at = WrappedChronoDateTime::new(
+                    chrono::Utc::now() - chrono::Duration::seconds(i as i64),
+                );
+                repo.create_function_instance(&CreateFunctionInstance {
+                    function_controller_type_id: format!("fn-{i}"),
+                    provider_controller_type_id: "test_provider".to_string(),
+                    provider_instance_id: "pi-1".to_string(),
+                    created_at,
+                    updated_at: created_at,
+                })
+                .await
+                .unwrap();
+            }
+
+            let ids = |page: &PaginatedResponse<FunctionInstanceSerialized>| {
+                page.items
+                    .iter()
+                    .map(|item| item.function_controller_type_id.clone())
+                    .collect::<Vec<_>>()
+            };
+            let list = |next_page_token: Option<String>, direction: PaginationDirection| {
+                let repo = &repo;
+                async move {
+                    repo.list_function_instances(
+                        &PaginationRequest {
+                            page_size: 2,
+                            next_page_token,
+                            direction,
+                        },
+                        None,
+                    )
+                    .await
+                    .unwrap()
+                }
+            };
+
+            let page_1 = list(None, PaginationDirection::Forward).await;
+            assert_eq!(ids(&page_1), vec!["fn-0", "fn-1"]);
+            assert!(page_1.prev_page_token.is_none());
+            let page_2 = list(page_1.next_page_token.clone(), PaginationDirection::Forward).await;
+            assert_eq!(ids(&page_2), vec!["fn-2", "fn-3"]);
+            let page_3 = list(page_2.next_page_token.clone(), PaginationDirection::Forward).await;
+            assert_eq!(ids(&page_3), vec!["fn-4", "fn-5"]);
+            assert!(page_3.next_page_token.is_none());
+
+            let back_to_2 = list(page_3.prev_page_token, PaginationDirection::Backward).await;
+            assert_eq!(ids(&back_to_2), ids(&page_2));
+            assert_eq!(back_to_2.next_page_token, page_2.next_page_token);
+            let back_to_1 = list(back_to_2.prev_page_token, PaginationDirection::Backward).await;
+            assert_eq!(ids(&back_to_1), ids(&page_1));
+            assert_eq!(back_to_1.next_page_token, page_1.next_page_token);
+            assert!(back_to_1.prev_page_token.is_none());
+
+            // Without a cursor there is nothing to page backward from
+            let result = repo
+                .list_function_instances(
+                    &PaginationRequest {
+                        page_size: 2,
+                        next_page_token: None,
+                        direction: PaginationDirection::Backward,
+                    },
+                    None,
+                )
+                .await;
+            assert!(matches!(result, Err(CommonError::InvalidRequest { .. })));
+        }
+
+        #[tokio::test]
+        async fn test_list_instances_with_equal_timestamps_walk_every_row_once() {
+            shared::setup_test!();
+
+            let (_db, conn) = setup_in_memory_database(vec![Repository::load_sql_migrations()])
+                .await
+                .unwrap();
+            let repo = Repository::new(conn);
+
+            // Bulk imports give every row the same created_at
+            let now = WrappedChronoDateTime::now();
+            let rsc_id = WrappedUuidV4::new();
+            repo.create_resource_server_credential(&CreateResourceServerCredential {
+                id: rsc_id.clone(),
+                type_id: "test_type".to_string(),
+                metadata: Metadata::new(),
+                value: WrappedJsonValue::new(serde_json::json!({"test": "value"})),
+                created_at: now,
+                updated_at: now,
+                next_rotation_time: None,
+                dek_alias: create_test_dek_alias(),
+            })
+            .await
+            .unwrap();
+            for i in 0..5 {
+                repo.create_provider_instance(&CreateProviderInstance {
+                    // Ids containing the token separator must survive the cursor round trip
+                    id: format!("pi__{i}"),
+                    display_name: format!("Provider {i}"),
+                    resource_server_credential_id: rsc_id.clone(),
+                    user_credential_id: None,
+                    created_at: now,
+                    updated_at: now,
+                    provider_controller_type_id: "test_provider".to_string(),
+                    credential_controller_type_id: "test_credential".to_string(),
+                    status: "active".to_string(),
+                    return_on_successful_brokering: None,
+                })
+                .await
+                .unwrap();
+                repo.create_function_instance(&CreateFunctionInstance {
+                    function_controller_type_id: format!("fn_{i}"),
+                    provider_controller_type_id: "test_provider".to_string(),
+                    provider_instance_id: "pi__0".to_string(),
+                    created_at: now,
+                    updated_at: now,
+                })
+                .await
+                .unwrap();
+            }
+
+            let mut provider_instance_ids = Vec::new();
+            let mut next_page_token = None;
+            loop {
+                let page = repo
+                    .list_provider_instances(
+                        &PaginationRequest {
+                            page_size: 2,
+                            next_page_token,
+                            direction: PaginationDirection::Forward,
+                        },
+                        None,
+                        None,
+                    )
+                    .await
+                    .unwrap();
+                provider_instance_ids
+                    .extend(page.items.into_iter().map(|item| item.provider_instance.id));
+                next_page_token = page.next_page_token;
+                if next_page_token.is_none() {
+                    break;
+                }
+            }
+            assert_eq!(
+                provider_instance_ids,
+                vec!["pi__4", "pi__3", "pi__2", "pi__1", "pi__0"]
+            );
+
+            let list = |next_page_token: Option<String>, direction: PaginationDirection| {
+                let repo = &repo;
+                async move {
+                    repo.list_function_instances(
+                        &PaginationRequest {
+                            page_size: 2,
+                            next_page_token,
+                            direction,
+                        },
+                        None,
+                    )
+                    .await
+                    .unwrap()
+                }
+            };
+            let mut function_ids = Vec::new();
+            let mut pages = Vec::new();
+            let mut next_page_token = None;
+            loop {
+                let page = list(next_page_token, PaginationDirection::Forward).await;
+                function_ids.extend(
+                    page.items
+                        .iter()
+                        .map(|item| item.function_controller_type_id.clone()),
+                );
+                next_page_token = page.next_page_token.clone();
+                pages.push(page);
+                if next_page_token.is_none() {
+                    break;
+                }
+            }
+            assert_eq!(function_ids, vec!["fn_4", "fn_3", "fn_2", "fn_1", "fn_0"]);
+
+            // Paging back from the last page returns the same rows as on the way forward
+            let back = list(
+                pages[2].prev_page_token.clone(),
+                PaginationDirection::Backward,
+            )
+            .await;
+            assert_eq!(
+                back.items
+                    .iter()
+                    .map(|item| item.function_controller_type_id.clone())
+                    .collect::<Vec<_>>(),
+                vec!["fn_2", "fn_1"]
+            );
+        }
+
         #[tokio::test]
         async fn test_list_provider_instances_filter_by_provider_controller_type_id() {
             shared::setup_test!();
@@ -2650,6 +3184,7 @@ mod tests {
             let pagination = PaginationRequest {
                 page_size: 10,
                 next_page_token: None,
+                ..Default::default()
             };
 
             // Test with provider_controller_type_id=None (should return all 3)
@@ -3327,6 +3862,7 @@ mod tests {
             let pagination = shared::primitives::PaginationRequest {
                 page_size: 10,
                 next_page_token: None,
+                ..Default::default()
             };
 
             let result = repo.list_mcp_server_instances(&pagination).await.unwrap();
@@ -3357,6 +3893,7 @@ mod tests {
             let pagination = shared::primitives::PaginationRequest {
                 page_size: 10,
                 next_page_token: None,
+                ..Default::default()
             };
 
             let result = repo.list_mcp_server_instances(&pagination).await.unwrap();
@@ -3389,6 +3926,7 @@ mod tests {
             let pagination = shared::primitives::PaginationRequest {
                 page_size: 2,
                 next_page_token: None,
+                ..Default::default()
             };
 
             let first_page = repo.list_mcp_server_instances(&pagination).await.unwrap();
@@ -3399,6 +3937,7 @@ mod tests {
             let pagination = shared::primitives::PaginationRequest {
                 page_size: 2,
                 next_page_token: first_page.next_page_token,
+                ..Default::default()
             };
 
             let second_page = repo.list_mcp_server_instances(&pagination).await.unwrap();
@@ -1449,6 +1449,9 @@ WHERE fi.function_controller_type_id = ? AND fi.provider_controller_type_id = ?
       pub cursor: &'a Option<
           shared::primitives::WrappedChronoDateTime
       >,
+      pub cursor_id: &'a Option<
+          String
+      >,
       pub status: &'a Option<
           String
       >,
@@ -1537,12 +1540,12 @@ WHERE fi.function_controller_type_id = ? AND fi.provider_controller_type_id = ?
         ), JSON('null')) AS TEXT
     ) AS user_credential
 FROM provider_instance pi
-WHERE (pi.created_at < ?1 OR ?1 IS NULL)
-  AND (CAST(pi.status = ?2 AS TEXT) OR ?2 IS NULL)
-  AND (CAST(pi.provider_controller_type_id = ?3 AS TEXT) OR ?3 IS NULL)
-ORDER BY pi.created_at DESC
-LIMIT CAST(?4 AS INTEGER) + 1"#).await?;
-      let mut rows = stmt.query(libsql::params![params.cursor.clone(),params.status.clone(),params.provider_controller_type_id.clone(),params.page_size.clone(),]).await?;
+WHERE ((pi.created_at, pi.id) < (?1, ?2) OR ?1 IS NULL)
+  AND (CAST(pi.status = ?3 AS TEXT) OR ?3 IS NULL)
+  AND (CAST(pi.provider_controller_type_id = ?4 AS TEXT) OR ?4 IS NULL)
+ORDER BY pi.created_at DESC, pi.id DESC
+LIMIT CAST(?5 AS INTEGER) + 1"#).await?;
+      let mut rows = stmt.query(libsql::params![params.cursor.clone(),params.cursor_id.clone(),params.status.clone(),params.provider_controller_type_id.clone(),params.page_size.clone(),]).await?;
       let mut mapped = vec![];
 
       while let Some(row) = rows.next().await? {
@@ -1565,10 +1568,50 @@ LIMIT CAST(?4 AS INTEGER) + 1"#).await?;
 
       Ok(mapped)
   }
+  pub struct count_provider_instances_params<'a> {
+      pub status: &'a Option<
+          String
+      >,
+      pub provider_controller_type_id: &'a Option<
+          String
+      >,
+  }
+    #[derive(Serialize, Deserialize, Debug)]
+
+  #[allow(non_camel_case_types)]
+  pub struct Row_count_provider_instances {
+      pub total:i64,
+  }
+  pub async fn count_provider_instances(
+      conn: &shared::libsql::Connection
+      ,params: count_provider_instances_params<'_>
+  ) -> Result<Option<Row_count_provider_instances>, libsql::Error> {
+      let mut stmt = conn.prepare(r#"SELECT CAST(COUNT(*) AS INTEGER) AS total
+FROM provider_instance pi
+WHERE (CAST(pi.status = ?1 AS TEXT) OR ?1 IS NULL)
+  AND (CAST(pi.provider_controller_type_id = ?2 AS TEXT) OR ?2 IS NULL)"#).await?;
+      let res = stmt.query_row(
+          libsql::params![params.status.clone(),params.provider_controller_type_id.clone(),],
+      ).await;
+
+      match res {
+          Ok(row) => Ok(Some(Row_count_provider_instances {
+                  total: row.get(0)?,
+              })),
+          Err(libsql::Error::QueryReturnedNoRows) => Ok(None),
+          Err(e) => Err(e),
+      }
+  }
   pub struct get_function_instances_params<'a> {
       pub cursor: &'a Option<
           shared::primitives::WrappedChronoDateTime
       >,
+      pub cursor_provider_instance_id: &'a Option<
+          String
+      >,
+      pub cursor_function_controller_type_id: &'a Option<
+          String
+      >,
       pub provider_instance_id: &'a Option<
           String
       >,
@@ -1592,11 +1635,11 @@ LIMIT CAST(?4 AS INTEGER) + 1"#).await?;
   ) -> Result<Vec<Row_get_function_instances>, libsql::Error> {
       let stmt = conn.prepare(r#"SELECT function_controller_type_id, provider_controller_type_id, provider_instance_id, created_at, updated_at
 FROM function_instance
-WHERE (created_at < ?1 OR ?1 IS NULL)
-  AND (CAST(provider_instance_id = ?2 AS TEXT) OR ?2 IS NULL)
-ORDER BY created_at DESC
-LIMIT CAST(?3 AS INTEGER) + 1"#).await?;
-      let mut rows = stmt.query(libsql::params![params.cursor.clone(),params.provider_instance_id.clone(),params.page_size.clone(),]).await?;
+WHERE ((created_at, provider_instance_id, function_controller_type_id) < (?1, ?2, ?3) OR ?1 IS NULL)
+  AND (CAST(provider_instance_id = ?4 AS TEXT) OR ?4 IS NULL)
+ORDER BY created_at DESC, provider_instance_id DESC, function_controller_type_id DESC
+LIMIT CAST(?5 AS INTEGER) + 1"#).await?;
+      let mut rows = stmt.query(libsql::params![params.cursor.clone(),params.cursor_provider_instance_id.clone(),params.cursor_function_controller_type_id.clone(),params.provider_instance_id.clone(),params.page_size.clone(),]).await?;
       let mut mapped = vec![];
 
       while let Some(row) = rows.next().await? {
@@ -1611,6 +1654,88 @@ LIMIT CAST(?3 AS INTEGER) + 1"#).await?;
 
       Ok(mapped)
   }
+  pub struct get_function_instances_backward_params<'a> {
+      pub cursor: &'a 
+          shared::primitives::WrappedChronoDateTime
+      ,
+      pub cursor_provider_instance_id: &'a Option<
+          String
+      >,
+      pub cursor_function_controller_type_id: &'a Option<
+          String
+      >,
+      pub provider_instance_id: &'a Option<
+          String
+      >,
+      pub page_size: &'a 
+          i64
+      ,
+  }
+    #[derive(Serialize, Deserialize, Debug)]
+
+  #[allow(non_camel_case_types)]
+  pub struct Row_get_function_instances_backward {
+      pub function_controller_type_id:String,
+      pub provider_controller_type_id:String,
+      pub provider_instance_id:String,
+      pub created_at:shared::primitives::WrappedChronoDateTime,
+      pub updated_at:shared::primitives::WrappedChronoDateTime,
+  }
+  pub async fn get_function_instances_backward(
+      conn: &shared::libsql::Connection
+      ,params: get_function_instances_backward_params<'_>
+  ) -> Result<Vec<Row_get_function_instances_backward>, libsql::Error> {
+      let stmt = conn.prepare(r#"SELECT function_controller_type_id, provider_controller_type_id, provider_instance_id, created_at, updated_at
+FROM function_instance
+WHERE (created_at, provider_instance_id, function_controller_type_id) > (?1, ?2, ?3)
+  AND (CAST(provider_instance_id = ?4 AS TEXT) OR ?4 IS NULL)
+ORDER BY created_at ASC, provider_instance_id ASC, function_controller_type_id ASC
+LIMIT CAST(?5 AS INTEGER) + 1"#).await?;
+      let mut rows = stmt.query(libsql::params![params.cursor.clone(),params.cursor_provider_instance_id.clone(),params.cursor_function_controller_type_id.clone(),params.provider_instance_id.clone(),params.page_size.clone(),]).await?;
+      let mut mapped = vec![];
+
+      while let Some(row) = rows.next().await? {
+          mapped.push(Row_get_function_instances_backward {
+              function_controller_type_id: row.get(0)?,
+              provider_controller_type_id: row.get(1)?,
+              provider_instance_id: row.get(2)?,
+              created_at: row.get(3)?,
+              updated_at: row.get(4)?,
+          });
+      }
+
+      Ok(mapped)
+  }
+  pub struct count_function_instances_params<'a> {
+      pub provider_instance_id: &'a Option<
+          String
+      >,
+  }
+    #[derive(Serialize, Deserialize, Debug)]
+
+  #[allow(non_camel_case_types)]
+  pub struct Row_count_function_instances {
+      pub total:i64,
+  }
+  pub async fn count_function_instances(
+      conn: &shared::libsql::Connection
+      ,params: count_function_instances_params<'_>
+  ) -> Result<Option<Row_count_function_instances>, libsql::Error> {
+      let mut stmt = conn.prepare(r#"SELECT CAST(COUNT(*) AS INTEGER) AS total
+FROM function_instance
+WHERE (CAST(provider_instance_id = ?1 AS TEXT) OR ?1 IS NULL)"#).await?;
+      let res = stmt.query_row(
+          libsql::params![params.provider_instance_id.clone(),],
+      ).await;
+
+      match res {
+          Ok(row) => Ok(Some(Row_count_function_instances {
+                  total: row.get(0)?,
+              })),
+          Err(libsql::Error::QueryReturnedNoRows) => Ok(None),
+          Err(e) => Err(e),
+      }
+  }
   pub struct get_provider_instances_grouped_by_function_controller_type_id_params<'a> {
       pub function_controller_type_ids: &'a 
           String
@@ -16,7 +16,9 @@ use crate::logic::{
     update_mcp_server_instance_function,
 };
 use axum::extract::{Json, Path, Query, State};
-use shared::adapters::openapi::{API_VERSION_TAG, JsonResponse};
+use shared::adapters::openapi::{
+    API_VERSION_TAG, ConditionalJsonResponse, JsonResponse, ListingValidators,
+};
 use shared::error::CommonError;
 
 // ============================================================================
@@ -215,12 +217,13 @@ pub async fn route_delete_mcp_server_instance(
     ),
     responses(
         (status = 200, description = "List MCP server instances", body = ListMcpServerInstancesResponse),
+        (status = 304, description = "Not Modified"),
         (status = 401, description = "Unauthorized", body = CommonError),
         (status = 403, description = "Forbidden", body = CommonError),
         (status = 500, description = "Internal Server Error", body = CommonError),
     ),
     summary = "List MCP server instances",
-    description = "List all MCP server instances with pagination",
+    description = "List all MCP server instances with pagination. Supports `If-None-Match` and `If-Modified-Since`, returning 304 when neither the servers nor their functions changed",
     operation_id = "list-mcp-server-instances",
     security(
         ("api_key" = []),
@@ -231,8 +234,9 @@ pub async fn route_list_mcp_server_instances(
     State(ctx): State<McpService>,
     headers: HeaderMap,
     Query(params): Query<ListMcpServerInstancesParams>,
-) -> JsonResponse<ListMcpServerInstancesResponse, CommonError> {
+) -> ConditionalJsonResponse<ListMcpServerInstancesResponse, CommonError> {
     trace!(page_size = params.page_size, "Listing MCP server instances");
+    let request_headers = headers.clone();
     let res =
         list_mcp_server_instances(ctx.auth_client().clone(), headers, ctx.repository(), params)
             .await;
@@ -240,7 +244,20 @@ pub async fn route_list_mcp_server_instances(
         success = res.is_ok(),
         "Listing MCP server instances completed"
     );
-    JsonResponse::from(res)
+    // Function mappings carry their own timestamps, so they count towards the listing's validators
+    ConditionalJsonResponse::new(&request_headers, res, |res| {
+        ListingValidators::new(
+            res,
+            res.items.iter().flat_map(|server| {
+                std::iter::once(*server.updated_at.get_inner()).chain(
+                    server
+                        .functions
+                        .iter()
+                        .map(|function| *function.updated_at.get_inner()),
+                )
+            }),
+        )
+    })
 }
 
 // ============================================================================
@@ -10,7 +10,7 @@ mod provider;
 use std::sync::Arc;
 
 use crate::logic::mcp::McpServerService;
-use crate::logic::{OnConfigChangeTx, process_credential_rotations_with_window};
+use crate::logic::{AgentAllowlists, OnConfigChangeTx, process_credential_rotations_with_window};
 use crate::repository::Repository;
 use encryption::logic::crypto_services::CryptoCache;
 use identity::logic::auth_client::AuthClient;
@@ -86,6 +86,8 @@ pub struct McpService {
     pub encryption_service: CryptoCache,
     pub mcp_service: StreamableHttpService<McpServerService, LocalSessionManager>,
     pub auth_client: Arc<AuthClient>,
+    /// Per-agent function allowlists, shared with the MCP protocol server
+    pub agent_allowlists: AgentAllowlists,
 }
 
 impl McpService {
@@ -95,6 +97,7 @@ impl McpService {
         encryption_service: CryptoCache,
         mcp_service: StreamableHttpService<McpServerService, LocalSessionManager>,
         auth_client: Arc<AuthClient>,
+        agent_allowlists: AgentAllowlists,
     ) -> Result<Self, CommonError> {
         // Run initial credential rotation check for expired and soon-to-expire credentials (30 min window)
         debug!("Running initial credential rotation check");
@@ -113,6 +116,7 @@ impl McpService {
             encryption_service,
             mcp_service,
             auth_client,
+            agent_allowlists,
         })
     }
 
@@ -136,4 +140,8 @@ impl McpService {
     pub fn auth_client(&self) -> &AuthClient {
         &self.auth_client
     }
+
+    pub fn agent_allowlists(&self) -> &AgentAllowlists {
+        &self.agent_allowlists
+    }
 }
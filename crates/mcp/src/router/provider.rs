@@ -5,13 +5,14 @@ use tracing::trace;
 
 use super::{API_VERSION_1, McpService, PATH_PREFIX, SERVICE_ROUTE_KEY};
 use crate::logic::{
-    BrokerAction, BrokerInput, CreateProviderInstanceParamsInner, CreateProviderInstanceResponse,
-    CreateResourceServerCredentialParamsInner, CreateResourceServerCredentialResponse,
-    CreateUserCredentialParamsInner, CreateUserCredentialResponse, DisableFunctionParamsInner,
-    DisableFunctionResponse, EnableFunctionParamsInner, EnableFunctionResponse,
-    EncryptCredentialConfigurationParamsInner, EncryptedCredentialConfigurationResponse,
-    GetProviderInstanceResponse, InvokeFunctionParamsInner, InvokeFunctionResponse,
-    ListAvailableProvidersResponse, ListFunctionInstancesParams, ListFunctionInstancesResponse,
+    AGENT_TASK_KEY_HEADER, BrokerAction, BrokerInput, CreateProviderInstanceParamsInner,
+    CreateProviderInstanceResponse, CreateResourceServerCredentialParamsInner,
+    CreateResourceServerCredentialResponse, CreateUserCredentialParamsInner,
+    CreateUserCredentialResponse, DisableFunctionParamsInner, DisableFunctionResponse,
+    EnableFunctionParamsInner, EnableFunctionResponse, EncryptCredentialConfigurationParamsInner,
+    EncryptedCredentialConfigurationResponse, GetProviderInstanceResponse,
+    InvokeFunctionParamsInner, InvokeFunctionResponse, ListAvailableProvidersResponse,
+    ListFunctionInstancesParams, ListFunctionInstancesResponse,
     ListProviderInstancesGroupedByFunctionParams, ListProviderInstancesGroupedByFunctionResponse,
     ListProviderInstancesParams, ListProviderInstancesResponse,
     ResumeUserCredentialBrokeringParams, StartUserCredentialBrokeringParamsInner,
@@ -30,9 +31,11 @@ use crate::repository::ProviderRepositoryLike;
 use axum::extract::{Json, Path, Query, State};
 use axum::response::IntoResponse;
 use serde::{Deserialize, Serialize};
-use shared::adapters::openapi::{API_VERSION_TAG, JsonResponse};
+use shared::adapters::openapi::{
+    API_VERSION_TAG, ConditionalJsonResponse, JsonResponse, ListingValidators,
+};
 use shared::error::CommonError;
-use shared::primitives::PaginationRequest;
+use shared::primitives::{PaginationDirection, PaginationRequest};
 use utoipa::openapi::OpenApi;
 use utoipa::{IntoParams, ToSchema};
 
@@ -854,11 +857,17 @@ pub async fn route_invoke_function(
         function_type = %function_controller_type_id,
         "Invoking function"
     );
+    let task_key = headers
+        .get(AGENT_TASK_KEY_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_string);
     let res = invoke_function(
         ctx.auth_client().clone(),
         headers,
         ctx.repository(),
         ctx.encryption_service(),
+        ctx.agent_allowlists(),
+        task_key,
         WithProviderInstanceId {
             provider_instance_id: provider_instance_id.clone(),
             inner: WithFunctionInstanceId {
@@ -923,6 +932,7 @@ pub async fn route_list_provider_instances(
             pagination: PaginationRequest {
                 page_size: query.page_size,
                 next_page_token: query.next_page_token,
+                ..Default::default()
             },
             status: query.status,
             provider_controller_type_id: query.provider_controller_type_id,
@@ -984,6 +994,10 @@ pub struct ListFunctionInstancesQuery {
     // TODO: utoipa doesnt support flattening yet https://github.com/juhaku/utoipa/pull/1426
     pub page_size: i64,
     pub next_page_token: Option<String>,
+    /// `backward` pages from a `prev_page_token` towards newer function instances
+    #[serde(default)]
+    #[param(inline)]
+    pub direction: PaginationDirection,
     pub provider_instance_id: Option<String>,
 }
 #[utoipa::path(
@@ -995,13 +1009,14 @@ pub struct ListFunctionInstancesQuery {
     ),
     responses(
         (status = 200, description = "List function instances", body = ListFunctionInstancesResponse),
+        (status = 304, description = "Not Modified"),
         (status = 400, description = "Bad Request", body = CommonError),
         (status = 401, description = "Unauthorized", body = CommonError),
         (status = 403, description = "Forbidden", body = CommonError),
         (status = 500, description = "Internal Server Error", body = CommonError),
     ),
     summary = "List function instances",
-    description = "List all function instances with optional filtering by provider instance",
+    description = "List all function instances with optional filtering by provider instance. Supports `If-None-Match` and `If-Modified-Since`, returning 304 when the listing is unchanged",
     operation_id = "list-function-instances",
     security(
         ("api_key" = []),
@@ -1012,12 +1027,13 @@ pub async fn route_list_function_instances(
     State(ctx): State<McpService>,
     headers: HeaderMap,
     Query(query): Query<ListFunctionInstancesQuery>,
-) -> JsonResponse<ListFunctionInstancesResponse, CommonError> {
+) -> ConditionalJsonResponse<ListFunctionInstancesResponse, CommonError> {
     trace!(
         page_size = query.page_size,
         provider_instance_id = ?query.provider_instance_id,
         "Listing function instances"
     );
+    let request_headers = headers.clone();
     let res = list_function_instances(
         ctx.auth_client().clone(),
         headers,
@@ -1026,6 +1042,7 @@ pub async fn route_list_function_instances(
             pagination: PaginationRequest {
                 page_size: query.page_size,
                 next_page_token: query.next_page_token,
+                direction: query.direction,
             },
             provider_instance_id: query.provider_instance_id,
         },
@@ -1035,7 +1052,12 @@ pub async fn route_list_function_instances(
         success = res.is_ok(),
         "Listing function instances completed"
     );
-    JsonResponse::from(res)
+    ConditionalJsonResponse::new(&request_headers, res, |res| {
+        ListingValidators::new(
+            res,
+            res.items.iter().map(|item| *item.updated_at.get_inner()),
+        )
+    })
 }
 
 #[utoipa::path(
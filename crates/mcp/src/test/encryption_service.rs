@@ -96,31 +96,12 @@ pub async fn setup_test_encryption(alias: &str) -> TestEncryptionSetup {
     let encryption_repo = encryption::repository::Repository::new(enc_conn);
 
     // First create the envelope encryption key
-    let (key_type, local_file_name, aws_arn, aws_region) = match &dek.envelope_encryption_key_id {
-        encryption::logic::envelope::EnvelopeEncryptionKey::Local(local) => (
-            encryption::repository::EnvelopeEncryptionKeyType::Local,
-            Some(local.file_name.clone()),
-            None,
-            None,
-        ),
-        encryption::logic::envelope::EnvelopeEncryptionKey::AwsKms(aws_kms) => (
-            encryption::repository::EnvelopeEncryptionKeyType::AwsKms,
-            None,
-            Some(aws_kms.arn.clone()),
-            Some(aws_kms.region.clone()),
-        ),
-    };
-
+    let envelope_key = encryption::repository::CreateEnvelopeEncryptionKey::from((
+        dek.envelope_encryption_key_id.clone(),
+        WrappedChronoDateTime::now(),
+    ));
     encryption_repo
-        .create_envelope_encryption_key(&encryption::repository::CreateEnvelopeEncryptionKey {
-            id: dek.envelope_encryption_key_id.id(),
-            key_type,
-            local_file_name,
-            aws_arn,
-            aws_region,
-            created_at: WrappedChronoDateTime::now(),
-            updated_at: WrappedChronoDateTime::now(),
-        })
+        .create_envelope_encryption_key(&envelope_key)
         .await
         .expect("Failed to create test envelope encryption key");
 
@@ -3,18 +3,23 @@ mod unix_socket;
 
 use arc_swap::ArcSwap;
 use shared::error::CommonError;
-use std::{path::PathBuf, sync::Arc};
+use std::{collections::HashMap, path::PathBuf, sync::Arc, time::Duration};
 use tracing_subscriber::EnvFilter;
 pub use types::*;
 use unix_socket::{bind_unix_listener, create_listener_stream};
 
 use sdk_proto::soma_sdk_service_server::{SomaSdkService, SomaSdkServiceServer};
-use tonic::{Request, Response, Status, transport::Server};
-use tracing::{debug, trace};
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::ReceiverStream;
+use tonic::{Request, Response, Status, codec::CompressionEncoding, transport::Server};
+use tracing::{debug, info, trace, warn};
 
 pub type GenerateMcpClientResponse = sdk_proto::GenerateMcpClientResponse;
 pub type GenerateMcpClientRequest = sdk_proto::GenerateMcpClientRequest;
 
+/// Chunks buffered per streaming invocation before the function is made to wait for the client
+const INVOKE_STREAM_BUFFER: usize = 16;
+
 /// Trait for SDK-specific code generation (TypeScript, Python, etc.)
 #[tonic::async_trait]
 pub trait SdkCodeGenerator: Send + Sync {
@@ -30,6 +35,9 @@ pub struct GrpcService<G: SdkCodeGenerator> {
     agents: ArcSwap<Vec<Agent>>,
     code_generator: Arc<G>,
     secret_handler: ArcSwap<Option<SecretHandler>>,
+    /// Secret handlers keyed by provider type_id, preferred over `secret_handler` for requests
+    /// scoped to that provider
+    scoped_secret_handlers: ArcSwap<HashMap<String, SecretHandler>>,
     environment_variable_handler: ArcSwap<Option<EnvironmentVariableHandler>>,
     unset_secret_handler: ArcSwap<Option<UnsetSecretHandler>>,
     unset_environment_variable_handler: ArcSwap<Option<UnsetEnvironmentVariableHandler>>,
@@ -83,42 +91,191 @@ impl<G: SdkCodeGenerator + 'static> SomaSdkService for GrpcService<G> {
                     Status::invalid_argument(format!("Invalid request: {e}"))
                 })?;
 
-        let providers = self.providers.load();
+        let (function, concurrency_limit) = self.find_function(
+            &req.provider_controller_type_id,
+            &req.function_controller_type_id,
+        )?;
+
+        let cache = function
+            .result_cache
+            .as_ref()
+            .filter(|_| !req.bypass_cache)
+            .map(|cache| (cache, FunctionResultCache::cache_key(&req)));
+
+        let result = match cache.and_then(|(cache, key)| cache.get(key)) {
+            Some(data) => {
+                trace!(function = %function.name, provider = %req.provider_controller_type_id, "Returning cached function result");
+                InvokeFunctionResponse {
+                    result: Ok(data),
+                    logs: vec![],
+                }
+            }
+            None => {
+                let _permit = match &concurrency_limit {
+                    Some(limit) => match limit.acquire().await {
+                        Ok(permit) => Some(permit),
+                        Err(error) => {
+                            debug!(provider = %req.provider_controller_type_id, error = %error.message, "Rejecting function invocation over concurrency limit");
+                            let response = InvokeFunctionResponse {
+                                result: Err(error),
+                                logs: vec![],
+                            };
+                            return Ok(Response::new(response.into()));
+                        }
+                    },
+                    None => None,
+                };
+
+                trace!(function = %function.name, provider = %req.provider_controller_type_id, "Executing function");
+
+                let in_flight_logs = types::InFlightInvocationLogs::register(&req.invocation_id);
+                let invocation = (function.invoke)(req);
+                let result = match function.timeout_ms {
+                    Some(timeout_ms) => {
+                        match tokio::time::timeout(Duration::from_millis(timeout_ms), invocation)
+                            .await
+                        {
+                            Ok(result) => result,
+                            Err(_) => {
+                                debug!(function = %function.name, timeout_ms, "Function invocation timed out");
+                                Ok(InvokeFunctionResponse {
+                                    result: Err(CallbackError {
+                                        message: format!(
+                                            "Function did not complete within {timeout_ms}ms"
+                                        ),
+                                        code: Some(CallbackErrorCode::DeadlineExceeded),
+                                    }),
+                                    logs: vec![],
+                                })
+                            }
+                        }
+                    }
+                    None => invocation.await,
+                }
+                .map_err(|e| Status::internal(format!("Function invocation failed: {e}")));
 
-        // Find the provider and function
-        let provider = providers
-            .iter()
-            .find(|p| p.type_id == req.provider_controller_type_id)
-            .ok_or_else(|| {
-                Status::not_found(format!(
-                    "Provider not found: {}",
-                    req.provider_controller_type_id
-                ))
-            })?;
+                trace!(success = result.is_ok(), "Invoking function completed");
 
-        let function = provider
-            .functions
-            .iter()
-            .find(|f| f.name == req.function_controller_type_id)
-            .ok_or_else(|| {
-                Status::not_found(format!(
-                    "Function not found: {}",
-                    req.function_controller_type_id
-                ))
-            })?;
+                let mut result = result?;
+                // Lines appended while running come first, then any returned with the response
+                for line in std::mem::take(&mut result.logs) {
+                    in_flight_logs.logs().push(line);
+                }
+                result.logs = in_flight_logs.logs().take();
+                if function.validate_output
+                    && let Ok(data) = &result.result
+                    && let Err(error) = function.check_output(data)
+                {
+                    debug!(function = %function.name, error = %error.message, "Function output failed schema validation");
+                    result.result = Err(error);
+                }
+                if let Some((cache, key)) = cache
+                    && let Ok(data) = &result.result
+                {
+                    cache.insert(key, data.clone());
+                }
+                result
+            }
+        };
 
-        trace!(function = %function.name, provider = %provider.type_id, "Executing function");
+        let mut response: sdk_proto::InvokeFunctionResponse = result.into();
+        if let Some(max_output_bytes) = function.max_output_bytes
+            && let Some(sdk_proto::invoke_function_response::Kind::Data(data)) = &mut response.kind
+            && let Some(truncated) = truncate_function_output(data, max_output_bytes)
+        {
+            debug!(
+                function = %function.name,
+                original_bytes = data.len(),
+                max_output_bytes,
+                "Truncating function output"
+            );
+            *data = truncated;
+            response.truncated = true;
+        }
 
-        // Invoke the function (Arc keeps providers alive during the call)
-        let result = (function.invoke)(req)
-            .await
-            .map_err(|e| Status::internal(format!("Function invocation failed: {e}")));
+        Ok(Response::new(response))
+    }
 
-        trace!(success = result.is_ok(), "Invoking function completed");
+    type InvokeFunctionStreamStream =
+        ReceiverStream<Result<sdk_proto::InvokeFunctionChunk, Status>>;
 
-        let result = result?;
+    async fn invoke_function_stream(
+        &self,
+        request: Request<sdk_proto::InvokeFunctionRequest>,
+    ) -> Result<Response<Self::InvokeFunctionStreamStream>, Status> {
+        trace!("Invoking streaming function");
+        let req: InvokeFunctionRequest =
+            request
+                .into_inner()
+                .try_into()
+                .map_err(|e: shared::error::CommonError| {
+                    Status::invalid_argument(format!("Invalid request: {e}"))
+                })?;
 
-        Ok(Response::new(result.into()))
+        let (function, concurrency_limit) = self.find_function(
+            &req.provider_controller_type_id,
+            &req.function_controller_type_id,
+        )?;
+
+        // Result caching, output truncation, `timeout_ms` and `validate_output` only apply to
+        // unary invocations, since stream chunks are forwarded as they arrive. The concurrency
+        // limit applies to both, and a stream holds its slot until the producer finishes.
+        let (chunk_tx, mut chunk_rx) = mpsc::channel::<InvokeFunctionChunk>(INVOKE_STREAM_BUFFER);
+        let producer = tokio::spawn(async move {
+            let _permit = match &concurrency_limit {
+                Some(limit) => match limit.acquire().await {
+                    Ok(permit) => Some(permit),
+                    Err(error) => {
+                        debug!(provider = %req.provider_controller_type_id, error = %error.message, "Rejecting function stream over concurrency limit");
+                        let _ = chunk_tx
+                            .send(InvokeFunctionChunk { result: Err(error) })
+                            .await;
+                        return Ok(());
+                    }
+                },
+                None => None,
+            };
+
+            match &function.invoke_stream {
+                Some(invoke_stream) => invoke_stream(req, chunk_tx).await,
+                None => {
+                    let response = (function.invoke)(req).await?;
+                    // The receiver only goes away once the client has disconnected
+                    let _ = chunk_tx
+                        .send(InvokeFunctionChunk {
+                            result: response.result,
+                        })
+                        .await;
+                    Ok(())
+                }
+            }
+        });
+
+        let (tx, rx) = mpsc::channel(INVOKE_STREAM_BUFFER);
+        tokio::spawn(async move {
+            while let Some(chunk) = chunk_rx.recv().await {
+                if tx.send(Ok(chunk.into())).await.is_err() {
+                    debug!("Client disconnected from function stream");
+                    producer.abort();
+                    return;
+                }
+            }
+
+            let status = match producer.await {
+                Ok(Ok(())) => None,
+                Ok(Err(e)) => Some(Status::internal(format!("Function invocation failed: {e}"))),
+                Err(e) => Some(Status::internal(format!("Function invocation failed: {e}"))),
+            };
+            trace!(
+                success = status.is_none(),
+                "Invoking streaming function completed"
+            );
+            if let Some(status) = status {
+                let _ = tx.send(Err(status)).await;
+            }
+        });
+
+        Ok(Response::new(ReceiverStream::new(rx)))
     }
 
     async fn generate_mcp_client(
@@ -157,19 +314,20 @@ impl<G: SdkCodeGenerator + 'static> SomaSdkService for GrpcService<G> {
         trace!(count, "Setting secrets");
 
         let proto_req = request.into_inner();
+        let provider_scope = proto_req.provider_scope;
         let secrets: Vec<Secret> = proto_req.secrets.into_iter().map(Into::into).collect();
+        let keys: Vec<String> = secrets.iter().map(|s| s.key.clone()).collect();
 
         // Get the secret handler
-        let handler_guard = self.secret_handler.load();
-        let handler = match handler_guard.as_ref() {
-            Some(h) => h.clone(),
+        let handler = match self.secret_handler_for(provider_scope.as_deref()) {
+            Some(h) => h,
             None => {
-                debug!("No secret handler registered");
+                debug!(?provider_scope, "No secret handler registered");
                 trace!(count, "Setting secrets completed (no handler)");
                 return Ok(Response::new(sdk_proto::SetSecretsResponse {
-                    kind: Some(Kind::Error(sdk_proto::CallbackError {
-                        message: "No secret handler registered".to_string(),
-                    })),
+                    kind: Some(Kind::Error(
+                        CallbackError::internal("No secret handler registered").into(),
+                    )),
                 }));
             }
         };
@@ -182,7 +340,10 @@ impl<G: SdkCodeGenerator + 'static> SomaSdkService for GrpcService<G> {
 
         trace!(count, success = result.is_ok(), "Setting secrets completed");
 
-        let result = result?;
+        let mut result = result?;
+        if let Ok(data) = &mut result.result {
+            data.outcomes = complete_key_outcomes(&keys, std::mem::take(&mut data.outcomes));
+        }
 
         Ok(Response::new(result.into()))
     }
@@ -201,6 +362,7 @@ impl<G: SdkCodeGenerator + 'static> SomaSdkService for GrpcService<G> {
             .into_iter()
             .map(Into::into)
             .collect();
+        let keys: Vec<String> = env_vars.iter().map(|e| e.key.clone()).collect();
 
         // Get the environment variable handler
         let handler_guard = self.environment_variable_handler.load();
@@ -213,9 +375,10 @@ impl<G: SdkCodeGenerator + 'static> SomaSdkService for GrpcService<G> {
                     "Setting environment variables completed (no handler)"
                 );
                 return Ok(Response::new(sdk_proto::SetEnvironmentVariablesResponse {
-                    kind: Some(Kind::Error(sdk_proto::CallbackError {
-                        message: "No environment variable handler registered".to_string(),
-                    })),
+                    kind: Some(Kind::Error(
+                        CallbackError::internal("No environment variable handler registered")
+                            .into(),
+                    )),
                 }));
             }
         };
@@ -232,7 +395,10 @@ impl<G: SdkCodeGenerator + 'static> SomaSdkService for GrpcService<G> {
             "Setting environment variables completed"
         );
 
-        let result = result?;
+        let mut result = result?;
+        if let Ok(data) = &mut result.result {
+            data.outcomes = complete_key_outcomes(&keys, std::mem::take(&mut data.outcomes));
+        }
 
         Ok(Response::new(result.into()))
     }
@@ -256,9 +422,9 @@ impl<G: SdkCodeGenerator + 'static> SomaSdkService for GrpcService<G> {
                 debug!("No unset secret handler registered");
                 trace!(key = %key, "Unsetting secret completed (no handler)");
                 return Ok(Response::new(sdk_proto::UnsetSecretResponse {
-                    kind: Some(Kind::Error(sdk_proto::CallbackError {
-                        message: "No unset secret handler registered".to_string(),
-                    })),
+                    kind: Some(Kind::Error(
+                        CallbackError::internal("No unset secret handler registered").into(),
+                    )),
                 }));
             }
         };
@@ -295,9 +461,10 @@ impl<G: SdkCodeGenerator + 'static> SomaSdkService for GrpcService<G> {
                 debug!("No unset environment variable handler registered");
                 trace!(key = %key, "Unsetting environment variable completed (no handler)");
                 return Ok(Response::new(sdk_proto::UnsetEnvironmentVariableResponse {
-                    kind: Some(Kind::Error(sdk_proto::CallbackError {
-                        message: "No unset environment variable handler registered".to_string(),
-                    })),
+                    kind: Some(Kind::Error(
+                        CallbackError::internal("No unset environment variable handler registered")
+                            .into(),
+                    )),
                 }));
             }
         };
@@ -317,12 +484,44 @@ impl<G: SdkCodeGenerator + 'static> SomaSdkService for GrpcService<G> {
 }
 
 impl<G: SdkCodeGenerator + 'static> GrpcService<G> {
+    /// Looks up a function by provider type id and function name, warning when it is deprecated
+    fn find_function(
+        &self,
+        provider_type_id: &str,
+        function_name: &str,
+    ) -> Result<(FunctionController, Option<ProviderConcurrencyLimit>), Status> {
+        let providers = self.providers.load();
+
+        let provider = providers
+            .iter()
+            .find(|p| p.type_id == provider_type_id)
+            .ok_or_else(|| Status::not_found(format!("Provider not found: {provider_type_id}")))?;
+
+        let function = provider
+            .functions
+            .iter()
+            .find(|f| f.name == function_name)
+            .ok_or_else(|| Status::not_found(format!("Function not found: {function_name}")))?;
+
+        if function.deprecated {
+            warn!(
+                function = %function.name,
+                provider = %provider.type_id,
+                deprecation_message = function.deprecation_message.as_deref().unwrap_or_default(),
+                "Invoking deprecated function"
+            );
+        }
+
+        Ok((function.clone(), provider.concurrency_limit.clone()))
+    }
+
     pub fn new(providers: Vec<ProviderController>, agents: Vec<Agent>, code_generator: G) -> Self {
         Self {
             providers: ArcSwap::from_pointee(providers),
             agents: ArcSwap::from_pointee(agents),
             code_generator: Arc::new(code_generator),
             secret_handler: ArcSwap::from_pointee(None),
+            scoped_secret_handlers: ArcSwap::from_pointee(HashMap::new()),
             environment_variable_handler: ArcSwap::from_pointee(None),
             unset_secret_handler: ArcSwap::from_pointee(None),
             unset_environment_variable_handler: ArcSwap::from_pointee(None),
@@ -334,6 +533,23 @@ impl<G: SdkCodeGenerator + 'static> GrpcService<G> {
         self.secret_handler.store(Arc::new(Some(handler)));
     }
 
+    /// Set the secret handler for secrets scoped to one provider. Scoped requests for providers
+    /// without their own handler fall back to the handler set by [`Self::set_secret_handler`].
+    pub fn set_secret_handler_for(&self, provider_type_id: &str, handler: SecretHandler) {
+        self.scoped_secret_handlers.rcu(|current| {
+            let mut handlers = (**current).clone();
+            handlers.insert(provider_type_id.to_string(), handler.clone());
+            handlers
+        });
+    }
+
+    /// Handler that receives secrets for `provider_scope`, falling back to the global handler
+    fn secret_handler_for(&self, provider_scope: Option<&str>) -> Option<SecretHandler> {
+        provider_scope
+            .and_then(|scope| self.scoped_secret_handlers.load().get(scope).cloned())
+            .or_else(|| self.secret_handler.load().as_ref().clone())
+    }
+
     /// Set the environment variable handler callback that will be invoked when environment variables are synced
     pub fn set_environment_variable_handler(&self, handler: EnvironmentVariableHandler) {
         self.environment_variable_handler
@@ -394,8 +610,35 @@ impl<G: SdkCodeGenerator + 'static> GrpcService<G> {
         updated
     }
 
-    /// Add a function controller to a specific provider
-    pub fn add_function(&self, provider_type_id: &str, function: FunctionController) -> bool {
+    /// Update a provider's own fields (name, documentation, categories, credential controllers)
+    /// while keeping the functions already registered on it. Functions are registered separately
+    /// through [`Self::add_function`], so bindings should update providers through this method.
+    pub fn update_provider_keeping_functions(&self, provider: ProviderController) -> bool {
+        let summary = self.reconcile_with(true, |current| {
+            current
+                .iter()
+                .map(|p| {
+                    if p.type_id == provider.type_id {
+                        provider.clone()
+                    } else {
+                        p.clone()
+                    }
+                })
+                .collect()
+        });
+        summary.updated.contains(&provider.type_id)
+    }
+
+    /// Add a function controller to a specific provider. Fails when the function validates its
+    /// output and its output schema does not compile.
+    pub fn add_function(
+        &self,
+        provider_type_id: &str,
+        function: FunctionController,
+    ) -> Result<bool, CommonError> {
+        if function.validate_output {
+            function.compile_output_schema()?;
+        }
         let mut added = false;
         self.providers.rcu(|current| {
             let mut new_providers = (**current).clone();
@@ -408,7 +651,7 @@ impl<G: SdkCodeGenerator + 'static> GrpcService<G> {
             }
             new_providers
         });
-        added
+        Ok(added)
     }
 
     /// Remove a function controller from a specific provider
@@ -429,8 +672,16 @@ impl<G: SdkCodeGenerator + 'static> GrpcService<G> {
         removed
     }
 
-    /// Update a function controller (removes old and inserts new), or add if it doesn't exist
-    pub fn update_function(&self, provider_type_id: &str, function: FunctionController) -> bool {
+    /// Update a function controller (removes old and inserts new), or add if it doesn't exist.
+    /// Rejects invalid output schemas like [`Self::add_function`].
+    pub fn update_function(
+        &self,
+        provider_type_id: &str,
+        function: FunctionController,
+    ) -> Result<bool, CommonError> {
+        if function.validate_output {
+            function.compile_output_schema()?;
+        }
         let mut updated = false;
         self.providers.rcu(|current| {
             let mut new_providers = (**current).clone();
@@ -455,27 +706,80 @@ impl<G: SdkCodeGenerator + 'static> GrpcService<G> {
             }
             new_providers
         });
-        updated
+        Ok(updated)
     }
 
-    /// Add a new agent
-    pub fn add_agent(&self, agent: Agent) -> bool {
-        let mut added = false;
+    /// Add a new agent. Fails when an agent with the same name is already registered in the
+    /// agent's project.
+    pub fn add_agent(&self, agent: Agent) -> Result<(), CommonError> {
+        let mut duplicate = false;
+        self.agents.rcu(|current| {
+            let mut new_agents = (**current).clone();
+            duplicate = current
+                .iter()
+                .any(|a| a.project_id == agent.project_id && a.name == agent.name);
+            if !duplicate {
+                new_agents.push(agent.clone());
+            }
+            new_agents
+        });
+
+        if duplicate {
+            return Err(CommonError::InvalidRequest {
+                msg: format!(
+                    "Agent '{}' is already registered in project '{}'",
+                    agent.name, agent.project_id
+                ),
+                source: None,
+            });
+        }
+        Ok(())
+    }
+
+    /// Agents registered under `project_id`, in registration order
+    pub fn list_agents_by_project(&self, project_id: &str) -> Vec<Agent> {
+        self.agents
+            .load()
+            .iter()
+            .filter(|a| a.project_id == project_id)
+            .cloned()
+            .collect()
+    }
+
+    /// Atomically add a batch of agents.
+    ///
+    /// Returns the ids that conflict, either with an already registered agent or with another
+    /// agent in the same batch. When any conflict is found nothing is installed.
+    pub fn add_agents_batch(&self, agents: Vec<Agent>) -> Vec<String> {
+        let mut conflicts = Vec::new();
         self.agents.rcu(|current| {
+            conflicts.clear();
+            let mut seen = std::collections::HashSet::new();
+            for agent in &agents {
+                let duplicate =
+                    current.iter().any(|a| a.id == agent.id) || !seen.insert(agent.id.as_str());
+                if duplicate && !conflicts.contains(&agent.id) {
+                    conflicts.push(agent.id.clone());
+                }
+            }
+
             let mut new_agents = (**current).clone();
-            new_agents.push(agent.clone());
-            added = true;
+            if conflicts.is_empty() {
+                new_agents.extend(agents.iter().cloned());
+            }
             new_agents
         });
-        added
+        conflicts
     }
 
     /// Remove an agent by id
     pub fn remove_agent(&self, id: &str) -> bool {
-        let removed = false;
+        let mut removed = false;
         self.agents.rcu(|current| {
+            let initial_len = current.len();
             let mut new_agents = (**current).clone();
             new_agents.retain(|a| a.id != id);
+            removed = new_agents.len() != initial_len;
             new_agents
         });
         removed
@@ -500,6 +804,69 @@ impl<G: SdkCodeGenerator + 'static> GrpcService<G> {
         self.providers.store(Arc::new(providers));
     }
 
+    /// Replace the registered providers with `desired` in a single swap, so invocations never
+    /// observe an intermediate state where kept providers are missing. Providers present in both
+    /// keep their registration position and take the desired definition; new providers are
+    /// appended in `desired` order.
+    pub fn reconcile_providers(
+        &self,
+        desired: Vec<ProviderController>,
+    ) -> ProviderReconcileSummary {
+        self.reconcile_with(false, |_| desired.clone())
+    }
+
+    /// Like [`Self::reconcile_providers`], but kept providers also keep the functions already
+    /// registered on them. Bindings pass providers without functions and register those through
+    /// [`Self::add_function`], so they reconcile through this method.
+    pub fn reconcile_providers_keeping_functions(
+        &self,
+        desired: Vec<ProviderController>,
+    ) -> ProviderReconcileSummary {
+        self.reconcile_with(true, |_| desired.clone())
+    }
+
+    /// Reconciles against the providers `desired` computes from the current ones, retried by the
+    /// swap if another update lands in between
+    fn reconcile_with(
+        &self,
+        keep_functions: bool,
+        desired: impl Fn(&[ProviderController]) -> Vec<ProviderController>,
+    ) -> ProviderReconcileSummary {
+        let mut summary = ProviderReconcileSummary::default();
+        self.providers.rcu(|current| {
+            summary = ProviderReconcileSummary::default();
+            let desired = desired(current);
+            let mut new_providers = Vec::with_capacity(desired.len());
+            for provider in current.iter() {
+                match desired.iter().find(|p| p.type_id == provider.type_id) {
+                    Some(replacement) => {
+                        summary.updated.push(provider.type_id.clone());
+                        let mut replacement = replacement.clone();
+                        if keep_functions {
+                            replacement.functions = provider.functions.clone();
+                        }
+                        new_providers.push(replacement);
+                    }
+                    None => summary.removed.push(provider.type_id.clone()),
+                }
+            }
+            for provider in &desired {
+                if !current.iter().any(|p| p.type_id == provider.type_id) {
+                    summary.added.push(provider.type_id.clone());
+                    new_providers.push(provider.clone());
+                }
+            }
+            new_providers
+        });
+        debug!(
+            added = summary.added.len(),
+            updated = summary.updated.len(),
+            removed = summary.removed.len(),
+            "Reconciled providers"
+        );
+        summary
+    }
+
     /// Get a provider by type_id
     pub fn get_provider(&self, type_id: &str) -> Option<ProviderController> {
         self.providers
@@ -509,12 +876,22 @@ impl<G: SdkCodeGenerator + 'static> GrpcService<G> {
             .cloned()
     }
 
+    /// Snapshot of every registered provider with its functions' schemas, in registration order
+    pub fn list_providers_with_functions(&self) -> Vec<ProviderWithFunctions> {
+        self.providers
+            .load()
+            .iter()
+            .map(ProviderWithFunctions::from)
+            .collect()
+    }
+
     /// Clears all providers, agents, and handlers from the service.
     /// This effectively resets the service state without shutting down the gRPC server.
     pub fn clear(&self) {
         self.providers.store(Arc::new(vec![]));
         self.agents.store(Arc::new(vec![]));
         self.secret_handler.store(Arc::new(None));
+        self.scoped_secret_handlers.store(Arc::new(HashMap::new()));
         self.environment_variable_handler.store(Arc::new(None));
         self.unset_secret_handler.store(Arc::new(None));
         self.unset_environment_variable_handler
@@ -522,6 +899,34 @@ impl<G: SdkCodeGenerator + 'static> GrpcService<G> {
     }
 }
 
+/// Stops a server started by [`start_grpc_server`]. Dropping the handle also stops the server,
+/// but leaves the socket file behind.
+pub struct ShutdownHandle {
+    shutdown_tx: tokio::sync::oneshot::Sender<()>,
+    server: tokio::task::JoinHandle<()>,
+    socket_path: PathBuf,
+}
+
+impl ShutdownHandle {
+    /// Stops accepting connections, waits for in-flight requests to finish and removes the
+    /// socket file
+    pub async fn shutdown(self) -> Result<(), CommonError> {
+        debug!(socket = ?self.socket_path, "Shutting down gRPC server");
+        // The server may already have exited, in which case nobody is listening
+        let _ = self.shutdown_tx.send(());
+        self.server
+            .await
+            .map_err(|e| anyhow::anyhow!("gRPC server task failed: {e}"))?;
+
+        if self.socket_path.exists() {
+            std::fs::remove_file(&self.socket_path)
+                .map_err(|e| anyhow::anyhow!("Failed to remove socket: {e}"))?;
+        }
+        trace!("gRPC server shut down");
+        Ok(())
+    }
+}
+
 /// Starts a gRPC server that handles function invocations over a Unix socket
 ///
 /// # Arguments
@@ -530,7 +935,8 @@ impl<G: SdkCodeGenerator + 'static> GrpcService<G> {
 /// * `code_generator` - Implementation of SdkCodeGenerator for mcp client generation
 ///
 /// # Returns
-/// A handle to the GrpcService for dynamic provider/function management
+/// A handle to the GrpcService for dynamic provider/function management, and a
+/// [`ShutdownHandle`] that stops the server
 ///
 /// # Example
 /// Each FunctionController must have an `invoke` function that handles the invocation.
@@ -538,10 +944,11 @@ pub async fn start_grpc_server<G: SdkCodeGenerator + 'static>(
     providers: Vec<ProviderController>,
     socket_path: PathBuf,
     code_generator: G,
-) -> Result<Arc<GrpcService<G>>, CommonError> {
-    tracing_subscriber::fmt()
+) -> Result<(Arc<GrpcService<G>>, ShutdownHandle), CommonError> {
+    // The server can be restarted in the same process, so the subscriber may already be set
+    let _ = tracing_subscriber::fmt()
         .with_env_filter(EnvFilter::from_default_env().add_directive("info".parse().unwrap()))
-        .init();
+        .try_init();
 
     // Remove existing socket file if it exists
     if socket_path.exists() {
@@ -555,29 +962,52 @@ pub async fn start_grpc_server<G: SdkCodeGenerator + 'static>(
     let service = Arc::new(GrpcService::new(providers, vec![], code_generator));
     let service_clone = Arc::clone(&service);
 
-    // Spawn the server in a background task
-    tokio::spawn(async move {
-        // Create Unix socket listener (platform-specific)
-        let uds = match bind_unix_listener(&socket_path).await {
-            Ok(uds) => uds,
-            Err(e) => {
-                tracing::error!("Failed to bind Unix socket: {e}");
-                return;
-            }
-        };
-
-        let incoming = create_listener_stream(uds);
+    // Create Unix socket listener (platform-specific)
+    let uds = bind_unix_listener(&socket_path)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to bind Unix socket: {e}"))?;
+    let incoming = create_listener_stream(uds);
+    let (shutdown_tx, shutdown_rx) = tokio::sync::oneshot::channel::<()>();
 
+    // Spawn the server in a background task
+    let server = tokio::spawn(async move {
         if let Err(e) = Server::builder()
-            .add_service(SomaSdkServiceServer::new(GrpcServiceWrapper(service_clone)))
-            .serve_with_incoming(incoming)
+            .add_service(sdk_service_server(
+                service_clone,
+                shared::uds::sdk_grpc_compression_enabled(),
+            ))
+            .serve_with_incoming_shutdown(incoming, async {
+                let _ = shutdown_rx.await;
+            })
             .await
         {
             tracing::error!("gRPC server error: {e}");
         }
     });
 
-    Ok(service)
+    Ok((
+        service,
+        ShutdownHandle {
+            shutdown_tx,
+            server,
+            socket_path,
+        },
+    ))
+}
+
+/// Builds the tonic service for `service`. Gzip requests are always accepted; responses are
+/// gzip-compressed when `compression` is set and the client accepts gzip.
+fn sdk_service_server<G: SdkCodeGenerator + 'static>(
+    service: Arc<GrpcService<G>>,
+    compression: bool,
+) -> SomaSdkServiceServer<GrpcServiceWrapper<G>> {
+    let server = SomaSdkServiceServer::new(GrpcServiceWrapper(service))
+        .accept_compressed(CompressionEncoding::Gzip);
+    if compression {
+        server.send_compressed(CompressionEncoding::Gzip)
+    } else {
+        server
+    }
 }
 
 /// Wrapper to allow Arc<GrpcService> to implement SomaSdkService
@@ -603,6 +1033,16 @@ impl<G: SdkCodeGenerator + 'static> SomaSdkService for GrpcServiceWrapper<G> {
         self.0.invoke_function(request).await
     }
 
+    type InvokeFunctionStreamStream =
+        ReceiverStream<Result<sdk_proto::InvokeFunctionChunk, Status>>;
+
+    async fn invoke_function_stream(
+        &self,
+        request: Request<sdk_proto::InvokeFunctionRequest>,
+    ) -> Result<Response<Self::InvokeFunctionStreamStream>, Status> {
+        self.0.invoke_function_stream(request).await
+    }
+
     async fn generate_mcp_client(
         &self,
         request: Request<sdk_proto::GenerateMcpClientRequest>,
@@ -643,6 +1083,75 @@ impl<G: SdkCodeGenerator + 'static> SomaSdkService for GrpcServiceWrapper<G> {
 #[derive(Debug, Clone)]
 pub struct ResyncSdkResponse {}
 
+/// Backoff used by [`resync_sdk`] while the API server is still starting up
+#[derive(Debug, Clone)]
+pub struct ResyncBackoff {
+    /// Delay before the second attempt (default: 500ms)
+    pub initial_delay: Duration,
+    /// Upper bound for any single delay (default: 4s)
+    pub max_delay: Duration,
+    /// Random jitter as a fraction of each delay, between 0.0 and 1.0 (default: 0.2)
+    pub jitter: f64,
+    /// Total number of attempts before giving up (default: 5)
+    pub max_attempts: u32,
+}
+
+impl Default for ResyncBackoff {
+    fn default() -> Self {
+        Self {
+            initial_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(4),
+            jitter: 0.2,
+            max_attempts: 5,
+        }
+    }
+}
+
+impl ResyncBackoff {
+    /// Delay to wait after the given (zero-based) failed attempt. Doubles each time.
+    fn delay(&self, attempt: u32) -> Duration {
+        let base = self
+            .initial_delay
+            .saturating_mul(2u32.saturating_pow(attempt))
+            .min(self.max_delay);
+        let jitter = self.jitter.clamp(0.0, 1.0);
+        if jitter == 0.0 {
+            return base;
+        }
+        let offset = rand::Rng::gen_range(&mut rand::thread_rng(), -jitter..=jitter);
+        base.mul_f64(1.0 + offset)
+    }
+}
+
+/// Why a resync did not go through
+#[derive(Debug)]
+pub enum ResyncError {
+    /// Every attempt failed, most likely because the API server never came up
+    Timeout { attempts: u32, last_error: String },
+}
+
+impl std::fmt::Display for ResyncError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ResyncError::Timeout {
+                attempts,
+                last_error,
+            } => write!(
+                f,
+                "Resync failed after {attempts} attempts, last error: {last_error}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ResyncError {}
+
+impl From<ResyncError> for CommonError {
+    fn from(error: ResyncError) -> Self {
+        CommonError::Unknown(anyhow::Error::new(error))
+    }
+}
+
 /// Calls the internal resync endpoint on the Soma API server.
 /// This triggers the API server to:
 /// - Fetch metadata from the SDK (providers, agents)
@@ -651,12 +1160,23 @@ pub struct ResyncSdkResponse {}
 /// - Sync secrets to the SDK
 /// - Sync environment variables to the SDK
 ///
+/// Connection errors and 5xx responses are retried with [`ResyncBackoff::default`], since the
+/// SDK often starts before the API server is listening.
+///
 /// # Arguments
 /// * `base_url` - Optional base URL of the Soma API server (defaults to SOMA_SERVER_BASE_URL env var or http://localhost:3000)
 ///
 /// # Returns
-/// The resync response from the server
+/// The resync response from the server, or a [`ResyncError::Timeout`] if every attempt failed
 pub async fn resync_sdk(base_url: Option<String>) -> Result<ResyncSdkResponse, CommonError> {
+    resync_sdk_with_backoff(base_url, &ResyncBackoff::default()).await
+}
+
+/// [`resync_sdk`] with a custom backoff
+pub async fn resync_sdk_with_backoff(
+    base_url: Option<String>,
+    backoff: &ResyncBackoff,
+) -> Result<ResyncSdkResponse, CommonError> {
     let api_base_url = base_url
         .or_else(|| std::env::var("SOMA_SERVER_BASE_URL").ok())
         .unwrap_or_else(|| "http://localhost:3000".to_string());
@@ -668,15 +1188,1583 @@ pub async fn resync_sdk(base_url: Option<String>) -> Result<ResyncSdkResponse, C
         ..Default::default()
     };
 
-    let result = soma_api_client::apis::internal_api::resync_sdk(&config)
-        .await
-        .map_err(|e| CommonError::Unknown(anyhow::anyhow!("Resync failed: {e:?}")));
+    let max_attempts = backoff.max_attempts.max(1);
+    let mut last_error = String::new();
+    for attempt in 1..=max_attempts {
+        let error = match soma_api_client::apis::internal_api::resync_sdk(&config).await {
+            Ok(_) => {
+                trace!(attempt, "Calling SDK resync endpoint completed");
+                return Ok(ResyncSdkResponse {});
+            }
+            Err(e) => e,
+        };
 
-    trace!(
-        success = result.is_ok(),
-        "Calling SDK resync endpoint completed"
-    );
-    result?;
+        let retryable = match &error {
+            soma_api_client::apis::Error::ResponseError(response) => {
+                response.status.is_server_error()
+            }
+            soma_api_client::apis::Error::Reqwest(_) | soma_api_client::apis::Error::Io(_) => true,
+            _ => false,
+        };
+        if !retryable {
+            return Err(CommonError::Unknown(anyhow::anyhow!(
+                "Resync failed: {error:?}"
+            )));
+        }
+
+        last_error = format!("{error:?}");
+        if attempt < max_attempts {
+            let delay = backoff.delay(attempt - 1);
+            info!(
+                attempt,
+                max_attempts,
+                retry_in_ms = delay.as_millis() as u64,
+                error = %last_error,
+                "SDK resync failed, retrying"
+            );
+            tokio::time::sleep(delay).await;
+        } else {
+            info!(attempt, max_attempts, error = %last_error, "SDK resync failed, giving up");
+        }
+    }
 
-    Ok(ResyncSdkResponse {})
+    Err(ResyncError::Timeout {
+        attempts: max_attempts,
+        last_error,
+    }
+    .into())
+}
+
+#[cfg(test)]
+mod tests {
+    mod unit {
+        use super::super::*;
+        use std::time::Duration;
+
+        struct NoopCodeGenerator;
+
+        #[tonic::async_trait]
+        impl SdkCodeGenerator for NoopCodeGenerator {
+            async fn generate_mcp_client(
+                &self,
+                _request: GenerateMcpClientRequest,
+            ) -> Result<GenerateMcpClientResponse, CommonError> {
+                Ok(GenerateMcpClientResponse { result: None })
+            }
+        }
+
+        fn provider_with_function(
+            output: &'static str,
+            max_output_bytes: Option<usize>,
+        ) -> ProviderController {
+            ProviderController {
+                type_id: "test-provider".to_string(),
+                name: "Test Provider".to_string(),
+                documentation: String::new(),
+                categories: vec![],
+                functions: vec![FunctionController {
+                    name: "test-function".to_string(),
+                    description: String::new(),
+                    parameters: "{}".to_string(),
+                    output: "{}".to_string(),
+                    max_output_bytes,
+                    result_cache: None,
+                    deprecated: false,
+                    deprecation_message: None,
+                    examples: vec![],
+                    timeout_ms: None,
+                    validate_output: false,
+                    output_validator: Default::default(),
+                    invoke: Arc::new(move |_req| {
+                        Box::pin(async move {
+                            Ok(InvokeFunctionResponse {
+                                result: Ok(output.to_string()),
+                                logs: vec![],
+                            })
+                        })
+                    }),
+                    invoke_stream: None,
+                }],
+                credential_controllers: vec![ProviderCredentialController::NoAuth],
+                concurrency_limit: None,
+            }
+        }
+
+        fn invoke_request() -> Request<sdk_proto::InvokeFunctionRequest> {
+            Request::new(sdk_proto::InvokeFunctionRequest {
+                provider_controller_type_id: "test-provider".to_string(),
+                function_controller_type_id: "test-function".to_string(),
+                credential_controller_type_id: "no_auth".to_string(),
+                credentials: "{}".to_string(),
+                parameters: "{}".to_string(),
+                request_id: String::new(),
+                bypass_cache: false,
+            })
+        }
+
+        #[tokio::test]
+        async fn test_invoke_function_truncates_output_over_limit() {
+            shared::setup_test!();
+
+            let service = GrpcService::new(
+                vec![provider_with_function("0123456789abcdef", Some(10))],
+                vec![],
+                NoopCodeGenerator,
+            );
+
+            let response = service
+                .invoke_function(invoke_request())
+                .await
+                .unwrap()
+                .into_inner();
+
+            assert!(response.truncated);
+            match response.kind {
+                Some(sdk_proto::invoke_function_response::Kind::Data(data)) => {
+                    assert_eq!(data, format!("0123456789{OUTPUT_TRUNCATION_MARKER}"));
+                }
+                other => panic!("Expected data response, got {other:?}"),
+            }
+        }
+
+        #[tokio::test]
+        async fn test_invoke_function_passes_through_output_within_limit() {
+            shared::setup_test!();
+
+            let service = GrpcService::new(
+                vec![provider_with_function("{\"ok\":true}", Some(1024))],
+                vec![],
+                NoopCodeGenerator,
+            );
+
+            let response = service
+                .invoke_function(invoke_request())
+                .await
+                .unwrap()
+                .into_inner();
+
+            assert!(!response.truncated);
+            match response.kind {
+                Some(sdk_proto::invoke_function_response::Kind::Data(data)) => {
+                    assert_eq!(data, "{\"ok\":true}");
+                }
+                other => panic!("Expected data response, got {other:?}"),
+            }
+        }
+
+        #[tokio::test]
+        async fn test_invoke_function_passes_request_id_to_callback() {
+            shared::setup_test!();
+
+            let mut provider = provider_with_function("", None);
+            provider.functions[0].invoke = Arc::new(|req| {
+                Box::pin(async move {
+                    Ok(InvokeFunctionResponse {
+                        result: Ok(req.request_id.unwrap_or_default()),
+                        logs: vec![],
+                    })
+                })
+            });
+            let service = GrpcService::new(vec![provider], vec![], NoopCodeGenerator);
+
+            let mut request = invoke_request();
+            request.get_mut().request_id = "req-123".to_string();
+            let response = service.invoke_function(request).await.unwrap().into_inner();
+
+            match response.kind {
+                Some(sdk_proto::invoke_function_response::Kind::Data(data)) => {
+                    assert_eq!(data, "req-123");
+                }
+                other => panic!("Expected data response, got {other:?}"),
+            }
+        }
+
+        #[tokio::test]
+        async fn test_invoke_function_returns_function_logs() {
+            shared::setup_test!();
+
+            let mut provider = provider_with_function("", None);
+            provider.functions[0].invoke = Arc::new(|_req| {
+                Box::pin(async move {
+                    Ok(InvokeFunctionResponse {
+                        result: Ok("{}".to_string()),
+                        logs: vec![
+                            LogLine::from_binding("info", "fetching page".to_string(), Some(1_000)),
+                            LogLine::from_binding("warning", "retrying".to_string(), Some(2_000)),
+                        ],
+                    })
+                })
+            });
+            let service = GrpcService::new(vec![provider], vec![], NoopCodeGenerator);
+
+            let response = service
+                .invoke_function(invoke_request())
+                .await
+                .unwrap()
+                .into_inner();
+
+            assert_eq!(
+                response.logs,
+                vec![
+                    sdk_proto::LogLine {
+                        level: sdk_proto::LogLevel::Info as i32,
+                        message: "fetching page".to_string(),
+                        timestamp_ms: 1_000,
+                    },
+                    sdk_proto::LogLine {
+                        level: sdk_proto::LogLevel::Warn as i32,
+                        message: "retrying".to_string(),
+                        timestamp_ms: 2_000,
+                    },
+                ]
+            );
+        }
+
+        #[test]
+        fn test_callback_error_codes_round_trip_through_proto() {
+            let codes = [
+                None,
+                Some(CallbackErrorCode::RateLimited { after_ms: None }),
+                Some(CallbackErrorCode::RateLimited {
+                    after_ms: Some(30_000),
+                }),
+                Some(CallbackErrorCode::Unauthorized),
+                Some(CallbackErrorCode::InvalidArgument),
+                Some(CallbackErrorCode::Internal),
+                Some(CallbackErrorCode::Retryable { after_ms: None }),
+                Some(CallbackErrorCode::Retryable {
+                    after_ms: Some(1500),
+                }),
+                Some(CallbackErrorCode::DeadlineExceeded),
+            ];
+
+            for code in codes {
+                let error = CallbackError {
+                    message: "boom".to_string(),
+                    code,
+                };
+                let proto: sdk_proto::CallbackError = error.clone().into();
+                assert_eq!(CallbackError::from(proto), error);
+            }
+        }
+
+        #[test]
+        fn test_callback_error_from_binding_parses_codes() {
+            let missing = CallbackError::from_binding("boom".to_string(), None, None);
+            assert_eq!(missing.code, Some(CallbackErrorCode::Internal));
+
+            let unknown = CallbackError::from_binding("boom".to_string(), Some("teapot"), None);
+            assert_eq!(unknown.code, Some(CallbackErrorCode::Internal));
+
+            let retryable =
+                CallbackError::from_binding("slow down".to_string(), Some("retryable"), Some(250));
+            assert_eq!(
+                retryable.code,
+                Some(CallbackErrorCode::Retryable {
+                    after_ms: Some(250)
+                })
+            );
+        }
+
+        #[tokio::test]
+        async fn test_invoke_function_returns_error_code() {
+            shared::setup_test!();
+
+            let mut provider = provider_with_function("", None);
+            provider.functions[0].invoke = Arc::new(|_req| {
+                Box::pin(async move {
+                    Ok(InvokeFunctionResponse {
+                        result: Err(CallbackError {
+                            message: "rate limited".to_string(),
+                            code: Some(CallbackErrorCode::RateLimited { after_ms: None }),
+                        }),
+                        logs: vec![],
+                    })
+                })
+            });
+            let service = GrpcService::new(vec![provider], vec![], NoopCodeGenerator);
+
+            let response = service
+                .invoke_function(invoke_request())
+                .await
+                .unwrap()
+                .into_inner();
+
+            match response.kind {
+                Some(sdk_proto::invoke_function_response::Kind::Error(error)) => {
+                    assert_eq!(error.code, sdk_proto::CallbackErrorCode::RateLimited as i32);
+                    assert_eq!(error.retry_after_ms, None);
+                }
+                other => panic!("Expected error response, got {other:?}"),
+            }
+        }
+
+        #[tokio::test]
+        async fn test_invoke_function_times_out_with_deadline_exceeded() {
+            shared::setup_test!();
+
+            let mut provider = provider_with_function("too late", None);
+            provider.functions[0].timeout_ms = Some(50);
+            provider.functions[0].invoke = Arc::new(|_req| {
+                Box::pin(async move {
+                    tokio::time::sleep(Duration::from_secs(5)).await;
+                    Ok(InvokeFunctionResponse {
+                        result: Ok("too late".to_string()),
+                        logs: vec![],
+                    })
+                })
+            });
+            let service = GrpcService::new(vec![provider], vec![], NoopCodeGenerator);
+
+            let response = service
+                .invoke_function(invoke_request())
+                .await
+                .unwrap()
+                .into_inner();
+
+            match response.kind {
+                Some(sdk_proto::invoke_function_response::Kind::Error(error)) => {
+                    assert_eq!(
+                        error.code,
+                        sdk_proto::CallbackErrorCode::DeadlineExceeded as i32
+                    );
+                }
+                other => panic!("Expected error response, got {other:?}"),
+            }
+        }
+
+        #[tokio::test]
+        async fn test_timed_out_invocation_returns_logs_appended_while_running() {
+            shared::setup_test!();
+
+            let mut provider = provider_with_function("too late", None);
+            provider.functions[0].timeout_ms = Some(50);
+            provider.functions[0].invoke = Arc::new(|req| {
+                Box::pin(async move {
+                    assert!(append_invocation_log(
+                        &req.invocation_id,
+                        LogLine::from_binding("info", "fetching page".to_string(), Some(1_000)),
+                    ));
+                    tokio::time::sleep(Duration::from_secs(5)).await;
+                    Ok(InvokeFunctionResponse {
+                        result: Ok("too late".to_string()),
+                        logs: vec![],
+                    })
+                })
+            });
+            let service = GrpcService::new(vec![provider], vec![], NoopCodeGenerator);
+
+            let response = service
+                .invoke_function(invoke_request())
+                .await
+                .unwrap()
+                .into_inner();
+
+            assert!(matches!(
+                response.kind,
+                Some(sdk_proto::invoke_function_response::Kind::Error(_))
+            ));
+            assert_eq!(
+                response.logs,
+                vec![sdk_proto::LogLine {
+                    level: sdk_proto::LogLevel::Info as i32,
+                    message: "fetching page".to_string(),
+                    timestamp_ms: 1_000,
+                }]
+            );
+        }
+
+        fn provider_logging(messages: Vec<String>) -> ProviderController {
+            let mut provider = provider_with_function("", None);
+            provider.functions[0].invoke = Arc::new(move |_req| {
+                let logs = messages
+                    .iter()
+                    .map(|message| LogLine::from_binding("info", message.clone(), Some(0)))
+                    .collect();
+                Box::pin(async move {
+                    Ok(InvokeFunctionResponse {
+                        result: Ok("{}".to_string()),
+                        logs,
+                    })
+                })
+            });
+            provider
+        }
+
+        #[tokio::test]
+        async fn test_invocation_logs_are_capped() {
+            shared::setup_test!();
+
+            let lines = (0..MAX_LOG_LINES_PER_INVOCATION + 5)
+                .map(|i| format!("line {i}"))
+                .collect();
+            let service =
+                GrpcService::new(vec![provider_logging(lines)], vec![], NoopCodeGenerator);
+            let logs = service
+                .invoke_function(invoke_request())
+                .await
+                .unwrap()
+                .into_inner()
+                .logs;
+            assert_eq!(logs.len(), MAX_LOG_LINES_PER_INVOCATION + 1);
+            let last = logs.last().unwrap();
+            assert_eq!(last.level, sdk_proto::LogLevel::Warn as i32);
+            assert!(last.message.starts_with("Dropped 5 log lines"));
+
+            let lines = vec!["x".repeat(MAX_LOG_BYTES_PER_INVOCATION / 2 + 1); 3];
+            let service =
+                GrpcService::new(vec![provider_logging(lines)], vec![], NoopCodeGenerator);
+            let logs = service
+                .invoke_function(invoke_request())
+                .await
+                .unwrap()
+                .into_inner()
+                .logs;
+            assert_eq!(logs.len(), 2);
+            assert!(logs[1].message.starts_with("Dropped 2 log lines"));
+        }
+
+        fn provider_tracking_concurrency(
+            in_flight: Arc<std::sync::atomic::AtomicUsize>,
+            peak: Arc<std::sync::atomic::AtomicUsize>,
+            limit: ProviderConcurrencyLimit,
+        ) -> ProviderController {
+            let mut provider = provider_with_function("", None);
+            provider.concurrency_limit = Some(limit);
+            provider.functions[0].invoke = Arc::new(move |_req| {
+                let in_flight = Arc::clone(&in_flight);
+                let peak = Arc::clone(&peak);
+                Box::pin(async move {
+                    let running = in_flight.fetch_add(1, std::sync::atomic::Ordering::SeqCst) + 1;
+                    peak.fetch_max(running, std::sync::atomic::Ordering::SeqCst);
+                    tokio::time::sleep(Duration::from_millis(20)).await;
+                    in_flight.fetch_sub(1, std::sync::atomic::Ordering::SeqCst);
+                    Ok(InvokeFunctionResponse {
+                        result: Ok("done".to_string()),
+                        logs: vec![],
+                    })
+                })
+            });
+            provider
+        }
+
+        #[tokio::test]
+        async fn test_invocations_over_concurrency_limit_queue() {
+            shared::setup_test!();
+
+            let in_flight = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+            let peak = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+            let provider = provider_tracking_concurrency(
+                in_flight,
+                Arc::clone(&peak),
+                ProviderConcurrencyLimit::new(2, 16),
+            );
+            let service = GrpcService::new(vec![provider], vec![], NoopCodeGenerator);
+
+            let responses = futures::future::join_all(
+                (0..8).map(|_| service.invoke_function(invoke_request())),
+            )
+            .await;
+
+            for response in responses {
+                match response.unwrap().into_inner().kind {
+                    Some(sdk_proto::invoke_function_response::Kind::Data(data)) => {
+                        assert_eq!(data, "done");
+                    }
+                    other => panic!("Expected data response, got {other:?}"),
+                }
+            }
+            assert_eq!(peak.load(std::sync::atomic::Ordering::SeqCst), 2);
+        }
+
+        #[tokio::test]
+        async fn test_invocations_over_queue_depth_are_rate_limited() {
+            shared::setup_test!();
+
+            let provider = provider_tracking_concurrency(
+                Arc::new(std::sync::atomic::AtomicUsize::new(0)),
+                Arc::new(std::sync::atomic::AtomicUsize::new(0)),
+                ProviderConcurrencyLimit::new(1, 1),
+            );
+            let service = GrpcService::new(vec![provider], vec![], NoopCodeGenerator);
+
+            // One invocation runs, one waits and the third finds the queue full
+            let responses = futures::future::join_all(
+                (0..3).map(|_| service.invoke_function(invoke_request())),
+            )
+            .await;
+
+            let rate_limited = responses
+                .into_iter()
+                .filter(|response| {
+                    matches!(
+                        &response.as_ref().unwrap().get_ref().kind,
+                        Some(sdk_proto::invoke_function_response::Kind::Error(error))
+                            if error.code == sdk_proto::CallbackErrorCode::RateLimited as i32
+                    )
+                })
+                .count();
+            assert_eq!(rate_limited, 1);
+        }
+
+        #[tokio::test]
+        async fn test_function_streams_share_the_concurrency_limit() {
+            shared::setup_test!();
+
+            let peak = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+            let provider = provider_tracking_concurrency(
+                Arc::new(std::sync::atomic::AtomicUsize::new(0)),
+                Arc::clone(&peak),
+                ProviderConcurrencyLimit::new(1, 1),
+            );
+            let service = GrpcService::new(vec![provider], vec![], NoopCodeGenerator);
+
+            // One stream runs, one waits and the third finds the queue full
+            let chunks = futures::future::join_all((0..3).map(|_| async {
+                let stream = service
+                    .invoke_function_stream(invoke_request())
+                    .await
+                    .unwrap()
+                    .into_inner();
+                tokio_stream::StreamExt::collect::<Vec<_>>(stream).await
+            }))
+            .await;
+
+            let rate_limited = chunks
+                .iter()
+                .flatten()
+                .filter(|chunk| {
+                    matches!(
+                        &chunk.as_ref().unwrap().kind,
+                        Some(sdk_proto::invoke_function_chunk::Kind::Error(error))
+                            if error.code == sdk_proto::CallbackErrorCode::RateLimited as i32
+                    )
+                })
+                .count();
+            assert_eq!(rate_limited, 1);
+            assert_eq!(peak.load(std::sync::atomic::Ordering::SeqCst), 1);
+        }
+
+        fn provider_with_validated_output(output: &'static str) -> ProviderController {
+            let mut provider = provider_with_function(output, None);
+            provider.functions[0].output =
+                r#"{"type":"object","properties":{"count":{"type":"integer"}},"required":["count"]}"#
+                    .to_string();
+            provider.functions[0].validate_output = true;
+            provider
+        }
+
+        #[tokio::test]
+        async fn test_invoke_function_accepts_output_matching_schema() {
+            shared::setup_test!();
+
+            let service = GrpcService::new(
+                vec![provider_with_validated_output(r#"{"count":3}"#)],
+                vec![],
+                NoopCodeGenerator,
+            );
+
+            let response = service
+                .invoke_function(invoke_request())
+                .await
+                .unwrap()
+                .into_inner();
+
+            match response.kind {
+                Some(sdk_proto::invoke_function_response::Kind::Data(data)) => {
+                    assert_eq!(data, r#"{"count":3}"#);
+                }
+                other => panic!("Expected data response, got {other:?}"),
+            }
+        }
+
+        #[tokio::test]
+        async fn test_invoke_function_rejects_output_not_matching_schema() {
+            shared::setup_test!();
+
+            let service = GrpcService::new(
+                vec![provider_with_validated_output(r#"{"count":"three"}"#)],
+                vec![],
+                NoopCodeGenerator,
+            );
+
+            let response = service
+                .invoke_function(invoke_request())
+                .await
+                .unwrap()
+                .into_inner();
+
+            match response.kind {
+                Some(sdk_proto::invoke_function_response::Kind::Error(error)) => {
+                    assert_eq!(
+                        error.code,
+                        sdk_proto::CallbackErrorCode::InvalidArgument as i32
+                    );
+                    assert!(error.message.contains("/count"), "{}", error.message);
+                }
+                other => panic!("Expected error response, got {other:?}"),
+            }
+        }
+
+        #[test]
+        fn test_add_function_rejects_invalid_output_schema_when_validating() {
+            shared::setup_test!();
+
+            let mut provider = provider_with_function("", None);
+            let mut function = provider.functions.remove(0);
+            let service = GrpcService::new(vec![provider], vec![], NoopCodeGenerator);
+            function.output = r#"{"type":12}"#.to_string();
+
+            function.validate_output = true;
+            assert!(
+                service
+                    .add_function("test-provider", function.clone())
+                    .is_err()
+            );
+            assert!(
+                service
+                    .get_provider("test-provider")
+                    .unwrap()
+                    .functions
+                    .is_empty()
+            );
+
+            // The schema is only compiled for functions that validate their output
+            function.validate_output = false;
+            assert!(service.add_function("test-provider", function).unwrap());
+        }
+
+        #[test]
+        fn test_list_providers_with_functions_snapshots_all_providers() {
+            let mut second = provider_with_function("", None);
+            second.type_id = "second-provider".to_string();
+            second.name = "Second Provider".to_string();
+            second.functions[0].name = "second-function".to_string();
+            second.functions[0].parameters =
+                r#"{"type":"object","properties":{"q":{"type":"string"}}}"#.to_string();
+            let service = GrpcService::new(
+                vec![provider_with_function("", None), second],
+                vec![],
+                NoopCodeGenerator,
+            );
+
+            let snapshot = service.list_providers_with_functions();
+
+            assert_eq!(
+                snapshot,
+                vec![
+                    ProviderWithFunctions {
+                        type_id: "test-provider".to_string(),
+                        name: "Test Provider".to_string(),
+                        documentation: String::new(),
+                        categories: vec![],
+                        functions: vec![FunctionSchema {
+                            name: "test-function".to_string(),
+                            description: String::new(),
+                            parameters: "{}".to_string(),
+                            output: "{}".to_string(),
+                        }],
+                    },
+                    ProviderWithFunctions {
+                        type_id: "second-provider".to_string(),
+                        name: "Second Provider".to_string(),
+                        documentation: String::new(),
+                        categories: vec![],
+                        functions: vec![FunctionSchema {
+                            name: "second-function".to_string(),
+                            description: String::new(),
+                            parameters: r#"{"type":"object","properties":{"q":{"type":"string"}}}"#
+                                .to_string(),
+                            output: "{}".to_string(),
+                        }],
+                    },
+                ]
+            );
+
+            // The snapshot is owned; later registry changes don't affect it
+            service.remove_provider("second-provider");
+            assert_eq!(snapshot.len(), 2);
+            assert_eq!(service.list_providers_with_functions().len(), 1);
+        }
+
+        fn agent(id: &str) -> Agent {
+            Agent {
+                id: id.to_string(),
+                project_id: "test-project".to_string(),
+                name: id.to_string(),
+                description: String::new(),
+                allowed_functions: vec![],
+            }
+        }
+
+        fn agent_ids<G: SdkCodeGenerator>(service: &GrpcService<G>) -> Vec<String> {
+            service.agents.load().iter().map(|a| a.id.clone()).collect()
+        }
+
+        #[test]
+        fn test_add_agents_batch_installs_all_agents() {
+            let service = GrpcService::new(vec![], vec![agent("existing")], NoopCodeGenerator);
+
+            let conflicts = service.add_agents_batch(vec![agent("a"), agent("b")]);
+
+            assert!(conflicts.is_empty());
+            assert_eq!(agent_ids(&service), vec!["existing", "a", "b"]);
+        }
+
+        #[test]
+        fn test_add_agents_batch_reports_duplicates_without_applying() {
+            let service = GrpcService::new(vec![], vec![agent("existing")], NoopCodeGenerator);
+
+            let conflicts = service.add_agents_batch(vec![
+                agent("a"),
+                agent("existing"),
+                agent("b"),
+                agent("a"),
+            ]);
+
+            assert_eq!(conflicts, vec!["existing", "a"]);
+            assert_eq!(agent_ids(&service), vec!["existing"]);
+        }
+
+        #[test]
+        fn test_remove_agent_reports_whether_an_agent_was_removed() {
+            let service = GrpcService::new(vec![], vec![], NoopCodeGenerator);
+            service.add_agent(agent("a")).unwrap();
+
+            assert!(service.remove_agent("a"));
+            assert!(agent_ids(&service).is_empty());
+            assert!(!service.remove_agent("missing"));
+        }
+
+        #[tokio::test]
+        async fn test_shutdown_removes_socket_and_frees_path() {
+            shared::setup_test!();
+
+            let dir = tempfile::tempdir().unwrap();
+            let socket_path = dir.path().join("sdk.sock");
+
+            let (_service, handle) =
+                start_grpc_server(vec![], socket_path.clone(), NoopCodeGenerator)
+                    .await
+                    .unwrap();
+            assert!(socket_path.exists());
+
+            handle.shutdown().await.unwrap();
+            assert!(!socket_path.exists());
+
+            // The same path can be bound again once the first server is gone
+            let (_service, handle) =
+                start_grpc_server(vec![], socket_path.clone(), NoopCodeGenerator)
+                    .await
+                    .unwrap();
+            assert!(socket_path.exists());
+            handle.shutdown().await.unwrap();
+        }
+
+        fn project_agent(id: &str, project_id: &str, name: &str) -> Agent {
+            Agent {
+                project_id: project_id.to_string(),
+                name: name.to_string(),
+                ..agent(id)
+            }
+        }
+
+        #[test]
+        fn test_list_agents_by_project_filters_on_project() {
+            let service = GrpcService::new(vec![], vec![], NoopCodeGenerator);
+            service
+                .add_agent(project_agent("a", "project-1", "support"))
+                .unwrap();
+            service
+                .add_agent(project_agent("b", "project-1", "billing"))
+                .unwrap();
+            service
+                .add_agent(project_agent("c", "project-2", "support"))
+                .unwrap();
+
+            let ids = |agents: Vec<Agent>| agents.into_iter().map(|a| a.id).collect::<Vec<_>>();
+            assert_eq!(
+                ids(service.list_agents_by_project("project-1")),
+                vec!["a", "b"]
+            );
+            assert_eq!(ids(service.list_agents_by_project("project-2")), vec!["c"]);
+            assert!(service.list_agents_by_project("project-3").is_empty());
+        }
+
+        #[test]
+        fn test_add_agent_rejects_duplicate_name_in_project() {
+            let service = GrpcService::new(vec![], vec![], NoopCodeGenerator);
+            service
+                .add_agent(project_agent("a", "project-1", "support"))
+                .unwrap();
+
+            let result = service.add_agent(project_agent("b", "project-1", "support"));
+
+            assert!(matches!(result, Err(CommonError::InvalidRequest { .. })));
+            assert_eq!(agent_ids(&service), vec!["a"]);
+        }
+
+        fn counting_provider(
+            calls: Arc<std::sync::atomic::AtomicUsize>,
+            ttl: Duration,
+        ) -> ProviderController {
+            let mut provider = provider_with_function("", None);
+            provider.functions[0].result_cache = Some(FunctionResultCache::new(ttl));
+            provider.functions[0].invoke = Arc::new(move |_req| {
+                let calls = Arc::clone(&calls);
+                Box::pin(async move {
+                    let n = calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst) + 1;
+                    Ok(InvokeFunctionResponse {
+                        result: Ok(format!("call-{n}")),
+                        logs: vec![],
+                    })
+                })
+            });
+            provider
+        }
+
+        async fn invoke_data<G: SdkCodeGenerator>(
+            service: &GrpcService<G>,
+            request: Request<sdk_proto::InvokeFunctionRequest>,
+        ) -> String {
+            match service
+                .invoke_function(request)
+                .await
+                .unwrap()
+                .into_inner()
+                .kind
+            {
+                Some(sdk_proto::invoke_function_response::Kind::Data(data)) => data,
+                other => panic!("Expected data response, got {other:?}"),
+            }
+        }
+
+        #[tokio::test(start_paused = true)]
+        async fn test_cached_function_invokes_callback_once_within_ttl() {
+            shared::setup_test!();
+
+            let calls = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+            let service = GrpcService::new(
+                vec![counting_provider(calls.clone(), Duration::from_secs(60))],
+                vec![],
+                NoopCodeGenerator,
+            );
+
+            assert_eq!(invoke_data(&service, invoke_request()).await, "call-1");
+            assert_eq!(invoke_data(&service, invoke_request()).await, "call-1");
+            assert_eq!(calls.load(std::sync::atomic::Ordering::SeqCst), 1);
+
+            // Different parameters miss the cache
+            let mut other = invoke_request();
+            other.get_mut().parameters = "{\"currency\":\"EUR\"}".to_string();
+            assert_eq!(invoke_data(&service, other).await, "call-2");
+
+            // Entries expire after the TTL
+            tokio::time::advance(Duration::from_secs(61)).await;
+            assert_eq!(invoke_data(&service, invoke_request()).await, "call-3");
+        }
+
+        #[tokio::test]
+        async fn test_cached_function_can_be_bypassed() {
+            shared::setup_test!();
+
+            let calls = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+            let service = GrpcService::new(
+                vec![counting_provider(calls.clone(), Duration::from_secs(60))],
+                vec![],
+                NoopCodeGenerator,
+            );
+
+            assert_eq!(invoke_data(&service, invoke_request()).await, "call-1");
+            let mut bypass = invoke_request();
+            bypass.get_mut().bypass_cache = true;
+            assert_eq!(invoke_data(&service, bypass).await, "call-2");
+        }
+
+        #[test]
+        fn test_cache_key_covers_every_field_and_ignores_json_key_order() {
+            shared::setup_test!();
+
+            let request = invoke_request().into_inner();
+            let request: InvokeFunctionRequest = request.try_into().unwrap();
+            let key = FunctionResultCache::cache_key(&request);
+
+            let mut reordered = request.clone();
+            reordered.parameters = "{\"b\":2,\"a\":1}".to_string();
+            let mut ordered = request.clone();
+            ordered.parameters = "{\"a\":1,\"b\":2}".to_string();
+            assert_eq!(
+                FunctionResultCache::cache_key(&reordered),
+                FunctionResultCache::cache_key(&ordered)
+            );
+
+            let mut other_function = request.clone();
+            other_function.function_controller_type_id.push_str("-v2");
+            let mut other_credentials = request.clone();
+            other_credentials.credentials.push_str(" ");
+            for changed in [other_function, other_credentials] {
+                assert_ne!(FunctionResultCache::cache_key(&changed), key);
+            }
+
+            // Moving a byte between adjacent fields must not produce the same key
+            let mut provider_suffix = request.clone();
+            provider_suffix.provider_controller_type_id.push('-');
+            let mut function_prefix = request.clone();
+            function_prefix.function_controller_type_id =
+                format!("-{}", request.function_controller_type_id);
+            assert_ne!(
+                FunctionResultCache::cache_key(&provider_suffix),
+                FunctionResultCache::cache_key(&function_prefix)
+            );
+        }
+
+        #[tokio::test(start_paused = true)]
+        async fn test_result_cache_evicts_least_recently_used_entry() {
+            shared::setup_test!();
+
+            let cache = FunctionResultCache::with_capacity(Duration::from_secs(60), 2);
+            let [a, b, c] = [[1u8; 32], [2u8; 32], [3u8; 32]];
+
+            cache.insert(a, "a".to_string());
+            cache.insert(b, "b".to_string());
+            // Reading `a` makes `b` the least recently used entry
+            assert_eq!(cache.get(a).as_deref(), Some("a"));
+            cache.insert(c, "c".to_string());
+
+            assert_eq!(cache.get(a).as_deref(), Some("a"));
+            assert_eq!(cache.get(b), None);
+            assert_eq!(cache.get(c).as_deref(), Some("c"));
+        }
+
+        #[test]
+        fn test_deprecation_metadata_round_trips_to_proto() {
+            let mut provider = provider_with_function("", None);
+            provider.functions[0].deprecated = true;
+            provider.functions[0].deprecation_message = Some("Use test-function-v2".to_string());
+
+            let proto: sdk_proto::ProviderController = (&provider).into();
+            assert!(proto.functions[0].deprecated);
+            assert_eq!(
+                proto.functions[0].deprecation_message,
+                "Use test-function-v2"
+            );
+
+            let not_deprecated: sdk_proto::FunctionController =
+                (&provider_with_function("", None).functions[0]).into();
+            assert!(!not_deprecated.deprecated);
+            assert!(not_deprecated.deprecation_message.is_empty());
+        }
+
+        #[tokio::test]
+        async fn test_function_schemas_survive_provider_update_in_metadata() {
+            let mut provider = provider_with_function("", None);
+            let function = function_with_examples(vec![]);
+            provider.functions.clear();
+
+            let service = GrpcService::new(vec![provider.clone()], vec![], NoopCodeGenerator);
+            assert!(
+                service
+                    .add_function("test-provider", function.clone())
+                    .unwrap()
+            );
+
+            // Bindings pass providers without functions; the registered ones must stay
+            provider.name = "Renamed Provider".to_string();
+            assert!(service.update_provider_keeping_functions(provider));
+
+            let metadata = service
+                .metadata(Request::new(()))
+                .await
+                .unwrap()
+                .into_inner();
+            assert_eq!(metadata.mcp_providers.len(), 1);
+            let proto_provider = &metadata.mcp_providers[0];
+            assert_eq!(proto_provider.name, "Renamed Provider");
+            assert_eq!(proto_provider.functions.len(), 1);
+            let proto_function = &proto_provider.functions[0];
+            assert_eq!(proto_function.name, function.name);
+            assert_eq!(proto_function.description, function.description);
+            assert_eq!(proto_function.parameters, function.parameters);
+            assert_eq!(proto_function.output, function.output);
+        }
+
+        #[tokio::test]
+        async fn test_function_stays_invocable_across_reconcile_that_keeps_it() {
+            shared::setup_test!();
+
+            let service = GrpcService::new(
+                vec![provider_with_function("{\"v\":1}", None)],
+                vec![],
+                NoopCodeGenerator,
+            );
+            let mut second = provider_with_function("", None);
+            second.type_id = "second-provider".to_string();
+
+            let (summary, responses) = tokio::join!(
+                async {
+                    service.reconcile_providers(vec![
+                        provider_with_function("{\"v\":2}", None),
+                        second.clone(),
+                    ])
+                },
+                futures::future::join_all(
+                    (0..16).map(|_| service.invoke_function(invoke_request()))
+                ),
+            );
+
+            assert_eq!(
+                summary,
+                ProviderReconcileSummary {
+                    added: vec!["second-provider".to_string()],
+                    updated: vec!["test-provider".to_string()],
+                    removed: vec![],
+                }
+            );
+            for response in responses {
+                match response.unwrap().into_inner().kind {
+                    Some(sdk_proto::invoke_function_response::Kind::Data(_)) => {}
+                    other => panic!("Expected data response, got {other:?}"),
+                }
+            }
+            match service
+                .invoke_function(invoke_request())
+                .await
+                .unwrap()
+                .into_inner()
+                .kind
+            {
+                Some(sdk_proto::invoke_function_response::Kind::Data(data)) => {
+                    assert_eq!(data, "{\"v\":2}");
+                }
+                other => panic!("Expected data response, got {other:?}"),
+            }
+
+            let summary = service.reconcile_providers(vec![second]);
+            assert_eq!(summary.removed, vec!["test-provider".to_string()]);
+            assert_eq!(summary.updated, vec!["second-provider".to_string()]);
+            assert!(summary.added.is_empty());
+            assert!(service.get_provider("test-provider").is_none());
+        }
+
+        #[test]
+        fn test_reconcile_keeping_functions_keeps_registered_functions() {
+            shared::setup_test!();
+
+            let mut provider = provider_with_function("", None);
+            let function = provider.functions.remove(0);
+            let service = GrpcService::new(vec![provider.clone()], vec![], NoopCodeGenerator);
+            assert!(
+                service
+                    .add_function("test-provider", function.clone())
+                    .unwrap()
+            );
+            let mut stale = provider.clone();
+            stale.type_id = "stale-provider".to_string();
+            service.add_provider(stale);
+
+            provider.name = "Renamed Provider".to_string();
+            let summary = service.reconcile_providers_keeping_functions(vec![provider]);
+            assert_eq!(summary.updated, vec!["test-provider".to_string()]);
+            assert_eq!(summary.removed, vec!["stale-provider".to_string()]);
+
+            let kept = service.get_provider("test-provider").unwrap();
+            assert_eq!(kept.name, "Renamed Provider");
+            assert_eq!(kept.functions.len(), 1);
+            assert_eq!(kept.functions[0].name, function.name);
+            assert!(service.get_provider("stale-provider").is_none());
+        }
+
+        fn function_with_examples(examples: Vec<FunctionExample>) -> FunctionController {
+            let mut function = provider_with_function("", None).functions.remove(0);
+            function.parameters =
+                r#"{"type":"object","required":["to"],"properties":{"to":{"type":"string"}}}"#
+                    .to_string();
+            function.output =
+                r#"{"type":"object","properties":{"id":{"type":"string"}}}"#.to_string();
+            function.examples = examples;
+            function
+        }
+
+        #[test]
+        fn test_examples_round_trip_to_proto() {
+            let example = FunctionExample {
+                description: Some("Send to a single recipient".to_string()),
+                parameters: r#"{"to":"a@example.com"}"#.to_string(),
+                output: Some(r#"{"id":"msg-1"}"#.to_string()),
+            };
+            let function = function_with_examples(vec![example.clone()]);
+            assert!(function.validate_examples().is_ok());
+
+            let proto: sdk_proto::FunctionController = (&function).into();
+            assert_eq!(proto.examples.len(), 1);
+            assert_eq!(FunctionExample::from(proto.examples[0].clone()), example);
+        }
+
+        #[test]
+        fn test_invalid_examples_are_rejected() {
+            let missing_required = function_with_examples(vec![FunctionExample {
+                description: None,
+                parameters: r#"{"subject":"hi"}"#.to_string(),
+                output: None,
+            }]);
+            assert!(matches!(
+                missing_required.validate_examples(),
+                Err(CommonError::InvalidRequest { .. })
+            ));
+
+            let wrong_output = function_with_examples(vec![FunctionExample {
+                description: None,
+                parameters: r#"{"to":"a@example.com"}"#.to_string(),
+                output: Some(r#"{"id":42}"#.to_string()),
+            }]);
+            assert!(wrong_output.validate_examples().is_err());
+
+            let not_json = function_with_examples(vec![FunctionExample {
+                description: None,
+                parameters: "{to:".to_string(),
+                output: None,
+            }]);
+            assert!(not_json.validate_examples().is_err());
+
+            // Full JSON Schema, including references
+            let mut wrong_reference = function_with_examples(vec![FunctionExample {
+                description: None,
+                parameters: r#"{"to":42}"#.to_string(),
+                output: None,
+            }]);
+            wrong_reference.parameters = r##"{"$defs":{"address":{"type":"string"}},"type":"object","properties":{"to":{"$ref":"#/$defs/address"}}}"##.to_string();
+            assert!(wrong_reference.validate_examples().is_err());
+        }
+
+        #[tokio::test]
+        async fn test_deprecated_function_is_still_invoked() {
+            shared::setup_test!();
+
+            let mut provider = provider_with_function("ok", None);
+            provider.functions[0].deprecated = true;
+            let service = GrpcService::new(vec![provider], vec![], NoopCodeGenerator);
+
+            assert_eq!(invoke_data(&service, invoke_request()).await, "ok");
+        }
+
+        fn recording_secret_handler(
+            received: Arc<std::sync::Mutex<Vec<String>>>,
+            name: &'static str,
+        ) -> SecretHandler {
+            Arc::new(move |secrets| {
+                let received = Arc::clone(&received);
+                Box::pin(async move {
+                    let mut received = received.lock().unwrap();
+                    for secret in secrets {
+                        received.push(format!("{name}:{}", secret.key));
+                    }
+                    Ok(SetSecretsResponse {
+                        result: Ok(SetSecretsSuccess {
+                            message: "ok".to_string(),
+                            outcomes: vec![],
+                        }),
+                    })
+                })
+            })
+        }
+
+        #[tokio::test]
+        async fn test_set_secrets_routes_to_provider_scoped_handler() {
+            shared::setup_test!();
+
+            let received = Arc::new(std::sync::Mutex::new(Vec::new()));
+            let service = GrpcService::new(vec![], vec![], NoopCodeGenerator);
+            service.set_secret_handler(recording_secret_handler(received.clone(), "global"));
+            service.set_secret_handler_for(
+                "stripe",
+                recording_secret_handler(received.clone(), "stripe"),
+            );
+            service.set_secret_handler_for(
+                "gmail",
+                recording_secret_handler(received.clone(), "gmail"),
+            );
+
+            for (key, provider_scope) in [
+                ("STRIPE_KEY", Some("stripe")),
+                ("GMAIL_KEY", Some("gmail")),
+                ("UNSCOPED_KEY", None),
+                ("OTHER_KEY", Some("unregistered")),
+            ] {
+                let response = service
+                    .set_secrets(Request::new(sdk_proto::SetSecretsRequest {
+                        secrets: vec![sdk_proto::Secret {
+                            key: key.to_string(),
+                            value: "value".to_string(),
+                        }],
+                        provider_scope: provider_scope.map(str::to_string),
+                    }))
+                    .await
+                    .unwrap()
+                    .into_inner();
+                assert!(matches!(
+                    response.kind,
+                    Some(sdk_proto::set_secrets_response::Kind::Data(_))
+                ));
+            }
+
+            assert_eq!(
+                *received.lock().unwrap(),
+                vec![
+                    "stripe:STRIPE_KEY",
+                    "gmail:GMAIL_KEY",
+                    "global:UNSCOPED_KEY",
+                    "global:OTHER_KEY",
+                ]
+            );
+        }
+
+        #[tokio::test]
+        async fn test_set_secrets_reports_per_key_outcomes() {
+            shared::setup_test!();
+
+            let service = GrpcService::new(vec![], vec![], NoopCodeGenerator);
+            service.set_secret_handler(Arc::new(|_secrets| {
+                Box::pin(async move {
+                    Ok(SetSecretsResponse {
+                        result: Ok(SetSecretsSuccess {
+                            message: "partially applied".to_string(),
+                            outcomes: vec![
+                                KeyOutcome::failed("BROKEN", "invalid value"),
+                                KeyOutcome::skipped("READONLY", "already set by host"),
+                                KeyOutcome::applied("NOT_IN_BATCH"),
+                            ],
+                        }),
+                    })
+                })
+            }));
+
+            let secrets = ["OK", "READONLY", "BROKEN"]
+                .into_iter()
+                .map(|key| sdk_proto::Secret {
+                    key: key.to_string(),
+                    value: "value".to_string(),
+                })
+                .collect();
+            let response = service
+                .set_secrets(Request::new(sdk_proto::SetSecretsRequest {
+                    secrets,
+                    provider_scope: None,
+                }))
+                .await
+                .unwrap()
+                .into_inner();
+
+            let Some(sdk_proto::set_secrets_response::Kind::Data(data)) = response.kind else {
+                panic!("expected success response");
+            };
+            let outcomes: Vec<_> = data
+                .outcomes
+                .iter()
+                .map(|o| (o.key.as_str(), o.status(), o.reason.as_str()))
+                .collect();
+            assert_eq!(
+                outcomes,
+                vec![
+                    ("OK", sdk_proto::KeyOutcomeStatus::Applied, ""),
+                    (
+                        "READONLY",
+                        sdk_proto::KeyOutcomeStatus::Skipped,
+                        "already set by host"
+                    ),
+                    (
+                        "BROKEN",
+                        sdk_proto::KeyOutcomeStatus::Failed,
+                        "invalid value"
+                    ),
+                ]
+            );
+        }
+
+        #[tokio::test]
+        async fn test_set_environment_variables_defaults_to_applied() {
+            shared::setup_test!();
+
+            let service = GrpcService::new(vec![], vec![], NoopCodeGenerator);
+            service.set_environment_variable_handler(Arc::new(|_env_vars| {
+                Box::pin(async move {
+                    Ok(SetEnvironmentVariablesResponse {
+                        result: Ok(SetEnvironmentVariablesSuccess {
+                            message: "ok".to_string(),
+                            outcomes: vec![],
+                        }),
+                    })
+                })
+            }));
+
+            let environment_variables = ["A", "B"]
+                .into_iter()
+                .map(|key| sdk_proto::EnvironmentVariable {
+                    key: key.to_string(),
+                    value: "value".to_string(),
+                })
+                .collect();
+            let response = service
+                .set_environment_variables(Request::new(
+                    sdk_proto::SetEnvironmentVariablesRequest {
+                        environment_variables,
+                    },
+                ))
+                .await
+                .unwrap()
+                .into_inner();
+
+            let Some(sdk_proto::set_environment_variables_response::Kind::Data(data)) =
+                response.kind
+            else {
+                panic!("expected success response");
+            };
+            assert_eq!(data.outcomes.len(), 2);
+            assert!(
+                data.outcomes
+                    .iter()
+                    .all(|o| o.status() == sdk_proto::KeyOutcomeStatus::Applied)
+            );
+        }
+
+        async fn collect_stream_data(
+            mut stream: tonic::Streaming<sdk_proto::InvokeFunctionChunk>,
+        ) -> Vec<String> {
+            let mut data = vec![];
+            while let Some(chunk) = stream.message().await.unwrap() {
+                match chunk.kind {
+                    Some(sdk_proto::invoke_function_chunk::Kind::Data(chunk)) => data.push(chunk),
+                    other => panic!("Expected data chunk, got {other:?}"),
+                }
+            }
+            data
+        }
+
+        #[tokio::test]
+        async fn test_invoke_function_stream_over_unix_socket_preserves_order() {
+            shared::setup_test!();
+
+            let mut provider = provider_with_function("", None);
+            provider.functions[0].invoke_stream = Some(Arc::new(|_req, chunks| {
+                Box::pin(async move {
+                    for page in 0..5 {
+                        // Yield between pages so chunks interleave with the forwarding task
+                        tokio::task::yield_now().await;
+                        chunks
+                            .send(InvokeFunctionChunk {
+                                result: Ok(format!("page-{page}")),
+                            })
+                            .await
+                            .map_err(|e| CommonError::Unknown(anyhow::anyhow!("{e}")))?;
+                    }
+                    Ok(())
+                })
+            }));
+            let service = Arc::new(GrpcService::new(vec![provider], vec![], NoopCodeGenerator));
+
+            let socket_path = std::env::temp_dir()
+                .join(format!("soma-sdk-core-stream-{}.sock", std::process::id()));
+            let uds = bind_unix_listener(&socket_path).await.unwrap();
+            let server = tokio::spawn(
+                Server::builder()
+                    .add_service(sdk_service_server(service, true))
+                    .serve_with_incoming(create_listener_stream(uds)),
+            );
+
+            let mut client =
+                shared::uds::create_soma_unix_socket_client(socket_path.to_str().unwrap())
+                    .await
+                    .unwrap();
+            let stream = client
+                .invoke_function_stream(invoke_request().into_inner())
+                .await
+                .unwrap()
+                .into_inner();
+
+            assert_eq!(
+                collect_stream_data(stream).await,
+                vec!["page-0", "page-1", "page-2", "page-3", "page-4"]
+            );
+
+            server.abort();
+            let _ = std::fs::remove_file(&socket_path);
+        }
+
+        #[tokio::test]
+        async fn test_metadata_is_identical_with_and_without_compression() {
+            shared::setup_test!();
+
+            // A schema large enough that compression makes a difference on the wire
+            let properties: serde_json::Map<String, serde_json::Value> = (0..2_000)
+                .map(|i| {
+                    (
+                        format!("field_{i}"),
+                        serde_json::json!({ "type": "string", "description": "a field" }),
+                    )
+                })
+                .collect();
+            let parameters =
+                serde_json::json!({ "type": "object", "properties": properties }).to_string();
+            let mut provider = provider_with_function("", None);
+            provider.functions[0].parameters = parameters.clone();
+            let service = Arc::new(GrpcService::new(vec![provider], vec![], NoopCodeGenerator));
+
+            // One server/client pair compresses in both directions, the other in neither, so the
+            // uncompressed metadata response never goes through gzip
+            let dir = tempfile::tempdir().unwrap();
+            let mut responses = vec![];
+            for compression in [true, false] {
+                let socket_path = dir.path().join(format!("sdk-{compression}.sock"));
+                let uds = bind_unix_listener(&socket_path).await.unwrap();
+                let server = tokio::spawn(
+                    Server::builder()
+                        .add_service(sdk_service_server(service.clone(), compression))
+                        .serve_with_incoming(create_listener_stream(uds)),
+                );
+
+                let mut client = shared::uds::create_soma_unix_socket_client_with_compression(
+                    socket_path.to_str().unwrap(),
+                    compression,
+                )
+                .await
+                .unwrap();
+                responses.push(client.metadata(()).await.unwrap().into_inner());
+
+                server.abort();
+            }
+
+            let [compressed, uncompressed] = <[_; 2]>::try_from(responses).unwrap();
+            assert_eq!(compressed, uncompressed);
+            assert_eq!(
+                compressed.mcp_providers[0].functions[0].parameters,
+                parameters
+            );
+        }
+
+        #[tokio::test]
+        async fn test_invoke_function_stream_falls_back_to_unary_invoke() {
+            shared::setup_test!();
+
+            let service = GrpcService::new(
+                vec![provider_with_function("{\"ok\":true}", None)],
+                vec![],
+                NoopCodeGenerator,
+            );
+
+            let mut stream = service
+                .invoke_function_stream(invoke_request())
+                .await
+                .unwrap()
+                .into_inner();
+
+            let chunk = tokio_stream::StreamExt::next(&mut stream)
+                .await
+                .unwrap()
+                .unwrap();
+            assert!(matches!(
+                chunk.kind,
+                Some(sdk_proto::invoke_function_chunk::Kind::Data(data)) if data == "{\"ok\":true}"
+            ));
+            assert!(tokio_stream::StreamExt::next(&mut stream).await.is_none());
+        }
+
+        #[tokio::test]
+        async fn test_invoke_function_stream_reports_function_failure() {
+            shared::setup_test!();
+
+            let mut provider = provider_with_function("", None);
+            provider.functions[0].invoke_stream = Some(Arc::new(|_req, chunks| {
+                Box::pin(async move {
+                    let _ = chunks
+                        .send(InvokeFunctionChunk {
+                            result: Ok("partial".to_string()),
+                        })
+                        .await;
+                    Err(CommonError::Unknown(anyhow::anyhow!("upstream went away")))
+                })
+            }));
+            let service = GrpcService::new(vec![provider], vec![], NoopCodeGenerator);
+
+            let mut stream = service
+                .invoke_function_stream(invoke_request())
+                .await
+                .unwrap()
+                .into_inner();
+
+            let first = tokio_stream::StreamExt::next(&mut stream).await.unwrap();
+            assert!(first.is_ok());
+            let second = tokio_stream::StreamExt::next(&mut stream).await.unwrap();
+            assert_eq!(second.unwrap_err().code(), tonic::Code::Internal);
+        }
+
+        #[test]
+        fn test_truncate_function_output_respects_char_boundaries() {
+            // "é" is two bytes, so a 3 byte limit must not split the second character
+            let truncated = truncate_function_output("éé", 3).unwrap();
+            assert_eq!(truncated, format!("é{OUTPUT_TRUNCATION_MARKER}"));
+            assert!(truncate_function_output("éé", 4).is_none());
+        }
+
+        /// Serves one canned HTTP response per connection, in order
+        async fn serve_responses(
+            statuses: Vec<u16>,
+        ) -> (String, Arc<std::sync::atomic::AtomicU32>) {
+            use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+            let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+            let base_url = format!("http://{}", listener.local_addr().unwrap());
+            let requests = Arc::new(std::sync::atomic::AtomicU32::new(0));
+            let counter = requests.clone();
+            tokio::spawn(async move {
+                for status in statuses {
+                    let (mut socket, _) = listener.accept().await.unwrap();
+                    let mut buf = [0u8; 4096];
+                    let _ = socket.read(&mut buf).await.unwrap();
+                    counter.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                    let response = format!(
+                        "HTTP/1.1 {status} X\r\ncontent-type: application/json\r\ncontent-length: 2\r\nconnection: close\r\n\r\n{{}}"
+                    );
+                    socket.write_all(response.as_bytes()).await.unwrap();
+                    socket.shutdown().await.unwrap();
+                }
+            });
+            (base_url, requests)
+        }
+
+        fn fast_resync_backoff(max_attempts: u32) -> ResyncBackoff {
+            ResyncBackoff {
+                initial_delay: Duration::from_millis(1),
+                max_delay: Duration::from_millis(5),
+                jitter: 0.0,
+                max_attempts,
+            }
+        }
+
+        #[tokio::test]
+        async fn test_resync_retries_until_server_is_up() {
+            let (base_url, requests) = serve_responses(vec![503, 503, 200]).await;
+
+            let result = resync_sdk_with_backoff(Some(base_url), &fast_resync_backoff(5)).await;
+
+            assert!(result.is_ok(), "{result:?}");
+            assert_eq!(requests.load(std::sync::atomic::Ordering::SeqCst), 3);
+        }
+
+        #[tokio::test]
+        async fn test_resync_times_out_after_max_attempts() {
+            let (base_url, requests) = serve_responses(vec![503, 503, 503]).await;
+
+            let error = resync_sdk_with_backoff(Some(base_url), &fast_resync_backoff(3))
+                .await
+                .unwrap_err();
+
+            assert_eq!(requests.load(std::sync::atomic::Ordering::SeqCst), 3);
+            let CommonError::Unknown(error) = error else {
+                panic!("expected an unknown error, got {error:?}");
+            };
+            assert!(matches!(
+                error.downcast_ref::<ResyncError>(),
+                Some(ResyncError::Timeout { attempts: 3, .. })
+            ));
+        }
+    }
 }
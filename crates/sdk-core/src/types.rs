@@ -1,7 +1,14 @@
 use futures::future::BoxFuture;
+use once_cell::sync::Lazy;
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use shared::error::CommonError;
-use std::sync::Arc;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::Duration;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore, mpsc};
+use tokio::time::Instant;
 
 #[derive(Clone)]
 pub struct Agent {
@@ -9,6 +16,9 @@ pub struct Agent {
     pub project_id: String,
     pub name: String,
     pub description: String,
+    /// Provider functions the agent may invoke, as
+    /// `provider_controller_type_id.function_controller_type_id`. Empty allows every function.
+    pub allowed_functions: Vec<String>,
 }
 
 #[derive(Clone)]
@@ -19,6 +29,58 @@ pub struct ProviderController {
     pub categories: Vec<String>,
     pub functions: Vec<FunctionController>,
     pub credential_controllers: Vec<ProviderCredentialController>,
+    /// Caps how many of this provider's functions run at once. `None` runs every invocation
+    /// immediately.
+    pub concurrency_limit: Option<ProviderConcurrencyLimit>,
+}
+
+/// Owned snapshot of a registered provider and the schemas of its functions, as returned by
+/// [`crate::GrpcService::list_providers_with_functions`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProviderWithFunctions {
+    pub type_id: String,
+    pub name: String,
+    pub documentation: String,
+    pub categories: Vec<String>,
+    pub functions: Vec<FunctionSchema>,
+}
+
+/// Provider type ids changed by [`crate::GrpcService::reconcile_providers`]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ProviderReconcileSummary {
+    pub added: Vec<String>,
+    pub updated: Vec<String>,
+    pub removed: Vec<String>,
+}
+
+/// A function's JSON parameter and output schemas
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FunctionSchema {
+    pub name: String,
+    pub description: String,
+    pub parameters: String,
+    pub output: String,
+}
+
+impl From<&ProviderController> for ProviderWithFunctions {
+    fn from(provider: &ProviderController) -> Self {
+        Self {
+            type_id: provider.type_id.clone(),
+            name: provider.name.clone(),
+            documentation: provider.documentation.clone(),
+            categories: provider.categories.clone(),
+            functions: provider
+                .functions
+                .iter()
+                .map(|function| FunctionSchema {
+                    name: function.name.clone(),
+                    description: function.description.clone(),
+                    parameters: function.parameters.clone(),
+                    output: function.output.clone(),
+                })
+                .collect(),
+        }
+    }
 }
 
 #[derive(Clone)]
@@ -27,6 +89,26 @@ pub struct FunctionController {
     pub description: String,
     pub parameters: String,
     pub output: String,
+    /// Optional cap on the size of the output returned to the caller. Outputs larger than this
+    /// are truncated and suffixed with [`OUTPUT_TRUNCATION_MARKER`].
+    pub max_output_bytes: Option<usize>,
+    /// Result cache for functions explicitly marked idempotent. When set, identical calls
+    /// within the cache TTL are answered without invoking the function again.
+    pub result_cache: Option<FunctionResultCache>,
+    /// Deprecated functions can still be invoked but are flagged to clients, which should warn.
+    pub deprecated: bool,
+    pub deprecation_message: Option<String>,
+    /// Example invocations surfaced in function listings and generated client doc comments.
+    /// Checked against the parameter and output schemas by [`FunctionController::validate_examples`].
+    pub examples: Vec<FunctionExample>,
+    /// Invocations running longer than this many milliseconds fail with
+    /// [`CallbackErrorCode::DeadlineExceeded`]. `None` waits indefinitely.
+    pub timeout_ms: Option<u64>,
+    /// Check each invocation's returned data against the `output` schema, failing mismatches
+    /// with [`CallbackErrorCode::InvalidArgument`]
+    pub validate_output: bool,
+    /// The `output` schema compiled for [`FunctionController::check_output`]
+    pub output_validator: OutputValidator,
     pub invoke: Arc<
         dyn Fn(
                 InvokeFunctionRequest,
@@ -35,6 +117,128 @@ pub struct FunctionController {
             + Sync
             + 'static,
     >,
+    /// Streaming implementation used by `InvokeFunctionStream`. Functions without one are
+    /// streamed as a single chunk produced by `invoke`.
+    pub invoke_stream: Option<InvokeStreamFn>,
+}
+
+/// Sends partial results of an invocation through the channel, in order, and resolves once the
+/// last chunk has been sent
+pub type InvokeStreamFn = Arc<
+    dyn Fn(
+            InvokeFunctionRequest,
+            mpsc::Sender<InvokeFunctionChunk>,
+        ) -> BoxFuture<'static, Result<(), CommonError>>
+        + Send
+        + Sync
+        + 'static,
+>;
+
+/// A function's output schema, compiled once and shared by clones of the function
+#[derive(Clone, Default)]
+pub struct OutputValidator(Arc<OnceLock<Result<Arc<jsonschema::Validator>, String>>>);
+
+impl FunctionController {
+    /// Compiles the output schema used by [`Self::check_output`], failing if it is not a valid
+    /// JSON schema
+    pub fn compile_output_schema(&self) -> Result<Arc<jsonschema::Validator>, CommonError> {
+        self.output_validator
+            .0
+            .get_or_init(|| {
+                compile_example_schema(&self.output, "output schema")
+                    .map(Arc::new)
+                    .map_err(|e| format!("Function '{}': {e}", self.name))
+            })
+            .clone()
+            .map_err(|msg| CommonError::InvalidRequest { msg, source: None })
+    }
+
+    /// Checks that every example is valid JSON matching the function's parameter and output
+    /// schemas
+    pub fn validate_examples(&self) -> Result<(), CommonError> {
+        if self.examples.is_empty() {
+            return Ok(());
+        }
+
+        let parameters_validator = compile_example_schema(&self.parameters, "parameter schema")?;
+        let output_validator = compile_example_schema(&self.output, "output schema")?;
+
+        for (index, example) in self.examples.iter().enumerate() {
+            let parameters = parse_example_json(&example.parameters, "example parameters")?;
+            if let Some(error) = parameters_validator.iter_errors(&parameters).next() {
+                return Err(CommonError::InvalidRequest {
+                    msg: format!(
+                        "Example {index} of function '{}' has invalid parameters at '{}': {error}",
+                        self.name, error.instance_path
+                    ),
+                    source: None,
+                });
+            }
+
+            if let Some(output) = &example.output {
+                let output = parse_example_json(output, "example output")?;
+                if let Some(error) = output_validator.iter_errors(&output).next() {
+                    return Err(CommonError::InvalidRequest {
+                        msg: format!(
+                            "Example {index} of function '{}' has invalid output at '{}': {error}",
+                            self.name, error.instance_path
+                        ),
+                        source: None,
+                    });
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Validates data returned by an invocation against the function's output schema
+    pub fn check_output(&self, data: &str) -> Result<(), CallbackError> {
+        let validator = self
+            .compile_output_schema()
+            .map_err(|e| CallbackError::internal(e.to_string()))?;
+
+        let invalid_output = |message: String| CallbackError {
+            message,
+            code: Some(CallbackErrorCode::InvalidArgument),
+        };
+        let output: serde_json::Value = serde_json::from_str(data).map_err(|e| {
+            invalid_output(format!(
+                "Function '{}' returned output that is not valid JSON: {e}",
+                self.name
+            ))
+        })?;
+        match validator.iter_errors(&output).next() {
+            Some(error) => Err(invalid_output(format!(
+                "Function '{}' returned output that does not match its output schema at '{}': {error}",
+                self.name, error.instance_path
+            ))),
+            None => Ok(()),
+        }
+    }
+}
+
+fn parse_example_json(json: &str, what: &str) -> Result<serde_json::Value, CommonError> {
+    serde_json::from_str(json).map_err(|e| CommonError::InvalidRequest {
+        msg: format!("Invalid JSON in {what}: {e}"),
+        source: Some(e.into()),
+    })
+}
+
+fn compile_example_schema(schema: &str, what: &str) -> Result<jsonschema::Validator, CommonError> {
+    let schema = parse_example_json(schema, what)?;
+    jsonschema::validator_for(&schema).map_err(|e| CommonError::InvalidRequest {
+        msg: format!("Invalid {what}: {e}"),
+        source: None,
+    })
+}
+
+/// Example invocation of a function. `parameters` and `output` are JSON-encoded.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FunctionExample {
+    pub description: Option<String>,
+    pub parameters: String,
+    pub output: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -91,6 +295,12 @@ pub struct InvokeFunctionRequest {
     pub credential_controller_type_id: String,
     pub credentials: String,
     pub parameters: String,
+    /// Correlation id of the API server request that triggered this invocation.
+    pub request_id: Option<String>,
+    /// Skip the function's result cache (if any) and always invoke the function.
+    pub bypass_cache: bool,
+    /// Identifies this invocation to [`append_invocation_log`]
+    pub invocation_id: String,
 }
 
 #[derive(Debug, Clone)]
@@ -98,14 +308,484 @@ pub struct InvokeError {
     pub message: String,
 }
 
-#[derive(Debug, Clone)]
+/// Classifies a callback failure so the invoking side can tell retryable errors from permanent
+/// ones
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CallbackErrorCode {
+    /// Too many requests; `after_ms` is the suggested delay before retrying, if known
+    RateLimited {
+        after_ms: Option<u64>,
+    },
+    Unauthorized,
+    InvalidArgument,
+    Internal,
+    /// Transient failure; `after_ms` is the suggested delay before retrying, if known
+    Retryable {
+        after_ms: Option<u64>,
+    },
+    /// The function did not finish within its configured timeout
+    DeadlineExceeded,
+}
+
+impl CallbackErrorCode {
+    /// Parses the snake_case code names used by the language bindings. `retry_after_ms` is only
+    /// used by `rate_limited` and `retryable`.
+    pub fn parse(code: &str, retry_after_ms: Option<u64>) -> Option<Self> {
+        match code {
+            "rate_limited" => Some(CallbackErrorCode::RateLimited {
+                after_ms: retry_after_ms,
+            }),
+            "unauthorized" => Some(CallbackErrorCode::Unauthorized),
+            "invalid_argument" => Some(CallbackErrorCode::InvalidArgument),
+            "internal" => Some(CallbackErrorCode::Internal),
+            "retryable" => Some(CallbackErrorCode::Retryable {
+                after_ms: retry_after_ms,
+            }),
+            "deadline_exceeded" => Some(CallbackErrorCode::DeadlineExceeded),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct CallbackError {
     pub message: String,
+    /// `None` when the error came from an SDK that predates error codes
+    pub code: Option<CallbackErrorCode>,
+}
+
+impl CallbackError {
+    /// An error raised by the SDK bindings themselves rather than by the callback
+    pub fn internal(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            code: Some(CallbackErrorCode::Internal),
+        }
+    }
+
+    /// Builds an error from the `{ code, message }` shape returned by a language binding
+    /// callback. A missing or unrecognized code falls back to `Internal`.
+    pub fn from_binding(message: String, code: Option<&str>, retry_after_ms: Option<u64>) -> Self {
+        let code = code
+            .and_then(|code| CallbackErrorCode::parse(code, retry_after_ms))
+            .unwrap_or(CallbackErrorCode::Internal);
+        Self {
+            message,
+            code: Some(code),
+        }
+    }
+}
+
+/// Severity of a line logged by a function during an invocation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogLevel {
+    Debug,
+    Info,
+    Warn,
+    Error,
+}
+
+impl LogLevel {
+    /// Parses the lowercase level names used by the language bindings
+    pub fn parse(level: &str) -> Option<Self> {
+        match level {
+            "debug" => Some(LogLevel::Debug),
+            "info" => Some(LogLevel::Info),
+            "warn" | "warning" => Some(LogLevel::Warn),
+            "error" => Some(LogLevel::Error),
+            _ => None,
+        }
+    }
+}
+
+/// A structured log line emitted by a function while it ran
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogLine {
+    pub level: LogLevel,
+    pub message: String,
+    /// Unix timestamp in milliseconds
+    pub timestamp_ms: i64,
+}
+
+impl LogLine {
+    /// Builds a log line from the `{ level, message, timestamp_ms }` shape returned by a language
+    /// binding callback. An unrecognized level falls back to `Info` and a missing timestamp to
+    /// the current time.
+    pub fn from_binding(level: &str, message: String, timestamp_ms: Option<i64>) -> Self {
+        Self {
+            level: LogLevel::parse(level).unwrap_or(LogLevel::Info),
+            message,
+            timestamp_ms: timestamp_ms.unwrap_or_else(|| {
+                std::time::SystemTime::now()
+                    .duration_since(std::time::UNIX_EPOCH)
+                    .map(|d| d.as_millis() as i64)
+                    .unwrap_or_default()
+            }),
+        }
+    }
+}
+
+/// Most log lines returned for one invocation, later lines are dropped
+pub const MAX_LOG_LINES_PER_INVOCATION: usize = 1_000;
+/// Most bytes of log messages returned for one invocation, later lines are dropped
+pub const MAX_LOG_BYTES_PER_INVOCATION: usize = 64 * 1024;
+
+#[derive(Default)]
+struct InvocationLogsState {
+    lines: Vec<LogLine>,
+    bytes: usize,
+    dropped: usize,
+}
+
+/// Lines logged by one invocation, bounded by [`MAX_LOG_LINES_PER_INVOCATION`] and
+/// [`MAX_LOG_BYTES_PER_INVOCATION`]. Clones share the same lines.
+#[derive(Clone, Default)]
+pub struct InvocationLogs {
+    state: Arc<Mutex<InvocationLogsState>>,
+}
+
+impl InvocationLogs {
+    /// Appends a line, dropping it once the invocation is over either limit
+    pub fn push(&self, line: LogLine) {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        if state.lines.len() >= MAX_LOG_LINES_PER_INVOCATION
+            || state.bytes + line.message.len() > MAX_LOG_BYTES_PER_INVOCATION
+        {
+            state.dropped += 1;
+            return;
+        }
+        state.bytes += line.message.len();
+        state.lines.push(line);
+    }
+
+    /// Takes the collected lines, ending with a warning when any were dropped
+    pub fn take(&self) -> Vec<LogLine> {
+        let state = std::mem::take(&mut *self.state.lock().unwrap_or_else(|e| e.into_inner()));
+        let mut lines = state.lines;
+        if state.dropped > 0 {
+            lines.push(LogLine::from_binding(
+                "warn",
+                format!(
+                    "Dropped {} log lines over the limit of {MAX_LOG_LINES_PER_INVOCATION} lines \
+                     or {MAX_LOG_BYTES_PER_INVOCATION} bytes per invocation",
+                    state.dropped
+                ),
+                None,
+            ));
+        }
+        lines
+    }
+}
+
+/// Logs of in-flight invocations keyed by invocation id, see [`append_invocation_log`]
+static IN_FLIGHT_INVOCATION_LOGS: Lazy<Mutex<HashMap<String, InvocationLogs>>> =
+    Lazy::new(Default::default);
+
+static NEXT_INVOCATION_ID: AtomicU64 = AtomicU64::new(1);
+
+/// Id identifying one invocation within this process
+pub fn next_invocation_id() -> String {
+    NEXT_INVOCATION_ID
+        .fetch_add(1, Ordering::Relaxed)
+        .to_string()
+}
+
+/// Appends a line to the logs of the in-flight invocation `invocation_id`. Lines appended this
+/// way are returned even if the invocation times out, unlike lines returned with the response.
+/// Returns false once the invocation has finished.
+pub fn append_invocation_log(invocation_id: &str, line: LogLine) -> bool {
+    let logs = IN_FLIGHT_INVOCATION_LOGS
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .get(invocation_id)
+        .cloned();
+    match logs {
+        Some(logs) => {
+            logs.push(line);
+            true
+        }
+        None => false,
+    }
+}
+
+/// Makes an invocation's logs reachable through [`append_invocation_log`] until dropped
+pub(crate) struct InFlightInvocationLogs {
+    invocation_id: String,
+    logs: InvocationLogs,
+}
+
+impl InFlightInvocationLogs {
+    pub(crate) fn register(invocation_id: &str) -> Self {
+        let logs = InvocationLogs::default();
+        IN_FLIGHT_INVOCATION_LOGS
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(invocation_id.to_string(), logs.clone());
+        Self {
+            invocation_id: invocation_id.to_string(),
+            logs,
+        }
+    }
+
+    pub(crate) fn logs(&self) -> &InvocationLogs {
+        &self.logs
+    }
+}
+
+impl Drop for InFlightInvocationLogs {
+    fn drop(&mut self) {
+        IN_FLIGHT_INVOCATION_LOGS
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .remove(&self.invocation_id);
+    }
 }
 
 #[derive(Debug, Clone)]
 pub struct InvokeFunctionResponse {
     pub result: Result<String, CallbackError>,
+    /// Lines the function logged while it ran, in emission order
+    pub logs: Vec<LogLine>,
+}
+
+/// Partial result of a streaming invocation
+#[derive(Debug, Clone)]
+pub struct InvokeFunctionChunk {
+    pub result: Result<String, CallbackError>,
+}
+
+/// Marker appended to function output that was truncated by `max_output_bytes`
+pub const OUTPUT_TRUNCATION_MARKER: &str = "...[output truncated]";
+
+/// Truncates `data` to at most `max_bytes` bytes (respecting UTF-8 boundaries) and appends
+/// [`OUTPUT_TRUNCATION_MARKER`]. Returns `None` when the data already fits within the limit.
+pub fn truncate_function_output(data: &str, max_bytes: usize) -> Option<String> {
+    if data.len() <= max_bytes {
+        return None;
+    }
+
+    let mut end = max_bytes;
+    while !data.is_char_boundary(end) {
+        end -= 1;
+    }
+
+    let mut truncated = String::with_capacity(end + OUTPUT_TRUNCATION_MARKER.len());
+    truncated.push_str(&data[..end]);
+    truncated.push_str(OUTPUT_TRUNCATION_MARKER);
+    Some(truncated)
+}
+
+/// Limits concurrent invocations of a provider's functions to protect its upstream API.
+/// Invocations beyond `max_concurrent` wait for a running one to finish; once `max_queue_depth`
+/// are already waiting, further invocations fail with [`CallbackErrorCode::RateLimited`].
+/// Clones share the same permits and queue.
+#[derive(Clone, Debug)]
+pub struct ProviderConcurrencyLimit {
+    max_concurrent: usize,
+    max_queue_depth: usize,
+    semaphore: Arc<Semaphore>,
+    queued: Arc<AtomicUsize>,
+}
+
+impl ProviderConcurrencyLimit {
+    /// `max_concurrent` is raised to 1 so invocations can always make progress
+    pub fn new(max_concurrent: usize, max_queue_depth: usize) -> Self {
+        let max_concurrent = max_concurrent.max(1);
+        Self {
+            max_concurrent,
+            max_queue_depth,
+            semaphore: Arc::new(Semaphore::new(max_concurrent)),
+            queued: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+
+    pub fn max_concurrent(&self) -> usize {
+        self.max_concurrent
+    }
+
+    pub fn max_queue_depth(&self) -> usize {
+        self.max_queue_depth
+    }
+
+    /// Waits for an invocation slot. The slot is released when the returned permit is dropped.
+    pub async fn acquire(&self) -> Result<OwnedSemaphorePermit, CallbackError> {
+        if let Ok(permit) = self.semaphore.clone().try_acquire_owned() {
+            return Ok(permit);
+        }
+
+        if self.queued.fetch_add(1, Ordering::SeqCst) >= self.max_queue_depth {
+            self.queued.fetch_sub(1, Ordering::SeqCst);
+            return Err(CallbackError {
+                message: format!(
+                    "Too many queued invocations (limit {} running, {} queued)",
+                    self.max_concurrent, self.max_queue_depth
+                ),
+                code: Some(CallbackErrorCode::RateLimited { after_ms: None }),
+            });
+        }
+        // Leave the queue even if the waiting invocation is cancelled
+        let _queued = QueuedInvocation(&self.queued);
+
+        self.semaphore
+            .clone()
+            .acquire_owned()
+            .await
+            .map_err(|_| CallbackError::internal("Provider concurrency limit was closed"))
+    }
+}
+
+struct QueuedInvocation<'a>(&'a AtomicUsize);
+
+impl Drop for QueuedInvocation<'_> {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+/// Entries a [`FunctionResultCache`] holds before evicting the least recently used one
+pub const DEFAULT_RESULT_CACHE_CAPACITY: usize = 1024;
+
+/// SHA-256 of a request's canonical (controller ids, credentials digest, parameters) tuple
+pub type ResultCacheKey = [u8; 32];
+
+struct ResultCacheEntry {
+    expires_at: Instant,
+    data: String,
+    /// Position in [`ResultCacheState::recency`]
+    last_used: u64,
+}
+
+#[derive(Default)]
+struct ResultCacheState {
+    entries: HashMap<ResultCacheKey, ResultCacheEntry>,
+    /// Keys ordered from least to most recently used
+    recency: BTreeMap<u64, ResultCacheKey>,
+    next_use: u64,
+}
+
+impl ResultCacheState {
+    fn touch(&mut self, key: ResultCacheKey) -> u64 {
+        let last_used = self.next_use;
+        self.next_use += 1;
+        self.recency.insert(last_used, key);
+        last_used
+    }
+
+    fn remove(&mut self, key: &ResultCacheKey) {
+        if let Some(entry) = self.entries.remove(key) {
+            self.recency.remove(&entry.last_used);
+        }
+    }
+}
+
+/// Caches successful results of an idempotent function, keyed by [`Self::cache_key`]. Holds at
+/// most `capacity` entries, evicting the least recently used. Clones share the same entries.
+#[derive(Clone)]
+pub struct FunctionResultCache {
+    ttl: Duration,
+    capacity: usize,
+    state: Arc<Mutex<ResultCacheState>>,
+}
+
+impl FunctionResultCache {
+    pub fn new(ttl: Duration) -> Self {
+        Self::with_capacity(ttl, DEFAULT_RESULT_CACHE_CAPACITY)
+    }
+
+    pub fn with_capacity(ttl: Duration, capacity: usize) -> Self {
+        Self {
+            ttl,
+            capacity: capacity.max(1),
+            state: Arc::new(Mutex::new(ResultCacheState::default())),
+        }
+    }
+
+    pub fn ttl(&self) -> Duration {
+        self.ttl
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Cache key for a request. Credentials are part of the key so results are never shared
+    /// between callers with different credentials. Each field is length-prefixed so no two
+    /// distinct tuples hash the same input, and JSON parameters are canonicalized so key order
+    /// does not split the cache.
+    pub fn cache_key(request: &InvokeFunctionRequest) -> ResultCacheKey {
+        let credentials_digest = Sha256::digest(request.credentials.as_bytes());
+        let parameters = serde_json::from_str::<serde_json::Value>(&request.parameters)
+            .map(|value| value.to_string())
+            .unwrap_or_else(|_| request.parameters.clone());
+
+        let mut hasher = Sha256::new();
+        for field in [
+            request.provider_controller_type_id.as_bytes(),
+            request.function_controller_type_id.as_bytes(),
+            request.credential_controller_type_id.as_bytes(),
+            credentials_digest.as_slice(),
+            parameters.as_bytes(),
+        ] {
+            hasher.update((field.len() as u64).to_be_bytes());
+            hasher.update(field);
+        }
+        hasher.finalize().into()
+    }
+
+    /// Returns the cached result for `key` if it has not expired, marking it recently used.
+    pub fn get(&self, key: ResultCacheKey) -> Option<String> {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        let (expires_at, last_used) = state
+            .entries
+            .get(&key)
+            .map(|entry| (entry.expires_at, entry.last_used))?;
+        if expires_at <= Instant::now() {
+            state.remove(&key);
+            return None;
+        }
+
+        state.recency.remove(&last_used);
+        let last_used = state.touch(key);
+        let entry = state.entries.get_mut(&key)?;
+        entry.last_used = last_used;
+        Some(entry.data.clone())
+    }
+
+    pub fn insert(&self, key: ResultCacheKey, data: String) {
+        let now = Instant::now();
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        let expired: Vec<ResultCacheKey> = state
+            .entries
+            .iter()
+            .filter(|(_, entry)| entry.expires_at <= now)
+            .map(|(key, _)| *key)
+            .collect();
+        for expired_key in &expired {
+            state.remove(expired_key);
+        }
+        state.remove(&key);
+
+        while state.entries.len() >= self.capacity {
+            let Some((_, least_recent)) = state.recency.pop_first() else {
+                break;
+            };
+            state.entries.remove(&least_recent);
+        }
+
+        let last_used = state.touch(key);
+        state.entries.insert(
+            key,
+            ResultCacheEntry {
+                expires_at: now + self.ttl,
+                data,
+                last_used,
+            },
+        );
+    }
+
+    pub fn clear(&self) {
+        *self.state.lock().unwrap_or_else(|e| e.into_inner()) = ResultCacheState::default();
+    }
 }
 
 pub struct MetadataResponse {
@@ -188,6 +868,9 @@ impl From<FunctionController> for sdk_proto::FunctionController {
             description: fc.description,
             parameters: fc.parameters,
             output: fc.output,
+            deprecated: fc.deprecated,
+            deprecation_message: fc.deprecation_message.unwrap_or_default(),
+            examples: fc.examples.into_iter().map(Into::into).collect(),
         }
     }
 }
@@ -199,6 +882,29 @@ impl From<&FunctionController> for sdk_proto::FunctionController {
             description: fc.description.clone(),
             parameters: fc.parameters.clone(),
             output: fc.output.clone(),
+            deprecated: fc.deprecated,
+            deprecation_message: fc.deprecation_message.clone().unwrap_or_default(),
+            examples: fc.examples.iter().cloned().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<FunctionExample> for sdk_proto::FunctionExample {
+    fn from(example: FunctionExample) -> Self {
+        Self {
+            description: example.description.unwrap_or_default(),
+            parameters: example.parameters,
+            output: example.output.unwrap_or_default(),
+        }
+    }
+}
+
+impl From<sdk_proto::FunctionExample> for FunctionExample {
+    fn from(proto: sdk_proto::FunctionExample) -> Self {
+        Self {
+            description: Some(proto.description).filter(|d| !d.is_empty()),
+            parameters: proto.parameters,
+            output: Some(proto.output).filter(|o| !o.is_empty()),
         }
     }
 }
@@ -432,14 +1138,57 @@ impl TryFrom<sdk_proto::InvokeFunctionRequest> for InvokeFunctionRequest {
             credential_controller_type_id: proto.credential_controller_type_id,
             credentials: proto.credentials,
             parameters: proto.parameters,
+            request_id: Some(proto.request_id).filter(|id| !id.is_empty()),
+            bypass_cache: proto.bypass_cache,
+            invocation_id: next_invocation_id(),
         })
     }
 }
 
 impl From<CallbackError> for sdk_proto::CallbackError {
     fn from(error: CallbackError) -> Self {
+        use sdk_proto::CallbackErrorCode as ProtoCode;
+
+        let (code, retry_after_ms) = match error.code {
+            None => (ProtoCode::Unspecified, None),
+            Some(CallbackErrorCode::RateLimited { after_ms }) => (ProtoCode::RateLimited, after_ms),
+            Some(CallbackErrorCode::Unauthorized) => (ProtoCode::Unauthorized, None),
+            Some(CallbackErrorCode::InvalidArgument) => (ProtoCode::InvalidArgument, None),
+            Some(CallbackErrorCode::Internal) => (ProtoCode::Internal, None),
+            Some(CallbackErrorCode::Retryable { after_ms }) => (ProtoCode::Retryable, after_ms),
+            Some(CallbackErrorCode::DeadlineExceeded) => (ProtoCode::DeadlineExceeded, None),
+        };
+
         Self {
             message: error.message,
+            code: code as i32,
+            retry_after_ms,
+        }
+    }
+}
+
+impl From<sdk_proto::CallbackError> for CallbackError {
+    fn from(proto: sdk_proto::CallbackError) -> Self {
+        use sdk_proto::CallbackErrorCode as ProtoCode;
+
+        let code = match ProtoCode::try_from(proto.code) {
+            Ok(ProtoCode::RateLimited) => Some(CallbackErrorCode::RateLimited {
+                after_ms: proto.retry_after_ms,
+            }),
+            Ok(ProtoCode::Unauthorized) => Some(CallbackErrorCode::Unauthorized),
+            Ok(ProtoCode::InvalidArgument) => Some(CallbackErrorCode::InvalidArgument),
+            Ok(ProtoCode::Internal) => Some(CallbackErrorCode::Internal),
+            Ok(ProtoCode::Retryable) => Some(CallbackErrorCode::Retryable {
+                after_ms: proto.retry_after_ms,
+            }),
+            Ok(ProtoCode::DeadlineExceeded) => Some(CallbackErrorCode::DeadlineExceeded),
+            // Unknown values come from newer peers; treat them like a missing code
+            Ok(ProtoCode::Unspecified) | Err(_) => None,
+        };
+
+        Self {
+            message: proto.message,
+            code,
         }
     }
 }
@@ -453,6 +1202,42 @@ impl From<InvokeFunctionResponse> for sdk_proto::InvokeFunctionResponse {
             Err(error) => Some(Kind::Error(error.into())),
         };
 
+        Self {
+            kind,
+            truncated: false,
+            logs: response.logs.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<LogLine> for sdk_proto::LogLine {
+    fn from(line: LogLine) -> Self {
+        use sdk_proto::LogLevel as ProtoLevel;
+
+        let level = match line.level {
+            LogLevel::Debug => ProtoLevel::Debug,
+            LogLevel::Info => ProtoLevel::Info,
+            LogLevel::Warn => ProtoLevel::Warn,
+            LogLevel::Error => ProtoLevel::Error,
+        };
+
+        Self {
+            level: level as i32,
+            message: line.message,
+            timestamp_ms: line.timestamp_ms,
+        }
+    }
+}
+
+impl From<InvokeFunctionChunk> for sdk_proto::InvokeFunctionChunk {
+    fn from(chunk: InvokeFunctionChunk) -> Self {
+        use sdk_proto::invoke_function_chunk::Kind;
+
+        let kind = match chunk.result {
+            Ok(data) => Some(Kind::Data(data)),
+            Err(error) => Some(Kind::Error(error.into())),
+        };
+
         Self { kind }
     }
 }
@@ -473,6 +1258,7 @@ impl From<Agent> for sdk_proto::Agent {
             project_id: agent.project_id,
             name: agent.name,
             description: agent.description,
+            allowed_functions: agent.allowed_functions,
         }
     }
 }
@@ -501,15 +1287,110 @@ impl From<Secret> for sdk_proto::Secret {
     }
 }
 
+/// Whether a single key from a set secrets/environment variables batch was applied
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyOutcomeStatus {
+    Applied,
+    Skipped,
+    Failed,
+}
+
+impl KeyOutcomeStatus {
+    /// Parses the lowercase status names used by the language bindings
+    pub fn parse(status: &str) -> Option<Self> {
+        match status {
+            "applied" => Some(KeyOutcomeStatus::Applied),
+            "skipped" => Some(KeyOutcomeStatus::Skipped),
+            "failed" => Some(KeyOutcomeStatus::Failed),
+            _ => None,
+        }
+    }
+}
+
+impl From<KeyOutcomeStatus> for sdk_proto::KeyOutcomeStatus {
+    fn from(status: KeyOutcomeStatus) -> Self {
+        match status {
+            KeyOutcomeStatus::Applied => sdk_proto::KeyOutcomeStatus::Applied,
+            KeyOutcomeStatus::Skipped => sdk_proto::KeyOutcomeStatus::Skipped,
+            KeyOutcomeStatus::Failed => sdk_proto::KeyOutcomeStatus::Failed,
+        }
+    }
+}
+
+/// Outcome of applying a single key, with a reason when it was skipped or failed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyOutcome {
+    pub key: String,
+    pub status: KeyOutcomeStatus,
+    pub reason: Option<String>,
+}
+
+impl KeyOutcome {
+    pub fn applied(key: impl Into<String>) -> Self {
+        Self {
+            key: key.into(),
+            status: KeyOutcomeStatus::Applied,
+            reason: None,
+        }
+    }
+
+    pub fn skipped(key: impl Into<String>, reason: impl Into<String>) -> Self {
+        Self {
+            key: key.into(),
+            status: KeyOutcomeStatus::Skipped,
+            reason: Some(reason.into()),
+        }
+    }
+
+    pub fn failed(key: impl Into<String>, reason: impl Into<String>) -> Self {
+        Self {
+            key: key.into(),
+            status: KeyOutcomeStatus::Failed,
+            reason: Some(reason.into()),
+        }
+    }
+}
+
+impl From<KeyOutcome> for sdk_proto::KeyOutcome {
+    fn from(outcome: KeyOutcome) -> Self {
+        Self {
+            key: outcome.key,
+            status: sdk_proto::KeyOutcomeStatus::from(outcome.status) as i32,
+            reason: outcome.reason.unwrap_or_default(),
+        }
+    }
+}
+
+/// Returns one outcome per key in `keys`, in request order. Keys the handler did not report
+/// on are treated as applied, so handlers that only return a message keep working. Outcomes
+/// for keys that were not part of the batch are dropped.
+pub fn complete_key_outcomes(keys: &[String], outcomes: Vec<KeyOutcome>) -> Vec<KeyOutcome> {
+    let mut reported: HashMap<String, KeyOutcome> = outcomes
+        .into_iter()
+        .map(|outcome| (outcome.key.clone(), outcome))
+        .collect();
+
+    keys.iter()
+        .map(|key| {
+            reported
+                .remove(key)
+                .unwrap_or_else(|| KeyOutcome::applied(key.clone()))
+        })
+        .collect()
+}
+
 #[derive(Debug, Clone)]
 pub struct SetSecretsSuccess {
     pub message: String,
+    /// Per-key outcomes; keys without an entry are treated as applied
+    pub outcomes: Vec<KeyOutcome>,
 }
 
-impl From<SetSecretsSuccess> for sdk_proto::CallbackSuccess {
+impl From<SetSecretsSuccess> for sdk_proto::SetKeysSuccess {
     fn from(success: SetSecretsSuccess) -> Self {
         Self {
             message: success.message,
+            outcomes: success.outcomes.into_iter().map(Into::into).collect(),
         }
     }
 }
@@ -526,9 +1407,7 @@ impl From<SetSecretsResponse> for sdk_proto::SetSecretsResponse {
 
         Self {
             kind: match response.result {
-                Ok(data) => Some(Kind::Data(sdk_proto::CallbackSuccess {
-                    message: data.message,
-                })),
+                Ok(data) => Some(Kind::Data(data.into())),
                 Err(error) => Some(Kind::Error(error.into())),
             },
         }
@@ -570,12 +1449,15 @@ impl From<EnvironmentVariable> for sdk_proto::EnvironmentVariable {
 #[derive(Debug, Clone)]
 pub struct SetEnvironmentVariablesSuccess {
     pub message: String,
+    /// Per-key outcomes; keys without an entry are treated as applied
+    pub outcomes: Vec<KeyOutcome>,
 }
 
-impl From<SetEnvironmentVariablesSuccess> for sdk_proto::CallbackSuccess {
+impl From<SetEnvironmentVariablesSuccess> for sdk_proto::SetKeysSuccess {
     fn from(success: SetEnvironmentVariablesSuccess) -> Self {
         Self {
             message: success.message,
+            outcomes: success.outcomes.into_iter().map(Into::into).collect(),
         }
     }
 }
@@ -592,9 +1474,7 @@ impl From<SetEnvironmentVariablesResponse> for sdk_proto::SetEnvironmentVariable
 
         Self {
             kind: match response.result {
-                Ok(data) => Some(Kind::Data(sdk_proto::CallbackSuccess {
-                    message: data.message,
-                })),
+                Ok(data) => Some(Kind::Data(data.into())),
                 Err(error) => Some(Kind::Error(error.into())),
             },
         }
@@ -1,15 +1,38 @@
 /* auto-generated by NAPI-RS */
 /* eslint-disable */
+/**
+ * Sink a streaming invoke callback sends its chunks through, passed to the callback together
+ * with the request
+ */
+export declare class FunctionChunkStream {
+	get request(): InvokeFunctionRequest;
+	/**
+	 * Send the next chunk, waiting while the client is behind. Resolves to false once the
+	 * stream is closed (the client went away or the invocation ended); stop sending then.
+	 */
+	send(chunk: InvokeFunctionChunk): Promise<boolean>;
+}
+
 export declare class InvokeFunctionRequest {
 	providerControllerTypeId: string;
 	functionControllerTypeId: string;
 	credentialControllerTypeId: string;
 	credentials: string;
 	parameters: string;
+	requestId?: string;
+	/** Pass to `appendInvocationLog` to log lines that are kept if the invocation times out */
+	invocationId: string;
 }
 
+/** Add an agent. Fails when an agent with the same name is already registered in its project. */
 export declare function addAgent(agent: Agent): boolean;
 
+/**
+ * Atomically add a batch of agents. Returns the conflicting ids; when any are returned,
+ * none of the agents are added.
+ */
+export declare function addAgentsBatch(agents: Array<Agent>): Array<string>;
+
 export declare function addFunction(
 	providerTypeId: string,
 	functionMetadata: FunctionMetadata,
@@ -17,20 +40,46 @@ export declare function addFunction(
 		err: Error | null,
 		arg: InvokeFunctionRequest,
 	) => Promise<InvokeFunctionResponse>,
+	invokeStreamCallback?:
+		| ((err: Error | null, arg: FunctionChunkStream) => Promise<void>)
+		| undefined
+		| null,
 ): boolean;
 
 /** Add a provider controller to the running server */
 export declare function addProvider(provider: ProviderController): void;
 
+/**
+ * Append a line to the logs of an in-flight invocation, identified by its request's
+ * `invocationId`. Unlike lines returned with the response, these are kept if the invocation
+ * times out. Returns false once the invocation has finished.
+ */
+export declare function appendInvocationLog(
+	invocationId: string,
+	line: LogLine,
+): boolean;
+
 export interface Agent {
 	id: string;
 	projectId: string;
 	name: string;
 	description: string;
+	/**
+	 * Provider functions the agent may invoke, as "providerTypeId.functionTypeId".
+	 * Omitted or empty allows every function.
+	 */
+	allowedFunctions?: Array<string>;
 }
 
+/**
+ * Error returned by a callback. `code` is one of "rate_limited", "unauthorized",
+ * "invalid_argument", "internal", "retryable" or "deadline_exceeded"; an unknown code is
+ * treated as "internal". `retry_after_ms` is only read for "rate_limited" and "retryable".
+ */
 export interface CallbackError {
 	message: string;
+	code?: string;
+	retryAfterMs?: number;
 }
 
 export interface EnvironmentVariable {
@@ -52,6 +101,13 @@ export interface FunctionControllerData {
 	returnValueJsonSchema: string;
 }
 
+/** Example invocation of a function; `parameters` and `output` are JSON strings */
+export interface FunctionExample {
+	description?: string;
+	parameters: string;
+	output?: string;
+}
+
 export interface FunctionInstanceData {
 	providerInstanceId: string;
 	providerInstanceDisplayName: string;
@@ -72,6 +128,20 @@ export interface FunctionMetadata {
 	description: string;
 	parameters: string;
 	output: string;
+	/** Truncate outputs larger than this many bytes */
+	maxOutputBytes?: number;
+	/** Mark the function idempotent and cache identical calls for this many milliseconds */
+	resultCacheTtlMs?: number;
+	/** Flag the function as deprecated; it can still be invoked */
+	deprecated?: boolean;
+	/** Guidance shown to callers of a deprecated function */
+	deprecationMessage?: string;
+	/** Example invocations, validated against the parameter and output schemas */
+	examples?: Array<FunctionExample>;
+	/** Fail invocations that run longer than this many milliseconds with "deadline_exceeded" */
+	timeoutMs?: number;
+	/** Check returned data against the output schema, failing mismatches with "invalid_argument" */
+	validateOutput?: boolean;
 }
 
 export interface GenerateMcpClientError {
@@ -91,16 +161,48 @@ export interface GenerateMcpClientSuccess {
 	message: string;
 }
 
+/** Partial result sent by a streaming function: either `data` or `error` */
+export interface InvokeFunctionChunk {
+	data?: string;
+	error?: CallbackError;
+}
+
 export interface InvokeFunctionResponse {
 	data?: string;
 	error?: CallbackError;
+	logs?: Array<LogLine>;
 }
 
 /**
  * Kill/clear the gRPC service, removing all providers, agents, and handlers.
- * This allows the service to be restarted fresh.
+ * Resolves once the server has stopped and its socket is removed, so the service can be
+ * restarted fresh on the same path.
  */
-export declare function killGrpcService(): void;
+export declare function killGrpcService(): Promise<void>;
+
+/** List every registered provider with its functions' schemas */
+export declare function listProviders(): Array<ProviderWithFunctions>;
+
+/**
+ * Outcome of applying a single secret or environment variable key.
+ * `status` is one of "applied", "skipped" or "failed".
+ */
+export interface KeyOutcome {
+	key: string;
+	status: string;
+	reason?: string;
+}
+
+/**
+ * Line logged by a function while it ran. `level` is one of "debug", "info", "warn" or
+ * "error"; an unknown level is treated as "info". `timestamp_ms` defaults to when the
+ * response is received.
+ */
+export interface LogLine {
+	level: string;
+	message: string;
+	timestampMs?: number;
+}
 
 export interface Metadata {
 	key: string;
@@ -141,6 +243,10 @@ export interface ProviderController {
 	documentation: string;
 	categories: Array<string>;
 	credentialControllers: Array<ProviderCredentialController>;
+	/** Run at most this many of the provider's functions at once; further invocations queue */
+	maxConcurrency?: number;
+	/** Fail invocations with "rate_limited" once this many are queued. Unbounded when omitted. */
+	maxQueueDepth?: number;
 }
 
 export interface ProviderControllerData {
@@ -160,6 +266,30 @@ export type ProviderCredentialController =
 			field0: Oauth2JwtBearerAssertionFlowConfiguration;
 	  };
 
+/** Provider type ids added, updated and removed by `reconcileProviders` */
+export interface ProviderReconcileSummary {
+	added: Array<string>;
+	updated: Array<string>;
+	removed: Array<string>;
+}
+
+/** A registered provider with its functions' parameter and output schemas */
+export interface ProviderWithFunctions {
+	typeId: string;
+	name: string;
+	documentation: string;
+	categories: Array<string>;
+	functions: Array<FunctionController>;
+}
+
+/**
+ * Replace the registered providers with `providers` in one step. Providers missing from the
+ * list are removed; functions already registered on a kept provider stay registered.
+ */
+export declare function reconcileProviders(
+	providers: Array<ProviderController>,
+): ProviderReconcileSummary;
+
 /** Remove an agent by id */
 export declare function removeAgent(id: string): boolean;
 
@@ -218,6 +348,8 @@ export interface SetEnvironmentVariablesResponse {
 
 export interface SetEnvironmentVariablesSuccess {
 	message: string;
+	/** Per-key outcomes; keys without an entry are treated as applied */
+	outcomes?: Array<KeyOutcome>;
 }
 
 /**
@@ -231,6 +363,18 @@ export declare function setSecretHandler(
 	) => Promise<SetSecretsResponse>,
 ): void;
 
+/**
+ * Set the secret handler callback for secrets scoped to one provider type_id. Secrets for
+ * providers without their own handler go to the handler set by `setSecretHandler`.
+ */
+export declare function setSecretHandlerFor(
+	providerTypeId: string,
+	callback: (
+		err: Error | null,
+		arg: Array<Secret>,
+	) => Promise<SetSecretsResponse>,
+): void;
+
 /** Response from setting secrets */
 export interface SetSecretsResponse {
 	data?: SetSecretsSuccess;
@@ -239,6 +383,8 @@ export interface SetSecretsResponse {
 
 export interface SetSecretsSuccess {
 	message: string;
+	/** Per-key outcomes; keys without an entry are treated as applied */
+	outcomes?: Array<KeyOutcome>;
 }
 
 /**
@@ -295,6 +441,10 @@ export declare function updateFunction(
 		err: Error | null,
 		arg: InvokeFunctionRequest,
 	) => InvokeFunctionResponse,
+	invokeStreamCallback?:
+		| ((err: Error | null, arg: FunctionChunkStream) => Promise<void>)
+		| undefined
+		| null,
 ): boolean;
 
 /** Update a provider controller (removes old and inserts new) */
@@ -777,11 +777,14 @@ if (!nativeBinding) {
 }
 
 module.exports = nativeBinding;
+module.exports.FunctionChunkStream = nativeBinding.FunctionChunkStream;
 module.exports.InvokeFunctionRequest = nativeBinding.InvokeFunctionRequest;
 module.exports.addAgent = nativeBinding.addAgent;
 module.exports.addFunction = nativeBinding.addFunction;
 module.exports.addProvider = nativeBinding.addProvider;
+module.exports.appendInvocationLog = nativeBinding.appendInvocationLog;
 module.exports.killGrpcService = nativeBinding.killGrpcService;
+module.exports.reconcileProviders = nativeBinding.reconcileProviders;
 module.exports.removeAgent = nativeBinding.removeAgent;
 module.exports.removeFunction = nativeBinding.removeFunction;
 module.exports.removeProvider = nativeBinding.removeProvider;
@@ -789,6 +792,7 @@ module.exports.resyncSdk = nativeBinding.resyncSdk;
 module.exports.setEnvironmentVariableHandler =
 	nativeBinding.setEnvironmentVariableHandler;
 module.exports.setSecretHandler = nativeBinding.setSecretHandler;
+module.exports.setSecretHandlerFor = nativeBinding.setSecretHandlerFor;
 module.exports.setUnsetEnvironmentVariableHandler =
 	nativeBinding.setUnsetEnvironmentVariableHandler;
 module.exports.setUnsetSecretHandler = nativeBinding.setUnsetSecretHandler;
@@ -30,6 +30,15 @@ pub struct FunctionControllerData {
     pub display_name: String,
     pub params_json_schema: Option<serde_json::Value>,
     pub return_value_json_schema: Option<serde_json::Value>,
+    pub examples: Vec<FunctionExampleData>,
+}
+
+/// Example invocation of a function; `parameters` and `output` are JSON strings
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FunctionExampleData {
+    pub description: Option<String>,
+    pub parameters: String,
+    pub output: Option<String>,
 }
 
 /// Serializable structure for provider in template
@@ -59,6 +68,8 @@ struct FunctionData {
     params_type_name: String,
     return_type: String,
     return_type_name: String,
+    /// Emitted as `@example` tags on the function's doc comment
+    examples: Vec<FunctionExampleData>,
 }
 
 /// Agent data structure for code generation
@@ -164,6 +175,12 @@ pub fn generate_typescript_code_from_api_data(
                     params_type_name,
                     return_type,
                     return_type_name,
+                    examples: func_data
+                        .function_controller
+                        .examples
+                        .iter()
+                        .map(example_for_doc_comment)
+                        .collect(),
                 });
             }
 
@@ -517,6 +534,16 @@ fn strip_provider_prefix_and_camel_case(function_name: &str, provider_name: &str
     }
 }
 
+/// Escapes an example so it cannot terminate the surrounding `/** */` doc comment
+fn example_for_doc_comment(example: &FunctionExampleData) -> FunctionExampleData {
+    let escape = |text: &str| text.replace("*/", "*\\/").replace('\n', " ");
+    FunctionExampleData {
+        description: example.description.as_deref().map(escape),
+        parameters: escape(&example.parameters),
+        output: example.output.as_deref().map(escape),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     mod unit {
@@ -572,5 +599,32 @@ mod tests {
                 "otherFunction"
             );
         }
+
+        #[test]
+        fn test_examples_are_emitted_as_doc_comments() {
+            let code = generate_typescript_code_from_api_data(&[FunctionInstanceData {
+                provider_instance_id: "instance-1".to_string(),
+                provider_instance_display_name: "default".to_string(),
+                provider_controller: ProviderControllerData {
+                    type_id: "mail".to_string(),
+                    display_name: "Mail".to_string(),
+                },
+                function_controller: FunctionControllerData {
+                    type_id: "send".to_string(),
+                    display_name: "Send".to_string(),
+                    params_json_schema: Some(serde_json::json!({"type": "object"})),
+                    return_value_json_schema: Some(serde_json::json!({"type": "object"})),
+                    examples: vec![FunctionExampleData {
+                        description: Some("Send to one recipient".to_string()),
+                        parameters: r#"{"to":"a@example.com"}"#.to_string(),
+                        output: Some(r#"{"id":"msg-*/1"}"#.to_string()),
+                    }],
+                },
+            }])
+            .unwrap();
+
+            assert!(code.contains("@example Send to one recipient"));
+            assert!(code.contains(r#"send({"to":"a@example.com"}) // => {"id":"msg-*\/1"}"#));
+        }
     }
 }
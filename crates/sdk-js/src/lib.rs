@@ -9,6 +9,8 @@ use parking_lot::Mutex;
 use shared::error::CommonError;
 use std::path::PathBuf;
 use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::mpsc;
 use tracing::{debug, trace};
 
 use codegen_impl::TypeScriptCodeGenerator;
@@ -19,6 +21,9 @@ use types as js_types;
 static GRPC_SERVICE: Mutex<Option<Arc<core_types::GrpcService<TypeScriptCodeGenerator>>>> =
     Mutex::new(None);
 
+// Stops the server behind GRPC_SERVICE; taken by kill_grpc_service
+static SHUTDOWN_HANDLE: Mutex<Option<core_types::ShutdownHandle>> = Mutex::new(None);
+
 /// Start the gRPC server on a Unix socket with TypeScript code generation
 #[napi]
 pub async fn start_grpc_server(socket_path: String, project_dir: String) -> Result<()> {
@@ -27,22 +32,32 @@ pub async fn start_grpc_server(socket_path: String, project_dir: String) -> Resu
 
     let code_generator = TypeScriptCodeGenerator::new(project_dir);
 
-    let service = core_types::start_grpc_server(vec![], socket_path, code_generator)
-        .await
-        .map_err(|e| napi::Error::from_reason(e.to_string()))?;
+    let (service, shutdown_handle) =
+        core_types::start_grpc_server(vec![], socket_path, code_generator)
+            .await
+            .map_err(|e| napi::Error::from_reason(e.to_string()))?;
 
     // Store the service, replacing any existing one
-    let mut guard = GRPC_SERVICE.lock();
-    *guard = Some(service);
+    *GRPC_SERVICE.lock() = Some(service);
+    *SHUTDOWN_HANDLE.lock() = Some(shutdown_handle);
 
     Ok(())
 }
 
 /// Kill/clear the gRPC service, removing all providers, agents, and handlers.
-/// This allows the service to be restarted fresh.
+/// Resolves once the server has stopped and its socket is removed, so the service can be
+/// restarted fresh on the same path.
 #[napi]
-pub fn kill_grpc_service() -> Result<()> {
+pub async fn kill_grpc_service() -> Result<()> {
     debug!("Killing gRPC service");
+    let shutdown_handle = SHUTDOWN_HANDLE.lock().take();
+    if let Some(shutdown_handle) = shutdown_handle {
+        shutdown_handle
+            .shutdown()
+            .await
+            .map_err(|e| napi::Error::from_reason(e.to_string()))?;
+    }
+
     let mut guard = GRPC_SERVICE.lock();
     if let Some(service) = guard.as_ref() {
         // Clear the service state
@@ -60,9 +75,10 @@ fn get_grpc_service() -> Result<Arc<core_types::GrpcService<TypeScriptCodeGenera
     })
 }
 
-/// Add a provider controller to the running server
-#[napi]
-pub fn add_provider(provider: js_types::ProviderController) -> Result<()> {
+/// Converts a provider controller from JS; functions are registered separately
+fn convert_provider_controller(
+    provider: js_types::ProviderController,
+) -> Result<core_types::ProviderController> {
     // Convert credential controllers
     let credential_controllers: Vec<core_types::ProviderCredentialController> = provider
         .credential_controllers
@@ -128,109 +144,88 @@ pub fn add_provider(provider: js_types::ProviderController) -> Result<()> {
         .collect::<std::result::Result<Vec<_>, CommonError>>()
         .map_err(|e| napi::Error::from_reason(e.to_string()))?;
 
-    let core_provider = core_types::ProviderController {
+    Ok(core_types::ProviderController {
         type_id: provider.type_id,
         name: provider.name,
         documentation: provider.documentation,
         categories: provider.categories,
         functions: vec![],
         credential_controllers,
-    };
+        concurrency_limit: convert_concurrency_limit(
+            provider.max_concurrency,
+            provider.max_queue_depth,
+        ),
+    })
+}
+
+/// Add a provider controller to the running server
+#[napi]
+pub fn add_provider(provider: js_types::ProviderController) -> Result<()> {
+    let core_provider = convert_provider_controller(provider)?;
 
     get_grpc_service()?.add_provider(core_provider);
     Ok(())
 }
 
+/// List every registered provider with its functions' schemas
+#[napi]
+pub fn list_providers() -> Result<Vec<js_types::ProviderWithFunctions>> {
+    Ok(get_grpc_service()?
+        .list_providers_with_functions()
+        .into_iter()
+        .map(|provider| js_types::ProviderWithFunctions {
+            type_id: provider.type_id,
+            name: provider.name,
+            documentation: provider.documentation,
+            categories: provider.categories,
+            functions: provider
+                .functions
+                .into_iter()
+                .map(|function| js_types::FunctionController {
+                    name: function.name,
+                    description: function.description,
+                    parameters: function.parameters,
+                    output: function.output,
+                })
+                .collect(),
+        })
+        .collect())
+}
+
 /// Remove a provider controller by type_id
 #[napi]
 pub fn remove_provider(type_id: String) -> Result<bool> {
     Ok(get_grpc_service()?.remove_provider(&type_id))
 }
 
-/// Update a provider controller (removes old and inserts new)
+/// Update a provider controller, keeping the functions already registered on it
 #[napi]
 pub fn update_provider(provider: js_types::ProviderController) -> Result<bool> {
-    let current_provider = get_grpc_service()?.get_provider(&provider.type_id);
-    let current_provider = if let Some(current_provider) = current_provider {
-        current_provider
-    } else {
+    let core_provider = convert_provider_controller(provider)?;
+
+    // Functions are registered separately, keep the ones already on the provider
+    if !get_grpc_service()?.update_provider_keeping_functions(core_provider) {
         return Err(napi::Error::from_reason("Provider not found"));
-    };
+    }
+    Ok(true)
+}
 
-    let credential_controllers: Vec<core_types::ProviderCredentialController> = provider
-        .credential_controllers
+/// Replace the registered providers with `providers` in one step. Providers missing from the
+/// list are removed; functions already registered on a kept provider stay registered.
+#[napi]
+pub fn reconcile_providers(
+    providers: Vec<js_types::ProviderController>,
+) -> Result<js_types::ProviderReconcileSummary> {
+    let providers = providers
         .into_iter()
-        .map(|js_cred| {
-            Ok(match js_cred {
-                js_types::ProviderCredentialController::NoAuth => {
-                    core_types::ProviderCredentialController::NoAuth
-                }
-                js_types::ProviderCredentialController::ApiKey => {
-                    core_types::ProviderCredentialController::ApiKey
-                }
-                js_types::ProviderCredentialController::Oauth2AuthorizationCodeFlow(config) => {
-                    let metadata = config.static_credential_configuration.metadata.map(|m| {
-                        m.into_iter()
-                            .map(|js_meta| core_types::Metadata {
-                                key: js_meta.key,
-                                value: js_meta.value,
-                            })
-                            .collect()
-                    });
-
-                    core_types::ProviderCredentialController::Oauth2AuthorizationCodeFlow(
-                        core_types::Oauth2AuthorizationCodeFlowConfiguration {
-                            static_credential_configuration: core_types::Oauth2AuthorizationCodeFlowStaticCredentialConfiguration {
-                                auth_uri: config.static_credential_configuration.auth_uri,
-                                token_uri: config.static_credential_configuration.token_uri,
-                                userinfo_uri: config.static_credential_configuration.userinfo_uri,
-                                jwks_uri: config.static_credential_configuration.jwks_uri,
-                                issuer: config.static_credential_configuration.issuer,
-                                scopes: config.static_credential_configuration.scopes,
-                                metadata,
-                            },
-                        },
-                    )
-                }
-                js_types::ProviderCredentialController::Oauth2JwtBearerAssertionFlow(config) => {
-                    let metadata = config.static_credential_configuration.metadata.map(|m| {
-                        m.into_iter()
-                            .map(|js_meta| core_types::Metadata {
-                                key: js_meta.key,
-                                value: js_meta.value,
-                            })
-                            .collect()
-                    });
-
-                    core_types::ProviderCredentialController::Oauth2JwtBearerAssertionFlow(
-                        core_types::Oauth2JwtBearerAssertionFlowConfiguration {
-                            static_credential_configuration: core_types::Oauth2JwtBearerAssertionFlowStaticCredentialConfiguration {
-                                auth_uri: config.static_credential_configuration.auth_uri,
-                                token_uri: config.static_credential_configuration.token_uri,
-                                userinfo_uri: config.static_credential_configuration.userinfo_uri,
-                                jwks_uri: config.static_credential_configuration.jwks_uri,
-                                issuer: config.static_credential_configuration.issuer,
-                                scopes: config.static_credential_configuration.scopes,
-                                metadata,
-                            },
-                        },
-                    )
-                }
-            })
-        })
-        .collect::<std::result::Result<Vec<_>, CommonError>>()
-        .map_err(|e| napi::Error::from_reason(e.to_string()))?;
-
-    let core_provider = core_types::ProviderController {
-        type_id: provider.type_id,
-        name: provider.name,
-        documentation: provider.documentation,
-        categories: provider.categories,
-        functions: current_provider.functions,
-        credential_controllers,
-    };
-
-    Ok(get_grpc_service()?.update_provider(core_provider))
+        .map(convert_provider_controller)
+        .collect::<Result<Vec<_>>>()?;
+    let summary = get_grpc_service()?.reconcile_providers_keeping_functions(providers);
+    Ok(js_types::ProviderReconcileSummary {
+        added: summary.added,
+        updated: summary.updated,
+        removed: summary.removed,
+    })
 }
 
 /// Add a function controller to a specific provider
@@ -239,12 +234,162 @@ pub fn update_provider(provider: js_types::ProviderController) -> Result<bool> {
 /// * `provider_type_id` - The type_id of the provider to add the function to
 /// * `function_metadata` - Object containing name, description, parameters, and output
 /// * `invoke_callback` - ThreadsafeFunction that will be called when the function is invoked
+/// * `invoke_stream_callback` - Optional ThreadsafeFunction used when the function is invoked as
+///   a stream; it sends each chunk through the [`FunctionChunkStream`] it is given
 #[napi(object)]
 pub struct FunctionMetadata {
     pub name: String,
     pub description: String,
     pub parameters: String,
     pub output: String,
+    /// Truncate outputs larger than this many bytes
+    pub max_output_bytes: Option<u32>,
+    /// Mark the function idempotent and cache identical calls for this many milliseconds
+    pub result_cache_ttl_ms: Option<u32>,
+    /// Flag the function as deprecated; it can still be invoked
+    pub deprecated: Option<bool>,
+    /// Guidance shown to callers of a deprecated function
+    pub deprecation_message: Option<String>,
+    /// Example invocations, validated against the parameter and output schemas
+    pub examples: Option<Vec<FunctionExample>>,
+    /// Fail invocations that run longer than this many milliseconds with "deadline_exceeded"
+    pub timeout_ms: Option<u32>,
+    /// Check returned data against the output schema, failing mismatches with "invalid_argument"
+    pub validate_output: Option<bool>,
+}
+
+/// Example invocation of a function; `parameters` and `output` are JSON strings
+#[napi(object)]
+pub struct FunctionExample {
+    pub description: Option<String>,
+    pub parameters: String,
+    pub output: Option<String>,
+}
+
+fn convert_concurrency_limit(
+    max_concurrency: Option<u32>,
+    max_queue_depth: Option<u32>,
+) -> Option<core_types::ProviderConcurrencyLimit> {
+    max_concurrency.map(|max_concurrency| {
+        core_types::ProviderConcurrencyLimit::new(
+            max_concurrency as usize,
+            max_queue_depth.map_or(usize::MAX, |depth| depth as usize),
+        )
+    })
+}
+
+fn convert_function_examples(
+    examples: Option<Vec<FunctionExample>>,
+) -> Vec<core_types::FunctionExample> {
+    examples
+        .unwrap_or_default()
+        .into_iter()
+        .map(|example| core_types::FunctionExample {
+            description: example.description,
+            parameters: example.parameters,
+            output: example.output,
+        })
+        .collect()
+}
+
+fn convert_log_lines(logs: Option<Vec<js_types::LogLine>>) -> Vec<core_types::LogLine> {
+    logs.unwrap_or_default()
+        .into_iter()
+        .map(|line| core_types::LogLine::from_binding(&line.level, line.message, line.timestamp_ms))
+        .collect()
+}
+
+/// Append a line to the logs of an in-flight invocation, identified by its request's
+/// `invocationId`. Unlike lines returned with the response, these are kept if the invocation
+/// times out. Returns false once the invocation has finished.
+#[napi]
+pub fn append_invocation_log(invocation_id: String, line: js_types::LogLine) -> bool {
+    core_types::append_invocation_log(
+        &invocation_id,
+        core_types::LogLine::from_binding(&line.level, line.message, line.timestamp_ms),
+    )
+}
+
+/// Sink a streaming invoke callback sends its chunks through, passed to the callback together
+/// with the request
+#[napi]
+pub struct FunctionChunkStream {
+    request: js_types::InvokeFunctionRequest,
+    chunks: Arc<Mutex<Option<mpsc::Sender<core_types::InvokeFunctionChunk>>>>,
+}
+
+#[napi]
+impl FunctionChunkStream {
+    #[napi(getter)]
+    pub fn request(&self) -> js_types::InvokeFunctionRequest {
+        self.request.clone()
+    }
+
+    /// Send the next chunk, waiting while the client is behind. Resolves to false once the
+    /// stream is closed (the client went away or the invocation ended); stop sending then.
+    #[napi]
+    pub async fn send(&self, chunk: js_types::InvokeFunctionChunk) -> Result<bool> {
+        let Some(chunks) = self.chunks.lock().clone() else {
+            return Ok(false);
+        };
+        Ok(chunks.send(convert_stream_chunk(chunk)).await.is_ok())
+    }
+}
+
+fn convert_stream_chunk(chunk: js_types::InvokeFunctionChunk) -> core_types::InvokeFunctionChunk {
+    let result = if let Some(data) = chunk.data {
+        Ok(data)
+    } else if let Some(error) = chunk.error {
+        Err(core_types::CallbackError::from_binding(
+            error.message,
+            error.code.as_deref(),
+            error.retry_after_ms.map(u64::from),
+        ))
+    } else {
+        Err(core_types::CallbackError::internal(
+            "JS chunk must contain .data or .error",
+        ))
+    };
+    core_types::InvokeFunctionChunk { result }
+}
+
+type InvokeStreamCallback = ThreadsafeFunction<FunctionChunkStream, Promise<()>>;
+
+/// Wraps a JS callback that drains the function's async generator into a
+/// [`FunctionChunkStream`] as a streaming implementation. The stream ends when the callback's
+/// promise settles.
+fn convert_invoke_stream_callback(callback: InvokeStreamCallback) -> core_types::InvokeStreamFn {
+    let callback = Arc::new(callback);
+    Arc::new(move |req: core_types::InvokeFunctionRequest, chunks| {
+        let callback = Arc::clone(&callback);
+        Box::pin(async move {
+            let chunks = Arc::new(Mutex::new(Some(chunks)));
+            let stream = FunctionChunkStream {
+                request: js_types::InvokeFunctionRequest {
+                    provider_controller_type_id: req.provider_controller_type_id,
+                    function_controller_type_id: req.function_controller_type_id,
+                    credential_controller_type_id: req.credential_controller_type_id,
+                    credentials: req.credentials,
+                    parameters: req.parameters,
+                    request_id: req.request_id,
+                    invocation_id: req.invocation_id,
+                },
+                chunks: Arc::clone(&chunks),
+            };
+
+            let result = match callback.call_async(Ok(stream)).await {
+                Ok(promise) => promise.await,
+                Err(e) => Err(e),
+            };
+            // The JS side may hold on to the sink until it is garbage collected, close it now
+            chunks.lock().take();
+            trace!("Streaming function invocation complete");
+
+            result.map_err(|e| {
+                CommonError::Unknown(anyhow::anyhow!("JavaScript function error: {}", e.reason))
+            })
+        })
+    })
 }
 
 #[napi]
@@ -255,6 +400,7 @@ pub fn add_function(
         js_types::InvokeFunctionRequest,
         Promise<js_types::InvokeFunctionResponse>,
     >,
+    invoke_stream_callback: Option<InvokeStreamCallback>,
 ) -> Result<bool> {
     let invoke_fn = Arc::new(invoke_callback);
 
@@ -263,6 +409,16 @@ pub fn add_function(
         description: function_metadata.description,
         parameters: function_metadata.parameters,
         output: function_metadata.output,
+        max_output_bytes: function_metadata.max_output_bytes.map(|b| b as usize),
+        result_cache: function_metadata
+            .result_cache_ttl_ms
+            .map(|ms| core_types::FunctionResultCache::new(Duration::from_millis(ms as u64))),
+        deprecated: function_metadata.deprecated.unwrap_or(false),
+        deprecation_message: function_metadata.deprecation_message,
+        examples: convert_function_examples(function_metadata.examples),
+        timeout_ms: function_metadata.timeout_ms.map(|ms| ms as u64),
+        validate_output: function_metadata.validate_output.unwrap_or(false),
+        output_validator: Default::default(),
         invoke: Arc::new(move |req: core_types::InvokeFunctionRequest| {
             let invoke_fn = Arc::clone(&invoke_fn);
             Box::pin(async move {
@@ -272,22 +428,22 @@ pub fn add_function(
                     credential_controller_type_id: req.credential_controller_type_id,
                     credentials: req.credentials,
                     parameters: req.parameters,
+                    request_id: req.request_id,
+                    invocation_id: req.invocation_id,
                 };
 
                 let result = invoke_fn
                     .call_async(Ok(js_req))
                     .await
                     .map_err(|e| core_types::InvokeFunctionResponse {
-                        result: Err(core_types::CallbackError {
-                            message: e.reason.clone(),
-                        }),
+                        result: Err(core_types::CallbackError::internal(e.reason.clone())),
+                        logs: vec![],
                     })
                     .unwrap()
                     .await
                     .map_err(|e| core_types::InvokeFunctionResponse {
-                        result: Err(core_types::CallbackError {
-                            message: e.reason.clone(),
-                        }),
+                        result: Err(core_types::CallbackError::internal(e.reason.clone())),
+                        logs: vec![],
                     })
                     .unwrap();
 
@@ -298,21 +454,31 @@ pub fn add_function(
                         result: if let Some(data) = result.data {
                             Ok(data)
                         } else if let Some(error) = result.error {
-                            Err(core_types::CallbackError {
-                                message: error.message,
-                            })
+                            Err(core_types::CallbackError::from_binding(
+                                error.message,
+                                error.code.as_deref(),
+                                error.retry_after_ms.map(u64::from),
+                            ))
                         } else {
-                            Err(core_types::CallbackError {
-                                message: "JS result must contain .data or .error".to_string(),
-                            })
+                            Err(core_types::CallbackError::internal(
+                                "JS result must contain .data or .error",
+                            ))
                         },
+                        logs: convert_log_lines(result.logs),
                     },
                 )
             })
         }),
+        invoke_stream: invoke_stream_callback.map(convert_invoke_stream_callback),
     };
 
-    Ok(get_grpc_service()?.add_function(&provider_type_id, core_function))
+    core_function
+        .validate_examples()
+        .map_err(|e| napi::Error::from_reason(e.to_string()))?;
+
+    get_grpc_service()?
+        .add_function(&provider_type_id, core_function)
+        .map_err(|e| napi::Error::from_reason(e.to_string()))
 }
 
 /// Remove a function controller from a specific provider
@@ -330,6 +496,7 @@ pub fn update_function(
         js_types::InvokeFunctionRequest,
         js_types::InvokeFunctionResponse,
     >,
+    invoke_stream_callback: Option<InvokeStreamCallback>,
 ) -> Result<bool> {
     trace!(function = %function_metadata.name, "Updating function");
     let invoke_fn = Arc::new(invoke_callback);
@@ -339,6 +506,16 @@ pub fn update_function(
         description: function_metadata.description,
         parameters: function_metadata.parameters,
         output: function_metadata.output,
+        max_output_bytes: function_metadata.max_output_bytes.map(|b| b as usize),
+        result_cache: function_metadata
+            .result_cache_ttl_ms
+            .map(|ms| core_types::FunctionResultCache::new(Duration::from_millis(ms as u64))),
+        deprecated: function_metadata.deprecated.unwrap_or(false),
+        deprecation_message: function_metadata.deprecation_message,
+        examples: convert_function_examples(function_metadata.examples),
+        timeout_ms: function_metadata.timeout_ms.map(|ms| ms as u64),
+        validate_output: function_metadata.validate_output.unwrap_or(false),
+        output_validator: Default::default(),
         invoke: Arc::new(move |req: core_types::InvokeFunctionRequest| {
             let invoke_fn = Arc::clone(&invoke_fn);
             Box::pin(async move {
@@ -348,6 +525,8 @@ pub fn update_function(
                     credential_controller_type_id: req.credential_controller_type_id,
                     credentials: req.credentials,
                     parameters: req.parameters,
+                    request_id: req.request_id,
+                    invocation_id: req.invocation_id,
                 };
 
                 let result = invoke_fn.call_async(Ok(js_req)).await;
@@ -355,35 +534,52 @@ pub fn update_function(
 
                 match result {
                     Ok(js_response) => {
+                        let logs = convert_log_lines(js_response.logs);
                         if let Some(data) = js_response.data {
-                            Ok(core_types::InvokeFunctionResponse { result: Ok(data) })
+                            Ok(core_types::InvokeFunctionResponse {
+                                result: Ok(data),
+                                logs,
+                            })
                         } else if let Some(error) = js_response.error {
                             Ok(core_types::InvokeFunctionResponse {
-                                result: Err(core_types::CallbackError {
-                                    message: error.message,
-                                }),
+                                result: Err(core_types::CallbackError::from_binding(
+                                    error.message,
+                                    error.code.as_deref(),
+                                    error.retry_after_ms.map(u64::from),
+                                )),
+                                logs,
                             })
                         } else {
                             Ok(core_types::InvokeFunctionResponse {
-                                result: Err(core_types::CallbackError {
-                                    message: "JS result must contain .data or .error".to_string(),
-                                }),
+                                result: Err(core_types::CallbackError::internal(
+                                    "JS result must contain .data or .error",
+                                )),
+                                logs,
                             })
                         }
                     }
                     Err(e) => Ok(core_types::InvokeFunctionResponse {
-                        result: Err(core_types::CallbackError {
-                            message: format!("JavaScript function error: {e}"),
-                        }),
+                        result: Err(core_types::CallbackError::internal(format!(
+                            "JavaScript function error: {e}"
+                        ))),
+                        logs: vec![],
                     }),
                 }
             })
         }),
+        invoke_stream: invoke_stream_callback.map(convert_invoke_stream_callback),
     };
 
-    Ok(get_grpc_service()?.update_function(&provider_type_id, core_function))
+    core_function
+        .validate_examples()
+        .map_err(|e| napi::Error::from_reason(e.to_string()))?;
+
+    get_grpc_service()?
+        .update_function(&provider_type_id, core_function)
+        .map_err(|e| napi::Error::from_reason(e.to_string()))
 }
 
+/// Add an agent. Fails when an agent with the same name is already registered in its project.
 #[napi]
 pub fn add_agent(agent: js_types::Agent) -> Result<bool> {
     let core_agent = core_types::Agent {
@@ -391,19 +587,62 @@ pub fn add_agent(agent: js_types::Agent) -> Result<bool> {
         project_id: agent.project_id,
         name: agent.name,
         description: agent.description,
+        allowed_functions: agent.allowed_functions.unwrap_or_default(),
     };
-    Ok(get_grpc_service()?.add_agent(core_agent))
+    get_grpc_service()?
+        .add_agent(core_agent)
+        .map_err(|e| napi::Error::from_reason(e.to_string()))?;
+    Ok(true)
 }
 
-/// Set the secret handler callback that will be called when secrets are synced from Soma
-/// The callback receives an array of secrets and should inject them into process.env
+/// Atomically add a batch of agents. Returns the conflicting ids; when any are returned,
+/// none of the agents are added.
 #[napi]
-pub fn set_secret_handler(
+pub fn add_agents_batch(agents: Vec<js_types::Agent>) -> Result<Vec<String>> {
+    let core_agents = agents
+        .into_iter()
+        .map(|agent| core_types::Agent {
+            id: agent.id,
+            project_id: agent.project_id,
+            name: agent.name,
+            description: agent.description,
+            allowed_functions: agent.allowed_functions.unwrap_or_default(),
+        })
+        .collect();
+    Ok(get_grpc_service()?.add_agents_batch(core_agents))
+}
+
+/// Convert JS KeyOutcomes to core types, rejecting unknown statuses
+fn convert_key_outcomes(
+    outcomes: Option<Vec<js_types::KeyOutcome>>,
+) -> std::result::Result<Vec<core_types::KeyOutcome>, CommonError> {
+    outcomes
+        .unwrap_or_default()
+        .into_iter()
+        .map(|outcome| {
+            let status = core_types::KeyOutcomeStatus::parse(&outcome.status).ok_or_else(|| {
+                CommonError::Unknown(anyhow::anyhow!(
+                    "Invalid status '{}' for key '{}', expected applied, skipped or failed",
+                    outcome.status,
+                    outcome.key
+                ))
+            })?;
+            Ok(core_types::KeyOutcome {
+                key: outcome.key,
+                status,
+                reason: outcome.reason,
+            })
+        })
+        .collect()
+}
+
+/// Wraps a JS secret callback as a core secret handler
+fn js_secret_handler(
     callback: ThreadsafeFunction<Vec<js_types::Secret>, Promise<js_types::SetSecretsResponse>>,
-) -> Result<()> {
+) -> core_types::SecretHandler {
     let callback = Arc::new(callback);
 
-    let handler: core_types::SecretHandler = Arc::new(move |secrets: Vec<core_types::Secret>| {
+    Arc::new(move |secrets: Vec<core_types::Secret>| {
         let callback = Arc::clone(&callback);
         trace!(count = secrets.len(), "Secret handler invoked");
         Box::pin(async move {
@@ -434,6 +673,7 @@ pub fn set_secret_handler(
                         Ok(core_types::SetSecretsResponse {
                             result: Ok(core_types::SetSecretsSuccess {
                                 message: data.message,
+                                outcomes: convert_key_outcomes(data.outcomes)?,
                             }),
                         })
                     } else if let Some(error) = js_response.error {
@@ -449,14 +689,34 @@ pub fn set_secret_handler(
                 )))),
             }
         })
-    });
+    })
+}
 
+/// Set the secret handler callback that will be called when secrets are synced from Soma
+/// The callback receives an array of secrets and should inject them into process.env
+#[napi]
+pub fn set_secret_handler(
+    callback: ThreadsafeFunction<Vec<js_types::Secret>, Promise<js_types::SetSecretsResponse>>,
+) -> Result<()> {
     trace!("Registering secret handler");
-    get_grpc_service()?.set_secret_handler(handler);
+    get_grpc_service()?.set_secret_handler(js_secret_handler(callback));
     trace!("Secret handler registered");
     Ok(())
 }
 
+/// Set the secret handler callback for secrets scoped to one provider type_id. Secrets for
+/// providers without their own handler go to the handler set by `setSecretHandler`.
+#[napi]
+pub fn set_secret_handler_for(
+    provider_type_id: String,
+    callback: ThreadsafeFunction<Vec<js_types::Secret>, Promise<js_types::SetSecretsResponse>>,
+) -> Result<()> {
+    trace!(provider_type_id = %provider_type_id, "Registering scoped secret handler");
+    get_grpc_service()?.set_secret_handler_for(&provider_type_id, js_secret_handler(callback));
+    trace!(provider_type_id = %provider_type_id, "Scoped secret handler registered");
+    Ok(())
+}
+
 /// Set the environment variable handler callback that will be called when environment variables are synced from Soma
 /// The callback receives an array of environment variables and should inject them into process.env
 #[napi]
@@ -503,6 +763,7 @@ pub fn set_environment_variable_handler(
                             Ok(core_types::SetEnvironmentVariablesResponse {
                                 result: Ok(core_types::SetEnvironmentVariablesSuccess {
                                     message: data.message,
+                                    outcomes: convert_key_outcomes(data.outcomes)?,
                                 }),
                             })
                         } else if let Some(error) = js_response.error {
@@ -647,6 +908,7 @@ pub fn update_agent(agent: js_types::Agent) -> Result<bool> {
         project_id: agent.project_id,
         name: agent.name,
         description: agent.description,
+        allowed_functions: agent.allowed_functions.unwrap_or_default(),
     };
     Ok(get_grpc_service()?.update_agent(core_agent))
 }
@@ -9,6 +9,9 @@ pub struct Agent {
     pub project_id: String,
     pub name: String,
     pub description: String,
+    /// Provider functions the agent may invoke, as "providerTypeId.functionTypeId".
+    /// Omitted or empty allows every function.
+    pub allowed_functions: Option<Vec<String>>,
 }
 
 #[napi(object)]
@@ -18,6 +21,10 @@ pub struct ProviderController {
     pub documentation: String,
     pub categories: Vec<String>,
     pub credential_controllers: Vec<ProviderCredentialController>,
+    /// Run at most this many of the provider's functions at once; further invocations queue
+    pub max_concurrency: Option<u32>,
+    /// Fail invocations with "rate_limited" once this many are queued. Unbounded when omitted.
+    pub max_queue_depth: Option<u32>,
 }
 
 #[napi(object)]
@@ -28,6 +35,24 @@ pub struct FunctionController {
     pub output: String,
 }
 
+/// A registered provider with its functions' parameter and output schemas
+#[napi(object)]
+pub struct ProviderWithFunctions {
+    pub type_id: String,
+    pub name: String,
+    pub documentation: String,
+    pub categories: Vec<String>,
+    pub functions: Vec<FunctionController>,
+}
+
+/// Provider type ids added, updated and removed by `reconcileProviders`
+#[napi(object)]
+pub struct ProviderReconcileSummary {
+    pub added: Vec<String>,
+    pub updated: Vec<String>,
+    pub removed: Vec<String>,
+}
+
 #[napi]
 pub enum ProviderCredentialController {
     NoAuth,
@@ -82,12 +107,31 @@ pub struct InvokeFunctionRequest {
     pub credential_controller_type_id: String,
     pub credentials: String,
     pub parameters: String,
+    pub request_id: Option<String>,
+    /// Pass to `appendInvocationLog` to log lines that are kept if the invocation times out
+    pub invocation_id: String,
 }
 
+/// Error returned by a callback. `code` is one of "rate_limited", "unauthorized",
+/// "invalid_argument", "internal", "retryable" or "deadline_exceeded"; an unknown code is
+/// treated as "internal". `retry_after_ms` is only read for "rate_limited" and "retryable".
 #[derive(Debug, Clone)]
 #[napi(object)]
 pub struct CallbackError {
     pub message: String,
+    pub code: Option<String>,
+    pub retry_after_ms: Option<u32>,
+}
+
+/// Line logged by a function while it ran. `level` is one of "debug", "info", "warn" or
+/// "error"; an unknown level is treated as "info". `timestamp_ms` defaults to when the
+/// response is received.
+#[derive(Debug, Clone)]
+#[napi(object)]
+pub struct LogLine {
+    pub level: String,
+    pub message: String,
+    pub timestamp_ms: Option<i64>,
 }
 
 #[derive(Debug, Clone)]
@@ -95,6 +139,15 @@ pub struct CallbackError {
 pub struct InvokeFunctionResponse {
     pub data: Option<String>,
     pub error: Option<CallbackError>,
+    pub logs: Option<Vec<LogLine>>,
+}
+
+/// Partial result sent by a streaming function: either `data` or `error`
+#[derive(Debug, Clone)]
+#[napi(object)]
+pub struct InvokeFunctionChunk {
+    pub data: Option<String>,
+    pub error: Option<CallbackError>,
 }
 
 #[napi(object)]
@@ -146,10 +199,22 @@ pub struct Secret {
     pub value: String,
 }
 
+/// Outcome of applying a single secret or environment variable key.
+/// `status` is one of "applied", "skipped" or "failed".
+#[derive(Debug, Clone)]
+#[napi(object)]
+pub struct KeyOutcome {
+    pub key: String,
+    pub status: String,
+    pub reason: Option<String>,
+}
+
 #[derive(Debug, Clone)]
 #[napi(object)]
 pub struct SetSecretsSuccess {
     pub message: String,
+    /// Per-key outcomes; keys without an entry are treated as applied
+    pub outcomes: Option<Vec<KeyOutcome>>,
 }
 
 /// Response from setting secrets
@@ -170,6 +235,8 @@ pub struct EnvironmentVariable {
 #[napi(object)]
 pub struct SetEnvironmentVariablesSuccess {
     pub message: String,
+    /// Per-key outcomes; keys without an entry are treated as applied
+    pub outcomes: Option<Vec<KeyOutcome>>,
 }
 
 /// Response from setting environment variables
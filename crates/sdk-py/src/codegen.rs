@@ -30,6 +30,15 @@ pub struct FunctionControllerData {
     pub display_name: String,
     pub params_json_schema: Option<serde_json::Value>,
     pub return_value_json_schema: Option<serde_json::Value>,
+    pub examples: Vec<FunctionExampleData>,
+}
+
+/// Example invocation of a function; `parameters` and `output` are JSON strings
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FunctionExampleData {
+    pub description: Option<String>,
+    pub parameters: String,
+    pub output: Option<String>,
 }
 
 /// Serializable structure for provider in template
@@ -59,6 +68,8 @@ struct FunctionData {
     params_type_classes: Vec<TypedDictClass>,
     return_type_name: String,
     return_type_classes: Vec<TypedDictClass>,
+    /// Listed in the method's docstring
+    examples: Vec<FunctionExampleData>,
 }
 
 /// Represents a TypedDict class definition
@@ -184,6 +195,12 @@ pub fn generate_python_code_from_api_data(
                     params_type_classes,
                     return_type_name,
                     return_type_classes,
+                    examples: func_data
+                        .function_controller
+                        .examples
+                        .iter()
+                        .map(example_for_docstring)
+                        .collect(),
                 });
             }
 
@@ -718,6 +735,20 @@ fn strip_provider_prefix_and_snake_case(function_name: &str, provider_name: &str
     }
 }
 
+/// Escapes an example so it cannot terminate the surrounding docstring
+fn example_for_docstring(example: &FunctionExampleData) -> FunctionExampleData {
+    let escape = |text: &str| {
+        text.replace('\\', "\\\\")
+            .replace("\"\"\"", "\\\"\"\"")
+            .replace('\n', " ")
+    };
+    FunctionExampleData {
+        description: example.description.as_deref().map(escape),
+        parameters: escape(&example.parameters),
+        output: example.output.as_deref().map(escape),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -817,4 +848,31 @@ mod tests {
         let bool_schema: serde_json::Value = serde_json::json!({"type": "boolean"});
         assert_eq!(json_schema_to_python(&bool_schema, 0).unwrap(), "bool");
     }
+
+    #[test]
+    fn test_examples_are_listed_in_docstrings() {
+        let code = generate_python_code_from_api_data(&[FunctionInstanceData {
+            provider_instance_id: "instance-1".to_string(),
+            provider_instance_display_name: "default".to_string(),
+            provider_controller: ProviderControllerData {
+                type_id: "mail".to_string(),
+                display_name: "Mail".to_string(),
+            },
+            function_controller: FunctionControllerData {
+                type_id: "send".to_string(),
+                display_name: "Send".to_string(),
+                params_json_schema: Some(serde_json::json!({"type": "object"})),
+                return_value_json_schema: Some(serde_json::json!({"type": "object"})),
+                examples: vec![FunctionExampleData {
+                    description: Some("Send to one recipient".to_string()),
+                    parameters: r#"{"to":"a@example.com"}"#.to_string(),
+                    output: Some(r#"{"id":"msg-*/1"}"#.to_string()),
+                }],
+            },
+        }])
+        .unwrap();
+
+        assert!(code.contains("Example (Send to one recipient):"));
+        assert!(code.contains(r#"params: {"to":"a@example.com"}"#));
+    }
 }
@@ -65,6 +65,21 @@ impl core_types::SdkCodeGenerator for PythonCodeGenerator {
                         .function_controller
                         .as_ref()
                         .and_then(|fc| serde_json::from_str(&fc.return_value_json_schema).ok()),
+                    examples: fi
+                        .function_controller
+                        .as_ref()
+                        .map(|fc| {
+                            fc.examples
+                                .iter()
+                                .map(|example| codegen::FunctionExampleData {
+                                    description: Some(example.description.clone())
+                                        .filter(|d| !d.is_empty()),
+                                    parameters: example.parameters.clone(),
+                                    output: Some(example.output.clone()).filter(|o| !o.is_empty()),
+                                })
+                                .collect()
+                        })
+                        .unwrap_or_default(),
                 },
             })
             .collect();
@@ -14,6 +14,7 @@ use sdk_core as core_types;
 use shared::error::CommonError;
 use std::path::PathBuf;
 use std::sync::Arc;
+use std::time::Duration;
 use tracing::{debug, trace};
 use types as py_types;
 
@@ -21,6 +22,9 @@ use types as py_types;
 static GRPC_SERVICE: Mutex<Option<Arc<core_types::GrpcService<PythonCodeGenerator>>>> =
     Mutex::new(None);
 
+// Stops the server behind GRPC_SERVICE; taken by kill_grpc_service
+static SHUTDOWN_HANDLE: Mutex<Option<core_types::ShutdownHandle>> = Mutex::new(None);
+
 fn get_grpc_service() -> PyResult<Arc<core_types::GrpcService<PythonCodeGenerator>>> {
     GRPC_SERVICE.lock().clone().ok_or_else(|| {
         pyo3::exceptions::PyRuntimeError::new_err(
@@ -124,9 +128,187 @@ fn convert_provider_controller(
         categories: provider.categories.clone(),
         functions: vec![],
         credential_controllers,
+        concurrency_limit: provider.max_concurrency.map(|max_concurrency| {
+            core_types::ProviderConcurrencyLimit::new(
+                max_concurrency,
+                provider.max_queue_depth.unwrap_or(usize::MAX),
+            )
+        }),
     }
 }
 
+/// Convert Python FunctionExamples to core types
+fn convert_function_examples(
+    examples: &[py_types::FunctionExample],
+) -> Vec<core_types::FunctionExample> {
+    examples
+        .iter()
+        .map(|example| core_types::FunctionExample {
+            description: example.description.clone(),
+            parameters: example.parameters.clone(),
+            output: example.output.clone(),
+        })
+        .collect()
+}
+
+/// Convert Python log lines to core types
+fn convert_log_lines(logs: Vec<py_types::LogLine>) -> Vec<core_types::LogLine> {
+    logs.into_iter()
+        .map(|line| core_types::LogLine::from_binding(&line.level, line.message, line.timestamp_ms))
+        .collect()
+}
+
+/// Append a line to the logs of an in-flight invocation, identified by its request's
+/// `invocation_id`. Unlike lines returned with the response, these are kept if the invocation
+/// times out. Returns false once the invocation has finished.
+#[pyfunction]
+#[pyo3(signature = (invocation_id, line, /) -> "bool")]
+pub fn append_invocation_log(invocation_id: String, line: py_types::LogLine) -> bool {
+    core_types::append_invocation_log(
+        &invocation_id,
+        core_types::LogLine::from_binding(&line.level, line.message, line.timestamp_ms),
+    )
+}
+
+/// Convert a core invocation request to the Python type
+fn convert_invoke_request(
+    req: &core_types::InvokeFunctionRequest,
+) -> py_types::InvokeFunctionRequest {
+    py_types::InvokeFunctionRequest {
+        provider_controller_type_id: req.provider_controller_type_id.clone(),
+        function_controller_type_id: req.function_controller_type_id.clone(),
+        credential_controller_type_id: req.credential_controller_type_id.clone(),
+        credentials: req.credentials.clone(),
+        parameters: req.parameters.clone(),
+        request_id: req.request_id.clone(),
+        invocation_id: req.invocation_id.clone(),
+    }
+}
+
+/// Convert a response yielded by a streaming Python callback to a chunk
+fn convert_stream_chunk(
+    response: py_types::InvokeFunctionResponse,
+) -> core_types::InvokeFunctionChunk {
+    let result = if let Some(data) = response.data {
+        Ok(data)
+    } else if let Some(error) = response.error {
+        Err(core_types::CallbackError::from_binding(
+            error.message,
+            error.code.as_deref(),
+            error.retry_after_ms,
+        ))
+    } else {
+        Err(core_types::CallbackError::internal(
+            "Python result must contain data or error",
+        ))
+    };
+    core_types::InvokeFunctionChunk { result }
+}
+
+/// Advances a sync or async iterator by one item, running async steps on `event_loop`.
+/// Returns `None` once the iterator is exhausted.
+fn next_stream_item(
+    items: &Bound<'_, PyAny>,
+    event_loop: &Bound<'_, PyAny>,
+) -> PyResult<Option<py_types::InvokeFunctionResponse>> {
+    let py = items.py();
+    let item = if items.hasattr("__anext__")? {
+        match event_loop.call_method1("run_until_complete", (items.call_method0("__anext__")?,)) {
+            Ok(item) => item,
+            Err(e) if e.is_instance_of::<pyo3::exceptions::PyStopAsyncIteration>(py) => {
+                return Ok(None);
+            }
+            Err(e) => return Err(e),
+        }
+    } else {
+        match items.call_method0("__next__") {
+            Ok(item) => item,
+            Err(e) if e.is_instance_of::<pyo3::exceptions::PyStopIteration>(py) => {
+                return Ok(None);
+            }
+            Err(e) => return Err(e),
+        }
+    };
+    item.extract().map(Some)
+}
+
+/// Wraps a Python callback returning an (async) iterator of `InvokeFunctionResponse` as a
+/// streaming implementation. Each item is sent as one chunk; async generators run on an event
+/// loop owned by the invocation, which is closed once the stream ends.
+fn convert_invoke_stream_callback(callback: Py<PyAny>) -> core_types::InvokeStreamFn {
+    let callback = Arc::new(callback);
+    Arc::new(move |req: core_types::InvokeFunctionRequest, chunks| {
+        let callback = Arc::clone(&callback);
+        Box::pin(async move {
+            let (items, event_loop) = Python::attach(|py| -> PyResult<_> {
+                let items = callback.call1(py, (convert_invoke_request(&req),))?;
+                let event_loop = py.import("asyncio")?.call_method0("new_event_loop")?;
+                Ok((items, event_loop.unbind()))
+            })
+            .map_err(|e| CommonError::Unknown(anyhow::anyhow!("Python function error: {e}")))?;
+
+            let mut result = Ok(());
+            loop {
+                let next =
+                    Python::attach(|py| next_stream_item(items.bind(py), event_loop.bind(py)));
+                match next {
+                    Ok(Some(response)) => {
+                        // The client went away, stop pulling from the handler
+                        if chunks.send(convert_stream_chunk(response)).await.is_err() {
+                            break;
+                        }
+                    }
+                    Ok(None) => break,
+                    Err(e) => {
+                        result = Err(CommonError::Unknown(anyhow::anyhow!(
+                            "Python function error: {e}"
+                        )));
+                        break;
+                    }
+                }
+            }
+
+            Python::attach(|py| {
+                let items = items.bind(py);
+                let event_loop = event_loop.bind(py);
+                // Let an abandoned async generator run its cleanup
+                if items.hasattr("aclose").unwrap_or(false)
+                    && let Ok(close) = items.call_method0("aclose")
+                {
+                    let _ = event_loop.call_method1("run_until_complete", (close,));
+                }
+                if let Err(e) = event_loop.call_method0("close") {
+                    debug!(error = %e, "Failed to close stream event loop");
+                }
+            });
+            result
+        })
+    })
+}
+
+/// Convert Python KeyOutcomes to core types, rejecting unknown statuses
+fn convert_key_outcomes(
+    outcomes: Vec<py_types::KeyOutcome>,
+) -> Result<Vec<core_types::KeyOutcome>, CommonError> {
+    outcomes
+        .into_iter()
+        .map(|outcome| {
+            let status = core_types::KeyOutcomeStatus::parse(&outcome.status).ok_or_else(|| {
+                CommonError::Unknown(anyhow::anyhow!(
+                    "Invalid status '{}' for key '{}', expected applied, skipped or failed",
+                    outcome.status,
+                    outcome.key
+                ))
+            })?;
+            Ok(core_types::KeyOutcome {
+                key: outcome.key,
+                status,
+                reason: outcome.reason,
+            })
+        })
+        .collect()
+}
+
 /// Start the gRPC server on a Unix socket with Python code generation
 #[pyfunction]
 #[pyo3(signature = (socket_path, project_dir, /) -> "typing.Awaitable[None]")]
@@ -141,24 +323,35 @@ pub fn start_grpc_server(
 
         let code_generator = PythonCodeGenerator::new(project_dir);
 
-        let service = core_types::start_grpc_server(vec![], socket_path, code_generator)
-            .await
-            .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?;
+        let (service, shutdown_handle) =
+            core_types::start_grpc_server(vec![], socket_path, code_generator)
+                .await
+                .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?;
 
         // Store the service, replacing any existing one
-        let mut guard = GRPC_SERVICE.lock();
-        *guard = Some(service);
+        *GRPC_SERVICE.lock() = Some(service);
+        *SHUTDOWN_HANDLE.lock() = Some(shutdown_handle);
 
         Ok(())
     })
 }
 
 /// Kill/clear the gRPC service, removing all providers, agents, and handlers.
-/// This allows the service to be restarted fresh.
+/// Blocks until the server has stopped and its socket is removed, so the service can be
+/// restarted fresh on the same path.
 #[pyfunction]
 #[pyo3(signature = () -> "None")]
-pub fn kill_grpc_service() -> PyResult<()> {
+pub fn kill_grpc_service(py: Python) -> PyResult<()> {
     debug!("Killing gRPC service");
+    let shutdown_handle = SHUTDOWN_HANDLE.lock().take();
+    if let Some(shutdown_handle) = shutdown_handle {
+        // Release the GIL so in-flight Python callbacks can finish
+        py.detach(|| {
+            pyo3_async_runtimes::tokio::get_runtime().block_on(shutdown_handle.shutdown())
+        })
+        .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?;
+    }
+
     let mut guard = GRPC_SERVICE.lock();
     if let Some(service) = guard.as_ref() {
         // Clear the service state
@@ -179,6 +372,32 @@ pub fn add_provider(provider: py_types::ProviderController) -> PyResult<()> {
     Ok(())
 }
 
+/// List every registered provider with its functions' schemas
+#[pyfunction]
+#[pyo3(signature = () -> "list[ProviderWithFunctions]")]
+pub fn list_providers() -> PyResult<Vec<py_types::ProviderWithFunctions>> {
+    Ok(get_grpc_service()?
+        .list_providers_with_functions()
+        .into_iter()
+        .map(|provider| py_types::ProviderWithFunctions {
+            type_id: provider.type_id,
+            name: provider.name,
+            documentation: provider.documentation,
+            categories: provider.categories,
+            functions: provider
+                .functions
+                .into_iter()
+                .map(|function| py_types::FunctionController {
+                    name: function.name,
+                    description: function.description,
+                    parameters: function.parameters,
+                    output: function.output,
+                })
+                .collect(),
+        })
+        .collect())
+}
+
 /// Remove a provider controller by type_id
 #[pyfunction]
 #[pyo3(signature = (type_id, /) -> "bool")]
@@ -186,35 +405,35 @@ pub fn remove_provider(type_id: String) -> PyResult<bool> {
     Ok(get_grpc_service()?.remove_provider(&type_id))
 }
 
-/// Update a provider controller (removes old and inserts new)
+/// Update a provider controller, keeping the functions already registered on it
 #[pyfunction]
 #[pyo3(signature = (provider, /) -> "bool")]
 pub fn update_provider(provider: py_types::ProviderController) -> PyResult<bool> {
-    let current_provider = get_grpc_service()?.get_provider(&provider.type_id);
-    let current_provider = if let Some(current_provider) = current_provider {
-        current_provider
-    } else {
+    let core_provider = convert_provider_controller(&provider);
+
+    // Functions are registered separately, keep the ones already on the provider
+    if !get_grpc_service()?.update_provider_keeping_functions(core_provider) {
         return Err(pyo3::exceptions::PyValueError::new_err(
             "Provider not found",
         ));
-    };
-
-    let credential_controllers: Vec<core_types::ProviderCredentialController> = provider
-        .credential_controllers
-        .iter()
-        .map(convert_credential_controller)
-        .collect();
-
-    let core_provider = core_types::ProviderController {
-        type_id: provider.type_id.clone(),
-        name: provider.name.clone(),
-        documentation: provider.documentation.clone(),
-        categories: provider.categories.clone(),
-        functions: current_provider.functions,
-        credential_controllers,
-    };
+    }
+    Ok(true)
+}
 
-    Ok(get_grpc_service()?.update_provider(core_provider))
+/// Replace the registered providers with `providers` in one step. Providers missing from the
+/// list are removed; functions already registered on a kept provider stay registered.
+#[pyfunction]
+#[pyo3(signature = (providers, /) -> "ProviderReconcileSummary")]
+pub fn reconcile_providers(
+    providers: Vec<py_types::ProviderController>,
+) -> PyResult<py_types::ProviderReconcileSummary> {
+    let providers = providers.iter().map(convert_provider_controller).collect();
+    let summary = get_grpc_service()?.reconcile_providers_keeping_functions(providers);
+    Ok(py_types::ProviderReconcileSummary {
+        added: summary.added,
+        updated: summary.updated,
+        removed: summary.removed,
+    })
 }
 
 /// Add a function controller to a specific provider
@@ -223,12 +442,15 @@ pub fn update_provider(provider: py_types::ProviderController) -> PyResult<bool>
 /// * `provider_type_id` - The type_id of the provider to add the function to
 /// * `function_metadata` - Object containing name, description, parameters, and output
 /// * `invoke_callback` - Python callable that will be called when the function is invoked
+/// * `invoke_stream_callback` - Optional callable returning an async generator (or iterator) of
+///   responses, used when the function is invoked as a stream
 #[pyfunction]
-#[pyo3(signature = (provider_type_id, function_metadata, invoke_callback: "typing.Callable[[InvokeFunctionRequest], InvokeFunctionResponse]", /) -> "bool")]
+#[pyo3(signature = (provider_type_id, function_metadata, invoke_callback: "typing.Callable[[InvokeFunctionRequest], InvokeFunctionResponse]", invoke_stream_callback: "typing.Callable[[InvokeFunctionRequest], typing.AsyncIterator[InvokeFunctionResponse] | typing.Iterator[InvokeFunctionResponse]] | None" = None, /) -> "bool")]
 pub fn add_function(
     provider_type_id: String,
     function_metadata: py_types::FunctionMetadata,
     invoke_callback: Py<PyAny>,
+    invoke_stream_callback: Option<Py<PyAny>>,
 ) -> PyResult<bool> {
     let callback = Arc::new(invoke_callback);
 
@@ -237,6 +459,16 @@ pub fn add_function(
         description: function_metadata.description.clone(),
         parameters: function_metadata.parameters.clone(),
         output: function_metadata.output.clone(),
+        max_output_bytes: function_metadata.max_output_bytes,
+        result_cache: function_metadata
+            .result_cache_ttl_ms
+            .map(|ms| core_types::FunctionResultCache::new(Duration::from_millis(ms))),
+        deprecated: function_metadata.deprecated,
+        deprecation_message: function_metadata.deprecation_message.clone(),
+        examples: convert_function_examples(&function_metadata.examples),
+        timeout_ms: function_metadata.timeout_ms,
+        validate_output: function_metadata.validate_output,
+        output_validator: Default::default(),
         invoke: Arc::new(move |req: core_types::InvokeFunctionRequest| {
             let callback = Arc::clone(&callback);
             Box::pin(async move {
@@ -248,6 +480,8 @@ pub fn add_function(
                         credential_controller_type_id: req.credential_controller_type_id.clone(),
                         credentials: req.credentials.clone(),
                         parameters: req.parameters.clone(),
+                        request_id: req.request_id.clone(),
+                        invocation_id: req.invocation_id.clone(),
                     };
 
                     // Call the Python function
@@ -258,34 +492,43 @@ pub fn add_function(
                             // Try to extract InvokeFunctionResponse
                             match py_result.extract::<py_types::InvokeFunctionResponse>(py) {
                                 Ok(response) => {
+                                    let logs = convert_log_lines(response.logs);
                                     if let Some(data) = response.data {
-                                        Ok(core_types::InvokeFunctionResponse { result: Ok(data) })
+                                        Ok(core_types::InvokeFunctionResponse {
+                                            result: Ok(data),
+                                            logs,
+                                        })
                                     } else if let Some(error) = response.error {
                                         Ok(core_types::InvokeFunctionResponse {
-                                            result: Err(core_types::CallbackError {
-                                                message: error.message,
-                                            }),
+                                            result: Err(core_types::CallbackError::from_binding(
+                                                error.message,
+                                                error.code.as_deref(),
+                                                error.retry_after_ms,
+                                            )),
+                                            logs,
                                         })
                                     } else {
                                         Ok(core_types::InvokeFunctionResponse {
-                                            result: Err(core_types::CallbackError {
-                                                message: "Python result must contain data or error"
-                                                    .to_string(),
-                                            }),
+                                            result: Err(core_types::CallbackError::internal(
+                                                "Python result must contain data or error",
+                                            )),
+                                            logs,
                                         })
                                     }
                                 }
                                 Err(e) => Ok(core_types::InvokeFunctionResponse {
-                                    result: Err(core_types::CallbackError {
-                                        message: format!("Failed to extract response: {e}"),
-                                    }),
+                                    result: Err(core_types::CallbackError::internal(format!(
+                                        "Failed to extract response: {e}"
+                                    ))),
+                                    logs: vec![],
                                 }),
                             }
                         }
                         Err(e) => Ok(core_types::InvokeFunctionResponse {
-                            result: Err(core_types::CallbackError {
-                                message: format!("Python function error: {e}"),
-                            }),
+                            result: Err(core_types::CallbackError::internal(format!(
+                                "Python function error: {e}"
+                            ))),
+                            logs: vec![],
                         }),
                     }
                 });
@@ -295,9 +538,16 @@ pub fn add_function(
                 })
             })
         }),
+        invoke_stream: invoke_stream_callback.map(convert_invoke_stream_callback),
     };
 
-    Ok(get_grpc_service()?.add_function(&provider_type_id, core_function))
+    core_function
+        .validate_examples()
+        .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+
+    get_grpc_service()?
+        .add_function(&provider_type_id, core_function)
+        .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))
 }
 
 /// Remove a function controller from a specific provider
@@ -309,11 +559,12 @@ pub fn remove_function(provider_type_id: String, function_name: String) -> PyRes
 
 /// Update a function controller (removes old and inserts new)
 #[pyfunction]
-#[pyo3(signature = (provider_type_id, function_metadata, invoke_callback: "typing.Callable[[InvokeFunctionRequest], InvokeFunctionResponse]", /) -> "bool")]
+#[pyo3(signature = (provider_type_id, function_metadata, invoke_callback: "typing.Callable[[InvokeFunctionRequest], InvokeFunctionResponse]", invoke_stream_callback: "typing.Callable[[InvokeFunctionRequest], typing.AsyncIterator[InvokeFunctionResponse] | typing.Iterator[InvokeFunctionResponse]] | None" = None, /) -> "bool")]
 pub fn update_function(
     provider_type_id: String,
     function_metadata: py_types::FunctionMetadata,
     invoke_callback: Py<PyAny>,
+    invoke_stream_callback: Option<Py<PyAny>>,
 ) -> PyResult<bool> {
     trace!(function = %function_metadata.name, "Updating function");
     let callback = Arc::new(invoke_callback);
@@ -323,6 +574,16 @@ pub fn update_function(
         description: function_metadata.description.clone(),
         parameters: function_metadata.parameters.clone(),
         output: function_metadata.output.clone(),
+        max_output_bytes: function_metadata.max_output_bytes,
+        result_cache: function_metadata
+            .result_cache_ttl_ms
+            .map(|ms| core_types::FunctionResultCache::new(Duration::from_millis(ms))),
+        deprecated: function_metadata.deprecated,
+        deprecation_message: function_metadata.deprecation_message.clone(),
+        examples: convert_function_examples(&function_metadata.examples),
+        timeout_ms: function_metadata.timeout_ms,
+        validate_output: function_metadata.validate_output,
+        output_validator: Default::default(),
         invoke: Arc::new(move |req: core_types::InvokeFunctionRequest| {
             let callback = Arc::clone(&callback);
             Box::pin(async move {
@@ -333,6 +594,8 @@ pub fn update_function(
                         credential_controller_type_id: req.credential_controller_type_id.clone(),
                         credentials: req.credentials.clone(),
                         parameters: req.parameters.clone(),
+                        request_id: req.request_id.clone(),
+                        invocation_id: req.invocation_id.clone(),
                     };
 
                     let result = callback.call1(py, (py_req,));
@@ -341,34 +604,43 @@ pub fn update_function(
                         Ok(py_result) => {
                             match py_result.extract::<py_types::InvokeFunctionResponse>(py) {
                                 Ok(response) => {
+                                    let logs = convert_log_lines(response.logs);
                                     if let Some(data) = response.data {
-                                        Ok(core_types::InvokeFunctionResponse { result: Ok(data) })
+                                        Ok(core_types::InvokeFunctionResponse {
+                                            result: Ok(data),
+                                            logs,
+                                        })
                                     } else if let Some(error) = response.error {
                                         Ok(core_types::InvokeFunctionResponse {
-                                            result: Err(core_types::CallbackError {
-                                                message: error.message,
-                                            }),
+                                            result: Err(core_types::CallbackError::from_binding(
+                                                error.message,
+                                                error.code.as_deref(),
+                                                error.retry_after_ms,
+                                            )),
+                                            logs,
                                         })
                                     } else {
                                         Ok(core_types::InvokeFunctionResponse {
-                                            result: Err(core_types::CallbackError {
-                                                message: "Python result must contain data or error"
-                                                    .to_string(),
-                                            }),
+                                            result: Err(core_types::CallbackError::internal(
+                                                "Python result must contain data or error",
+                                            )),
+                                            logs,
                                         })
                                     }
                                 }
                                 Err(e) => Ok(core_types::InvokeFunctionResponse {
-                                    result: Err(core_types::CallbackError {
-                                        message: format!("Failed to extract response: {e}"),
-                                    }),
+                                    result: Err(core_types::CallbackError::internal(format!(
+                                        "Failed to extract response: {e}"
+                                    ))),
+                                    logs: vec![],
                                 }),
                             }
                         }
                         Err(e) => Ok(core_types::InvokeFunctionResponse {
-                            result: Err(core_types::CallbackError {
-                                message: format!("Python function error: {e}"),
-                            }),
+                            result: Err(core_types::CallbackError::internal(format!(
+                                "Python function error: {e}"
+                            ))),
+                            logs: vec![],
                         }),
                     }
                 });
@@ -378,12 +650,20 @@ pub fn update_function(
                 })
             })
         }),
+        invoke_stream: invoke_stream_callback.map(convert_invoke_stream_callback),
     };
 
-    Ok(get_grpc_service()?.update_function(&provider_type_id, core_function))
+    core_function
+        .validate_examples()
+        .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+
+    get_grpc_service()?
+        .update_function(&provider_type_id, core_function)
+        .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))
 }
 
-/// Add an agent to the running server
+/// Add an agent to the running server. Raises `ValueError` when an agent with the same name
+/// is already registered in its project.
 #[pyfunction]
 #[pyo3(signature = (agent, /) -> "bool")]
 pub fn add_agent(agent: py_types::Agent) -> PyResult<bool> {
@@ -392,8 +672,30 @@ pub fn add_agent(agent: py_types::Agent) -> PyResult<bool> {
         project_id: agent.project_id,
         name: agent.name,
         description: agent.description,
+        allowed_functions: agent.allowed_functions.unwrap_or_default(),
     };
-    Ok(get_grpc_service()?.add_agent(core_agent))
+    get_grpc_service()?
+        .add_agent(core_agent)
+        .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+    Ok(true)
+}
+
+/// Atomically add a batch of agents. Returns the conflicting ids; when any are returned,
+/// none of the agents are added.
+#[pyfunction]
+#[pyo3(signature = (agents, /) -> "list[str]")]
+pub fn add_agents_batch(agents: Vec<py_types::Agent>) -> PyResult<Vec<String>> {
+    let core_agents = agents
+        .into_iter()
+        .map(|agent| core_types::Agent {
+            id: agent.id,
+            project_id: agent.project_id,
+            name: agent.name,
+            description: agent.description,
+            allowed_functions: agent.allowed_functions.unwrap_or_default(),
+        })
+        .collect();
+    Ok(get_grpc_service()?.add_agents_batch(core_agents))
 }
 
 /// Remove an agent by id
@@ -412,18 +714,16 @@ pub fn update_agent(agent: py_types::Agent) -> PyResult<bool> {
         project_id: agent.project_id,
         name: agent.name,
         description: agent.description,
+        allowed_functions: agent.allowed_functions.unwrap_or_default(),
     };
     Ok(get_grpc_service()?.update_agent(core_agent))
 }
 
-/// Set the secret handler callback that will be called when secrets are synced from Soma
-/// The callback receives a list of secrets and should inject them into os.environ
-#[pyfunction]
-#[pyo3(signature = (callback: "typing.Callable[[list[Secret]], SetSecretsResponse]", /) -> "None")]
-pub fn set_secret_handler(callback: Py<PyAny>) -> PyResult<()> {
+/// Wraps a Python secret callback as a core secret handler
+fn py_secret_handler(callback: Py<PyAny>) -> core_types::SecretHandler {
     let callback = Arc::new(callback);
 
-    let handler: core_types::SecretHandler = Arc::new(move |secrets: Vec<core_types::Secret>| {
+    Arc::new(move |secrets: Vec<core_types::Secret>| {
         let callback: Arc<Py<PyAny>> = Arc::clone(&callback);
         trace!(count = secrets.len(), "Secret handler invoked");
 
@@ -448,6 +748,7 @@ pub fn set_secret_handler(callback: Py<PyAny>) -> PyResult<()> {
                                 Ok(core_types::SetSecretsResponse {
                                     result: Ok(core_types::SetSecretsSuccess {
                                         message: data.message,
+                                        outcomes: convert_key_outcomes(data.outcomes)?,
                                     }),
                                 })
                             } else if let Some(error) = response.error {
@@ -470,14 +771,31 @@ pub fn set_secret_handler(callback: Py<PyAny>) -> PyResult<()> {
 
             result
         })
-    });
+    })
+}
 
+/// Set the secret handler callback that will be called when secrets are synced from Soma
+/// The callback receives a list of secrets and should inject them into os.environ
+#[pyfunction]
+#[pyo3(signature = (callback: "typing.Callable[[list[Secret]], SetSecretsResponse]", /) -> "None")]
+pub fn set_secret_handler(callback: Py<PyAny>) -> PyResult<()> {
     trace!("Registering secret handler");
-    get_grpc_service()?.set_secret_handler(handler);
+    get_grpc_service()?.set_secret_handler(py_secret_handler(callback));
     trace!("Secret handler registered");
     Ok(())
 }
 
+/// Set the secret handler callback for secrets scoped to one provider type_id. Secrets for
+/// providers without their own handler go to the handler set by set_secret_handler.
+#[pyfunction]
+#[pyo3(signature = (provider_type_id, callback: "typing.Callable[[list[Secret]], SetSecretsResponse]", /) -> "None")]
+pub fn set_secret_handler_for(provider_type_id: String, callback: Py<PyAny>) -> PyResult<()> {
+    trace!(provider_type_id = %provider_type_id, "Registering scoped secret handler");
+    get_grpc_service()?.set_secret_handler_for(&provider_type_id, py_secret_handler(callback));
+    trace!(provider_type_id = %provider_type_id, "Scoped secret handler registered");
+    Ok(())
+}
+
 /// Set the environment variable handler callback
 #[pyfunction]
 #[pyo3(signature = (callback: "typing.Callable[[list[EnvironmentVariable]], SetEnvironmentVariablesResponse]", /) -> "None")]
@@ -516,6 +834,7 @@ pub fn set_environment_variable_handler(callback: Py<PyAny>) -> PyResult<()> {
                                             result: Ok(
                                                 core_types::SetEnvironmentVariablesSuccess {
                                                     message: data.message,
+                                                    outcomes: convert_key_outcomes(data.outcomes)?,
                                                 },
                                             ),
                                         })
@@ -681,12 +1000,20 @@ pub mod trysoma_sdk_core {
     #[pymodule_export]
     pub use super::add_agent;
     #[pymodule_export]
+    pub use super::add_agents_batch;
+    #[pymodule_export]
     pub use super::add_function;
     #[pymodule_export]
     pub use super::add_provider;
     #[pymodule_export]
+    pub use super::append_invocation_log;
+    #[pymodule_export]
     pub use super::kill_grpc_service;
     #[pymodule_export]
+    pub use super::list_providers;
+    #[pymodule_export]
+    pub use super::reconcile_providers;
+    #[pymodule_export]
     pub use super::remove_agent;
     #[pymodule_export]
     pub use super::remove_function;
@@ -699,6 +1026,8 @@ pub mod trysoma_sdk_core {
     #[pymodule_export]
     pub use super::set_secret_handler;
     #[pymodule_export]
+    pub use super::set_secret_handler_for;
+    #[pymodule_export]
     pub use super::set_unset_environment_variable_handler;
     #[pymodule_export]
     pub use super::set_unset_secret_handler;
@@ -721,12 +1050,18 @@ pub mod trysoma_sdk_core {
     #[pymodule_export]
     pub use super::py_types::FunctionController;
     #[pymodule_export]
+    pub use super::py_types::FunctionExample;
+    #[pymodule_export]
     pub use super::py_types::FunctionMetadata;
     #[pymodule_export]
     pub use super::py_types::InvokeFunctionRequest;
     #[pymodule_export]
     pub use super::py_types::InvokeFunctionResponse;
     #[pymodule_export]
+    pub use super::py_types::KeyOutcome;
+    #[pymodule_export]
+    pub use super::py_types::LogLine;
+    #[pymodule_export]
     pub use super::py_types::Metadata;
     #[pymodule_export]
     pub use super::py_types::Oauth2AuthorizationCodeFlowConfiguration;
@@ -741,6 +1076,10 @@ pub mod trysoma_sdk_core {
     #[pymodule_export]
     pub use super::py_types::ProviderCredentialController;
     #[pymodule_export]
+    pub use super::py_types::ProviderReconcileSummary;
+    #[pymodule_export]
+    pub use super::py_types::ProviderWithFunctions;
+    #[pymodule_export]
     pub use super::py_types::Secret;
     #[pymodule_export]
     pub use super::py_types::SetEnvironmentVariablesResponse;
@@ -759,3 +1098,116 @@ pub mod trysoma_sdk_core {
     #[pymodule_export]
     pub use super::py_types::UnsetSecretSuccess;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ffi::CStr;
+    use tokio::sync::mpsc;
+
+    /// Loads `handler` from `code`, with `InvokeFunctionResponse` in scope
+    fn python_handler(code: &CStr) -> Py<PyAny> {
+        Python::initialize();
+        Python::attach(|py| {
+            let module =
+                PyModule::from_code(py, code, c"stream_handler.py", c"stream_handler").unwrap();
+            module
+                .add_class::<py_types::InvokeFunctionResponse>()
+                .unwrap();
+            module.getattr("handler").unwrap().unbind()
+        })
+    }
+
+    fn request(parameters: &str) -> core_types::InvokeFunctionRequest {
+        core_types::InvokeFunctionRequest {
+            provider_controller_type_id: "provider".to_string(),
+            function_controller_type_id: "function".to_string(),
+            credential_controller_type_id: "credential".to_string(),
+            credentials: "{}".to_string(),
+            parameters: parameters.to_string(),
+            request_id: None,
+            bypass_cache: false,
+            invocation_id: "1".to_string(),
+        }
+    }
+
+    /// Runs a stream callback to completion, returning its result and every chunk it sent
+    async fn run_stream(
+        handler: Py<PyAny>,
+        parameters: &str,
+    ) -> (
+        Result<(), CommonError>,
+        Vec<Result<String, core_types::CallbackError>>,
+    ) {
+        let invoke_stream = convert_invoke_stream_callback(handler);
+        let (chunks_tx, mut chunks_rx) = mpsc::channel(16);
+        let result = invoke_stream(request(parameters), chunks_tx).await;
+
+        let mut chunks = Vec::new();
+        while let Some(chunk) = chunks_rx.recv().await {
+            chunks.push(chunk.result);
+        }
+        (result, chunks)
+    }
+
+    #[tokio::test]
+    async fn test_async_generator_handler_streams_chunks_in_order() {
+        let handler = python_handler(
+            cr#"
+async def handler(req):
+    for i in range(3):
+        yield InvokeFunctionResponse.success(f"{req.parameters}-{i}")
+    yield InvokeFunctionResponse.failure("no more", code="invalid_argument")
+"#,
+        );
+
+        let (result, chunks) = run_stream(handler, "chunk").await;
+        assert!(result.is_ok());
+        assert_eq!(chunks.len(), 4);
+        let data: Vec<&str> = chunks[..3]
+            .iter()
+            .map(|chunk| chunk.as_deref().unwrap())
+            .collect();
+        assert_eq!(data, vec!["chunk-0", "chunk-1", "chunk-2"]);
+        let error = chunks[3].as_ref().unwrap_err();
+        assert_eq!(error.message, "no more");
+        assert_eq!(
+            error.code,
+            Some(core_types::CallbackErrorCode::InvalidArgument)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_sync_generator_handler_streams_chunks() {
+        let handler = python_handler(
+            cr#"
+def handler(req):
+    yield InvokeFunctionResponse.success("only")
+"#,
+        );
+
+        let (result, chunks) = run_stream(handler, "{}").await;
+        assert!(result.is_ok());
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(chunks[0].as_deref().unwrap(), "only");
+    }
+
+    #[tokio::test]
+    async fn test_raising_handler_fails_stream_after_sent_chunks() {
+        let handler = python_handler(
+            cr#"
+async def handler(req):
+    yield InvokeFunctionResponse.success("first")
+    raise RuntimeError("boom")
+"#,
+        );
+
+        let (result, chunks) = run_stream(handler, "{}").await;
+        match result {
+            Err(CommonError::Unknown(e)) => assert!(e.to_string().contains("boom")),
+            other => panic!("expected the handler error, got {other:?}"),
+        }
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(chunks[0].as_deref().unwrap(), "first");
+    }
+}
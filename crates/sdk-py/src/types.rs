@@ -12,18 +12,29 @@ pub struct Agent {
     pub name: String,
     #[pyo3(get, set)]
     pub description: String,
+    /// Provider functions the agent may invoke, as "provider_type_id.function_type_id".
+    /// None or empty allows every function.
+    #[pyo3(get, set)]
+    pub allowed_functions: Option<Vec<String>>,
 }
 
 #[pymethods]
 impl Agent {
     #[new]
-    #[pyo3(signature = (id, project_id, name, description, /)-> "Agent")]
-    fn new(id: String, project_id: String, name: String, description: String) -> Self {
+    #[pyo3(signature = (id, project_id, name, description, /, allowed_functions=None)-> "Agent")]
+    fn new(
+        id: String,
+        project_id: String,
+        name: String,
+        description: String,
+        allowed_functions: Option<Vec<String>>,
+    ) -> Self {
         Self {
             id,
             project_id,
             name,
             description,
+            allowed_functions,
         }
     }
 }
@@ -42,18 +53,26 @@ pub struct ProviderController {
     pub categories: Vec<String>,
     #[pyo3(get, set)]
     pub credential_controllers: Vec<ProviderCredentialController>,
+    /// Run at most this many of the provider's functions at once; further invocations queue
+    #[pyo3(get, set)]
+    pub max_concurrency: Option<usize>,
+    /// Fail invocations with "rate_limited" once this many are queued. Unbounded when None.
+    #[pyo3(get, set)]
+    pub max_queue_depth: Option<usize>,
 }
 
 #[pymethods]
 impl ProviderController {
     #[new]
-    #[pyo3(signature = (type_id, name, documentation, categories: "list[str]", credential_controllers: "list[ProviderCredentialController]", /) -> "ProviderController")]
+    #[pyo3(signature = (type_id, name, documentation, categories: "list[str]", credential_controllers: "list[ProviderCredentialController]", /, max_concurrency: "int | None" = None, max_queue_depth: "int | None" = None) -> "ProviderController")]
     fn new(
         type_id: String,
         name: String,
         documentation: String,
         categories: Vec<String>,
         credential_controllers: Vec<ProviderCredentialController>,
+        max_concurrency: Option<usize>,
+        max_queue_depth: Option<usize>,
     ) -> Self {
         Self {
             type_id,
@@ -61,6 +80,8 @@ impl ProviderController {
             documentation,
             categories,
             credential_controllers,
+            max_concurrency,
+            max_queue_depth,
         }
     }
 }
@@ -93,6 +114,34 @@ impl FunctionController {
     }
 }
 
+/// A registered provider with its functions' parameter and output schemas
+#[pyclass]
+#[derive(Clone, Debug)]
+pub struct ProviderWithFunctions {
+    #[pyo3(get)]
+    pub type_id: String,
+    #[pyo3(get)]
+    pub name: String,
+    #[pyo3(get)]
+    pub documentation: String,
+    #[pyo3(get)]
+    pub categories: Vec<String>,
+    #[pyo3(get)]
+    pub functions: Vec<FunctionController>,
+}
+
+/// Provider type ids added, updated and removed by `reconcile_providers`
+#[pyclass]
+#[derive(Clone, Debug)]
+pub struct ProviderReconcileSummary {
+    #[pyo3(get)]
+    pub added: Vec<String>,
+    #[pyo3(get)]
+    pub updated: Vec<String>,
+    #[pyo3(get)]
+    pub removed: Vec<String>,
+}
+
 /// Credential controller types
 #[pyclass]
 #[derive(Clone, Debug)]
@@ -332,18 +381,26 @@ pub struct InvokeFunctionRequest {
     pub credentials: String,
     #[pyo3(get, set)]
     pub parameters: String,
+    #[pyo3(get, set)]
+    pub request_id: Option<String>,
+    /// Pass to `append_invocation_log` to log lines that are kept if the invocation times out
+    #[pyo3(get, set)]
+    pub invocation_id: String,
 }
 
 #[pymethods]
 impl InvokeFunctionRequest {
     #[new]
-    #[pyo3(signature = (provider_controller_type_id, function_controller_type_id, credential_controller_type_id, credentials, parameters, /) -> "InvokeFunctionRequest")]
+    #[pyo3(signature = (provider_controller_type_id, function_controller_type_id, credential_controller_type_id, credentials, parameters, /, request_id: "str | None" = None, invocation_id: "str" = String::new()) -> "InvokeFunctionRequest")]
+    #[allow(clippy::too_many_arguments)]
     fn new(
         provider_controller_type_id: String,
         function_controller_type_id: String,
         credential_controller_type_id: String,
         credentials: String,
         parameters: String,
+        request_id: Option<String>,
+        invocation_id: String,
     ) -> Self {
         Self {
             provider_controller_type_id,
@@ -351,24 +408,47 @@ impl InvokeFunctionRequest {
             credential_controller_type_id,
             credentials,
             parameters,
+            request_id,
+            invocation_id,
         }
     }
 }
 
-/// Callback error
+/// Callback error. `code` is one of "rate_limited", "unauthorized", "invalid_argument",
+/// "internal", "retryable" or "deadline_exceeded"; an unknown code is treated as "internal".
+/// `retry_after_ms` is only read for "rate_limited" and "retryable".
 #[pyclass]
 #[derive(Clone, Debug)]
 pub struct CallbackError {
     #[pyo3(get, set)]
     pub message: String,
+    #[pyo3(get, set)]
+    pub code: Option<String>,
+    #[pyo3(get, set)]
+    pub retry_after_ms: Option<u64>,
 }
 
 #[pymethods]
 impl CallbackError {
     #[new]
-    #[pyo3(signature = (message, /) -> "CallbackError")]
-    fn new(message: String) -> Self {
-        Self { message }
+    #[pyo3(signature = (message, /, code=None, retry_after_ms=None) -> "CallbackError")]
+    fn new(message: String, code: Option<String>, retry_after_ms: Option<u64>) -> Self {
+        Self {
+            message,
+            code,
+            retry_after_ms,
+        }
+    }
+}
+
+impl CallbackError {
+    /// An error with no code, as built by the `failure` helpers
+    pub(crate) fn from_message(message: String) -> Self {
+        Self {
+            message,
+            code: None,
+            retry_after_ms: None,
+        }
     }
 }
 
@@ -380,31 +460,71 @@ pub struct InvokeFunctionResponse {
     pub data: Option<String>,
     #[pyo3(get, set)]
     pub error: Option<CallbackError>,
+    #[pyo3(get, set)]
+    pub logs: Vec<LogLine>,
 }
 
 #[pymethods]
 impl InvokeFunctionResponse {
     #[new]
-    #[pyo3(signature = (data=None, error=None) -> "InvokeFunctionResponse")]
-    fn new(data: Option<String>, error: Option<CallbackError>) -> Self {
-        Self { data, error }
+    #[pyo3(signature = (data=None, error=None, logs=None) -> "InvokeFunctionResponse")]
+    fn new(data: Option<String>, error: Option<CallbackError>, logs: Option<Vec<LogLine>>) -> Self {
+        Self {
+            data,
+            error,
+            logs: logs.unwrap_or_default(),
+        }
     }
 
     #[staticmethod]
-    #[pyo3(signature = (data, /) -> "InvokeFunctionResponse")]
-    fn success(data: String) -> Self {
+    #[pyo3(signature = (data, /, logs=None) -> "InvokeFunctionResponse")]
+    fn success(data: String, logs: Option<Vec<LogLine>>) -> Self {
         Self {
             data: Some(data),
             error: None,
+            logs: logs.unwrap_or_default(),
         }
     }
 
     #[staticmethod]
-    #[pyo3(signature = (message, /) -> "InvokeFunctionResponse")]
-    fn failure(message: String) -> Self {
+    #[pyo3(signature = (message, /, code=None, retry_after_ms=None, logs=None) -> "InvokeFunctionResponse")]
+    fn failure(
+        message: String,
+        code: Option<String>,
+        retry_after_ms: Option<u64>,
+        logs: Option<Vec<LogLine>>,
+    ) -> Self {
         Self {
             data: None,
-            error: Some(CallbackError { message }),
+            error: Some(CallbackError::new(message, code, retry_after_ms)),
+            logs: logs.unwrap_or_default(),
+        }
+    }
+}
+
+/// Line logged by a function while it ran. `level` is one of "debug", "info", "warn" or
+/// "error"; an unknown level is treated as "info". `timestamp_ms` defaults to when the
+/// response is received.
+#[pyclass]
+#[derive(Clone, Debug)]
+pub struct LogLine {
+    #[pyo3(get, set)]
+    pub level: String,
+    #[pyo3(get, set)]
+    pub message: String,
+    #[pyo3(get, set)]
+    pub timestamp_ms: Option<i64>,
+}
+
+#[pymethods]
+impl LogLine {
+    #[new]
+    #[pyo3(signature = (level, message, /, timestamp_ms=None) -> "LogLine")]
+    fn new(level: String, message: String, timestamp_ms: Option<i64>) -> Self {
+        Self {
+            level,
+            message,
+            timestamp_ms,
         }
     }
 }
@@ -428,20 +548,82 @@ impl Secret {
     }
 }
 
+/// Outcome of applying a single secret or environment variable key.
+/// `status` is one of "applied", "skipped" or "failed".
+#[pyclass]
+#[derive(Clone, Debug)]
+pub struct KeyOutcome {
+    #[pyo3(get, set)]
+    pub key: String,
+    #[pyo3(get, set)]
+    pub status: String,
+    #[pyo3(get, set)]
+    pub reason: Option<String>,
+}
+
+#[pymethods]
+impl KeyOutcome {
+    #[new]
+    #[pyo3(signature = (key, status, /, reason=None) -> "KeyOutcome")]
+    fn new(key: String, status: String, reason: Option<String>) -> Self {
+        Self {
+            key,
+            status,
+            reason,
+        }
+    }
+
+    #[staticmethod]
+    #[pyo3(signature = (key, /) -> "KeyOutcome")]
+    fn applied(key: String) -> Self {
+        Self {
+            key,
+            status: "applied".to_string(),
+            reason: None,
+        }
+    }
+
+    #[staticmethod]
+    #[pyo3(signature = (key, reason, /) -> "KeyOutcome")]
+    fn skipped(key: String, reason: String) -> Self {
+        Self {
+            key,
+            status: "skipped".to_string(),
+            reason: Some(reason),
+        }
+    }
+
+    #[staticmethod]
+    #[pyo3(signature = (key, reason, /) -> "KeyOutcome")]
+    fn failed(key: String, reason: String) -> Self {
+        Self {
+            key,
+            status: "failed".to_string(),
+            reason: Some(reason),
+        }
+    }
+}
+
 /// Success response for setting secrets
 #[pyclass]
 #[derive(Clone, Debug)]
 pub struct SetSecretsSuccess {
     #[pyo3(get, set)]
     pub message: String,
+    /// Per-key outcomes; keys without an entry are treated as applied
+    #[pyo3(get, set)]
+    pub outcomes: Vec<KeyOutcome>,
 }
 
 #[pymethods]
 impl SetSecretsSuccess {
     #[new]
-    #[pyo3(signature = (message, /) -> "SetSecretsSuccess")]
-    fn new(message: String) -> Self {
-        Self { message }
+    #[pyo3(signature = (message, /, outcomes=None) -> "SetSecretsSuccess")]
+    fn new(message: String, outcomes: Option<Vec<KeyOutcome>>) -> Self {
+        Self {
+            message,
+            outcomes: outcomes.unwrap_or_default(),
+        }
     }
 }
 
@@ -467,7 +649,10 @@ impl SetSecretsResponse {
     #[pyo3(signature = (message, /) -> "SetSecretsResponse")]
     fn success(message: String) -> Self {
         Self {
-            data: Some(SetSecretsSuccess { message }),
+            data: Some(SetSecretsSuccess {
+                message,
+                outcomes: vec![],
+            }),
             error: None,
         }
     }
@@ -477,7 +662,7 @@ impl SetSecretsResponse {
     fn failure(message: String) -> Self {
         Self {
             data: None,
-            error: Some(CallbackError { message }),
+            error: Some(CallbackError::from_message(message)),
         }
     }
 }
@@ -507,14 +692,20 @@ impl EnvironmentVariable {
 pub struct SetEnvironmentVariablesSuccess {
     #[pyo3(get, set)]
     pub message: String,
+    /// Per-key outcomes; keys without an entry are treated as applied
+    #[pyo3(get, set)]
+    pub outcomes: Vec<KeyOutcome>,
 }
 
 #[pymethods]
 impl SetEnvironmentVariablesSuccess {
     #[new]
-    #[pyo3(signature = (message, /) -> "SetEnvironmentVariablesSuccess")]
-    fn new(message: String) -> Self {
-        Self { message }
+    #[pyo3(signature = (message, /, outcomes=None) -> "SetEnvironmentVariablesSuccess")]
+    fn new(message: String, outcomes: Option<Vec<KeyOutcome>>) -> Self {
+        Self {
+            message,
+            outcomes: outcomes.unwrap_or_default(),
+        }
     }
 }
 
@@ -540,7 +731,10 @@ impl SetEnvironmentVariablesResponse {
     #[pyo3(signature = (message, /) -> "SetEnvironmentVariablesResponse")]
     fn success(message: String) -> Self {
         Self {
-            data: Some(SetEnvironmentVariablesSuccess { message }),
+            data: Some(SetEnvironmentVariablesSuccess {
+                message,
+                outcomes: vec![],
+            }),
             error: None,
         }
     }
@@ -550,7 +744,7 @@ impl SetEnvironmentVariablesResponse {
     fn failure(message: String) -> Self {
         Self {
             data: None,
-            error: Some(CallbackError { message }),
+            error: Some(CallbackError::from_message(message)),
         }
     }
 }
@@ -604,7 +798,7 @@ impl UnsetSecretResponse {
     fn failure(message: String) -> Self {
         Self {
             data: None,
-            error: Some(CallbackError { message }),
+            error: Some(CallbackError::from_message(message)),
         }
     }
 }
@@ -658,7 +852,7 @@ impl UnsetEnvironmentVariableResponse {
     fn failure(message: String) -> Self {
         Self {
             data: None,
-            error: Some(CallbackError { message }),
+            error: Some(CallbackError::from_message(message)),
         }
     }
 }
@@ -675,18 +869,82 @@ pub struct FunctionMetadata {
     pub parameters: String,
     #[pyo3(get, set)]
     pub output: String,
+    #[pyo3(get, set)]
+    pub max_output_bytes: Option<usize>,
+    /// Mark the function idempotent and cache identical calls for this many milliseconds
+    #[pyo3(get, set)]
+    pub result_cache_ttl_ms: Option<u64>,
+    /// Flag the function as deprecated; it can still be invoked
+    #[pyo3(get, set)]
+    pub deprecated: bool,
+    /// Guidance shown to callers of a deprecated function
+    #[pyo3(get, set)]
+    pub deprecation_message: Option<String>,
+    /// Example invocations, validated against the parameter and output schemas
+    #[pyo3(get, set)]
+    pub examples: Vec<FunctionExample>,
+    /// Fail invocations that run longer than this many milliseconds with "deadline_exceeded"
+    #[pyo3(get, set)]
+    pub timeout_ms: Option<u64>,
+    /// Check returned data against the output schema, failing mismatches with "invalid_argument"
+    #[pyo3(get, set)]
+    pub validate_output: bool,
 }
 
 #[pymethods]
 impl FunctionMetadata {
     #[new]
-    #[pyo3(signature = (name, description, parameters, output, /) -> "FunctionMetadata")]
-    fn new(name: String, description: String, parameters: String, output: String) -> Self {
+    #[pyo3(signature = (name, description, parameters, output, /, max_output_bytes: "int | None" = None, result_cache_ttl_ms: "int | None" = None, deprecated: "bool" = false, deprecation_message: "str | None" = None, examples: "list[FunctionExample] | None" = None, timeout_ms: "int | None" = None, validate_output: "bool" = false) -> "FunctionMetadata")]
+    fn new(
+        name: String,
+        description: String,
+        parameters: String,
+        output: String,
+        max_output_bytes: Option<usize>,
+        result_cache_ttl_ms: Option<u64>,
+        deprecated: bool,
+        deprecation_message: Option<String>,
+        examples: Option<Vec<FunctionExample>>,
+        timeout_ms: Option<u64>,
+        validate_output: bool,
+    ) -> Self {
         Self {
             name,
             description,
             parameters,
             output,
+            max_output_bytes,
+            result_cache_ttl_ms,
+            deprecated,
+            deprecation_message,
+            examples: examples.unwrap_or_default(),
+            timeout_ms,
+            validate_output,
+        }
+    }
+}
+
+/// Example invocation of a function; `parameters` and `output` are JSON strings
+#[pyclass]
+#[derive(Clone, Debug)]
+pub struct FunctionExample {
+    #[pyo3(get, set)]
+    pub description: Option<String>,
+    #[pyo3(get, set)]
+    pub parameters: String,
+    #[pyo3(get, set)]
+    pub output: Option<String>,
+}
+
+#[pymethods]
+impl FunctionExample {
+    #[new]
+    #[pyo3(signature = (parameters, /, output=None, description=None) -> "FunctionExample")]
+    fn new(parameters: String, output: Option<String>, description: Option<String>) -> Self {
+        Self {
+            description,
+            parameters,
+            output,
         }
     }
 }
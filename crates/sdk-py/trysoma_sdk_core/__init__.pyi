@@ -1,7 +1,11 @@
 import typing
 
 class Agent:
-    def __new__(cls, id: str, project_id: str, name: str, description: str, /) -> Agent: ...
+    def __new__(cls, id: str, project_id: str, name: str, description: str, /, allowed_functions: list[str] | None = None) -> Agent: ...
+    @property
+    def allowed_functions(self, /) -> list[str] | None: ...
+    @allowed_functions.setter
+    def allowed_functions(self, /, value: list[str] | None) -> None: ...
     @property
     def description(self, /) -> str: ...
     @description.setter
@@ -20,11 +24,19 @@ class Agent:
     def project_id(self, /, value: str) -> None: ...
 
 class CallbackError:
-    def __new__(cls, message: str, /) -> CallbackError: ...
+    def __new__(cls, message: str, /, code: str | None = None, retry_after_ms: int | None = None) -> CallbackError: ...
+    @property
+    def code(self, /) -> str | None: ...
+    @code.setter
+    def code(self, /, value: str | None) -> None: ...
     @property
     def message(self, /) -> str: ...
     @message.setter
     def message(self, /, value: str) -> None: ...
+    @property
+    def retry_after_ms(self, /) -> int | None: ...
+    @retry_after_ms.setter
+    def retry_after_ms(self, /, value: int | None) -> None: ...
 
 class EnvironmentVariable:
     def __new__(cls, key: str, value: str, /) -> EnvironmentVariable: ...
@@ -56,13 +68,44 @@ class FunctionController:
     @parameters.setter
     def parameters(self, /, value: str) -> None: ...
 
+class FunctionExample:
+    def __new__(cls, parameters: str, /, output: str | None = None, description: str | None = None) -> FunctionExample: ...
+    @property
+    def description(self, /) -> str | None: ...
+    @description.setter
+    def description(self, /, value: str | None) -> None: ...
+    @property
+    def output(self, /) -> str | None: ...
+    @output.setter
+    def output(self, /, value: str | None) -> None: ...
+    @property
+    def parameters(self, /) -> str: ...
+    @parameters.setter
+    def parameters(self, /, value: str) -> None: ...
+
 class FunctionMetadata:
-    def __new__(cls, name: str, description: str, parameters: str, output: str, /) -> FunctionMetadata: ...
+    def __new__(cls, name: str, description: str, parameters: str, output: str, /, max_output_bytes: int | None = None, result_cache_ttl_ms: int | None = None, deprecated: bool = False, deprecation_message: str | None = None, examples: list[FunctionExample] | None = None, timeout_ms: int | None = None, validate_output: bool = False) -> FunctionMetadata: ...
+    @property
+    def deprecated(self, /) -> bool: ...
+    @deprecated.setter
+    def deprecated(self, /, value: bool) -> None: ...
+    @property
+    def deprecation_message(self, /) -> str | None: ...
+    @deprecation_message.setter
+    def deprecation_message(self, /, value: str | None) -> None: ...
     @property
     def description(self, /) -> str: ...
     @description.setter
     def description(self, /, value: str) -> None: ...
     @property
+    def examples(self, /) -> list[FunctionExample]: ...
+    @examples.setter
+    def examples(self, /, value: list[FunctionExample]) -> None: ...
+    @property
+    def max_output_bytes(self, /) -> int | None: ...
+    @max_output_bytes.setter
+    def max_output_bytes(self, /, value: int | None) -> None: ...
+    @property
     def name(self, /) -> str: ...
     @name.setter
     def name(self, /, value: str) -> None: ...
@@ -74,9 +117,21 @@ class FunctionMetadata:
     def parameters(self, /) -> str: ...
     @parameters.setter
     def parameters(self, /, value: str) -> None: ...
+    @property
+    def result_cache_ttl_ms(self, /) -> int | None: ...
+    @result_cache_ttl_ms.setter
+    def result_cache_ttl_ms(self, /, value: int | None) -> None: ...
+    @property
+    def timeout_ms(self, /) -> int | None: ...
+    @timeout_ms.setter
+    def timeout_ms(self, /, value: int | None) -> None: ...
+    @property
+    def validate_output(self, /) -> bool: ...
+    @validate_output.setter
+    def validate_output(self, /, value: bool) -> None: ...
 
 class InvokeFunctionRequest:
-    def __new__(cls, provider_controller_type_id: str, function_controller_type_id: str, credential_controller_type_id: str, credentials: str, parameters: str, /) -> InvokeFunctionRequest: ...
+    def __new__(cls, provider_controller_type_id: str, function_controller_type_id: str, credential_controller_type_id: str, credentials: str, parameters: str, /, request_id: str | None = None, invocation_id: str = ...) -> InvokeFunctionRequest: ...
     @property
     def credential_controller_type_id(self, /) -> str: ...
     @credential_controller_type_id.setter
@@ -90,6 +145,10 @@ class InvokeFunctionRequest:
     @function_controller_type_id.setter
     def function_controller_type_id(self, /, value: str) -> None: ...
     @property
+    def invocation_id(self, /) -> str: ...
+    @invocation_id.setter
+    def invocation_id(self, /, value: str) -> None: ...
+    @property
     def parameters(self, /) -> str: ...
     @parameters.setter
     def parameters(self, /, value: str) -> None: ...
@@ -97,9 +156,13 @@ class InvokeFunctionRequest:
     def provider_controller_type_id(self, /) -> str: ...
     @provider_controller_type_id.setter
     def provider_controller_type_id(self, /, value: str) -> None: ...
+    @property
+    def request_id(self, /) -> str | None: ...
+    @request_id.setter
+    def request_id(self, /, value: str | None) -> None: ...
 
 class InvokeFunctionResponse:
-    def __new__(cls, /, data: str | None = None, error: CallbackError | None = None) -> InvokeFunctionResponse: ...
+    def __new__(cls, /, data: str | None = None, error: CallbackError | None = None, logs: list[LogLine] | None = None) -> InvokeFunctionResponse: ...
     @property
     def data(self, /) -> typing.Any: ...
     @data.setter
@@ -108,10 +171,50 @@ class InvokeFunctionResponse:
     def error(self, /) -> typing.Any: ...
     @error.setter
     def error(self, /, value: typing.Any) -> None: ...
+    @property
+    def logs(self, /) -> list[LogLine]: ...
+    @logs.setter
+    def logs(self, /, value: list[LogLine]) -> None: ...
+    @staticmethod
+    def failure(message: str, /, code: str | None = None, retry_after_ms: int | None = None, logs: list[LogLine] | None = None) -> InvokeFunctionResponse: ...
+    @staticmethod
+    def success(data: str, /, logs: list[LogLine] | None = None) -> InvokeFunctionResponse: ...
+
+class KeyOutcome:
+    def __new__(cls, key: str, status: str, /, reason: str | None = None) -> KeyOutcome: ...
+    @property
+    def key(self, /) -> str: ...
+    @key.setter
+    def key(self, /, value: str) -> None: ...
+    @property
+    def reason(self, /) -> str | None: ...
+    @reason.setter
+    def reason(self, /, value: str | None) -> None: ...
+    @property
+    def status(self, /) -> str: ...
+    @status.setter
+    def status(self, /, value: str) -> None: ...
+    @staticmethod
+    def applied(key: str, /) -> KeyOutcome: ...
     @staticmethod
-    def failure(message: str, /) -> InvokeFunctionResponse: ...
+    def failed(key: str, reason: str, /) -> KeyOutcome: ...
     @staticmethod
-    def success(data: str, /) -> InvokeFunctionResponse: ...
+    def skipped(key: str, reason: str, /) -> KeyOutcome: ...
+
+class LogLine:
+    def __new__(cls, level: str, message: str, /, timestamp_ms: int | None = None) -> LogLine: ...
+    @property
+    def level(self, /) -> str: ...
+    @level.setter
+    def level(self, /, value: str) -> None: ...
+    @property
+    def message(self, /) -> str: ...
+    @message.setter
+    def message(self, /, value: str) -> None: ...
+    @property
+    def timestamp_ms(self, /) -> int | None: ...
+    @timestamp_ms.setter
+    def timestamp_ms(self, /, value: int | None) -> None: ...
 
 class Metadata:
     def __new__(cls, key: str, value: str, /) -> Metadata: ...
@@ -201,7 +304,7 @@ class Oauth2JwtBearerAssertionFlowStaticCredentialConfiguration:
     def userinfo_uri(self, /, value: str) -> None: ...
 
 class ProviderController:
-    def __new__(cls, type_id: str, name: str, documentation: str, categories: list[str], credential_controllers: list[ProviderCredentialController], /) -> ProviderController: ...
+    def __new__(cls, type_id: str, name: str, documentation: str, categories: list[str], credential_controllers: list[ProviderCredentialController], /, max_concurrency: int | None = None, max_queue_depth: int | None = None) -> ProviderController: ...
     @property
     def categories(self, /) -> typing.Any: ...
     @categories.setter
@@ -215,6 +318,14 @@ class ProviderController:
     @documentation.setter
     def documentation(self, /, value: str) -> None: ...
     @property
+    def max_concurrency(self, /) -> int | None: ...
+    @max_concurrency.setter
+    def max_concurrency(self, /, value: int | None) -> None: ...
+    @property
+    def max_queue_depth(self, /) -> int | None: ...
+    @max_queue_depth.setter
+    def max_queue_depth(self, /, value: int | None) -> None: ...
+    @property
     def name(self, /) -> str: ...
     @name.setter
     def name(self, /, value: str) -> None: ...
@@ -237,6 +348,26 @@ class ProviderCredentialController:
     @staticmethod
     def oauth2_jwt_bearer_flow(config: Oauth2JwtBearerAssertionFlowConfiguration, /) -> ProviderCredentialController: ...
 
+class ProviderReconcileSummary:
+    @property
+    def added(self, /) -> list[str]: ...
+    @property
+    def removed(self, /) -> list[str]: ...
+    @property
+    def updated(self, /) -> list[str]: ...
+
+class ProviderWithFunctions:
+    @property
+    def categories(self, /) -> list[str]: ...
+    @property
+    def documentation(self, /) -> str: ...
+    @property
+    def functions(self, /) -> list[FunctionController]: ...
+    @property
+    def name(self, /) -> str: ...
+    @property
+    def type_id(self, /) -> str: ...
+
 class Secret:
     def __new__(cls, key: str, value: str, /) -> Secret: ...
     @property
@@ -264,11 +395,15 @@ class SetEnvironmentVariablesResponse:
     def success(message: str, /) -> SetEnvironmentVariablesResponse: ...
 
 class SetEnvironmentVariablesSuccess:
-    def __new__(cls, message: str, /) -> SetEnvironmentVariablesSuccess: ...
+    def __new__(cls, message: str, /, outcomes: list[KeyOutcome] | None = None) -> SetEnvironmentVariablesSuccess: ...
     @property
     def message(self, /) -> str: ...
     @message.setter
     def message(self, /, value: str) -> None: ...
+    @property
+    def outcomes(self, /) -> list[KeyOutcome]: ...
+    @outcomes.setter
+    def outcomes(self, /, value: list[KeyOutcome]) -> None: ...
 
 class SetSecretsResponse:
     def __new__(cls, /, data: SetSecretsSuccess | None = None, error: CallbackError | None = None) -> SetSecretsResponse: ...
@@ -286,11 +421,15 @@ class SetSecretsResponse:
     def success(message: str, /) -> SetSecretsResponse: ...
 
 class SetSecretsSuccess:
-    def __new__(cls, message: str, /) -> SetSecretsSuccess: ...
+    def __new__(cls, message: str, /, outcomes: list[KeyOutcome] | None = None) -> SetSecretsSuccess: ...
     @property
     def message(self, /) -> str: ...
     @message.setter
     def message(self, /, value: str) -> None: ...
+    @property
+    def outcomes(self, /) -> list[KeyOutcome]: ...
+    @outcomes.setter
+    def outcomes(self, /, value: list[KeyOutcome]) -> None: ...
 
 class UnsetEnvironmentVariableResponse:
     def __new__(cls, /, data: UnsetEnvironmentVariableSuccess | None = None, error: CallbackError | None = None) -> UnsetEnvironmentVariableResponse: ...
@@ -337,18 +476,23 @@ class UnsetSecretSuccess:
     def message(self, /, value: str) -> None: ...
 
 def add_agent(agent: Agent, /) -> bool: ...
-def add_function(provider_type_id: str, function_metadata: FunctionMetadata, invoke_callback: typing.Callable[[InvokeFunctionRequest], InvokeFunctionResponse], /) -> bool: ...
+def add_agents_batch(agents: list[Agent], /) -> list[str]: ...
+def add_function(provider_type_id: str, function_metadata: FunctionMetadata, invoke_callback: typing.Callable[[InvokeFunctionRequest], InvokeFunctionResponse], invoke_stream_callback: typing.Callable[[InvokeFunctionRequest], typing.AsyncIterator[InvokeFunctionResponse] | typing.Iterator[InvokeFunctionResponse]] | None = None, /) -> bool: ...
 def add_provider(provider: ProviderController, /) -> None: ...
+def append_invocation_log(invocation_id: str, line: LogLine, /) -> bool: ...
 def kill_grpc_service() -> None: ...
+def list_providers() -> list[ProviderWithFunctions]: ...
+def reconcile_providers(providers: list[ProviderController], /) -> ProviderReconcileSummary: ...
 def remove_agent(id: str, /) -> bool: ...
 def remove_function(provider_type_id: str, function_name: str, /) -> bool: ...
 def remove_provider(type_id: str, /) -> bool: ...
 def resync_sdk(base_url: str | None = None) -> typing.Awaitable[None]: ...
 def set_environment_variable_handler(callback: typing.Callable[[list[EnvironmentVariable]], SetEnvironmentVariablesResponse], /) -> None: ...
 def set_secret_handler(callback: typing.Callable[[list[Secret]], SetSecretsResponse], /) -> None: ...
+def set_secret_handler_for(provider_type_id: str, callback: typing.Callable[[list[Secret]], SetSecretsResponse], /) -> None: ...
 def set_unset_environment_variable_handler(callback: typing.Callable[[str], UnsetEnvironmentVariableResponse], /) -> None: ...
 def set_unset_secret_handler(callback: typing.Callable[[str], UnsetSecretResponse], /) -> None: ...
 def start_grpc_server(socket_path: str, project_dir: str, /) -> typing.Awaitable[None]: ...
 def update_agent(agent: Agent, /) -> bool: ...
-def update_function(provider_type_id: str, function_metadata: FunctionMetadata, invoke_callback: typing.Callable[[InvokeFunctionRequest], InvokeFunctionResponse], /) -> bool: ...
+def update_function(provider_type_id: str, function_metadata: FunctionMetadata, invoke_callback: typing.Callable[[InvokeFunctionRequest], InvokeFunctionResponse], invoke_stream_callback: typing.Callable[[InvokeFunctionRequest], typing.AsyncIterator[InvokeFunctionResponse] | typing.Iterator[InvokeFunctionResponse]] | None = None, /) -> bool: ...
 def update_provider(provider: ProviderController, /) -> bool: ...
@@ -96,6 +96,22 @@ pub fn integration_test(attr: TokenStream, item: TokenStream) -> TokenStream {
     integration_test::integration_test_impl(attr, item)
 }
 
+/// Database backends migrations can target. A file named `*.{backend}.sql` only applies to that
+/// backend; any other `.sql` file applies to all of them.
+const SUPPORTED_BACKENDS: &[&str] = &["sqlite", "postgres"];
+
+/// Backends a migration file applies to, based on its `.{backend}.` infix
+fn migration_backends(file_name: &str) -> Vec<&'static str> {
+    match SUPPORTED_BACKENDS
+        .iter()
+        .find(|backend| file_name.contains(&format!(".{backend}.")))
+    {
+        Some(backend) => vec![*backend],
+        // No specific backend mentioned — add to ALL backends
+        None => SUPPORTED_BACKENDS.to_vec(),
+    }
+}
+
 #[proc_macro]
 pub fn load_sql_migrations(input: TokenStream) -> TokenStream {
     let path_lit = parse_macro_input!(input as LitStr);
@@ -104,8 +120,6 @@ pub fn load_sql_migrations(input: TokenStream) -> TokenStream {
     let manifest_dir = std::env::var("CARGO_MANIFEST_DIR").expect("CARGO_MANIFEST_DIR not set");
     let migrations_path = Path::new(&manifest_dir).join(&path_str);
 
-    let supported_backends = vec!["sqlite"];
-
     let mut backend_map = std::collections::BTreeMap::<String, Vec<(String, String)>>::new();
 
     for entry in fs::read_dir(&migrations_path).expect("Failed to read migrations directory") {
@@ -123,27 +137,11 @@ pub fn load_sql_migrations(input: TokenStream) -> TokenStream {
             if let Some(file_name) = path.file_name().and_then(|n| n.to_str()) {
                 let contents = fs::read_to_string(&path).expect("Failed to read migration file");
 
-                let mut matched_backend = None;
-                for backend in &supported_backends {
-                    if file_name.contains(&format!(".{backend}.")) {
-                        matched_backend = Some(*backend);
-                        break;
-                    }
-                }
-
-                if let Some(backend) = matched_backend {
+                for backend in migration_backends(file_name) {
                     backend_map
                         .entry(backend.to_string())
                         .or_default()
-                        .push((file_name.to_string(), contents));
-                } else {
-                    // No specific backend mentioned — add to ALL backends
-                    for backend in &supported_backends {
-                        backend_map
-                            .entry(backend.to_string())
-                            .or_default()
-                            .push((file_name.to_string(), contents.clone()));
-                    }
+                        .push((file_name.to_string(), contents.clone()));
                 }
             }
         }
@@ -184,8 +182,6 @@ pub fn load_atlas_sql_migrations(input: TokenStream) -> TokenStream {
     let manifest_dir = std::env::var("CARGO_MANIFEST_DIR").expect("CARGO_MANIFEST_DIR not set");
     let migrations_path = Path::new(&manifest_dir).join(&path_str);
 
-    let supported_backends = vec!["sqlite"];
-
     let mut backend_map = std::collections::BTreeMap::<String, Vec<(String, String)>>::new();
 
     for entry in fs::read_dir(&migrations_path).expect("Failed to read migrations directory") {
@@ -208,41 +204,15 @@ pub fn load_atlas_sql_migrations(input: TokenStream) -> TokenStream {
             // Parse the goose format migration (-- +goose Up / -- +goose Down)
             let (migration_sql, down_sql) = parse_goose_format(&contents, file_name);
 
-            // Determine which backend(s) this migration applies to
-            let mut matched_backend = None;
-            for backend in &supported_backends {
-                if file_name.contains(&format!(".{backend}.")) {
-                    matched_backend = Some(*backend);
-                    break;
-                }
-            }
-
             // Generate .up.sql and .down.sql filenames
             let base_name = file_name.trim_end_matches(".sql");
             let up_filename = format!("{base_name}.up.sql");
             let down_filename = format!("{base_name}.down.sql");
 
-            if let Some(backend) = matched_backend {
-                backend_map
-                    .entry(backend.to_string())
-                    .or_default()
-                    .push((up_filename.clone(), migration_sql.clone()));
-                backend_map
-                    .entry(backend.to_string())
-                    .or_default()
-                    .push((down_filename.clone(), down_sql.clone()));
-            } else {
-                // No specific backend mentioned — add to ALL backends
-                for backend in &supported_backends {
-                    backend_map
-                        .entry(backend.to_string())
-                        .or_default()
-                        .push((up_filename.clone(), migration_sql.clone()));
-                    backend_map
-                        .entry(backend.to_string())
-                        .or_default()
-                        .push((down_filename.clone(), down_sql.clone()));
-                }
+            for backend in migration_backends(file_name) {
+                let files = backend_map.entry(backend.to_string()).or_default();
+                files.push((up_filename.clone(), migration_sql.clone()));
+                files.push((down_filename.clone(), down_sql.clone()));
             }
         }
     }
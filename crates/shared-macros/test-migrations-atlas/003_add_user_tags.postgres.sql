@@ -0,0 +1,10 @@
+-- +goose Up
+-- create "user_tags" table
+CREATE TABLE user_tags (
+    user_id INTEGER NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    tags TEXT[] NOT NULL DEFAULT '{}'
+);
+
+-- +goose Down
+-- reverse: create "user_tags" table
+DROP TABLE IF EXISTS user_tags;
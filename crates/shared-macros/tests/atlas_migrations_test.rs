@@ -129,3 +129,26 @@ fn test_atlas_migration_structure() {
         "Should not contain goose Down marker"
     );
 }
+
+#[test]
+fn test_backend_specific_atlas_migrations() {
+    let migrations: BTreeMap<&str, BTreeMap<&str, &str>> =
+        load_atlas_sql_migrations!("test-migrations-atlas");
+    let sqlite_migrations = migrations.get("sqlite").unwrap();
+    let postgres_migrations = migrations
+        .get("postgres")
+        .expect("Should have postgres backend");
+
+    // .postgres.sql files only land in the postgres map
+    assert!(postgres_migrations.contains_key("003_add_user_tags.postgres.up.sql"));
+    assert!(postgres_migrations.contains_key("003_add_user_tags.postgres.down.sql"));
+    assert!(!sqlite_migrations.contains_key("003_add_user_tags.postgres.up.sql"));
+    assert!(!sqlite_migrations.contains_key("003_add_user_tags.postgres.down.sql"));
+
+    // .sqlite.sql files only land in the sqlite map
+    assert!(!postgres_migrations.contains_key("002_add_user_settings.sqlite.up.sql"));
+
+    // Unsuffixed files fan out to every backend
+    assert!(postgres_migrations.contains_key("001_create_users.up.sql"));
+    assert!(sqlite_migrations.contains_key("001_create_users.up.sql"));
+}
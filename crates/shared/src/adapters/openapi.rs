@@ -4,8 +4,10 @@ use axum::{
     Json,
     response::{IntoResponse, Redirect, Response},
 };
-use http::StatusCode;
+use chrono::{DateTime, Utc};
+use http::{HeaderMap, HeaderValue, StatusCode, header};
 use serde::Serialize;
+use sha2::{Digest, Sha256};
 use utoipa::IntoResponses;
 
 pub const API_VERSION_TAG: &str = "v1";
@@ -54,6 +56,107 @@ impl<T: Serialize, E: Serialize + IntoResponse> From<Result<T, E>> for JsonRespo
     }
 }
 
+/// HTTP-date format used by `Last-Modified` and `If-Modified-Since`
+const HTTP_DATE_FORMAT: &str = "%a, %d %b %Y %H:%M:%S GMT";
+
+/// Validators for a listing response. The ETag is a hash of the serialized response body, so
+/// any change to the page, its page tokens or its total count changes it. `Last-Modified` is the
+/// newest `updated_at` in the result set.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ListingValidators {
+    pub last_modified: Option<DateTime<Utc>>,
+    pub etag: String,
+}
+
+impl ListingValidators {
+    pub fn new(body: &impl Serialize, updated_at: impl IntoIterator<Item = DateTime<Utc>>) -> Self {
+        let last_modified = updated_at.into_iter().max();
+        let serialized = serde_json::to_vec(body).unwrap_or_default();
+        Self {
+            last_modified,
+            etag: format!("W/\"{:x}\"", Sha256::digest(&serialized)),
+        }
+    }
+
+    /// Whether the request preconditions show the client already has this listing.
+    /// `If-None-Match` takes precedence over `If-Modified-Since` when both are sent.
+    pub fn is_not_modified(&self, request_headers: &HeaderMap) -> bool {
+        if let Some(if_none_match) = request_headers.get(header::IF_NONE_MATCH) {
+            let Ok(if_none_match) = if_none_match.to_str() else {
+                return false;
+            };
+            return if_none_match.split(',').map(str::trim).any(|tag| {
+                tag == "*" || tag.trim_start_matches("W/") == self.etag.trim_start_matches("W/")
+            });
+        }
+
+        let Some(last_modified) = self.last_modified else {
+            return false;
+        };
+        request_headers
+            .get(header::IF_MODIFIED_SINCE)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| DateTime::parse_from_rfc2822(value).ok())
+            // HTTP dates have second precision
+            .is_some_and(|since| last_modified.timestamp() <= since.timestamp())
+    }
+
+    fn apply_headers(&self, response: &mut Response) {
+        let headers = response.headers_mut();
+        if let Ok(etag) = HeaderValue::from_str(&self.etag) {
+            headers.insert(header::ETAG, etag);
+        }
+        if let Some(last_modified) = self.last_modified {
+            let formatted = last_modified.format(HTTP_DATE_FORMAT).to_string();
+            if let Ok(last_modified) = HeaderValue::from_str(&formatted) {
+                headers.insert(header::LAST_MODIFIED, last_modified);
+            }
+        }
+    }
+}
+
+/// A JSON listing response that honours `If-None-Match` / `If-Modified-Since`, answering
+/// `304 Not Modified` when the listing is unchanged since the client last fetched it
+pub struct ConditionalJsonResponse<T: Serialize, E: Serialize> {
+    inner: JsonResponse<T, E>,
+    validators: Option<ListingValidators>,
+    not_modified: bool,
+}
+
+impl<T: Serialize, E: Serialize + IntoResponse> ConditionalJsonResponse<T, E> {
+    pub fn new(
+        request_headers: &HeaderMap,
+        result: Result<T, E>,
+        validators: impl FnOnce(&T) -> ListingValidators,
+    ) -> Self {
+        let validators = result.as_ref().ok().map(validators);
+        let not_modified = validators
+            .as_ref()
+            .is_some_and(|validators| validators.is_not_modified(request_headers));
+        Self {
+            inner: JsonResponse::from(result),
+            validators,
+            not_modified,
+        }
+    }
+}
+
+impl<T: Serialize, E: Serialize + IntoResponse + Debug> IntoResponse
+    for ConditionalJsonResponse<T, E>
+{
+    fn into_response(self) -> Response {
+        let mut response = if self.not_modified {
+            StatusCode::NOT_MODIFIED.into_response()
+        } else {
+            self.inner.into_response()
+        };
+        if let Some(validators) = &self.validators {
+            validators.apply_headers(&mut response);
+        }
+        response
+    }
+}
+
 pub struct RedirectResponse<E: Serialize + ToOwned + IntoResponse>(Result<Redirect, E>);
 
 impl<E: Serialize + ToOwned + IntoResponse> RedirectResponse<E> {
@@ -96,3 +199,91 @@ pub const API_KEY_OPENAPI_NAME: &str = "api_key";
 //     description: Some("API key for authentication"),
 //     extensions: None,
 // }));
+
+#[cfg(test)]
+mod tests {
+    mod unit {
+        use super::super::*;
+        use chrono::TimeZone;
+
+        fn timestamp(seconds: i64) -> DateTime<Utc> {
+            Utc.timestamp_opt(seconds, 0).unwrap()
+        }
+
+        #[derive(Serialize)]
+        struct Page {
+            items: Vec<DateTime<Utc>>,
+            next_page_token: Option<String>,
+            total_count: u64,
+        }
+
+        fn page(updated_at: &[i64], total_count: u64) -> Result<Page, String> {
+            Ok(Page {
+                items: updated_at
+                    .iter()
+                    .map(|seconds| timestamp(*seconds))
+                    .collect(),
+                next_page_token: Some("page-2".to_string()),
+                total_count,
+            })
+        }
+
+        fn respond_page(headers: &HeaderMap, updated_at: &[i64], total_count: u64) -> Response {
+            ConditionalJsonResponse::new(headers, page(updated_at, total_count), |page| {
+                ListingValidators::new(page, page.items.iter().copied())
+            })
+            .into_response()
+        }
+
+        fn respond(headers: &HeaderMap, updated_at: &[i64]) -> Response {
+            respond_page(headers, updated_at, 10)
+        }
+
+        #[test]
+        fn test_matching_etag_returns_not_modified() {
+            let first = respond(&HeaderMap::new(), &[1_700_000_000, 1_700_000_100]);
+            assert_eq!(first.status(), StatusCode::OK);
+            let etag = first.headers().get(header::ETAG).unwrap().clone();
+
+            let mut headers = HeaderMap::new();
+            headers.insert(header::IF_NONE_MATCH, etag);
+            let second = respond(&headers, &[1_700_000_000, 1_700_000_100]);
+            assert_eq!(second.status(), StatusCode::NOT_MODIFIED);
+
+            // A newer item or a removed item changes the ETag
+            let updated = respond(&headers, &[1_700_000_000, 1_700_000_200]);
+            assert_eq!(updated.status(), StatusCode::OK);
+            let removed = respond(&headers, &[1_700_000_100]);
+            assert_eq!(removed.status(), StatusCode::OK);
+        }
+
+        #[test]
+        fn test_delete_on_later_page_changes_first_page_etag() {
+            let first = respond_page(&HeaderMap::new(), &[1_700_000_000, 1_700_000_100], 10);
+            let etag = first.headers().get(header::ETAG).unwrap().clone();
+
+            // The first page keeps the same items, only the total count drops
+            let mut headers = HeaderMap::new();
+            headers.insert(header::IF_NONE_MATCH, etag);
+            let after_delete = respond_page(&headers, &[1_700_000_000, 1_700_000_100], 9);
+            assert_eq!(after_delete.status(), StatusCode::OK);
+        }
+
+        #[test]
+        fn test_if_modified_since_uses_newest_updated_at() {
+            let first = respond(&HeaderMap::new(), &[1_700_000_000, 1_700_000_100]);
+            let last_modified = first.headers().get(header::LAST_MODIFIED).unwrap().clone();
+
+            let mut headers = HeaderMap::new();
+            headers.insert(header::IF_MODIFIED_SINCE, last_modified);
+            assert_eq!(
+                respond(&headers, &[1_700_000_000, 1_700_000_100]).status(),
+                StatusCode::NOT_MODIFIED
+            );
+            assert_eq!(
+                respond(&headers, &[1_700_000_000, 1_700_000_101]).status(),
+                StatusCode::OK
+            );
+        }
+    }
+}
@@ -303,15 +303,20 @@ impl IntoResponses for CommonError {
     }
 }
 
-impl IntoResponse for CommonError {
-    fn into_response(self) -> Response {
-        let status = match self {
+impl CommonError {
+    /// HTTP status this error is reported with. An `Unknown` error that wraps another
+    /// `CommonError` (e.g. via `anyhow::Error::from`) reports the status of the wrapped error.
+    pub fn http_status(&self) -> StatusCode {
+        match self {
+            CommonError::Unknown(e) => match e.downcast_ref::<CommonError>() {
+                Some(inner) => inner.http_status(),
+                None => StatusCode::INTERNAL_SERVER_ERROR,
+            },
             CommonError::Authentication { .. } => StatusCode::UNAUTHORIZED,
             CommonError::Authorization { .. } => StatusCode::FORBIDDEN,
             CommonError::NotFound { .. } => StatusCode::NOT_FOUND,
             CommonError::InvalidRequest { .. } => StatusCode::BAD_REQUEST,
             CommonError::InvalidResponse { .. }
-            | CommonError::Unknown(_)
             | CommonError::Repository { .. }
             | CommonError::SqliteError { .. }
             | CommonError::TokioChannelError { .. }
@@ -326,7 +331,13 @@ impl IntoResponse for CommonError {
             | CommonError::ReqwestError { .. }
             | CommonError::PmdaemonError { .. }
             | CommonError::AddrParseError { .. } => StatusCode::INTERNAL_SERVER_ERROR,
-        };
+        }
+    }
+}
+
+impl IntoResponse for CommonError {
+    fn into_response(self) -> Response {
+        let status = self.http_status();
 
         // Get a more detailed message for Unknown errors
         let message = match &self {
@@ -409,3 +420,78 @@ impl McpErrorMsg for CommonError {
         self.to_string()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    mod unit {
+        use super::super::*;
+
+        #[test]
+        fn test_http_status_per_variant() {
+            let cases = [
+                (
+                    CommonError::Authentication {
+                        msg: "no token".to_string(),
+                        source: None,
+                    },
+                    StatusCode::UNAUTHORIZED,
+                ),
+                (
+                    CommonError::Authorization {
+                        msg: "not allowed".to_string(),
+                        source: anyhow::anyhow!("not allowed"),
+                    },
+                    StatusCode::FORBIDDEN,
+                ),
+                (
+                    CommonError::NotFound {
+                        msg: "agent not found".to_string(),
+                        lookup_id: "agent-1".to_string(),
+                        source: None,
+                    },
+                    StatusCode::NOT_FOUND,
+                ),
+                (
+                    CommonError::InvalidRequest {
+                        msg: "bad input".to_string(),
+                        source: None,
+                    },
+                    StatusCode::BAD_REQUEST,
+                ),
+                (
+                    CommonError::InvalidResponse {
+                        msg: "bad output".to_string(),
+                        source: None,
+                    },
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                ),
+                (
+                    CommonError::Repository {
+                        msg: "db down".to_string(),
+                        source: None,
+                    },
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                ),
+                (
+                    CommonError::Unknown(anyhow::anyhow!("boom")),
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                ),
+            ];
+
+            for (error, expected) in cases {
+                assert_eq!(error.http_status(), expected, "{error:?}");
+                assert_eq!(error.into_response().status(), expected);
+            }
+        }
+
+        #[test]
+        fn test_unknown_wrapping_common_error_uses_inner_status() {
+            let error = CommonError::Unknown(anyhow::Error::from(CommonError::NotFound {
+                msg: "task not found".to_string(),
+                lookup_id: "t1".to_string(),
+                source: None,
+            }));
+            assert_eq!(error.http_status(), StatusCode::NOT_FOUND);
+        }
+    }
+}
@@ -9,6 +9,7 @@ pub mod logging;
 pub mod port;
 pub mod primitives;
 pub mod process_manager;
+pub mod request_id;
 pub mod restate;
 pub mod soma_agent_definition;
 pub mod test_utils;
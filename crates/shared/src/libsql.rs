@@ -5,6 +5,7 @@ use std::{collections::BTreeMap, fs, path::PathBuf};
 use crate::error::CommonError;
 use libsql::params::IntoParams;
 use libsql::{BatchRows, Database, Rows};
+use sha2::{Digest, Sha256};
 use tempfile::TempDir;
 use tracing::debug;
 use url::Url;
@@ -178,6 +179,15 @@ fn get_libsql_path(url_str: &str) -> Result<String, CommonError> {
     }
 }
 
+/// Returns the database file for a `mode=local` connection string, or `None` for remote and
+/// remote replica connections
+pub fn local_db_file_path(url: &Url) -> Result<Option<PathBuf>, CommonError> {
+    match ConnectionType::try_from(url.clone())? {
+        ConnectionType::Local(params) => Ok(Some(params.path_to_db_file)),
+        ConnectionType::RemoteReplica(_) | ConnectionType::Remote(_) => Ok(None),
+    }
+}
+
 impl TryFrom<Url> for ConnectionType {
     type Error = CommonError;
     fn try_from(url: Url) -> Result<Self, Self::Error> {
@@ -290,6 +300,84 @@ pub fn merge_nested_migrations<'a>(mergable_migrations: Vec<Migrations<'a>>) ->
     target
 }
 
+/// Table holding the checksum of every migration applied to the database
+const MIGRATION_CHECKSUMS_TABLE: &str = "soma_migration_checksums";
+
+fn migration_checksum(contents: &str) -> String {
+    format!("{:x}", Sha256::digest(contents.as_bytes()))
+}
+
+async fn recorded_migration_checksums(
+    conn: &libsql::Connection,
+) -> Result<BTreeMap<String, String>, CommonError> {
+    conn.execute(
+        &format!(
+            "CREATE TABLE IF NOT EXISTS {MIGRATION_CHECKSUMS_TABLE} (name TEXT PRIMARY KEY NOT NULL, checksum TEXT NOT NULL)"
+        ),
+        (),
+    )
+    .await?;
+
+    let mut rows = conn
+        .query(
+            &format!("SELECT name, checksum FROM {MIGRATION_CHECKSUMS_TABLE}"),
+            (),
+        )
+        .await?;
+    let mut recorded = BTreeMap::new();
+    while let Some(row) = rows.next().await? {
+        recorded.insert(row.get::<String>(0)?, row.get::<String>(1)?);
+    }
+    Ok(recorded)
+}
+
+/// Fails if any migration that was already applied has been edited since. Migrations applied
+/// before checksums were recorded are trusted as they are now.
+async fn verify_migration_checksums(
+    conn: &libsql::Connection,
+    migrations: &BTreeMap<&str, &str>,
+) -> Result<(), CommonError> {
+    let recorded = recorded_migration_checksums(conn).await?;
+    let modified = migrations
+        .iter()
+        .filter(|(name, contents)| {
+            recorded
+                .get(**name)
+                .is_some_and(|checksum| *checksum != migration_checksum(contents))
+        })
+        .map(|(name, _)| *name)
+        .collect::<Vec<_>>();
+
+    if modified.is_empty() {
+        Ok(())
+    } else {
+        Err(CommonError::Repository {
+            msg: format!(
+                "Applied migrations were modified after being applied: {}. Restore the original migration files and add a new migration instead.",
+                modified.join(", ")
+            ),
+            source: None,
+        })
+    }
+}
+
+/// Records the checksum of every migration that does not have one yet
+async fn record_migration_checksums(
+    conn: &libsql::Connection,
+    migrations: &BTreeMap<&str, &str>,
+) -> Result<(), CommonError> {
+    for (name, contents) in migrations {
+        conn.execute(
+            &format!(
+                "INSERT OR IGNORE INTO {MIGRATION_CHECKSUMS_TABLE} (name, checksum) VALUES (?1, ?2)"
+            ),
+            libsql::params![*name, migration_checksum(contents)],
+        )
+        .await?;
+    }
+    Ok(())
+}
+
 pub async fn establish_db_connection<'a>(
     connection_string: &Url,
     migrations: Option<Migrations<'a>>,
@@ -351,10 +439,88 @@ pub async fn establish_db_connection<'a>(
             .map(|(k, v)| (*k, *v))
             .collect::<BTreeMap<&str, &str>>();
 
+        // Refuse to start on a schema built from different migration sources
+        verify_migration_checksums(&conn, &migrations_to_run).await?;
+
         let temp_dir = write_migrations_to_temp_dir(&migrations_to_run).await?;
         libsql_migration::dir::migrate(&conn, temp_dir.path().to_path_buf()).await?;
         // temp_dir is dropped here, cleaning up the temp directory
+
+        record_migration_checksums(&conn, &migrations_to_run).await?;
     }
 
     Ok((db, Connection(conn)))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn migrations<'a>(files: &[(&'a str, &'a str)]) -> Migrations<'a> {
+        BTreeMap::from([("sqlite", files.iter().copied().collect())])
+    }
+
+    fn db_url(dir: &TempDir) -> Url {
+        Url::parse(&format!(
+            "libsql://{}?mode=local",
+            dir.path().join("test.db").display()
+        ))
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_unchanged_migrations_pass_checksum_verification() {
+        let dir = TempDir::new().unwrap();
+        let url = db_url(&dir);
+        let initial = ("0001_init.up.sql", "CREATE TABLE a (id INTEGER);");
+
+        establish_db_connection(&url, Some(migrations(&[initial])))
+            .await
+            .unwrap();
+        let (_db, conn) = establish_db_connection(
+            &url,
+            Some(migrations(&[
+                initial,
+                ("0002_more.up.sql", "CREATE TABLE b (id INTEGER);"),
+            ])),
+        )
+        .await
+        .unwrap();
+
+        let recorded = recorded_migration_checksums(&conn).await.unwrap();
+        assert_eq!(
+            recorded.keys().collect::<Vec<_>>(),
+            vec!["0001_init.up.sql", "0002_more.up.sql"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_modified_applied_migration_is_rejected() {
+        let dir = TempDir::new().unwrap();
+        let url = db_url(&dir);
+
+        establish_db_connection(
+            &url,
+            Some(migrations(&[(
+                "0001_init.up.sql",
+                "CREATE TABLE a (id INTEGER);",
+            )])),
+        )
+        .await
+        .unwrap();
+        let error = establish_db_connection(
+            &url,
+            Some(migrations(&[(
+                "0001_init.up.sql",
+                "CREATE TABLE a (id INTEGER, name TEXT);",
+            )])),
+        )
+        .await
+        .unwrap_err();
+
+        match error {
+            CommonError::Repository { msg, .. } => assert!(msg.contains("0001_init.up.sql")),
+            other => panic!("Expected repository error, got {other:?}"),
+        }
+    }
+}
@@ -116,6 +116,85 @@ impl WrappedJsonValue {
     pub fn new(value: serde_json::Value) -> Self {
         Self(value)
     }
+
+    /// Parses JSON from untrusted input, rejecting payloads beyond `limits`. The size limit is
+    /// checked before parsing so oversized input is never deserialized.
+    pub fn from_untrusted_str(s: &str, limits: &JsonLimits) -> Result<Self, CommonError> {
+        if s.len() > limits.max_bytes {
+            return Err(json_limit_error(format!(
+                "JSON payload is {} bytes, exceeding the limit of {} bytes",
+                s.len(),
+                limits.max_bytes
+            )));
+        }
+        let value: serde_json::Value =
+            serde_json::from_str(s).map_err(|e| CommonError::InvalidRequest {
+                msg: format!("invalid json value: {e}"),
+                source: None,
+            })?;
+        let value = Self(value);
+        value.check_limits(limits)?;
+        Ok(value)
+    }
+
+    /// Checks an already parsed value (e.g. from a request body) against `limits`.
+    pub fn check_limits(&self, limits: &JsonLimits) -> Result<(), CommonError> {
+        let depth = json_depth(&self.0);
+        if depth > limits.max_depth {
+            return Err(json_limit_error(format!(
+                "JSON payload is nested {depth} levels deep, exceeding the limit of {}",
+                limits.max_depth
+            )));
+        }
+        let size = serde_json::to_vec(&self.0)
+            .map(|bytes| bytes.len())
+            .unwrap_or(usize::MAX);
+        if size > limits.max_bytes {
+            return Err(json_limit_error(format!(
+                "JSON payload is {size} bytes, exceeding the limit of {} bytes",
+                limits.max_bytes
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Depth and size bounds for JSON accepted from untrusted input such as credentials and
+/// provider configuration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct JsonLimits {
+    pub max_depth: usize,
+    pub max_bytes: usize,
+}
+
+impl Default for JsonLimits {
+    fn default() -> Self {
+        Self {
+            max_depth: 32,
+            max_bytes: 1024 * 1024,
+        }
+    }
+}
+
+fn json_limit_error(msg: String) -> CommonError {
+    CommonError::InvalidRequest { msg, source: None }
+}
+
+/// Nesting depth of a JSON value, computed iteratively so hostile input cannot exhaust the stack.
+/// Scalars have depth 0 and every enclosing array or object adds one level.
+fn json_depth(value: &serde_json::Value) -> usize {
+    let mut max_depth = 0;
+    let mut stack = vec![(value, 0usize)];
+    while let Some((value, depth)) = stack.pop() {
+        let children: Box<dyn Iterator<Item = &serde_json::Value>> = match value {
+            serde_json::Value::Array(items) => Box::new(items.iter()),
+            serde_json::Value::Object(map) => Box::new(map.values()),
+            _ => continue,
+        };
+        max_depth = max_depth.max(depth + 1);
+        stack.extend(children.map(|child| (child, depth + 1)));
+    }
+    max_depth
 }
 
 impl From<serde_json::Value> for WrappedJsonValue {
@@ -286,17 +365,126 @@ impl From<WrappedChronoDateTime> for libsql::Value {
 }
 
 // Pagination types
-#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, JsonSchema, IntoParams)]
+
+/// Page size used when a request leaves `page_size` unset (zero or negative)
+pub const DEFAULT_PAGE_SIZE: i64 = 50;
+/// Largest page size a repository will return, regardless of what was requested
+pub const MAX_PAGE_SIZE: i64 = 1000;
+
+/// Order listings return their items in
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema, JsonSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum PaginationOrder {
+    /// Most recently created first. Every repository list query orders by `created_at`
+    /// descending and the cursor tokens encode the `created_at` of the last item.
+    #[default]
+    NewestFirst,
+    /// Alphabetical by name, for listings built from in-memory registries rather than rows
+    NameAscending,
+}
+
+/// Page size and ordering defaults shared by every repository's list queries
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PaginationDefaults {
+    pub page_size: i64,
+    pub max_page_size: i64,
+    pub order: PaginationOrder,
+}
+
+impl Default for PaginationDefaults {
+    fn default() -> Self {
+        Self {
+            page_size: DEFAULT_PAGE_SIZE,
+            max_page_size: MAX_PAGE_SIZE,
+            order: PaginationOrder::NewestFirst,
+        }
+    }
+}
+
+static PAGINATION_DEFAULTS: std::sync::RwLock<PaginationDefaults> =
+    std::sync::RwLock::new(PaginationDefaults {
+        page_size: DEFAULT_PAGE_SIZE,
+        max_page_size: MAX_PAGE_SIZE,
+        order: PaginationOrder::NewestFirst,
+    });
+
+/// Returns the process-wide pagination defaults
+pub fn pagination_defaults() -> PaginationDefaults {
+    *PAGINATION_DEFAULTS
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Overrides the process-wide pagination defaults, e.g. from server configuration
+pub fn set_pagination_defaults(defaults: PaginationDefaults) {
+    *PAGINATION_DEFAULTS
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner()) = defaults;
+}
+
+/// Which way a listing pages from the cursor in `next_page_token`
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema, JsonSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum PaginationDirection {
+    /// Older items, continuing from a `next_page_token`
+    #[default]
+    Forward,
+    /// Newer items, continuing from a `prev_page_token`
+    Backward,
+}
+
+/// Items come back in the default [`PaginationOrder`] (newest first, by `created_at`), since
+/// cursor tokens encode the `created_at` of the last item returned.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema, JsonSchema, IntoParams)]
 #[into_params(style = Form, parameter_in = Query)]
 pub struct PaginationRequest {
+    /// Items per page; zero or negative uses the server default (50), values above the maximum (1000) are capped
     pub page_size: i64,
     pub next_page_token: Option<String>,
+    /// Only honoured by listings that return a `prev_page_token`; every other listing pages forward
+    #[serde(default)]
+    #[param(ignore)]
+    pub direction: PaginationDirection,
+}
+
+impl PaginationRequest {
+    /// Page size a repository should query with, resolved against the global defaults
+    pub fn resolved_page_size(&self) -> i64 {
+        self.resolved_page_size_with(&pagination_defaults())
+    }
+
+    /// Page size resolved against explicit defaults, for callers that override them per call
+    pub fn resolved_page_size_with(&self, defaults: &PaginationDefaults) -> i64 {
+        if self.page_size <= 0 {
+            defaults.page_size.min(defaults.max_page_size)
+        } else {
+            self.page_size.min(defaults.max_page_size)
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct PaginatedResponse<T: ToSchema + Serialize> {
     pub items: Vec<T>,
     pub next_page_token: Option<String>,
+    /// Token to send with `direction=backward` to fetch the page before this one. Only set by
+    /// listings that can page backward.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prev_page_token: Option<String>,
+    /// Total number of items matching the query across all pages. Only set by listings that can
+    /// count without a full scan.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub total_count: Option<u64>,
+    /// Page size the listing resolved the request to, after applying the defaults
+    #[serde(default)]
+    pub page_size: i64,
+    /// Order the items were returned in
+    #[serde(default)]
+    pub order: PaginationOrder,
 }
 
 impl<T: ToSchema + Serialize> ToSchema for PaginatedResponse<T> {
@@ -312,6 +500,10 @@ impl<T: ToSchema + Serialize> ToSchema for PaginatedResponse<T> {
     ) {
         schemas.push((T::name().to_string(), T::schema()));
         T::schemas(schemas);
+        schemas.push((
+            PaginationOrder::name().to_string(),
+            PaginationOrder::schema(),
+        ));
         schemas.push((format!("{}PaginatedResponse", T::name()), Self::schema()));
     }
 }
@@ -332,12 +524,45 @@ impl<T: ToSchema + Serialize> PartialSchema for PaginatedResponse<T> {
                     utoipa::openapi::ObjectBuilder::new()
                         .schema_type(utoipa::openapi::schema::Type::String),
                 )
+                .property(
+                    "prev_page_token",
+                    utoipa::openapi::ObjectBuilder::new()
+                        .schema_type(utoipa::openapi::schema::Type::String),
+                )
+                .property(
+                    "total_count",
+                    utoipa::openapi::ObjectBuilder::new()
+                        .schema_type(utoipa::openapi::schema::Type::Integer)
+                        .format(Some(utoipa::openapi::schema::SchemaFormat::KnownFormat(
+                            utoipa::openapi::schema::KnownFormat::Int64,
+                        )))
+                        .minimum(Some(0)),
+                )
+                .property(
+                    "page_size",
+                    utoipa::openapi::ObjectBuilder::new()
+                        .schema_type(utoipa::openapi::schema::Type::Integer)
+                        .format(Some(utoipa::openapi::schema::SchemaFormat::KnownFormat(
+                            utoipa::openapi::schema::KnownFormat::Int64,
+                        ))),
+                )
+                .property(
+                    "order",
+                    utoipa::openapi::schema::Ref::from_schema_name(PaginationOrder::name()),
+                )
                 .required("items")
+                .required("page_size")
+                .required("order")
                 .build(),
         ))
     }
 }
 
+/// Encode a composite key as a base64 pagination token
+pub fn encode_pagination_token(key_parts: &[String]) -> String {
+    base64::engine::general_purpose::STANDARD.encode(key_parts.join("__").as_bytes())
+}
+
 /// Decode a base64-encoded pagination token back to a vector of strings
 pub fn decode_pagination_token(token: &str) -> Result<Vec<String>, CommonError> {
     let decoded_bytes = base64::engine::general_purpose::STANDARD
@@ -364,19 +589,35 @@ impl<T: ToSchema + Serialize> PaginatedResponse<T> {
     /// - Generate the next page token from the last item's composite key
     ///
     /// # Arguments
-    /// * `items` - The list of items fetched (should be `page_size + 1` items)
+    /// * `items` - The list of items fetched (should be the resolved `page_size + 1` items)
     /// * `pagination` - The original pagination request
     /// * `get_id` - A closure that extracts a vector of strings (composite key) from an item
     pub fn from_items_with_extra<F>(
+        items: Vec<T>,
+        pagination: &PaginationRequest,
+        get_id: F,
+    ) -> Self
+    where
+        F: FnOnce(&T) -> Vec<String>,
+    {
+        Self::from_items_with_extra_with_defaults(items, pagination, &pagination_defaults(), get_id)
+    }
+
+    /// Like [`Self::from_items_with_extra`], for callers that queried with
+    /// [`PaginationRequest::resolved_page_size_with`] and explicit defaults
+    pub fn from_items_with_extra_with_defaults<F>(
         mut items: Vec<T>,
         pagination: &PaginationRequest,
+        defaults: &PaginationDefaults,
         get_id: F,
     ) -> Self
     where
         F: FnOnce(&T) -> Vec<String>,
     {
+        let page_size = pagination.resolved_page_size_with(defaults);
+
         // Check if we got more items than requested (page_size + 1)
-        let has_more = items.len() as i64 > pagination.page_size;
+        let has_more = items.len() as i64 > page_size;
 
         // If we have more items than page_size, remove the extra item
         if has_more {
@@ -384,11 +625,9 @@ impl<T: ToSchema + Serialize> PaginatedResponse<T> {
         }
 
         let next_page_token = if has_more && !items.is_empty() {
-            items.last().map(|item| {
-                let key_parts = get_id(item);
-                let composite_key = key_parts.join("__");
-                base64::engine::general_purpose::STANDARD.encode(composite_key.as_bytes())
-            })
+            items
+                .last()
+                .map(|item| encode_pagination_token(&get_id(item)))
         } else {
             None
         };
@@ -396,8 +635,67 @@ impl<T: ToSchema + Serialize> PaginatedResponse<T> {
         Self {
             items,
             next_page_token,
+            prev_page_token: None,
+            total_count: None,
+            page_size,
+            order: defaults.order,
         }
     }
+
+    /// Like [`Self::from_items_with_extra`], for listings that can also page backward.
+    ///
+    /// Backward pages are expected oldest first, as fetched by flipping the cursor comparison and
+    /// the `ORDER BY`. They are reversed here so every page comes back newest first. The
+    /// `next_page_token` is set when older items exist and the `prev_page_token` when newer ones do.
+    pub fn from_items_with_extra_bidirectional<F>(
+        mut items: Vec<T>,
+        pagination: &PaginationRequest,
+        get_id: F,
+    ) -> Self
+    where
+        F: Fn(&T) -> Vec<String>,
+    {
+        let defaults = pagination_defaults();
+        let page_size = pagination.resolved_page_size_with(&defaults);
+        let has_more = items.len() as i64 > page_size;
+        if has_more {
+            items.pop();
+        }
+
+        // Whichever way we paged, the cursor item itself is on the other side of this page
+        let has_cursor = pagination.next_page_token.is_some();
+        let (has_next, has_prev) = match pagination.direction {
+            PaginationDirection::Forward => (has_more, has_cursor),
+            PaginationDirection::Backward => {
+                items.reverse();
+                (has_cursor, has_more)
+            }
+        };
+
+        let next_page_token = items
+            .last()
+            .filter(|_| has_next)
+            .map(|item| encode_pagination_token(&get_id(item)));
+        let prev_page_token = items
+            .first()
+            .filter(|_| has_prev)
+            .map(|item| encode_pagination_token(&get_id(item)));
+
+        Self {
+            items,
+            next_page_token,
+            prev_page_token,
+            total_count: None,
+            page_size,
+            order: defaults.order,
+        }
+    }
+
+    /// Attach the total number of matching items, for listings that can count them cheaply
+    pub fn with_total_count(mut self, total_count: u64) -> Self {
+        self.total_count = Some(total_count);
+        self
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -518,3 +816,133 @@ impl PartialSchema for WrappedSchema {
         ))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_paginated_response_total_count_is_optional() {
+        let pagination = PaginationRequest {
+            page_size: 2,
+            next_page_token: None,
+            ..Default::default()
+        };
+        let page = PaginatedResponse::from_items_with_extra(
+            vec!["a".to_string(), "b".to_string(), "c".to_string()],
+            &pagination,
+            |item| vec![item.clone()],
+        );
+        assert_eq!(page.items.len(), 2);
+        assert_eq!(page.total_count, None);
+        let json = serde_json::to_value(&page).unwrap();
+        assert!(json.get("total_count").is_none());
+
+        let page = page.with_total_count(3);
+        assert_eq!(page.total_count, Some(3));
+        let json = serde_json::to_value(&page).unwrap();
+        assert_eq!(json["total_count"], 3);
+    }
+
+    #[test]
+    fn test_paginated_response_reports_resolved_defaults() {
+        let defaults = PaginationDefaults {
+            page_size: 2,
+            max_page_size: 10,
+            ..Default::default()
+        };
+        let page = PaginatedResponse::from_items_with_extra_with_defaults(
+            vec!["a".to_string(), "b".to_string(), "c".to_string()],
+            &PaginationRequest::default(),
+            &defaults,
+            |item| vec![item.clone()],
+        );
+        assert_eq!(page.items, vec!["a".to_string(), "b".to_string()]);
+        assert!(page.next_page_token.is_some());
+        assert_eq!(page.page_size, 2);
+        assert_eq!(page.order, PaginationOrder::NewestFirst);
+
+        let json = serde_json::to_value(&page).unwrap();
+        assert_eq!(json["page_size"], 2);
+        assert_eq!(json["order"], "newest_first");
+    }
+
+    fn nested_arrays(depth: usize) -> String {
+        format!("{}{}", "[".repeat(depth), "]".repeat(depth))
+    }
+
+    #[test]
+    fn test_untrusted_json_within_limits_is_accepted() {
+        let value = WrappedJsonValue::from_untrusted_str(
+            r#"{"client_id":"abc","scopes":["a","b"],"metadata":{"k":"v"}}"#,
+            &JsonLimits::default(),
+        )
+        .unwrap();
+        assert_eq!(value.get_inner()["client_id"], "abc");
+    }
+
+    #[test]
+    fn test_deeply_nested_json_is_rejected() {
+        let limits = JsonLimits::default();
+        assert!(WrappedJsonValue::from_untrusted_str(&nested_arrays(32), &limits).is_ok());
+
+        match WrappedJsonValue::from_untrusted_str(&nested_arrays(33), &limits) {
+            Err(CommonError::InvalidRequest { msg, .. }) => {
+                assert!(msg.contains("nested 33 levels"), "{msg}");
+            }
+            other => panic!("Expected InvalidRequest, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_oversized_json_is_rejected() {
+        let limits = JsonLimits {
+            max_depth: 32,
+            max_bytes: 64,
+        };
+        let oversized = format!("\"{}\"", "x".repeat(100));
+        assert!(matches!(
+            WrappedJsonValue::from_untrusted_str(&oversized, &limits),
+            Err(CommonError::InvalidRequest { .. })
+        ));
+
+        let parsed = WrappedJsonValue::new(serde_json::Value::String("x".repeat(100)));
+        assert!(parsed.check_limits(&limits).is_err());
+    }
+
+    #[test]
+    fn test_unset_page_size_resolves_to_default() {
+        let defaults = PaginationDefaults {
+            page_size: 25,
+            max_page_size: 100,
+            ..Default::default()
+        };
+        assert_eq!(
+            PaginationRequest::default().resolved_page_size_with(&defaults),
+            25
+        );
+        let negative = PaginationRequest {
+            page_size: -1,
+            next_page_token: None,
+            ..Default::default()
+        };
+        assert_eq!(negative.resolved_page_size_with(&defaults), 25);
+    }
+
+    #[test]
+    fn test_page_size_is_capped_at_max() {
+        let defaults = PaginationDefaults::default();
+        let explicit = PaginationRequest {
+            page_size: 10,
+            next_page_token: None,
+            ..Default::default()
+        };
+        assert_eq!(explicit.resolved_page_size_with(&defaults), 10);
+        let oversized = PaginationRequest {
+            page_size: MAX_PAGE_SIZE + 1,
+            next_page_token: None,
+            ..Default::default()
+        };
+        assert_eq!(oversized.resolved_page_size_with(&defaults), MAX_PAGE_SIZE);
+    }
+}
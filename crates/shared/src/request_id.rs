@@ -0,0 +1,57 @@
+//! Correlation id of the API request being handled, so calls made on its behalf (e.g. SDK
+//! function invocations) can carry the caller's id instead of minting their own.
+
+use std::future::Future;
+
+/// Header a caller sets to choose the request id
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+
+/// Longest request id accepted from a caller; longer values are ignored
+pub const MAX_REQUEST_ID_LEN: usize = 128;
+
+tokio::task_local! {
+    static REQUEST_ID: String;
+}
+
+/// Runs `fut` with `request_id` as the current request id
+pub async fn scope<F: Future>(request_id: String, fut: F) -> F::Output {
+    REQUEST_ID.scope(request_id, fut).await
+}
+
+/// Request id of the request being handled, if one was set
+pub fn current() -> Option<String> {
+    REQUEST_ID.try_with(Clone::clone).ok()
+}
+
+/// The current request id, or a fresh one for work no request id was set for
+pub fn current_or_new() -> String {
+    current().unwrap_or_else(|| uuid::Uuid::new_v4().to_string())
+}
+
+/// Returns the caller-supplied id if it is usable as a request id
+pub fn sanitize(request_id: &str) -> Option<&str> {
+    let request_id = request_id.trim();
+    (!request_id.is_empty() && request_id.len() <= MAX_REQUEST_ID_LEN).then_some(request_id)
+}
+
+#[cfg(test)]
+mod tests {
+    mod unit {
+        use super::super::*;
+
+        #[tokio::test]
+        async fn test_scoped_request_id_is_current() {
+            assert_eq!(current(), None);
+            let inside = scope("req-1".to_string(), async { current_or_new() }).await;
+            assert_eq!(inside, "req-1");
+            assert_ne!(current_or_new(), current_or_new());
+        }
+
+        #[test]
+        fn test_sanitize_rejects_empty_and_oversized_ids() {
+            assert_eq!(sanitize(" req-1 "), Some("req-1"));
+            assert_eq!(sanitize("  "), None);
+            assert_eq!(sanitize(&"x".repeat(MAX_REQUEST_ID_LEN + 1)), None);
+        }
+    }
+}
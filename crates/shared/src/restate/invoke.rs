@@ -104,6 +104,13 @@ pub fn construct_initial_object_id(task_id: &str) -> String {
     format!("soma:v1:task:{task_id}")
 }
 
+/// Inverse of [`construct_initial_object_id`]: returns the task id of an agent object key
+pub fn parse_initial_object_id(object_id: &str) -> Option<&str> {
+    object_id
+        .strip_prefix("soma:v1:task:")
+        .filter(|task_id| !task_id.is_empty() && !task_id.contains(':'))
+}
+
 pub fn construct_cancel_awakeable_id(task_id: &str) -> String {
     format!("soma:v1:task:{task_id}:cancel")
 }
@@ -267,7 +267,7 @@ pub struct ScopeToGroupMappingYaml {
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema, JsonSchema, Default)]
 #[serde(rename_all = "snake_case")]
 pub struct EncryptionConfig {
-    /// Map of envelope key id (ARN or file_name) -> envelope key configuration with nested DEKs
+    /// Map of envelope key id (ARN, GCP key name, Vault or Azure Key Vault key URL or file_name) -> envelope key configuration with nested DEKs
     /// DEKs are stored by their alias name (e.g., "default") rather than UUID
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub envelope_keys: Option<HashMap<String, EnvelopeKeyConfig>>,
@@ -289,11 +289,40 @@ pub struct EnvelopeKeyConfigLocal {
     pub deks: Option<HashMap<String, DekConfig>>,
 }
 
+#[derive(Serialize, Deserialize, Clone, Debug, JsonSchema, ToSchema)]
+pub struct EnvelopeKeyConfigGcpKms {
+    pub key_name: String,
+    pub location: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deks: Option<HashMap<String, DekConfig>>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, JsonSchema, ToSchema)]
+pub struct EnvelopeKeyConfigVaultTransit {
+    pub address: String,
+    pub mount: String,
+    pub key_name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deks: Option<HashMap<String, DekConfig>>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, JsonSchema, ToSchema)]
+pub struct EnvelopeKeyConfigAzureKeyVault {
+    pub vault_url: String,
+    pub key_name: String,
+    pub key_version: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deks: Option<HashMap<String, DekConfig>>,
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, JsonSchema, ToSchema)]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum EnvelopeKeyConfig {
     AwsKms(EnvelopeKeyConfigAwsKms),
     Local(EnvelopeKeyConfigLocal),
+    GcpKms(EnvelopeKeyConfigGcpKms),
+    VaultTransit(EnvelopeKeyConfigVaultTransit),
+    AzureKeyVault(EnvelopeKeyConfigAzureKeyVault),
 }
 
 impl EnvelopeKeyConfig {
@@ -312,6 +341,24 @@ impl EnvelopeKeyConfig {
                 }
                 local.deks.as_mut().unwrap()
             }
+            EnvelopeKeyConfig::GcpKms(gcp_kms) => {
+                if gcp_kms.deks.is_none() {
+                    gcp_kms.deks = Some(HashMap::new());
+                }
+                gcp_kms.deks.as_mut().unwrap()
+            }
+            EnvelopeKeyConfig::VaultTransit(vault) => {
+                if vault.deks.is_none() {
+                    vault.deks = Some(HashMap::new());
+                }
+                vault.deks.as_mut().unwrap()
+            }
+            EnvelopeKeyConfig::AzureKeyVault(azure) => {
+                if azure.deks.is_none() {
+                    azure.deks = Some(HashMap::new());
+                }
+                azure.deks.as_mut().unwrap()
+            }
         }
     }
 
@@ -320,6 +367,9 @@ impl EnvelopeKeyConfig {
         match self {
             EnvelopeKeyConfig::AwsKms(aws_kms) => aws_kms.deks.as_ref(),
             EnvelopeKeyConfig::Local(local) => local.deks.as_ref(),
+            EnvelopeKeyConfig::GcpKms(gcp_kms) => gcp_kms.deks.as_ref(),
+            EnvelopeKeyConfig::VaultTransit(vault) => vault.deks.as_ref(),
+            EnvelopeKeyConfig::AzureKeyVault(azure) => azure.deks.as_ref(),
         }
     }
 }
@@ -343,19 +393,56 @@ pub struct EnvelopeEncryptionKeyLocal {
     pub file_name: String,
 }
 
+#[derive(Serialize, Deserialize, Clone, Debug, JsonSchema, ToSchema)]
+pub struct EnvelopeEncryptionKeyGcpKms {
+    pub key_name: String,
+    pub location: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, JsonSchema, ToSchema)]
+pub struct EnvelopeEncryptionKeyVaultTransit {
+    pub address: String,
+    pub mount: String,
+    pub key_name: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, JsonSchema, ToSchema)]
+pub struct EnvelopeEncryptionKeyAzureKeyVault {
+    pub vault_url: String,
+    pub key_name: String,
+    pub key_version: String,
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, JsonSchema, ToSchema)]
 #[serde(rename_all = "snake_case", tag = "type")]
 pub enum EnvelopeEncryptionKey {
     AwsKms(EnvelopeEncryptionKeyAwsKms),
     Local(EnvelopeEncryptionKeyLocal),
+    GcpKms(EnvelopeEncryptionKeyGcpKms),
+    VaultTransit(EnvelopeEncryptionKeyVaultTransit),
+    AzureKeyVault(EnvelopeEncryptionKeyAzureKeyVault),
 }
 
 impl EnvelopeEncryptionKey {
-    /// Get the key id (ARN for KMS, file_name for local)
+    /// Get the key id (ARN for AWS KMS, key name for GCP KMS, key URL for Vault Transit and
+    /// Azure Key Vault, file_name for local)
     pub fn key_id(&self) -> String {
         match self {
             EnvelopeEncryptionKey::AwsKms(aws_kms) => aws_kms.arn.clone(),
             EnvelopeEncryptionKey::Local(local) => local.file_name.clone(),
+            EnvelopeEncryptionKey::GcpKms(gcp_kms) => gcp_kms.key_name.clone(),
+            EnvelopeEncryptionKey::VaultTransit(vault) => format!(
+                "{}/v1/{}/keys/{}",
+                vault.address.trim_end_matches('/'),
+                vault.mount.trim_matches('/'),
+                vault.key_name
+            ),
+            EnvelopeEncryptionKey::AzureKeyVault(azure) => format!(
+                "{}/keys/{}/{}",
+                azure.vault_url.trim_end_matches('/'),
+                azure.key_name,
+                azure.key_version
+            ),
         }
     }
 }
@@ -376,6 +463,29 @@ impl From<EnvelopeEncryptionKey> for EnvelopeKeyConfig {
                     deks: None,
                 })
             }
+            EnvelopeEncryptionKey::GcpKms(gcp_kms) => {
+                EnvelopeKeyConfig::GcpKms(EnvelopeKeyConfigGcpKms {
+                    key_name: gcp_kms.key_name,
+                    location: gcp_kms.location,
+                    deks: None,
+                })
+            }
+            EnvelopeEncryptionKey::VaultTransit(vault) => {
+                EnvelopeKeyConfig::VaultTransit(EnvelopeKeyConfigVaultTransit {
+                    address: vault.address,
+                    mount: vault.mount,
+                    key_name: vault.key_name,
+                    deks: None,
+                })
+            }
+            EnvelopeEncryptionKey::AzureKeyVault(azure) => {
+                EnvelopeKeyConfig::AzureKeyVault(EnvelopeKeyConfigAzureKeyVault {
+                    vault_url: azure.vault_url,
+                    key_name: azure.key_name,
+                    key_version: azure.key_version,
+                    deks: None,
+                })
+            }
         }
     }
 }
@@ -394,6 +504,26 @@ impl From<EnvelopeKeyConfig> for EnvelopeEncryptionKey {
                     file_name: local.file_name,
                 })
             }
+            EnvelopeKeyConfig::GcpKms(gcp_kms) => {
+                EnvelopeEncryptionKey::GcpKms(EnvelopeEncryptionKeyGcpKms {
+                    key_name: gcp_kms.key_name,
+                    location: gcp_kms.location,
+                })
+            }
+            EnvelopeKeyConfig::VaultTransit(vault) => {
+                EnvelopeEncryptionKey::VaultTransit(EnvelopeEncryptionKeyVaultTransit {
+                    address: vault.address,
+                    mount: vault.mount,
+                    key_name: vault.key_name,
+                })
+            }
+            EnvelopeKeyConfig::AzureKeyVault(azure) => {
+                EnvelopeEncryptionKey::AzureKeyVault(EnvelopeEncryptionKeyAzureKeyVault {
+                    vault_url: azure.vault_url,
+                    key_name: azure.key_name,
+                    key_version: azure.key_version,
+                })
+            }
         }
     }
 }
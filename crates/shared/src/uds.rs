@@ -3,17 +3,57 @@ use std::path::Path;
 
 use hyper_util::rt::TokioIo;
 use sdk_proto::soma_sdk_service_client::SomaSdkServiceClient;
+use tonic::codec::CompressionEncoding;
 use tonic::transport::{Endpoint, Uri};
 use tower::service_fn;
 
 /// Default Unix socket path for the SDK gRPC server
 pub const DEFAULT_SOMA_SERVER_SOCK: &str = "/tmp/soma-sdk.sock";
 
+/// Set to `0` or `false` to stop the SDK server and client from gzip-compressing messages
+pub const SDK_GRPC_COMPRESSION_ENV: &str = "SOMA_SDK_GRPC_COMPRESSION";
+
+/// Whether messages sent over the SDK socket are gzip-compressed. Enabled unless
+/// [`SDK_GRPC_COMPRESSION_ENV`] turns it off.
+pub fn sdk_grpc_compression_enabled() -> bool {
+    match std::env::var(SDK_GRPC_COMPRESSION_ENV) {
+        Ok(value) => !matches!(value.trim().to_lowercase().as_str(), "0" | "false"),
+        Err(_) => true,
+    }
+}
+
+/// Always accepts gzip responses so a client works against a server with either setting, and
+/// only compresses requests when `compression` is set
+pub fn configure_sdk_client_compression(
+    client: SomaSdkServiceClient<tonic::transport::Channel>,
+    compression: bool,
+) -> SomaSdkServiceClient<tonic::transport::Channel> {
+    let client = client.accept_compressed(CompressionEncoding::Gzip);
+    if compression {
+        client.send_compressed(CompressionEncoding::Gzip)
+    } else {
+        client
+    }
+}
+
 pub async fn create_soma_unix_socket_client(
     socket_path: &str,
+) -> Result<SomaSdkServiceClient<tonic::transport::Channel>, CommonError> {
+    create_soma_unix_socket_client_with_compression(socket_path, sdk_grpc_compression_enabled())
+        .await
+}
+
+/// Like [`create_soma_unix_socket_client`], with compression chosen by the caller instead of
+/// [`SDK_GRPC_COMPRESSION_ENV`]
+pub async fn create_soma_unix_socket_client_with_compression(
+    socket_path: &str,
+    compression: bool,
 ) -> Result<SomaSdkServiceClient<tonic::transport::Channel>, CommonError> {
     let channel = create_unix_socket_client(socket_path).await?;
-    Ok(SomaSdkServiceClient::new(channel))
+    Ok(configure_sdk_client_compression(
+        SomaSdkServiceClient::new(channel),
+        compression,
+    ))
 }
 
 /// Create a gRPC client connected to a Unix socket
@@ -0,0 +1,11 @@
+-- +goose Up
+-- add column "idempotency_key" to table: "task"
+ALTER TABLE `task` ADD COLUMN `idempotency_key` text NULL;
+-- create index "task_idempotency_key" to table: "task"
+CREATE UNIQUE INDEX `task_idempotency_key` ON `task` (`idempotency_key`);
+
+-- +goose Down
+-- reverse: create index "task_idempotency_key" to table: "task"
+DROP INDEX `task_idempotency_key`;
+-- reverse: add column "idempotency_key" to table: "task"
+ALTER TABLE `task` DROP COLUMN `idempotency_key`;
@@ -6,7 +6,8 @@ INSERT INTO task (
     status_timestamp,
     metadata,
     created_at,
-    updated_at
+    updated_at,
+    idempotency_key
 ) VALUES (
     :id,
     :context_id,
@@ -14,8 +15,12 @@ INSERT INTO task (
     :status_timestamp,
     :metadata,
     :created_at,
-    :updated_at
-);
+    :updated_at,
+    :idempotency_key
+) ON CONFLICT (idempotency_key) DO NOTHING;
+
+-- name: get_task_id_by_idempotency_key :one
+SELECT id FROM task WHERE idempotency_key = :idempotency_key;
 
 -- name: update_task_status :exec
 UPDATE task SET status = :status, status_message_id = :status_message_id, status_timestamp = :status_timestamp, updated_at = :updated_at WHERE id = :id;
@@ -7,10 +7,13 @@ CREATE TABLE IF NOT EXISTS task (
     metadata JSON NOT NULL,
     created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
     updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    idempotency_key TEXT,
 
     CONSTRAINT status_check CHECK (status IN ("submitted", "working", "input-required", "completed", "canceled", "failed", "rejected", "auth-required", "unknown"))
 );
 
+CREATE UNIQUE INDEX IF NOT EXISTS task_idempotency_key ON task (idempotency_key);
+
 CREATE TABLE IF NOT EXISTS task_timeline (
     id TEXT PRIMARY KEY,
     task_id TEXT NOT NULL,
@@ -123,6 +123,8 @@ pub async fn create_api_service(
     trace!("Crypto cache initialized");
     // Create the agent cache early (shared between services, needed for codegen)
     let agent_cache = sdk_agent_sync::create_agent_cache();
+    // Per-agent function allowlists, shared by the bridge API, the MCP server and the A2A handler
+    let agent_allowlists = ::mcp::logic::AgentAllowlists::new();
 
     // Create JWKS cache (JWKs will be created when default DEK alias is available)
     let internal_jwks_cache = identity::logic::jwk::cache::JwksCache::new(identity_repo.clone());
@@ -200,7 +202,7 @@ pub async fn create_api_service(
                         match crate::logic::secret_sync::sync_secrets_to_sdk(&mut client, secrets)
                             .await
                         {
-                            Ok(()) => {
+                            Ok(_) => {
                                 trace!("Initial secret sync complete");
                             }
                             Err(e) => {
@@ -231,7 +233,7 @@ pub async fn create_api_service(
                         match crate::logic::variable_sync::sync_variables_to_sdk(&mut client, vars)
                             .await
                         {
-                            Ok(()) => {
+                            Ok(_) => {
                                 trace!("Initial var sync complete");
                             }
                             Err(e) => {
@@ -289,11 +291,13 @@ pub async fn create_api_service(
     // Clone values for use in the service factory closure
     let mcp_repo_for_mcp = mcp_repo.clone();
     let crypto_cache_for_mcp = crypto_cache.clone();
+    let agent_allowlists_for_mcp = agent_allowlists.clone();
     let mcp_service = StreamableHttpService::new(
         move || {
             Ok(McpServerService {
                 repository: mcp_repo_for_mcp.clone(),
                 encryption_service: crypto_cache_for_mcp.clone(),
+                agent_allowlists: agent_allowlists_for_mcp.clone(),
             })
         },
         LocalSessionManager::default().into(),
@@ -341,6 +345,9 @@ pub async fn create_api_service(
         encryption_repository: encryption_repo.clone(),
         local_envelope_encryption_key_path,
         agent_cache: agent_cache.clone(),
+        agent_allowlists,
+        sdk_backoff: Default::default(),
+        agent_card_ttl: crate::logic::agent::DEFAULT_AGENT_CARD_TTL,
     })
     .await?;
     debug!("API service initialized");
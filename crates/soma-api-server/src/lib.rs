@@ -91,6 +91,12 @@ pub struct InitApiServiceParams {
     >,
     pub local_envelope_encryption_key_path: PathBuf,
     pub agent_cache: AgentCache,
+    /// Per-agent function allowlists enforced on provider function invocations
+    pub agent_allowlists: ::mcp::logic::AgentAllowlists,
+    /// Backoff used when waiting for the SDK server to become ready
+    pub sdk_backoff: logic::internal::SdkBackoffConfig,
+    /// How long a constructed A2A agent card is cached before it is rebuilt
+    pub agent_card_ttl: std::time::Duration,
 }
 
 impl ApiService {
@@ -123,6 +129,8 @@ impl ApiService {
             restate_ingress_client: init_params.restate_ingress_client.clone(),
             restate_admin_client: init_params.restate_admin_client.clone(),
             agent_cache: agent_cache.clone(),
+            agent_allowlists: init_params.agent_allowlists.clone(),
+            agent_card_ttl: init_params.agent_card_ttl,
         }));
         let task_service = Arc::new(TaskService::new(
             init_params.connection_manager.clone(),
@@ -134,6 +142,7 @@ impl ApiService {
             init_params.crypto_cache.clone(),
             init_params.mcp_service,
             auth_client.clone(),
+            init_params.agent_allowlists,
         )
         .await?;
 
@@ -144,6 +153,7 @@ impl ApiService {
             init_params.crypto_cache.clone(),
             init_params.restate_params.clone(),
             agent_cache.clone(),
+            init_params.sdk_backoff.clone(),
         ));
 
         // Create environment service
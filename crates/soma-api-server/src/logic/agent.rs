@@ -9,29 +9,139 @@ use crate::{
     logic::task as task_logic,
     repository::{CreateTask, Repository, TaskRepositoryLike},
     router::agent::AgentListItem,
-    sdk::sdk_agent_sync::{AgentCache, get_all_agents},
+    sdk::sdk_agent_sync::{AgentCache, AgentMetadata, get_agent, get_agents_page},
 };
 use a2a_rs::types::Task;
 use a2a_rs::{
     errors::A2aServerError,
     tasks::store::TaskStore,
-    types::{TaskId, TaskState, TaskStatus},
+    types::{TASK_REASON_CODE_METADATA_KEY, TaskId, TaskReasonCode, TaskState, TaskStatus},
 };
+use base64::Engine;
 use shared::{
     error::CommonError,
-    primitives::{WrappedChronoDateTime, WrappedJsonValue, WrappedUuidV4},
+    primitives::{
+        PaginatedResponse, PaginationRequest, WrappedChronoDateTime, WrappedJsonValue,
+        WrappedUuidV4,
+    },
 };
 use tracing::{info, trace};
 
-/// List all agents from the agent cache
-pub fn list_agents(cache: &AgentCache) -> Vec<AgentListItem> {
-    get_all_agents(cache)
-        .into_iter()
-        .map(|agent| AgentListItem {
-            project_id: agent.project_id,
-            agent_id: agent.id,
+pub type ListAgentsResponse = PaginatedResponse<AgentListItem>;
+
+/// List agents from the agent cache, one page at a time ordered by (project_id, agent_id)
+pub fn list_agents(
+    cache: &AgentCache,
+    pagination: &PaginationRequest,
+) -> Result<ListAgentsResponse, CommonError> {
+    let cursor = pagination
+        .next_page_token
+        .as_deref()
+        .map(decode_agent_cursor)
+        .transpose()?;
+
+    let page_size = pagination.resolved_page_size() as usize;
+    let items = get_agents_page(
+        cache,
+        cursor
+            .as_ref()
+            .map(|(project_id, agent_id)| (project_id.as_str(), agent_id.as_str())),
+        page_size + 1,
+    )
+    .into_iter()
+    .map(|agent| AgentListItem {
+        project_id: agent.project_id,
+        agent_id: agent.id,
+    })
+    .collect();
+
+    Ok(PaginatedResponse::from_items_with_extra(
+        items,
+        pagination,
+        |item| vec![encode_agent_cursor(&item.project_id, &item.agent_id)],
+    ))
+}
+
+/// Agent list cursors are a JSON `[project_id, agent_id]` array rather than `__`-joined parts,
+/// since either id may itself contain `__`
+fn encode_agent_cursor(project_id: &str, agent_id: &str) -> String {
+    serde_json::json!([project_id, agent_id]).to_string()
+}
+
+/// Inverse of [`encode_agent_cursor`] for a base64 pagination token
+fn decode_agent_cursor(token: &str) -> Result<(String, String), CommonError> {
+    let invalid_token = |msg: &str| CommonError::InvalidRequest {
+        msg: format!("Invalid pagination token: {msg}"),
+        source: None,
+    };
+    let decoded = base64::engine::general_purpose::STANDARD
+        .decode(token)
+        .map_err(|_e| invalid_token("invalid base64 string"))?;
+    serde_json::from_slice(&decoded)
+        .map_err(|_e| invalid_token("expected a [project_id, agent_id] array"))
+}
+
+/// The task an agent cancel publishes: canceled, with a status message recording that the
+/// cancellation was user initiated
+pub fn user_canceled_task(task_id: String, context_id: String) -> Task {
+    Task {
+        status: TaskStatus::with_reason(
+            TaskState::Canceled,
+            &task_id,
+            &context_id,
+            TaskReasonCode::UserCanceled,
+            Some("Task canceled by user".to_string()),
+        ),
+        id: task_id,
+        context_id,
+        artifacts: vec![],
+        history: vec![],
+        metadata: Default::default(),
+        kind: "task".to_string(),
+    }
+}
+
+/// Status message for a task that failed or was canceled, carrying `reason_code` in its metadata
+/// the same way [`TaskStatus::with_reason`] does
+pub fn reason_status_message(
+    reason_code: TaskReasonCode,
+    reason: String,
+) -> task_logic::CreateMessageRequest {
+    let mut metadata = task_logic::Metadata::new();
+    metadata.0.insert(
+        TASK_REASON_CODE_METADATA_KEY.to_string(),
+        serde_json::to_value(reason_code).unwrap_or_default(),
+    );
+    task_logic::CreateMessageRequest {
+        reference_task_ids: vec![],
+        role: task_logic::MessageRole::Agent,
+        metadata,
+        parts: vec![task_logic::MessagePart::TextPart(task_logic::TextPart {
+            text: reason,
+            metadata: task_logic::Metadata::new(),
+        })],
+    }
+}
+
+/// Why invoking an agent entrypoint failed: the ingress call timing out means the agent ran past
+/// its deadline, anything else is an agent error
+pub fn entrypoint_failure_reason(error: &anyhow::Error) -> TaskReasonCode {
+    let timed_out = error.chain().any(|cause| {
+        cause.downcast_ref::<reqwest::Error>().is_some_and(|e| {
+            e.is_timeout()
+                || matches!(
+                    e.status(),
+                    Some(
+                        reqwest::StatusCode::REQUEST_TIMEOUT | reqwest::StatusCode::GATEWAY_TIMEOUT
+                    )
+                )
         })
-        .collect()
+    });
+    if timed_out {
+        TaskReasonCode::DeadlineExceeded
+    } else {
+        TaskReasonCode::AgentError
+    }
 }
 
 pub struct RepositoryTaskStore {
@@ -42,6 +152,62 @@ impl RepositoryTaskStore {
     pub fn new(repository: Repository) -> Self {
         Self { repository }
     }
+
+    /// Stores `message` as the status message of `task_id`, keeping its A2A message id when it
+    /// is a UUID. Only text parts are kept, matching what the message store supports.
+    async fn insert_status_message(
+        &self,
+        task_id: &WrappedUuidV4,
+        message: &a2a_rs::types::Message,
+    ) -> Result<WrappedUuidV4, A2aServerError> {
+        let id = WrappedUuidV4::try_from(message.message_id.clone())
+            .unwrap_or_else(|_| WrappedUuidV4::new());
+        let parts = message
+            .parts
+            .iter()
+            .filter_map(|part| match part {
+                a2a_rs::types::Part::TextPart(text_part) => {
+                    Some(task_logic::MessagePart::TextPart(task_logic::TextPart {
+                        text: text_part.text.clone(),
+                        metadata: task_logic::Metadata(text_part.metadata.clone()),
+                    }))
+                }
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        let message = task_logic::Message {
+            id: id.clone(),
+            task_id: task_id.clone(),
+            reference_task_ids: message
+                .reference_task_ids
+                .iter()
+                .filter_map(|id| WrappedUuidV4::try_from(id.clone()).ok())
+                .collect(),
+            role: match message.role {
+                a2a_rs::types::MessageRole::User => task_logic::MessageRole::User,
+                a2a_rs::types::MessageRole::Agent => task_logic::MessageRole::Agent,
+            },
+            metadata: task_logic::Metadata(message.metadata.clone()),
+            parts,
+            created_at: WrappedChronoDateTime::now(),
+        };
+        self.repository
+            .insert_message(&message.try_into().map_err(convert_common_error)?)
+            .await
+            .map_err(convert_common_error)?;
+        Ok(id)
+    }
+}
+
+/// The id of the stored status message when `message` is that same message, so re-saving a task
+/// does not store its status message twice
+fn existing_status_message_id(
+    existing_task: &task_logic::TaskWithDetails,
+    message: &a2a_rs::types::Message,
+) -> Option<WrappedUuidV4> {
+    let stored = existing_task.status_message.as_ref()?;
+    (stored.id.to_string() == message.message_id).then(|| stored.id.clone())
 }
 
 // TODO: implement better type conversion
@@ -82,14 +248,21 @@ impl TaskStore for RepositoryTaskStore {
             WrappedChronoDateTime::try_from(task.status.timestamp.as_deref().unwrap_or(""))
                 .map_err(convert_common_error)?;
 
-        if existing_task.is_some() {
+        if let Some(existing_task) = existing_task {
             // Task exists, update it
             trace!(task_id = %task_id, status = ?task.status.state, "Updating existing task");
+            let status_message_id = match &task.status.message {
+                Some(message) => match existing_status_message_id(&existing_task, message) {
+                    Some(id) => Some(id),
+                    None => Some(self.insert_status_message(&task_id, message).await?),
+                },
+                None => None,
+            };
             self.repository
                 .update_task_status(&crate::repository::UpdateTaskStatus {
                     id: task_id,
                     status,
-                    status_message_id: None, // TODO: Handle status message if present
+                    status_message_id,
                     status_timestamp,
                     updated_at: now,
                 })
@@ -97,22 +270,44 @@ impl TaskStore for RepositoryTaskStore {
                 .map_err(convert_common_error)
         } else {
             // Task doesn't exist, create it
-            info!(task_id = %task_id, context_id = %task.context_id, "Task created");
-            self.repository
+            let stored_task_id = self
+                .repository
                 .create_task(&CreateTask {
-                    id: task_id,
+                    id: task_id.clone(),
                     context_id: WrappedUuidV4::try_from(task.context_id.clone())
                         .map_err(convert_common_error)?,
-                    status,
+                    status: status.clone(),
                     status_timestamp,
                     metadata: WrappedJsonValue::from(serde_json::Value::Object(
                         task.metadata.clone(),
                     )),
                     created_at: now,
                     updated_at: now,
+                    idempotency_key: task_idempotency_key(task),
                 })
                 .await
-                .map_err(convert_common_error)
+                .map_err(convert_common_error)?;
+            if stored_task_id != task_id {
+                // A retried send already created this task, keep the prior one as is
+                info!(task_id = %task_id, existing_task_id = %stored_task_id, "Task already created for idempotency key");
+                return Ok(());
+            }
+            info!(task_id = %task_id, context_id = %task.context_id, "Task created");
+
+            if let Some(message) = &task.status.message {
+                let status_message_id = self.insert_status_message(&task_id, message).await?;
+                self.repository
+                    .update_task_status(&crate::repository::UpdateTaskStatus {
+                        id: task_id,
+                        status,
+                        status_message_id: Some(status_message_id),
+                        status_timestamp,
+                        updated_at: now,
+                    })
+                    .await
+                    .map_err(convert_common_error)?;
+            }
+            Ok(())
         }
     }
 
@@ -123,41 +318,7 @@ impl TaskStore for RepositoryTaskStore {
             .await
             .map_err(convert_common_error)?;
 
-        let task_with_details = match task {
-            Some(x) => x,
-            None => return Ok(None),
-        };
-
-        // Convert status_message to a2a_rs::types::Message if present
-        let status_message = task_with_details.status_message.map(|msg| msg.into());
-
-        Ok(Some(Task {
-            artifacts: vec![],
-            context_id: task_with_details.task.context_id.to_string(),
-            history: task_with_details
-                .messages
-                .iter()
-                .map(|msg| msg.clone().into())
-                .collect(),
-            id: task_with_details.task.id.to_string(),
-            kind: "task".to_string(),
-            metadata: task_with_details.task.metadata.0,
-            status: TaskStatus {
-                message: status_message,
-                state: match task_with_details.task.status {
-                    task_logic::TaskStatus::Submitted => TaskState::Submitted,
-                    task_logic::TaskStatus::Working => TaskState::Working,
-                    task_logic::TaskStatus::Completed => TaskState::Completed,
-                    task_logic::TaskStatus::Failed => TaskState::Failed,
-                    task_logic::TaskStatus::Canceled => TaskState::Canceled,
-                    task_logic::TaskStatus::InputRequired => TaskState::InputRequired,
-                    task_logic::TaskStatus::Rejected => TaskState::Rejected,
-                    task_logic::TaskStatus::AuthRequired => TaskState::AuthRequired,
-                    task_logic::TaskStatus::Unknown => TaskState::Unknown,
-                },
-                timestamp: Some(task_with_details.task.status_timestamp.to_string()),
-            },
-        }))
+        Ok(task.map(a2a_task_from_details))
     }
 
     async fn delete(&self, _id: &TaskId) -> Result<(), A2aServerError> {
@@ -170,21 +331,148 @@ impl TaskStore for RepositoryTaskStore {
     }
 }
 
+/// The idempotency key of a task, which is the id of the client message that started it. A
+/// client retrying `message/send` resends the same message id, so the retry maps to the same key
+pub fn task_idempotency_key(task: &Task) -> Option<String> {
+    task.history
+        .first()
+        .map(|message| message.message_id.clone())
+}
+
+/// Converts a stored task into its A2A representation
+pub fn a2a_task_from_details(task_with_details: task_logic::TaskWithDetails) -> Task {
+    // Convert status_message to a2a_rs::types::Message if present
+    let status_message = task_with_details.status_message.map(|msg| msg.into());
+
+    Task {
+        artifacts: vec![],
+        context_id: task_with_details.task.context_id.to_string(),
+        history: task_with_details
+            .messages
+            .iter()
+            .map(|msg| msg.clone().into())
+            .collect(),
+        id: task_with_details.task.id.to_string(),
+        kind: "task".to_string(),
+        metadata: task_with_details.task.metadata.0,
+        status: TaskStatus {
+            message: status_message,
+            state: match task_with_details.task.status {
+                task_logic::TaskStatus::Submitted => TaskState::Submitted,
+                task_logic::TaskStatus::Working => TaskState::Working,
+                task_logic::TaskStatus::Completed => TaskState::Completed,
+                task_logic::TaskStatus::Failed => TaskState::Failed,
+                task_logic::TaskStatus::Canceled => TaskState::Canceled,
+                task_logic::TaskStatus::InputRequired => TaskState::InputRequired,
+                task_logic::TaskStatus::Rejected => TaskState::Rejected,
+                task_logic::TaskStatus::AuthRequired => TaskState::AuthRequired,
+                task_logic::TaskStatus::Unknown => TaskState::Unknown,
+            },
+            timestamp: Some(task_with_details.task.status_timestamp.to_string()),
+        },
+    }
+}
+
 use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
 
-use a2a_rs::types::AgentCard;
+use a2a_rs::types::{AgentCard, AgentSkill};
+use dashmap::DashMap;
 use shared::soma_agent_definition::SomaAgentDefinition;
+use tokio::time::Instant;
+
+/// Transports an agent card may advertise as `preferred_transport`
+pub const AGENT_CARD_TRANSPORTS: [&str; 3] = ["JSONRPC", "GRPC", "HTTP+JSON"];
 
 pub struct ConstructAgentCardParams {
     pub definition: SomaAgentDefinition,
     pub url: String,
+    pub skills: Vec<AgentSkill>,
+    pub preferred_transport: String,
+}
+
+/// A single reason an agent card cannot be served
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AgentCardProblem {
+    MissingUrl,
+    NoSkills,
+    InvalidPreferredTransport(String),
 }
 
-pub fn construct_agent_card(params: ConstructAgentCardParams) -> a2a_rs::types::AgentCard {
+impl std::fmt::Display for AgentCardProblem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AgentCardProblem::MissingUrl => write!(f, "url is empty"),
+            AgentCardProblem::NoSkills => write!(f, "no skills are defined"),
+            AgentCardProblem::InvalidPreferredTransport(transport) => write!(
+                f,
+                "preferred transport {transport:?} is not one of {}",
+                AGENT_CARD_TRANSPORTS.join(", ")
+            ),
+        }
+    }
+}
+
+/// Every problem found while validating [`ConstructAgentCardParams`]
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("invalid agent card: {}", .problems.iter().map(ToString::to_string).collect::<Vec<_>>().join("; "))]
+pub struct AgentCardError {
+    pub problems: Vec<AgentCardProblem>,
+}
+
+impl From<AgentCardError> for CommonError {
+    fn from(error: AgentCardError) -> Self {
+        CommonError::Unknown(anyhow::Error::new(error))
+    }
+}
+
+impl ConstructAgentCardParams {
+    /// Checks the invariants A2A clients rely on, reporting all violations at once
+    pub fn validate(&self) -> Result<(), AgentCardError> {
+        let mut problems = Vec::new();
+        if self.url.trim().is_empty() {
+            problems.push(AgentCardProblem::MissingUrl);
+        }
+        if self.skills.is_empty() {
+            problems.push(AgentCardProblem::NoSkills);
+        }
+        if !AGENT_CARD_TRANSPORTS.contains(&self.preferred_transport.as_str()) {
+            problems.push(AgentCardProblem::InvalidPreferredTransport(
+                self.preferred_transport.clone(),
+            ));
+        }
+
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(AgentCardError { problems })
+        }
+    }
+}
+
+/// The skill advertised for a registered agent
+pub fn agent_skill(agent: &AgentMetadata) -> AgentSkill {
+    AgentSkill {
+        description: agent.description.clone(),
+        examples: vec![],
+        id: agent.id.clone(),
+        input_modes: vec![],
+        name: agent.name.clone(),
+        output_modes: vec![],
+        security: vec![],
+        tags: vec![],
+    }
+}
+
+pub fn construct_agent_card(
+    params: ConstructAgentCardParams,
+) -> Result<a2a_rs::types::AgentCard, AgentCardError> {
+    params.validate()?;
     let _definition = params.definition;
     let url = params.url;
 
-    AgentCard {
+    Ok(AgentCard {
         additional_interfaces: vec![],
         capabilities: a2a_rs::types::AgentCapabilities {
             streaming: Some(true),
@@ -198,15 +486,520 @@ pub fn construct_agent_card(params: ConstructAgentCardParams) -> a2a_rs::types::
         documentation_url: None,
         icon_url: None,
         name: String::new(),
-        preferred_transport: "JSONRPC".to_string(),
+        preferred_transport: params.preferred_transport,
         protocol_version: "1.0.0".to_string(),
         provider: None,
         security: vec![],
         security_schemes: HashMap::new(),
         signatures: vec![],
-        skills: vec![],
+        skills: params.skills,
         supports_authenticated_extended_card: None,
         url: url.to_string(),
         version: "1.0.0".to_string(),
+    })
+}
+
+/// Default time a constructed agent card is served from cache before it is rebuilt
+pub const DEFAULT_AGENT_CARD_TTL: Duration = Duration::from_secs(60);
+
+struct CachedAgentCard {
+    card: AgentCard,
+    /// Agent metadata the card was built against, used to detect agent updates
+    agent: Option<AgentMetadata>,
+    built_at: Instant,
+}
+
+/// Cache of constructed A2A agent cards keyed by (project_id, agent_id).
+/// An entry is stale once it is older than the TTL, or as soon as the agent's entry in the
+/// [`AgentCache`] differs from the one the card was built against (e.g. after an SDK sync).
+#[derive(Clone)]
+pub struct AgentCardCache {
+    ttl: Duration,
+    entries: Arc<DashMap<(String, String), CachedAgentCard>>,
+}
+
+impl AgentCardCache {
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            entries: Arc::new(DashMap::new()),
+        }
+    }
+
+    pub fn ttl(&self) -> Duration {
+        self.ttl
+    }
+
+    /// Returns the cached card if it is still within the TTL and the agent has not changed
+    pub fn get(
+        &self,
+        agent_cache: &AgentCache,
+        project_id: &str,
+        agent_id: &str,
+    ) -> Option<AgentCard> {
+        let key = (project_id.to_string(), agent_id.to_string());
+        let current_agent = get_agent(agent_cache, project_id, agent_id);
+        let entry = self.entries.get(&key)?;
+        if entry.built_at.elapsed() < self.ttl && entry.agent == current_agent {
+            return Some(entry.card.clone());
+        }
+        drop(entry);
+
+        trace!(project_id, agent_id, "Agent card cache entry is stale");
+        self.entries.remove(&key);
+        None
+    }
+
+    /// Stores a freshly built card along with the agent metadata it was built against
+    pub fn insert(
+        &self,
+        agent_cache: &AgentCache,
+        project_id: &str,
+        agent_id: &str,
+        card: AgentCard,
+    ) {
+        self.entries.insert(
+            (project_id.to_string(), agent_id.to_string()),
+            CachedAgentCard {
+                card,
+                agent: get_agent(agent_cache, project_id, agent_id),
+                built_at: Instant::now(),
+            },
+        );
+    }
+
+    /// Forces the card for a single agent to be rebuilt on the next request
+    pub fn invalidate(&self, project_id: &str, agent_id: &str) {
+        self.entries
+            .remove(&(project_id.to_string(), agent_id.to_string()));
+    }
+
+    /// Forces every card to be rebuilt on the next request
+    pub fn clear(&self) {
+        self.entries.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod unit {
+        use super::super::*;
+        use crate::sdk::sdk_agent_sync::{create_agent_cache, sync_agents_from_metadata};
+        use a2a_rs::agent_execution::{
+            agent_executor::{AgentExecutor, BoxedFuture},
+            context::RequestContext,
+        };
+        use a2a_rs::events::event_queue::{Event, EventQueue};
+        use a2a_rs::request_handlers::{
+            default_request_handler::DefaultRequestHandler, request_handler::RequestHandler,
+        };
+        use a2a_rs::types::{TaskIdParams, TaskQueryParams};
+
+        fn sync_agent(cache: &AgentCache, description: &str) {
+            sync_agents_from_metadata(
+                cache,
+                &sdk_proto::MetadataResponse {
+                    agents: vec![sdk_proto::Agent {
+                        id: "agent".to_string(),
+                        project_id: "project".to_string(),
+                        name: "Agent".to_string(),
+                        description: description.to_string(),
+                        allowed_functions: vec![],
+                    }],
+                    ..Default::default()
+                },
+            );
+        }
+
+        fn card_params(url: &str) -> ConstructAgentCardParams {
+            ConstructAgentCardParams {
+                definition: SomaAgentDefinition::default(),
+                url: url.to_string(),
+                skills: vec![agent_skill(&AgentMetadata {
+                    id: "agent".to_string(),
+                    project_id: "project".to_string(),
+                    name: "Agent".to_string(),
+                    description: "An agent".to_string(),
+                    allowed_functions: vec![],
+                })],
+                preferred_transport: "JSONRPC".to_string(),
+            }
+        }
+
+        fn card(url: &str) -> AgentCard {
+            construct_agent_card(card_params(url)).unwrap()
+        }
+
+        #[test]
+        fn test_construct_agent_card_accepts_valid_params() {
+            let card = card("http://a");
+            assert_eq!(card.url, "http://a");
+            assert_eq!(card.preferred_transport, "JSONRPC");
+            assert_eq!(card.skills.len(), 1);
+            assert_eq!(card.skills[0].id, "agent");
+        }
+
+        #[test]
+        fn test_construct_agent_card_rejects_missing_url() {
+            let error = construct_agent_card(card_params("")).unwrap_err();
+            assert_eq!(error.problems, vec![AgentCardProblem::MissingUrl]);
+        }
+
+        #[test]
+        fn test_construct_agent_card_rejects_missing_skills() {
+            let mut params = card_params("http://a");
+            params.skills.clear();
+            let error = construct_agent_card(params).unwrap_err();
+            assert_eq!(error.problems, vec![AgentCardProblem::NoSkills]);
+        }
+
+        #[test]
+        fn test_construct_agent_card_rejects_invalid_transport() {
+            let mut params = card_params("http://a");
+            params.preferred_transport = "SOAP".to_string();
+            let error = construct_agent_card(params).unwrap_err();
+            assert_eq!(
+                error.problems,
+                vec![AgentCardProblem::InvalidPreferredTransport(
+                    "SOAP".to_string()
+                )]
+            );
+        }
+
+        #[test]
+        fn test_construct_agent_card_lists_every_problem() {
+            let mut params = card_params(" ");
+            params.skills.clear();
+            params.preferred_transport = String::new();
+            let error = construct_agent_card(params).unwrap_err();
+            assert_eq!(error.problems.len(), 3);
+        }
+
+        #[tokio::test(start_paused = true)]
+        async fn test_agent_card_served_from_cache_within_ttl() {
+            shared::setup_test!();
+
+            let agent_cache = create_agent_cache();
+            sync_agent(&agent_cache, "first");
+            let cards = AgentCardCache::new(Duration::from_secs(60));
+            assert!(cards.get(&agent_cache, "project", "agent").is_none());
+
+            cards.insert(&agent_cache, "project", "agent", card("http://a"));
+            tokio::time::advance(Duration::from_secs(59)).await;
+            let cached = cards.get(&agent_cache, "project", "agent").unwrap();
+            assert_eq!(cached.url, "http://a");
+        }
+
+        #[tokio::test(start_paused = true)]
+        async fn test_agent_card_rebuilt_after_ttl_expiry() {
+            shared::setup_test!();
+
+            let agent_cache = create_agent_cache();
+            sync_agent(&agent_cache, "first");
+            let cards = AgentCardCache::new(Duration::from_secs(60));
+            cards.insert(&agent_cache, "project", "agent", card("http://a"));
+
+            tokio::time::advance(Duration::from_secs(61)).await;
+            assert!(cards.get(&agent_cache, "project", "agent").is_none());
+        }
+
+        #[tokio::test(start_paused = true)]
+        async fn test_agent_card_rebuilt_after_agent_update() {
+            shared::setup_test!();
+
+            let agent_cache = create_agent_cache();
+            sync_agent(&agent_cache, "first");
+            let cards = AgentCardCache::new(Duration::from_secs(60));
+            cards.insert(&agent_cache, "project", "agent", card("http://a"));
+
+            sync_agent(&agent_cache, "second");
+            assert!(cards.get(&agent_cache, "project", "agent").is_none());
+
+            cards.insert(&agent_cache, "project", "agent", card("http://b"));
+            cards.invalidate("project", "agent");
+            assert!(cards.get(&agent_cache, "project", "agent").is_none());
+        }
+
+        #[test]
+        fn test_list_agents_walks_pages_in_order() {
+            shared::setup_test!();
+
+            let agent_cache = create_agent_cache();
+            let agents = (0..5)
+                .map(|i| sdk_proto::Agent {
+                    id: format!("agent-{i}"),
+                    project_id: format!("project-{}", i % 2),
+                    name: format!("Agent {i}"),
+                    description: String::new(),
+                    allowed_functions: vec![],
+                })
+                .collect();
+            sync_agents_from_metadata(
+                &agent_cache,
+                &sdk_proto::MetadataResponse {
+                    agents,
+                    ..Default::default()
+                },
+            );
+
+            let ids = |page: &ListAgentsResponse| {
+                page.items
+                    .iter()
+                    .map(|item| format!("{}/{}", item.project_id, item.agent_id))
+                    .collect::<Vec<_>>()
+            };
+
+            let first = list_agents(
+                &agent_cache,
+                &PaginationRequest {
+                    page_size: 3,
+                    next_page_token: None,
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+            assert_eq!(
+                ids(&first),
+                vec![
+                    "project-0/agent-0",
+                    "project-0/agent-2",
+                    "project-0/agent-4"
+                ]
+            );
+            assert!(first.next_page_token.is_some());
+
+            let second = list_agents(
+                &agent_cache,
+                &PaginationRequest {
+                    page_size: 3,
+                    next_page_token: first.next_page_token,
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+            assert_eq!(ids(&second), vec!["project-1/agent-1", "project-1/agent-3"]);
+            assert!(second.next_page_token.is_none());
+        }
+
+        #[test]
+        fn test_list_agents_pages_ids_containing_separator() {
+            shared::setup_test!();
+
+            let agent_cache = create_agent_cache();
+            let agents = [("a__b", "c"), ("a", "b__c"), ("a", "b__d"), ("a__b", "a")]
+                .into_iter()
+                .map(|(project_id, agent_id)| sdk_proto::Agent {
+                    id: agent_id.to_string(),
+                    project_id: project_id.to_string(),
+                    name: agent_id.to_string(),
+                    description: String::new(),
+                    allowed_functions: vec![],
+                })
+                .collect();
+            sync_agents_from_metadata(
+                &agent_cache,
+                &sdk_proto::MetadataResponse {
+                    agents,
+                    ..Default::default()
+                },
+            );
+
+            let mut ids = Vec::new();
+            let mut next_page_token = None;
+            loop {
+                let page = list_agents(
+                    &agent_cache,
+                    &PaginationRequest {
+                        page_size: 1,
+                        next_page_token,
+                        ..Default::default()
+                    },
+                )
+                .unwrap();
+                ids.extend(
+                    page.items
+                        .into_iter()
+                        .map(|item| (item.project_id, item.agent_id)),
+                );
+                next_page_token = page.next_page_token;
+                if next_page_token.is_none() {
+                    break;
+                }
+            }
+
+            let expected = [("a", "b__c"), ("a", "b__d"), ("a__b", "a"), ("a__b", "c")]
+                .map(|(project_id, agent_id)| (project_id.to_string(), agent_id.to_string()));
+            assert_eq!(ids, expected);
+        }
+
+        #[test]
+        fn test_list_agents_rejects_malformed_token() {
+            shared::setup_test!();
+
+            let list = |token: String| {
+                list_agents(
+                    &create_agent_cache(),
+                    &PaginationRequest {
+                        page_size: 3,
+                        next_page_token: Some(token),
+                        ..Default::default()
+                    },
+                )
+            };
+            assert!(matches!(
+                list("not base64!".to_string()),
+                Err(CommonError::InvalidRequest { .. })
+            ));
+            // A `__`-joined token from before cursors were JSON arrays
+            let legacy = base64::engine::general_purpose::STANDARD.encode("project__agent");
+            assert!(matches!(
+                list(legacy),
+                Err(CommonError::InvalidRequest { .. })
+            ));
+        }
+
+        /// Executor whose cancel publishes the same canceled task the agent router does
+        struct UserCancelingExecutor;
+
+        impl AgentExecutor for UserCancelingExecutor {
+            fn execute<'a>(
+                &'a self,
+                _context: RequestContext,
+                _queue: EventQueue,
+            ) -> BoxedFuture<'a> {
+                Box::pin(async { Ok(()) })
+            }
+
+            fn cancel<'a>(&'a self, context: RequestContext, queue: EventQueue) -> BoxedFuture<'a> {
+                Box::pin(async move {
+                    let task = user_canceled_task(
+                        context.task_id().unwrap().to_string(),
+                        context.context_id().unwrap().to_string(),
+                    );
+                    queue.enqueue_event(Event::Task(task)).await?;
+                    Ok(())
+                })
+            }
+        }
+
+        #[tokio::test]
+        async fn test_canceled_task_reason_survives_round_trip() {
+            shared::setup_test!();
+
+            let (_db, conn) = shared::test_utils::repository::setup_in_memory_database(vec![
+                Repository::load_sql_migrations(),
+                mcp::repository::Repository::load_sql_migrations(),
+            ])
+            .await
+            .unwrap();
+            let task_store = RepositoryTaskStore::new(Repository::new(conn));
+            let task_id = WrappedUuidV4::new().to_string();
+            task_store
+                .save(&Task {
+                    id: task_id.clone(),
+                    context_id: WrappedUuidV4::new().to_string(),
+                    status: TaskStatus {
+                        state: TaskState::Working,
+                        message: None,
+                        timestamp: Some(WrappedChronoDateTime::now().to_string()),
+                    },
+                    artifacts: vec![],
+                    history: vec![],
+                    metadata: Default::default(),
+                    kind: "task".to_string(),
+                })
+                .await
+                .unwrap();
+
+            let handler = DefaultRequestHandler::new(
+                Arc::new(UserCancelingExecutor),
+                Arc::new(task_store),
+                None,
+                None,
+                None,
+                None,
+            );
+            handler
+                .on_cancel_task(TaskIdParams {
+                    id: task_id.clone(),
+                    metadata: Default::default(),
+                })
+                .await
+                .unwrap();
+
+            let task = handler
+                .on_get_task(TaskQueryParams {
+                    id: task_id,
+                    history_length: None,
+                    metadata: Default::default(),
+                })
+                .await
+                .unwrap()
+                .unwrap();
+            assert_eq!(task.status.state, TaskState::Canceled);
+            assert_eq!(
+                task.status.reason_code(),
+                Some(TaskReasonCode::UserCanceled)
+            );
+        }
+
+        /// Answers every request with `status`, or never answers when `status` is `None`
+        async fn serve_status(status: Option<u16>) -> String {
+            use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+            let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+            let url = format!("http://{}/", listener.local_addr().unwrap());
+            tokio::spawn(async move {
+                loop {
+                    let (mut socket, _) = listener.accept().await.unwrap();
+                    tokio::spawn(async move {
+                        let mut buf = [0u8; 4096];
+                        let _ = socket.read(&mut buf).await;
+                        match status {
+                            Some(status) => {
+                                let response = format!(
+                                    "HTTP/1.1 {status} Error\r\ncontent-length: 0\r\nconnection: close\r\n\r\n"
+                                );
+                                let _ = socket.write_all(response.as_bytes()).await;
+                            }
+                            None => std::future::pending::<()>().await,
+                        }
+                    });
+                }
+            });
+            url
+        }
+
+        async fn entrypoint_error(status: Option<u16>) -> anyhow::Error {
+            let url = serve_status(status).await;
+            let client = reqwest::Client::builder()
+                .timeout(Duration::from_millis(100))
+                .build()
+                .unwrap();
+            let error = match client.post(&url).send().await {
+                Ok(response) => response.error_for_status().unwrap_err(),
+                Err(error) => error,
+            };
+            anyhow::Error::new(error).context("Failed to invoke agent entrypoint")
+        }
+
+        #[tokio::test]
+        async fn test_entrypoint_timeouts_are_deadline_exceeded() {
+            shared::setup_test!();
+
+            for status in [None, Some(408), Some(504)] {
+                let error = entrypoint_error(status).await;
+                assert_eq!(
+                    entrypoint_failure_reason(&error),
+                    TaskReasonCode::DeadlineExceeded,
+                    "status {status:?}"
+                );
+            }
+            let error = entrypoint_error(Some(500)).await;
+            assert_eq!(
+                entrypoint_failure_reason(&error),
+                TaskReasonCode::AgentError
+            );
+        }
     }
 }
@@ -1,7 +1,10 @@
 use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
 
 use encryption::logic::crypto_services::CryptoCache;
 use mcp::repository::ProviderRepositoryLike;
+use rand::Rng;
 use sdk_proto::soma_sdk_service_client::SomaSdkServiceClient;
 use serde::{Deserialize, Serialize};
 use shared::error::CommonError;
@@ -21,19 +24,22 @@ pub struct CheckSdkHealthResponse {}
 /// Checks SDK server health via gRPC
 pub async fn check_sdk_health(
     sdk_client: &Arc<Mutex<Option<SomaSdkServiceClient<Channel>>>>,
+    sdk_backoff: &SdkBackoffConfig,
 ) -> Result<CheckSdkHealthResponse, CommonError> {
-    let mut sdk_client_guard = sdk_client.lock().await;
-    let client = match sdk_client_guard.as_mut() {
-        Some(client) => client,
-        None => {
-            return Err(CommonError::Unknown(anyhow::anyhow!(
-                "SDK client not available. Please ensure the SDK server is running."
-            )));
-        }
-    };
+    if sdk_client.lock().await.is_none() {
+        return Err(CommonError::Unknown(anyhow::anyhow!(
+            "SDK client not available. Please ensure the SDK server is running."
+        )));
+    }
 
-    let request = Request::new(());
-    match client.health_check(request).await {
+    match call_sdk_with_reconnect(
+        sdk_client,
+        DEFAULT_SOMA_SERVER_SOCK,
+        sdk_backoff,
+        |mut client| async move { client.health_check(Request::new(())).await },
+    )
+    .await
+    {
         Ok(_) => {
             trace!("SDK server health check passed");
             Ok(CheckSdkHealthResponse {})
@@ -47,27 +53,279 @@ pub async fn check_sdk_health(
     }
 }
 
+/// A subsystem the api server needs before it can serve traffic
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ReadinessCheck {
+    /// The gRPC client to the SDK server is connected
+    SdkClient,
+    /// Agents have been synced from the SDK
+    AgentCache,
+    /// The crypto cache has loaded every DEK
+    CryptoCache,
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct ReadinessResponse {
+    pub ready: bool,
+    /// Subsystems that are not ready yet, empty when `ready` is true
+    pub not_ready: Vec<ReadinessCheck>,
+}
+
+/// Reports which subsystems are not ready yet. Unlike [`check_sdk_health`] this only inspects
+/// local state and never calls the SDK server, nor waits on it: an SDK client that is locked
+/// (e.g. by an in-flight resync) counts as not ready.
+pub fn check_readiness(
+    sdk_client: &Arc<Mutex<Option<SomaSdkServiceClient<Channel>>>>,
+    agents_synced: &AtomicBool,
+    crypto_cache: &CryptoCache,
+) -> ReadinessResponse {
+    let mut not_ready = Vec::new();
+    let sdk_client_ready = sdk_client
+        .try_lock()
+        .is_ok_and(|sdk_client| sdk_client.is_some());
+    if !sdk_client_ready {
+        not_ready.push(ReadinessCheck::SdkClient);
+    }
+    // A project without agents is ready once the (empty) agent list has been synced
+    if !agents_synced.load(Ordering::Acquire) {
+        not_ready.push(ReadinessCheck::AgentCache);
+    }
+    if !crypto_cache.is_warm() {
+        not_ready.push(ReadinessCheck::CryptoCache);
+    }
+
+    ReadinessResponse {
+        ready: not_ready.is_empty(),
+        not_ready,
+    }
+}
+
+/// Backoff parameters used while waiting for the SDK server to become reachable.
+/// Local dev usually wants to fail fast, while production can afford to wait longer.
+#[derive(Debug, Clone)]
+pub struct SdkBackoffConfig {
+    /// Delay before the second attempt (default: 100ms)
+    pub initial_delay: Duration,
+    /// Upper bound for any single delay (default: 2s)
+    pub max_delay: Duration,
+    /// Factor applied to the delay after each failed attempt (default: 2.0)
+    pub multiplier: f64,
+    /// Random jitter as a fraction of each delay, between 0.0 and 1.0 (default: 0.1)
+    pub jitter: f64,
+    /// Total number of attempts before giving up (default: 6)
+    pub max_attempts: u32,
+}
+
+impl Default for SdkBackoffConfig {
+    fn default() -> Self {
+        Self {
+            initial_delay: Duration::from_millis(100),
+            max_delay: Duration::from_secs(2),
+            multiplier: 2.0,
+            jitter: 0.1,
+            max_attempts: 6,
+        }
+    }
+}
+
+impl SdkBackoffConfig {
+    /// Delay to wait after the given (zero-based) failed attempt, before jitter is applied
+    pub fn base_delay(&self, attempt: u32) -> Duration {
+        let factor = self
+            .multiplier
+            .max(1.0)
+            .powi(attempt.min(i32::MAX as u32) as i32);
+        self.initial_delay
+            .mul_f64(factor.min(u32::MAX as f64))
+            .min(self.max_delay)
+    }
+
+    fn delay_with_jitter(&self, attempt: u32) -> Duration {
+        let base = self.base_delay(attempt);
+        let jitter = self.jitter.clamp(0.0, 1.0);
+        if jitter == 0.0 {
+            return base;
+        }
+        let offset = rand::thread_rng().gen_range(-jitter..=jitter);
+        base.mul_f64(1.0 + offset).min(self.max_delay)
+    }
+}
+
+/// Polls `probe` until it succeeds, backing off between attempts according to `backoff`.
+/// Returns an "SDK not ready" error carrying the last failure if it never succeeds.
+async fn wait_for_sdk_ready<T, F, Fut>(
+    mut probe: F,
+    backoff: &SdkBackoffConfig,
+) -> Result<T, CommonError>
+where
+    F: FnMut() -> Fut,
+    Fut: std::future::Future<Output = Result<T, CommonError>>,
+{
+    let max_attempts = backoff.max_attempts.max(1);
+    let mut last_error = None;
+
+    for attempt in 0..max_attempts {
+        match probe().await {
+            Ok(value) => {
+                trace!(attempt = attempt + 1, "SDK server ready");
+                return Ok(value);
+            }
+            Err(e) => {
+                trace!(attempt = attempt + 1, error = ?e, "SDK server not ready");
+                last_error = Some(e);
+            }
+        }
+
+        if attempt < max_attempts - 1 {
+            tokio::time::sleep(backoff.delay_with_jitter(attempt)).await;
+        }
+    }
+
+    let reason = last_error
+        .map(|e| format!("{e:?}"))
+        .unwrap_or_else(|| "no health checks were attempted".to_string());
+    Err(CommonError::Unknown(anyhow::anyhow!(
+        "SDK not ready: health check did not pass after {max_attempts} attempts ({reason})"
+    )))
+}
+
+/// Whether a failed SDK call failed because the channel to the SDK server is broken (e.g. the
+/// SDK process restarted), as opposed to the SDK server rejecting the call
+fn is_transport_error(status: &tonic::Status) -> bool {
+    if status.code() == tonic::Code::Unavailable {
+        return true;
+    }
+
+    let mut source = std::error::Error::source(status);
+    while let Some(err) = source {
+        if err.is::<tonic::transport::Error>()
+            || err.is::<hyper::Error>()
+            || err.is::<std::io::Error>()
+        {
+            return true;
+        }
+        source = err.source();
+    }
+    false
+}
+
+/// Runs `call` with the stored SDK client. If the channel turns out to be broken, re-dials
+/// `socket_path` backing off according to `backoff`, stores the new client and retries the call
+/// once before failing.
+pub async fn call_sdk_with_reconnect<T, F, Fut>(
+    sdk_client: &Arc<Mutex<Option<SomaSdkServiceClient<Channel>>>>,
+    socket_path: &str,
+    backoff: &SdkBackoffConfig,
+    mut call: F,
+) -> Result<T, tonic::Status>
+where
+    F: FnMut(SomaSdkServiceClient<Channel>) -> Fut,
+    Fut: std::future::Future<Output = Result<T, tonic::Status>>,
+{
+    // Clients share the underlying channel, so calls don't need to hold the lock
+    let client = sdk_client.lock().await.clone().ok_or_else(|| {
+        tonic::Status::unavailable(
+            "SDK client not available. Please ensure the SDK server is running.",
+        )
+    })?;
+
+    let status = match call(client).await {
+        Err(status) if is_transport_error(&status) => status,
+        result => return result,
+    };
+    debug!(error = ?status, "SDK channel broken, reconnecting");
+
+    // Dial without holding the lock, so other callers aren't blocked while this one backs off
+    let new_client = wait_for_sdk_ready(|| create_soma_unix_socket_client(socket_path), backoff)
+        .await
+        .map_err(|e| {
+            warn!(error = ?e, "Failed to reconnect to SDK server");
+            tonic::Status::unavailable(format!("Failed to reconnect to SDK server: {e}"))
+        })?;
+    trace!("Reconnected to SDK server");
+    *sdk_client.lock().await = Some(new_client.clone());
+
+    call(new_client).await
+}
+
+/// Re-dials `socket_path` and stores the new client, keeping the stored one if the SDK server
+/// can't be reached. Returns a clone of whichever client is stored. The lock is only held to
+/// swap the client, never across the dial.
+async fn reconnect_sdk_client(
+    sdk_client: &Arc<Mutex<Option<SomaSdkServiceClient<Channel>>>>,
+    socket_path: &str,
+) -> Result<SomaSdkServiceClient<Channel>, CommonError> {
+    let new_client = create_soma_unix_socket_client(socket_path).await;
+
+    let mut sdk_client_guard = sdk_client.lock().await;
+    match new_client {
+        Ok(new_client) => {
+            trace!("Reconnected to SDK server");
+            *sdk_client_guard = Some(new_client);
+        }
+        Err(e) => {
+            debug!(error = ?e, "Failed to reconnect to SDK server, using existing client");
+        }
+    }
+
+    sdk_client_guard.clone().ok_or_else(|| {
+        CommonError::Unknown(anyhow::anyhow!(
+            "SDK client not available. Please ensure the SDK server is running."
+        ))
+    })
+}
+
+/// Reconnects to the SDK server (it may have restarted) and waits until it passes a health
+/// check. Each attempt takes the client lock only to swap in the new client, so the lock is
+/// free while backing off.
+async fn connect_ready_sdk_client(
+    sdk_client: &Arc<Mutex<Option<SomaSdkServiceClient<Channel>>>>,
+    socket_path: &str,
+    backoff: &SdkBackoffConfig,
+) -> Result<SomaSdkServiceClient<Channel>, CommonError> {
+    wait_for_sdk_ready(
+        || async move {
+            let mut client = reconnect_sdk_client(sdk_client, socket_path).await?;
+            client.health_check(Request::new(())).await.map_err(|e| {
+                CommonError::Unknown(anyhow::anyhow!("SDK server health check failed: {e}"))
+            })?;
+            Ok(client)
+        },
+        backoff,
+    )
+    .await
+}
+
 #[derive(Debug, Deserialize, Serialize, ToSchema)]
 pub struct TriggerCodegenResponse {}
 
+/// Returns a client to the SDK server once it passes a health check, reconnecting first if the
+/// stored channel is broken
+pub async fn healthy_sdk_client(
+    sdk_client: &Arc<Mutex<Option<SomaSdkServiceClient<Channel>>>>,
+    sdk_backoff: &SdkBackoffConfig,
+) -> Result<SomaSdkServiceClient<Channel>, CommonError> {
+    call_sdk_with_reconnect(
+        sdk_client,
+        DEFAULT_SOMA_SERVER_SOCK,
+        sdk_backoff,
+        |mut client| async move { client.health_check(Request::new(())).await.map(|_| client) },
+    )
+    .await
+    .map_err(|e| CommonError::Unknown(anyhow::anyhow!("SDK server health check failed: {e}")))
+}
+
 /// Triggers mcp client generation via gRPC call to SDK server
 pub async fn trigger_codegen(
     sdk_client: &Arc<Mutex<Option<SomaSdkServiceClient<Channel>>>>,
     mcp_repo: &impl ProviderRepositoryLike,
     agent_cache: &sdk_agent_sync::AgentCache,
+    sdk_backoff: &SdkBackoffConfig,
 ) -> Result<TriggerCodegenResponse, CommonError> {
-    let mut sdk_client_guard = sdk_client.lock().await;
-
-    let client = match sdk_client_guard.as_mut() {
-        Some(client) => client,
-        None => {
-            return Err(CommonError::Unknown(anyhow::anyhow!(
-                "SDK client not available. Please ensure the SDK server is running."
-            )));
-        }
-    };
+    let mut client = healthy_sdk_client(sdk_client, sdk_backoff).await?;
 
-    crate::logic::mcp::codegen::trigger_mcp_client_generation(client, mcp_repo, agent_cache)
+    crate::logic::mcp::codegen::trigger_mcp_client_generation(&mut client, mcp_repo, agent_cache)
         .await?;
 
     Ok(TriggerCodegenResponse {})
@@ -86,36 +344,23 @@ pub struct ResyncSdkResponse {}
 
 /// Resync SDK: fetches metadata from SDK, syncs providers/agents to mcp registry,
 /// registers Restate deployments, syncs secrets/env vars to SDK, and triggers codegen
+#[allow(clippy::too_many_arguments)]
 pub async fn resync_sdk(
     environment_repo: &std::sync::Arc<environment::repository::Repository>,
     crypto_cache: &CryptoCache,
     restate_params: &crate::restate::RestateServerParams,
     sdk_client: &Arc<Mutex<Option<SomaSdkServiceClient<Channel>>>>,
+    sdk_socket_path: &str,
     agent_cache: &sdk_agent_sync::AgentCache,
+    agent_allowlists: &mcp::logic::AgentAllowlists,
+    agents_synced: &AtomicBool,
     mcp_repo: &impl mcp::repository::ProviderRepositoryLike,
+    sdk_backoff: &SdkBackoffConfig,
 ) -> Result<ResyncSdkResponse, CommonError> {
-    let mut sdk_client_guard = sdk_client.lock().await;
-
-    // Try to reconnect to SDK server (it may have restarted)
+    // Make sure the SDK is serving before issuing any RPCs, it may still be starting up.
+    // Clients share the underlying channel, so the rest of the resync doesn't hold the lock.
     trace!("Reconnecting to SDK server");
-    match create_soma_unix_socket_client(DEFAULT_SOMA_SERVER_SOCK).await {
-        Ok(new_client) => {
-            trace!("Reconnected to SDK server");
-            *sdk_client_guard = Some(new_client);
-        }
-        Err(e) => {
-            debug!(error = ?e, "Failed to reconnect to SDK server, using existing client");
-        }
-    }
-
-    let client = match sdk_client_guard.as_mut() {
-        Some(client) => client,
-        None => {
-            return Err(CommonError::Unknown(anyhow::anyhow!(
-                "SDK client not available. Please ensure the SDK server is running."
-            )));
-        }
-    };
+    let mut client = connect_ready_sdk_client(sdk_client, sdk_socket_path, sdk_backoff).await?;
 
     let request = Request::new(());
     let response = client
@@ -139,6 +384,8 @@ pub async fn resync_sdk(
 
     // Sync agents to cache (this clears and repopulates)
     sdk_agent_sync::sync_agents_from_metadata(agent_cache, &metadata);
+    sdk_agent_sync::sync_agent_allowlists_from_metadata(agent_allowlists, &metadata);
+    agents_synced.store(true, Ordering::Release);
 
     let providers_synced = metadata.mcp_providers.len();
     let agents_synced = metadata.agents.len();
@@ -169,7 +416,15 @@ pub async fn resync_sdk(
     let secrets_count = secrets.len();
     if !secrets.is_empty() {
         trace!(count = secrets_count, "Syncing secrets to SDK");
-        sync_secrets_to_sdk(client, secrets).await?;
+        let report = sync_secrets_to_sdk(&mut client, secrets).await?;
+        if !report.is_complete() {
+            warn!(
+                applied = report.applied.len(),
+                skipped = report.skipped.len(),
+                failed = report.failed.len(),
+                "Secrets were only partially applied by the SDK"
+            );
+        }
     }
 
     // Sync variables to SDK
@@ -177,7 +432,15 @@ pub async fn resync_sdk(
     let vars_count = vars.len();
     if !vars.is_empty() {
         trace!(count = vars_count, "Syncing variables to SDK");
-        sync_variables_to_sdk(client, vars).await?;
+        let report = sync_variables_to_sdk(&mut client, vars).await?;
+        if !report.is_complete() {
+            warn!(
+                applied = report.applied.len(),
+                skipped = report.skipped.len(),
+                failed = report.failed.len(),
+                "Variables were only partially applied by the SDK"
+            );
+        }
     }
 
     debug!(
@@ -190,9 +453,12 @@ pub async fn resync_sdk(
 
     // Trigger mcp client generation (includes agents now that they're synced)
     trace!("Triggering mcp client generation");
-    if let Err(e) =
-        crate::logic::mcp::codegen::trigger_mcp_client_generation(client, mcp_repo, agent_cache)
-            .await
+    if let Err(e) = crate::logic::mcp::codegen::trigger_mcp_client_generation(
+        &mut client,
+        mcp_repo,
+        agent_cache,
+    )
+    .await
     {
         warn!(error = ?e, "Failed to trigger mcp client generation");
     }
@@ -309,3 +575,397 @@ pub struct RuntimeConfigResponse {}
 pub async fn runtime_config() -> Result<RuntimeConfigResponse, CommonError> {
     Ok(RuntimeConfigResponse {})
 }
+
+#[cfg(test)]
+mod tests {
+    mod unit {
+        use super::super::*;
+        use std::sync::Mutex as StdMutex;
+        use std::sync::atomic::{AtomicU32, Ordering};
+
+        fn fast_backoff(max_attempts: u32) -> SdkBackoffConfig {
+            SdkBackoffConfig {
+                initial_delay: Duration::from_millis(1),
+                max_delay: Duration::from_millis(5),
+                multiplier: 2.0,
+                jitter: 0.0,
+                max_attempts,
+            }
+        }
+
+        #[tokio::test]
+        async fn test_wait_for_sdk_ready_waits_until_healthy() {
+            shared::setup_test!();
+
+            let attempts = AtomicU32::new(0);
+            let result = wait_for_sdk_ready(
+                || {
+                    let attempt = attempts.fetch_add(1, Ordering::SeqCst);
+                    async move {
+                        if attempt < 2 {
+                            Err(CommonError::Unknown(anyhow::anyhow!("socket not ready")))
+                        } else {
+                            Ok(())
+                        }
+                    }
+                },
+                &fast_backoff(5),
+            )
+            .await;
+
+            assert!(result.is_ok());
+            assert_eq!(attempts.load(Ordering::SeqCst), 3);
+        }
+
+        #[tokio::test]
+        async fn test_wait_for_sdk_ready_returns_clear_error_when_never_healthy() {
+            shared::setup_test!();
+
+            let attempts = AtomicU32::new(0);
+            let result: Result<(), CommonError> = wait_for_sdk_ready(
+                || {
+                    attempts.fetch_add(1, Ordering::SeqCst);
+                    async { Err(CommonError::Unknown(anyhow::anyhow!("socket not ready"))) }
+                },
+                &fast_backoff(3),
+            )
+            .await;
+
+            assert_eq!(attempts.load(Ordering::SeqCst), 3);
+            let err = format!("{:?}", result.unwrap_err());
+            assert!(err.contains("SDK not ready"));
+        }
+
+        #[tokio::test(start_paused = true)]
+        async fn test_wait_for_sdk_ready_honors_custom_backoff() {
+            shared::setup_test!();
+
+            let backoff = SdkBackoffConfig {
+                initial_delay: Duration::from_millis(100),
+                max_delay: Duration::from_millis(500),
+                multiplier: 3.0,
+                jitter: 0.0,
+                max_attempts: 5,
+            };
+
+            let probe_times = StdMutex::new(Vec::new());
+            let result = wait_for_sdk_ready(
+                || {
+                    let mut times = probe_times.lock().unwrap();
+                    times.push(tokio::time::Instant::now());
+                    let healthy = times.len() > 3;
+                    async move {
+                        if healthy {
+                            Ok(())
+                        } else {
+                            Err(CommonError::Unknown(anyhow::anyhow!("socket not ready")))
+                        }
+                    }
+                },
+                &backoff,
+            )
+            .await;
+            assert!(result.is_ok());
+
+            let times = probe_times.into_inner().unwrap();
+            let delays: Vec<Duration> = times.windows(2).map(|w| w[1] - w[0]).collect();
+            assert_eq!(
+                delays,
+                vec![
+                    Duration::from_millis(100),
+                    Duration::from_millis(300),
+                    Duration::from_millis(500),
+                ]
+            );
+        }
+
+        #[tokio::test]
+        async fn test_readiness_follows_sdk_client() {
+            shared::setup_test!();
+
+            let (_db, conn) = shared::test_utils::repository::setup_in_memory_database(vec![
+                <encryption::repository::Repository as shared::primitives::SqlMigrationLoader>::load_sql_migrations(),
+            ])
+            .await
+            .unwrap();
+            let crypto_cache = CryptoCache::new(
+                encryption::repository::Repository::new(conn),
+                std::env::temp_dir(),
+            );
+            let agents_synced = AtomicBool::new(false);
+            let sdk_client = Arc::new(Mutex::new(None));
+
+            let response = check_readiness(&sdk_client, &agents_synced, &crypto_cache);
+            assert!(!response.ready);
+            assert_eq!(
+                response.not_ready,
+                vec![
+                    ReadinessCheck::SdkClient,
+                    ReadinessCheck::AgentCache,
+                    ReadinessCheck::CryptoCache
+                ]
+            );
+
+            encryption::logic::crypto_services::init_crypto_cache(&crypto_cache)
+                .await
+                .unwrap();
+            // A sync that returned no agents still counts, the agent cache stays empty
+            agents_synced.store(true, Ordering::Release);
+            let response = check_readiness(&sdk_client, &agents_synced, &crypto_cache);
+            assert_eq!(response.not_ready, vec![ReadinessCheck::SdkClient]);
+
+            // A lazy channel never dials, so no SDK server is needed
+            let channel = tonic::transport::Endpoint::from_static("http://[::1]:1").connect_lazy();
+            *sdk_client.lock().await = Some(SomaSdkServiceClient::new(channel));
+            let response = check_readiness(&sdk_client, &agents_synced, &crypto_cache);
+            assert!(response.ready);
+            assert!(response.not_ready.is_empty());
+
+            // A held client lock (e.g. a resync in progress) is not ready rather than blocking
+            let guard = sdk_client.lock().await;
+            let response = check_readiness(&sdk_client, &agents_synced, &crypto_cache);
+            assert_eq!(response.not_ready, vec![ReadinessCheck::SdkClient]);
+            drop(guard);
+
+            // Dropping the client (e.g. the SDK server restarting) makes it not ready again
+            *sdk_client.lock().await = None;
+            let response = check_readiness(&sdk_client, &agents_synced, &crypto_cache);
+            assert!(!response.ready);
+            assert_eq!(response.not_ready, vec![ReadinessCheck::SdkClient]);
+        }
+
+        /// SDK server that only answers health checks and reports no providers or agents
+        struct HealthOnlySdkServer;
+
+        #[tonic::async_trait]
+        impl sdk_proto::soma_sdk_service_server::SomaSdkService for HealthOnlySdkServer {
+            async fn metadata(
+                &self,
+                _request: Request<()>,
+            ) -> Result<tonic::Response<sdk_proto::MetadataResponse>, tonic::Status> {
+                Ok(tonic::Response::new(sdk_proto::MetadataResponse::default()))
+            }
+
+            async fn health_check(
+                &self,
+                _request: Request<()>,
+            ) -> Result<tonic::Response<()>, tonic::Status> {
+                Ok(tonic::Response::new(()))
+            }
+
+            async fn invoke_function(
+                &self,
+                _request: Request<sdk_proto::InvokeFunctionRequest>,
+            ) -> Result<tonic::Response<sdk_proto::InvokeFunctionResponse>, tonic::Status>
+            {
+                Err(tonic::Status::unimplemented("invoke_function"))
+            }
+
+            type InvokeFunctionStreamStream = tokio_stream::wrappers::ReceiverStream<
+                Result<sdk_proto::InvokeFunctionChunk, tonic::Status>,
+            >;
+
+            async fn invoke_function_stream(
+                &self,
+                _request: Request<sdk_proto::InvokeFunctionRequest>,
+            ) -> Result<tonic::Response<Self::InvokeFunctionStreamStream>, tonic::Status>
+            {
+                Err(tonic::Status::unimplemented("invoke_function_stream"))
+            }
+
+            async fn generate_mcp_client(
+                &self,
+                _request: Request<sdk_proto::GenerateMcpClientRequest>,
+            ) -> Result<tonic::Response<sdk_proto::GenerateMcpClientResponse>, tonic::Status>
+            {
+                Err(tonic::Status::unimplemented("generate_mcp_client"))
+            }
+
+            async fn set_secrets(
+                &self,
+                _request: Request<sdk_proto::SetSecretsRequest>,
+            ) -> Result<tonic::Response<sdk_proto::SetSecretsResponse>, tonic::Status> {
+                Err(tonic::Status::unimplemented("set_secrets"))
+            }
+
+            async fn unset_secrets(
+                &self,
+                _request: Request<sdk_proto::UnsetSecretRequest>,
+            ) -> Result<tonic::Response<sdk_proto::UnsetSecretResponse>, tonic::Status>
+            {
+                Err(tonic::Status::unimplemented("unset_secrets"))
+            }
+
+            async fn set_environment_variables(
+                &self,
+                _request: Request<sdk_proto::SetEnvironmentVariablesRequest>,
+            ) -> Result<tonic::Response<sdk_proto::SetEnvironmentVariablesResponse>, tonic::Status>
+            {
+                Err(tonic::Status::unimplemented("set_environment_variables"))
+            }
+
+            async fn unset_environment_variables(
+                &self,
+                _request: Request<sdk_proto::UnsetEnvironmentVariableRequest>,
+            ) -> Result<tonic::Response<sdk_proto::UnsetEnvironmentVariableResponse>, tonic::Status>
+            {
+                Err(tonic::Status::unimplemented("unset_environment_variables"))
+            }
+        }
+
+        /// Serves [`HealthOnlySdkServer`] on `socket_path` until `shutdown` fires
+        fn start_sdk_server(
+            socket_path: &std::path::Path,
+            shutdown: tokio::sync::oneshot::Receiver<()>,
+        ) -> tokio::task::JoinHandle<()> {
+            let listener = tokio::net::UnixListener::bind(socket_path).unwrap();
+            let incoming = tokio_stream::wrappers::UnixListenerStream::new(listener);
+            tokio::spawn(async move {
+                tonic::transport::Server::builder()
+                    .add_service(
+                        sdk_proto::soma_sdk_service_server::SomaSdkServiceServer::new(
+                            HealthOnlySdkServer,
+                        )
+                        .accept_compressed(tonic::codec::CompressionEncoding::Gzip),
+                    )
+                    .serve_with_incoming_shutdown(incoming, async {
+                        let _ = shutdown.await;
+                    })
+                    .await
+                    .unwrap();
+            })
+        }
+
+        #[tokio::test]
+        async fn test_sdk_call_reconnects_after_sdk_server_restart() {
+            shared::setup_test!();
+
+            let dir = tempfile::tempdir().unwrap();
+            let socket_path = dir.path().join("sdk.sock");
+            let socket = socket_path.to_str().unwrap().to_string();
+
+            let (shutdown_tx, shutdown_rx) = tokio::sync::oneshot::channel();
+            let server = start_sdk_server(&socket_path, shutdown_rx);
+            let sdk_client = Arc::new(Mutex::new(Some(
+                create_soma_unix_socket_client(&socket).await.unwrap(),
+            )));
+            let backoff = fast_backoff(50);
+            let health_check = |mut client: SomaSdkServiceClient<Channel>| async move {
+                client.health_check(Request::new(())).await
+            };
+
+            call_sdk_with_reconnect(&sdk_client, &socket, &backoff, health_check)
+                .await
+                .unwrap();
+
+            // The SDK process goes away, taking its socket with it
+            shutdown_tx.send(()).unwrap();
+            server.await.unwrap();
+            std::fs::remove_file(&socket_path).unwrap();
+
+            let mut stale_client = sdk_client.lock().await.clone().unwrap();
+            let status = stale_client
+                .health_check(Request::new(()))
+                .await
+                .unwrap_err();
+            assert!(is_transport_error(&status), "{status:?}");
+
+            // ...and comes back on the same socket while the call is backing off
+            let (_restarted_shutdown_tx, restarted_shutdown_rx) = tokio::sync::oneshot::channel();
+            let restart = {
+                let socket_path = socket_path.clone();
+                tokio::spawn(async move {
+                    tokio::time::sleep(Duration::from_millis(20)).await;
+                    start_sdk_server(&socket_path, restarted_shutdown_rx)
+                })
+            };
+
+            call_sdk_with_reconnect(&sdk_client, &socket, &backoff, health_check)
+                .await
+                .unwrap();
+            restart.await.unwrap();
+
+            // The reconnected client replaced the stale one
+            let mut stored_client = sdk_client.lock().await.clone().unwrap();
+            assert!(stored_client.health_check(Request::new(())).await.is_ok());
+        }
+
+        #[tokio::test]
+        async fn test_resync_sdk_reconnects_without_holding_client_lock() {
+            shared::setup_test!();
+
+            let (_db, conn) = shared::test_utils::repository::setup_in_memory_database(vec![
+                <encryption::repository::Repository as shared::primitives::SqlMigrationLoader>::load_sql_migrations(),
+                <environment::repository::Repository as shared::primitives::SqlMigrationLoader>::load_sql_migrations(),
+                <mcp::repository::Repository as shared::primitives::SqlMigrationLoader>::load_sql_migrations(),
+            ])
+            .await
+            .unwrap();
+            let crypto_cache = CryptoCache::new(
+                encryption::repository::Repository::new(conn.clone()),
+                std::env::temp_dir(),
+            );
+            let environment_repo = Arc::new(environment::repository::Repository::new(conn.clone()));
+            let mcp_repo = mcp::repository::Repository::new(conn);
+
+            let dir = tempfile::tempdir().unwrap();
+            let socket_path = dir.path().join("sdk.sock");
+            let socket = socket_path.to_str().unwrap().to_string();
+            let restate_params = crate::restate::RestateServerParams::Local(
+                crate::restate::RestateServerLocalParams {
+                    restate_server_data_dir: dir.path().to_path_buf(),
+                    ingress_port: 0,
+                    admin_port: 0,
+                    soma_restate_service_port: 0,
+                    soma_restate_service_additional_headers: Default::default(),
+                    clean: false,
+                },
+            );
+            let agent_cache = sdk_agent_sync::AgentCache::default();
+            let agent_allowlists = mcp::logic::AgentAllowlists::new();
+            let agents_synced = AtomicBool::new(false);
+            let sdk_client = Arc::new(Mutex::new(None));
+
+            // The SDK server only comes up while the resync is backing off
+            let (_shutdown_tx, shutdown_rx) = tokio::sync::oneshot::channel();
+            let start = {
+                let socket_path = socket_path.clone();
+                let sdk_client = sdk_client.clone();
+                tokio::spawn(async move {
+                    tokio::time::sleep(Duration::from_millis(20)).await;
+                    // ...and the client lock is free in the meantime
+                    assert!(sdk_client.try_lock().is_ok());
+                    start_sdk_server(&socket_path, shutdown_rx)
+                })
+            };
+
+            resync_sdk(
+                &environment_repo,
+                &crypto_cache,
+                &restate_params,
+                &sdk_client,
+                &socket,
+                &agent_cache,
+                &agent_allowlists,
+                &agents_synced,
+                &mcp_repo,
+                &fast_backoff(50),
+            )
+            .await
+            .unwrap();
+            start.await.unwrap();
+
+            assert!(agents_synced.load(Ordering::Acquire));
+            let mut stored_client = sdk_client.lock().await.clone().unwrap();
+            assert!(stored_client.health_check(Request::new(())).await.is_ok());
+        }
+
+        #[test]
+        fn test_sdk_backoff_config_default_delays_are_capped() {
+            let backoff = SdkBackoffConfig::default();
+            assert_eq!(backoff.base_delay(0), Duration::from_millis(100));
+            assert_eq!(backoff.base_delay(1), Duration::from_millis(200));
+            assert_eq!(backoff.base_delay(10), Duration::from_secs(2));
+        }
+    }
+}
@@ -0,0 +1,128 @@
+use sdk_proto::KeyOutcomeStatus;
+use shared::error::CommonError;
+use tracing::warn;
+
+/// Per-key result of syncing a batch of secrets or variables to the SDK
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct KeySyncReport {
+    pub applied: Vec<String>,
+    /// (key, reason) pairs the SDK chose not to apply
+    pub skipped: Vec<(String, String)>,
+    /// (key, reason) pairs the SDK failed to apply
+    pub failed: Vec<(String, String)>,
+}
+
+impl KeySyncReport {
+    pub fn from_outcomes(outcomes: Vec<sdk_proto::KeyOutcome>) -> Self {
+        let mut report = Self::default();
+        for outcome in outcomes {
+            match outcome.status() {
+                KeyOutcomeStatus::Applied => report.applied.push(outcome.key),
+                KeyOutcomeStatus::Skipped => report.skipped.push((outcome.key, outcome.reason)),
+                KeyOutcomeStatus::Failed => report.failed.push((outcome.key, outcome.reason)),
+                // The SDK always sets a status, so a missing one means it could not report it
+                KeyOutcomeStatus::Unspecified => report
+                    .failed
+                    .push((outcome.key, "SDK reported no outcome".to_string())),
+            }
+        }
+        report
+    }
+
+    /// Folds the outcomes of another batch into this report
+    pub fn merge(&mut self, other: KeySyncReport) {
+        self.applied.extend(other.applied);
+        self.skipped.extend(other.skipped);
+        self.failed.extend(other.failed);
+    }
+
+    /// True when every key in the batch was applied
+    pub fn is_complete(&self) -> bool {
+        self.skipped.is_empty() && self.failed.is_empty()
+    }
+
+    /// Logs every key that was not applied; `kind` names what was synced (e.g. "secret")
+    pub fn log_unapplied(&self, kind: &str) {
+        for (key, reason) in &self.skipped {
+            warn!(kind, key = %key, reason = %reason, "SDK skipped key");
+        }
+        for (key, reason) in &self.failed {
+            warn!(kind, key = %key, reason = %reason, "SDK failed to apply key");
+        }
+    }
+
+    /// Turns a failed key into an error, for incremental syncs of a single key
+    pub fn into_single_key_result(self, kind: &str) -> Result<(), CommonError> {
+        self.log_unapplied(kind);
+        match self.failed.into_iter().next() {
+            Some((key, reason)) => Err(CommonError::Unknown(anyhow::anyhow!(
+                "SDK failed to apply {kind} {key}: {reason}"
+            ))),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod unit {
+        use super::super::*;
+
+        fn outcome(key: &str, status: KeyOutcomeStatus, reason: &str) -> sdk_proto::KeyOutcome {
+            sdk_proto::KeyOutcome {
+                key: key.to_string(),
+                status: status as i32,
+                reason: reason.to_string(),
+            }
+        }
+
+        #[test]
+        fn test_report_groups_mixed_outcomes_by_status() {
+            shared::setup_test!();
+
+            let report = KeySyncReport::from_outcomes(vec![
+                outcome("A", KeyOutcomeStatus::Applied, ""),
+                outcome("B", KeyOutcomeStatus::Skipped, "unchanged"),
+                outcome("C", KeyOutcomeStatus::Failed, "invalid value"),
+                outcome("D", KeyOutcomeStatus::Applied, ""),
+            ]);
+
+            assert_eq!(report.applied, vec!["A".to_string(), "D".to_string()]);
+            assert_eq!(
+                report.skipped,
+                vec![("B".to_string(), "unchanged".to_string())]
+            );
+            assert_eq!(
+                report.failed,
+                vec![("C".to_string(), "invalid value".to_string())]
+            );
+            assert!(!report.is_complete());
+            assert!(report.into_single_key_result("secret").is_err());
+        }
+
+        #[test]
+        fn test_unspecified_outcome_is_reported_as_failed() {
+            shared::setup_test!();
+
+            let report =
+                KeySyncReport::from_outcomes(vec![outcome("A", KeyOutcomeStatus::Unspecified, "")]);
+            assert_eq!(
+                report.failed,
+                vec![("A".to_string(), "SDK reported no outcome".to_string())]
+            );
+            assert!(report.into_single_key_result("secret").is_err());
+        }
+
+        #[test]
+        fn test_skipped_single_key_is_not_an_error() {
+            shared::setup_test!();
+
+            let report = KeySyncReport::from_outcomes(vec![outcome(
+                "A",
+                KeyOutcomeStatus::Skipped,
+                "unchanged",
+            )]);
+            assert!(report.into_single_key_result("variable").is_ok());
+        }
+    }
+}
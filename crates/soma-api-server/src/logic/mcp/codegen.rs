@@ -33,6 +33,7 @@ pub async fn trigger_mcp_client_generation(
             project_id: agent.project_id,
             name: agent.name,
             description: agent.description,
+            allowed_functions: agent.allowed_functions,
         })
         .collect();
 
@@ -94,6 +95,20 @@ fn convert_to_proto_function_instance(
                 .unwrap_or_default(),
             return_value_json_schema: serde_json::to_string(output_schema.get_inner().as_value())
                 .unwrap_or_default(),
+            examples: func
+                .function_controller
+                .examples()
+                .into_iter()
+                .map(|example| sdk_proto::FunctionExample {
+                    description: example.description.unwrap_or_default(),
+                    parameters: serde_json::to_string(example.parameters.get_inner())
+                        .unwrap_or_default(),
+                    output: example
+                        .output
+                        .and_then(|output| serde_json::to_string(output.get_inner()).ok())
+                        .unwrap_or_default(),
+                })
+                .collect(),
         }),
     }
 }
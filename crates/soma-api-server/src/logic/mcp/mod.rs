@@ -6,6 +6,8 @@ use tokio::sync::Mutex;
 use tonic::transport::Channel;
 use tracing::{debug, info, warn};
 
+use crate::logic::internal::SdkBackoffConfig;
+
 pub mod codegen;
 pub mod providers;
 
@@ -34,25 +36,16 @@ pub async fn run_mcp_client_generation_loop(
                 if should_trigger {
                     debug!("MCP change event detected, triggering mcp client generation");
 
-                    // Get the SDK client and verify it's ready
-                    let mut client_guard = sdk_client.lock().await;
-                    if let Some(ref mut client) = *client_guard {
-                        // Verify SDK server is ready by checking health
-                        let health_ready = match client.health_check(tonic::Request::new(())).await
-                        {
-                            Ok(_) => true,
-                            Err(e) => {
-                                warn!(
-                                    "SDK server healthcheck failed, skipping mcp client generation: {:?}",
-                                    e
-                                );
-                                false
-                            }
-                        };
-
-                        if health_ready {
+                    // Get the SDK client and verify it's ready, reconnecting if the SDK restarted
+                    match crate::logic::internal::healthy_sdk_client(
+                        &sdk_client,
+                        &SdkBackoffConfig::default(),
+                    )
+                    .await
+                    {
+                        Ok(mut client) => {
                             match crate::logic::mcp::codegen::trigger_mcp_client_generation(
-                                client,
+                                &mut client,
                                 &mcp_repo,
                                 &agent_cache,
                             )
@@ -67,8 +60,12 @@ pub async fn run_mcp_client_generation_loop(
                                 }
                             }
                         }
-                    } else {
-                        warn!("SDK client not available, skipping mcp client generation");
+                        Err(e) => {
+                            warn!(
+                                "SDK server not ready, skipping mcp client generation: {:?}",
+                                e
+                            );
+                        }
                     }
                 }
             }
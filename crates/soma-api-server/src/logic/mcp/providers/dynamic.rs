@@ -3,6 +3,7 @@ use std::sync::Arc;
 use async_trait::async_trait;
 use encryption::logic::crypto_services::DecryptionService;
 use mcp::logic::FunctionControllerLike;
+use mcp::logic::FunctionExample;
 use mcp::logic::InvokeError;
 use mcp::logic::InvokeResult;
 use mcp::logic::Metadata;
@@ -118,6 +119,9 @@ struct DynamicFunctionController {
     parameters: WrappedSchema,
     output: WrappedSchema,
     categories: Vec<String>,
+    deprecated: bool,
+    deprecation_message: Option<String>,
+    examples: Vec<FunctionExample>,
 }
 
 pub struct DynamicFunctionControllerParams {
@@ -128,6 +132,9 @@ pub struct DynamicFunctionControllerParams {
     pub parameters: WrappedSchema,
     pub output: WrappedSchema,
     pub categories: Vec<String>,
+    pub deprecated: bool,
+    pub deprecation_message: Option<String>,
+    pub examples: Vec<FunctionExample>,
 }
 
 impl DynamicFunctionController {
@@ -140,6 +147,55 @@ impl DynamicFunctionController {
             parameters: params.parameters,
             output: params.output,
             categories: params.categories,
+            deprecated: params.deprecated,
+            deprecation_message: params.deprecation_message,
+            examples: params.examples,
+        }
+    }
+
+    /// Re-emits the lines the SDK function logged during an invocation at their own level
+    fn forward_function_logs(&self, logs: &[sdk_proto::LogLine]) {
+        use sdk_proto::LogLevel;
+
+        let provider = self.provider_type_id.as_str();
+        let function = self.type_id.as_str();
+        for line in logs {
+            let timestamp_ms = line.timestamp_ms;
+            let message = line.message.as_str();
+            match line.level() {
+                LogLevel::Debug => {
+                    tracing::debug!(
+                        provider,
+                        function,
+                        timestamp_ms,
+                        "SDK function log: {message}"
+                    )
+                }
+                LogLevel::Warn => {
+                    tracing::warn!(
+                        provider,
+                        function,
+                        timestamp_ms,
+                        "SDK function log: {message}"
+                    )
+                }
+                LogLevel::Error => {
+                    tracing::error!(
+                        provider,
+                        function,
+                        timestamp_ms,
+                        "SDK function log: {message}"
+                    )
+                }
+                LogLevel::Info | LogLevel::Unspecified => {
+                    tracing::info!(
+                        provider,
+                        function,
+                        timestamp_ms,
+                        "SDK function log: {message}"
+                    )
+                }
+            }
         }
     }
 }
@@ -164,6 +220,15 @@ impl FunctionControllerLike for DynamicFunctionController {
     fn categories(&self) -> Vec<String> {
         self.categories.clone()
     }
+    fn deprecated(&self) -> bool {
+        self.deprecated
+    }
+    fn deprecation_message(&self) -> Option<String> {
+        self.deprecation_message.clone()
+    }
+    fn examples(&self) -> Vec<FunctionExample> {
+        self.examples.clone()
+    }
 
     async fn invoke(
         &self,
@@ -232,13 +297,27 @@ impl FunctionControllerLike for DynamicFunctionController {
             )));
         };
 
+        // Correlates this invocation with logs emitted by the user's function. Reuses the id of
+        // the API request that triggered it, and only mints one for invocations outside a request.
+        let request_id = shared::request_id::current_or_new();
+
         tracing::info!(
-            "Invoking SDK function: provider={}, function={}, credential_type={}",
+            "Invoking SDK function: provider={}, function={}, credential_type={}, request_id={}",
             self.provider_type_id,
             self.type_id,
-            credential_controller.type_id()
+            credential_controller.type_id(),
+            request_id
         );
 
+        if self.deprecated {
+            tracing::warn!(
+                "Invoking deprecated SDK function: provider={}, function={}, message={}",
+                self.provider_type_id,
+                self.type_id,
+                self.deprecation_message.as_deref().unwrap_or_default()
+            );
+        }
+
         // Create gRPC client
         let mut client = create_soma_unix_socket_client(DEFAULT_SOMA_SERVER_SOCK)
             .await
@@ -266,6 +345,8 @@ impl FunctionControllerLike for DynamicFunctionController {
             credential_controller_type_id: credential_controller.type_id().to_string(),
             credentials: credentials_json,
             parameters: parameters_json,
+            request_id,
+            bypass_cache: false,
         });
 
         // Call the SDK server
@@ -274,18 +355,29 @@ impl FunctionControllerLike for DynamicFunctionController {
         })?;
 
         let result = response.into_inner();
+        self.forward_function_logs(&result.logs);
 
         // Check the oneof kind field
         match result.kind {
             Some(sdk_proto::invoke_function_response::Kind::Error(error)) => {
+                let code = error.code();
                 tracing::error!(
-                    "SDK function execution error: provider={}, function={}, error={}",
+                    "SDK function execution error: provider={}, function={}, code={}, error={}",
                     self.provider_type_id,
                     self.type_id,
+                    code.as_str_name(),
                     error.message
                 );
+                // Callers can back off and retry rate limited and retryable failures
+                let retry_after_ms = match code {
+                    sdk_proto::CallbackErrorCode::RateLimited
+                    | sdk_proto::CallbackErrorCode::Retryable => error.retry_after_ms,
+                    _ => None,
+                };
                 return Ok(InvokeResult::Error(InvokeError {
                     message: error.message,
+                    code: callback_error_code_name(code).map(str::to_string),
+                    retry_after_ms,
                 }));
             }
             Some(sdk_proto::invoke_function_response::Kind::Data(data_str)) => {
@@ -296,6 +388,18 @@ impl FunctionControllerLike for DynamicFunctionController {
                     data_str.len()
                 );
 
+                // Truncated output is no longer valid JSON, so surface it as a plain string
+                if result.truncated {
+                    tracing::warn!(
+                        "SDK function output was truncated: provider={}, function={}",
+                        self.provider_type_id,
+                        self.type_id
+                    );
+                    return Ok(InvokeResult::Success(WrappedJsonValue::new(
+                        serde_json::Value::String(data_str),
+                    )));
+                }
+
                 let data_value: serde_json::Value = serde_json::from_str(&data_str)
                     .map_err(|e| {
                         tracing::error!(
@@ -329,6 +433,22 @@ impl FunctionControllerLike for DynamicFunctionController {
     }
 }
 
+/// Snake_case name of a callback error code, as the language bindings spell it. `None` for
+/// errors from SDKs that predate error codes.
+fn callback_error_code_name(code: sdk_proto::CallbackErrorCode) -> Option<&'static str> {
+    use sdk_proto::CallbackErrorCode as Code;
+
+    match code {
+        Code::Unspecified => None,
+        Code::RateLimited => Some("rate_limited"),
+        Code::Unauthorized => Some("unauthorized"),
+        Code::InvalidArgument => Some("invalid_argument"),
+        Code::Internal => Some("internal"),
+        Code::Retryable => Some("retryable"),
+        Code::DeadlineExceeded => Some("deadline_exceeded"),
+    }
+}
+
 // pub const DYNAMIC_FN_TYPE_ID: &str = "dynamic";
 
 // pub fn register_dynamic_functions(
@@ -143,6 +143,8 @@ impl FunctionControllerLike for GetTaskTimelineItemsFunctionController {
             ))),
             Err(e) => Ok(InvokeResult::Error(InvokeError {
                 message: e.to_string(),
+                code: None,
+                retry_after_ms: None,
             })),
         }
     }
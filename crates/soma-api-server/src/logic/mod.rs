@@ -1,6 +1,7 @@
 pub mod agent;
 pub mod identity;
 pub mod internal;
+pub mod key_sync;
 pub mod mcp;
 pub mod on_change_pubsub;
 pub mod secret_sync;
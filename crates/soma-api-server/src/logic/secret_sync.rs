@@ -1,3 +1,5 @@
+use std::collections::BTreeMap;
+
 use encryption::logic::crypto_services::{CryptoCache, EncryptedString};
 use environment::repository::SecretRepositoryLike;
 use shared::error::CommonError;
@@ -5,6 +7,7 @@ use shared::primitives::PaginationRequest;
 use tokio::sync::broadcast;
 use tracing::{debug, error, trace, warn};
 
+use crate::logic::key_sync::KeySyncReport;
 use crate::logic::on_change_pubsub::SecretChangeRx;
 
 /// A decrypted secret ready to be sent to the SDK
@@ -27,6 +30,7 @@ pub async fn fetch_and_decrypt_all_secrets(
         let pagination = PaginationRequest {
             page_size: 100,
             next_page_token: page_token.clone(),
+            ..Default::default()
         };
 
         let page = repository.as_ref().get_secrets(&pagination).await?;
@@ -81,35 +85,56 @@ pub async fn fetch_and_decrypt_all_secrets(
     Ok(all_secrets)
 }
 
-/// Sync secrets to the SDK via gRPC (for initial sync - sends all secrets)
-pub async fn sync_secrets_to_sdk(
+/// Separates a provider type_id namespace from the rest of a secret key: `stripe/API_KEY` is
+/// scoped to the `stripe` provider, so the SDK hands it to that provider's secret handler
+pub const PROVIDER_SCOPE_SEPARATOR: char = '/';
+
+/// Provider type_id a secret key is namespaced under, if any
+pub fn secret_provider_scope(key: &str) -> Option<&str> {
+    key.split_once(PROVIDER_SCOPE_SEPARATOR)
+        .filter(|(scope, rest)| !scope.is_empty() && !rest.is_empty())
+        .map(|(scope, _)| scope)
+}
+
+/// Groups secrets into one batch per provider scope, unscoped secrets under `None`. An empty
+/// input still yields one unscoped batch, so a full sync always reaches the SDK.
+fn group_secrets_by_provider_scope(
+    secrets: Vec<DecryptedSecret>,
+) -> BTreeMap<Option<String>, Vec<sdk_proto::Secret>> {
+    let mut batches: BTreeMap<Option<String>, Vec<sdk_proto::Secret>> = BTreeMap::new();
+    for secret in secrets {
+        let scope = secret_provider_scope(&secret.key).map(str::to_string);
+        batches.entry(scope).or_default().push(sdk_proto::Secret {
+            key: secret.key,
+            value: secret.value,
+        });
+    }
+    if batches.is_empty() {
+        batches.insert(None, vec![]);
+    }
+    batches
+}
+
+/// Sends one `set_secrets` request and returns the SDK's per-key outcomes
+async fn send_secrets(
     sdk_client: &mut sdk_proto::soma_sdk_service_client::SomaSdkServiceClient<
         tonic::transport::Channel,
     >,
-    secrets: Vec<DecryptedSecret>,
-) -> Result<(), CommonError> {
-    let proto_secrets: Vec<sdk_proto::Secret> = secrets
-        .into_iter()
-        .map(|s| sdk_proto::Secret {
-            key: s.key,
-            value: s.value,
-        })
-        .collect();
-
+    secrets: Vec<sdk_proto::Secret>,
+    provider_scope: Option<String>,
+) -> Result<KeySyncReport, CommonError> {
     let request = tonic::Request::new(sdk_proto::SetSecretsRequest {
-        secrets: proto_secrets,
+        secrets,
+        provider_scope,
     });
 
     let response = sdk_client.set_secrets(request).await.map_err(|e| {
         CommonError::Unknown(anyhow::anyhow!("Failed to call set_secrets RPC: {e}"))
     })?;
 
-    let inner = response.into_inner();
-
-    match inner.kind {
-        Some(sdk_proto::set_secrets_response::Kind::Data(_)) => {
-            trace!("Secrets synced to SDK");
-            Ok(())
+    match response.into_inner().kind {
+        Some(sdk_proto::set_secrets_response::Kind::Data(data)) => {
+            Ok(KeySyncReport::from_outcomes(data.outcomes))
         }
         Some(sdk_proto::set_secrets_response::Kind::Error(error)) => Err(CommonError::Unknown(
             anyhow::anyhow!("SDK rejected secrets: {}", error.message),
@@ -120,6 +145,32 @@ pub async fn sync_secrets_to_sdk(
     }
 }
 
+/// Sync secrets to the SDK via gRPC (for initial sync - sends all secrets).
+///
+/// Secrets namespaced under a provider (see [`secret_provider_scope`]) are sent in a separate
+/// request scoped to that provider.
+pub async fn sync_secrets_to_sdk(
+    sdk_client: &mut sdk_proto::soma_sdk_service_client::SomaSdkServiceClient<
+        tonic::transport::Channel,
+    >,
+    secrets: Vec<DecryptedSecret>,
+) -> Result<KeySyncReport, CommonError> {
+    let mut report = KeySyncReport::default();
+    for (provider_scope, batch) in group_secrets_by_provider_scope(secrets) {
+        trace!(?provider_scope, count = batch.len(), "Syncing secret batch");
+        report.merge(send_secrets(sdk_client, batch, provider_scope).await?);
+    }
+
+    report.log_unapplied("secret");
+    trace!(
+        applied = report.applied.len(),
+        skipped = report.skipped.len(),
+        failed = report.failed.len(),
+        "Secrets synced to SDK"
+    );
+    Ok(report)
+}
+
 /// Incrementally sync a single secret to the SDK via gRPC
 pub async fn sync_secret_to_sdk(
     sdk_client: &mut sdk_proto::soma_sdk_service_client::SomaSdkServiceClient<
@@ -128,8 +179,10 @@ pub async fn sync_secret_to_sdk(
     key: String,
     value: String,
 ) -> Result<(), CommonError> {
+    let provider_scope = secret_provider_scope(&key).map(str::to_string);
     let request = tonic::Request::new(sdk_proto::SetSecretsRequest {
         secrets: vec![sdk_proto::Secret { key, value }],
+        provider_scope,
     });
 
     let response = sdk_client.set_secrets(request).await.map_err(|e| {
@@ -139,9 +192,9 @@ pub async fn sync_secret_to_sdk(
     let inner = response.into_inner();
 
     match inner.kind {
-        Some(sdk_proto::set_secrets_response::Kind::Data(_)) => {
+        Some(sdk_proto::set_secrets_response::Kind::Data(data)) => {
             trace!("Secret synced to SDK");
-            Ok(())
+            KeySyncReport::from_outcomes(data.outcomes).into_single_key_result("secret")
         }
         Some(sdk_proto::set_secrets_response::Kind::Error(error)) => Err(CommonError::Unknown(
             anyhow::anyhow!("SDK rejected secret: {}", error.message),
@@ -246,5 +299,72 @@ async fn sync_all_secrets(
 
     // Connect to SDK and sync
     let mut client = shared::uds::create_soma_unix_socket_client(socket_path).await?;
-    sync_secrets_to_sdk(&mut client, secrets).await
+    sync_secrets_to_sdk(&mut client, secrets).await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    mod unit {
+        use super::super::*;
+
+        fn secret(key: &str) -> DecryptedSecret {
+            DecryptedSecret {
+                key: key.to_string(),
+                value: "value".to_string(),
+            }
+        }
+
+        #[test]
+        fn test_secret_provider_scope_reads_namespace_prefix() {
+            shared::setup_test!();
+
+            assert_eq!(secret_provider_scope("stripe/API_KEY"), Some("stripe"));
+            assert_eq!(secret_provider_scope("API_KEY"), None);
+            assert_eq!(secret_provider_scope("/API_KEY"), None);
+            assert_eq!(secret_provider_scope("stripe/"), None);
+        }
+
+        #[test]
+        fn test_secrets_are_batched_by_provider_scope() {
+            shared::setup_test!();
+
+            let batches = group_secrets_by_provider_scope(vec![
+                secret("stripe/API_KEY"),
+                secret("SHARED"),
+                secret("gmail/CLIENT_SECRET"),
+                secret("stripe/WEBHOOK_SECRET"),
+            ]);
+
+            let keys: Vec<(Option<&str>, Vec<&str>)> = batches
+                .iter()
+                .map(|(scope, batch)| {
+                    (
+                        scope.as_deref(),
+                        batch.iter().map(|s| s.key.as_str()).collect(),
+                    )
+                })
+                .collect();
+            assert_eq!(
+                keys,
+                vec![
+                    (None, vec!["SHARED"]),
+                    (Some("gmail"), vec!["gmail/CLIENT_SECRET"]),
+                    (
+                        Some("stripe"),
+                        vec!["stripe/API_KEY", "stripe/WEBHOOK_SECRET"]
+                    ),
+                ]
+            );
+        }
+
+        #[test]
+        fn test_empty_sync_sends_one_unscoped_batch() {
+            shared::setup_test!();
+
+            let batches = group_secrets_by_provider_scope(vec![]);
+            assert_eq!(batches.len(), 1);
+            assert!(batches[&None].is_empty());
+        }
+    }
 }
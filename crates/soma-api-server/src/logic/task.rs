@@ -777,6 +777,7 @@ mod tests {
                 metadata: WrappedJsonValue::new(serde_json::to_value(&metadata).unwrap()),
                 created_at,
                 updated_at,
+                idempotency_key: None,
             };
             repo.create_task(&create_params).await.unwrap();
 
@@ -798,6 +799,7 @@ mod tests {
             let pagination = PaginationRequest {
                 page_size: 10,
                 next_page_token: None,
+                ..Default::default()
             };
 
             let result = list_tasks(&repo, pagination).await.unwrap();
@@ -820,6 +822,7 @@ mod tests {
             let pagination = PaginationRequest {
                 page_size: 10,
                 next_page_token: None,
+                ..Default::default()
             };
 
             let result = list_tasks(&repo, pagination).await.unwrap();
@@ -848,6 +851,7 @@ mod tests {
             let pagination = PaginationRequest {
                 page_size: 2,
                 next_page_token: None,
+                ..Default::default()
             };
             let result = list_tasks(&repo, pagination).await.unwrap();
 
@@ -858,6 +862,7 @@ mod tests {
             let pagination = PaginationRequest {
                 page_size: 2,
                 next_page_token: result.next_page_token,
+                ..Default::default()
             };
             let result = list_tasks(&repo, pagination).await.unwrap();
 
@@ -981,6 +986,7 @@ mod tests {
             let pagination = PaginationRequest {
                 page_size: 10,
                 next_page_token: None,
+                ..Default::default()
             };
             let messages = repo
                 .get_messages_by_task_id(&task.id, &pagination)
@@ -1031,6 +1037,7 @@ mod tests {
                 inner: PaginationRequest {
                     page_size: 10,
                     next_page_token: None,
+                    ..Default::default()
                 },
             };
 
@@ -1069,6 +1076,7 @@ mod tests {
                 inner: PaginationRequest {
                     page_size: 10,
                     next_page_token: None,
+                    ..Default::default()
                 },
             };
 
@@ -1108,6 +1116,7 @@ mod tests {
                 inner: PaginationRequest {
                     page_size: 10,
                     next_page_token: None,
+                    ..Default::default()
                 },
             };
 
@@ -1187,6 +1196,7 @@ mod tests {
                 inner: PaginationRequest {
                     page_size: 10,
                     next_page_token: None,
+                    ..Default::default()
                 },
             };
 
@@ -1242,6 +1252,7 @@ mod tests {
                 inner: PaginationRequest {
                     page_size: 2,
                     next_page_token: None,
+                    ..Default::default()
                 },
             };
 
@@ -1256,6 +1267,7 @@ mod tests {
                 inner: PaginationRequest {
                     page_size: 2,
                     next_page_token: result.next_page_token,
+                    ..Default::default()
                 },
             };
 
@@ -1287,6 +1299,7 @@ mod tests {
                     metadata: WrappedJsonValue::new(serde_json::to_value(&metadata).unwrap()),
                     created_at,
                     updated_at: created_at,
+                    idempotency_key: None,
                 };
                 repo.create_task(&create_params).await.unwrap();
                 tokio::time::sleep(tokio::time::Duration::from_millis(10)).await;
@@ -1306,6 +1319,7 @@ mod tests {
                 metadata: WrappedJsonValue::new(serde_json::to_value(&metadata).unwrap()),
                 created_at,
                 updated_at: created_at,
+                idempotency_key: None,
             };
             repo.create_task(&create_params).await.unwrap();
 
@@ -1313,6 +1327,7 @@ mod tests {
             let pagination = PaginationRequest {
                 page_size: 10,
                 next_page_token: None,
+                ..Default::default()
             };
             let result = list_unique_contexts(&repo, pagination).await.unwrap();
 
@@ -1355,6 +1370,7 @@ mod tests {
                     metadata: WrappedJsonValue::new(serde_json::to_value(&metadata).unwrap()),
                     created_at,
                     updated_at: created_at,
+                    idempotency_key: None,
                 };
                 repo.create_task(&create_params).await.unwrap();
                 tokio::time::sleep(tokio::time::Duration::from_millis(10)).await;
@@ -1375,6 +1391,7 @@ mod tests {
                     metadata: WrappedJsonValue::new(serde_json::to_value(&metadata).unwrap()),
                     created_at,
                     updated_at: created_at,
+                    idempotency_key: None,
                 };
                 repo.create_task(&create_params).await.unwrap();
                 tokio::time::sleep(tokio::time::Duration::from_millis(10)).await;
@@ -1384,6 +1401,7 @@ mod tests {
             let pagination = PaginationRequest {
                 page_size: 10,
                 next_page_token: None,
+                ..Default::default()
             };
             let result = list_tasks_by_context_id(
                 &repo,
@@ -1431,6 +1449,7 @@ mod tests {
                     metadata: WrappedJsonValue::new(serde_json::to_value(&metadata).unwrap()),
                     created_at,
                     updated_at: created_at,
+                    idempotency_key: None,
                 };
                 repo.create_task(&create_params).await.unwrap();
                 tokio::time::sleep(tokio::time::Duration::from_millis(10)).await;
@@ -1440,6 +1459,7 @@ mod tests {
             let pagination = PaginationRequest {
                 page_size: 3,
                 next_page_token: None,
+                ..Default::default()
             };
             let result = list_tasks_by_context_id(
                 &repo,
@@ -1458,6 +1478,7 @@ mod tests {
             let pagination = PaginationRequest {
                 page_size: 3,
                 next_page_token: result.next_page_token,
+                ..Default::default()
             };
             let result = list_tasks_by_context_id(
                 &repo,
@@ -4,6 +4,7 @@ use shared::primitives::PaginationRequest;
 use tokio::sync::broadcast;
 use tracing::{debug, error, trace, warn};
 
+use crate::logic::key_sync::KeySyncReport;
 use crate::logic::on_change_pubsub::VariableChangeRx;
 
 /// A variable ready to be sent to the SDK
@@ -46,6 +47,7 @@ pub async fn fetch_all_variables(
         let pagination = PaginationRequest {
             page_size: 100,
             next_page_token: page_token.clone(),
+            ..Default::default()
         };
 
         let page = repository.as_ref().get_variables(&pagination).await?;
@@ -76,7 +78,7 @@ pub async fn sync_variables_to_sdk(
         tonic::transport::Channel,
     >,
     vars: Vec<VariableData>,
-) -> Result<(), CommonError> {
+) -> Result<KeySyncReport, CommonError> {
     let proto_vars: Vec<sdk_proto::EnvironmentVariable> = vars
         .into_iter()
         .map(|e| sdk_proto::EnvironmentVariable {
@@ -101,9 +103,16 @@ pub async fn sync_variables_to_sdk(
     let inner = response.into_inner();
 
     match inner.kind {
-        Some(sdk_proto::set_environment_variables_response::Kind::Data(_)) => {
-            trace!("Variables synced to SDK");
-            Ok(())
+        Some(sdk_proto::set_environment_variables_response::Kind::Data(data)) => {
+            let report = KeySyncReport::from_outcomes(data.outcomes);
+            report.log_unapplied("variable");
+            trace!(
+                applied = report.applied.len(),
+                skipped = report.skipped.len(),
+                failed = report.failed.len(),
+                "Variables synced to SDK"
+            );
+            Ok(report)
         }
         Some(sdk_proto::set_environment_variables_response::Kind::Error(error)) => Err(
             CommonError::Unknown(anyhow::anyhow!("SDK rejected variables: {}", error.message)),
@@ -141,9 +150,9 @@ pub async fn sync_variable_to_sdk(
     let inner = response.into_inner();
 
     match inner.kind {
-        Some(sdk_proto::set_environment_variables_response::Kind::Data(_)) => {
+        Some(sdk_proto::set_environment_variables_response::Kind::Data(data)) => {
             trace!("Variable synced to SDK");
-            Ok(())
+            KeySyncReport::from_outcomes(data.outcomes).into_single_key_result("variable")
         }
         Some(sdk_proto::set_environment_variables_response::Kind::Error(error)) => Err(
             CommonError::Unknown(anyhow::anyhow!("SDK rejected variable: {}", error.message)),
@@ -253,7 +262,8 @@ async fn sync_all_variables(
 
     // Connect to SDK and sync
     let mut client = shared::uds::create_soma_unix_socket_client(socket_path).await?;
-    sync_variables_to_sdk(&mut client, vars).await
+    sync_variables_to_sdk(&mut client, vars).await?;
+    Ok(())
 }
 
 #[cfg(test)]
@@ -26,6 +26,8 @@ pub struct CreateTask {
     pub metadata: WrappedJsonValue,
     pub created_at: WrappedChronoDateTime,
     pub updated_at: WrappedChronoDateTime,
+    /// Client-supplied key that makes a retried create return the task it already created
+    pub idempotency_key: Option<String>,
 }
 
 impl TryFrom<Task> for CreateTask {
@@ -41,6 +43,7 @@ impl TryFrom<Task> for CreateTask {
             metadata,
             created_at: task.created_at,
             updated_at: task.updated_at,
+            idempotency_key: None,
         })
     }
 }
@@ -120,7 +123,9 @@ impl TryFrom<Message> for CreateMessage {
 // Repository trait
 #[allow(async_fn_in_trait)]
 pub trait TaskRepositoryLike {
-    async fn create_task(&self, params: &CreateTask) -> Result<(), CommonError>;
+    /// Returns the id of the stored task, which is the existing task's id when another task
+    /// already holds `params.idempotency_key`
+    async fn create_task(&self, params: &CreateTask) -> Result<WrappedUuidV4, CommonError>;
     async fn update_task_status(&self, params: &UpdateTaskStatus) -> Result<(), CommonError>;
     async fn insert_task_timeline_item(
         &self,
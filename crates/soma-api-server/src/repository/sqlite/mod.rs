@@ -37,7 +37,7 @@ impl Repository {
 }
 
 impl TaskRepositoryLike for Repository {
-    async fn create_task(&self, params: &CreateTask) -> Result<(), CommonError> {
+    async fn create_task(&self, params: &CreateTask) -> Result<WrappedUuidV4, CommonError> {
         let sqlc_params = insert_task_params {
             id: &params.id,
             context_id: &params.context_id,
@@ -46,16 +46,40 @@ impl TaskRepositoryLike for Repository {
             metadata: &params.metadata,
             created_at: &params.created_at,
             updated_at: &params.updated_at,
+            idempotency_key: &params.idempotency_key,
         };
 
-        insert_task(&self.conn, sqlc_params)
+        let inserted = insert_task(&self.conn, sqlc_params)
             .await
             .context("Failed to create task")
             .map_err(|e| CommonError::Repository {
                 msg: e.to_string(),
                 source: Some(e),
             })?;
-        Ok(())
+        if inserted > 0 || params.idempotency_key.is_none() {
+            return Ok(params.id.clone());
+        }
+
+        // The idempotency key is already taken, so hand back the task that holds it
+        let existing = get_task_id_by_idempotency_key(
+            &self.conn,
+            get_task_id_by_idempotency_key_params {
+                idempotency_key: &params.idempotency_key,
+            },
+        )
+        .await
+        .context("Failed to get task by idempotency key")
+        .map_err(|e| CommonError::Repository {
+            msg: e.to_string(),
+            source: Some(e),
+        })?;
+        match existing {
+            Some(row) => Ok(row.id),
+            None => Err(CommonError::Repository {
+                msg: "Task with idempotency key was not created and could not be found".to_string(),
+                source: None,
+            }),
+        }
     }
 
     async fn update_task_status(&self, params: &UpdateTaskStatus) -> Result<(), CommonError> {
@@ -127,7 +151,7 @@ impl TaskRepositoryLike for Repository {
 
         let sqlc_params = get_tasks_params {
             cursor: &cursor_datetime,
-            page_size: &pagination.page_size,
+            page_size: &pagination.resolved_page_size(),
         };
 
         let rows = get_tasks(&self.conn, sqlc_params)
@@ -176,7 +200,7 @@ impl TaskRepositoryLike for Repository {
 
         let sqlc_params = get_unique_contexts_params {
             cursor: &cursor_datetime,
-            page_size: &pagination.page_size,
+            page_size: &pagination.resolved_page_size(),
         };
 
         let rows = get_unique_contexts(&self.conn, sqlc_params)
@@ -232,7 +256,7 @@ impl TaskRepositoryLike for Repository {
         let sqlc_params = get_tasks_by_context_id_params {
             context_id,
             cursor: &cursor_datetime,
-            page_size: &pagination.page_size,
+            page_size: &pagination.resolved_page_size(),
         };
 
         let rows = get_tasks_by_context_id(&self.conn, sqlc_params)
@@ -283,7 +307,7 @@ impl TaskRepositoryLike for Repository {
         let sqlc_params = get_task_timeline_items_params {
             task_id: &task_id.to_string(),
             cursor: &cursor_datetime,
-            page_size: &pagination.page_size,
+            page_size: &pagination.resolved_page_size(),
         };
 
         let rows = get_task_timeline_items(&self.conn, sqlc_params)
@@ -376,7 +400,7 @@ impl TaskRepositoryLike for Repository {
         let sqlc_params = get_messages_by_task_id_params {
             task_id,
             cursor: &cursor_datetime,
-            page_size: &pagination.page_size,
+            page_size: &pagination.resolved_page_size(),
         };
 
         let rows = get_messages_by_task_id(&self.conn, sqlc_params)
@@ -418,9 +442,12 @@ mod tests {
             CreateMessage, CreateTask, CreateTaskTimelineItem, TaskRepositoryLike,
         };
         use base64::Engine;
+        use identity::repository::{
+            Repository as IdentityRepository, Role, User, UserRepositoryLike, UserType,
+        };
         use shared::primitives::{
-            PaginationRequest, SqlMigrationLoader, WrappedChronoDateTime, WrappedJsonValue,
-            WrappedUuidV4,
+            PaginationDefaults, PaginationOrder, PaginationRequest, SqlMigrationLoader,
+            WrappedChronoDateTime, WrappedJsonValue, WrappedUuidV4, set_pagination_defaults,
         };
         use shared::test_utils::repository::setup_in_memory_database;
 
@@ -447,6 +474,7 @@ mod tests {
                 metadata: WrappedJsonValue::new(serde_json::to_value(&metadata).unwrap()),
                 created_at,
                 updated_at,
+                idempotency_key: None,
             };
             repo.create_task(&create_params).await.unwrap();
 
@@ -461,6 +489,54 @@ mod tests {
             assert_eq!(task_with_details.task.updated_at, updated_at);
         }
 
+        #[tokio::test]
+        async fn test_create_task_with_same_idempotency_key_returns_existing_task() {
+            let (_db, conn) = setup_in_memory_database(vec![Repository::load_sql_migrations()])
+                .await
+                .unwrap();
+            let repo = Repository::new(conn);
+
+            let context_id = WrappedUuidV4::new();
+            let create_params = |id: WrappedUuidV4| {
+                let now = WrappedChronoDateTime::now();
+                CreateTask {
+                    id,
+                    context_id: context_id.clone(),
+                    status: TaskStatus::Submitted,
+                    status_timestamp: now,
+                    metadata: WrappedJsonValue::new(serde_json::to_value(Metadata::new()).unwrap()),
+                    created_at: now,
+                    updated_at: now,
+                    idempotency_key: Some("message-1".to_string()),
+                }
+            };
+
+            let first_id = WrappedUuidV4::new();
+            let stored_id = repo
+                .create_task(&create_params(first_id.clone()))
+                .await
+                .unwrap();
+            assert_eq!(stored_id, first_id);
+
+            // A retry with a new task id but the same key gets the first task back
+            let retry_id = WrappedUuidV4::new();
+            let stored_id = repo
+                .create_task(&create_params(retry_id.clone()))
+                .await
+                .unwrap();
+            assert_eq!(stored_id, first_id);
+            assert!(repo.get_task_by_id(&retry_id).await.unwrap().is_none());
+
+            let pagination = PaginationRequest {
+                page_size: 10,
+                next_page_token: None,
+                ..Default::default()
+            };
+            let response = repo.get_tasks(&pagination).await.unwrap();
+            assert_eq!(response.items.len(), 1);
+            assert_eq!(response.items[0].id, first_id);
+        }
+
         #[tokio::test]
         async fn test_update_task_status() {
             let (_db, conn) = setup_in_memory_database(vec![Repository::load_sql_migrations()])
@@ -484,6 +560,7 @@ mod tests {
                 metadata: WrappedJsonValue::new(serde_json::to_value(&metadata).unwrap()),
                 created_at,
                 updated_at,
+                idempotency_key: None,
             };
             repo.create_task(&create_params).await.unwrap();
 
@@ -545,6 +622,7 @@ mod tests {
                 metadata: WrappedJsonValue::new(serde_json::to_value(&metadata).unwrap()),
                 created_at,
                 updated_at,
+                idempotency_key: None,
             };
             repo.create_task(&create_params).await.unwrap();
 
@@ -585,6 +663,7 @@ mod tests {
             let pagination = PaginationRequest {
                 page_size: 10,
                 next_page_token: None,
+                ..Default::default()
             };
             let response = repo
                 .get_task_timeline_items(&task_id, &pagination)
@@ -632,6 +711,7 @@ mod tests {
                     metadata: WrappedJsonValue::new(serde_json::to_value(&metadata).unwrap()),
                     created_at,
                     updated_at,
+                    idempotency_key: None,
                 };
                 repo.create_task(&create_params).await.unwrap();
             }
@@ -640,6 +720,7 @@ mod tests {
             let pagination = PaginationRequest {
                 page_size: 10,
                 next_page_token: None,
+                ..Default::default()
             };
             let response = repo.get_tasks(&pagination).await.unwrap();
 
@@ -651,6 +732,7 @@ mod tests {
             let pagination = PaginationRequest {
                 page_size: 3,
                 next_page_token: None,
+                ..Default::default()
             };
             let response = repo.get_tasks(&pagination).await.unwrap();
             assert_eq!(response.items.len(), 3);
@@ -660,11 +742,89 @@ mod tests {
             let pagination = PaginationRequest {
                 page_size: 3,
                 next_page_token: response.next_page_token,
+                ..Default::default()
             };
             let response = repo.get_tasks(&pagination).await.unwrap();
             assert!(response.items.len() >= 2 && response.items.len() <= 3);
         }
 
+        #[tokio::test]
+        async fn test_unset_page_size_uses_configured_default_across_repositories() {
+            let configured = PaginationDefaults {
+                page_size: 3,
+                ..Default::default()
+            };
+            set_pagination_defaults(configured);
+
+            let (_db, conn) = setup_in_memory_database(vec![Repository::load_sql_migrations()])
+                .await
+                .unwrap();
+            let task_repo = Repository::new(conn);
+            let (_identity_db, identity_conn) =
+                setup_in_memory_database(vec![IdentityRepository::load_sql_migrations()])
+                    .await
+                    .unwrap();
+            let identity_repo = IdentityRepository::new(identity_conn);
+
+            let context_id = WrappedUuidV4::new();
+            for i in 0..=configured.page_size {
+                let now = WrappedChronoDateTime::now();
+                let create_params = CreateTask {
+                    id: WrappedUuidV4::new(),
+                    context_id: context_id.clone(),
+                    status: TaskStatus::Submitted,
+                    status_timestamp: now,
+                    metadata: WrappedJsonValue::new(serde_json::to_value(Metadata::new()).unwrap()),
+                    created_at: now,
+                    updated_at: now,
+                    idempotency_key: None,
+                };
+                task_repo.create_task(&create_params).await.unwrap();
+
+                let user = User {
+                    id: format!("user-{i}"),
+                    user_type: UserType::Machine,
+                    email: None,
+                    role: Role::User,
+                    description: None,
+                    created_at: now,
+                    updated_at: now,
+                };
+                identity_repo.create_user(&user).await.unwrap();
+            }
+
+            let tasks = task_repo
+                .get_tasks(&PaginationRequest::default())
+                .await
+                .unwrap();
+            let users = identity_repo
+                .list_users(&PaginationRequest::default(), None, None)
+                .await
+                .unwrap();
+
+            set_pagination_defaults(PaginationDefaults::default());
+
+            for (items, page_size, order, next_page_token) in [
+                (
+                    tasks.items.len(),
+                    tasks.page_size,
+                    tasks.order,
+                    tasks.next_page_token,
+                ),
+                (
+                    users.items.len(),
+                    users.page_size,
+                    users.order,
+                    users.next_page_token,
+                ),
+            ] {
+                assert_eq!(items as i64, configured.page_size);
+                assert_eq!(page_size, configured.page_size);
+                assert_eq!(order, PaginationOrder::NewestFirst);
+                assert!(next_page_token.is_some());
+            }
+        }
+
         #[tokio::test]
         async fn test_get_task_timeline_items_pagination() {
             let (_db, conn) = setup_in_memory_database(vec![Repository::load_sql_migrations()])
@@ -688,6 +848,7 @@ mod tests {
                 metadata: WrappedJsonValue::new(serde_json::to_value(&metadata).unwrap()),
                 created_at,
                 updated_at,
+                idempotency_key: None,
             };
             repo.create_task(&create_params).await.unwrap();
 
@@ -748,6 +909,7 @@ mod tests {
             let pagination = PaginationRequest {
                 page_size: 10,
                 next_page_token: None,
+                ..Default::default()
             };
             let response = repo
                 .get_task_timeline_items(&task_id, &pagination)
@@ -766,6 +928,7 @@ mod tests {
             let pagination = PaginationRequest {
                 page_size: 3,
                 next_page_token: None,
+                ..Default::default()
             };
             let response = repo
                 .get_task_timeline_items(&task_id, &pagination)
@@ -778,6 +941,7 @@ mod tests {
             let pagination = PaginationRequest {
                 page_size: 3,
                 next_page_token: response.next_page_token,
+                ..Default::default()
             };
             let response = repo
                 .get_task_timeline_items(&task_id, &pagination)
@@ -828,6 +992,7 @@ mod tests {
                 metadata: WrappedJsonValue::new(serde_json::to_value(&metadata).unwrap()),
                 created_at,
                 updated_at: created_at,
+                idempotency_key: None,
             };
             repo.create_task(&create_params).await.unwrap();
 
@@ -871,6 +1036,7 @@ mod tests {
                 metadata: WrappedJsonValue::new(serde_json::to_value(&task_metadata).unwrap()),
                 created_at,
                 updated_at,
+                idempotency_key: None,
             };
             repo.create_task(&task_params).await.unwrap();
 
@@ -902,6 +1068,7 @@ mod tests {
             let pagination = PaginationRequest {
                 page_size: 10,
                 next_page_token: None,
+                ..Default::default()
             };
             let response = repo
                 .get_messages_by_task_id(&task_id, &pagination)
@@ -939,6 +1106,7 @@ mod tests {
                 metadata: WrappedJsonValue::new(serde_json::to_value(&task_metadata).unwrap()),
                 created_at,
                 updated_at,
+                idempotency_key: None,
             };
             repo.create_task(&task_params).await.unwrap();
 
@@ -979,6 +1147,7 @@ mod tests {
             let pagination = PaginationRequest {
                 page_size: 10,
                 next_page_token: None,
+                ..Default::default()
             };
             let response = repo
                 .get_messages_by_task_id(&task_id, &pagination)
@@ -997,6 +1166,7 @@ mod tests {
             let pagination = PaginationRequest {
                 page_size: 3,
                 next_page_token: None,
+                ..Default::default()
             };
             let response = repo
                 .get_messages_by_task_id(&task_id, &pagination)
@@ -1009,6 +1179,7 @@ mod tests {
             let pagination = PaginationRequest {
                 page_size: 3,
                 next_page_token: response.next_page_token,
+                ..Default::default()
             };
             let response = repo
                 .get_messages_by_task_id(&task_id, &pagination)
@@ -1042,6 +1213,7 @@ mod tests {
                     metadata: WrappedJsonValue::new(serde_json::to_value(&task_metadata).unwrap()),
                     created_at,
                     updated_at,
+                    idempotency_key: None,
                 };
                 repo.create_task(&task_params).await.unwrap();
             }
@@ -1101,6 +1273,7 @@ mod tests {
             let pagination = PaginationRequest {
                 page_size: 10,
                 next_page_token: None,
+                ..Default::default()
             };
             let response = repo
                 .get_messages_by_task_id(&task_id_1, &pagination)
@@ -1149,6 +1322,7 @@ mod tests {
                 metadata: WrappedJsonValue::new(serde_json::to_value(&task_metadata).unwrap()),
                 created_at,
                 updated_at,
+                idempotency_key: None,
             };
             repo.create_task(&task_params).await.unwrap();
 
@@ -1180,6 +1354,7 @@ mod tests {
             let pagination = PaginationRequest {
                 page_size: 10,
                 next_page_token: None,
+                ..Default::default()
             };
             let response = repo
                 .get_messages_by_task_id(&task_id, &pagination)
@@ -1217,6 +1392,7 @@ mod tests {
                 metadata: WrappedJsonValue::new(serde_json::to_value(&metadata).unwrap()),
                 created_at,
                 updated_at,
+                idempotency_key: None,
             };
             repo.create_task(&create_params).await.unwrap();
 
@@ -1253,6 +1429,7 @@ mod tests {
                 metadata: WrappedJsonValue::new(serde_json::to_value(&metadata).unwrap()),
                 created_at,
                 updated_at,
+                idempotency_key: None,
             };
             repo.create_task(&create_params).await.unwrap();
 
@@ -1308,6 +1485,7 @@ mod tests {
                 metadata: WrappedJsonValue::new(serde_json::to_value(&metadata).unwrap()),
                 created_at,
                 updated_at: created_at,
+                idempotency_key: None,
             };
             repo.create_task(&create_params).await.unwrap();
 
@@ -1377,6 +1555,7 @@ mod tests {
                 metadata: WrappedJsonValue::new(serde_json::to_value(&metadata).unwrap()),
                 created_at,
                 updated_at: created_at,
+                idempotency_key: None,
             };
             repo.create_task(&create_params).await.unwrap();
 
@@ -1384,6 +1563,7 @@ mod tests {
             let pagination = PaginationRequest {
                 page_size: 10,
                 next_page_token: None,
+                ..Default::default()
             };
             let response = repo
                 .get_task_timeline_items(&task_id, &pagination)
@@ -1416,6 +1596,7 @@ mod tests {
                 metadata: WrappedJsonValue::new(serde_json::to_value(&metadata).unwrap()),
                 created_at,
                 updated_at: created_at,
+                idempotency_key: None,
             };
             repo.create_task(&create_params).await.unwrap();
 
@@ -1423,6 +1604,7 @@ mod tests {
             let pagination = PaginationRequest {
                 page_size: 10,
                 next_page_token: None,
+                ..Default::default()
             };
             let response = repo
                 .get_messages_by_task_id(&task_id, &pagination)
@@ -1444,6 +1626,7 @@ mod tests {
             let pagination = PaginationRequest {
                 page_size: 10,
                 next_page_token: None,
+                ..Default::default()
             };
             let response = repo.get_tasks(&pagination).await.unwrap();
 
@@ -1481,6 +1664,7 @@ mod tests {
                     metadata: WrappedJsonValue::new(serde_json::to_value(&metadata).unwrap()),
                     created_at,
                     updated_at: created_at,
+                    idempotency_key: None,
                 };
                 repo.create_task(&create_params).await.unwrap();
             }
@@ -1500,6 +1684,7 @@ mod tests {
                 metadata: WrappedJsonValue::new(serde_json::to_value(&metadata).unwrap()),
                 created_at,
                 updated_at: created_at,
+                idempotency_key: None,
             };
             repo.create_task(&create_params).await.unwrap();
 
@@ -1507,6 +1692,7 @@ mod tests {
             let pagination = PaginationRequest {
                 page_size: 10,
                 next_page_token: None,
+                ..Default::default()
             };
             let response = repo.get_unique_contexts(&pagination).await.unwrap();
 
@@ -1560,6 +1746,7 @@ mod tests {
                     metadata: WrappedJsonValue::new(serde_json::to_value(&metadata).unwrap()),
                     created_at,
                     updated_at: created_at,
+                    idempotency_key: None,
                 };
                 repo.create_task(&create_params).await.unwrap();
             }
@@ -1582,6 +1769,7 @@ mod tests {
                     metadata: WrappedJsonValue::new(serde_json::to_value(&metadata).unwrap()),
                     created_at,
                     updated_at: created_at,
+                    idempotency_key: None,
                 };
                 repo.create_task(&create_params).await.unwrap();
             }
@@ -1590,6 +1778,7 @@ mod tests {
             let pagination = PaginationRequest {
                 page_size: 10,
                 next_page_token: None,
+                ..Default::default()
             };
             let response = repo
                 .get_tasks_by_context_id(&context_id_1, &pagination)
@@ -1659,6 +1848,7 @@ mod tests {
                     metadata: WrappedJsonValue::new(serde_json::to_value(&metadata).unwrap()),
                     created_at,
                     updated_at: created_at,
+                    idempotency_key: None,
                 };
                 repo.create_task(&create_params).await.unwrap();
             }
@@ -1667,6 +1857,7 @@ mod tests {
             let pagination = PaginationRequest {
                 page_size: 3,
                 next_page_token: None,
+                ..Default::default()
             };
             let response = repo
                 .get_tasks_by_context_id(&context_id, &pagination)
@@ -1680,6 +1871,7 @@ mod tests {
             let pagination = PaginationRequest {
                 page_size: 3,
                 next_page_token: response.next_page_token,
+                ..Default::default()
             };
             let response = repo
                 .get_tasks_by_context_id(&context_id, &pagination)
@@ -1701,6 +1893,7 @@ mod tests {
             let pagination = PaginationRequest {
                 page_size: 10,
                 next_page_token: None,
+                ..Default::default()
             };
             let response = repo
                 .get_tasks_by_context_id(&non_existent_context_id, &pagination)
@@ -1733,6 +1926,7 @@ mod tests {
                 metadata: WrappedJsonValue::new(serde_json::to_value(&metadata).unwrap()),
                 created_at,
                 updated_at: created_at,
+                idempotency_key: None,
             };
             repo.create_task(&create_params).await.unwrap();
 
@@ -1805,6 +1999,7 @@ mod tests {
                 metadata: WrappedJsonValue::new(serde_json::to_value(&metadata).unwrap()),
                 created_at,
                 updated_at: created_at,
+                idempotency_key: None,
             };
             repo.create_task(&create_params).await.unwrap();
 
@@ -1868,6 +2063,7 @@ mod tests {
                 metadata: WrappedJsonValue::new(serde_json::to_value(&metadata).unwrap()),
                 created_at,
                 updated_at: created_at,
+                idempotency_key: None,
             };
             repo.create_task(&create_params).await.unwrap();
 
@@ -1931,6 +2127,7 @@ mod tests {
                 metadata: WrappedJsonValue::new(serde_json::to_value(&metadata).unwrap()),
                 created_at,
                 updated_at: created_at,
+                idempotency_key: None,
             };
             repo.create_task(&create_params).await.unwrap();
 
@@ -2015,6 +2212,7 @@ mod tests {
                 metadata: WrappedJsonValue::new(serde_json::to_value(&metadata).unwrap()),
                 created_at,
                 updated_at: created_at,
+                idempotency_key: None,
             };
             repo.create_task(&create_params).await.unwrap();
 
@@ -2075,6 +2273,7 @@ mod tests {
             let pagination = PaginationRequest {
                 page_size: 10,
                 next_page_token: None,
+                ..Default::default()
             };
             let response = repo
                 .get_task_timeline_items(&task_id, &pagination)
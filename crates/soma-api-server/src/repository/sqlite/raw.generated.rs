@@ -582,6 +582,9 @@ LIMIT CAST(?2 AS INTEGER) + 1"#).await?;
       pub updated_at: &'a 
           shared::primitives::WrappedChronoDateTime
       ,
+      pub idempotency_key: &'a Option<
+          String
+      >,
   }
 
   pub async fn insert_task(
@@ -595,7 +598,8 @@ LIMIT CAST(?2 AS INTEGER) + 1"#).await?;
     status_timestamp,
     metadata,
     created_at,
-    updated_at
+    updated_at,
+    idempotency_key
 ) VALUES (
     ?1,
     ?2,
@@ -603,8 +607,9 @@ LIMIT CAST(?2 AS INTEGER) + 1"#).await?;
     ?4,
     ?5,
     ?6,
-    ?7
-)"#, libsql::params![
+    ?7,
+    ?8
+) ON CONFLICT (idempotency_key) DO NOTHING"#, libsql::params![
               <shared::primitives::WrappedUuidV4 as TryInto<libsql::Value>>::try_into(params.id.clone())
                   .map_err(|e| libsql::Error::ToSqlConversionFailure(e.into()))?
             ,
@@ -626,8 +631,44 @@ LIMIT CAST(?2 AS INTEGER) + 1"#).await?;
               <shared::primitives::WrappedChronoDateTime as TryInto<libsql::Value>>::try_into(params.updated_at.clone())
                   .map_err(|e| libsql::Error::ToSqlConversionFailure(e.into()))?
             ,
+              match params.idempotency_key.clone() {
+                Some(value) => {
+                  <String as TryInto<libsql::Value>>::try_into(value.clone())
+                      .map_err(|e| libsql::Error::ToSqlConversionFailure(e.into()))?
+                },
+                None => libsql::Value::Null,
+              }
+            ,
     ]).await
 }
+  pub struct get_task_id_by_idempotency_key_params<'a> {
+      pub idempotency_key: &'a Option<
+          String
+      >,
+  }
+    #[derive(Serialize, Deserialize, Debug)]
+
+  #[allow(non_camel_case_types)]
+  pub struct Row_get_task_id_by_idempotency_key {
+      pub id:shared::primitives::WrappedUuidV4,
+  }
+  pub async fn get_task_id_by_idempotency_key(
+      conn: &shared::libsql::Connection
+      ,params: get_task_id_by_idempotency_key_params<'_>
+  ) -> Result<Option<Row_get_task_id_by_idempotency_key>, libsql::Error> {
+      let mut stmt = conn.prepare(r#"SELECT id FROM task WHERE idempotency_key = ?1"#).await?;
+      let res = stmt.query_row(
+          libsql::params![params.idempotency_key.clone(),],
+      ).await;
+
+      match res {
+          Ok(row) => Ok(Some(Row_get_task_id_by_idempotency_key {
+                  id: row.get(0)?,
+              })),
+          Err(libsql::Error::QueryReturnedNoRows) => Ok(None),
+          Err(e) => Err(e),
+      }
+  }
   pub struct update_task_status_params<'a> {
       pub status: &'a 
           crate::repository::TaskStatus
@@ -745,12 +786,13 @@ LIMIT CAST(?2 AS INTEGER) + 1"#).await?;
       pub metadata:shared::primitives::WrappedJsonValue,
       pub created_at:shared::primitives::WrappedChronoDateTime,
       pub updated_at:shared::primitives::WrappedChronoDateTime,
+      pub idempotency_key:Option<String> ,
   }
   pub async fn get_tasks(
       conn: &shared::libsql::Connection
       ,params: get_tasks_params<'_>
   ) -> Result<Vec<Row_get_tasks>, libsql::Error> {
-      let stmt = conn.prepare(r#"SELECT id, context_id, status, status_message_id, status_timestamp, metadata, created_at, updated_at FROM task WHERE (created_at < ?1 OR ?1 IS NULL)
+      let stmt = conn.prepare(r#"SELECT id, context_id, status, status_message_id, status_timestamp, metadata, created_at, updated_at, idempotency_key FROM task WHERE (created_at < ?1 OR ?1 IS NULL)
 ORDER BY created_at DESC
 LIMIT CAST(?2 AS INTEGER) + 1"#).await?;
       let mut rows = stmt.query(libsql::params![params.cursor.clone(),params.page_size.clone(),]).await?;
@@ -766,6 +808,7 @@ LIMIT CAST(?2 AS INTEGER) + 1"#).await?;
               metadata: row.get(5)?,
               created_at: row.get(6)?,
               updated_at: row.get(7)?,
+              idempotency_key: row.get(8)?,
           });
       }
 
@@ -828,12 +871,13 @@ LIMIT CAST(?2 AS INTEGER) + 1"#).await?;
       pub metadata:shared::primitives::WrappedJsonValue,
       pub created_at:shared::primitives::WrappedChronoDateTime,
       pub updated_at:shared::primitives::WrappedChronoDateTime,
+      pub idempotency_key:Option<String> ,
   }
   pub async fn get_tasks_by_context_id(
       conn: &shared::libsql::Connection
       ,params: get_tasks_by_context_id_params<'_>
   ) -> Result<Vec<Row_get_tasks_by_context_id>, libsql::Error> {
-      let stmt = conn.prepare(r#"SELECT id, context_id, status, status_message_id, status_timestamp, metadata, created_at, updated_at FROM task WHERE context_id = ?1 AND (created_at < ?2 OR ?2 IS NULL)
+      let stmt = conn.prepare(r#"SELECT id, context_id, status, status_message_id, status_timestamp, metadata, created_at, updated_at, idempotency_key FROM task WHERE context_id = ?1 AND (created_at < ?2 OR ?2 IS NULL)
 ORDER BY created_at DESC
 LIMIT CAST(?3 AS INTEGER) + 1"#).await?;
       let mut rows = stmt.query(libsql::params![params.context_id.clone(),params.cursor.clone(),params.page_size.clone(),]).await?;
@@ -849,6 +893,7 @@ LIMIT CAST(?3 AS INTEGER) + 1"#).await?;
               metadata: row.get(5)?,
               created_at: row.get(6)?,
               updated_at: row.get(7)?,
+              idempotency_key: row.get(8)?,
           });
       }
 
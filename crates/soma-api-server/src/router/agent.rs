@@ -12,7 +12,7 @@ use a2a_rs::{
     types::{Task, TaskState, TaskStatus},
 };
 use axum::Json;
-use axum::extract::{Path, State};
+use axum::extract::{Path, Query, State};
 use axum::response::IntoResponse;
 use axum::response::sse::{Event as SseEvent, Sse};
 use reqwest::Client;
@@ -20,26 +20,32 @@ use serde::{Deserialize, Serialize};
 use serde_json::json;
 use shared::adapters::openapi::{API_VERSION_TAG, JsonResponse};
 use shared::error::CommonError;
-use shared::primitives::{WrappedChronoDateTime, WrappedJsonValue, WrappedUuidV4};
+use shared::primitives::{
+    PaginationRequest, WrappedChronoDateTime, WrappedJsonValue, WrappedUuidV4,
+};
 use std::collections::HashMap;
 use std::str::FromStr;
 use std::time::Duration;
 use std::{pin::Pin, sync::Arc};
 use tokio::sync::RwLock;
 use tokio_stream::StreamExt as TokioStreamExt;
-use tracing::trace;
+use tracing::{trace, warn};
 use url::Url;
 use utoipa::ToSchema;
 use utoipa_axum::router::OpenApiRouter;
 use utoipa_axum::routes;
 
-use crate::logic::agent::ConstructAgentCardParams;
-use crate::logic::agent::{RepositoryTaskStore, construct_agent_card};
+use crate::logic::agent::{AgentCardCache, ConstructAgentCardParams, ListAgentsResponse};
+use crate::logic::agent::{
+    RepositoryTaskStore, a2a_task_from_details, agent_skill, construct_agent_card,
+    entrypoint_failure_reason, reason_status_message, user_canceled_task,
+};
 use crate::logic::task::{
     self as task_logic, ConnectionManager, CreateMessageRequest, UpdateTaskStatusRequest,
     WithTaskId, update_task_status,
 };
 use crate::repository::{CreateTask, Repository, TaskRepositoryLike};
+use crate::sdk::sdk_agent_sync::get_agent;
 use shared::restate::admin_client::AdminClient;
 use shared::restate::invoke::{RestateIngressClient, construct_initial_object_id};
 use shared::soma_agent_definition::SomaAgentDefinitionLike;
@@ -63,13 +69,6 @@ pub struct AgentListItem {
     pub agent_id: String,
 }
 
-/// Response for listing agents
-#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
-pub struct ListAgentsResponse {
-    /// List of agents
-    pub agents: Vec<AgentListItem>,
-}
-
 pub fn create_router() -> OpenApiRouter<Arc<AgentService>> {
     OpenApiRouter::new()
         .routes(routes!(route_list_agents))
@@ -82,11 +81,15 @@ pub fn create_router() -> OpenApiRouter<Arc<AgentService>> {
     get,
     path = format!("{}/{}", PATH_PREFIX, SERVICE_ROUTE_KEY),
     tags = [SERVICE_ROUTE_KEY, API_VERSION_TAG],
+    params(
+        PaginationRequest
+    ),
     responses(
         (status = 200, description = "List of agents", body = ListAgentsResponse),
+        (status = 400, description = "Bad Request", body = CommonError),
     ),
     summary = "List available agents",
-    description = "List all available agents from the agent cache",
+    description = "List available agents from the agent cache with pagination",
     operation_id = "list-agents",
     security(
         (),
@@ -96,11 +99,12 @@ pub fn create_router() -> OpenApiRouter<Arc<AgentService>> {
 )]
 async fn route_list_agents(
     State(ctx): State<Arc<AgentService>>,
+    Query(pagination): Query<PaginationRequest>,
 ) -> JsonResponse<ListAgentsResponse, CommonError> {
-    trace!("Listing agents");
-    let agents = crate::logic::agent::list_agents(&ctx.agent_cache);
-    trace!(count = agents.len(), "Listing agents completed");
-    JsonResponse::from(Ok(ListAgentsResponse { agents }))
+    trace!(page_size = pagination.page_size, "Listing agents");
+    let res = crate::logic::agent::list_agents(&ctx.agent_cache, &pagination);
+    trace!(success = res.is_ok(), "Listing agents completed");
+    JsonResponse::from(res)
 }
 
 /// GET /api/agent/{project_id}/{agent_id}/a2a/.well-known/agent.json - Get A2A agent card
@@ -114,6 +118,8 @@ async fn route_list_agents(
     ),
     responses(
         (status = 200, description = "Agent card", body = a2a_rs::types::AgentCard),
+        (status = 404, description = "Not Found", body = CommonError),
+        (status = 500, description = "Internal Server Error", body = CommonError),
     ),
     summary = "Get agent card for specific agent",
     description = "Get the agent card describing agent capabilities and metadata for a specific agent",
@@ -138,7 +144,7 @@ async fn route_agent_card(
     trace!(success = result.is_ok(), "Getting agent card completed");
     match result {
         Ok(card) => (http::StatusCode::OK, Json(card)).into_response(),
-        Err(e) => (http::StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Err(e) => (e.http_status(), e.to_string()).into_response(),
     }
 }
 
@@ -350,6 +356,8 @@ pub struct AgentService {
     restate_ingress_client: RestateIngressClient,
     restate_admin_client: AdminClient,
     agent_cache: crate::sdk::sdk_agent_sync::AgentCache,
+    agent_allowlists: mcp::logic::AgentAllowlists,
+    agent_card_cache: AgentCardCache,
 }
 
 pub struct AgentServiceParams {
@@ -360,6 +368,10 @@ pub struct AgentServiceParams {
     pub restate_ingress_client: RestateIngressClient,
     pub restate_admin_client: AdminClient,
     pub agent_cache: crate::sdk::sdk_agent_sync::AgentCache,
+    /// Allowlists the tasks run by each agent are bound to
+    pub agent_allowlists: mcp::logic::AgentAllowlists,
+    /// How long a constructed agent card is served before it is rebuilt
+    pub agent_card_ttl: Duration,
 }
 
 impl AgentService {
@@ -372,6 +384,8 @@ impl AgentService {
             restate_ingress_client,
             restate_admin_client,
             agent_cache,
+            agent_allowlists,
+            agent_card_ttl,
         } = params;
 
         // Create a task store
@@ -395,6 +409,8 @@ impl AgentService {
             restate_ingress_client,
             restate_admin_client,
             agent_cache,
+            agent_allowlists,
+            agent_card_cache: AgentCardCache::new(agent_card_ttl),
         }
     }
 
@@ -403,6 +419,15 @@ impl AgentService {
         &self,
         path_params: &AgentPathParams,
     ) -> Result<a2a_rs::types::AgentCard, CommonError> {
+        if let Some(card) = self.agent_card_cache.get(
+            &self.agent_cache,
+            &path_params.project_id,
+            &path_params.agent_id,
+        ) {
+            trace!("Serving agent card from cache");
+            return Ok(card);
+        }
+
         let soma_definition = self.soma_definition.get_definition().await?;
 
         let mut full_url = self.host.clone();
@@ -412,13 +437,38 @@ impl AgentService {
             path_params.project_id, path_params.agent_id
         ));
 
+        let agent = get_agent(
+            &self.agent_cache,
+            &path_params.project_id,
+            &path_params.agent_id,
+        )
+        .ok_or_else(|| CommonError::NotFound {
+            msg: "Agent not found".to_string(),
+            lookup_id: format!("{}/{}", path_params.project_id, path_params.agent_id),
+            source: None,
+        })?;
+
         let card = construct_agent_card(ConstructAgentCardParams {
             definition: soma_definition,
             url: full_url.to_string(),
-        });
+            skills: vec![agent_skill(&agent)],
+            preferred_transport: "JSONRPC".to_string(),
+        })?;
+        self.agent_card_cache.insert(
+            &self.agent_cache,
+            &path_params.project_id,
+            &path_params.agent_id,
+            card.clone(),
+        );
         Ok(card)
     }
 
+    /// Forces the agent card for a specific project/agent to be rebuilt on the next request
+    pub fn invalidate_agent_card(&self, path_params: &AgentPathParams) {
+        self.agent_card_cache
+            .invalidate(&path_params.project_id, &path_params.agent_id);
+    }
+
     /// Create a request handler for a specific project/agent
     pub fn get_request_handler_with_params(
         &self,
@@ -431,6 +481,7 @@ impl AgentService {
             repository: self.repository.clone(),
             restate_ingress_client: self.restate_ingress_client.clone(),
             restate_admin_client: self.restate_admin_client.clone(),
+            agent_allowlists: self.agent_allowlists.clone(),
             project_id: path_params.project_id.clone(),
             agent_id: path_params.agent_id.clone(),
         });
@@ -462,6 +513,7 @@ struct ProxiedAgent {
     repository: Repository,
     restate_ingress_client: RestateIngressClient,
     restate_admin_client: AdminClient,
+    agent_allowlists: mcp::logic::AgentAllowlists,
     // Path params for multi-agent routing
     project_id: String,
     agent_id: String,
@@ -511,6 +563,40 @@ impl AgentExecutor for ProxiedAgent {
                 }
             };
 
+            let db_task = self.repository.get_task_by_id(&task_id).await?;
+            if db_task.is_none() {
+                // A client retrying message/send resends the same message id, so it doubles as
+                // the idempotency key for the task it starts
+                let stored_task_id = self
+                    .repository
+                    .create_task(&CreateTask {
+                        id: task_id.clone(),
+                        context_id: WrappedUuidV4::from_str(&task.context_id).unwrap(),
+                        // todo: convert to task_logic::TaskStatus
+                        status: task_logic::TaskStatus::from(task.status.state.to_string()),
+                        status_timestamp: WrappedChronoDateTime::now(),
+                        metadata: WrappedJsonValue::new(serde_json::to_value(
+                            task.metadata.clone(),
+                        )?),
+                        created_at: WrappedChronoDateTime::now(),
+                        updated_at: WrappedChronoDateTime::now(),
+                        idempotency_key: context.message().map(|m| m.message_id.clone()),
+                    })
+                    .await?;
+                if stored_task_id != task_id {
+                    trace!(task_id = %task_id, existing_task_id = %stored_task_id, "Returning task already created for this message");
+                    if let Some(existing_task) =
+                        self.repository.get_task_by_id(&stored_task_id).await?
+                    {
+                        event_queue
+                            .enqueue_event(Event::Task(a2a_task_from_details(existing_task)))
+                            .await?;
+                    }
+                    return Ok(());
+                }
+                event_queue.enqueue_event(Event::Task(task.clone())).await?;
+            }
+
             // Register the connection BEFORE invoking the handler
             // so that any messages sent during handler execution can be received
             let (connection_id, mut receiver) = match self
@@ -565,25 +651,6 @@ impl AgentExecutor for ProxiedAgent {
                     .unwrap();
             });
 
-            let db_task = self.repository.get_task_by_id(&task_id).await?;
-            if db_task.is_none() {
-                self.repository
-                    .create_task(&CreateTask {
-                        id: task_id.clone(),
-                        context_id: WrappedUuidV4::from_str(&task.context_id).unwrap(),
-                        // todo: convert to task_logic::TaskStatus
-                        status: task_logic::TaskStatus::from(task.status.state.to_string()),
-                        status_timestamp: WrappedChronoDateTime::now(),
-                        metadata: WrappedJsonValue::new(serde_json::to_value(
-                            task.metadata.clone(),
-                        )?),
-                        created_at: WrappedChronoDateTime::now(),
-                        updated_at: WrappedChronoDateTime::now(),
-                    })
-                    .await?;
-                event_queue.enqueue_event(Event::Task(task.clone())).await?;
-            }
-
             let message = match context.message() {
                 Some(message) => message,
                 None => unreachable!("message must be present"),
@@ -635,6 +702,11 @@ impl AgentExecutor for ProxiedAgent {
             trace!(service = %service_name, "Resolved agent service name");
             let object_id = construct_initial_object_id(&task.id);
 
+            // Bridge calls made from this task are checked against this agent's allowlist
+            self.agent_allowlists
+                .bind_task(&task.id, &service_name)
+                .map_err(|e| Box::new(e) as Box<dyn std::error::Error + Send + Sync + 'static>)?;
+
             // Use the task status from the database if available, otherwise convert from context task
             let task_status = if let Some(db_task) = &db_task {
                 db_task.task.status.clone()
@@ -676,7 +748,8 @@ impl AgentExecutor for ProxiedAgent {
                         },
                     )
                     .await?;
-                    self.restate_ingress_client
+                    if let Err(e) = self
+                        .restate_ingress_client
                         .invoke_virtual_object_handler(
                             &service_name,
                             &object_id,
@@ -684,12 +757,31 @@ impl AgentExecutor for ProxiedAgent {
                             body,
                         )
                         .await
-                        .map_err(|e| {
-                            Box::new(CommonError::Unknown(anyhow::anyhow!(
-                                "Failed to invoke entrypoint: {e}"
-                            )))
-                                as Box<dyn std::error::Error + Send + Sync + 'static>
-                        })?;
+                    {
+                        // Record why the task failed before surfacing the error
+                        let reason_code = entrypoint_failure_reason(&e);
+                        warn!(task_id = %task.id, ?reason_code, error = ?e, "Agent entrypoint failed");
+                        update_task_status(
+                            &self.repository,
+                            &self.connection_manager,
+                            Some(event_queue.clone()),
+                            WithTaskId {
+                                task_id: task_id.clone(),
+                                inner: UpdateTaskStatusRequest {
+                                    status: task_logic::TaskStatus::Failed,
+                                    message: Some(reason_status_message(
+                                        reason_code,
+                                        format!("Agent entrypoint failed: {e}"),
+                                    )),
+                                },
+                            },
+                        )
+                        .await?;
+                        return Err(Box::new(CommonError::Unknown(anyhow::anyhow!(
+                            "Failed to invoke entrypoint: {e}"
+                        )))
+                            as Box<dyn std::error::Error + Send + Sync + 'static>);
+                    }
                 }
                 _ => {
                     // Existing task - resolve the new_input_promise awakeable
@@ -751,26 +843,16 @@ impl AgentExecutor for ProxiedAgent {
         Box::pin(async move {
             trace!("Executing task cancel");
 
-            // Create a cancelled task
-            let task = Task {
-                id: _context
-                    .task_id()
-                    .expect("task_id must be present")
-                    .to_string(),
-                context_id: _context
-                    .context_id()
-                    .expect("context_id must be present")
-                    .to_string(),
-                status: TaskStatus {
-                    state: TaskState::Canceled,
-                    message: None,
-                    timestamp: Some(chrono::Utc::now().to_rfc3339()),
-                },
-                artifacts: vec![],
-                history: vec![],
-                metadata: Default::default(),
-                kind: "task".to_string(),
-            };
+            let task_id = _context
+                .task_id()
+                .expect("task_id must be present")
+                .to_string();
+            let context_id = _context
+                .context_id()
+                .expect("context_id must be present")
+                .to_string();
+
+            let task = user_canceled_task(task_id, context_id);
 
             event_queue
                 .enqueue_event(Event::Task(task))
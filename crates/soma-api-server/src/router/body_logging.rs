@@ -0,0 +1,235 @@
+//! Optional request/response logging for debugging provider invocations. Enabled with
+//! `SOMA_HTTP_BODY_LOGGING=1`; bodies of event streams are never buffered and bodies of routes
+//! carrying secrets, keys, tokens or credentials are never logged.
+
+use std::time::Instant;
+
+use axum::{
+    body::{Body, Bytes, HttpBody},
+    extract::Request,
+    http::{HeaderMap, StatusCode, header},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use tracing::info;
+
+use crate::router::REQUEST_BODY_LIMIT_BYTES;
+
+pub(crate) const BODY_LOGGING_ENV_VAR: &str = "SOMA_HTTP_BODY_LOGGING";
+
+/// Bodies longer than this are truncated in the log line
+const MAX_LOGGED_BODY_BYTES: usize = 4096;
+
+/// Routes whose bodies carry API keys, tokens or OAuth client secrets
+const REDACTED_PATH_PREFIXES: &[&str] = &[
+    "/api/identity/v1/api-key",
+    "/api/identity/v1/auth/",
+    "/api/identity/v1/sts/",
+    "/api/identity/v1/user-auth-flow-config",
+    "/api/mcp/v1/generic-oauth-callback",
+];
+
+pub(crate) fn body_logging_enabled() -> bool {
+    std::env::var(BODY_LOGGING_ENV_VAR).is_ok_and(|value| value == "1")
+}
+
+fn is_event_stream(headers: &HeaderMap, name: header::HeaderName) -> bool {
+    headers
+        .get(name)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.contains("text/event-stream"))
+}
+
+/// Whether `path` is a route whose request or response body must not be logged: secrets,
+/// API keys, tokens and provider credentials
+fn is_redacted_path(path: &str) -> bool {
+    REDACTED_PATH_PREFIXES
+        .iter()
+        .any(|prefix| path.starts_with(prefix))
+        || (path.starts_with("/api/environment/") && path.contains("/secret"))
+        || (path.starts_with("/api/mcp/") && path.split('/').any(|segment| segment == "credential"))
+}
+
+fn truncate_body(bytes: &Bytes) -> String {
+    let shown = &bytes[..bytes.len().min(MAX_LOGGED_BODY_BYTES)];
+    let mut body = String::from_utf8_lossy(shown).into_owned();
+    if bytes.len() > MAX_LOGGED_BODY_BYTES {
+        body.push_str(&format!("... ({} bytes total)", bytes.len()));
+    }
+    body
+}
+
+/// Logs method, path, status, latency and (truncated) bodies. Bodies up to the router's body
+/// limit are buffered and handed on unchanged; streaming requests and responses, responses of
+/// unknown or larger size and redacted routes are passed through without reading them.
+pub(crate) async fn log_http_bodies(request: Request, next: Next) -> Response {
+    let started = Instant::now();
+    let method = request.method().clone();
+    let path = request.uri().path().to_string();
+    let streaming_request = is_event_stream(request.headers(), header::ACCEPT);
+    let redacted = is_redacted_path(&path);
+
+    let (request, request_body) = if streaming_request {
+        (request, "<streaming>".to_string())
+    } else if redacted {
+        (request, "<redacted>".to_string())
+    } else {
+        let (parts, body) = request.into_parts();
+        let bytes = match axum::body::to_bytes(body, REQUEST_BODY_LIMIT_BYTES).await {
+            Ok(bytes) => bytes,
+            Err(e) => {
+                return (
+                    StatusCode::PAYLOAD_TOO_LARGE,
+                    format!("Failed to read request body: {e}"),
+                )
+                    .into_response();
+            }
+        };
+        let logged = truncate_body(&bytes);
+        (Request::from_parts(parts, Body::from(bytes)), logged)
+    };
+
+    let response = next.run(request).await;
+    let status = response.status().as_u16();
+
+    let fits_limit = response
+        .body()
+        .size_hint()
+        .upper()
+        .is_some_and(|upper| upper <= REQUEST_BODY_LIMIT_BYTES as u64);
+    let (response, response_body) =
+        if streaming_request || is_event_stream(response.headers(), header::CONTENT_TYPE) {
+            (response, "<streaming>".to_string())
+        } else if redacted {
+            (response, "<redacted>".to_string())
+        } else if !fits_limit {
+            (response, "<too large>".to_string())
+        } else {
+            let (parts, body) = response.into_parts();
+            match axum::body::to_bytes(body, REQUEST_BODY_LIMIT_BYTES).await {
+                Ok(bytes) => {
+                    let logged = truncate_body(&bytes);
+                    (Response::from_parts(parts, Body::from(bytes)), logged)
+                }
+                Err(e) => {
+                    return (
+                        StatusCode::INTERNAL_SERVER_ERROR,
+                        format!("Failed to read response body: {e}"),
+                    )
+                        .into_response();
+                }
+            }
+        };
+
+    info!(
+        %method,
+        path,
+        status,
+        latency_ms = started.elapsed().as_millis() as u64,
+        request_body,
+        response_body,
+        "HTTP request"
+    );
+
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    mod unit {
+        use super::super::*;
+        use axum::{Router, middleware, routing::post};
+        use tower::Service;
+
+        async fn call(router: Router, request: Request) -> (StatusCode, Bytes) {
+            let mut service = router.layer(middleware::from_fn(log_http_bodies));
+            let response = service.call(request).await.unwrap();
+            let status = response.status();
+            let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+                .await
+                .unwrap();
+            (status, body)
+        }
+
+        #[tokio::test]
+        async fn test_bodies_pass_through_unchanged() {
+            shared::setup_test!();
+
+            let router = Router::new().route("/echo", post(|body: Bytes| async move { body }));
+            let payload = "x".repeat(MAX_LOGGED_BODY_BYTES * 2);
+            let request = Request::post("/echo")
+                .header(header::CONTENT_TYPE, "text/plain")
+                .body(Body::from(payload.clone()))
+                .unwrap();
+
+            let (status, body) = call(router, request).await;
+
+            assert_eq!(status, StatusCode::OK);
+            assert_eq!(body, Bytes::from(payload));
+        }
+
+        #[tokio::test]
+        async fn test_streaming_requests_pass_through_unread() {
+            shared::setup_test!();
+
+            let router = Router::new().route("/events", post(|| async { "subscribed" }));
+            // A body that never ends: reading it would hang the request
+            let body =
+                Body::from_stream(futures::stream::pending::<Result<Bytes, std::io::Error>>());
+            let request = Request::post("/events")
+                .header(header::ACCEPT, "text/event-stream")
+                .body(body)
+                .unwrap();
+
+            let (status, body) =
+                tokio::time::timeout(std::time::Duration::from_secs(5), call(router, request))
+                    .await
+                    .expect("streaming request body was read");
+
+            assert_eq!(status, StatusCode::OK);
+            assert_eq!(body, Bytes::from_static(b"subscribed"));
+        }
+
+        #[tokio::test]
+        async fn test_oversized_request_bodies_are_rejected() {
+            shared::setup_test!();
+
+            let router = Router::new().route("/echo", post(|body: Bytes| async move { body }));
+            let request = Request::post("/echo")
+                .body(Body::from(vec![b'a'; REQUEST_BODY_LIMIT_BYTES + 1]))
+                .unwrap();
+
+            let (status, _) = call(router, request).await;
+
+            assert_eq!(status, StatusCode::PAYLOAD_TOO_LARGE);
+        }
+
+        #[test]
+        fn test_sensitive_routes_are_redacted() {
+            assert!(is_redacted_path("/api/environment/v1/secret"));
+            assert!(is_redacted_path(
+                "/api/environment/v1/secret/list-decrypted"
+            ));
+            assert!(is_redacted_path("/api/identity/v1/api-key/import"));
+            assert!(is_redacted_path("/api/identity/v1/auth/refresh"));
+            assert!(is_redacted_path(
+                "/api/mcp/v1/available-providers/google_mail/available-credentials/oauth/credential/user-credential"
+            ));
+            assert!(!is_redacted_path("/api/environment/v1/variable"));
+            assert!(!is_redacted_path(
+                "/api/mcp/v1/available-providers/google_mail/available-credentials/oauth"
+            ));
+        }
+
+        #[test]
+        fn test_long_bodies_are_truncated() {
+            let short = Bytes::from_static(b"{\"ok\":true}");
+            assert_eq!(truncate_body(&short), "{\"ok\":true}");
+
+            let long = Bytes::from(vec![b'a'; MAX_LOGGED_BODY_BYTES + 1]);
+            let logged = truncate_body(&long);
+            assert!(logged.starts_with(&"a".repeat(MAX_LOGGED_BODY_BYTES)));
+            assert!(logged.ends_with(&format!("({} bytes total)", MAX_LOGGED_BODY_BYTES + 1)));
+        }
+    }
+}
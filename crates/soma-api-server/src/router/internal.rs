@@ -1,7 +1,12 @@
 use axum::extract::State;
+use axum::http::{StatusCode, header};
+use axum::response::IntoResponse;
+use axum::routing::get;
+use axum::{Json, Router};
 use encryption::logic::crypto_services::CryptoCache;
 use sdk_proto::soma_sdk_service_client::SomaSdkServiceClient;
 use std::sync::Arc;
+use std::sync::atomic::AtomicBool;
 use tokio::sync::Mutex;
 use tonic::transport::Channel;
 use tracing::trace;
@@ -13,7 +18,8 @@ use shared::{
 };
 
 use crate::logic::internal::{
-    CheckSdkHealthResponse, ResyncSdkResponse, RuntimeConfigResponse, TriggerCodegenResponse,
+    CheckSdkHealthResponse, ReadinessResponse, ResyncSdkResponse, RuntimeConfigResponse,
+    SdkBackoffConfig, TriggerCodegenResponse,
 };
 use crate::sdk::sdk_agent_sync::AgentCache;
 
@@ -30,6 +36,60 @@ pub fn create_router() -> OpenApiRouter<Arc<InternalService>> {
         .routes(routes!(route_resync_sdk))
 }
 
+/// Content type of the Prometheus text exposition format served on `/metrics`
+const PROMETHEUS_CONTENT_TYPE: &str = "text/plain; version=0.0.4; charset=utf-8";
+
+/// Liveness and readiness probes for orchestrators, and the Prometheus scrape endpoint. These
+/// stay out of the OpenAPI spec.
+pub fn create_probe_router() -> Router<Arc<InternalService>> {
+    Router::new()
+        .route("/healthz", get(route_healthz))
+        .route("/readyz", get(route_readyz))
+        .route("/metrics", get(route_metrics))
+}
+
+/// Paths the rate limiter never counts: orchestrator probes, and the routes the SDK calls back
+/// into while (re)starting, which must not be starved by user traffic
+pub(crate) fn rate_limit_exempt_paths() -> Vec<String> {
+    vec![
+        "/healthz".to_string(),
+        "/readyz".to_string(),
+        "/metrics".to_string(),
+        format!("{PATH_PREFIX}/{API_VERSION_1}/health"),
+        format!("{PATH_PREFIX}/{API_VERSION_1}/trigger_codegen"),
+        format!("{PATH_PREFIX}/{API_VERSION_1}/resync_sdk"),
+    ]
+}
+
+/// Liveness: the process is up and serving requests. Deliberately checks nothing else.
+async fn route_healthz() -> StatusCode {
+    StatusCode::OK
+}
+
+/// Encryption latency and DEK cache metrics in the Prometheus text format
+async fn route_metrics() -> impl IntoResponse {
+    let text = encryption::logic::metrics::encryption_metrics().to_prometheus_text();
+    ([(header::CONTENT_TYPE, PROMETHEUS_CONTENT_TYPE)], text)
+}
+
+/// Readiness: 503 with the subsystems that are not ready, until all of them are
+async fn route_readyz(
+    State(ctx): State<Arc<InternalService>>,
+) -> (StatusCode, Json<ReadinessResponse>) {
+    let response = crate::logic::internal::check_readiness(
+        &ctx.sdk_client,
+        &ctx.agents_synced,
+        &ctx.crypto_cache,
+    );
+    trace!(ready = response.ready, not_ready = ?response.not_ready, "Checked readiness");
+    let status = if response.ready {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+    (status, Json(response))
+}
+
 #[utoipa::path(
     get,
     path = format!("{}/{}/health", PATH_PREFIX, API_VERSION_1),
@@ -51,7 +111,8 @@ async fn route_health(
     State(ctx): State<Arc<InternalService>>,
 ) -> JsonResponse<CheckSdkHealthResponse, CommonError> {
     trace!("Checking SDK health");
-    let response = crate::logic::internal::check_sdk_health(&ctx.sdk_client).await;
+    let response =
+        crate::logic::internal::check_sdk_health(&ctx.sdk_client, &ctx.sdk_backoff).await;
     trace!(success = response.is_ok(), "Checking SDK health completed");
     JsonResponse::from(response)
 }
@@ -110,6 +171,7 @@ async fn route_trigger_codegen(
         &ctx.sdk_client,
         ctx.mcp_service.repository(),
         &ctx.agent_cache,
+        &ctx.sdk_backoff,
     )
     .await;
     trace!(success = response.is_ok(), "Triggering codegen completed");
@@ -144,8 +206,12 @@ async fn route_resync_sdk(
         &ctx.crypto_cache,
         &ctx.restate_params,
         &ctx.sdk_client,
+        shared::uds::DEFAULT_SOMA_SERVER_SOCK,
         &ctx.agent_cache,
+        ctx.mcp_service.agent_allowlists(),
+        &ctx.agents_synced,
         ctx.mcp_service.repository(),
+        &ctx.sdk_backoff,
     )
     .await;
     trace!(success = response.is_ok(), "Resyncing SDK completed");
@@ -159,6 +225,9 @@ pub struct InternalService {
     crypto_cache: CryptoCache,
     restate_params: crate::restate::RestateServerParams,
     agent_cache: AgentCache,
+    /// Set by the first successful agent sync, see [`crate::logic::internal::check_readiness`]
+    agents_synced: AtomicBool,
+    sdk_backoff: SdkBackoffConfig,
 }
 
 impl InternalService {
@@ -169,6 +238,7 @@ impl InternalService {
         crypto_cache: CryptoCache,
         restate_params: crate::restate::RestateServerParams,
         agent_cache: AgentCache,
+        sdk_backoff: SdkBackoffConfig,
     ) -> Self {
         Self {
             mcp_service,
@@ -177,6 +247,34 @@ impl InternalService {
             crypto_cache,
             restate_params,
             agent_cache,
+            agents_synced: AtomicBool::new(false),
+            sdk_backoff,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod unit {
+        use super::super::*;
+
+        #[tokio::test]
+        async fn test_metrics_route_serves_prometheus_text() {
+            shared::setup_test!();
+
+            let response = route_metrics().await.into_response();
+            assert_eq!(response.status(), StatusCode::OK);
+            assert_eq!(
+                response.headers()[header::CONTENT_TYPE],
+                PROMETHEUS_CONTENT_TYPE
+            );
+
+            let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+                .await
+                .unwrap();
+            let text = String::from_utf8(body.to_vec()).unwrap();
+            assert!(text.contains("# TYPE soma_encryption_operations_total counter"));
+            assert!(text.contains("soma_encryption_dek_cache_total{result=\"hit\"}"));
         }
     }
 }
@@ -1,4 +1,8 @@
-use axum::{Router, extract::OriginalUri, middleware};
+use axum::{
+    Router,
+    extract::{DefaultBodyLimit, OriginalUri},
+    middleware,
+};
 use shared::adapters::openapi::API_VERSION_TAG;
 use utoipa::openapi::OpenApi as OpenApiDoc;
 use utoipa::openapi::security::{ApiKey, ApiKeyValue, Http, HttpAuthScheme, SecurityScheme};
@@ -8,10 +12,14 @@ use crate::ApiService;
 use encryption::router::create_router as create_encryption_router;
 use environment::router::create_router as create_environment_router;
 use identity::router::create_router as create_identity_router;
+use identity::router::rate_limit::{RateLimitConfig, RateLimiter, rate_limit};
 use mcp::router::create_router as create_mcp_router;
 use shared::error::CommonError;
+use shared::primitives::{PaginationDefaults, set_pagination_defaults};
+use shared::request_id;
 
 pub(crate) mod agent;
+pub(crate) mod body_logging;
 pub(crate) mod internal;
 pub(crate) mod task;
 
@@ -25,7 +33,79 @@ async fn store_original_uri(
     next.run(request).await
 }
 
+/// Middleware that runs the request with the caller's `x-request-id` as the current request id,
+/// so SDK function invocations it triggers carry the same id.
+async fn propagate_request_id(
+    request: axum::http::Request<axum::body::Body>,
+    next: middleware::Next,
+) -> axum::response::Response {
+    let request_id = request
+        .headers()
+        .get(request_id::REQUEST_ID_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(request_id::sanitize)
+        .map(str::to_string);
+    match request_id {
+        Some(request_id) => request_id::scope(request_id, next.run(request)).await,
+        None => next.run(request).await,
+    }
+}
+
+/// Largest request body the API accepts (axum's default, made explicit so the body logging
+/// middleware buffers no more than the handlers would)
+pub(crate) const REQUEST_BODY_LIMIT_BYTES: usize = 2 * 1024 * 1024;
+
+const RATE_LIMIT_ENV_VAR: &str = "SOMA_RATE_LIMIT_REQUESTS_PER_MINUTE";
+
+fn rate_limit_requests_per_minute() -> Result<Option<u32>, CommonError> {
+    match std::env::var(RATE_LIMIT_ENV_VAR) {
+        Ok(value) => value
+            .trim()
+            .parse()
+            .map(Some)
+            .map_err(|e| CommonError::InvalidRequest {
+                msg: format!("{RATE_LIMIT_ENV_VAR} must be a number of requests per minute"),
+                source: Some(anyhow::Error::new(e)),
+            }),
+        Err(_) => Ok(None),
+    }
+}
+
+const DEFAULT_PAGE_SIZE_ENV_VAR: &str = "SOMA_DEFAULT_PAGE_SIZE";
+const MAX_PAGE_SIZE_ENV_VAR: &str = "SOMA_MAX_PAGE_SIZE";
+
+fn page_size_from_env(var_name: &str) -> Result<Option<i64>, CommonError> {
+    match std::env::var(var_name) {
+        Ok(value) => match value.trim().parse::<i64>() {
+            Ok(page_size) if page_size > 0 => Ok(Some(page_size)),
+            Ok(_) => Err(CommonError::InvalidRequest {
+                msg: format!("{var_name} must be a positive number of items"),
+                source: None,
+            }),
+            Err(e) => Err(CommonError::InvalidRequest {
+                msg: format!("{var_name} must be a positive number of items"),
+                source: Some(anyhow::Error::new(e)),
+            }),
+        },
+        Err(_) => Ok(None),
+    }
+}
+
+/// Pagination defaults with any `SOMA_DEFAULT_PAGE_SIZE` / `SOMA_MAX_PAGE_SIZE` overrides applied
+fn pagination_defaults_from_env() -> Result<PaginationDefaults, CommonError> {
+    let mut defaults = PaginationDefaults::default();
+    if let Some(page_size) = page_size_from_env(DEFAULT_PAGE_SIZE_ENV_VAR)? {
+        defaults.page_size = page_size;
+    }
+    if let Some(max_page_size) = page_size_from_env(MAX_PAGE_SIZE_ENV_VAR)? {
+        defaults.max_page_size = max_page_size;
+    }
+    Ok(defaults)
+}
+
 pub fn initiaite_api_router(api_service: ApiService) -> Result<Router, CommonError> {
+    set_pagination_defaults(pagination_defaults_from_env()?);
+
     let mut router = Router::new();
 
     // agent router
@@ -52,8 +132,9 @@ pub fn initiaite_api_router(api_service: ApiService) -> Result<Router, CommonErr
 
     // internal router
     let (internal_router, _) = internal::create_router().split_for_parts();
-    let internal_router = internal_router.with_state(api_service.internal_service);
+    let internal_router = internal_router.with_state(api_service.internal_service.clone());
     router = router.merge(internal_router);
+    router = router.merge(internal::create_probe_router().with_state(api_service.internal_service));
 
     // encryption router
     let (encryption_router, _) = create_encryption_router().split_for_parts();
@@ -66,12 +147,33 @@ pub fn initiaite_api_router(api_service: ApiService) -> Result<Router, CommonErr
     router = router.merge(environment_router);
 
     // identity router
+    let api_key_cache = api_service.identity_service.api_key_cache.clone();
     let (identity_router, _) = create_identity_router().split_for_parts();
     let identity_router = identity_router.with_state(api_service.identity_service);
     router = router.merge(identity_router);
 
     // Apply middleware to store original URI for nested services (like MCP)
-    let router = router.layer(middleware::from_fn(store_original_uri));
+    let mut router = router
+        .layer(middleware::from_fn(store_original_uri))
+        .layer(middleware::from_fn(propagate_request_id))
+        .layer(DefaultBodyLimit::max(REQUEST_BODY_LIMIT_BYTES));
+
+    // Opt-in per-caller rate limiting (SOMA_RATE_LIMIT_REQUESTS_PER_MINUTE=<n>)
+    if let Some(requests_per_minute) = rate_limit_requests_per_minute()? {
+        let limiter = RateLimiter::new(
+            RateLimitConfig {
+                requests_per_minute,
+                exempt_paths: internal::rate_limit_exempt_paths(),
+            },
+            api_key_cache,
+        );
+        router = router.layer(middleware::from_fn_with_state(limiter, rate_limit));
+    }
+
+    // Opt-in request/response body logging (SOMA_HTTP_BODY_LOGGING=1)
+    if body_logging::body_logging_enabled() {
+        router = router.layer(middleware::from_fn(body_logging::log_http_bodies));
+    }
 
     Ok(router)
 }
@@ -136,3 +238,41 @@ impl Modify for SecurityAddon {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    mod unit {
+        use super::super::*;
+        use axum::{body::Body, http::Request, routing::get};
+        use tower::Service;
+
+        async fn current_request_id(header: Option<&str>) -> String {
+            let mut service = Router::new()
+                .route(
+                    "/id",
+                    get(|| async { request_id::current().unwrap_or_default() }),
+                )
+                .layer(middleware::from_fn(propagate_request_id));
+            let mut request = Request::get("/id");
+            if let Some(header) = header {
+                request = request.header(request_id::REQUEST_ID_HEADER, header);
+            }
+            let response = service
+                .call(request.body(Body::empty()).unwrap())
+                .await
+                .unwrap();
+            let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+                .await
+                .unwrap();
+            String::from_utf8(body.to_vec()).unwrap()
+        }
+
+        #[tokio::test]
+        async fn test_incoming_request_id_is_propagated() {
+            shared::setup_test!();
+
+            assert_eq!(current_request_id(Some("req-123")).await, "req-123");
+            assert_eq!(current_request_id(None).await, "");
+        }
+    }
+}
@@ -1,12 +1,13 @@
+use std::collections::BTreeSet;
 use std::sync::Arc;
 
 use dashmap::DashMap;
 use serde::{Deserialize, Serialize};
-use tracing::{debug, trace};
+use tracing::{debug, trace, warn};
 use utoipa::ToSchema;
 
 /// Metadata for a registered agent
-#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
 pub struct AgentMetadata {
     /// The agent ID
     pub id: String,
@@ -16,6 +17,8 @@ pub struct AgentMetadata {
     pub name: String,
     /// Description of the agent
     pub description: String,
+    /// Provider functions the agent may invoke; empty allows every function
+    pub allowed_functions: Vec<String>,
 }
 
 impl From<sdk_proto::Agent> for AgentMetadata {
@@ -25,6 +28,7 @@ impl From<sdk_proto::Agent> for AgentMetadata {
             project_id: agent.project_id,
             name: agent.name,
             description: agent.description,
+            allowed_functions: agent.allowed_functions,
         }
     }
 }
@@ -69,6 +73,27 @@ pub fn sync_agents_from_metadata(cache: &AgentCache, metadata: &sdk_proto::Metad
     trace!(count = metadata.agents.len(), "Agent sync complete");
 }
 
+/// Replaces the per-agent function allowlists enforced on invocation with the ones declared in
+/// SDK metadata, keyed by the agent's `{project_id}.{agent_id}` service name
+pub fn sync_agent_allowlists_from_metadata(
+    allowlists: &mcp::logic::AgentAllowlists,
+    metadata: &sdk_proto::MetadataResponse,
+) {
+    let entries = metadata
+        .agents
+        .iter()
+        .map(|agent| {
+            (
+                format!("{}.{}", agent.project_id, agent.id),
+                agent.allowed_functions.clone(),
+            )
+        })
+        .collect();
+    if let Err(e) = allowlists.replace(entries) {
+        warn!(error = ?e, "Failed to update agent function allowlists");
+    }
+}
+
 /// Get all agents from the cache as a flat list
 pub fn get_all_agents(cache: &AgentCache) -> Vec<AgentMetadata> {
     let mut agents = Vec::new();
@@ -102,6 +127,49 @@ pub fn get_agents_by_project(cache: &AgentCache, project_id: &str) -> Vec<AgentM
         .unwrap_or_default()
 }
 
+/// Get up to `limit` agents ordered by (project_id, agent_id), starting strictly after the
+/// `after` cursor. Only the ids that can still make the page are kept while scanning, and only
+/// the returned agents are cloned out of the cache.
+pub fn get_agents_page(
+    cache: &AgentCache,
+    after: Option<(&str, &str)>,
+    limit: usize,
+) -> Vec<AgentMetadata> {
+    if limit == 0 {
+        return Vec::new();
+    }
+
+    // The `limit` smallest ids past the cursor seen so far
+    let mut page_ids: BTreeSet<(String, String)> = BTreeSet::new();
+    for project_entry in cache.iter() {
+        let project_id = project_entry.key().as_str();
+        if after.is_some_and(|(after_project, _)| project_id < after_project) {
+            continue;
+        }
+        for agent_entry in project_entry.value().iter() {
+            let id = (project_id, agent_entry.key().as_str());
+            if after.is_some_and(|after| id <= after) {
+                continue;
+            }
+            if page_ids.len() >= limit {
+                let before_last = page_ids
+                    .last()
+                    .is_some_and(|(project, agent)| id < (project.as_str(), agent.as_str()));
+                if !before_last {
+                    continue;
+                }
+                page_ids.pop_last();
+            }
+            page_ids.insert((id.0.to_string(), id.1.to_string()));
+        }
+    }
+
+    page_ids
+        .into_iter()
+        .filter_map(|(project_id, agent_id)| get_agent(cache, &project_id, &agent_id))
+        .collect()
+}
+
 /// Get all agent identifiers from cache as (project_id, agent_id) pairs.
 /// Used to capture state before syncing to detect removed agents.
 pub fn get_all_agent_ids(cache: &AgentCache) -> Vec<(String, String)> {
@@ -1,11 +1,11 @@
 use std::sync::Arc;
 
 use mcp::logic::{
-    PROVIDER_REGISTRY, add_provider_controller_to_registry,
+    FunctionExample, PROVIDER_REGISTRY, add_provider_controller_to_registry,
     remove_provider_controller_from_registry,
 };
 use shared::error::CommonError;
-use shared::primitives::WrappedSchema;
+use shared::primitives::{WrappedJsonValue, WrappedSchema};
 use tracing::{debug, error, trace, warn};
 
 use crate::logic::mcp::providers::dynamic::{
@@ -101,6 +101,14 @@ fn register_provider_from_proto(
                 parameters: parse_schema_string(&f.parameters)?,
                 output: parse_schema_string(&f.output)?,
                 categories: proto_provider.categories.clone(), // Inherit from provider
+                deprecated: f.deprecated,
+                deprecation_message: Some(f.deprecation_message.clone())
+                    .filter(|message| !message.is_empty()),
+                examples: f
+                    .examples
+                    .iter()
+                    .map(parse_function_example)
+                    .collect::<Result<_, _>>()?,
             })
         })
         .collect();
@@ -130,3 +138,127 @@ fn parse_schema_string(schema_str: &str) -> Result<WrappedSchema, CommonError> {
 
     Ok(WrappedSchema::new(schema))
 }
+
+/// Convert a proto example, whose parameters and output are JSON strings, into a FunctionExample
+fn parse_function_example(
+    example: &sdk_proto::FunctionExample,
+) -> Result<FunctionExample, CommonError> {
+    let parse = |json: &str| -> Result<WrappedJsonValue, CommonError> {
+        serde_json::from_str(json)
+            .map(WrappedJsonValue::new)
+            .map_err(|e| {
+                CommonError::Unknown(anyhow::anyhow!("Failed to parse function example: {e}"))
+            })
+    };
+
+    Ok(FunctionExample {
+        description: Some(example.description.clone()).filter(|d| !d.is_empty()),
+        parameters: parse(&example.parameters)?,
+        output: if example.output.is_empty() {
+            None
+        } else {
+            Some(parse(&example.output)?)
+        },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    mod unit {
+        use super::super::*;
+        use mcp::logic::{FunctionControllerSerialized, get_provider_controller};
+
+        fn proto_function(
+            name: &str,
+            deprecated: bool,
+            deprecation_message: &str,
+        ) -> sdk_proto::FunctionController {
+            sdk_proto::FunctionController {
+                name: name.to_string(),
+                description: String::new(),
+                parameters: "{}".to_string(),
+                output: "{}".to_string(),
+                deprecated,
+                deprecation_message: deprecation_message.to_string(),
+                examples: vec![],
+            }
+        }
+
+        #[tokio::test]
+        async fn test_deprecation_metadata_appears_in_function_listing() {
+            shared::setup_test!();
+
+            let type_id = "test-deprecation-provider";
+            register_provider_from_proto(&sdk_proto::ProviderController {
+                type_id: type_id.to_string(),
+                name: "Deprecation Provider".to_string(),
+                documentation: String::new(),
+                categories: vec![],
+                functions: vec![
+                    proto_function("old-function", true, "Use new-function instead"),
+                    proto_function("new-function", false, ""),
+                ],
+                credential_controllers: vec![],
+            })
+            .unwrap();
+
+            let functions: Vec<FunctionControllerSerialized> = get_provider_controller(type_id)
+                .unwrap()
+                .functions()
+                .iter()
+                .map(Into::into)
+                .collect();
+            remove_provider_controller_from_registry(type_id).unwrap();
+
+            let old = functions.iter().find(|f| f.name == "old-function").unwrap();
+            assert!(old.deprecated);
+            assert_eq!(
+                old.deprecation_message.as_deref(),
+                Some("Use new-function instead")
+            );
+
+            let new = functions.iter().find(|f| f.name == "new-function").unwrap();
+            assert!(!new.deprecated);
+            assert_eq!(new.deprecation_message, None);
+        }
+
+        #[tokio::test]
+        async fn test_examples_appear_in_function_listing() {
+            shared::setup_test!();
+
+            let type_id = "test-examples-provider";
+            let mut function = proto_function("send", false, "");
+            function.examples = vec![sdk_proto::FunctionExample {
+                description: "Send to one recipient".to_string(),
+                parameters: r#"{"to":"a@example.com"}"#.to_string(),
+                output: String::new(),
+            }];
+            register_provider_from_proto(&sdk_proto::ProviderController {
+                type_id: type_id.to_string(),
+                name: "Examples Provider".to_string(),
+                documentation: String::new(),
+                categories: vec![],
+                functions: vec![function],
+                credential_controllers: vec![],
+            })
+            .unwrap();
+
+            let functions: Vec<FunctionControllerSerialized> = get_provider_controller(type_id)
+                .unwrap()
+                .functions()
+                .iter()
+                .map(Into::into)
+                .collect();
+            remove_provider_controller_from_registry(type_id).unwrap();
+
+            assert_eq!(
+                functions[0].examples,
+                vec![FunctionExample {
+                    description: Some("Send to one recipient".to_string()),
+                    parameters: WrappedJsonValue::new(serde_json::json!({"to": "a@example.com"})),
+                    output: None,
+                }]
+            );
+        }
+    }
+}
@@ -93,7 +93,7 @@ export interface paths {
 		};
 		/**
 		 * List available agents
-		 * @description List all available agents from the agent cache
+		 * @description List available agents from the agent cache with pagination
 		 */
 		get: operations["list-agents"];
 		put?: never;
@@ -1245,7 +1245,7 @@ export interface paths {
 		};
 		/**
 		 * List function instances
-		 * @description List all function instances with optional filtering by provider instance
+		 * @description List all function instances with optional filtering by provider instance. Supports `If-None-Match` and `If-Modified-Since`, returning 304 when the listing is unchanged
 		 */
 		get: operations["list-function-instances"];
 		put?: never;
@@ -1305,7 +1305,7 @@ export interface paths {
 		};
 		/**
 		 * List MCP server instances
-		 * @description List all MCP server instances with pagination
+		 * @description List all MCP server instances with pagination. Supports `If-None-Match` and `If-Modified-Since`, returning 304 when neither the servers nor their functions changed
 		 */
 		get: operations["list-mcp-server-instances"];
 		put?: never;
@@ -1667,6 +1667,16 @@ export interface components {
 			/** @description The project ID */
 			project_id: string;
 		};
+		AgentListItemPaginatedResponse: {
+			items: components["schemas"]["AgentListItem"][];
+			next_page_token?: string;
+			order: components["schemas"]["PaginationOrder"];
+			/** Format: int64 */
+			page_size: number;
+			prev_page_token?: string;
+			/** Format: int64 */
+			total_count?: number;
+		};
 		BrokerAction:
 			| {
 					Redirect: components["schemas"]["BrokerActionRedirect"];
@@ -1697,6 +1707,12 @@ export interface components {
 		ContextInfoPaginatedResponse: {
 			items: components["schemas"]["ContextInfo"][];
 			next_page_token?: string;
+			order: components["schemas"]["PaginationOrder"];
+			/** Format: int64 */
+			page_size: number;
+			prev_page_token?: string;
+			/** Format: int64 */
+			total_count?: number;
 		};
 		/** @description Parameters for creating an API key */
 		CreateApiKeyParams: {
@@ -1814,6 +1830,12 @@ export interface components {
 		DataEncryptionKeyListItemPaginatedResponse: {
 			items: components["schemas"]["DataEncryptionKeyListItem"][];
 			next_page_token?: string;
+			order: components["schemas"]["PaginationOrder"];
+			/** Format: int64 */
+			page_size: number;
+			prev_page_token?: string;
+			/** Format: int64 */
+			total_count?: number;
 		};
 		/** @description Decrypted secret type for list-decrypted endpoint */
 		DecryptedSecret: {
@@ -1908,17 +1930,61 @@ export interface components {
 			| (components["schemas"]["EnvelopeEncryptionKeyLocal"] & {
 					/** @enum {string} */
 					type: "local";
+			  })
+			| (components["schemas"]["EnvelopeEncryptionKeyGcpKms"] & {
+					/** @enum {string} */
+					type: "gcp_kms";
+			  })
+			| (components["schemas"]["EnvelopeEncryptionKeyVaultTransit"] & {
+					/** @enum {string} */
+					type: "vault_transit";
+			  })
+			| (components["schemas"]["EnvelopeEncryptionKeyAzureKeyVault"] & {
+					/** @enum {string} */
+					type: "azure_key_vault";
 			  });
 		EnvelopeEncryptionKeyAwsKms: {
 			arn: string;
 			region: string;
 		};
+		/**
+		 * @description An Azure Key Vault RSA key. `vault_url` is the vault's base URL, e.g.
+		 *     `https://my-vault.vault.azure.net`, and `key_version` pins the key version DEKs are wrapped with.
+		 */
+		EnvelopeEncryptionKeyAzureKeyVault: {
+			vault_url: string;
+			key_name: string;
+			key_version: string;
+		};
+		/**
+		 * @description A GCP Cloud KMS key. `key_name` is the full resource name of the crypto key
+		 *     (`projects/PROJECT/locations/LOCATION/keyRings/KEY_RING/cryptoKeys/KEY`).
+		 */
+		EnvelopeEncryptionKeyGcpKms: {
+			key_name: string;
+			location: string;
+		};
 		EnvelopeEncryptionKeyLocal: {
 			file_name: string;
 		};
+		/**
+		 * @description A HashiCorp Vault Transit key. `address` is the Vault server URL and `mount` the path the
+		 *     transit secrets engine is mounted at.
+		 */
+		EnvelopeEncryptionKeyVaultTransit: {
+			address: string;
+			mount: string;
+			key_name: string;
+		};
 		EnvelopeEncryptionKeyPaginatedResponse: {
 			items: components["schemas"]["EnvelopeEncryptionKey"][];
 			next_page_token?: string;
+			order: components["schemas"]["PaginationOrder"];
+			/** Format: int64 */
+			page_size: number;
+			prev_page_token?: string;
+			/** Format: int64 */
+			total_count?: number;
 		};
 		Error: {
 			message: string;
@@ -1926,12 +1992,21 @@ export interface components {
 		};
 		FunctionControllerSerialized: {
 			categories: string[];
+			deprecated: boolean;
+			deprecation_message?: string | null;
 			documentation: string;
+			examples: components["schemas"]["FunctionExample"][];
 			name: string;
 			output: components["schemas"]["JsonSchema"];
 			parameters: components["schemas"]["JsonSchema"];
 			type_id: string;
 		};
+		/** @description Example invocation of a function, for documentation in UIs and generated clients */
+		FunctionExample: {
+			description?: string | null;
+			output?: null | components["schemas"]["WrappedJsonValue"];
+			parameters: components["schemas"]["WrappedJsonValue"];
+		};
 		FunctionInstanceConfig: {
 			function_controller: components["schemas"]["FunctionControllerSerialized"];
 			provider_controller: components["schemas"]["ProviderControllerSerialized"];
@@ -1940,6 +2015,12 @@ export interface components {
 		FunctionInstanceConfigPaginatedResponse: {
 			items: components["schemas"]["FunctionInstanceConfig"][];
 			next_page_token?: string;
+			order: components["schemas"]["PaginationOrder"];
+			/** Format: int64 */
+			page_size: number;
+			prev_page_token?: string;
+			/** Format: int64 */
+			total_count?: number;
 		};
 		FunctionInstanceListItem: components["schemas"]["FunctionInstanceSerialized"] & {
 			controller: components["schemas"]["FunctionControllerSerialized"];
@@ -1954,6 +2035,12 @@ export interface components {
 		FunctionInstanceSerializedPaginatedResponse: {
 			items: components["schemas"]["FunctionInstanceSerialized"][];
 			next_page_token?: string;
+			order: components["schemas"]["PaginationOrder"];
+			/** Format: int64 */
+			page_size: number;
+			prev_page_token?: string;
+			/** Format: int64 */
+			total_count?: number;
 		};
 		/** @description Response from getting a user auth flow configuration */
 		GetUserAuthFlowConfigResponse: {
@@ -1977,6 +2064,12 @@ export interface components {
 		GroupMemberWithUserPaginatedResponse: {
 			items: components["schemas"]["GroupMemberWithUser"][];
 			next_page_token?: string;
+			order: components["schemas"]["PaginationOrder"];
+			/** Format: int64 */
+			page_size: number;
+			prev_page_token?: string;
+			/** Format: int64 */
+			total_count?: number;
 		};
 		GroupMembership: {
 			created_at: components["schemas"]["WrappedChronoDateTime"];
@@ -1987,6 +2080,12 @@ export interface components {
 		GroupPaginatedResponse: {
 			items: components["schemas"]["Group"][];
 			next_page_token?: string;
+			order: components["schemas"]["PaginationOrder"];
+			/** Format: int64 */
+			page_size: number;
+			prev_page_token?: string;
+			/** Format: int64 */
+			total_count?: number;
 		};
 		GroupToRoleMapping: {
 			group: string;
@@ -2048,7 +2147,14 @@ export interface components {
 			value: string;
 		};
 		InvokeError: {
+			/** @description Classifies the failure, e.g. `rate_limited` or `retryable`, when the function reported it */
+			code?: string | null;
 			message: string;
+			/**
+			 * Format: int64
+			 * @description Suggested delay before retrying a `rate_limited` or `retryable` failure
+			 */
+			retry_after_ms?: number | null;
 		};
 		InvokeFunctionParamsInner: {
 			params: components["schemas"]["WrappedJsonValue"];
@@ -2083,6 +2189,12 @@ export interface components {
 		JwkResponsePaginatedResponse: {
 			items: components["schemas"]["JwkResponse"][];
 			next_page_token?: string;
+			order: components["schemas"]["PaginationOrder"];
+			/** Format: int64 */
+			page_size: number;
+			prev_page_token?: string;
+			/** Format: int64 */
+			total_count?: number;
 		};
 		JwksResponse: {
 			keys: components["schemas"]["Jwk"][];
@@ -2117,11 +2229,6 @@ export interface components {
 			required_scopes?: string[] | null;
 			valid_audiences?: string[] | null;
 		};
-		/** @description Response for listing agents */
-		ListAgentsResponse: {
-			/** @description List of agents */
-			agents: components["schemas"]["AgentListItem"][];
-		};
 		/** @description Response from listing API keys */
 		ListApiKeysResponse: {
 			items: components["schemas"]["HashedApiKey"][];
@@ -2196,6 +2303,12 @@ export interface components {
 		McpServerInstanceSerializedWithFunctionsPaginatedResponse: {
 			items: components["schemas"]["McpServerInstanceSerializedWithFunctions"][];
 			next_page_token?: string;
+			order: components["schemas"]["PaginationOrder"];
+			/** Format: int64 */
+			page_size: number;
+			prev_page_token?: string;
+			/** Format: int64 */
+			total_count?: number;
 		};
 		Message: {
 			created_at: components["schemas"]["WrappedChronoDateTime"];
@@ -2259,6 +2372,11 @@ export interface components {
 			mapping: components["schemas"]["TokenMapping"];
 			userinfo_endpoint?: string | null;
 		};
+		/**
+		 * @description Order listings return their items in
+		 * @enum {string}
+		 */
+		PaginationOrder: "newest_first" | "name_ascending";
 		ProviderControllerSerialized: {
 			categories: string[];
 			credential_controllers: components["schemas"]["ProviderCredentialControllerSerialized"][];
@@ -2270,6 +2388,12 @@ export interface components {
 		ProviderControllerSerializedPaginatedResponse: {
 			items: components["schemas"]["ProviderControllerSerialized"][];
 			next_page_token?: string;
+			order: components["schemas"]["PaginationOrder"];
+			/** Format: int64 */
+			page_size: number;
+			prev_page_token?: string;
+			/** Format: int64 */
+			total_count?: number;
 		};
 		ProviderCredentialControllerSerialized: {
 			configuration_schema: components["schemas"]["ConfigurationSchema"];
@@ -2288,6 +2412,12 @@ export interface components {
 		ProviderInstanceListItemPaginatedResponse: {
 			items: components["schemas"]["ProviderInstanceListItem"][];
 			next_page_token?: string;
+			order: components["schemas"]["PaginationOrder"];
+			/** Format: int64 */
+			page_size: number;
+			prev_page_token?: string;
+			/** Format: int64 */
+			total_count?: number;
 		};
 		ProviderInstanceSerialized: {
 			created_at: components["schemas"]["WrappedChronoDateTime"];
@@ -2487,6 +2617,12 @@ export interface components {
 		StsTokenConfigPaginatedResponse: {
 			items: components["schemas"]["StsTokenConfig"][];
 			next_page_token?: string;
+			order: components["schemas"]["PaginationOrder"];
+			/** Format: int64 */
+			page_size: number;
+			prev_page_token?: string;
+			/** Format: int64 */
+			total_count?: number;
 		};
 		Task: {
 			context_id: components["schemas"]["WrappedUuidV4"];
@@ -2501,6 +2637,12 @@ export interface components {
 		TaskPaginatedResponse: {
 			items: components["schemas"]["Task"][];
 			next_page_token?: string;
+			order: components["schemas"]["PaginationOrder"];
+			/** Format: int64 */
+			page_size: number;
+			prev_page_token?: string;
+			/** Format: int64 */
+			total_count?: number;
 		};
 		/** @enum {string} */
 		TaskStatus:
@@ -2526,6 +2668,12 @@ export interface components {
 		TaskTimelineItemPaginatedResponse: {
 			items: components["schemas"]["TaskTimelineItem"][];
 			next_page_token?: string;
+			order: components["schemas"]["PaginationOrder"];
+			/** Format: int64 */
+			page_size: number;
+			prev_page_token?: string;
+			/** Format: int64 */
+			total_count?: number;
 		};
 		TaskTimelineItemPayload:
 			| (components["schemas"]["TaskStatusUpdateTaskTimelineItem"] & {
@@ -2673,10 +2821,22 @@ export interface components {
 		UserGroupWithGroupPaginatedResponse: {
 			items: components["schemas"]["UserGroupWithGroup"][];
 			next_page_token?: string;
+			order: components["schemas"]["PaginationOrder"];
+			/** Format: int64 */
+			page_size: number;
+			prev_page_token?: string;
+			/** Format: int64 */
+			total_count?: number;
 		};
 		UserPaginatedResponse: {
 			items: components["schemas"]["User"][];
 			next_page_token?: string;
+			order: components["schemas"]["PaginationOrder"];
+			/** Format: int64 */
+			page_size: number;
+			prev_page_token?: string;
+			/** Format: int64 */
+			total_count?: number;
 		};
 		/** @enum {string} */
 		UserType: "machine" | "human";
@@ -2827,7 +2987,11 @@ export interface operations {
 	};
 	"list-agents": {
 		parameters: {
-			query?: never;
+			query: {
+				/** @description Items per page; zero or negative uses the server default (50), values above the maximum (1000) are capped */
+				page_size: number;
+				next_page_token?: string;
+			};
 			header?: never;
 			path?: never;
 			cookie?: never;
@@ -2840,7 +3004,16 @@ export interface operations {
 					[name: string]: unknown;
 				};
 				content: {
-					"application/json": components["schemas"]["ListAgentsResponse"];
+					"application/json": components["schemas"]["AgentListItemPaginatedResponse"];
+				};
+			};
+			/** @description Bad Request */
+			400: {
+				headers: {
+					[name: string]: unknown;
+				};
+				content: {
+					"application/json": components["schemas"]["Error"];
 				};
 			};
 		};
@@ -2902,6 +3075,24 @@ export interface operations {
 					"application/json": components["schemas"]["AgentCard"];
 				};
 			};
+			/** @description Not Found */
+			404: {
+				headers: {
+					[name: string]: unknown;
+				};
+				content: {
+					"application/json": components["schemas"]["Error"];
+				};
+			};
+			/** @description Internal Server Error */
+			500: {
+				headers: {
+					[name: string]: unknown;
+				};
+				content: {
+					"application/json": components["schemas"]["Error"];
+				};
+			};
 		};
 	};
 	"create-dek-alias": {
@@ -3218,6 +3409,7 @@ export interface operations {
 	"list-envelope-encryption-keys": {
 		parameters: {
 			query: {
+				/** @description Items per page; zero or negative uses the server default (50), values above the maximum (1000) are capped */
 				page_size: number;
 				next_page_token?: string;
 			};
@@ -3757,6 +3949,7 @@ export interface operations {
 	"list-secrets": {
 		parameters: {
 			query: {
+				/** @description Items per page; zero or negative uses the server default (50), values above the maximum (1000) are capped */
 				page_size: number;
 				next_page_token?: string;
 			};
@@ -4004,6 +4197,7 @@ export interface operations {
 	"list-decrypted-secrets": {
 		parameters: {
 			query: {
+				/** @description Items per page; zero or negative uses the server default (50), values above the maximum (1000) are capped */
 				page_size: number;
 				next_page_token?: string;
 			};
@@ -4271,6 +4465,7 @@ export interface operations {
 	"list-variables": {
 		parameters: {
 			query: {
+				/** @description Items per page; zero or negative uses the server default (50), values above the maximum (1000) are capped */
 				page_size: number;
 				next_page_token?: string;
 			};
@@ -5295,6 +5490,7 @@ export interface operations {
 	route_list_api_keys: {
 		parameters: {
 			query: {
+				/** @description Items per page; zero or negative uses the server default (50), values above the maximum (1000) are capped */
 				page_size: number;
 				next_page_token?: string;
 			};
@@ -5980,6 +6176,7 @@ export interface operations {
 	route_list_jwks: {
 		parameters: {
 			query: {
+				/** @description Items per page; zero or negative uses the server default (50), values above the maximum (1000) are capped */
 				page_size: number;
 				next_page_token?: string;
 			};
@@ -6051,6 +6248,7 @@ export interface operations {
 	route_list_sts_configs: {
 		parameters: {
 			query: {
+				/** @description Items per page; zero or negative uses the server default (50), values above the maximum (1000) are capped */
 				page_size: number;
 				next_page_token?: string;
 			};
@@ -6716,6 +6914,7 @@ export interface operations {
 	"list-available-providers": {
 		parameters: {
 			query: {
+				/** @description Items per page; zero or negative uses the server default (50), values above the maximum (1000) are capped */
 				page_size: number;
 				next_page_token?: string;
 			};
@@ -7039,6 +7238,8 @@ export interface operations {
 			query: {
 				page_size: number;
 				next_page_token?: string;
+				/** @description `backward` pages from a `prev_page_token` towards newer function instances */
+				direction?: "forward" | "backward";
 				provider_instance_id?: string;
 			};
 			header?: never;
@@ -7056,6 +7257,13 @@ export interface operations {
 					"application/json": components["schemas"]["FunctionInstanceSerializedPaginatedResponse"];
 				};
 			};
+			/** @description Not Modified */
+			304: {
+				headers: {
+					[name: string]: unknown;
+				};
+				content?: never;
+			};
 			/** @description Bad Request */
 			400: {
 				headers: {
@@ -7173,6 +7381,13 @@ export interface operations {
 					"application/json": components["schemas"]["McpServerInstanceSerializedWithFunctionsPaginatedResponse"];
 				};
 			};
+			/** @description Not Modified */
+			304: {
+				headers: {
+					[name: string]: unknown;
+				};
+				content?: never;
+			};
 			/** @description Internal Server Error */
 			500: {
 				headers: {
@@ -7874,6 +8089,7 @@ export interface operations {
 	"list-tasks": {
 		parameters: {
 			query: {
+				/** @description Items per page; zero or negative uses the server default (50), values above the maximum (1000) are capped */
 				page_size: number;
 				next_page_token?: string;
 			};
@@ -7942,6 +8158,7 @@ export interface operations {
 	"list-contexts": {
 		parameters: {
 			query: {
+				/** @description Items per page; zero or negative uses the server default (50), values above the maximum (1000) are capped */
 				page_size: number;
 				next_page_token?: string;
 			};
@@ -8010,6 +8227,7 @@ export interface operations {
 	"list-tasks-by-context-id": {
 		parameters: {
 			query: {
+				/** @description Items per page; zero or negative uses the server default (50), values above the maximum (1000) are capped */
 				page_size: number;
 				next_page_token?: string;
 			};
@@ -8293,6 +8511,7 @@ export interface operations {
 	"task-history": {
 		parameters: {
 			query: {
+				/** @description Items per page; zero or negative uses the server default (50), values above the maximum (1000) are capped */
 				page_size: number;
 				next_page_token?: string;
 			};
@@ -15,19 +15,25 @@ export function useAgentList(): UseAgentListResult {
 	const { data, isLoading, error, refetch } = $api.useQuery(
 		"get",
 		"/api/agent",
-		{},
+		{
+			params: {
+				query: {
+					page_size: 1000,
+				},
+			},
+		},
 		{
 			// Refetch every 5 seconds while agents list is empty to handle race condition
 			// where frontend loads before agents are registered
 			refetchInterval: (query) => {
-				const agents = query.state.data?.agents;
+				const agents = query.state.data?.items;
 				return !agents || agents.length === 0 ? 5000 : false;
 			},
 		},
 	);
 
 	return {
-		agents: data?.agents || [],
+		agents: data?.items || [],
 		isLoading,
 		error: error ? String(error) : null,
 		refetch,
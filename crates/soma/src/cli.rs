@@ -7,8 +7,9 @@ use tracing::error;
 use crate::{
     commands::{
         self, api_key::ApiKeyParams, auth::AuthParams, codegen::CodegenParams,
-        completions::CompletionShell, dev::DevParams, encryption::EncKeyParams,
-        environment::EnvironmentParams, init::InitParams, secret::SecretParams, sts::StsParams,
+        completions::CompletionShell, db::DbParams, dev::DevParams, encryption::EncKeyParams,
+        environment::EnvironmentParams, init::InitParams, schema::SchemaParams,
+        secret::SecretParams, sts::StsParams,
     },
     utils::get_or_init_cli_config,
 };
@@ -28,6 +29,8 @@ pub enum Commands {
     Dev(DevParams),
     /// Generate mcp client for current project
     Codegen(CodegenParams),
+    /// Manage the local development database
+    Db(DbParams),
     /// Generate shell completions for soma
     Completions {
         /// Shell to generate completions for
@@ -50,6 +53,8 @@ pub enum Commands {
     Auth(AuthParams),
     /// Manage STS (Security Token Service) configurations
     Sts(StsParams),
+    /// Export JSON schemas for provider configurations
+    Schema(SchemaParams),
     /// Show Soma version
     Version,
 }
@@ -77,6 +82,7 @@ pub async fn run_cli(cli: Cli) -> Result<(), anyhow::Error> {
     let cmd_res = match cli.command {
         Commands::Dev(params) => commands::dev::cmd_dev(params, &mut config).await,
         Commands::Codegen(params) => commands::codegen::cmd_codegen(params, &mut config).await,
+        Commands::Db(params) => commands::db::cmd_db(params, &mut config).await,
         Commands::Completions { shell } => commands::completions::cmd_completions(shell),
         Commands::EncKey(params) => commands::encryption::cmd_enc_key(params, &mut config).await,
         Commands::Init(params) => commands::init::cmd_init(params).await,
@@ -87,6 +93,7 @@ pub async fn run_cli(cli: Cli) -> Result<(), anyhow::Error> {
         Commands::ApiKey(params) => commands::api_key::cmd_api_key(params, &mut config).await,
         Commands::Auth(params) => commands::auth::cmd_auth(params, &mut config).await,
         Commands::Sts(params) => commands::sts::cmd_sts(params, &mut config).await,
+        Commands::Schema(params) => commands::schema::cmd_schema(params).await,
         Commands::Version => {
             println!("Soma CLI version: {CLI_VERSION}");
             Ok(())
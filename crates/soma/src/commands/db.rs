@@ -0,0 +1,230 @@
+use std::path::{Path, PathBuf};
+
+use clap::{Args, Subcommand};
+use inquire::Confirm;
+use shared::error::CommonError;
+use tracing::{debug, trace};
+use url::Url;
+
+use crate::utils::{CliConfig, construct_cwd_absolute};
+
+/// Connection string used by `soma dev` and `soma db` when none is given
+pub const DEFAULT_DB_CONN_STRING: &str = "libsql://./.soma/local.db?mode=local";
+/// Prefix of connection strings that point at a path relative to the project directory
+const RELATIVE_DB_CONN_STRING_PREFIX: &str = "libsql://./";
+
+#[derive(Args, Debug, Clone)]
+pub struct DbParams {
+    #[command(subcommand)]
+    pub command: DbCommands,
+}
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum DbCommands {
+    /// Delete the local sqlite DB and re-run all migrations
+    Reset(DbResetParams),
+}
+
+#[derive(Args, Debug, Clone)]
+pub struct DbResetParams {
+    #[arg(long)]
+    pub cwd: Option<PathBuf>,
+    #[arg(long, default_value = DEFAULT_DB_CONN_STRING)]
+    pub db_conn_string: Url,
+    #[arg(long, help = "Reset without asking for confirmation")]
+    pub yes: bool,
+}
+
+pub async fn cmd_db(params: DbParams, _cli_config: &mut CliConfig) -> Result<(), CommonError> {
+    match params.command {
+        DbCommands::Reset(params) => {
+            let project_dir = construct_cwd_absolute(params.cwd)?;
+            let db_conn_string = resolve_db_conn_string(&params.db_conn_string, &project_dir);
+            if !params.yes {
+                let confirmed = Confirm::new("Delete the local DB and re-run all migrations?")
+                    .with_default(false)
+                    .prompt()
+                    .map_err(|e| {
+                        CommonError::Unknown(anyhow::anyhow!("Failed to read input: {e}"))
+                    })?;
+                if !confirmed {
+                    println!("Reset cancelled");
+                    return Ok(());
+                }
+            }
+            let db_path = reset_db(&db_conn_string).await?;
+            println!("Reset local DB at {}", db_path.display());
+            Ok(())
+        }
+    }
+}
+
+/// Resolves a relative `libsql://./` connection string against `project_dir`. Remote and
+/// absolute connection strings are returned as is.
+pub fn resolve_db_conn_string(db_conn_string: &Url, project_dir: &Path) -> Url {
+    let Some(path_with_query) = db_conn_string
+        .as_str()
+        .strip_prefix(RELATIVE_DB_CONN_STRING_PREFIX)
+    else {
+        debug!(
+            "Libsql connection is a remote HTTP connection or an absolute file path, using as is"
+        );
+        return db_conn_string.clone();
+    };
+
+    debug!("Libsql connection is a relative path, resolving to absolute path");
+    let (path_part, query_part) = path_with_query
+        .split_once('?')
+        .unwrap_or((path_with_query, ""));
+
+    let absolute_path = project_dir.join(path_part);
+    let path_str = absolute_path.to_string_lossy();
+    let new_url_str = if query_part.is_empty() {
+        format!("libsql://{path_str}")
+    } else {
+        format!("libsql://{path_str}?{query_part}")
+    };
+
+    debug!("Database path resolved to: {}", absolute_path.display());
+    Url::parse(&new_url_str).unwrap_or_else(|_| db_conn_string.clone())
+}
+
+/// Whether `db_conn_string` points at a path relative to the project directory. `soma dev`
+/// only deletes such DBs for `--clean` / `--fresh`, so a shared DB given by absolute path is
+/// never wiped by a dev flag.
+pub fn is_relative_db_conn_string(db_conn_string: &Url) -> bool {
+    db_conn_string
+        .as_str()
+        .starts_with(RELATIVE_DB_CONN_STRING_PREFIX)
+}
+
+/// Deletes a local sqlite DB along with its WAL and shared-memory files, if present
+pub fn remove_local_db(db_path: &Path) -> Result<(), CommonError> {
+    for suffix in ["", "-wal", "-shm"] {
+        let mut file = db_path.as_os_str().to_owned();
+        file.push(suffix);
+        let file = PathBuf::from(file);
+        if file.exists() {
+            trace!("Deleting local sqlite DB file: {}", file.display());
+            std::fs::remove_file(&file)?;
+        }
+    }
+    Ok(())
+}
+
+/// Deletes the local sqlite DB behind `db_conn_string` and re-runs all migrations against a
+/// new, empty DB. Returns the path of the DB file.
+pub async fn reset_db(db_conn_string: &Url) -> Result<PathBuf, CommonError> {
+    let Some(db_path) = shared::libsql::local_db_file_path(db_conn_string)? else {
+        return Err(CommonError::InvalidRequest {
+            msg: "Only local sqlite DBs (mode=local) can be reset".to_string(),
+            source: None,
+        });
+    };
+
+    remove_local_db(&db_path)?;
+    soma_api_server::repository::setup_repository(db_conn_string, &None).await?;
+    debug!("Local sqlite DB reset at {}", db_path.display());
+
+    Ok(db_path)
+}
+
+#[cfg(test)]
+mod tests {
+    mod unit {
+        use super::super::*;
+
+        async fn count_rows(db_conn_string: &Url, table: &str) -> u64 {
+            let (_db, conn) = shared::libsql::establish_db_connection(db_conn_string, None)
+                .await
+                .unwrap();
+            let mut rows = conn
+                .query(&format!("SELECT COUNT(*) FROM {table}"), ())
+                .await
+                .unwrap();
+            let row = rows.next().await.unwrap().unwrap();
+            row.get::<u64>(0).unwrap()
+        }
+
+        #[test]
+        fn test_relative_conn_string_is_resolved_against_project_dir() {
+            let conn = Url::parse(DEFAULT_DB_CONN_STRING).unwrap();
+            let resolved = resolve_db_conn_string(&conn, Path::new("/tmp/project"));
+            assert_eq!(
+                resolved.as_str(),
+                "libsql:///tmp/project/.soma/local.db?mode=local"
+            );
+
+            let remote = Url::parse("libsql://db.example.com?mode=remote&auth=token").unwrap();
+            assert_eq!(resolve_db_conn_string(&remote, Path::new("/tmp")), remote);
+        }
+
+        #[test]
+        fn test_only_relative_conn_strings_are_relative() {
+            assert!(is_relative_db_conn_string(
+                &Url::parse(DEFAULT_DB_CONN_STRING).unwrap()
+            ));
+            assert!(!is_relative_db_conn_string(
+                &Url::parse("libsql:///var/lib/soma/shared.db?mode=local").unwrap()
+            ));
+            assert!(!is_relative_db_conn_string(
+                &Url::parse("libsql://db.example.com?mode=remote&auth=token").unwrap()
+            ));
+        }
+
+        #[tokio::test]
+        async fn test_reset_reapplies_migrations_on_empty_db() {
+            shared::setup_test!();
+
+            let project_dir = tempfile::tempdir().unwrap();
+            let conn = resolve_db_conn_string(
+                &Url::parse(DEFAULT_DB_CONN_STRING).unwrap(),
+                project_dir.path(),
+            );
+
+            {
+                let (_db, conn, ..) = soma_api_server::repository::setup_repository(&conn, &None)
+                    .await
+                    .unwrap();
+                conn.execute(
+                    "INSERT INTO task (id, context_id, status, metadata) VALUES ('t1', 'c1', 'submitted', '{}')",
+                    (),
+                )
+                .await
+                .unwrap();
+            }
+            assert_eq!(count_rows(&conn, "task").await, 1);
+
+            let db_path = reset_db(&conn).await.unwrap();
+            assert!(db_path.exists());
+            // Tables exist again after migrations ran, but hold no data
+            assert_eq!(count_rows(&conn, "task").await, 0);
+            assert_eq!(count_rows(&conn, "message").await, 0);
+        }
+
+        #[test]
+        fn test_fresh_removes_existing_db() {
+            shared::setup_test!();
+
+            let project_dir = tempfile::tempdir().unwrap();
+            let db_path = project_dir.path().join(".soma/local.db");
+            std::fs::create_dir_all(db_path.parent().unwrap()).unwrap();
+            std::fs::write(&db_path, b"stale").unwrap();
+            std::fs::write(project_dir.path().join(".soma/local.db-wal"), b"stale").unwrap();
+
+            remove_local_db(&db_path).unwrap();
+
+            assert!(!db_path.exists());
+            assert!(!project_dir.path().join(".soma/local.db-wal").exists());
+        }
+
+        #[tokio::test]
+        async fn test_reset_rejects_remote_db() {
+            shared::setup_test!();
+
+            let conn = Url::parse("libsql://db.example.com?mode=remote&auth=token").unwrap();
+            let result = reset_db(&conn).await;
+            assert!(matches!(result, Err(CommonError::InvalidRequest { .. })));
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use std::ops::RangeInclusive;
 use std::path::Path;
 use std::path::PathBuf;
 use std::sync::Arc;
@@ -17,6 +18,9 @@ use shared::error::CommonError;
 use shared::port::find_free_port;
 use shared::soma_agent_definition::{SomaAgentDefinitionLike, YamlSomaAgentDefinition};
 
+use crate::commands::db::{
+    DEFAULT_DB_CONN_STRING, is_relative_db_conn_string, remove_local_db, resolve_db_conn_string,
+};
 use crate::mcp::run_mcp_sync_to_yaml_loop;
 use crate::server::start_axum_server;
 use crate::utils::{CliConfig, construct_cwd_absolute, create_and_wait_for_api_client};
@@ -25,6 +29,15 @@ use soma_api_server::factory::{CreateApiServiceParams, create_api_service};
 use soma_api_server::restate::{
     RestateServerLocalParams, RestateServerParams, RestateServerRemoteParams,
 };
+use soma_api_server::sdk::{SdkRuntime, determine_sdk_runtime};
+
+/// Ports used by a local Restate instance started by `soma dev`
+const LOCAL_RESTATE_INGRESS_PORT: u16 = 8080;
+const LOCAL_RESTATE_ADMIN_PORT: u16 = 9070;
+/// Ports the Soma restate service may listen on, the first free one is used
+const SOMA_RESTATE_SERVICE_PORTS: RangeInclusive<u16> = 9080..=10080;
+/// How many ports above `--port` the API server may fall back to when it is taken
+const API_PORT_FALLBACKS: u16 = 100;
 
 #[derive(Args, Debug, Clone)]
 #[group(multiple = false, required = false)]
@@ -41,7 +54,7 @@ pub struct RemoteRestateParams {
     pub admin_token: Option<String>,
 }
 
-impl TryFrom<RemoteRestateParams> for RestateServerParams {
+impl TryFrom<RemoteRestateParams> for RestateServerRemoteParams {
     type Error = CommonError;
     fn try_from(params: RemoteRestateParams) -> Result<Self, Self::Error> {
         if params.admin_url.is_none() || params.ingress_url.is_none() {
@@ -49,14 +62,14 @@ impl TryFrom<RemoteRestateParams> for RestateServerParams {
                 "Admin URL and ingress URL are required"
             )));
         }
-        Ok(RestateServerParams::Remote(RestateServerRemoteParams {
+        Ok(RestateServerRemoteParams {
             admin_address: params.admin_url.clone().unwrap(),
             ingress_address: params.ingress_url.clone().unwrap(),
             admin_token: params.admin_token,
             // Default to using the ingress address for the soma restate service
             soma_restate_service_address: params.ingress_url.unwrap(),
             soma_restate_service_additional_headers: std::collections::HashMap::new(),
-        }))
+        })
     }
 }
 
@@ -68,7 +81,7 @@ pub struct DevParams {
     pub host: String,
     #[arg(long)]
     pub cwd: Option<PathBuf>,
-    #[arg(long, default_value = "libsql://./.soma/local.db?mode=local")]
+    #[arg(long, default_value = DEFAULT_DB_CONN_STRING)]
     pub db_conn_string: Url,
     #[arg(long)]
     pub db_auth_token: Option<String>,
@@ -77,13 +90,31 @@ pub struct DevParams {
 
     #[arg(
         long,
-        help = "Delete the Restate data directory, local sqlite DB before starting (only applies to local Restate instances and local sqlite DB)"
+        help = "Delete the Restate data directory, local sqlite DB before starting (only applies to local Restate instances and sqlite DBs given by a libsql://./ relative path)"
     )]
     pub clean: bool,
+
+    #[arg(
+        long,
+        help = "Delete the local sqlite DB before starting so migrations run against an empty DB (only applies to libsql://./ relative paths, Restate data is kept)"
+    )]
+    pub fresh: bool,
+
+    #[arg(
+        long,
+        help = "Resolve and print the dev configuration, then exit without starting any server or touching the DB"
+    )]
+    pub dry_run: bool,
 }
 
 /// Main entry point for the start command
 pub async fn cmd_dev(params: DevParams, cli_config: &mut CliConfig) -> Result<(), CommonError> {
+    let plan = plan_dev(&params)?;
+    if params.dry_run {
+        println!("{plan}");
+        return Ok(());
+    }
+
     // Create shutdown notification channel
     let (shutdown_notifier_tx, mut shutdown_notifier_rx) = oneshot::channel();
 
@@ -100,11 +131,11 @@ pub async fn cmd_dev(params: DevParams, cli_config: &mut CliConfig) -> Result<()
     // Wrap in Arc for sharing across tasks (no Mutex needed - interior mutability)
     let process_manager_arc = Arc::new(process_manager);
     let process_manager_arc_for_shutdown = process_manager_arc.clone();
-    let params_clone = params.clone();
     let cli_config_clone = cli_config.clone();
-    let mut cmd_dev_inner_handle = tokio::spawn(async move {
-        cmd_dev_inner(params_clone, &cli_config_clone, process_manager_arc).await
-    });
+    let mut cmd_dev_inner_handle =
+        tokio::spawn(
+            async move { cmd_dev_inner(plan, &cli_config_clone, process_manager_arc).await },
+        );
 
     // Wait for one of: Ctrl+C, cmd_dev_inner to complete/error, or shutdown notification
     let cmd_result: Result<(), CommonError>;
@@ -162,13 +193,13 @@ pub async fn cmd_dev(params: DevParams, cli_config: &mut CliConfig) -> Result<()
     cmd_result
 }
 
-/// Inner implementation of the dev command
+/// Inner implementation of the dev command, carrying out `plan`
 async fn cmd_dev_inner(
-    params: DevParams,
+    plan: DevPlan,
     _cli_config: &CliConfig,
     process_manager: Arc<CustomProcessManager>,
 ) -> Result<(), CommonError> {
-    let project_dir = construct_cwd_absolute(params.clone().cwd)?;
+    let project_dir = plan.project_dir.clone();
 
     debug!(
         "Starting dev server in project directory: {}",
@@ -176,49 +207,23 @@ async fn cmd_dev_inner(
     );
 
     trace!("setting up Libsql database");
-    // Resolve relative db_conn_string paths relative to project_dir
-    let db_conn_string = if params.db_conn_string.as_str().starts_with("libsql://./") {
-        debug!("Libsql connection is a relative path, resolving to absolute path");
-        // Extract the path portion after libsql://./
-        let url_str = params.db_conn_string.as_str();
-        let path_with_query = url_str.strip_prefix("libsql://./").unwrap_or("");
-        let (path_part, query_part) = path_with_query
-            .split_once('?')
-            .unwrap_or((path_with_query, ""));
-
-        // Resolve relative path to absolute path relative to project_dir
-        let absolute_path = project_dir.join(path_part);
-
-        // Reconstruct the URL with absolute path
-        let path_str = absolute_path.to_string_lossy();
-        let new_url_str = if query_part.is_empty() {
-            format!("libsql://{path_str}")
-        } else {
-            format!("libsql://{path_str}?{query_part}")
-        };
-
-        debug!("Database path resolved to: {}", absolute_path.display());
-
-        if params.clean && absolute_path.exists() {
+    let db_conn_string = plan.db_conn_string.clone();
+
+    match &plan.db_to_delete {
+        Some(db_path) => {
+            debug!("--clean or --fresh flag is set, cleaning local sqlite DB");
+            remove_local_db(db_path).inspect_err(|_e| {
+                error!("Failed to clean local sqlite DB");
+            })?;
+            trace!("Local sqlite DB deleted successfully");
+        }
+        None if plan.clean_db => {
             debug!(
-                "Libsql connection is a relative path and --clean flag is set, cleaning local sqlite DB"
+                "Libsql connection is not a local sqlite DB under the project directory, skipping clean"
             );
-            trace!("Deleting local sqlite DB file: {}", absolute_path.display());
-            std::fs::remove_file(absolute_path)
-                .inspect_err(|_e| {
-                    error!("Failed to clean local sqlite DB");
-                })
-                .map_err(CommonError::from)?;
-            trace!("Local sqlite DB file deleted successfully");
         }
-
-        Url::parse(&new_url_str).unwrap_or_else(|_| params.db_conn_string.clone())
-    } else {
-        debug!(
-            "Libsql connection is a remote HTTP connection or an absolute file path, using as is"
-        );
-        params.db_conn_string.clone()
-    };
+        None => {}
+    }
 
     trace!("Libsql database setup complete");
 
@@ -236,49 +241,41 @@ async fn cmd_dev_inner(
 
     trace!("Configuring restate server");
     // Find free port for SDK server
-    let soma_restate_service_port = find_free_port(9080, 10080)?;
+    let soma_restate_service_port = find_free_port(
+        *plan.soma_restate_service_ports.start(),
+        *plan.soma_restate_service_ports.end(),
+    )?;
 
     // Setup Restate parameters
-    let restate_params = match params.remote_restate {
-        Some(remote_restate) => {
+    let restate_params = plan.restate_params(soma_restate_service_port);
+    match &restate_params {
+        RestateServerParams::Remote(remote) => {
             debug!("Configuring remote restate server parameters");
-            debug!("restate admin url: {:?}", remote_restate.admin_url);
-            debug!("restate ingress url: {:?}", remote_restate.ingress_url);
+            debug!("restate admin url: {:?}", remote.admin_address);
+            debug!("restate ingress url: {:?}", remote.ingress_address);
             debug!("restate admin token: **********");
-            remote_restate.try_into()?
         }
-        None => {
-            let restate_server_data_dir = project_dir.join(".soma/restate-data");
-            let ingress_port = 8080;
-            let admin_port = 9070;
+        RestateServerParams::Local(local) => {
             debug!("Configuring local restate server parameters");
             debug!(
                 "restate server data directory: {:?}",
-                restate_server_data_dir
+                local.restate_server_data_dir
             );
             debug!(
                 "restate ingress port (this is where requests to trigger a restate workflow are sent): {:?}",
-                ingress_port
+                local.ingress_port
             );
             debug!(
                 "restate admin port (this is where the restate admin API is exposed): {:?}",
-                admin_port
+                local.admin_port
             );
             debug!(
                 "restate soma restate service port (this is where the Soma SDK restate service is exposed): {:?}",
-                soma_restate_service_port
+                local.soma_restate_service_port
             );
-            debug!("restate clean: {:?}", params.clean);
-            RestateServerParams::Local(RestateServerLocalParams {
-                restate_server_data_dir,
-                ingress_port,
-                admin_port,
-                soma_restate_service_port,
-                soma_restate_service_additional_headers: std::collections::HashMap::new(),
-                clean: params.clean,
-            })
+            debug!("restate clean: {:?}", local.clean);
         }
-    };
+    }
 
     // Start Restate server subsystem
     let mut bar = ProgressBar::new_spinner();
@@ -293,14 +290,16 @@ async fn cmd_dev_inner(
     bar = ProgressBar::new_spinner();
     bar.enable_steady_tick(Duration::from_millis(100));
     bar.set_message("Waiting for API server to start...");
+    let port = find_free_port(*plan.api_ports.start(), *plan.api_ports.end())?;
+    let api_base_url = format!("http://{}:{}", plan.host, port);
     let api_service_bundle = create_api_service(CreateApiServiceParams {
         project_dir: project_dir.clone(),
-        host: params.host.clone(),
-        port: params.port,
-        base_url: format!("http://{}:{}", params.host, params.port),
+        host: plan.host.clone(),
+        port,
+        base_url: api_base_url.clone(),
         soma_restate_service_port,
         db_conn_string: db_conn_string.to_string(),
-        db_auth_token: params.db_auth_token.clone(),
+        db_auth_token: plan.db_auth_token.clone(),
         soma_definition: soma_definition.clone(),
         restate_params: restate_params.clone(),
         process_manager: process_manager.clone(),
@@ -344,8 +343,8 @@ async fn cmd_dev_inner(
     // Start Axum server subsystem
     start_axum_server(crate::server::StartAxumServerParams {
         api_service: api_service.clone(),
-        host: params.host.clone(),
-        port: params.port,
+        host: plan.host.clone(),
+        port,
         process_manager: process_manager.clone(),
     })
     .await
@@ -354,7 +353,6 @@ async fn cmd_dev_inner(
     })?;
 
     // Create API client configuration for the soma API server and exchange STS token
-    let api_base_url = format!("http://{}:{}", params.host, params.port);
     bar = ProgressBar::new_spinner();
     bar.enable_steady_tick(Duration::from_millis(100));
     bar.set_message("Synchronizing soma.yaml on server start");
@@ -402,6 +400,171 @@ async fn cmd_dev_inner(
     Ok(())
 }
 
+/// Configuration `soma dev` resolves before starting anything. `--dry-run` prints it,
+/// otherwise `cmd_dev_inner` carries it out.
+#[derive(Debug)]
+struct DevPlan {
+    project_dir: PathBuf,
+    host: String,
+    /// Ports the API server may listen on, the first free one is used
+    api_ports: RangeInclusive<u16>,
+    db_conn_string: Url,
+    db_auth_token: Option<String>,
+    /// Whether `--clean` or `--fresh` asked for the DB to be deleted
+    clean_db: bool,
+    /// Path of the local sqlite DB that `--clean` / `--fresh` deletes, only set for
+    /// `libsql://./` relative connection strings
+    db_to_delete: Option<PathBuf>,
+    sdk_runtime: SdkRuntime,
+    sdk_socket_path: &'static str,
+    soma_restate_service_ports: RangeInclusive<u16>,
+    restate: RestatePlan,
+    /// Files and directories under `agents/`, sorted
+    agents: Vec<String>,
+}
+
+/// Restate instance `soma dev` uses
+#[derive(Debug)]
+enum RestatePlan {
+    Local {
+        restate_server_data_dir: PathBuf,
+        ingress_port: u16,
+        admin_port: u16,
+        clean: bool,
+    },
+    Remote(RestateServerRemoteParams),
+}
+
+impl DevPlan {
+    /// Restate parameters once the Soma restate service port has been picked
+    fn restate_params(&self, soma_restate_service_port: u16) -> RestateServerParams {
+        match &self.restate {
+            RestatePlan::Local {
+                restate_server_data_dir,
+                ingress_port,
+                admin_port,
+                clean,
+            } => RestateServerParams::Local(RestateServerLocalParams {
+                restate_server_data_dir: restate_server_data_dir.clone(),
+                ingress_port: *ingress_port,
+                admin_port: *admin_port,
+                soma_restate_service_port,
+                soma_restate_service_additional_headers: std::collections::HashMap::new(),
+                clean: *clean,
+            }),
+            RestatePlan::Remote(remote) => RestateServerParams::Remote(remote.clone()),
+        }
+    }
+}
+
+/// Resolves the dev configuration without binding ports, starting processes or modifying the
+/// DB.
+fn plan_dev(params: &DevParams) -> Result<DevPlan, CommonError> {
+    let project_dir = construct_cwd_absolute(params.cwd.clone())?;
+    // Resolve relative db_conn_string paths relative to project_dir
+    let db_conn_string = resolve_db_conn_string(&params.db_conn_string, &project_dir);
+    let clean_db = params.clean || params.fresh;
+    let db_to_delete = if clean_db && is_relative_db_conn_string(&params.db_conn_string) {
+        shared::libsql::local_db_file_path(&db_conn_string)?
+    } else {
+        None
+    };
+
+    // Fails the same way `soma dev` would on a missing or invalid soma.yaml
+    load_soma_definition(&project_dir)?;
+
+    let sdk_runtime = determine_sdk_runtime(&project_dir)?.ok_or_else(|| {
+        CommonError::Unknown(anyhow::anyhow!(
+            "No SDK runtime matched in {}",
+            project_dir.display()
+        ))
+    })?;
+
+    let restate = match params.remote_restate.clone() {
+        Some(remote_restate) => RestatePlan::Remote(remote_restate.try_into()?),
+        None => RestatePlan::Local {
+            restate_server_data_dir: project_dir.join(".soma/restate-data"),
+            ingress_port: LOCAL_RESTATE_INGRESS_PORT,
+            admin_port: LOCAL_RESTATE_ADMIN_PORT,
+            clean: params.clean,
+        },
+    };
+
+    let mut agents = match std::fs::read_dir(project_dir.join("agents")) {
+        Ok(entries) => entries
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.file_name().to_string_lossy().into_owned())
+            .collect(),
+        Err(_) => vec![],
+    };
+    agents.sort();
+
+    Ok(DevPlan {
+        project_dir,
+        host: params.host.clone(),
+        api_ports: params.port..=params.port.saturating_add(API_PORT_FALLBACKS),
+        db_conn_string,
+        db_auth_token: params.db_auth_token.clone(),
+        clean_db,
+        db_to_delete,
+        sdk_runtime,
+        sdk_socket_path: shared::uds::DEFAULT_SOMA_SERVER_SOCK,
+        soma_restate_service_ports: SOMA_RESTATE_SERVICE_PORTS,
+        restate,
+        agents,
+    })
+}
+
+impl std::fmt::Display for DevPlan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Project directory: {}", self.project_dir.display())?;
+        writeln!(
+            f,
+            "API server: http://{}:<first free port in {}-{}>",
+            self.host,
+            self.api_ports.start(),
+            self.api_ports.end()
+        )?;
+        writeln!(f, "Database: {}", self.db_conn_string)?;
+        if let Some(db_path) = &self.db_to_delete {
+            writeln!(f, "Database to delete before start: {}", db_path.display())?;
+        }
+        writeln!(f, "SDK runtime: {:?}", self.sdk_runtime)?;
+        writeln!(f, "SDK socket: {}", self.sdk_socket_path)?;
+        match &self.restate {
+            RestatePlan::Local {
+                restate_server_data_dir,
+                ingress_port,
+                admin_port,
+                clean,
+            } => {
+                writeln!(f, "Restate: local")?;
+                writeln!(f, "  data directory: {}", restate_server_data_dir.display())?;
+                writeln!(f, "  ingress port: {ingress_port}")?;
+                writeln!(f, "  admin port: {admin_port}")?;
+                writeln!(f, "  clean: {clean}")?;
+            }
+            RestatePlan::Remote(remote) => {
+                writeln!(f, "Restate: remote")?;
+                writeln!(f, "  admin url: {}", remote.admin_address)?;
+                writeln!(f, "  ingress url: {}", remote.ingress_address)?;
+                writeln!(f, "  admin token set: {}", remote.admin_token.is_some())?;
+            }
+        }
+        writeln!(
+            f,
+            "Soma restate service port: first free port in {}-{}",
+            self.soma_restate_service_ports.start(),
+            self.soma_restate_service_ports.end()
+        )?;
+        if self.agents.is_empty() {
+            write!(f, "Agents: none found under agents/")
+        } else {
+            write!(f, "Agents: {}", self.agents.join(", "))
+        }
+    }
+}
+
 /// Loads the soma definition from the source directory
 fn load_soma_definition(
     project_dir: &Path,
@@ -463,3 +626,118 @@ async fn enable_dev_mode_sts(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    mod unit {
+        use super::super::*;
+
+        fn dry_run_params(project_dir: &Path, port: u16) -> DevParams {
+            DevParams {
+                port,
+                host: "127.0.0.1".to_string(),
+                cwd: Some(project_dir.to_path_buf()),
+                db_conn_string: Url::parse(DEFAULT_DB_CONN_STRING).unwrap(),
+                db_auth_token: None,
+                remote_restate: None,
+                clean: false,
+                fresh: true,
+                dry_run: true,
+            }
+        }
+
+        #[test]
+        fn test_dry_run_resolves_config_without_binding_or_deleting() {
+            shared::setup_test!();
+
+            let project_dir = tempfile::tempdir().unwrap();
+            std::fs::write(project_dir.path().join("soma.yaml"), "{}\n").unwrap();
+            std::fs::write(project_dir.path().join("pyproject.toml"), "").unwrap();
+            std::fs::create_dir_all(project_dir.path().join("agents")).unwrap();
+            std::fs::write(project_dir.path().join("agents/support.py"), "").unwrap();
+            let db_path = project_dir.path().join(".soma/local.db");
+            std::fs::create_dir_all(db_path.parent().unwrap()).unwrap();
+            std::fs::write(&db_path, b"keep").unwrap();
+
+            let port = std::net::TcpListener::bind("127.0.0.1:0")
+                .unwrap()
+                .local_addr()
+                .unwrap()
+                .port();
+
+            let plan = plan_dev(&dry_run_params(project_dir.path(), port)).unwrap();
+
+            // The API server falls back to later ports the same way `soma dev` does
+            assert_eq!(
+                plan.api_ports,
+                port..=port.saturating_add(API_PORT_FALLBACKS)
+            );
+            assert_eq!(plan.soma_restate_service_ports, SOMA_RESTATE_SERVICE_PORTS);
+            assert_eq!(plan.sdk_runtime, SdkRuntime::Python);
+            assert_eq!(plan.agents, vec!["support.py".to_string()]);
+            assert!(plan.clean_db);
+            assert!(plan.db_to_delete.is_some());
+            // --fresh is only reported, the DB is left alone
+            assert_eq!(std::fs::read(&db_path).unwrap(), b"keep");
+            // Nothing is listening on the API port
+            assert!(std::net::TcpListener::bind(("127.0.0.1", port)).is_ok());
+        }
+
+        #[test]
+        fn test_dry_run_fails_without_soma_yaml() {
+            shared::setup_test!();
+
+            let project_dir = tempfile::tempdir().unwrap();
+            std::fs::write(project_dir.path().join("pyproject.toml"), "").unwrap();
+
+            assert!(plan_dev(&dry_run_params(project_dir.path(), 3000)).is_err());
+        }
+
+        #[test]
+        fn test_plan_restate_params_use_picked_service_port() {
+            shared::setup_test!();
+
+            let project_dir = tempfile::tempdir().unwrap();
+            std::fs::write(project_dir.path().join("soma.yaml"), "{}\n").unwrap();
+            std::fs::write(project_dir.path().join("pyproject.toml"), "").unwrap();
+
+            let plan = plan_dev(&dry_run_params(project_dir.path(), 3000)).unwrap();
+
+            match plan.restate_params(9123) {
+                RestateServerParams::Local(local) => {
+                    assert_eq!(local.soma_restate_service_port, 9123);
+                    assert_eq!(local.ingress_port, LOCAL_RESTATE_INGRESS_PORT);
+                    assert_eq!(local.admin_port, LOCAL_RESTATE_ADMIN_PORT);
+                    assert_eq!(
+                        local.restate_server_data_dir,
+                        plan.project_dir.join(".soma/restate-data")
+                    );
+                }
+                RestateServerParams::Remote(_) => panic!("expected a local Restate instance"),
+            }
+        }
+
+        #[test]
+        fn test_fresh_never_deletes_absolute_path_db() {
+            shared::setup_test!();
+
+            let project_dir = tempfile::tempdir().unwrap();
+            std::fs::write(project_dir.path().join("soma.yaml"), "{}\n").unwrap();
+            std::fs::write(project_dir.path().join("pyproject.toml"), "").unwrap();
+            let shared_db = tempfile::tempdir().unwrap();
+
+            let mut params = dry_run_params(project_dir.path(), 3000);
+            params.clean = true;
+            params.db_conn_string = Url::parse(&format!(
+                "libsql://{}?mode=local",
+                shared_db.path().join("shared.db").display()
+            ))
+            .unwrap();
+
+            let plan = plan_dev(&params).unwrap();
+
+            assert!(plan.clean_db);
+            assert_eq!(plan.db_to_delete, None);
+        }
+    }
+}
@@ -1,4 +1,5 @@
 use clap::{Args, Subcommand};
+use encryption::logic::envelope::{azure_key_vault_key_id, vault_transit_key_id};
 use shared::error::CommonError;
 use soma_api_client::apis::encryption_api;
 use soma_api_client::models;
@@ -34,9 +35,11 @@ pub enum EncKeyCommands {
     },
     /// Migrate all DEKs from one envelope encryption key to another
     Migrate {
-        /// Source encryption key ID (ARN for AWS, file name for local)
+        /// Source encryption key ID (ARN for AWS, key name for GCP, key URL for Vault or Azure
+        /// Key Vault, file name for local)
         from: String,
-        /// Target encryption key ID (ARN for AWS, file name for local)
+        /// Target encryption key ID (ARN for AWS, key name for GCP, key URL for Vault or Azure
+        /// Key Vault, file name for local)
         to: String,
     },
 }
@@ -58,6 +61,39 @@ pub enum AddKeyType {
         #[arg(long)]
         file_name: String,
     },
+    /// Add a GCP Cloud KMS encryption key
+    Gcp {
+        /// Cloud KMS key resource name (projects/.../keyRings/.../cryptoKeys/...)
+        #[arg(long)]
+        key_name: String,
+        /// GCP location of the key ring
+        #[arg(long)]
+        location: String,
+    },
+    /// Add a HashiCorp Vault Transit encryption key (requests use the VAULT_TOKEN env var)
+    Vault {
+        /// Vault server address, e.g. https://vault.example.com:8200
+        #[arg(long)]
+        address: String,
+        /// Path the transit secrets engine is mounted at
+        #[arg(long, default_value = "transit")]
+        mount: String,
+        /// Transit key name
+        #[arg(long)]
+        key_name: String,
+    },
+    /// Add an Azure Key Vault RSA key (credentials come from the default Azure credential chain)
+    Azure {
+        /// Key Vault URL, e.g. https://my-vault.vault.azure.net
+        #[arg(long)]
+        vault_url: String,
+        /// Key name
+        #[arg(long)]
+        key_name: String,
+        /// Key version DEKs are wrapped with
+        #[arg(long)]
+        key_version: String,
+    },
 }
 
 #[derive(Subcommand, Debug, Clone)]
@@ -72,6 +108,33 @@ pub enum RmKeyType {
         /// Local key file name (relative to cwd / project root, used as ID)
         file_name: String,
     },
+    /// Remove a GCP Cloud KMS encryption key
+    Gcp {
+        /// Cloud KMS key resource name (used as ID)
+        key_name: String,
+    },
+    /// Remove a HashiCorp Vault Transit encryption key
+    Vault {
+        /// Transit key name
+        key_name: String,
+        /// Vault server address
+        #[arg(long)]
+        address: String,
+        /// Path the transit secrets engine is mounted at
+        #[arg(long, default_value = "transit")]
+        mount: String,
+    },
+    /// Remove an Azure Key Vault key
+    Azure {
+        /// Key name
+        key_name: String,
+        /// Key Vault URL
+        #[arg(long)]
+        vault_url: String,
+        /// Key version
+        #[arg(long)]
+        key_version: String,
+    },
 }
 
 pub async fn cmd_enc_key(
@@ -91,6 +154,21 @@ pub async fn cmd_enc_key(
     }
 }
 
+/// The ID the API uses for an envelope encryption key
+fn envelope_key_id(key: &models::EnvelopeEncryptionKey) -> String {
+    match key {
+        models::EnvelopeEncryptionKey::EnvelopeEncryptionKeyOneOf(key) => key.arn.clone(),
+        models::EnvelopeEncryptionKey::EnvelopeEncryptionKeyOneOf1(key) => key.file_name.clone(),
+        models::EnvelopeEncryptionKey::EnvelopeEncryptionKeyOneOf2(key) => key.key_name.clone(),
+        models::EnvelopeEncryptionKey::EnvelopeEncryptionKeyOneOf3(key) => {
+            vault_transit_key_id(&key.address, &key.mount, &key.key_name)
+        }
+        models::EnvelopeEncryptionKey::EnvelopeEncryptionKeyOneOf4(key) => {
+            azure_key_vault_key_id(&key.vault_url, &key.key_name, &key.key_version)
+        }
+    }
+}
+
 /// Check if a DEK alias exists
 async fn default_alias_exists(
     api_config: &soma_api_client::apis::configuration::Configuration,
@@ -176,12 +254,7 @@ pub async fn cmd_enc_key_add(
                         ))
                     })?;
 
-            let envelope_id = match &created_key {
-                models::EnvelopeEncryptionKey::EnvelopeEncryptionKeyOneOf(key) => key.arn.clone(),
-                models::EnvelopeEncryptionKey::EnvelopeEncryptionKeyOneOf1(key) => {
-                    key.file_name.clone()
-                }
-            };
+            let envelope_id = envelope_key_id(&created_key);
 
             debug!(
                 "Successfully created envelope encryption key: {}",
@@ -221,12 +294,140 @@ pub async fn cmd_enc_key_add(
                         ))
                     })?;
 
-            let envelope_id = match &created_key {
-                models::EnvelopeEncryptionKey::EnvelopeEncryptionKeyOneOf(key) => key.arn.clone(),
-                models::EnvelopeEncryptionKey::EnvelopeEncryptionKeyOneOf1(key) => {
-                    key.file_name.clone()
-                }
-            };
+            let envelope_id = envelope_key_id(&created_key);
+
+            debug!(
+                "Successfully created envelope encryption key: {}",
+                envelope_id
+            );
+
+            // If no default alias exists, create a DEK and set it as default
+            if !has_default_alias {
+                debug!("No default DEK alias found, creating default DEK...");
+                create_default_dek(&api_config, &envelope_id).await?;
+            } else {
+                debug!("Default DEK alias already exists, skipping DEK creation");
+            }
+
+            Ok(())
+        }
+        AddKeyType::Gcp { key_name, location } => {
+            debug!(
+                "Adding GCP KMS envelope encryption key: {} in location {}",
+                key_name, location
+            );
+
+            // Create the envelope encryption key using the API client
+            let envelope_key = models::EnvelopeEncryptionKey::EnvelopeEncryptionKeyOneOf2(
+                models::EnvelopeEncryptionKeyOneOf2::new(
+                    key_name.clone(),
+                    location.clone(),
+                    models::envelope_encryption_key_one_of_2::Type::GcpKms,
+                ),
+            );
+
+            let created_key =
+                encryption_api::create_envelope_encryption_key(&api_config, envelope_key)
+                    .await
+                    .map_err(|e| {
+                        CommonError::Unknown(anyhow::anyhow!(
+                            "Failed to create envelope encryption key: {e:?}"
+                        ))
+                    })?;
+
+            let envelope_id = envelope_key_id(&created_key);
+
+            debug!(
+                "Successfully created envelope encryption key: {}",
+                envelope_id
+            );
+
+            // If no default alias exists, create a DEK and set it as default
+            if !has_default_alias {
+                debug!("No default DEK alias found, creating default DEK...");
+                create_default_dek(&api_config, &envelope_id).await?;
+            } else {
+                debug!("Default DEK alias already exists, skipping DEK creation");
+            }
+
+            Ok(())
+        }
+        AddKeyType::Vault {
+            address,
+            mount,
+            key_name,
+        } => {
+            debug!(
+                "Adding Vault Transit envelope encryption key: {} on {} (mount {})",
+                key_name, address, mount
+            );
+
+            // Create the envelope encryption key using the API client
+            let envelope_key = models::EnvelopeEncryptionKey::EnvelopeEncryptionKeyOneOf3(
+                models::EnvelopeEncryptionKeyOneOf3::new(
+                    address.clone(),
+                    mount.clone(),
+                    key_name.clone(),
+                    models::envelope_encryption_key_one_of_3::Type::VaultTransit,
+                ),
+            );
+
+            let created_key =
+                encryption_api::create_envelope_encryption_key(&api_config, envelope_key)
+                    .await
+                    .map_err(|e| {
+                        CommonError::Unknown(anyhow::anyhow!(
+                            "Failed to create envelope encryption key: {e:?}"
+                        ))
+                    })?;
+
+            let envelope_id = envelope_key_id(&created_key);
+
+            debug!(
+                "Successfully created envelope encryption key: {}",
+                envelope_id
+            );
+
+            // If no default alias exists, create a DEK and set it as default
+            if !has_default_alias {
+                debug!("No default DEK alias found, creating default DEK...");
+                create_default_dek(&api_config, &envelope_id).await?;
+            } else {
+                debug!("Default DEK alias already exists, skipping DEK creation");
+            }
+
+            Ok(())
+        }
+        AddKeyType::Azure {
+            vault_url,
+            key_name,
+            key_version,
+        } => {
+            debug!(
+                "Adding Azure Key Vault envelope encryption key: {} (version {}) in {}",
+                key_name, key_version, vault_url
+            );
+
+            // Create the envelope encryption key using the API client
+            let envelope_key = models::EnvelopeEncryptionKey::EnvelopeEncryptionKeyOneOf4(
+                models::EnvelopeEncryptionKeyOneOf4::new(
+                    vault_url.clone(),
+                    key_name.clone(),
+                    key_version.clone(),
+                    models::envelope_encryption_key_one_of_4::Type::AzureKeyVault,
+                ),
+            );
+
+            let created_key =
+                encryption_api::create_envelope_encryption_key(&api_config, envelope_key)
+                    .await
+                    .map_err(|e| {
+                        CommonError::Unknown(anyhow::anyhow!(
+                            "Failed to create envelope encryption key: {e:?}"
+                        ))
+                    })?;
+
+            let envelope_id = envelope_key_id(&created_key);
 
             debug!(
                 "Successfully created envelope encryption key: {}",
@@ -263,6 +464,26 @@ pub async fn cmd_enc_key_rm(
             debug!("Checking local encryption key at file name: {}", file_name);
             file_name
         }
+        RmKeyType::Gcp { key_name } => {
+            debug!("Checking GCP KMS encryption key: {}", key_name);
+            key_name
+        }
+        RmKeyType::Vault {
+            key_name,
+            address,
+            mount,
+        } => {
+            debug!("Checking Vault Transit encryption key: {}", key_name);
+            vault_transit_key_id(&address, &mount, &key_name)
+        }
+        RmKeyType::Azure {
+            key_name,
+            vault_url,
+            key_version,
+        } => {
+            debug!("Checking Azure Key Vault encryption key: {}", key_name);
+            azure_key_vault_key_id(&vault_url, &key_name, &key_version)
+        }
     };
 
     // List DEKs tied to this envelope key
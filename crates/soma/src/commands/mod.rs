@@ -2,9 +2,11 @@ pub mod api_key;
 pub mod auth;
 pub mod codegen;
 pub mod completions;
+pub mod db;
 pub mod dev;
 pub mod encryption;
 pub mod environment;
 pub mod init;
+pub mod schema;
 pub mod secret;
 pub mod sts;
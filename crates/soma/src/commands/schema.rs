@@ -0,0 +1,130 @@
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use clap::{Args, Subcommand};
+use mcp::logic::{PROVIDER_REGISTRY, ProviderControllerLike, register_all_mcp_providers};
+use shared::error::CommonError;
+use tracing::{debug, trace};
+
+#[derive(Args, Debug, Clone)]
+pub struct SchemaParams {
+    #[command(subcommand)]
+    pub command: SchemaCommands,
+}
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum SchemaCommands {
+    /// Write the credential configuration schemas of every provider as JSON files
+    Export(SchemaExportParams),
+}
+
+#[derive(Args, Debug, Clone)]
+pub struct SchemaExportParams {
+    /// Directory to write the schemas to, created if missing
+    #[arg(long)]
+    pub out: PathBuf,
+}
+
+pub async fn cmd_schema(params: SchemaParams) -> Result<(), CommonError> {
+    match params.command {
+        SchemaCommands::Export(params) => {
+            let files = export_schemas(&params.out).await?;
+            println!(
+                "Exported {} schema files to {}",
+                files.len(),
+                params.out.display()
+            );
+            Ok(())
+        }
+    }
+}
+
+/// Exports the schemas of every registered provider, registering the built-in providers first
+/// when nothing has been registered yet
+pub async fn export_schemas(out_dir: &Path) -> Result<Vec<PathBuf>, CommonError> {
+    let registry_is_empty = PROVIDER_REGISTRY
+        .read()
+        .map_err(|_e| CommonError::Unknown(anyhow::anyhow!("Poison error")))?
+        .is_empty();
+    if registry_is_empty {
+        register_all_mcp_providers().await?;
+    }
+
+    let providers = PROVIDER_REGISTRY
+        .read()
+        .map_err(|_e| CommonError::Unknown(anyhow::anyhow!("Poison error")))?
+        .clone();
+    export_provider_schemas(&providers, out_dir)
+}
+
+/// Writes `<out_dir>/<provider_type_id>/<credential_controller_type_id>.json` for every credential
+/// controller of every provider, holding its resource server and user credential schemas.
+/// Returns the files written.
+pub fn export_provider_schemas(
+    providers: &[Arc<dyn ProviderControllerLike>],
+    out_dir: &Path,
+) -> Result<Vec<PathBuf>, CommonError> {
+    let mut files = Vec::new();
+    for provider in providers {
+        let provider_dir = out_dir.join(provider.type_id());
+        std::fs::create_dir_all(&provider_dir)?;
+
+        for credential_controller in provider.credential_controllers() {
+            let file = provider_dir.join(format!("{}.json", credential_controller.type_id()));
+            let schema =
+                serde_json::to_string_pretty(&credential_controller.configuration_schema())
+                    .map_err(|e| {
+                        CommonError::Unknown(anyhow::anyhow!(
+                            "Failed to serialize schema for {}/{}: {e}",
+                            provider.type_id(),
+                            credential_controller.type_id()
+                        ))
+                    })?;
+            trace!(file = %file.display(), "Writing schema");
+            std::fs::write(&file, schema)?;
+            files.push(file);
+        }
+    }
+
+    debug!(count = files.len(), "Exported provider schemas");
+    Ok(files)
+}
+
+#[cfg(test)]
+mod tests {
+    mod unit {
+        use super::super::*;
+
+        #[tokio::test]
+        async fn test_export_writes_valid_json_for_every_credential_controller() {
+            let dir = tempfile::tempdir().unwrap();
+            let out_dir = dir.path().join("schemas");
+
+            let files = export_schemas(&out_dir).await.unwrap();
+            assert!(!files.is_empty());
+
+            let providers = PROVIDER_REGISTRY.read().unwrap().clone();
+            let expected: usize = providers
+                .iter()
+                .map(|p| p.credential_controllers().len())
+                .sum();
+            assert_eq!(files.len(), expected);
+
+            for file in &files {
+                assert!(file.starts_with(&out_dir));
+                let contents = std::fs::read_to_string(file).unwrap();
+                let schema: serde_json::Value = serde_json::from_str(&contents).unwrap();
+                assert!(
+                    schema.get("resource_server").is_some(),
+                    "{}",
+                    file.display()
+                );
+                assert!(
+                    schema.get("user_credential").is_some(),
+                    "{}",
+                    file.display()
+                );
+            }
+        }
+    }
+}
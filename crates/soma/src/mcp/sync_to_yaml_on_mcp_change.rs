@@ -11,8 +11,10 @@ use tracing::{debug, trace, warn};
 use shared::error::CommonError;
 use shared::soma_agent_definition::{
     ApiKeyYamlConfig, EncryptedOauthYamlConfig, EncryptedOidcYamlConfig, EnvelopeKeyConfig,
-    EnvelopeKeyConfigAwsKms, EnvelopeKeyConfigLocal, McpServerConfig, McpServerFunctionConfig,
-    SecretConfig, SomaAgentDefinitionLike, StsConfigYaml, UserAuthFlowYamlConfig,
+    EnvelopeKeyConfigAwsKms, EnvelopeKeyConfigAzureKeyVault, EnvelopeKeyConfigGcpKms,
+    EnvelopeKeyConfigLocal, EnvelopeKeyConfigVaultTransit, McpServerConfig,
+    McpServerFunctionConfig, SecretConfig, SomaAgentDefinitionLike, StsConfigYaml,
+    UserAuthFlowYamlConfig,
 };
 use soma_api_server::logic::on_change_pubsub::{
     SecretChangeEvt, SomaChangeEvt, SomaChangeRx, VariableChangeEvt,
@@ -367,6 +369,29 @@ async fn handle_encryption_event(
                         deks: None,
                     })
                 }
+                EnvelopeEncryptionKey::GcpKms(gcp_kms) => {
+                    EnvelopeKeyConfig::GcpKms(EnvelopeKeyConfigGcpKms {
+                        key_name: gcp_kms.key_name.clone(),
+                        location: gcp_kms.location.clone(),
+                        deks: None,
+                    })
+                }
+                EnvelopeEncryptionKey::VaultTransit(vault) => {
+                    EnvelopeKeyConfig::VaultTransit(EnvelopeKeyConfigVaultTransit {
+                        address: vault.address.clone(),
+                        mount: vault.mount.clone(),
+                        key_name: vault.key_name.clone(),
+                        deks: None,
+                    })
+                }
+                EnvelopeEncryptionKey::AzureKeyVault(azure) => {
+                    EnvelopeKeyConfig::AzureKeyVault(EnvelopeKeyConfigAzureKeyVault {
+                        vault_url: azure.vault_url.clone(),
+                        key_name: azure.key_name.clone(),
+                        key_version: azure.key_version.clone(),
+                        deks: None,
+                    })
+                }
             };
             soma_definition.add_envelope_key(key_id, config).await?;
         }
@@ -622,6 +647,26 @@ async fn handle_secret_event(
             debug!("Secret deleted: {:?}", key);
             soma_definition.remove_secret(key).await?;
         }
+        SecretChangeEvt::BatchUpdated(secrets) => {
+            debug!("Secrets batch updated: {} secrets", secrets.len());
+            let definition = soma_definition.get_definition().await?;
+            for secret in secrets {
+                let exists_in_yaml = definition
+                    .environment
+                    .as_ref()
+                    .and_then(|env| env.secrets.as_ref())
+                    .is_some_and(|secrets| secrets.contains_key(&secret.key));
+                let config = SecretConfig {
+                    value: secret.encrypted_secret,
+                    dek_alias: secret.dek_alias,
+                };
+                if exists_in_yaml {
+                    soma_definition.update_secret(secret.key, config).await?;
+                } else {
+                    soma_definition.add_secret(secret.key, config).await?;
+                }
+            }
+        }
     }
     Ok(())
 }
@@ -1094,6 +1094,23 @@ fn get_envelope_key_id(key: &models::EnvelopeEncryptionKey) -> String {
         models::EnvelopeEncryptionKey::EnvelopeEncryptionKeyOneOf1(local) => {
             local.file_name.clone()
         }
+        models::EnvelopeEncryptionKey::EnvelopeEncryptionKeyOneOf2(gcp_kms) => {
+            gcp_kms.key_name.clone()
+        }
+        models::EnvelopeEncryptionKey::EnvelopeEncryptionKeyOneOf3(vault) => {
+            encryption::logic::envelope::vault_transit_key_id(
+                &vault.address,
+                &vault.mount,
+                &vault.key_name,
+            )
+        }
+        models::EnvelopeEncryptionKey::EnvelopeEncryptionKeyOneOf4(azure) => {
+            encryption::logic::envelope::azure_key_vault_key_id(
+                &azure.vault_url,
+                &azure.key_name,
+                &azure.key_version,
+            )
+        }
     }
 }
 
@@ -1120,5 +1137,34 @@ fn envelope_key_config_to_api_model(
                 },
             )
         }
+        EnvelopeKeyConfig::GcpKms(gcp_kms) => {
+            models::EnvelopeEncryptionKey::EnvelopeEncryptionKeyOneOf2(
+                models::EnvelopeEncryptionKeyOneOf2 {
+                    key_name: gcp_kms.key_name.clone(),
+                    location: gcp_kms.location.clone(),
+                    r#type: models::envelope_encryption_key_one_of_2::Type::GcpKms,
+                },
+            )
+        }
+        EnvelopeKeyConfig::VaultTransit(vault) => {
+            models::EnvelopeEncryptionKey::EnvelopeEncryptionKeyOneOf3(
+                models::EnvelopeEncryptionKeyOneOf3 {
+                    address: vault.address.clone(),
+                    mount: vault.mount.clone(),
+                    key_name: vault.key_name.clone(),
+                    r#type: models::envelope_encryption_key_one_of_3::Type::VaultTransit,
+                },
+            )
+        }
+        EnvelopeKeyConfig::AzureKeyVault(azure) => {
+            models::EnvelopeEncryptionKey::EnvelopeEncryptionKeyOneOf4(
+                models::EnvelopeEncryptionKeyOneOf4 {
+                    vault_url: azure.vault_url.clone(),
+                    key_name: azure.key_name.clone(),
+                    key_version: azure.key_version.clone(),
+                    r#type: models::envelope_encryption_key_one_of_4::Type::AzureKeyVault,
+                },
+            )
+        }
     }
 }
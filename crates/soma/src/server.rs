@@ -3,13 +3,13 @@ use std::sync::Arc;
 
 use http::header::HeaderName;
 use shared::error::CommonError;
-use shared::port::find_free_port;
 use shared::process_manager::{CustomProcessManager, ShutdownCallback};
 use soma_api_server::ApiService;
 use tower_http::cors::{AllowHeaders, AllowMethods, AllowOrigin, CorsLayer, ExposeHeaders};
 
 pub struct StartAxumServerParams {
     pub host: String,
+    /// Port to bind, already picked by the caller
     pub port: u16,
     pub api_service: ApiService,
     pub process_manager: Arc<CustomProcessManager>,
@@ -17,8 +17,7 @@ pub struct StartAxumServerParams {
 
 /// Starts the Axum server using the process manager
 pub async fn start_axum_server(params: StartAxumServerParams) -> Result<(), CommonError> {
-    let port = find_free_port(params.port, params.port + 100)?;
-    let addr: SocketAddr = format!("{}:{}", params.host, port)
+    let addr: SocketAddr = format!("{}:{}", params.host, params.port)
         .parse()
         .map_err(|e| CommonError::AddrParseError { source: e })?;
 
@@ -122,7 +121,7 @@ pub async fn start_axum_server(params: StartAxumServerParams) -> Result<(), Comm
                         tracing::trace!(address = %addr, "Starting axum server");
                         let server_fut = axum_server::bind(addr)
                             .handle(handle)
-                            .serve(router.into_make_service());
+                            .serve(router.into_make_service_with_connect_info::<SocketAddr>());
 
                         match server_fut.await {
                             Ok(()) => {
@@ -159,7 +158,7 @@ pub async fn start_axum_server(params: StartAxumServerParams) -> Result<(), Comm
 #[cfg(test)]
 mod tests {
     mod unit {
-        use shared::port::find_free_port_with_bind;
+        use shared::port::{find_free_port, find_free_port_with_bind};
 
         use super::super::*;
         use std::{
@@ -12,8 +12,8 @@
  * Do not edit the class manually.
  */
 
-import type { ListAgentsResponse } from "../models/index";
-import { ListAgentsResponseFromJSON } from "../models/index";
+import type { AgentListItemPaginatedResponse } from "../models/index";
+import { AgentListItemPaginatedResponseFromJSON } from "../models/index";
 import * as runtime from "../runtime";
 
 export interface AgentApiGetAgentCardRequest {
@@ -27,6 +27,11 @@ export interface AgentApiHandleA2aJsonrpcRequestRequest {
 	body: object;
 }
 
+export interface AgentApiListAgentsRequest {
+	pageSize: number;
+	nextPageToken?: string;
+}
+
 /**
  *
  */
@@ -192,14 +197,30 @@ export class AgentApi extends runtime.BaseAPI {
 	}
 
 	/**
-	 * List all available agents from the agent cache
+	 * List available agents from the agent cache with pagination
 	 * List available agents
 	 */
 	async listAgentsRaw(
+		requestParameters: AgentApiListAgentsRequest,
 		initOverrides?: RequestInit | runtime.InitOverrideFunction,
-	): Promise<runtime.ApiResponse<ListAgentsResponse>> {
+	): Promise<runtime.ApiResponse<AgentListItemPaginatedResponse>> {
+		if (requestParameters.pageSize == null) {
+			throw new runtime.RequiredError(
+				"pageSize",
+				'Required parameter "pageSize" was null or undefined when calling listAgents().',
+			);
+		}
+
 		const queryParameters: any = {};
 
+		if (requestParameters.pageSize != null) {
+			queryParameters.page_size = requestParameters.pageSize;
+		}
+
+		if (requestParameters.nextPageToken != null) {
+			queryParameters.next_page_token = requestParameters.nextPageToken;
+		}
+
 		const headerParameters: runtime.HTTPHeaders = {};
 
 		if (this.configuration?.accessToken) {
@@ -228,18 +249,19 @@ export class AgentApi extends runtime.BaseAPI {
 		);
 
 		return new runtime.JSONApiResponse(response, (jsonValue) =>
-			ListAgentsResponseFromJSON(jsonValue),
+			AgentListItemPaginatedResponseFromJSON(jsonValue),
 		);
 	}
 
 	/**
-	 * List all available agents from the agent cache
+	 * List available agents from the agent cache with pagination
 	 * List available agents
 	 */
 	async listAgents(
+		requestParameters: AgentApiListAgentsRequest,
 		initOverrides?: RequestInit | runtime.InitOverrideFunction,
-	): Promise<ListAgentsResponse> {
-		const response = await this.listAgentsRaw(initOverrides);
+	): Promise<AgentListItemPaginatedResponse> {
+		const response = await this.listAgentsRaw(requestParameters, initOverrides);
 		return await response.value();
 	}
 }
@@ -146,6 +146,7 @@ export interface McpApiListAvailableProvidersRequest {
 export interface McpApiListFunctionInstancesRequest {
 	pageSize: number;
 	nextPageToken?: string;
+	direction?: ListFunctionInstancesDirectionEnum;
 	providerInstanceId?: string;
 }
 
@@ -1477,7 +1478,7 @@ export class McpApi extends runtime.BaseAPI {
 	}
 
 	/**
-	 * List all function instances with optional filtering by provider instance
+	 * List all function instances with optional filtering by provider instance. Supports `If-None-Match` and `If-Modified-Since`, returning 304 when the listing is unchanged
 	 * List function instances
 	 */
 	async listFunctionInstancesRaw(
@@ -1501,6 +1502,10 @@ export class McpApi extends runtime.BaseAPI {
 			queryParameters.next_page_token = requestParameters.nextPageToken;
 		}
 
+		if (requestParameters.direction != null) {
+			queryParameters.direction = requestParameters.direction;
+		}
+
 		if (requestParameters.providerInstanceId != null) {
 			queryParameters.provider_instance_id =
 				requestParameters.providerInstanceId;
@@ -1539,7 +1544,7 @@ export class McpApi extends runtime.BaseAPI {
 	}
 
 	/**
-	 * List all function instances with optional filtering by provider instance
+	 * List all function instances with optional filtering by provider instance. Supports `If-None-Match` and `If-Modified-Since`, returning 304 when the listing is unchanged
 	 * List function instances
 	 */
 	async listFunctionInstances(
@@ -1554,7 +1559,7 @@ export class McpApi extends runtime.BaseAPI {
 	}
 
 	/**
-	 * List all MCP server instances with pagination
+	 * List all MCP server instances with pagination. Supports `If-None-Match` and `If-Modified-Since`, returning 304 when neither the servers nor their functions changed
 	 * List MCP server instances
 	 */
 	async listMcpServerInstancesRaw(
@@ -1615,7 +1620,7 @@ export class McpApi extends runtime.BaseAPI {
 	}
 
 	/**
-	 * List all MCP server instances with pagination
+	 * List all MCP server instances with pagination. Supports `If-None-Match` and `If-Modified-Since`, returning 304 when neither the servers nor their functions changed
 	 * List MCP server instances
 	 */
 	async listMcpServerInstances(
@@ -2331,3 +2336,13 @@ export class McpApi extends runtime.BaseAPI {
 		return await response.value();
 	}
 }
+
+/**
+ * @export
+ */
+export const ListFunctionInstancesDirectionEnum = {
+	Forward: "forward",
+	Backward: "backward",
+} as const;
+export type ListFunctionInstancesDirectionEnum =
+	(typeof ListFunctionInstancesDirectionEnum)[keyof typeof ListFunctionInstancesDirectionEnum];
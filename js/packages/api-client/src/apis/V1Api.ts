@@ -15,6 +15,7 @@
 import type {
 	AddGroupMemberRequest,
 	AddMcpServerInstanceFunctionRequest,
+	AgentListItemPaginatedResponse,
 	ContextInfoPaginatedResponse,
 	CreateApiKeyParams,
 	CreateApiKeyResponse,
@@ -57,7 +58,6 @@ import type {
 	InvokeResult,
 	JwkResponsePaginatedResponse,
 	JwksResponse,
-	ListAgentsResponse,
 	ListApiKeysResponse,
 	ListDecryptedSecretsResponse,
 	ListSecretsResponse,
@@ -105,6 +105,7 @@ import type {
 import {
 	AddGroupMemberRequestToJSON,
 	AddMcpServerInstanceFunctionRequestToJSON,
+	AgentListItemPaginatedResponseFromJSON,
 	ContextInfoPaginatedResponseFromJSON,
 	CreateApiKeyParamsToJSON,
 	CreateApiKeyResponseFromJSON,
@@ -148,7 +149,6 @@ import {
 	InvokeResultFromJSON,
 	JwkResponsePaginatedResponseFromJSON,
 	JwksResponseFromJSON,
-	ListAgentsResponseFromJSON,
 	ListApiKeysResponseFromJSON,
 	ListDecryptedSecretsResponseFromJSON,
 	ListSecretsResponseFromJSON,
@@ -380,6 +380,11 @@ export interface V1ApiInvokeFunctionRequest {
 	invokeFunctionParamsInner: InvokeFunctionParamsInner;
 }
 
+export interface V1ApiListAgentsRequest {
+	pageSize: number;
+	nextPageToken?: string;
+}
+
 export interface V1ApiListAvailableProvidersRequest {
 	pageSize: number;
 	nextPageToken?: string;
@@ -409,6 +414,7 @@ export interface V1ApiListEnvelopeEncryptionKeysRequest {
 export interface V1ApiListFunctionInstancesRequest {
 	pageSize: number;
 	nextPageToken?: string;
+	direction?: ListFunctionInstancesDirectionEnum;
 	providerInstanceId?: string;
 }
 
@@ -3808,14 +3814,30 @@ export class V1Api extends runtime.BaseAPI {
 	}
 
 	/**
-	 * List all available agents from the agent cache
+	 * List available agents from the agent cache with pagination
 	 * List available agents
 	 */
 	async listAgentsRaw(
+		requestParameters: V1ApiListAgentsRequest,
 		initOverrides?: RequestInit | runtime.InitOverrideFunction,
-	): Promise<runtime.ApiResponse<ListAgentsResponse>> {
+	): Promise<runtime.ApiResponse<AgentListItemPaginatedResponse>> {
+		if (requestParameters.pageSize == null) {
+			throw new runtime.RequiredError(
+				"pageSize",
+				'Required parameter "pageSize" was null or undefined when calling listAgents().',
+			);
+		}
+
 		const queryParameters: any = {};
 
+		if (requestParameters.pageSize != null) {
+			queryParameters.page_size = requestParameters.pageSize;
+		}
+
+		if (requestParameters.nextPageToken != null) {
+			queryParameters.next_page_token = requestParameters.nextPageToken;
+		}
+
 		const headerParameters: runtime.HTTPHeaders = {};
 
 		if (this.configuration?.accessToken) {
@@ -3844,18 +3866,19 @@ export class V1Api extends runtime.BaseAPI {
 		);
 
 		return new runtime.JSONApiResponse(response, (jsonValue) =>
-			ListAgentsResponseFromJSON(jsonValue),
+			AgentListItemPaginatedResponseFromJSON(jsonValue),
 		);
 	}
 
 	/**
-	 * List all available agents from the agent cache
+	 * List available agents from the agent cache with pagination
 	 * List available agents
 	 */
 	async listAgents(
+		requestParameters: V1ApiListAgentsRequest,
 		initOverrides?: RequestInit | runtime.InitOverrideFunction,
-	): Promise<ListAgentsResponse> {
-		const response = await this.listAgentsRaw(initOverrides);
+	): Promise<AgentListItemPaginatedResponse> {
+		const response = await this.listAgentsRaw(requestParameters, initOverrides);
 		return await response.value();
 	}
 
@@ -4233,7 +4256,7 @@ export class V1Api extends runtime.BaseAPI {
 	}
 
 	/**
-	 * List all function instances with optional filtering by provider instance
+	 * List all function instances with optional filtering by provider instance. Supports `If-None-Match` and `If-Modified-Since`, returning 304 when the listing is unchanged
 	 * List function instances
 	 */
 	async listFunctionInstancesRaw(
@@ -4257,6 +4280,10 @@ export class V1Api extends runtime.BaseAPI {
 			queryParameters.next_page_token = requestParameters.nextPageToken;
 		}
 
+		if (requestParameters.direction != null) {
+			queryParameters.direction = requestParameters.direction;
+		}
+
 		if (requestParameters.providerInstanceId != null) {
 			queryParameters.provider_instance_id =
 				requestParameters.providerInstanceId;
@@ -4295,7 +4322,7 @@ export class V1Api extends runtime.BaseAPI {
 	}
 
 	/**
-	 * List all function instances with optional filtering by provider instance
+	 * List all function instances with optional filtering by provider instance. Supports `If-None-Match` and `If-Modified-Since`, returning 304 when the listing is unchanged
 	 * List function instances
 	 */
 	async listFunctionInstances(
@@ -4462,7 +4489,7 @@ export class V1Api extends runtime.BaseAPI {
 	}
 
 	/**
-	 * List all MCP server instances with pagination
+	 * List all MCP server instances with pagination. Supports `If-None-Match` and `If-Modified-Since`, returning 304 when neither the servers nor their functions changed
 	 * List MCP server instances
 	 */
 	async listMcpServerInstancesRaw(
@@ -4523,7 +4550,7 @@ export class V1Api extends runtime.BaseAPI {
 	}
 
 	/**
-	 * List all MCP server instances with pagination
+	 * List all MCP server instances with pagination. Supports `If-None-Match` and `If-Modified-Since`, returning 304 when neither the servers nor their functions changed
 	 * List MCP server instances
 	 */
 	async listMcpServerInstances(
@@ -8900,3 +8927,13 @@ export class V1Api extends runtime.BaseAPI {
 		return await response.value();
 	}
 }
+
+/**
+ * @export
+ */
+export const ListFunctionInstancesDirectionEnum = {
+	Forward: "forward",
+	Backward: "backward",
+} as const;
+export type ListFunctionInstancesDirectionEnum =
+	(typeof ListFunctionInstancesDirectionEnum)[keyof typeof ListFunctionInstancesDirectionEnum];
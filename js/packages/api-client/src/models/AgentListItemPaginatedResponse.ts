@@ -0,0 +1,126 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * soma
+ * An open source AI agent runtime
+ *
+ * The version of the OpenAPI document: v1
+ *
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+import type { AgentListItem } from "./AgentListItem";
+import { AgentListItemFromJSON, AgentListItemToJSON } from "./AgentListItem";
+import type { PaginationOrder } from "./PaginationOrder";
+import {
+	PaginationOrderFromJSON,
+	PaginationOrderToJSON,
+} from "./PaginationOrder";
+
+/**
+ *
+ * @export
+ * @interface AgentListItemPaginatedResponse
+ */
+export interface AgentListItemPaginatedResponse {
+	/**
+	 *
+	 * @type {Array<AgentListItem>}
+	 * @memberof AgentListItemPaginatedResponse
+	 */
+	items: Array<AgentListItem>;
+	/**
+	 *
+	 * @type {string}
+	 * @memberof AgentListItemPaginatedResponse
+	 */
+	nextPageToken?: string;
+	/**
+	 *
+	 * @type {string}
+	 * @memberof AgentListItemPaginatedResponse
+	 */
+	prevPageToken?: string;
+	/**
+	 *
+	 * @type {number}
+	 * @memberof AgentListItemPaginatedResponse
+	 */
+	totalCount?: number;
+	/**
+	 *
+	 * @type {number}
+	 * @memberof AgentListItemPaginatedResponse
+	 */
+	pageSize: number;
+	/**
+	 *
+	 * @type {PaginationOrder}
+	 * @memberof AgentListItemPaginatedResponse
+	 */
+	order: PaginationOrder;
+}
+
+/**
+ * Check if a given object implements the AgentListItemPaginatedResponse interface.
+ */
+export function instanceOfAgentListItemPaginatedResponse(
+	value: object,
+): value is AgentListItemPaginatedResponse {
+	if (!("items" in value) || value.items === undefined) return false;
+	if (!("pageSize" in value) || value.pageSize === undefined) return false;
+	if (!("order" in value) || value.order === undefined) return false;
+	return true;
+}
+
+export function AgentListItemPaginatedResponseFromJSON(
+	json: any,
+): AgentListItemPaginatedResponse {
+	return AgentListItemPaginatedResponseFromJSONTyped(json, false);
+}
+
+export function AgentListItemPaginatedResponseFromJSONTyped(
+	json: any,
+	_ignoreDiscriminator: boolean,
+): AgentListItemPaginatedResponse {
+	if (json == null) {
+		return json;
+	}
+	return {
+		items: (json.items as Array<any>).map(AgentListItemFromJSON),
+		nextPageToken:
+			json.next_page_token == null ? undefined : json.next_page_token,
+		prevPageToken:
+			json.prev_page_token == null ? undefined : json.prev_page_token,
+		totalCount: json.total_count == null ? undefined : json.total_count,
+		pageSize: json.page_size,
+		order: PaginationOrderFromJSON(json.order),
+	};
+}
+
+export function AgentListItemPaginatedResponseToJSON(
+	json: any,
+): AgentListItemPaginatedResponse {
+	return AgentListItemPaginatedResponseToJSONTyped(json, false);
+}
+
+export function AgentListItemPaginatedResponseToJSONTyped(
+	value?: AgentListItemPaginatedResponse | null,
+	_ignoreDiscriminator: boolean = false,
+): any {
+	if (value == null) {
+		return value;
+	}
+
+	return {
+		items: (value.items as Array<any>).map(AgentListItemToJSON),
+		next_page_token: value.nextPageToken,
+		prev_page_token: value.prevPageToken,
+		total_count: value.totalCount,
+		page_size: value.pageSize,
+		order: PaginationOrderToJSON(value.order),
+	};
+}
@@ -14,6 +14,11 @@
 
 import type { ContextInfo } from "./ContextInfo";
 import { ContextInfoFromJSON, ContextInfoToJSON } from "./ContextInfo";
+import type { PaginationOrder } from "./PaginationOrder";
+import {
+	PaginationOrderFromJSON,
+	PaginationOrderToJSON,
+} from "./PaginationOrder";
 
 /**
  *
@@ -33,6 +38,30 @@ export interface ContextInfoPaginatedResponse {
 	 * @memberof ContextInfoPaginatedResponse
 	 */
 	nextPageToken?: string;
+	/**
+	 *
+	 * @type {string}
+	 * @memberof ContextInfoPaginatedResponse
+	 */
+	prevPageToken?: string;
+	/**
+	 *
+	 * @type {number}
+	 * @memberof ContextInfoPaginatedResponse
+	 */
+	totalCount?: number;
+	/**
+	 *
+	 * @type {number}
+	 * @memberof ContextInfoPaginatedResponse
+	 */
+	pageSize: number;
+	/**
+	 *
+	 * @type {PaginationOrder}
+	 * @memberof ContextInfoPaginatedResponse
+	 */
+	order: PaginationOrder;
 }
 
 /**
@@ -42,6 +71,8 @@ export function instanceOfContextInfoPaginatedResponse(
 	value: object,
 ): value is ContextInfoPaginatedResponse {
 	if (!("items" in value) || value.items === undefined) return false;
+	if (!("pageSize" in value) || value.pageSize === undefined) return false;
+	if (!("order" in value) || value.order === undefined) return false;
 	return true;
 }
 
@@ -62,6 +93,11 @@ export function ContextInfoPaginatedResponseFromJSONTyped(
 		items: (json.items as Array<any>).map(ContextInfoFromJSON),
 		nextPageToken:
 			json.next_page_token == null ? undefined : json.next_page_token,
+		prevPageToken:
+			json.prev_page_token == null ? undefined : json.prev_page_token,
+		totalCount: json.total_count == null ? undefined : json.total_count,
+		pageSize: json.page_size,
+		order: PaginationOrderFromJSON(json.order),
 	};
 }
 
@@ -82,5 +118,9 @@ export function ContextInfoPaginatedResponseToJSONTyped(
 	return {
 		items: (value.items as Array<any>).map(ContextInfoToJSON),
 		next_page_token: value.nextPageToken,
+		prev_page_token: value.prevPageToken,
+		total_count: value.totalCount,
+		page_size: value.pageSize,
+		order: PaginationOrderToJSON(value.order),
 	};
 }
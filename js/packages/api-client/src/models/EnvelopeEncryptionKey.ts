@@ -24,6 +24,30 @@ import {
 	EnvelopeEncryptionKeyOneOf1ToJSON,
 	instanceOfEnvelopeEncryptionKeyOneOf1,
 } from "./EnvelopeEncryptionKeyOneOf1";
+import type { EnvelopeEncryptionKeyOneOf2 } from "./EnvelopeEncryptionKeyOneOf2";
+import {
+	EnvelopeEncryptionKeyOneOf2FromJSONTyped,
+	EnvelopeEncryptionKeyOneOf2ToJSON,
+	instanceOfEnvelopeEncryptionKeyOneOf2,
+} from "./EnvelopeEncryptionKeyOneOf2";
+import type { EnvelopeEncryptionKeyOneOf3 } from "./EnvelopeEncryptionKeyOneOf3";
+import type { EnvelopeEncryptionKeyOneOf4 } from "./EnvelopeEncryptionKeyOneOf4";
+import {
+	EnvelopeEncryptionKeyOneOf4FromJSONTyped,
+	EnvelopeEncryptionKeyOneOf4ToJSON,
+	instanceOfEnvelopeEncryptionKeyOneOf4,
+} from "./EnvelopeEncryptionKeyOneOf4";
+import {
+	EnvelopeEncryptionKeyOneOf3FromJSONTyped,
+	EnvelopeEncryptionKeyOneOf3ToJSON,
+	instanceOfEnvelopeEncryptionKeyOneOf3,
+} from "./EnvelopeEncryptionKeyOneOf3";
+import type { EnvelopeEncryptionKeyOneOf4 } from "./EnvelopeEncryptionKeyOneOf4";
+import {
+	EnvelopeEncryptionKeyOneOf4FromJSONTyped,
+	EnvelopeEncryptionKeyOneOf4ToJSON,
+	instanceOfEnvelopeEncryptionKeyOneOf4,
+} from "./EnvelopeEncryptionKeyOneOf4";
 
 /**
  * @type EnvelopeEncryptionKey
@@ -32,7 +56,10 @@ import {
  */
 export type EnvelopeEncryptionKey =
 	| EnvelopeEncryptionKeyOneOf
-	| EnvelopeEncryptionKeyOneOf1;
+	| EnvelopeEncryptionKeyOneOf1
+	| EnvelopeEncryptionKeyOneOf2
+	| EnvelopeEncryptionKeyOneOf3
+	| EnvelopeEncryptionKeyOneOf4;
 
 export function EnvelopeEncryptionKeyFromJSON(
 	json: any,
@@ -56,6 +83,15 @@ export function EnvelopeEncryptionKeyFromJSONTyped(
 	if (instanceOfEnvelopeEncryptionKeyOneOf1(json)) {
 		return EnvelopeEncryptionKeyOneOf1FromJSONTyped(json, true);
 	}
+	if (instanceOfEnvelopeEncryptionKeyOneOf2(json)) {
+		return EnvelopeEncryptionKeyOneOf2FromJSONTyped(json, true);
+	}
+	if (instanceOfEnvelopeEncryptionKeyOneOf3(json)) {
+		return EnvelopeEncryptionKeyOneOf3FromJSONTyped(json, true);
+	}
+	if (instanceOfEnvelopeEncryptionKeyOneOf4(json)) {
+		return EnvelopeEncryptionKeyOneOf4FromJSONTyped(json, true);
+	}
 
 	return {} as any;
 }
@@ -84,6 +120,21 @@ export function EnvelopeEncryptionKeyToJSONTyped(
 			value as EnvelopeEncryptionKeyOneOf1,
 		);
 	}
+	if (instanceOfEnvelopeEncryptionKeyOneOf2(value)) {
+		return EnvelopeEncryptionKeyOneOf2ToJSON(
+			value as EnvelopeEncryptionKeyOneOf2,
+		);
+	}
+	if (instanceOfEnvelopeEncryptionKeyOneOf3(value)) {
+		return EnvelopeEncryptionKeyOneOf3ToJSON(
+			value as EnvelopeEncryptionKeyOneOf3,
+		);
+	}
+	if (instanceOfEnvelopeEncryptionKeyOneOf4(value)) {
+		return EnvelopeEncryptionKeyOneOf4ToJSON(
+			value as EnvelopeEncryptionKeyOneOf4,
+		);
+	}
 
 	return {};
 }
@@ -0,0 +1,93 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * soma
+ * An open source AI agent runtime
+ *
+ * The version of the OpenAPI document: v1
+ *
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+/**
+ * An Azure Key Vault RSA key. `vault_url` is the vault's base URL, e.g.
+ * `https://my-vault.vault.azure.net`, and `key_version` pins the key version DEKs are wrapped with.
+ * @export
+ * @interface EnvelopeEncryptionKeyAzureKeyVault
+ */
+export interface EnvelopeEncryptionKeyAzureKeyVault {
+	/**
+	 *
+	 * @type {string}
+	 * @memberof EnvelopeEncryptionKeyAzureKeyVault
+	 */
+	vaultUrl: string;
+	/**
+	 *
+	 * @type {string}
+	 * @memberof EnvelopeEncryptionKeyAzureKeyVault
+	 */
+	keyName: string;
+	/**
+	 *
+	 * @type {string}
+	 * @memberof EnvelopeEncryptionKeyAzureKeyVault
+	 */
+	keyVersion: string;
+}
+
+/**
+ * Check if a given object implements the EnvelopeEncryptionKeyAzureKeyVault interface.
+ */
+export function instanceOfEnvelopeEncryptionKeyAzureKeyVault(
+	value: object,
+): value is EnvelopeEncryptionKeyAzureKeyVault {
+	if (!("vaultUrl" in value) || value.vaultUrl === undefined) return false;
+	if (!("keyName" in value) || value.keyName === undefined) return false;
+	if (!("keyVersion" in value) || value.keyVersion === undefined) return false;
+	return true;
+}
+
+export function EnvelopeEncryptionKeyAzureKeyVaultFromJSON(
+	json: any,
+): EnvelopeEncryptionKeyAzureKeyVault {
+	return EnvelopeEncryptionKeyAzureKeyVaultFromJSONTyped(json, false);
+}
+
+export function EnvelopeEncryptionKeyAzureKeyVaultFromJSONTyped(
+	json: any,
+	_ignoreDiscriminator: boolean,
+): EnvelopeEncryptionKeyAzureKeyVault {
+	if (json == null) {
+		return json;
+	}
+	return {
+		vaultUrl: json.vault_url,
+		keyName: json.key_name,
+		keyVersion: json.key_version,
+	};
+}
+
+export function EnvelopeEncryptionKeyAzureKeyVaultToJSON(
+	json: any,
+): EnvelopeEncryptionKeyAzureKeyVault {
+	return EnvelopeEncryptionKeyAzureKeyVaultToJSONTyped(json, false);
+}
+
+export function EnvelopeEncryptionKeyAzureKeyVaultToJSONTyped(
+	value?: EnvelopeEncryptionKeyAzureKeyVault | null,
+	_ignoreDiscriminator: boolean = false,
+): any {
+	if (value == null) {
+		return value;
+	}
+
+	return {
+		vault_url: value.vaultUrl,
+		key_name: value.keyName,
+		key_version: value.keyVersion,
+	};
+}
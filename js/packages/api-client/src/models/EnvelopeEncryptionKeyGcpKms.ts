@@ -0,0 +1,84 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * soma
+ * An open source AI agent runtime
+ *
+ * The version of the OpenAPI document: v1
+ *
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+/**
+ * A GCP Cloud KMS key. `key_name` is the full resource name of the crypto key
+ * (`projects/PROJECT/locations/LOCATION/keyRings/KEY_RING/cryptoKeys/KEY`).
+ * @export
+ * @interface EnvelopeEncryptionKeyGcpKms
+ */
+export interface EnvelopeEncryptionKeyGcpKms {
+	/**
+	 *
+	 * @type {string}
+	 * @memberof EnvelopeEncryptionKeyGcpKms
+	 */
+	keyName: string;
+	/**
+	 *
+	 * @type {string}
+	 * @memberof EnvelopeEncryptionKeyGcpKms
+	 */
+	location: string;
+}
+
+/**
+ * Check if a given object implements the EnvelopeEncryptionKeyGcpKms interface.
+ */
+export function instanceOfEnvelopeEncryptionKeyGcpKms(
+	value: object,
+): value is EnvelopeEncryptionKeyGcpKms {
+	if (!("keyName" in value) || value.keyName === undefined) return false;
+	if (!("location" in value) || value.location === undefined) return false;
+	return true;
+}
+
+export function EnvelopeEncryptionKeyGcpKmsFromJSON(
+	json: any,
+): EnvelopeEncryptionKeyGcpKms {
+	return EnvelopeEncryptionKeyGcpKmsFromJSONTyped(json, false);
+}
+
+export function EnvelopeEncryptionKeyGcpKmsFromJSONTyped(
+	json: any,
+	_ignoreDiscriminator: boolean,
+): EnvelopeEncryptionKeyGcpKms {
+	if (json == null) {
+		return json;
+	}
+	return {
+		keyName: json.key_name,
+		location: json.location,
+	};
+}
+
+export function EnvelopeEncryptionKeyGcpKmsToJSON(
+	json: any,
+): EnvelopeEncryptionKeyGcpKms {
+	return EnvelopeEncryptionKeyGcpKmsToJSONTyped(json, false);
+}
+
+export function EnvelopeEncryptionKeyGcpKmsToJSONTyped(
+	value?: EnvelopeEncryptionKeyGcpKms | null,
+	_ignoreDiscriminator: boolean = false,
+): any {
+	if (value == null) {
+		return value;
+	}
+
+	return {
+		key_name: value.keyName,
+		location: value.location,
+	};
+}
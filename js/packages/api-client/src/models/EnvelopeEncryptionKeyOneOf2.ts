@@ -0,0 +1,101 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * soma
+ * An open source AI agent runtime
+ *
+ * The version of the OpenAPI document: v1
+ *
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+/**
+ *
+ * @export
+ * @interface EnvelopeEncryptionKeyOneOf2
+ */
+export interface EnvelopeEncryptionKeyOneOf2 {
+	/**
+	 *
+	 * @type {string}
+	 * @memberof EnvelopeEncryptionKeyOneOf2
+	 */
+	keyName: string;
+	/**
+	 *
+	 * @type {string}
+	 * @memberof EnvelopeEncryptionKeyOneOf2
+	 */
+	location: string;
+	/**
+	 *
+	 * @type {string}
+	 * @memberof EnvelopeEncryptionKeyOneOf2
+	 */
+	type: EnvelopeEncryptionKeyOneOf2TypeEnum;
+}
+
+/**
+ * @export
+ */
+export const EnvelopeEncryptionKeyOneOf2TypeEnum = {
+	GcpKms: "gcp_kms",
+} as const;
+export type EnvelopeEncryptionKeyOneOf2TypeEnum =
+	(typeof EnvelopeEncryptionKeyOneOf2TypeEnum)[keyof typeof EnvelopeEncryptionKeyOneOf2TypeEnum];
+
+/**
+ * Check if a given object implements the EnvelopeEncryptionKeyOneOf2 interface.
+ */
+export function instanceOfEnvelopeEncryptionKeyOneOf2(
+	value: object,
+): value is EnvelopeEncryptionKeyOneOf2 {
+	if (!("keyName" in value) || value.keyName === undefined) return false;
+	if (!("location" in value) || value.location === undefined) return false;
+	if (!("type" in value) || value.type === undefined) return false;
+	return true;
+}
+
+export function EnvelopeEncryptionKeyOneOf2FromJSON(
+	json: any,
+): EnvelopeEncryptionKeyOneOf2 {
+	return EnvelopeEncryptionKeyOneOf2FromJSONTyped(json, false);
+}
+
+export function EnvelopeEncryptionKeyOneOf2FromJSONTyped(
+	json: any,
+	_ignoreDiscriminator: boolean,
+): EnvelopeEncryptionKeyOneOf2 {
+	if (json == null) {
+		return json;
+	}
+	return {
+		keyName: json.key_name,
+		location: json.location,
+		type: json.type,
+	};
+}
+
+export function EnvelopeEncryptionKeyOneOf2ToJSON(
+	json: any,
+): EnvelopeEncryptionKeyOneOf2 {
+	return EnvelopeEncryptionKeyOneOf2ToJSONTyped(json, false);
+}
+
+export function EnvelopeEncryptionKeyOneOf2ToJSONTyped(
+	value?: EnvelopeEncryptionKeyOneOf2 | null,
+	_ignoreDiscriminator: boolean = false,
+): any {
+	if (value == null) {
+		return value;
+	}
+
+	return {
+		key_name: value.keyName,
+		location: value.location,
+		type: value.type,
+	};
+}
@@ -0,0 +1,110 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * soma
+ * An open source AI agent runtime
+ *
+ * The version of the OpenAPI document: v1
+ *
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+/**
+ *
+ * @export
+ * @interface EnvelopeEncryptionKeyOneOf3
+ */
+export interface EnvelopeEncryptionKeyOneOf3 {
+	/**
+	 *
+	 * @type {string}
+	 * @memberof EnvelopeEncryptionKeyOneOf3
+	 */
+	address: string;
+	/**
+	 *
+	 * @type {string}
+	 * @memberof EnvelopeEncryptionKeyOneOf3
+	 */
+	mount: string;
+	/**
+	 *
+	 * @type {string}
+	 * @memberof EnvelopeEncryptionKeyOneOf3
+	 */
+	keyName: string;
+	/**
+	 *
+	 * @type {string}
+	 * @memberof EnvelopeEncryptionKeyOneOf3
+	 */
+	type: EnvelopeEncryptionKeyOneOf3TypeEnum;
+}
+
+/**
+ * @export
+ */
+export const EnvelopeEncryptionKeyOneOf3TypeEnum = {
+	VaultTransit: "vault_transit",
+} as const;
+export type EnvelopeEncryptionKeyOneOf3TypeEnum =
+	(typeof EnvelopeEncryptionKeyOneOf3TypeEnum)[keyof typeof EnvelopeEncryptionKeyOneOf3TypeEnum];
+
+/**
+ * Check if a given object implements the EnvelopeEncryptionKeyOneOf3 interface.
+ */
+export function instanceOfEnvelopeEncryptionKeyOneOf3(
+	value: object,
+): value is EnvelopeEncryptionKeyOneOf3 {
+	if (!("address" in value) || value.address === undefined) return false;
+	if (!("mount" in value) || value.mount === undefined) return false;
+	if (!("keyName" in value) || value.keyName === undefined) return false;
+	if (!("type" in value) || value.type === undefined) return false;
+	return true;
+}
+
+export function EnvelopeEncryptionKeyOneOf3FromJSON(
+	json: any,
+): EnvelopeEncryptionKeyOneOf3 {
+	return EnvelopeEncryptionKeyOneOf3FromJSONTyped(json, false);
+}
+
+export function EnvelopeEncryptionKeyOneOf3FromJSONTyped(
+	json: any,
+	_ignoreDiscriminator: boolean,
+): EnvelopeEncryptionKeyOneOf3 {
+	if (json == null) {
+		return json;
+	}
+	return {
+		address: json.address,
+		mount: json.mount,
+		keyName: json.key_name,
+		type: json.type,
+	};
+}
+
+export function EnvelopeEncryptionKeyOneOf3ToJSON(
+	json: any,
+): EnvelopeEncryptionKeyOneOf3 {
+	return EnvelopeEncryptionKeyOneOf3ToJSONTyped(json, false);
+}
+
+export function EnvelopeEncryptionKeyOneOf3ToJSONTyped(
+	value?: EnvelopeEncryptionKeyOneOf3 | null,
+	_ignoreDiscriminator: boolean = false,
+): any {
+	if (value == null) {
+		return value;
+	}
+
+	return {
+		address: value.address,
+		mount: value.mount,
+		key_name: value.keyName,
+		type: value.type,
+	};
+}
@@ -0,0 +1,110 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * soma
+ * An open source AI agent runtime
+ *
+ * The version of the OpenAPI document: v1
+ *
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+/**
+ *
+ * @export
+ * @interface EnvelopeEncryptionKeyOneOf4
+ */
+export interface EnvelopeEncryptionKeyOneOf4 {
+	/**
+	 *
+	 * @type {string}
+	 * @memberof EnvelopeEncryptionKeyOneOf4
+	 */
+	vaultUrl: string;
+	/**
+	 *
+	 * @type {string}
+	 * @memberof EnvelopeEncryptionKeyOneOf4
+	 */
+	keyName: string;
+	/**
+	 *
+	 * @type {string}
+	 * @memberof EnvelopeEncryptionKeyOneOf4
+	 */
+	keyVersion: string;
+	/**
+	 *
+	 * @type {string}
+	 * @memberof EnvelopeEncryptionKeyOneOf4
+	 */
+	type: EnvelopeEncryptionKeyOneOf4TypeEnum;
+}
+
+/**
+ * @export
+ */
+export const EnvelopeEncryptionKeyOneOf4TypeEnum = {
+	AzureKeyVault: "azure_key_vault",
+} as const;
+export type EnvelopeEncryptionKeyOneOf4TypeEnum =
+	(typeof EnvelopeEncryptionKeyOneOf4TypeEnum)[keyof typeof EnvelopeEncryptionKeyOneOf4TypeEnum];
+
+/**
+ * Check if a given object implements the EnvelopeEncryptionKeyOneOf4 interface.
+ */
+export function instanceOfEnvelopeEncryptionKeyOneOf4(
+	value: object,
+): value is EnvelopeEncryptionKeyOneOf4 {
+	if (!("vaultUrl" in value) || value.vaultUrl === undefined) return false;
+	if (!("keyName" in value) || value.keyName === undefined) return false;
+	if (!("keyVersion" in value) || value.keyVersion === undefined) return false;
+	if (!("type" in value) || value.type === undefined) return false;
+	return true;
+}
+
+export function EnvelopeEncryptionKeyOneOf4FromJSON(
+	json: any,
+): EnvelopeEncryptionKeyOneOf4 {
+	return EnvelopeEncryptionKeyOneOf4FromJSONTyped(json, false);
+}
+
+export function EnvelopeEncryptionKeyOneOf4FromJSONTyped(
+	json: any,
+	_ignoreDiscriminator: boolean,
+): EnvelopeEncryptionKeyOneOf4 {
+	if (json == null) {
+		return json;
+	}
+	return {
+		vaultUrl: json.vault_url,
+		keyName: json.key_name,
+		keyVersion: json.key_version,
+		type: json.type,
+	};
+}
+
+export function EnvelopeEncryptionKeyOneOf4ToJSON(
+	json: any,
+): EnvelopeEncryptionKeyOneOf4 {
+	return EnvelopeEncryptionKeyOneOf4ToJSONTyped(json, false);
+}
+
+export function EnvelopeEncryptionKeyOneOf4ToJSONTyped(
+	value?: EnvelopeEncryptionKeyOneOf4 | null,
+	_ignoreDiscriminator: boolean = false,
+): any {
+	if (value == null) {
+		return value;
+	}
+
+	return {
+		vault_url: value.vaultUrl,
+		key_name: value.keyName,
+		key_version: value.keyVersion,
+		type: value.type,
+	};
+}
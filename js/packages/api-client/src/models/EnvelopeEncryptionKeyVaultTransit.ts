@@ -0,0 +1,93 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * soma
+ * An open source AI agent runtime
+ *
+ * The version of the OpenAPI document: v1
+ *
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+/**
+ * A HashiCorp Vault Transit key. `address` is the Vault server URL and `mount` the path the
+ * transit secrets engine is mounted at.
+ * @export
+ * @interface EnvelopeEncryptionKeyVaultTransit
+ */
+export interface EnvelopeEncryptionKeyVaultTransit {
+	/**
+	 *
+	 * @type {string}
+	 * @memberof EnvelopeEncryptionKeyVaultTransit
+	 */
+	address: string;
+	/**
+	 *
+	 * @type {string}
+	 * @memberof EnvelopeEncryptionKeyVaultTransit
+	 */
+	mount: string;
+	/**
+	 *
+	 * @type {string}
+	 * @memberof EnvelopeEncryptionKeyVaultTransit
+	 */
+	keyName: string;
+}
+
+/**
+ * Check if a given object implements the EnvelopeEncryptionKeyVaultTransit interface.
+ */
+export function instanceOfEnvelopeEncryptionKeyVaultTransit(
+	value: object,
+): value is EnvelopeEncryptionKeyVaultTransit {
+	if (!("address" in value) || value.address === undefined) return false;
+	if (!("mount" in value) || value.mount === undefined) return false;
+	if (!("keyName" in value) || value.keyName === undefined) return false;
+	return true;
+}
+
+export function EnvelopeEncryptionKeyVaultTransitFromJSON(
+	json: any,
+): EnvelopeEncryptionKeyVaultTransit {
+	return EnvelopeEncryptionKeyVaultTransitFromJSONTyped(json, false);
+}
+
+export function EnvelopeEncryptionKeyVaultTransitFromJSONTyped(
+	json: any,
+	_ignoreDiscriminator: boolean,
+): EnvelopeEncryptionKeyVaultTransit {
+	if (json == null) {
+		return json;
+	}
+	return {
+		address: json.address,
+		mount: json.mount,
+		keyName: json.key_name,
+	};
+}
+
+export function EnvelopeEncryptionKeyVaultTransitToJSON(
+	json: any,
+): EnvelopeEncryptionKeyVaultTransit {
+	return EnvelopeEncryptionKeyVaultTransitToJSONTyped(json, false);
+}
+
+export function EnvelopeEncryptionKeyVaultTransitToJSONTyped(
+	value?: EnvelopeEncryptionKeyVaultTransit | null,
+	_ignoreDiscriminator: boolean = false,
+): any {
+	if (value == null) {
+		return value;
+	}
+
+	return {
+		address: value.address,
+		mount: value.mount,
+		key_name: value.keyName,
+	};
+}
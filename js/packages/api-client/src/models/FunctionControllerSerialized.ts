@@ -12,6 +12,12 @@
  * Do not edit the class manually.
  */
 
+import type { FunctionExample } from "./FunctionExample";
+import {
+	FunctionExampleFromJSON,
+	FunctionExampleToJSON,
+} from "./FunctionExample";
+
 /**
  *
  * @export
@@ -24,12 +30,30 @@ export interface FunctionControllerSerialized {
 	 * @memberof FunctionControllerSerialized
 	 */
 	categories: Array<string>;
+	/**
+	 *
+	 * @type {boolean}
+	 * @memberof FunctionControllerSerialized
+	 */
+	deprecated: boolean;
+	/**
+	 *
+	 * @type {string}
+	 * @memberof FunctionControllerSerialized
+	 */
+	deprecationMessage?: string | null;
 	/**
 	 *
 	 * @type {string}
 	 * @memberof FunctionControllerSerialized
 	 */
 	documentation: string;
+	/**
+	 *
+	 * @type {Array<FunctionExample>}
+	 * @memberof FunctionControllerSerialized
+	 */
+	examples: Array<FunctionExample>;
 	/**
 	 *
 	 * @type {string}
@@ -63,8 +87,10 @@ export function instanceOfFunctionControllerSerialized(
 	value: object,
 ): value is FunctionControllerSerialized {
 	if (!("categories" in value) || value.categories === undefined) return false;
+	if (!("deprecated" in value) || value.deprecated === undefined) return false;
 	if (!("documentation" in value) || value.documentation === undefined)
 		return false;
+	if (!("examples" in value) || value.examples === undefined) return false;
 	if (!("name" in value) || value.name === undefined) return false;
 	if (!("output" in value) || value.output === undefined) return false;
 	if (!("parameters" in value) || value.parameters === undefined) return false;
@@ -87,7 +113,11 @@ export function FunctionControllerSerializedFromJSONTyped(
 	}
 	return {
 		categories: json.categories,
+		deprecated: json.deprecated,
+		deprecationMessage:
+			json.deprecation_message == null ? undefined : json.deprecation_message,
 		documentation: json.documentation,
+		examples: (json.examples as Array<any>).map(FunctionExampleFromJSON),
 		name: json.name,
 		output: json.output,
 		parameters: json.parameters,
@@ -111,7 +141,10 @@ export function FunctionControllerSerializedToJSONTyped(
 
 	return {
 		categories: value.categories,
+		deprecated: value.deprecated,
+		deprecation_message: value.deprecationMessage,
 		documentation: value.documentation,
+		examples: (value.examples as Array<any>).map(FunctionExampleToJSON),
 		name: value.name,
 		output: value.output,
 		parameters: value.parameters,
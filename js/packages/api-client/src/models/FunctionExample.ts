@@ -0,0 +1,86 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * soma
+ * An open source AI agent runtime
+ *
+ * The version of the OpenAPI document: v1
+ *
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+/**
+ * Example invocation of a function, for documentation in UIs and generated clients
+ * @export
+ * @interface FunctionExample
+ */
+export interface FunctionExample {
+	/**
+	 *
+	 * @type {string}
+	 * @memberof FunctionExample
+	 */
+	description?: string | null;
+	/**
+	 *
+	 * @type {any}
+	 * @memberof FunctionExample
+	 */
+	output?: any | null;
+	/**
+	 *
+	 * @type {any}
+	 * @memberof FunctionExample
+	 */
+	parameters: any | null;
+}
+
+/**
+ * Check if a given object implements the FunctionExample interface.
+ */
+export function instanceOfFunctionExample(
+	value: object,
+): value is FunctionExample {
+	if (!("parameters" in value) || value.parameters === undefined) return false;
+	return true;
+}
+
+export function FunctionExampleFromJSON(json: any): FunctionExample {
+	return FunctionExampleFromJSONTyped(json, false);
+}
+
+export function FunctionExampleFromJSONTyped(
+	json: any,
+	_ignoreDiscriminator: boolean,
+): FunctionExample {
+	if (json == null) {
+		return json;
+	}
+	return {
+		description: json.description == null ? undefined : json.description,
+		output: json.output == null ? undefined : json.output,
+		parameters: json.parameters,
+	};
+}
+
+export function FunctionExampleToJSON(json: any): FunctionExample {
+	return FunctionExampleToJSONTyped(json, false);
+}
+
+export function FunctionExampleToJSONTyped(
+	value?: FunctionExample | null,
+	_ignoreDiscriminator: boolean = false,
+): any {
+	if (value == null) {
+		return value;
+	}
+
+	return {
+		description: value.description,
+		output: value.output,
+		parameters: value.parameters,
+	};
+}
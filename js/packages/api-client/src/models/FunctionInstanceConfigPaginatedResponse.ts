@@ -17,6 +17,11 @@ import {
 	FunctionInstanceConfigFromJSON,
 	FunctionInstanceConfigToJSON,
 } from "./FunctionInstanceConfig";
+import type { PaginationOrder } from "./PaginationOrder";
+import {
+	PaginationOrderFromJSON,
+	PaginationOrderToJSON,
+} from "./PaginationOrder";
 
 /**
  *
@@ -36,6 +41,30 @@ export interface FunctionInstanceConfigPaginatedResponse {
 	 * @memberof FunctionInstanceConfigPaginatedResponse
 	 */
 	nextPageToken?: string;
+	/**
+	 *
+	 * @type {string}
+	 * @memberof FunctionInstanceConfigPaginatedResponse
+	 */
+	prevPageToken?: string;
+	/**
+	 *
+	 * @type {number}
+	 * @memberof FunctionInstanceConfigPaginatedResponse
+	 */
+	totalCount?: number;
+	/**
+	 *
+	 * @type {number}
+	 * @memberof FunctionInstanceConfigPaginatedResponse
+	 */
+	pageSize: number;
+	/**
+	 *
+	 * @type {PaginationOrder}
+	 * @memberof FunctionInstanceConfigPaginatedResponse
+	 */
+	order: PaginationOrder;
 }
 
 /**
@@ -45,6 +74,8 @@ export function instanceOfFunctionInstanceConfigPaginatedResponse(
 	value: object,
 ): value is FunctionInstanceConfigPaginatedResponse {
 	if (!("items" in value) || value.items === undefined) return false;
+	if (!("pageSize" in value) || value.pageSize === undefined) return false;
+	if (!("order" in value) || value.order === undefined) return false;
 	return true;
 }
 
@@ -65,6 +96,11 @@ export function FunctionInstanceConfigPaginatedResponseFromJSONTyped(
 		items: (json.items as Array<any>).map(FunctionInstanceConfigFromJSON),
 		nextPageToken:
 			json.next_page_token == null ? undefined : json.next_page_token,
+		prevPageToken:
+			json.prev_page_token == null ? undefined : json.prev_page_token,
+		totalCount: json.total_count == null ? undefined : json.total_count,
+		pageSize: json.page_size,
+		order: PaginationOrderFromJSON(json.order),
 	};
 }
 
@@ -85,5 +121,9 @@ export function FunctionInstanceConfigPaginatedResponseToJSONTyped(
 	return {
 		items: (value.items as Array<any>).map(FunctionInstanceConfigToJSON),
 		next_page_token: value.nextPageToken,
+		prev_page_token: value.prevPageToken,
+		total_count: value.totalCount,
+		page_size: value.pageSize,
+		order: PaginationOrderToJSON(value.order),
 	};
 }
@@ -17,6 +17,11 @@ import {
 	FunctionInstanceSerializedFromJSON,
 	FunctionInstanceSerializedToJSON,
 } from "./FunctionInstanceSerialized";
+import type { PaginationOrder } from "./PaginationOrder";
+import {
+	PaginationOrderFromJSON,
+	PaginationOrderToJSON,
+} from "./PaginationOrder";
 
 /**
  *
@@ -36,6 +41,30 @@ export interface FunctionInstanceSerializedPaginatedResponse {
 	 * @memberof FunctionInstanceSerializedPaginatedResponse
 	 */
 	nextPageToken?: string;
+	/**
+	 *
+	 * @type {string}
+	 * @memberof FunctionInstanceSerializedPaginatedResponse
+	 */
+	prevPageToken?: string;
+	/**
+	 *
+	 * @type {number}
+	 * @memberof FunctionInstanceSerializedPaginatedResponse
+	 */
+	totalCount?: number;
+	/**
+	 *
+	 * @type {number}
+	 * @memberof FunctionInstanceSerializedPaginatedResponse
+	 */
+	pageSize: number;
+	/**
+	 *
+	 * @type {PaginationOrder}
+	 * @memberof FunctionInstanceSerializedPaginatedResponse
+	 */
+	order: PaginationOrder;
 }
 
 /**
@@ -45,6 +74,8 @@ export function instanceOfFunctionInstanceSerializedPaginatedResponse(
 	value: object,
 ): value is FunctionInstanceSerializedPaginatedResponse {
 	if (!("items" in value) || value.items === undefined) return false;
+	if (!("pageSize" in value) || value.pageSize === undefined) return false;
+	if (!("order" in value) || value.order === undefined) return false;
 	return true;
 }
 
@@ -65,6 +96,11 @@ export function FunctionInstanceSerializedPaginatedResponseFromJSONTyped(
 		items: (json.items as Array<any>).map(FunctionInstanceSerializedFromJSON),
 		nextPageToken:
 			json.next_page_token == null ? undefined : json.next_page_token,
+		prevPageToken:
+			json.prev_page_token == null ? undefined : json.prev_page_token,
+		totalCount: json.total_count == null ? undefined : json.total_count,
+		pageSize: json.page_size,
+		order: PaginationOrderFromJSON(json.order),
 	};
 }
 
@@ -85,5 +121,9 @@ export function FunctionInstanceSerializedPaginatedResponseToJSONTyped(
 	return {
 		items: (value.items as Array<any>).map(FunctionInstanceSerializedToJSON),
 		next_page_token: value.nextPageToken,
+		prev_page_token: value.prevPageToken,
+		total_count: value.totalCount,
+		page_size: value.pageSize,
+		order: PaginationOrderToJSON(value.order),
 	};
 }
@@ -18,12 +18,24 @@
  * @interface InvokeError
  */
 export interface InvokeError {
+	/**
+	 * Classifies the failure, e.g. `rate_limited` or `retryable`, when the function reported it
+	 * @type {string}
+	 * @memberof InvokeError
+	 */
+	code?: string | null;
 	/**
 	 *
 	 * @type {string}
 	 * @memberof InvokeError
 	 */
 	message: string;
+	/**
+	 * Suggested delay before retrying a `rate_limited` or `retryable` failure
+	 * @type {number}
+	 * @memberof InvokeError
+	 */
+	retryAfterMs?: number | null;
 }
 
 /**
@@ -46,7 +58,10 @@ export function InvokeErrorFromJSONTyped(
 		return json;
 	}
 	return {
+		code: json.code == null ? undefined : json.code,
 		message: json.message,
+		retryAfterMs:
+			json.retry_after_ms == null ? undefined : json.retry_after_ms,
 	};
 }
 
@@ -63,6 +78,8 @@ export function InvokeErrorToJSONTyped(
 	}
 
 	return {
+		code: value.code,
 		message: value.message,
+		retry_after_ms: value.retryAfterMs,
 	};
 }
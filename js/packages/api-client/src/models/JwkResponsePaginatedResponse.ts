@@ -14,6 +14,11 @@
 
 import type { JwkResponse } from "./JwkResponse";
 import { JwkResponseFromJSON, JwkResponseToJSON } from "./JwkResponse";
+import type { PaginationOrder } from "./PaginationOrder";
+import {
+	PaginationOrderFromJSON,
+	PaginationOrderToJSON,
+} from "./PaginationOrder";
 
 /**
  *
@@ -33,6 +38,30 @@ export interface JwkResponsePaginatedResponse {
 	 * @memberof JwkResponsePaginatedResponse
 	 */
 	nextPageToken?: string;
+	/**
+	 *
+	 * @type {string}
+	 * @memberof JwkResponsePaginatedResponse
+	 */
+	prevPageToken?: string;
+	/**
+	 *
+	 * @type {number}
+	 * @memberof JwkResponsePaginatedResponse
+	 */
+	totalCount?: number;
+	/**
+	 *
+	 * @type {number}
+	 * @memberof JwkResponsePaginatedResponse
+	 */
+	pageSize: number;
+	/**
+	 *
+	 * @type {PaginationOrder}
+	 * @memberof JwkResponsePaginatedResponse
+	 */
+	order: PaginationOrder;
 }
 
 /**
@@ -42,6 +71,8 @@ export function instanceOfJwkResponsePaginatedResponse(
 	value: object,
 ): value is JwkResponsePaginatedResponse {
 	if (!("items" in value) || value.items === undefined) return false;
+	if (!("pageSize" in value) || value.pageSize === undefined) return false;
+	if (!("order" in value) || value.order === undefined) return false;
 	return true;
 }
 
@@ -62,6 +93,11 @@ export function JwkResponsePaginatedResponseFromJSONTyped(
 		items: (json.items as Array<any>).map(JwkResponseFromJSON),
 		nextPageToken:
 			json.next_page_token == null ? undefined : json.next_page_token,
+		prevPageToken:
+			json.prev_page_token == null ? undefined : json.prev_page_token,
+		totalCount: json.total_count == null ? undefined : json.total_count,
+		pageSize: json.page_size,
+		order: PaginationOrderFromJSON(json.order),
 	};
 }
 
@@ -82,5 +118,9 @@ export function JwkResponsePaginatedResponseToJSONTyped(
 	return {
 		items: (value.items as Array<any>).map(JwkResponseToJSON),
 		next_page_token: value.nextPageToken,
+		prev_page_token: value.prevPageToken,
+		total_count: value.totalCount,
+		page_size: value.pageSize,
+		order: PaginationOrderToJSON(value.order),
 	};
 }
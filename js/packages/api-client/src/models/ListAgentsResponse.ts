@@ -1,73 +0,0 @@
-/* tslint:disable */
-/* eslint-disable */
-/**
- * soma
- * An open source AI agent runtime
- *
- * The version of the OpenAPI document: v1
- *
- *
- * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
- * https://openapi-generator.tech
- * Do not edit the class manually.
- */
-
-import type { AgentListItem } from "./AgentListItem";
-import { AgentListItemFromJSON, AgentListItemToJSON } from "./AgentListItem";
-
-/**
- * Response for listing agents
- * @export
- * @interface ListAgentsResponse
- */
-export interface ListAgentsResponse {
-	/**
-	 * List of agents
-	 * @type {Array<AgentListItem>}
-	 * @memberof ListAgentsResponse
-	 */
-	agents: Array<AgentListItem>;
-}
-
-/**
- * Check if a given object implements the ListAgentsResponse interface.
- */
-export function instanceOfListAgentsResponse(
-	value: object,
-): value is ListAgentsResponse {
-	if (!("agents" in value) || value.agents === undefined) return false;
-	return true;
-}
-
-export function ListAgentsResponseFromJSON(json: any): ListAgentsResponse {
-	return ListAgentsResponseFromJSONTyped(json, false);
-}
-
-export function ListAgentsResponseFromJSONTyped(
-	json: any,
-	_ignoreDiscriminator: boolean,
-): ListAgentsResponse {
-	if (json == null) {
-		return json;
-	}
-	return {
-		agents: (json.agents as Array<any>).map(AgentListItemFromJSON),
-	};
-}
-
-export function ListAgentsResponseToJSON(json: any): ListAgentsResponse {
-	return ListAgentsResponseToJSONTyped(json, false);
-}
-
-export function ListAgentsResponseToJSONTyped(
-	value?: ListAgentsResponse | null,
-	_ignoreDiscriminator: boolean = false,
-): any {
-	if (value == null) {
-		return value;
-	}
-
-	return {
-		agents: (value.agents as Array<any>).map(AgentListItemToJSON),
-	};
-}
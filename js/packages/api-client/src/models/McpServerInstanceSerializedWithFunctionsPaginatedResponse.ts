@@ -17,6 +17,11 @@ import {
 	McpServerInstanceSerializedWithFunctionsFromJSON,
 	McpServerInstanceSerializedWithFunctionsToJSON,
 } from "./McpServerInstanceSerializedWithFunctions";
+import type { PaginationOrder } from "./PaginationOrder";
+import {
+	PaginationOrderFromJSON,
+	PaginationOrderToJSON,
+} from "./PaginationOrder";
 
 /**
  *
@@ -36,6 +41,30 @@ export interface McpServerInstanceSerializedWithFunctionsPaginatedResponse {
 	 * @memberof McpServerInstanceSerializedWithFunctionsPaginatedResponse
 	 */
 	nextPageToken?: string;
+	/**
+	 *
+	 * @type {string}
+	 * @memberof McpServerInstanceSerializedWithFunctionsPaginatedResponse
+	 */
+	prevPageToken?: string;
+	/**
+	 *
+	 * @type {number}
+	 * @memberof McpServerInstanceSerializedWithFunctionsPaginatedResponse
+	 */
+	totalCount?: number;
+	/**
+	 *
+	 * @type {number}
+	 * @memberof McpServerInstanceSerializedWithFunctionsPaginatedResponse
+	 */
+	pageSize: number;
+	/**
+	 *
+	 * @type {PaginationOrder}
+	 * @memberof McpServerInstanceSerializedWithFunctionsPaginatedResponse
+	 */
+	order: PaginationOrder;
 }
 
 /**
@@ -45,6 +74,8 @@ export function instanceOfMcpServerInstanceSerializedWithFunctionsPaginatedRespo
 	value: object,
 ): value is McpServerInstanceSerializedWithFunctionsPaginatedResponse {
 	if (!("items" in value) || value.items === undefined) return false;
+	if (!("pageSize" in value) || value.pageSize === undefined) return false;
+	if (!("order" in value) || value.order === undefined) return false;
 	return true;
 }
 
@@ -70,6 +101,11 @@ export function McpServerInstanceSerializedWithFunctionsPaginatedResponseFromJSO
 		),
 		nextPageToken:
 			json.next_page_token == null ? undefined : json.next_page_token,
+		prevPageToken:
+			json.prev_page_token == null ? undefined : json.prev_page_token,
+		totalCount: json.total_count == null ? undefined : json.total_count,
+		pageSize: json.page_size,
+		order: PaginationOrderFromJSON(json.order),
 	};
 }
 
@@ -95,5 +131,9 @@ export function McpServerInstanceSerializedWithFunctionsPaginatedResponseToJSONT
 			McpServerInstanceSerializedWithFunctionsToJSON,
 		),
 		next_page_token: value.nextPageToken,
+		prev_page_token: value.prevPageToken,
+		total_count: value.totalCount,
+		page_size: value.pageSize,
+		order: PaginationOrderToJSON(value.order),
 	};
 }
@@ -0,0 +1,57 @@
+/* tslint:disable */
+/* eslint-disable */
+/**
+ * soma
+ * An open source AI agent runtime
+ *
+ * The version of the OpenAPI document: v1
+ *
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+/**
+ * Order listings return their items in
+ * @export
+ */
+export const PaginationOrder = {
+	NewestFirst: "newest_first",
+	NameAscending: "name_ascending",
+} as const;
+export type PaginationOrder =
+	(typeof PaginationOrder)[keyof typeof PaginationOrder];
+
+export function instanceOfPaginationOrder(value: any): boolean {
+	for (const key in PaginationOrder) {
+		if (Object.hasOwn(PaginationOrder, key)) {
+			if (PaginationOrder[key as keyof typeof PaginationOrder] === value) {
+				return true;
+			}
+		}
+	}
+	return false;
+}
+
+export function PaginationOrderFromJSON(json: any): PaginationOrder {
+	return PaginationOrderFromJSONTyped(json, false);
+}
+
+export function PaginationOrderFromJSONTyped(
+	json: any,
+	_ignoreDiscriminator: boolean,
+): PaginationOrder {
+	return json as PaginationOrder;
+}
+
+export function PaginationOrderToJSON(value?: PaginationOrder | null): any {
+	return value as any;
+}
+
+export function PaginationOrderToJSONTyped(
+	value: any,
+	_ignoreDiscriminator: boolean,
+): PaginationOrder {
+	return value as PaginationOrder;
+}
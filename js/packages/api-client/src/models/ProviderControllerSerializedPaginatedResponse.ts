@@ -12,6 +12,11 @@
  * Do not edit the class manually.
  */
 
+import type { PaginationOrder } from "./PaginationOrder";
+import {
+	PaginationOrderFromJSON,
+	PaginationOrderToJSON,
+} from "./PaginationOrder";
 import type { ProviderControllerSerialized } from "./ProviderControllerSerialized";
 import {
 	ProviderControllerSerializedFromJSON,
@@ -36,6 +41,30 @@ export interface ProviderControllerSerializedPaginatedResponse {
 	 * @memberof ProviderControllerSerializedPaginatedResponse
 	 */
 	nextPageToken?: string;
+	/**
+	 *
+	 * @type {string}
+	 * @memberof ProviderControllerSerializedPaginatedResponse
+	 */
+	prevPageToken?: string;
+	/**
+	 *
+	 * @type {number}
+	 * @memberof ProviderControllerSerializedPaginatedResponse
+	 */
+	totalCount?: number;
+	/**
+	 *
+	 * @type {number}
+	 * @memberof ProviderControllerSerializedPaginatedResponse
+	 */
+	pageSize: number;
+	/**
+	 *
+	 * @type {PaginationOrder}
+	 * @memberof ProviderControllerSerializedPaginatedResponse
+	 */
+	order: PaginationOrder;
 }
 
 /**
@@ -45,6 +74,8 @@ export function instanceOfProviderControllerSerializedPaginatedResponse(
 	value: object,
 ): value is ProviderControllerSerializedPaginatedResponse {
 	if (!("items" in value) || value.items === undefined) return false;
+	if (!("pageSize" in value) || value.pageSize === undefined) return false;
+	if (!("order" in value) || value.order === undefined) return false;
 	return true;
 }
 
@@ -68,6 +99,11 @@ export function ProviderControllerSerializedPaginatedResponseFromJSONTyped(
 		items: (json.items as Array<any>).map(ProviderControllerSerializedFromJSON),
 		nextPageToken:
 			json.next_page_token == null ? undefined : json.next_page_token,
+		prevPageToken:
+			json.prev_page_token == null ? undefined : json.prev_page_token,
+		totalCount: json.total_count == null ? undefined : json.total_count,
+		pageSize: json.page_size,
+		order: PaginationOrderFromJSON(json.order),
 	};
 }
 
@@ -88,5 +124,9 @@ export function ProviderControllerSerializedPaginatedResponseToJSONTyped(
 	return {
 		items: (value.items as Array<any>).map(ProviderControllerSerializedToJSON),
 		next_page_token: value.nextPageToken,
+		prev_page_token: value.prevPageToken,
+		total_count: value.totalCount,
+		page_size: value.pageSize,
+		order: PaginationOrderToJSON(value.order),
 	};
 }
@@ -12,6 +12,11 @@
  * Do not edit the class manually.
  */
 
+import type { PaginationOrder } from "./PaginationOrder";
+import {
+	PaginationOrderFromJSON,
+	PaginationOrderToJSON,
+} from "./PaginationOrder";
 import type { ProviderInstanceListItem } from "./ProviderInstanceListItem";
 import {
 	ProviderInstanceListItemFromJSON,
@@ -36,6 +41,30 @@ export interface ProviderInstanceListItemPaginatedResponse {
 	 * @memberof ProviderInstanceListItemPaginatedResponse
 	 */
 	nextPageToken?: string;
+	/**
+	 *
+	 * @type {string}
+	 * @memberof ProviderInstanceListItemPaginatedResponse
+	 */
+	prevPageToken?: string;
+	/**
+	 *
+	 * @type {number}
+	 * @memberof ProviderInstanceListItemPaginatedResponse
+	 */
+	totalCount?: number;
+	/**
+	 *
+	 * @type {number}
+	 * @memberof ProviderInstanceListItemPaginatedResponse
+	 */
+	pageSize: number;
+	/**
+	 *
+	 * @type {PaginationOrder}
+	 * @memberof ProviderInstanceListItemPaginatedResponse
+	 */
+	order: PaginationOrder;
 }
 
 /**
@@ -45,6 +74,8 @@ export function instanceOfProviderInstanceListItemPaginatedResponse(
 	value: object,
 ): value is ProviderInstanceListItemPaginatedResponse {
 	if (!("items" in value) || value.items === undefined) return false;
+	if (!("pageSize" in value) || value.pageSize === undefined) return false;
+	if (!("order" in value) || value.order === undefined) return false;
 	return true;
 }
 
@@ -65,6 +96,11 @@ export function ProviderInstanceListItemPaginatedResponseFromJSONTyped(
 		items: (json.items as Array<any>).map(ProviderInstanceListItemFromJSON),
 		nextPageToken:
 			json.next_page_token == null ? undefined : json.next_page_token,
+		prevPageToken:
+			json.prev_page_token == null ? undefined : json.prev_page_token,
+		totalCount: json.total_count == null ? undefined : json.total_count,
+		pageSize: json.page_size,
+		order: PaginationOrderFromJSON(json.order),
 	};
 }
 
@@ -85,5 +121,9 @@ export function ProviderInstanceListItemPaginatedResponseToJSONTyped(
 	return {
 		items: (value.items as Array<any>).map(ProviderInstanceListItemToJSON),
 		next_page_token: value.nextPageToken,
+		prev_page_token: value.prevPageToken,
+		total_count: value.totalCount,
+		page_size: value.pageSize,
+		order: PaginationOrderToJSON(value.order),
 	};
 }
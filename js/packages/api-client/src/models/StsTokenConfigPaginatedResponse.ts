@@ -12,6 +12,11 @@
  * Do not edit the class manually.
  */
 
+import type { PaginationOrder } from "./PaginationOrder";
+import {
+	PaginationOrderFromJSON,
+	PaginationOrderToJSON,
+} from "./PaginationOrder";
 import type { StsTokenConfig } from "./StsTokenConfig";
 import { StsTokenConfigFromJSON, StsTokenConfigToJSON } from "./StsTokenConfig";
 
@@ -33,6 +38,30 @@ export interface StsTokenConfigPaginatedResponse {
 	 * @memberof StsTokenConfigPaginatedResponse
 	 */
 	nextPageToken?: string;
+	/**
+	 *
+	 * @type {string}
+	 * @memberof StsTokenConfigPaginatedResponse
+	 */
+	prevPageToken?: string;
+	/**
+	 *
+	 * @type {number}
+	 * @memberof StsTokenConfigPaginatedResponse
+	 */
+	totalCount?: number;
+	/**
+	 *
+	 * @type {number}
+	 * @memberof StsTokenConfigPaginatedResponse
+	 */
+	pageSize: number;
+	/**
+	 *
+	 * @type {PaginationOrder}
+	 * @memberof StsTokenConfigPaginatedResponse
+	 */
+	order: PaginationOrder;
 }
 
 /**
@@ -42,6 +71,8 @@ export function instanceOfStsTokenConfigPaginatedResponse(
 	value: object,
 ): value is StsTokenConfigPaginatedResponse {
 	if (!("items" in value) || value.items === undefined) return false;
+	if (!("pageSize" in value) || value.pageSize === undefined) return false;
+	if (!("order" in value) || value.order === undefined) return false;
 	return true;
 }
 
@@ -62,6 +93,11 @@ export function StsTokenConfigPaginatedResponseFromJSONTyped(
 		items: (json.items as Array<any>).map(StsTokenConfigFromJSON),
 		nextPageToken:
 			json.next_page_token == null ? undefined : json.next_page_token,
+		prevPageToken:
+			json.prev_page_token == null ? undefined : json.prev_page_token,
+		totalCount: json.total_count == null ? undefined : json.total_count,
+		pageSize: json.page_size,
+		order: PaginationOrderFromJSON(json.order),
 	};
 }
 
@@ -82,5 +118,9 @@ export function StsTokenConfigPaginatedResponseToJSONTyped(
 	return {
 		items: (value.items as Array<any>).map(StsTokenConfigToJSON),
 		next_page_token: value.nextPageToken,
+		prev_page_token: value.prevPageToken,
+		total_count: value.totalCount,
+		page_size: value.pageSize,
+		order: PaginationOrderToJSON(value.order),
 	};
 }
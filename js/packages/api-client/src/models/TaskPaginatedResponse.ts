@@ -12,6 +12,11 @@
  * Do not edit the class manually.
  */
 
+import type { PaginationOrder } from "./PaginationOrder";
+import {
+	PaginationOrderFromJSON,
+	PaginationOrderToJSON,
+} from "./PaginationOrder";
 import type { Task } from "./Task";
 import { TaskFromJSON, TaskToJSON } from "./Task";
 
@@ -33,6 +38,30 @@ export interface TaskPaginatedResponse {
 	 * @memberof TaskPaginatedResponse
 	 */
 	nextPageToken?: string;
+	/**
+	 *
+	 * @type {string}
+	 * @memberof TaskPaginatedResponse
+	 */
+	prevPageToken?: string;
+	/**
+	 *
+	 * @type {number}
+	 * @memberof TaskPaginatedResponse
+	 */
+	totalCount?: number;
+	/**
+	 *
+	 * @type {number}
+	 * @memberof TaskPaginatedResponse
+	 */
+	pageSize: number;
+	/**
+	 *
+	 * @type {PaginationOrder}
+	 * @memberof TaskPaginatedResponse
+	 */
+	order: PaginationOrder;
 }
 
 /**
@@ -42,6 +71,8 @@ export function instanceOfTaskPaginatedResponse(
 	value: object,
 ): value is TaskPaginatedResponse {
 	if (!("items" in value) || value.items === undefined) return false;
+	if (!("pageSize" in value) || value.pageSize === undefined) return false;
+	if (!("order" in value) || value.order === undefined) return false;
 	return true;
 }
 
@@ -62,6 +93,11 @@ export function TaskPaginatedResponseFromJSONTyped(
 		items: (json.items as Array<any>).map(TaskFromJSON),
 		nextPageToken:
 			json.next_page_token == null ? undefined : json.next_page_token,
+		prevPageToken:
+			json.prev_page_token == null ? undefined : json.prev_page_token,
+		totalCount: json.total_count == null ? undefined : json.total_count,
+		pageSize: json.page_size,
+		order: PaginationOrderFromJSON(json.order),
 	};
 }
 
@@ -80,5 +116,9 @@ export function TaskPaginatedResponseToJSONTyped(
 	return {
 		items: (value.items as Array<any>).map(TaskToJSON),
 		next_page_token: value.nextPageToken,
+		prev_page_token: value.prevPageToken,
+		total_count: value.totalCount,
+		page_size: value.pageSize,
+		order: PaginationOrderToJSON(value.order),
 	};
 }
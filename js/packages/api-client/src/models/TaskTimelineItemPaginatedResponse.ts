@@ -12,6 +12,11 @@
  * Do not edit the class manually.
  */
 
+import type { PaginationOrder } from "./PaginationOrder";
+import {
+	PaginationOrderFromJSON,
+	PaginationOrderToJSON,
+} from "./PaginationOrder";
 import type { TaskTimelineItem } from "./TaskTimelineItem";
 import {
 	TaskTimelineItemFromJSON,
@@ -36,6 +41,30 @@ export interface TaskTimelineItemPaginatedResponse {
 	 * @memberof TaskTimelineItemPaginatedResponse
 	 */
 	nextPageToken?: string;
+	/**
+	 *
+	 * @type {string}
+	 * @memberof TaskTimelineItemPaginatedResponse
+	 */
+	prevPageToken?: string;
+	/**
+	 *
+	 * @type {number}
+	 * @memberof TaskTimelineItemPaginatedResponse
+	 */
+	totalCount?: number;
+	/**
+	 *
+	 * @type {number}
+	 * @memberof TaskTimelineItemPaginatedResponse
+	 */
+	pageSize: number;
+	/**
+	 *
+	 * @type {PaginationOrder}
+	 * @memberof TaskTimelineItemPaginatedResponse
+	 */
+	order: PaginationOrder;
 }
 
 /**
@@ -45,6 +74,8 @@ export function instanceOfTaskTimelineItemPaginatedResponse(
 	value: object,
 ): value is TaskTimelineItemPaginatedResponse {
 	if (!("items" in value) || value.items === undefined) return false;
+	if (!("pageSize" in value) || value.pageSize === undefined) return false;
+	if (!("order" in value) || value.order === undefined) return false;
 	return true;
 }
 
@@ -65,6 +96,11 @@ export function TaskTimelineItemPaginatedResponseFromJSONTyped(
 		items: (json.items as Array<any>).map(TaskTimelineItemFromJSON),
 		nextPageToken:
 			json.next_page_token == null ? undefined : json.next_page_token,
+		prevPageToken:
+			json.prev_page_token == null ? undefined : json.prev_page_token,
+		totalCount: json.total_count == null ? undefined : json.total_count,
+		pageSize: json.page_size,
+		order: PaginationOrderFromJSON(json.order),
 	};
 }
 
@@ -85,5 +121,9 @@ export function TaskTimelineItemPaginatedResponseToJSONTyped(
 	return {
 		items: (value.items as Array<any>).map(TaskTimelineItemToJSON),
 		next_page_token: value.nextPageToken,
+		prev_page_token: value.prevPageToken,
+		total_count: value.totalCount,
+		page_size: value.pageSize,
+		order: PaginationOrderToJSON(value.order),
 	};
 }
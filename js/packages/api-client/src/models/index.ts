@@ -3,6 +3,7 @@
 export * from "./AddGroupMemberRequest";
 export * from "./AddMcpServerInstanceFunctionRequest";
 export * from "./AgentListItem";
+export * from "./AgentListItemPaginatedResponse";
 export * from "./BrokerAction";
 export * from "./BrokerActionOneOf";
 export * from "./BrokerActionRedirect";
@@ -45,11 +46,18 @@ export * from "./EncryptedUserAuthFlowConfigOneOf2";
 export * from "./EncryptedUserAuthFlowConfigOneOf3";
 export * from "./EnvelopeEncryptionKey";
 export * from "./EnvelopeEncryptionKeyAwsKms";
+export * from "./EnvelopeEncryptionKeyAzureKeyVault";
+export * from "./EnvelopeEncryptionKeyGcpKms";
 export * from "./EnvelopeEncryptionKeyLocal";
 export * from "./EnvelopeEncryptionKeyOneOf";
 export * from "./EnvelopeEncryptionKeyOneOf1";
+export * from "./EnvelopeEncryptionKeyOneOf2";
+export * from "./EnvelopeEncryptionKeyOneOf3";
+export * from "./EnvelopeEncryptionKeyOneOf4";
 export * from "./EnvelopeEncryptionKeyPaginatedResponse";
+export * from "./EnvelopeEncryptionKeyVaultTransit";
 export * from "./FunctionControllerSerialized";
+export * from "./FunctionExample";
 export * from "./FunctionInstanceConfig";
 export * from "./FunctionInstanceConfigPaginatedResponse";
 export * from "./FunctionInstanceListItem";
@@ -86,7 +94,6 @@ export * from "./JwtTemplateModeConfig";
 export * from "./JwtTokenMappingConfig";
 export * from "./JwtTokenTemplateConfig";
 export * from "./JwtTokenTemplateValidationConfig";
-export * from "./ListAgentsResponse";
 export * from "./ListApiKeysResponse";
 export * from "./ListDecryptedSecretsResponse";
 export * from "./ListSecretsResponse";
@@ -110,6 +117,7 @@ export * from "./ModelError";
 export * from "./NormalizedTokenIssuanceResult";
 export * from "./OauthConfig";
 export * from "./OidcConfig";
+export * from "./PaginationOrder";
 export * from "./ProviderControllerSerialized";
 export * from "./ProviderControllerSerializedPaginatedResponse";
 export * from "./ProviderCredentialControllerSerialized";
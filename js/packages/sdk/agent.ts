@@ -20,6 +20,11 @@ interface CreateSomaAgentParams {
 	agentId: string;
 	name: string;
 	description: string;
+	/**
+	 * Provider functions this agent may invoke, as "providerTypeId.functionTypeId".
+	 * Omitted or empty allows every function.
+	 */
+	allowedFunctions?: string[];
 	entrypoint: (params: HandlerParams) => Promise<void>;
 }
 
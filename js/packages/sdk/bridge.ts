@@ -1,12 +1,85 @@
-import type { FunctionController, ProviderController } from "@trysoma/sdk-core";
+import {
+	appendInvocationLog,
+	type CallbackError,
+	type FunctionController,
+	type LogLine,
+	type ProviderController,
+} from "@trysoma/sdk-core";
 import z from "zod";
 
+export type LogLevel = "debug" | "info" | "warn" | "error";
+
+/** Per-invocation context passed to a function handler */
+export interface SomaFunctionContext {
+	/** Logs a line that is returned to the caller with the invocation response */
+	log(level: LogLevel, message: string): void;
+	debug(message: string): void;
+	info(message: string): void;
+	warn(message: string): void;
+	error(message: string): void;
+}
+
+/**
+ * Creates the context for one invocation. Given the request's `invocationId`, lines are handed
+ * to the SDK as they are logged so they survive a timeout; otherwise `logs` collects them to be
+ * returned with the response.
+ */
+export function createFunctionContext(invocationId?: string): {
+	context: SomaFunctionContext;
+	logs: LogLine[];
+} {
+	const logs: LogLine[] = [];
+	const log = (level: LogLevel, message: string) => {
+		const line = { level, message, timestampMs: Date.now() };
+		if (invocationId !== undefined) {
+			appendInvocationLog(invocationId, line);
+		} else {
+			logs.push(line);
+		}
+	};
+	return {
+		context: {
+			log,
+			debug: (message) => log("debug", message),
+			info: (message) => log("info", message),
+			warn: (message) => log("warn", message),
+			error: (message) => log("error", message),
+		},
+		logs,
+	};
+}
+
+const isRecord = (value: unknown): value is Record<string, unknown> =>
+	typeof value === "object" && value !== null;
+
+/**
+ * Builds the error returned to the caller from a value thrown by a handler. `code` and
+ * `retryAfterMs` are read from the thrown value, or from the `{ error: { code, message } }`
+ * shape returned by the bridge clients; the code is left unset when there is none.
+ */
+export function toCallbackError(error: unknown): CallbackError {
+	const source = isRecord(error) && isRecord(error.error) ? error.error : error;
+	if (!isRecord(source)) {
+		return { message: String(error) };
+	}
+	const retryAfterMs = source.retryAfterMs ?? source.retry_after_ms;
+	return {
+		message:
+			typeof source.message === "string" ? source.message : String(error),
+		code: typeof source.code === "string" ? source.code : undefined,
+		retryAfterMs: typeof retryAfterMs === "number" ? retryAfterMs : undefined,
+	};
+}
+
 interface CreateSomaFunctionParams<InputType, OutputType> {
 	inputSchema: z.ZodSchema<InputType>;
 	outputSchema: z.ZodSchema<OutputType>;
 	providerController: ProviderController;
 	functionController: Omit<FunctionController, "parameters" | "output">;
-	handler: (input: InputType) => Promise<OutputType>;
+	handler: (
+		input: InputType,
+		context: SomaFunctionContext,
+	) => Promise<OutputType>;
 }
 
 export function createSomaFunction<InputType, OutputType>(
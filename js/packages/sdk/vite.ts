@@ -159,6 +159,7 @@ function generateStandaloneServer(
 
 	const functionImports: string[] = [];
 	const functionRegistrations: string[] = [];
+	const functionVarNames: string[] = [];
 	const agentImports: string[] = [];
 	const agentRegistrations: string[] = [];
 
@@ -171,13 +172,11 @@ function generateStandaloneServer(
 			? `../functions/${name}`
 			: `./functions/${name}.js`;
 		functionImports.push(`import ${varName} from '${importPath}';`);
+		functionVarNames.push(varName);
 		functionRegistrations.push(`
   // Register function: ${name}
   {
     const fn = await ${varName};
-    if (fn.providerController) {
-      addProvider(fn.providerController);
-    }
     if (fn.functionController && fn.providerController && fn.handler) {
       const providerTypeId = fn.providerController.typeId || fn.providerController.typeId;
       const functionMetadata = {
@@ -187,6 +186,19 @@ function generateStandaloneServer(
         output: fn.functionController.output,
       };
 
+      // Async generator handlers stream their results; unary calls get every chunk as an array
+      const isStreaming = Object.prototype.toString.call(fn.handler) === '[object AsyncGeneratorFunction]';
+      const runHandler = async (params: unknown, context: unknown): Promise<unknown> => {
+        if (!isStreaming) {
+          return fn.handler(params, context);
+        }
+        const items: unknown[] = [];
+        for await (const item of fn.handler(params, context)) {
+          items.push(item);
+        }
+        return items;
+      };
+
       // Create invoke callback that calls the handler
       const invokeCallback = async (err: Error | null, req: InvokeFunctionRequest): Promise<InvokeFunctionResponse> => {
         if (err) {
@@ -196,24 +208,43 @@ function generateStandaloneServer(
           return { error: callbackError };
         }
 
+        const { context, logs } = createFunctionContext(req.invocationId);
         try {
           // Parse the parameters and call the handler
           const params = JSON.parse(req.parameters);
           console.log(params);
-          const result = await fn.handler(params);
+          const result = await runHandler(params, context);
           console.log(result);
-          return { data: JSON.stringify(result) };
+          return { data: JSON.stringify(result), logs };
         } catch (error: unknown) {
           console.error(error);
-          const errorMessage = error instanceof Error ? error.message : String(error);
-          const callbackError: CallbackError = {
-            message: errorMessage,
-          };
-          return { error: callbackError };
+          return { error: toCallbackError(error), logs };
         }
       };
 
-      addFunction(providerTypeId, functionMetadata, invokeCallback);
+      // Create stream callback that sends each yielded item as a chunk
+      const invokeStreamCallback = isStreaming
+        ? async (err: Error | null, stream: FunctionChunkStream): Promise<void> => {
+            if (err) {
+              throw err;
+            }
+            const { context } = createFunctionContext();
+            try {
+              const params = JSON.parse(stream.request.parameters);
+              for await (const item of fn.handler(params, context)) {
+                // The client went away, returning from the loop closes the generator
+                if (!(await stream.send({ data: JSON.stringify(item) }))) {
+                  return;
+                }
+              }
+            } catch (error: unknown) {
+              console.error(error);
+              await stream.send({ error: toCallbackError(error) });
+            }
+          }
+        : undefined;
+
+      addFunction(providerTypeId, functionMetadata, invokeCallback, invokeStreamCallback);
     }
   }`);
 	}
@@ -235,6 +266,7 @@ function generateStandaloneServer(
         projectId: agent.projectId,
         name: agent.name,
         description: agent.description,
+        allowedFunctions: agent.allowedFunctions,
       });
     }
   }`);
@@ -257,8 +289,8 @@ function generateStandaloneServer(
 
 	return `/// <reference types="node" />
 // Auto-generated standalone server
-import { addFunction, addProvider, addAgent, startGrpcServer, killGrpcService, setSecretHandler, setEnvironmentVariableHandler, setUnsetSecretHandler, setUnsetEnvironmentVariableHandler, resyncSdk } from '@trysoma/sdk';
-import type { Secret, SetSecretsResponse, SetSecretsSuccess, CallbackError, EnvironmentVariable, SetEnvironmentVariablesResponse, SetEnvironmentVariablesSuccess, UnsetSecretResponse, UnsetSecretSuccess, UnsetEnvironmentVariableResponse, UnsetEnvironmentVariableSuccess, InvokeFunctionRequest, InvokeFunctionResponse } from '@trysoma/sdk';
+import { addFunction, reconcileProviders, addAgent, startGrpcServer, killGrpcService, setSecretHandler, setEnvironmentVariableHandler, setUnsetSecretHandler, setUnsetEnvironmentVariableHandler, resyncSdk, createFunctionContext, toCallbackError } from '@trysoma/sdk';
+import type { Secret, SetSecretsResponse, SetSecretsSuccess, CallbackError, EnvironmentVariable, SetEnvironmentVariablesResponse, SetEnvironmentVariablesSuccess, UnsetSecretResponse, UnsetSecretSuccess, UnsetEnvironmentVariableResponse, UnsetEnvironmentVariableSuccess, InvokeFunctionRequest, InvokeFunctionResponse, FunctionChunkStream } from '@trysoma/sdk';
 import * as restate from '@restatedev/restate-sdk';
 import * as http2 from 'http2';
 
@@ -346,7 +378,19 @@ setUnsetEnvironmentVariableHandler(async (err, key) => {
   return { data: { message: \`Removed env var '\${key}'\` } };
 });
 
-// Register all providers and functions
+// Register all providers in one step, so providers shared by several functions are added once
+// and providers no longer backed by a function are removed
+{
+  const providers = new Map();
+  for (const fn of await Promise.all([${functionVarNames.join(", ")}])) {
+    if (fn.providerController) {
+      providers.set(fn.providerController.typeId, fn.providerController);
+    }
+  }
+  reconcileProviders([...providers.values()]);
+}
+
+// Register all functions
 ${functionRegistrations.join("\n")}
 
 // Register all agents
@@ -437,7 +481,7 @@ const shutdown = async () => {
   console.debug('[SDK] Shutting down');
 
   try {
-    killGrpcService();
+    await killGrpcService();
   } catch (err) {
     console.error('[SDK] Error killing gRPC:', err);
   }
@@ -551,12 +595,12 @@ process.on('unhandledRejection', (reason: unknown) => {
   }
 });
 
-const shutdownNoAgents = () => {
+const shutdownNoAgents = async () => {
   if (isShuttingDownNoAgents) return;
   isShuttingDownNoAgents = true;
   console.debug('[SDK] Shutting down');
   try {
-    killGrpcService();
+    await killGrpcService();
   } catch (err) {
     console.error('[SDK] Error killing gRPC:', err);
   }
@@ -38,6 +38,7 @@ __all__ = [
     "AddGroupMemberRequest",
     "AddMcpServerInstanceFunctionRequest",
     "AgentListItem",
+    "AgentListItemPaginatedResponse",
     "BrokerAction",
     "BrokerActionOneOf",
     "BrokerActionRedirect",
@@ -80,10 +81,16 @@ __all__ = [
     "EncryptedUserAuthFlowConfigOneOf3",
     "EnvelopeEncryptionKey",
     "EnvelopeEncryptionKeyAwsKms",
+    "EnvelopeEncryptionKeyAzureKeyVault",
+    "EnvelopeEncryptionKeyGcpKms",
     "EnvelopeEncryptionKeyLocal",
     "EnvelopeEncryptionKeyOneOf",
     "EnvelopeEncryptionKeyOneOf1",
+    "EnvelopeEncryptionKeyOneOf2",
+    "EnvelopeEncryptionKeyOneOf3",
+    "EnvelopeEncryptionKeyOneOf4",
     "EnvelopeEncryptionKeyPaginatedResponse",
+    "EnvelopeEncryptionKeyVaultTransit",
     "Error",
     "FunctionControllerSerialized",
     "FunctionInstanceConfig",
@@ -122,7 +129,6 @@ __all__ = [
     "JwtTokenMappingConfig",
     "JwtTokenTemplateConfig",
     "JwtTokenTemplateValidationConfig",
-    "ListAgentsResponse",
     "ListApiKeysResponse",
     "ListDecryptedSecretsResponse",
     "ListSecretsResponse",
@@ -250,6 +256,9 @@ from trysoma_api_client.models.add_mcp_server_instance_function_request import (
     AddMcpServerInstanceFunctionRequest as AddMcpServerInstanceFunctionRequest,
 )
 from trysoma_api_client.models.agent_list_item import AgentListItem as AgentListItem
+from trysoma_api_client.models.agent_list_item_paginated_response import (
+    AgentListItemPaginatedResponse as AgentListItemPaginatedResponse,
+)
 from trysoma_api_client.models.broker_action import BrokerAction as BrokerAction
 from trysoma_api_client.models.broker_action_one_of import (
     BrokerActionOneOf as BrokerActionOneOf,
@@ -368,6 +377,12 @@ from trysoma_api_client.models.envelope_encryption_key import (
 from trysoma_api_client.models.envelope_encryption_key_aws_kms import (
     EnvelopeEncryptionKeyAwsKms as EnvelopeEncryptionKeyAwsKms,
 )
+from trysoma_api_client.models.envelope_encryption_key_azure_key_vault import (
+    EnvelopeEncryptionKeyAzureKeyVault as EnvelopeEncryptionKeyAzureKeyVault,
+)
+from trysoma_api_client.models.envelope_encryption_key_gcp_kms import (
+    EnvelopeEncryptionKeyGcpKms as EnvelopeEncryptionKeyGcpKms,
+)
 from trysoma_api_client.models.envelope_encryption_key_local import (
     EnvelopeEncryptionKeyLocal as EnvelopeEncryptionKeyLocal,
 )
@@ -377,9 +392,21 @@ from trysoma_api_client.models.envelope_encryption_key_one_of import (
 from trysoma_api_client.models.envelope_encryption_key_one_of1 import (
     EnvelopeEncryptionKeyOneOf1 as EnvelopeEncryptionKeyOneOf1,
 )
+from trysoma_api_client.models.envelope_encryption_key_one_of2 import (
+    EnvelopeEncryptionKeyOneOf2 as EnvelopeEncryptionKeyOneOf2,
+)
+from trysoma_api_client.models.envelope_encryption_key_one_of3 import (
+    EnvelopeEncryptionKeyOneOf3 as EnvelopeEncryptionKeyOneOf3,
+)
+from trysoma_api_client.models.envelope_encryption_key_one_of4 import (
+    EnvelopeEncryptionKeyOneOf4 as EnvelopeEncryptionKeyOneOf4,
+)
 from trysoma_api_client.models.envelope_encryption_key_paginated_response import (
     EnvelopeEncryptionKeyPaginatedResponse as EnvelopeEncryptionKeyPaginatedResponse,
 )
+from trysoma_api_client.models.envelope_encryption_key_vault_transit import (
+    EnvelopeEncryptionKeyVaultTransit as EnvelopeEncryptionKeyVaultTransit,
+)
 from trysoma_api_client.models.error import Error as Error
 from trysoma_api_client.models.function_controller_serialized import (
     FunctionControllerSerialized as FunctionControllerSerialized,
@@ -466,9 +493,6 @@ from trysoma_api_client.models.jwt_token_template_config import (
 from trysoma_api_client.models.jwt_token_template_validation_config import (
     JwtTokenTemplateValidationConfig as JwtTokenTemplateValidationConfig,
 )
-from trysoma_api_client.models.list_agents_response import (
-    ListAgentsResponse as ListAgentsResponse,
-)
 from trysoma_api_client.models.list_api_keys_response import (
     ListApiKeysResponse as ListApiKeysResponse,
 )
@@ -16,10 +16,12 @@ from pydantic import validate_call, Field, StrictFloat, StrictStr, StrictInt
 from typing import Any, Dict, List, Optional, Tuple, Union
 from typing_extensions import Annotated
 
-from pydantic import Field, StrictStr
-from typing import Any, Dict
+from pydantic import Field, StrictInt, StrictStr
+from typing import Any, Dict, Optional
 from typing_extensions import Annotated
-from trysoma_api_client.models.list_agents_response import ListAgentsResponse
+from trysoma_api_client.models.agent_list_item_paginated_response import (
+    AgentListItemPaginatedResponse,
+)
 
 from trysoma_api_client.api_client import ApiClient, RequestSerialized
 from trysoma_api_client.api_response import ApiResponse
@@ -96,6 +98,8 @@ class AgentApi:
 
         _response_types_map: Dict[str, Optional[str]] = {
             "200": "object",
+            "404": "Error",
+            "500": "Error",
         }
         response_data = self.api_client.call_api(
             *_param, _request_timeout=_request_timeout
@@ -164,6 +168,8 @@ class AgentApi:
 
         _response_types_map: Dict[str, Optional[str]] = {
             "200": "object",
+            "404": "Error",
+            "500": "Error",
         }
         response_data = self.api_client.call_api(
             *_param, _request_timeout=_request_timeout
@@ -232,6 +238,8 @@ class AgentApi:
 
         _response_types_map: Dict[str, Optional[str]] = {
             "200": "object",
+            "404": "Error",
+            "500": "Error",
         }
         response_data = self.api_client.call_api(
             *_param, _request_timeout=_request_timeout
@@ -575,6 +583,8 @@ class AgentApi:
     @validate_call
     def list_agents(
         self,
+        page_size: StrictInt,
+        next_page_token: Optional[StrictStr] = None,
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -586,11 +596,15 @@ class AgentApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ListAgentsResponse:
+    ) -> AgentListItemPaginatedResponse:
         """List available agents
 
-        List all available agents from the agent cache
+        List available agents from the agent cache with pagination
 
+        :param page_size: (required)
+        :type page_size: int
+        :param next_page_token:
+        :type next_page_token: str
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -614,6 +628,8 @@ class AgentApi:
         """  # noqa: E501
 
         _param = self._list_agents_serialize(
+            page_size=page_size,
+            next_page_token=next_page_token,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -621,7 +637,8 @@ class AgentApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            "200": "ListAgentsResponse",
+            "200": "AgentListItemPaginatedResponse",
+            "400": "Error",
         }
         response_data = self.api_client.call_api(
             *_param, _request_timeout=_request_timeout
@@ -635,6 +652,8 @@ class AgentApi:
     @validate_call
     def list_agents_with_http_info(
         self,
+        page_size: StrictInt,
+        next_page_token: Optional[StrictStr] = None,
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -646,11 +665,15 @@ class AgentApi:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ApiResponse[ListAgentsResponse]:
+    ) -> ApiResponse[AgentListItemPaginatedResponse]:
         """List available agents
 
-        List all available agents from the agent cache
+        List available agents from the agent cache with pagination
 
+        :param page_size: (required)
+        :type page_size: int
+        :param next_page_token:
+        :type next_page_token: str
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -674,6 +697,8 @@ class AgentApi:
         """  # noqa: E501
 
         _param = self._list_agents_serialize(
+            page_size=page_size,
+            next_page_token=next_page_token,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -681,7 +706,8 @@ class AgentApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            "200": "ListAgentsResponse",
+            "200": "AgentListItemPaginatedResponse",
+            "400": "Error",
         }
         response_data = self.api_client.call_api(
             *_param, _request_timeout=_request_timeout
@@ -695,6 +721,8 @@ class AgentApi:
     @validate_call
     def list_agents_without_preload_content(
         self,
+        page_size: StrictInt,
+        next_page_token: Optional[StrictStr] = None,
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -709,8 +737,12 @@ class AgentApi:
     ) -> RESTResponseType:
         """List available agents
 
-        List all available agents from the agent cache
+        List available agents from the agent cache with pagination
 
+        :param page_size: (required)
+        :type page_size: int
+        :param next_page_token:
+        :type next_page_token: str
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -734,6 +766,8 @@ class AgentApi:
         """  # noqa: E501
 
         _param = self._list_agents_serialize(
+            page_size=page_size,
+            next_page_token=next_page_token,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -741,7 +775,8 @@ class AgentApi:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            "200": "ListAgentsResponse",
+            "200": "AgentListItemPaginatedResponse",
+            "400": "Error",
         }
         response_data = self.api_client.call_api(
             *_param, _request_timeout=_request_timeout
@@ -750,6 +785,8 @@ class AgentApi:
 
     def _list_agents_serialize(
         self,
+        page_size,
+        next_page_token,
         _request_auth,
         _content_type,
         _headers,
@@ -770,6 +807,12 @@ class AgentApi:
 
         # process the path parameters
         # process the query parameters
+        if page_size is not None:
+            _query_params.append(("page_size", page_size))
+
+        if next_page_token is not None:
+            _query_params.append(("next_page_token", next_page_token))
+
         # process the header parameters
         # process the form parameters
         # process the body parameter
@@ -797,3 +840,4 @@ class AgentApi:
             _host=_host,
             _request_auth=_request_auth,
         )
+
@@ -24,6 +24,9 @@ from trysoma_api_client.models.add_group_member_request import AddGroupMemberReq
 from trysoma_api_client.models.add_mcp_server_instance_function_request import (
     AddMcpServerInstanceFunctionRequest,
 )
+from trysoma_api_client.models.agent_list_item_paginated_response import (
+    AgentListItemPaginatedResponse,
+)
 from trysoma_api_client.models.context_info_paginated_response import (
     ContextInfoPaginatedResponse,
 )
@@ -106,7 +109,6 @@ from trysoma_api_client.models.jwk_response_paginated_response import (
     JwkResponsePaginatedResponse,
 )
 from trysoma_api_client.models.jwks_response import JwksResponse
-from trysoma_api_client.models.list_agents_response import ListAgentsResponse
 from trysoma_api_client.models.list_api_keys_response import ListApiKeysResponse
 from trysoma_api_client.models.list_decrypted_secrets_response import (
     ListDecryptedSecretsResponse,
@@ -6783,6 +6785,8 @@ class V1Api:
 
         _response_types_map: Dict[str, Optional[str]] = {
             "200": "object",
+            "404": "Error",
+            "500": "Error",
         }
         response_data = self.api_client.call_api(
             *_param, _request_timeout=_request_timeout
@@ -6851,6 +6855,8 @@ class V1Api:
 
         _response_types_map: Dict[str, Optional[str]] = {
             "200": "object",
+            "404": "Error",
+            "500": "Error",
         }
         response_data = self.api_client.call_api(
             *_param, _request_timeout=_request_timeout
@@ -6919,6 +6925,8 @@ class V1Api:
 
         _response_types_map: Dict[str, Optional[str]] = {
             "200": "object",
+            "404": "Error",
+            "500": "Error",
         }
         response_data = self.api_client.call_api(
             *_param, _request_timeout=_request_timeout
@@ -11600,6 +11608,8 @@ class V1Api:
     @validate_call
     def list_agents(
         self,
+        page_size: StrictInt,
+        next_page_token: Optional[StrictStr] = None,
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -11611,11 +11621,15 @@ class V1Api:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ListAgentsResponse:
+    ) -> AgentListItemPaginatedResponse:
         """List available agents
 
-        List all available agents from the agent cache
+        List available agents from the agent cache with pagination
 
+        :param page_size: (required)
+        :type page_size: int
+        :param next_page_token:
+        :type next_page_token: str
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -11639,6 +11653,8 @@ class V1Api:
         """  # noqa: E501
 
         _param = self._list_agents_serialize(
+            page_size=page_size,
+            next_page_token=next_page_token,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -11646,7 +11662,8 @@ class V1Api:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            "200": "ListAgentsResponse",
+            "200": "AgentListItemPaginatedResponse",
+            "400": "Error",
         }
         response_data = self.api_client.call_api(
             *_param, _request_timeout=_request_timeout
@@ -11660,6 +11677,8 @@ class V1Api:
     @validate_call
     def list_agents_with_http_info(
         self,
+        page_size: StrictInt,
+        next_page_token: Optional[StrictStr] = None,
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -11671,11 +11690,15 @@ class V1Api:
         _content_type: Optional[StrictStr] = None,
         _headers: Optional[Dict[StrictStr, Any]] = None,
         _host_index: Annotated[StrictInt, Field(ge=0, le=0)] = 0,
-    ) -> ApiResponse[ListAgentsResponse]:
+    ) -> ApiResponse[AgentListItemPaginatedResponse]:
         """List available agents
 
-        List all available agents from the agent cache
+        List available agents from the agent cache with pagination
 
+        :param page_size: (required)
+        :type page_size: int
+        :param next_page_token:
+        :type next_page_token: str
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -11699,6 +11722,8 @@ class V1Api:
         """  # noqa: E501
 
         _param = self._list_agents_serialize(
+            page_size=page_size,
+            next_page_token=next_page_token,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -11706,7 +11731,8 @@ class V1Api:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            "200": "ListAgentsResponse",
+            "200": "AgentListItemPaginatedResponse",
+            "400": "Error",
         }
         response_data = self.api_client.call_api(
             *_param, _request_timeout=_request_timeout
@@ -11720,6 +11746,8 @@ class V1Api:
     @validate_call
     def list_agents_without_preload_content(
         self,
+        page_size: StrictInt,
+        next_page_token: Optional[StrictStr] = None,
         _request_timeout: Union[
             None,
             Annotated[StrictFloat, Field(gt=0)],
@@ -11734,8 +11762,12 @@ class V1Api:
     ) -> RESTResponseType:
         """List available agents
 
-        List all available agents from the agent cache
+        List available agents from the agent cache with pagination
 
+        :param page_size: (required)
+        :type page_size: int
+        :param next_page_token:
+        :type next_page_token: str
         :param _request_timeout: timeout setting for this request. If one
                                  number provided, it will be total request
                                  timeout. It can also be a pair (tuple) of
@@ -11759,6 +11791,8 @@ class V1Api:
         """  # noqa: E501
 
         _param = self._list_agents_serialize(
+            page_size=page_size,
+            next_page_token=next_page_token,
             _request_auth=_request_auth,
             _content_type=_content_type,
             _headers=_headers,
@@ -11766,7 +11800,8 @@ class V1Api:
         )
 
         _response_types_map: Dict[str, Optional[str]] = {
-            "200": "ListAgentsResponse",
+            "200": "AgentListItemPaginatedResponse",
+            "400": "Error",
         }
         response_data = self.api_client.call_api(
             *_param, _request_timeout=_request_timeout
@@ -11775,6 +11810,8 @@ class V1Api:
 
     def _list_agents_serialize(
         self,
+        page_size,
+        next_page_token,
         _request_auth,
         _content_type,
         _headers,
@@ -11795,6 +11832,12 @@ class V1Api:
 
         # process the path parameters
         # process the query parameters
+        if page_size is not None:
+            _query_params.append(("page_size", page_size))
+
+        if next_page_token is not None:
+            _query_params.append(("next_page_token", next_page_token))
+
         # process the header parameters
         # process the form parameters
         # process the body parameter
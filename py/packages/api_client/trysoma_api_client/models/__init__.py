@@ -18,6 +18,9 @@ from trysoma_api_client.models.add_mcp_server_instance_function_request import (
     AddMcpServerInstanceFunctionRequest,
 )
 from trysoma_api_client.models.agent_list_item import AgentListItem
+from trysoma_api_client.models.agent_list_item_paginated_response import (
+    AgentListItemPaginatedResponse,
+)
 from trysoma_api_client.models.broker_action import BrokerAction
 from trysoma_api_client.models.broker_action_one_of import BrokerActionOneOf
 from trysoma_api_client.models.broker_action_redirect import BrokerActionRedirect
@@ -94,6 +97,12 @@ from trysoma_api_client.models.envelope_encryption_key import EnvelopeEncryption
 from trysoma_api_client.models.envelope_encryption_key_aws_kms import (
     EnvelopeEncryptionKeyAwsKms,
 )
+from trysoma_api_client.models.envelope_encryption_key_azure_key_vault import (
+    EnvelopeEncryptionKeyAzureKeyVault,
+)
+from trysoma_api_client.models.envelope_encryption_key_gcp_kms import (
+    EnvelopeEncryptionKeyGcpKms,
+)
 from trysoma_api_client.models.envelope_encryption_key_local import (
     EnvelopeEncryptionKeyLocal,
 )
@@ -103,9 +112,21 @@ from trysoma_api_client.models.envelope_encryption_key_one_of import (
 from trysoma_api_client.models.envelope_encryption_key_one_of1 import (
     EnvelopeEncryptionKeyOneOf1,
 )
+from trysoma_api_client.models.envelope_encryption_key_one_of2 import (
+    EnvelopeEncryptionKeyOneOf2,
+)
+from trysoma_api_client.models.envelope_encryption_key_one_of3 import (
+    EnvelopeEncryptionKeyOneOf3,
+)
+from trysoma_api_client.models.envelope_encryption_key_one_of4 import (
+    EnvelopeEncryptionKeyOneOf4,
+)
 from trysoma_api_client.models.envelope_encryption_key_paginated_response import (
     EnvelopeEncryptionKeyPaginatedResponse,
 )
+from trysoma_api_client.models.envelope_encryption_key_vault_transit import (
+    EnvelopeEncryptionKeyVaultTransit,
+)
 from trysoma_api_client.models.error import Error
 from trysoma_api_client.models.function_controller_serialized import (
     FunctionControllerSerialized,
@@ -168,7 +189,6 @@ from trysoma_api_client.models.jwt_token_template_config import JwtTokenTemplate
 from trysoma_api_client.models.jwt_token_template_validation_config import (
     JwtTokenTemplateValidationConfig,
 )
-from trysoma_api_client.models.list_agents_response import ListAgentsResponse
 from trysoma_api_client.models.list_api_keys_response import ListApiKeysResponse
 from trysoma_api_client.models.list_decrypted_secrets_response import (
     ListDecryptedSecretsResponse,
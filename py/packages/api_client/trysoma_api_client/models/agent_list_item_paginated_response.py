@@ -16,20 +16,21 @@ import pprint
 import re  # noqa: F401
 import json
 
-from pydantic import BaseModel, ConfigDict, Field
-from typing import Any, ClassVar, Dict, List
+from pydantic import BaseModel, ConfigDict, StrictStr
+from typing import Any, ClassVar, Dict, List, Optional
 from trysoma_api_client.models.agent_list_item import AgentListItem
 from typing import Optional, Set
 from typing_extensions import Self
 
 
-class ListAgentsResponse(BaseModel):
+class AgentListItemPaginatedResponse(BaseModel):
     """
-    Response for listing agents
+    AgentListItemPaginatedResponse
     """  # noqa: E501
 
-    agents: List[AgentListItem] = Field(description="List of agents")
-    __properties: ClassVar[List[str]] = ["agents"]
+    items: List[AgentListItem]
+    next_page_token: Optional[StrictStr] = None
+    __properties: ClassVar[List[str]] = ["items", "next_page_token"]
 
     model_config = ConfigDict(
         populate_by_name=True,
@@ -48,7 +49,7 @@ class ListAgentsResponse(BaseModel):
 
     @classmethod
     def from_json(cls, json_str: str) -> Optional[Self]:
-        """Create an instance of ListAgentsResponse from a JSON string"""
+        """Create an instance of AgentListItemPaginatedResponse from a JSON string"""
         return cls.from_dict(json.loads(json_str))
 
     def to_dict(self) -> Dict[str, Any]:
@@ -68,18 +69,18 @@ class ListAgentsResponse(BaseModel):
             exclude=excluded_fields,
             exclude_none=True,
         )
-        # override the default output from pydantic by calling `to_dict()` of each item in agents (list)
+        # override the default output from pydantic by calling `to_dict()` of each item in items (list)
         _items = []
-        if self.agents:
-            for _item_agents in self.agents:
-                if _item_agents:
-                    _items.append(_item_agents.to_dict())
-            _dict["agents"] = _items
+        if self.items:
+            for _item_items in self.items:
+                if _item_items:
+                    _items.append(_item_items.to_dict())
+            _dict["items"] = _items
         return _dict
 
     @classmethod
     def from_dict(cls, obj: Optional[Dict[str, Any]]) -> Optional[Self]:
-        """Create an instance of ListAgentsResponse from a dict"""
+        """Create an instance of AgentListItemPaginatedResponse from a dict"""
         if obj is None:
             return None
 
@@ -88,9 +89,10 @@ class ListAgentsResponse(BaseModel):
 
         _obj = cls.model_validate(
             {
-                "agents": [AgentListItem.from_dict(_item) for _item in obj["agents"]]
-                if obj.get("agents") is not None
-                else None
+                "items": [AgentListItem.from_dict(_item) for _item in obj["items"]]
+                if obj.get("items") is not None
+                else None,
+                "next_page_token": obj.get("next_page_token"),
             }
         )
         return _obj
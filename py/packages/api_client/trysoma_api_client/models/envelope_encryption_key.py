@@ -29,6 +29,15 @@ from trysoma_api_client.models.envelope_encryption_key_one_of import (
 from trysoma_api_client.models.envelope_encryption_key_one_of1 import (
     EnvelopeEncryptionKeyOneOf1,
 )
+from trysoma_api_client.models.envelope_encryption_key_one_of2 import (
+    EnvelopeEncryptionKeyOneOf2,
+)
+from trysoma_api_client.models.envelope_encryption_key_one_of3 import (
+    EnvelopeEncryptionKeyOneOf3,
+)
+from trysoma_api_client.models.envelope_encryption_key_one_of4 import (
+    EnvelopeEncryptionKeyOneOf4,
+)
 from pydantic import StrictStr, Field
 from typing import Union, List, Set, Optional, Dict
 from typing_extensions import Literal, Self
@@ -36,6 +45,9 @@ from typing_extensions import Literal, Self
 ENVELOPEENCRYPTIONKEY_ONE_OF_SCHEMAS = [
     "EnvelopeEncryptionKeyOneOf",
     "EnvelopeEncryptionKeyOneOf1",
+    "EnvelopeEncryptionKeyOneOf2",
+    "EnvelopeEncryptionKeyOneOf3",
+    "EnvelopeEncryptionKeyOneOf4",
 ]
 
 
@@ -48,12 +60,27 @@ class EnvelopeEncryptionKey(BaseModel):
     oneof_schema_1_validator: Optional[EnvelopeEncryptionKeyOneOf] = None
     # data type: EnvelopeEncryptionKeyOneOf1
     oneof_schema_2_validator: Optional[EnvelopeEncryptionKeyOneOf1] = None
+    # data type: EnvelopeEncryptionKeyOneOf2
+    oneof_schema_3_validator: Optional[EnvelopeEncryptionKeyOneOf2] = None
+    # data type: EnvelopeEncryptionKeyOneOf3
+    oneof_schema_4_validator: Optional[EnvelopeEncryptionKeyOneOf3] = None
+    # data type: EnvelopeEncryptionKeyOneOf4
+    oneof_schema_5_validator: Optional[EnvelopeEncryptionKeyOneOf4] = None
     actual_instance: Optional[
-        Union[EnvelopeEncryptionKeyOneOf, EnvelopeEncryptionKeyOneOf1]
+        Union[
+            EnvelopeEncryptionKeyOneOf,
+            EnvelopeEncryptionKeyOneOf1,
+            EnvelopeEncryptionKeyOneOf2,
+            EnvelopeEncryptionKeyOneOf3,
+            EnvelopeEncryptionKeyOneOf4,
+        ]
     ] = None
     one_of_schemas: Set[str] = {
         "EnvelopeEncryptionKeyOneOf",
         "EnvelopeEncryptionKeyOneOf1",
+        "EnvelopeEncryptionKeyOneOf2",
+        "EnvelopeEncryptionKeyOneOf3",
+        "EnvelopeEncryptionKeyOneOf4",
     }
 
     model_config = ConfigDict(
@@ -94,16 +121,37 @@ class EnvelopeEncryptionKey(BaseModel):
             )
         else:
             match += 1
+        # validate data type: EnvelopeEncryptionKeyOneOf2
+        if not isinstance(v, EnvelopeEncryptionKeyOneOf2):
+            error_messages.append(
+                f"Error! Input type `{type(v)}` is not `EnvelopeEncryptionKeyOneOf2`"
+            )
+        else:
+            match += 1
+        # validate data type: EnvelopeEncryptionKeyOneOf3
+        if not isinstance(v, EnvelopeEncryptionKeyOneOf3):
+            error_messages.append(
+                f"Error! Input type `{type(v)}` is not `EnvelopeEncryptionKeyOneOf3`"
+            )
+        else:
+            match += 1
+        # validate data type: EnvelopeEncryptionKeyOneOf4
+        if not isinstance(v, EnvelopeEncryptionKeyOneOf4):
+            error_messages.append(
+                f"Error! Input type `{type(v)}` is not `EnvelopeEncryptionKeyOneOf4`"
+            )
+        else:
+            match += 1
         if match > 1:
             # more than 1 match
             raise ValueError(
-                "Multiple matches found when setting `actual_instance` in EnvelopeEncryptionKey with oneOf schemas: EnvelopeEncryptionKeyOneOf, EnvelopeEncryptionKeyOneOf1. Details: "
+                "Multiple matches found when setting `actual_instance` in EnvelopeEncryptionKey with oneOf schemas: EnvelopeEncryptionKeyOneOf, EnvelopeEncryptionKeyOneOf1, EnvelopeEncryptionKeyOneOf2, EnvelopeEncryptionKeyOneOf3, EnvelopeEncryptionKeyOneOf4. Details: "
                 + ", ".join(error_messages)
             )
         elif match == 0:
             # no match
             raise ValueError(
-                "No match found when setting `actual_instance` in EnvelopeEncryptionKey with oneOf schemas: EnvelopeEncryptionKeyOneOf, EnvelopeEncryptionKeyOneOf1. Details: "
+                "No match found when setting `actual_instance` in EnvelopeEncryptionKey with oneOf schemas: EnvelopeEncryptionKeyOneOf, EnvelopeEncryptionKeyOneOf1, EnvelopeEncryptionKeyOneOf2, EnvelopeEncryptionKeyOneOf3, EnvelopeEncryptionKeyOneOf4. Details: "
                 + ", ".join(error_messages)
             )
         else:
@@ -132,17 +180,35 @@ class EnvelopeEncryptionKey(BaseModel):
             match += 1
         except (ValidationError, ValueError) as e:
             error_messages.append(str(e))
+        # deserialize data into EnvelopeEncryptionKeyOneOf2
+        try:
+            instance.actual_instance = EnvelopeEncryptionKeyOneOf2.from_json(json_str)
+            match += 1
+        except (ValidationError, ValueError) as e:
+            error_messages.append(str(e))
+        # deserialize data into EnvelopeEncryptionKeyOneOf3
+        try:
+            instance.actual_instance = EnvelopeEncryptionKeyOneOf3.from_json(json_str)
+            match += 1
+        except (ValidationError, ValueError) as e:
+            error_messages.append(str(e))
+        # deserialize data into EnvelopeEncryptionKeyOneOf4
+        try:
+            instance.actual_instance = EnvelopeEncryptionKeyOneOf4.from_json(json_str)
+            match += 1
+        except (ValidationError, ValueError) as e:
+            error_messages.append(str(e))
 
         if match > 1:
             # more than 1 match
             raise ValueError(
-                "Multiple matches found when deserializing the JSON string into EnvelopeEncryptionKey with oneOf schemas: EnvelopeEncryptionKeyOneOf, EnvelopeEncryptionKeyOneOf1. Details: "
+                "Multiple matches found when deserializing the JSON string into EnvelopeEncryptionKey with oneOf schemas: EnvelopeEncryptionKeyOneOf, EnvelopeEncryptionKeyOneOf1, EnvelopeEncryptionKeyOneOf2, EnvelopeEncryptionKeyOneOf3, EnvelopeEncryptionKeyOneOf4. Details: "
                 + ", ".join(error_messages)
             )
         elif match == 0:
             # no match
             raise ValueError(
-                "No match found when deserializing the JSON string into EnvelopeEncryptionKey with oneOf schemas: EnvelopeEncryptionKeyOneOf, EnvelopeEncryptionKeyOneOf1. Details: "
+                "No match found when deserializing the JSON string into EnvelopeEncryptionKey with oneOf schemas: EnvelopeEncryptionKeyOneOf, EnvelopeEncryptionKeyOneOf1, EnvelopeEncryptionKeyOneOf2, EnvelopeEncryptionKeyOneOf3, EnvelopeEncryptionKeyOneOf4. Details: "
                 + ", ".join(error_messages)
             )
         else:
@@ -163,7 +229,14 @@ class EnvelopeEncryptionKey(BaseModel):
     def to_dict(
         self,
     ) -> Optional[
-        Union[Dict[str, Any], EnvelopeEncryptionKeyOneOf, EnvelopeEncryptionKeyOneOf1]
+        Union[
+            Dict[str, Any],
+            EnvelopeEncryptionKeyOneOf,
+            EnvelopeEncryptionKeyOneOf1,
+            EnvelopeEncryptionKeyOneOf2,
+            EnvelopeEncryptionKeyOneOf3,
+            EnvelopeEncryptionKeyOneOf4,
+        ]
     ]:
         """Returns the dict representation of the actual instance"""
         if self.actual_instance is None:
@@ -0,0 +1,90 @@
+# coding: utf-8
+
+"""
+soma
+
+An open source AI agent runtime
+
+The version of the OpenAPI document: v1
+Generated by OpenAPI Generator (https://openapi-generator.tech)
+
+Do not edit the class manually.
+"""  # noqa: E501
+
+from __future__ import annotations
+import pprint
+import re  # noqa: F401
+import json
+
+from pydantic import BaseModel, ConfigDict, StrictStr
+from typing import Any, ClassVar, Dict, List
+from typing import Optional, Set
+from typing_extensions import Self
+
+
+class EnvelopeEncryptionKeyAzureKeyVault(BaseModel):
+    """
+    An Azure Key Vault RSA key. `vault_url` is the vault's base URL, e.g. `https://my-vault.vault.azure.net`, and `key_version` pins the key version DEKs are wrapped with.
+    """  # noqa: E501
+
+    vault_url: StrictStr
+    key_name: StrictStr
+    key_version: StrictStr
+    __properties: ClassVar[List[str]] = ["vault_url", "key_name", "key_version"]
+
+    model_config = ConfigDict(
+        populate_by_name=True,
+        validate_assignment=True,
+        protected_namespaces=(),
+    )
+
+    def to_str(self) -> str:
+        """Returns the string representation of the model using alias"""
+        return pprint.pformat(self.model_dump(by_alias=True))
+
+    def to_json(self) -> str:
+        """Returns the JSON representation of the model using alias"""
+        # TODO: pydantic v2: use .model_dump_json(by_alias=True, exclude_unset=True) instead
+        return json.dumps(self.to_dict())
+
+    @classmethod
+    def from_json(cls, json_str: str) -> Optional[Self]:
+        """Create an instance of EnvelopeEncryptionKeyAzureKeyVault from a JSON string"""
+        return cls.from_dict(json.loads(json_str))
+
+    def to_dict(self) -> Dict[str, Any]:
+        """Return the dictionary representation of the model using alias.
+
+        This has the following differences from calling pydantic's
+        `self.model_dump(by_alias=True)`:
+
+        * `None` is only added to the output dict for nullable fields that
+          were set at model initialization. Other fields with value `None`
+          are ignored.
+        """
+        excluded_fields: Set[str] = set([])
+
+        _dict = self.model_dump(
+            by_alias=True,
+            exclude=excluded_fields,
+            exclude_none=True,
+        )
+        return _dict
+
+    @classmethod
+    def from_dict(cls, obj: Optional[Dict[str, Any]]) -> Optional[Self]:
+        """Create an instance of EnvelopeEncryptionKeyAzureKeyVault from a dict"""
+        if obj is None:
+            return None
+
+        if not isinstance(obj, dict):
+            return cls.model_validate(obj)
+
+        _obj = cls.model_validate(
+            {
+                "vault_url": obj.get("vault_url"),
+                "key_name": obj.get("key_name"),
+                "key_version": obj.get("key_version"),
+            }
+        )
+        return _obj
@@ -16,8 +16,9 @@ import pprint
 import re  # noqa: F401
 import json
 
-from pydantic import BaseModel, ConfigDict, StrictStr
-from typing import Any, ClassVar, Dict, List
+from pydantic import BaseModel, ConfigDict, Field, StrictStr
+from typing import Any, ClassVar, Dict, List, Optional
+from typing_extensions import Annotated
 from typing import Optional, Set
 from typing_extensions import Self
 
@@ -27,8 +28,16 @@ class InvokeError(BaseModel):
     InvokeError
     """  # noqa: E501
 
+    code: Optional[StrictStr] = Field(
+        default=None,
+        description="Classifies the failure, e.g. `rate_limited` or `retryable`, when the function reported it",
+    )
     message: StrictStr
-    __properties: ClassVar[List[str]] = ["message"]
+    retry_after_ms: Optional[Annotated[int, Field(strict=True, ge=0)]] = Field(
+        default=None,
+        description="Suggested delay before retrying a `rate_limited` or `retryable` failure",
+    )
+    __properties: ClassVar[List[str]] = ["code", "message", "retry_after_ms"]
 
     model_config = ConfigDict(
         populate_by_name=True,
@@ -67,6 +76,16 @@ class InvokeError(BaseModel):
             exclude=excluded_fields,
             exclude_none=True,
         )
+        # set to None if code (nullable) is None
+        # and model_fields_set contains the field
+        if self.code is None and "code" in self.model_fields_set:
+            _dict["code"] = None
+
+        # set to None if retry_after_ms (nullable) is None
+        # and model_fields_set contains the field
+        if self.retry_after_ms is None and "retry_after_ms" in self.model_fields_set:
+            _dict["retry_after_ms"] = None
+
         return _dict
 
     @classmethod
@@ -78,5 +97,11 @@ class InvokeError(BaseModel):
         if not isinstance(obj, dict):
             return cls.model_validate(obj)
 
-        _obj = cls.model_validate({"message": obj.get("message")})
+        _obj = cls.model_validate(
+            {
+                "code": obj.get("code"),
+                "message": obj.get("message"),
+                "retry_after_ms": obj.get("retry_after_ms"),
+            }
+        )
         return _obj
@@ -1,5 +1,7 @@
 """Basic tests for trysoma_sdk package."""
 
+import pytest
+
 
 def test_import_sdk() -> None:
     """Test that the SDK can be imported."""
@@ -83,3 +85,101 @@ class TestPatterns:
 
         wrapped = patterns.workflow(my_handler)
         assert callable(wrapped)
+
+
+class TestFunctionContext:
+    """Tests for the context passed to function handlers."""
+
+    def test_handler_logs_are_collected(self) -> None:
+        """Test that lines logged by a handler end up on the context in order."""
+        import asyncio
+        from trysoma_sdk.bridge import FunctionContext, call_handler
+
+        async def handler(value: int, context: FunctionContext) -> int:
+            context.info("starting")
+            context.warn("almost done")
+            return value + 1
+
+        context = FunctionContext()
+        result = asyncio.run(call_handler(handler, 1, context))
+
+        assert result == 2
+        assert [(line.level, line.message) for line in context.logs] == [
+            ("info", "starting"),
+            ("warn", "almost done"),
+        ]
+        assert all(line.timestamp_ms is not None for line in context.logs)
+
+    def test_handler_without_context_is_called_with_input_only(self) -> None:
+        """Test that single-argument handlers keep working."""
+        import asyncio
+        from trysoma_sdk.bridge import FunctionContext, call_handler
+
+        async def handler(value: int) -> int:
+            return value * 2
+
+        context = FunctionContext()
+        assert asyncio.run(call_handler(handler, 3, context)) == 6
+        assert context.logs == []
+
+    def test_invocation_logs_are_appended_while_running(
+        self, monkeypatch: pytest.MonkeyPatch
+    ) -> None:
+        """Test that a context with an invocation id hands lines to the SDK as they are logged."""
+        import trysoma_sdk.bridge as bridge
+
+        appended: list[tuple[str, str, str]] = []
+        monkeypatch.setattr(
+            bridge,
+            "append_invocation_log",
+            lambda invocation_id, line: appended.append(
+                (invocation_id, line.level, line.message)
+            )
+            or True,
+        )
+
+        context = bridge.FunctionContext("7")
+        context.info("starting")
+        context.error("failed")
+
+        assert appended == [("7", "info", "starting"), ("7", "error", "failed")]
+        assert context.logs == []
+
+    def test_streaming_handler_is_collected_in_order(self) -> None:
+        """Test that unary calls to an async generator handler get every item."""
+        import asyncio
+        from collections.abc import AsyncIterator
+        from trysoma_sdk.bridge import FunctionContext, collect_stream
+
+        async def handler(value: int, context: FunctionContext) -> AsyncIterator[int]:
+            for i in range(value):
+                context.info(f"chunk {i}")
+                yield i
+
+        context = FunctionContext()
+        assert asyncio.run(collect_stream(handler, 3, context)) == [0, 1, 2]
+        assert len(context.logs) == 3
+
+    def test_failure_response_keeps_error_code(self) -> None:
+        """Test that error codes raised by handlers reach the failure response."""
+        from trysoma_sdk.bridge import to_failure_response
+
+        class RateLimitedError(Exception):
+            code = "rate_limited"
+            retry_after_ms = 500
+
+        response = to_failure_response(RateLimitedError("slow down"), [])
+        assert response.error is not None
+        assert response.error.message == "slow down"
+        assert response.error.code == "rate_limited"
+        assert response.error.retry_after_ms == 500
+
+        payload = {"error": {"code": "unauthorized", "message": "bad token"}}
+        response = to_failure_response(Exception(payload), [])
+        assert response.error is not None
+        assert response.error.message == "bad token"
+        assert response.error.code == "unauthorized"
+
+        response = to_failure_response(ValueError("boom"), [])
+        assert response.error is not None
+        assert response.error.code is None
@@ -1,7 +1,7 @@
 """Soma Python SDK - Build AI agents with ease."""
 
 from trysoma_sdk.agent import SomaAgent, create_soma_agent, HandlerParams
-from trysoma_sdk.bridge import create_soma_function, SomaFunction
+from trysoma_sdk.bridge import create_soma_function, FunctionContext, SomaFunction
 from trysoma_sdk.patterns import patterns
 from trysoma_sdk.standalone import generate_standalone, watch_and_regenerate
 
@@ -21,6 +21,7 @@ from trysoma_sdk_core import (
     InvokeFunctionRequest,
     InvokeFunctionResponse,
     CallbackError,
+    LogLine,
     Secret,
     EnvironmentVariable,
     SetSecretsResponse,
@@ -35,6 +36,8 @@ from trysoma_sdk_core import (
     start_grpc_server,
     kill_grpc_service,
     add_provider,
+    append_invocation_log,
+    reconcile_providers,
     remove_provider,
     update_provider,
     remove_function,
@@ -43,6 +46,7 @@ from trysoma_sdk_core import (
     remove_agent,
     update_agent,
     set_secret_handler,
+    set_secret_handler_for,
     set_environment_variable_handler,
     set_unset_secret_handler,
     set_unset_environment_variable_handler,
@@ -57,6 +61,7 @@ __all__ = [
     "create_soma_agent",
     "HandlerParams",
     "SomaFunction",
+    "FunctionContext",
     "create_soma_function",
     "patterns",
     "generate_standalone",
@@ -75,6 +80,7 @@ __all__ = [
     "InvokeFunctionRequest",
     "InvokeFunctionResponse",
     "CallbackError",
+    "LogLine",
     "Secret",
     "EnvironmentVariable",
     "SetSecretsResponse",
@@ -89,6 +95,8 @@ __all__ = [
     "start_grpc_server",
     "kill_grpc_service",
     "add_provider",
+    "append_invocation_log",
+    "reconcile_providers",
     "remove_provider",
     "update_provider",
     "remove_function",
@@ -97,6 +105,7 @@ __all__ = [
     "remove_agent",
     "update_agent",
     "set_secret_handler",
+    "set_secret_handler_for",
     "set_environment_variable_handler",
     "set_unset_secret_handler",
     "set_unset_environment_variable_handler",
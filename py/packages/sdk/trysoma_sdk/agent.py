@@ -1,6 +1,6 @@
 """Agent creation and management for Soma SDK."""
 
-from dataclasses import dataclass
+from dataclasses import dataclass, field
 from typing import Awaitable, Callable, Protocol
 
 from restate import ObjectContext
@@ -24,6 +24,7 @@ class SomaAgent(Protocol):
     agent_id: str
     name: str
     description: str
+    allowed_functions: list[str]
 
     async def entrypoint(self, params: HandlerParams) -> None:
         """Main entry point for the agent."""
@@ -39,6 +40,7 @@ class _SomaAgentImpl:
     name: str
     description: str
     _entrypoint: Callable[[HandlerParams], Awaitable[None]]
+    allowed_functions: list[str] = field(default_factory=list)
 
     async def entrypoint(self, params: HandlerParams) -> None:
         """Main entry point for the agent."""
@@ -52,6 +54,7 @@ def create_soma_agent(
     name: str,
     description: str,
     entrypoint: Callable[[HandlerParams], Awaitable[None]],
+    allowed_functions: list[str] | None = None,
 ) -> SomaAgent:
     """Create a new Soma agent.
 
@@ -61,6 +64,8 @@ def create_soma_agent(
         name: Human-readable name for the agent.
         description: Description of what this agent does.
         entrypoint: Async function that handles agent invocations.
+        allowed_functions: Provider functions the agent may invoke, as
+            "provider_type_id.function_type_id". None or empty allows every function.
 
     Returns:
         A SomaAgent instance.
@@ -86,4 +91,5 @@ def create_soma_agent(
         name=name,
         description=description,
         _entrypoint=entrypoint,
+        allowed_functions=list(allowed_functions or []),
     )
@@ -1,19 +1,122 @@
 """Bridge client and function creation utilities."""
 
+import inspect
+import time
 from dataclasses import dataclass
-from typing import Awaitable, Callable, Generic, TypeVar
+from typing import Any, AsyncIterator, Awaitable, Callable, Generic, TypeVar
 
 from pydantic import BaseModel
 
 from trysoma_sdk_core import (
     FunctionMetadata,
+    InvokeFunctionResponse,
+    LogLine,
     ProviderController,
+    append_invocation_log,
 )
 
 InputT = TypeVar("InputT", bound=BaseModel)
 OutputT = TypeVar("OutputT", bound=BaseModel)
+ResultT = TypeVar("ResultT")
 
 
+class FunctionContext:
+    """Per-invocation context passed to handlers that accept a second argument.
+
+    Given the request's `invocation_id`, lines are handed to the SDK as they are logged so
+    they survive a timeout; otherwise `logs` collects them to be returned with the response.
+    """
+
+    def __init__(self, invocation_id: str | None = None) -> None:
+        self.invocation_id = invocation_id
+        self.logs: list[LogLine] = []
+
+    def log(self, level: str, message: str) -> None:
+        """Log a line at `level`, one of "debug", "info", "warn" or "error"."""
+        line = LogLine(level, message, int(time.time() * 1000))
+        if self.invocation_id is not None:
+            append_invocation_log(self.invocation_id, line)
+        else:
+            self.logs.append(line)
+
+    def debug(self, message: str) -> None:
+        self.log("debug", message)
+
+    def info(self, message: str) -> None:
+        self.log("info", message)
+
+    def warn(self, message: str) -> None:
+        self.log("warn", message)
+
+    def error(self, message: str) -> None:
+        self.log("error", message)
+
+
+def call_handler(
+    handler: Callable[..., ResultT], input: Any, context: FunctionContext
+) -> ResultT:
+    """Call a function handler, passing `context` only if the handler accepts it."""
+    try:
+        parameters = list(inspect.signature(handler).parameters.values())
+    except (TypeError, ValueError):
+        return handler(input)
+
+    positional_kinds = (
+        inspect.Parameter.POSITIONAL_ONLY,
+        inspect.Parameter.POSITIONAL_OR_KEYWORD,
+    )
+    positional = [p for p in parameters if p.kind in positional_kinds]
+    variadic = any(p.kind == inspect.Parameter.VAR_POSITIONAL for p in parameters)
+    if len(positional) >= 2 or variadic:
+        return handler(input, context)
+    return handler(input)
+
+
+
+async def collect_stream(
+    handler: Callable[..., AsyncIterator[Any]], input: Any, context: FunctionContext
+) -> list[Any]:
+    """Run an async generator handler to completion, returning every item it yielded."""
+    return [item async for item in call_handler(handler, input, context)]
+
+
+def to_json_value(value: Any) -> Any:
+    """Convert a handler result to a JSON-serializable value."""
+    if isinstance(value, BaseModel):
+        return value.model_dump(mode="json")
+    return value
+
+
+def to_failure_response(
+    error: BaseException, logs: list[LogLine]
+) -> InvokeFunctionResponse:
+    """Build the failure response for an exception raised by a handler.
+
+    `code` and `retry_after_ms` are read from the exception, or from an
+    `{"error": {"code", "message"}}` payload it was raised with. The code is left
+    unset when there is none.
+    """
+    source: Any = error
+    if len(error.args) == 1 and isinstance(error.args[0], dict):
+        payload = error.args[0].get("error")
+        if isinstance(payload, dict):
+            source = payload
+
+    def field(name: str) -> Any:
+        if isinstance(source, dict):
+            return source.get(name)
+        return getattr(source, name, None)
+
+    message = field("message")
+    code = field("code")
+    retry_after_ms = field("retry_after_ms")
+    return InvokeFunctionResponse.failure(
+        message if isinstance(message, str) else str(error),
+        code if isinstance(code, str) else None,
+        retry_after_ms if isinstance(retry_after_ms, int) else None,
+        logs,
+    )
+
 @dataclass
 class SomaFunction(Generic[InputT, OutputT]):
     """A Soma function with its metadata."""
@@ -22,7 +125,10 @@ class SomaFunction(Generic[InputT, OutputT]):
     output_schema: type[OutputT]
     provider_controller: ProviderController
     function_metadata: FunctionMetadata
-    handler: Callable[[InputT], Awaitable[OutputT]]
+    handler: (
+        Callable[[InputT], Awaitable[OutputT]]
+        | Callable[[InputT, FunctionContext], Awaitable[OutputT]]
+    )
 
 
 def create_soma_function(
@@ -32,7 +138,10 @@ def create_soma_function(
     provider_controller: ProviderController,
     function_name: str,
     function_description: str,
-    handler: Callable[[InputT], Awaitable[OutputT]],
+    handler: (
+        Callable[[InputT], Awaitable[OutputT]]
+        | Callable[[InputT, FunctionContext], Awaitable[OutputT]]
+    ),
 ) -> SomaFunction[InputT, OutputT]:
     """Create a new Soma function.
 
@@ -43,6 +152,7 @@ def create_soma_function(
         function_name: Name of the function.
         function_description: Description of what the function does.
         handler: Async function that processes the input and returns the output.
+            A handler that takes a second argument receives a FunctionContext.
 
     Returns:
         A SomaFunction instance.
@@ -59,6 +59,7 @@ def generate_standalone_server(base_dir: Path, is_dev: bool = False) -> str:
     # Generate imports
     function_imports: list[str] = []
     function_registrations: list[str] = []
+    function_var_names: list[str] = []
     agent_imports: list[str] = []
     agent_registrations: list[str] = []
 
@@ -81,12 +82,11 @@ def generate_standalone_server(base_dir: Path, is_dev: bool = False) -> str:
             # In production, import from built modules
             module_path = f"functions.{name.replace('/', '.')}"
             function_imports.append(f"from {module_path} import default as {var_name}")
+        function_var_names.append(var_name)
 
         function_registrations.append(f"""
     # Register function: {name}
     fn = {var_name}
-    if hasattr(fn, 'provider_controller') and fn.provider_controller:
-        add_provider(fn.provider_controller)
     if (hasattr(fn, 'function_metadata') and hasattr(fn, 'provider_controller')
             and hasattr(fn, 'handler')):
         provider_type_id = getattr(fn.provider_controller, 'type_id')
@@ -96,6 +96,7 @@ def generate_standalone_server(base_dir: Path, is_dev: bool = False) -> str:
             input_schema: type[BaseModel] | type[object]
         ) -> Callable[[InvokeFunctionRequest], InvokeFunctionResponse]:
             def invoke_callback(req: InvokeFunctionRequest) -> InvokeFunctionResponse:
+                context = FunctionContext(getattr(req, 'invocation_id'))
                 try:
                     import json
                     params = json.loads(getattr(req, 'parameters'))
@@ -106,24 +107,66 @@ def generate_standalone_server(base_dir: Path, is_dev: bool = False) -> str:
                     else:
                         parsed_input = params
                     loop = asyncio.get_event_loop()
-                    result = loop.run_until_complete(fn_handler(parsed_input))
+                    if inspect.isasyncgenfunction(fn_handler):
+                        # Unary calls to a streaming handler get every chunk as a list
+                        result = [
+                            to_json_value(item)
+                            for item in loop.run_until_complete(
+                                collect_stream(fn_handler, parsed_input, context)
+                            )
+                        ]
+                    else:
+                        result = loop.run_until_complete(
+                            call_handler(fn_handler, parsed_input, context)
+                        )
                     # Serialize output using pydantic model if available
                     if hasattr(result, 'model_dump_json'):
                         from pydantic import BaseModel as PydanticBaseModel
                         pydantic_result = cast(PydanticBaseModel, result)
                         return InvokeFunctionResponse.success(
-                            pydantic_result.model_dump_json()
+                            pydantic_result.model_dump_json(), logs=context.logs
                         )
                     else:
-                        return InvokeFunctionResponse.success(json.dumps(result))
+                        return InvokeFunctionResponse.success(
+                            json.dumps(result), logs=context.logs
+                        )
                 except Exception as e:
-                    return InvokeFunctionResponse.failure(str(e))
+                    return to_failure_response(e, context.logs)
             return invoke_callback
 
+        def make_invoke_stream_callback(
+            fn_handler: Callable[..., AsyncIterator[object]],
+            input_schema: type[BaseModel] | type[object]
+        ) -> Callable[[InvokeFunctionRequest], AsyncIterator[InvokeFunctionResponse]]:
+            async def invoke_stream_callback(
+                req: InvokeFunctionRequest,
+            ) -> AsyncIterator[InvokeFunctionResponse]:
+                context = FunctionContext()
+                try:
+                    import json
+                    params = json.loads(getattr(req, 'parameters'))
+                    if hasattr(input_schema, 'model_validate'):
+                        schema = cast(type[BaseModel], input_schema)
+                        parsed_input = schema.model_validate(params)
+                    else:
+                        parsed_input = params
+                    async for item in call_handler(fn_handler, parsed_input, context):
+                        yield InvokeFunctionResponse.success(
+                            json.dumps(to_json_value(item)), logs=context.logs
+                        )
+                except Exception as e:
+                    yield to_failure_response(e, context.logs)
+            return invoke_stream_callback
+
         update_function(
             provider_type_id,
             fn.function_metadata,
-            make_invoke_callback(fn.handler, fn.input_schema)
+            make_invoke_callback(fn.handler, fn.input_schema),
+            (
+                make_invoke_stream_callback(fn.handler, fn.input_schema)
+                if inspect.isasyncgenfunction(fn.handler)
+                else None
+            ),
         )
 """)
 
@@ -155,6 +198,7 @@ def generate_standalone_server(base_dir: Path, is_dev: bool = False) -> str:
             agent.project_id,
             agent.name,
             agent.description,
+            allowed_functions=getattr(agent, 'allowed_functions', None),
         ))
 """)
 
@@ -423,11 +467,12 @@ def generate_standalone_server(base_dir: Path, is_dev: bool = False) -> str:
 """Auto-generated standalone server for Soma SDK."""
 
 import asyncio
+import inspect
 import os
 import sys
 import signal
 import types
-from typing import Awaitable, Callable, cast
+from typing import AsyncIterator, Awaitable, Callable, cast
 
 from pydantic import BaseModel
 
@@ -438,7 +483,7 @@ if _soma_dir not in sys.path:
     sys.path.insert(0, _soma_dir)
 
 from trysoma_sdk import (  # noqa: E402
-    add_provider,
+    reconcile_providers,
     update_function,
     add_agent,
     update_agent,
@@ -459,6 +504,13 @@ from trysoma_sdk import (  # noqa: E402
     UnsetSecretResponse,
     UnsetEnvironmentVariableResponse,
 )
+from trysoma_sdk.bridge import (  # noqa: E402
+    FunctionContext,
+    call_handler,
+    collect_stream,
+    to_failure_response,
+    to_json_value,
+)
 
 {chr(10).join(function_imports)}
 
@@ -518,7 +570,15 @@ async def main() -> None:
 
     set_unset_environment_variable_handler(unset_env_var_handler)
 
-    # Register all providers and functions
+    # Register all providers in one step, so providers shared by several functions
+    # are added once and providers no longer backed by a function are removed
+    providers = {{}}
+    for fn in [{', '.join(function_var_names)}]:
+        if hasattr(fn, 'provider_controller') and fn.provider_controller:
+            providers[fn.provider_controller.type_id] = fn.provider_controller
+    reconcile_providers(list(providers.values()))
+
+    # Register all functions
 {chr(10).join(function_registrations)}
 
     # Register all agents